@@ -0,0 +1,136 @@
+use burn_tensor::{backend::Backend, ElementConversion, Tensor};
+use core::cell::{Cell, RefCell};
+
+std::thread_local! {
+    /// Whether the anomaly detection mode is enabled.
+    static DETECT_ANOMALY: Cell<bool> = const { Cell::new(false) };
+    /// The names of the [anomaly scopes](anomaly_scope) currently entered.
+    static SCOPES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    /// The path of the [module](set_anomaly_module) currently executing its forward pass.
+    static MODULE: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// The operation currently executing its backward step.
+    static CURRENT_OPERATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Executes the function with the anomaly detection mode enabled.
+///
+/// Every gradient computed during a backward pass executed in the function is checked, and the
+/// backward pass panics as soon as a gradient contains NaN or infinite values, reporting the
+/// operation whose backward step produced it along with its [scope](anomaly_scope). This is
+/// slow, since each gradient is read, so it should only be used for debugging.
+///
+/// # Example
+///
+/// ```ignore
+/// let grads = detect_anomaly(|| model.forward(input).backward());
+/// ```
+pub fn detect_anomaly<O, F: FnOnce() -> O>(func: F) -> O {
+    struct ModeGuard {
+        previous: bool,
+    }
+
+    impl Drop for ModeGuard {
+        fn drop(&mut self) {
+            DETECT_ANOMALY.with(|enabled| enabled.set(self.previous));
+            if !self.previous {
+                set_anomaly_module(None);
+            }
+        }
+    }
+
+    let _guard = ModeGuard {
+        previous: DETECT_ANOMALY.with(|enabled| enabled.replace(true)),
+    };
+
+    func()
+}
+
+/// Returns whether the anomaly detection mode is enabled.
+pub fn is_detect_anomaly() -> bool {
+    DETECT_ANOMALY.with(|enabled| enabled.get())
+}
+
+/// Executes the function in a named scope, reported when an anomaly is detected during the
+/// backward step of one of its operations.
+///
+/// Scopes can be nested, their names are then joined with dots, e.g. `encoder.layers.0`, so the
+/// forward pass of each module can be wrapped with its path in the module tree.
+///
+/// # Example
+///
+/// ```ignore
+/// let output = anomaly_scope("encoder", || self.encoder.forward(input));
+/// ```
+pub fn anomaly_scope<O, F: FnOnce() -> O>(name: &str, func: F) -> O {
+    struct ScopeGuard;
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            SCOPES.with(|scopes| scopes.borrow_mut().pop());
+        }
+    }
+
+    SCOPES.with(|scopes| scopes.borrow_mut().push(name.to_string()));
+    let _guard = ScopeGuard;
+
+    func()
+}
+
+/// Set the path of the module executing its forward pass, reported along with the
+/// [scope](anomaly_scope) of the operations created until the next call.
+///
+/// This is done automatically by the modules when their parameters are used in the anomaly
+/// detection mode, so the operations are reported with the path of the module using the last
+/// parameter.
+pub fn set_anomaly_module(path: Option<String>) {
+    MODULE.with(|module| *module.borrow_mut() = path);
+}
+
+/// The path of the scope of an operation created now, only tracked when the anomaly detection
+/// mode is enabled, so the forward pass must also be executed in [detect_anomaly] to report
+/// scopes.
+pub(crate) fn current_scope() -> Option<String> {
+    if !is_detect_anomaly() {
+        return None;
+    }
+
+    let scopes = SCOPES.with(|scopes| {
+        let scopes = scopes.borrow();
+        match scopes.is_empty() {
+            true => None,
+            false => Some(scopes.join(".")),
+        }
+    });
+    let module = MODULE.with(|module| module.borrow().clone());
+
+    match (scopes, module) {
+        (Some(scopes), Some(module)) => Some(format!("{scopes} (module {module})")),
+        (Some(scopes), None) => Some(scopes),
+        (None, Some(module)) => Some(format!("module {module}")),
+        (None, None) => None,
+    }
+}
+
+/// Set the operation currently executing its backward step.
+pub(crate) fn set_current_operation(operation: Option<String>) {
+    CURRENT_OPERATION.with(|current| *current.borrow_mut() = operation);
+}
+
+/// Panics if the gradient contains NaN or infinite values.
+pub(crate) fn check_gradient<B: Backend, const D: usize>(grad: &B::TensorPrimitive<D>) {
+    let grad = Tensor::<B, D>::from_primitive(grad.clone());
+    // Subtracting a tensor from itself only gives zeros when all its values are finite.
+    let sum = (grad.clone() - grad).sum().into_scalar().elem::<f64>();
+
+    if sum.is_nan() {
+        let operation = CURRENT_OPERATION.with(|current| current.borrow().clone());
+
+        match operation {
+            Some(operation) => panic!(
+                "Anomaly detected: the backward step of {operation} produced a gradient with NaN \
+                 or infinite values."
+            ),
+            None => panic!("Anomaly detected: the gradient contains NaN or infinite values."),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{grads::Gradients, graph::backward::backward, tensor::AutodiffTensor};
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::backend::{AutodiffBackend, Backend, MemoryStats};
 use core::marker::PhantomData;
 
 /// Enable auto-differentiation on a backend.
@@ -37,9 +37,29 @@ impl<B: Backend> Backend for Autodiff<B> {
         B::seed(seed)
     }
 
+    fn seed_all(seed: u64) {
+        B::seed_all(seed)
+    }
+
+    fn seed_device(seed: u64, device: &Self::Device) {
+        B::seed_device(seed, device)
+    }
+
+    fn set_deterministic(deterministic: bool) -> bool {
+        B::set_deterministic(deterministic)
+    }
+
+    fn devices() -> Vec<Self::Device> {
+        B::devices()
+    }
+
     fn sync(device: &B::Device) {
         B::sync(device);
     }
+
+    fn memory_stats(device: &B::Device) -> Option<MemoryStats> {
+        B::memory_stats(device)
+    }
 }
 
 impl<B: Backend> AutodiffBackend for Autodiff<B> {
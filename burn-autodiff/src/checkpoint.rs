@@ -0,0 +1,90 @@
+use crate::{
+    grads::Gradients,
+    graph::{backward::backward_into, untracked, Requirement},
+    ops::{Backward, Ops, OpsKind, OpsPrep},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Applies the function on the input without keeping its intermediate activations, which are
+/// recomputed during the backward pass instead.
+///
+/// This trades computation for memory: only the input of the function is kept for the backward
+/// pass, so deeper models can be trained within the same memory budget. The gradients of the
+/// tracked tensors captured by the function, such as the parameters of a module, are computed as
+/// well. Those captured tensors should be leaves of the graph, since only the operations executed
+/// by the function are recomputed.
+///
+/// The function is executed twice, so it should be deterministic. In particular, random
+/// operations such as dropout produce different values during the recomputation.
+///
+/// # Example
+///
+/// ```ignore
+/// let block = self.block.clone();
+/// let output = checkpoint(input, move |x| block.forward(x));
+/// ```
+pub fn checkpoint<B, F, const D1: usize, const D2: usize>(
+    input: Tensor<Autodiff<B>, D1>,
+    func: F,
+) -> Tensor<Autodiff<B>, D2>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<B>, D1>) -> Tensor<Autodiff<B>, D2> + Send + Sync + 'static,
+{
+    let input = input.into_primitive();
+    let (output, used_tracked) = untracked(|| func(Tensor::from_primitive(input.clone())));
+
+    let requirement = match used_tracked {
+        true => Requirement::GradInBackward,
+        false => Requirement::None,
+    };
+    let prep = OpsPrep::new(
+        [input.node.clone()],
+        [input.graph.clone()],
+        requirement,
+        Checkpoint::<F, D1> { func },
+    );
+
+    let output = match prep.stateful() {
+        OpsKind::Tracked(prep) => prep.finish(input.primitive, output.into_primitive().primitive),
+        OpsKind::UnTracked(prep) => prep.finish(output.into_primitive().primitive),
+    };
+
+    Tensor::from_primitive(output)
+}
+
+struct Checkpoint<F, const D: usize> {
+    func: F,
+}
+
+impl<F, const D: usize> core::fmt::Debug for Checkpoint<F, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Checkpoint").finish()
+    }
+}
+
+impl<B, F, const D1: usize, const D2: usize> Backward<B, D2, 1> for Checkpoint<F, D1>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<B>, D1>) -> Tensor<Autodiff<B>, D2> + Send + Sync + 'static,
+{
+    type State = B::TensorPrimitive<D1>;
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [parent] = ops.parents;
+        let grad = grads.consume::<B, D2>(&ops.node);
+
+        // Recompute the activations with tracking enabled, then backpropagate through them.
+        let input = AutodiffTensor::<B, D1>::new(ops.state).require_grad();
+        let output = (self.func)(Tensor::from_primitive(input.clone())).into_primitive();
+        backward_into(output, grad, grads);
+
+        let grad_input = grads.remove(&input);
+
+        if let (Some(parent), Some(grad_input)) = (parent, grad_input) {
+            grads.register::<B, D1>(parent, grad_input);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::Autodiff;
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Computes the jacobian matrix of the function at the given input.
+///
+/// The input and the output of the function are flattened, so the jacobian has the shape
+/// `[num_outputs, num_inputs]`, where the row `i` contains the gradients of the output `i` with
+/// respect to every input.
+///
+/// The function is executed once per output, since each row is computed with a backward pass.
+#[allow(clippy::single_range_in_vec_init)]
+pub fn jacobian<B, F, const D1: usize, const D2: usize>(
+    func: F,
+    input: Tensor<B, D1>,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<B>, D1>) -> Tensor<Autodiff<B>, D2>,
+{
+    let num_inputs = input.shape().num_elements();
+    let device = input.device();
+
+    let forward = || {
+        let input = Tensor::<Autodiff<B>, D1>::from_inner(input.clone()).require_grad();
+        let output = func(input.clone()).flatten::<1>(0, D2 - 1);
+
+        (input, output)
+    };
+
+    let first = forward();
+    let [num_outputs] = first.1.dims();
+    let mut first = Some(first);
+    let mut rows = Vec::with_capacity(num_outputs);
+
+    for i in 0..num_outputs {
+        // The graph is consumed by each backward pass.
+        let (input_tracked, output) = first.take().unwrap_or_else(forward);
+        let grads = output.slice([i..i + 1]).backward();
+        let row = match input_tracked.grad(&grads) {
+            Some(grad) => grad.reshape([1, num_inputs]),
+            None => Tensor::zeros_device([1, num_inputs], &device),
+        };
+
+        rows.push(row);
+    }
+
+    Tensor::cat(rows, 0)
+}
+
+/// Computes the product of the hessian matrix of the function at the given input with the
+/// given vector, without computing the hessian.
+///
+/// The function must return a single value, which is differentiated twice using a
+/// [nested autodiff backend](Autodiff). The vector has the same shape as the input.
+pub fn hvp<B, F, const D: usize>(func: F, input: Tensor<B, D>, vector: Tensor<B, D>) -> Tensor<B, D>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<Autodiff<B>>, D>) -> Tensor<Autodiff<Autodiff<B>>, 1>,
+{
+    let shape = input.shape();
+    let device = input.device();
+
+    let input_inner = Tensor::<Autodiff<B>, D>::from_inner(input).require_grad();
+    let input_outer =
+        Tensor::<Autodiff<Autodiff<B>>, D>::from_inner(input_inner.clone()).require_grad();
+
+    let grads = func(input_outer.clone()).backward();
+    let grad = match input_outer.grad(&grads) {
+        Some(grad) => grad,
+        None => return Tensor::zeros_device(shape.clone(), &device),
+    };
+
+    let grads = grad.mul(Tensor::from_inner(vector)).sum().backward();
+
+    input_inner
+        .grad(&grads)
+        .unwrap_or_else(|| Tensor::zeros_device(shape, &device))
+}
@@ -1,6 +1,7 @@
 use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
 
 use crate::{
+    anomaly,
     graph::{NodeRef, Requirement},
     tensor::AutodiffTensor,
 };
@@ -79,6 +80,10 @@ impl Gradients {
         node: NodeRef,
         value: TensorPrimitive<B, D>,
     ) {
+        if anomaly::is_detect_anomaly() {
+            anomaly::check_gradient::<B, D>(&value);
+        }
+
         if let Some(tensor_old) = self.container.remove::<B, D>(&node.id.value) {
             self.container
                 .register(node.id.value, Tensor::from_primitive(value).add(tensor_old));
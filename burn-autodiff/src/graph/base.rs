@@ -21,7 +21,24 @@ pub type NodeSteps = HashMap<NodeID, StepBoxed>;
 /// The graph contains the [node steps](Step), which can be access by [node id](NodeID).
 #[derive(Default, Clone, Debug)]
 pub struct Graph {
-    steps: Arc<Mutex<NodeSteps>>,
+    state: Arc<Mutex<GraphState>>,
+}
+
+/// The content of a graph.
+///
+/// When two graphs are merged, the steps of one graph are moved into the other one, which is
+/// then referenced by the emptied graph. This way, the tensors still holding the emptied graph
+/// keep access to all of their steps.
+#[derive(Debug)]
+enum GraphState {
+    Steps(NodeSteps),
+    Merged(Graph),
+}
+
+impl Default for GraphState {
+    fn default() -> Self {
+        Self::Steps(NodeSteps::new())
+    }
 }
 
 impl Graph {
@@ -30,21 +47,20 @@ impl Graph {
         Self::default()
     }
 
-    /// Get all the steps for the graph.
+    /// Remove the step of the given node from the graph.
     ///
     /// # Notes
     ///
-    /// This is a owned method, so the current graph will be freed. However, the steps can
-    /// be shared with other graphs, therefore they are going to be cleared.
-    ///
-    /// This is useful, since the graph is supposed to be consumed only once for backprop, and
-    /// keeping all the tensors alive for multiple backward call is a heavy waste of resources.
-    pub fn steps(self) -> NodeSteps {
-        let mut map_drain = HashMap::new();
-        self.execute_mut(|map| {
-            std::mem::swap(&mut *map, &mut map_drain);
-        });
-        map_drain
+    /// The steps are removed while they are visited during the backward pass, so the tensors
+    /// they hold are freed as soon as possible.
+    pub fn remove(&self, id: &NodeID) -> Option<StepBoxed> {
+        let graph = self.clone().resolve();
+        let mut state = graph.state.lock();
+
+        match &mut *state {
+            GraphState::Steps(map) => map.remove(id),
+            GraphState::Merged(_) => unreachable!("The graph is resolved before removing steps"),
+        }
     }
 
     /// Register a new step into the graph.
@@ -56,31 +72,59 @@ impl Graph {
 
     /// Merge two graphs.
     pub fn merge(self, other: Self) -> Self {
-        if Arc::ptr_eq(&self.steps, &other.steps) {
-            return self;
+        let graph = self.resolve();
+        let other = other.resolve();
+
+        if Arc::ptr_eq(&graph.state, &other.state) {
+            return graph;
         }
 
-        self.merge_different(other)
+        graph.merge_different(other)
     }
 
-    fn execute_mut<F: FnOnce(&mut NodeSteps)>(mut self, func: F) -> Self {
-        match Arc::get_mut(&mut self.steps) {
+    /// Follows the merged graphs until the one holding the steps.
+    fn resolve(self) -> Self {
+        let mut graph = self;
+
+        loop {
+            let next = match &*graph.state.lock() {
+                GraphState::Steps(_) => None,
+                GraphState::Merged(next) => Some(next.clone()),
+            };
+
+            match next {
+                Some(next) => graph = next,
+                None => return graph,
+            }
+        }
+    }
+
+    fn execute_mut<F: FnOnce(&mut NodeSteps)>(self, func: F) -> Self {
+        let mut graph = self.resolve();
+
+        match Arc::get_mut(&mut graph.state) {
             Some(mutex) => {
-                let map = mutex.get_mut();
-                func(map);
+                if let GraphState::Steps(map) = mutex.get_mut() {
+                    func(map);
+                }
             }
             None => {
                 // Only lock when there are multiple references to the graph.
-                let mut map = self.steps.lock();
-                func(&mut map);
+                if let GraphState::Steps(map) = &mut *graph.state.lock() {
+                    func(map);
+                }
             }
         };
 
-        self
+        graph
     }
 
     fn merge_different(self, other: Self) -> Self {
-        let mut map2 = other.steps();
+        let mut map2 =
+            match core::mem::replace(&mut *other.state.lock(), GraphState::Merged(self.clone())) {
+                GraphState::Steps(map) => map,
+                GraphState::Merged(_) => unreachable!("The graph is resolved before merging"),
+            };
 
         self.execute_mut(|map1| {
             if map1.len() > map2.len() {
@@ -1,4 +1,5 @@
 use super::NodeRef;
+use core::cell::Cell;
 
 /// Requirement for each tensor in the graph.
 #[derive(Debug, Clone, Copy)]
@@ -17,12 +18,22 @@ impl Requirement {
         matches!(self, Self::None)
     }
     /// Returns the right requirement from a list of nodes.
+    ///
+    /// In an [untracked](untracked) scope, it is always [none](Requirement::None).
     pub fn from_nodes(nodes: &[NodeRef]) -> Self {
-        nodes
+        let requirement = nodes
             .iter()
             .map(|node| node.requirement)
             .reduce(|acc, requirement| requirement.infer(&acc))
-            .unwrap_or(Requirement::None)
+            .unwrap_or(Requirement::None);
+
+        UNTRACKED_SCOPE.with(|scope| match scope.get() {
+            Some(used_tracked) => {
+                scope.set(Some(used_tracked || !requirement.is_none()));
+                Requirement::None
+            }
+            None => requirement,
+        })
     }
 
     fn infer(&self, other: &Self) -> Self {
@@ -32,3 +43,38 @@ impl Requirement {
         }
     }
 }
+
+std::thread_local! {
+    /// Whether the operations are currently executed in an [untracked](untracked) scope, and if a
+    /// tracked tensor was used in that scope.
+    static UNTRACKED_SCOPE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Executes the function without tracking its operations, so no backward step is registered and
+/// all the output tensors are untracked.
+///
+/// Returns the output of the function and whether it used any tracked tensor.
+pub fn untracked<O, F: FnOnce() -> O>(func: F) -> (O, bool) {
+    struct ScopeGuard {
+        previous: Option<bool>,
+    }
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            let used_tracked = UNTRACKED_SCOPE.with(|scope| scope.replace(self.previous));
+            // Nested scopes also report the tracked tensors to their parent scope.
+            if self.previous.is_some() && used_tracked == Some(true) {
+                UNTRACKED_SCOPE.with(|scope| scope.set(Some(true)));
+            }
+        }
+    }
+
+    let guard = ScopeGuard {
+        previous: UNTRACKED_SCOPE.with(|scope| scope.replace(Some(false))),
+    };
+    let output = func();
+    let used_tracked = UNTRACKED_SCOPE.with(|scope| scope.get()).unwrap_or(false);
+    core::mem::drop(guard);
+
+    (output, used_tracked)
+}
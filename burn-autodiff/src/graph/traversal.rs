@@ -15,8 +15,7 @@ impl BreadthFirstSearch {
     ) {
         let mut visited = HashSet::with_capacity(root.order);
         let mut parents = Vec::with_capacity(root.order);
-        let mut steps = graph.steps();
-        let root_step = steps
+        let root_step = graph
             .remove(&root.id)
             .expect("Root node should have a step registered, did you forget to call `Tensor::register_grad` on the tensor where you need gradients?");
 
@@ -25,7 +24,7 @@ impl BreadthFirstSearch {
         callback(root, root_step);
 
         while let Some(id) = parents.pop() {
-            let step = match steps.remove(&id) {
+            let step = match graph.remove(&id) {
                 Some(step) => step,
                 None => continue,
             };
@@ -24,5 +24,15 @@ pub(crate) mod utils;
 mod backend;
 pub use backend::*;
 
+mod anomaly;
+mod checkpoint;
+mod functional;
+mod no_grad;
+
+pub use anomaly::*;
+pub use checkpoint::*;
+pub use functional::*;
+pub use no_grad::*;
+
 #[cfg(feature = "export_tests")]
 mod tests;
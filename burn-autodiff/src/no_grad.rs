@@ -0,0 +1,17 @@
+use crate::graph::untracked;
+
+/// Executes the function without building the autodiff graph.
+///
+/// All the tensors created in the function are untracked, even when they are computed from
+/// tensors requiring gradients, so no backward state is kept in memory. This is useful for
+/// validation and inference with a model of an autodiff backend.
+///
+/// # Example
+///
+/// ```ignore
+/// let output = no_grad(|| model.forward(input));
+/// ```
+pub fn no_grad<O, F: FnOnce() -> O>(func: F) -> O {
+    let (output, _) = untracked(func);
+    output
+}
@@ -1,5 +1,6 @@
 use super::Backward;
 use crate::{
+    anomaly,
     grads::Gradients,
     graph::{
         NodeRef, Requirement, {Graph, Step},
@@ -108,7 +109,7 @@ where
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), state);
 
-        output.register_step(OpsStep::new(ops, self.backward))
+        output.register_step(OpsStep::new(ops, self.backward, anomaly::current_scope()))
     }
 }
 
@@ -141,6 +142,8 @@ where
 {
     ops: Ops<SB, N>,
     backward: T,
+    // The anomaly scope in which the operation was created.
+    scope: Option<String>,
     phantom: PhantomData<B>,
 }
 
@@ -151,7 +154,17 @@ where
     SB: Clone + Send + Sync + std::fmt::Debug + 'static,
 {
     fn step(self: Box<Self>, grads: &mut Gradients) {
-        self.backward.backward(self.ops, grads);
+        if anomaly::is_detect_anomaly() {
+            let operation = match &self.scope {
+                Some(scope) => format!("{:?} in {scope}", self.backward),
+                None => format!("{:?}", self.backward),
+            };
+            anomaly::set_current_operation(Some(operation));
+            self.backward.backward(self.ops, grads);
+            anomaly::set_current_operation(None);
+        } else {
+            self.backward.backward(self.ops, grads);
+        }
     }
 
     fn node(&self) -> NodeRef {
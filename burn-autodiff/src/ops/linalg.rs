@@ -0,0 +1,5 @@
+use crate::Autodiff;
+use burn_tensor::{backend::Backend, ops::LinalgOps};
+
+// The default implementations are written with tensor operations, which are all differentiable.
+impl<B: Backend> LinalgOps<Autodiff<B>> for Autodiff<B> {}
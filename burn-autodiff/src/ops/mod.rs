@@ -2,7 +2,9 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
 
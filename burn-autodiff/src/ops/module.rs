@@ -37,12 +37,104 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
         }
     }
 
-    fn embedding_backward(
-        _weights: AutodiffTensor<B, 2>,
-        _output: AutodiffTensor<B, 3>,
-        _indices: IntTensor<B, 2>,
-    ) -> AutodiffTensor<B, 2> {
-        panic!("Can't differentiate embedding backward.");
+    fn attention(
+        query: AutodiffTensor<B, 4>,
+        key: AutodiffTensor<B, 4>,
+        value: AutodiffTensor<B, 4>,
+        mask: Option<BoolTensor<B, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct Attention;
+
+        impl<B: Backend> Backward<B, 4, 3> for Attention {
+            type State = (
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<4>,
+                Option<BoolTensor<B, 3>>,
+                Option<AttentionDropout>,
+                f64,
+                B::TensorPrimitive<4>,
+                B::TensorPrimitive<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query, key, value, mask, dropout, min_float, output, logsumexp) = ops.state;
+                let backward = B::attention_backward(
+                    query,
+                    key,
+                    value,
+                    mask,
+                    dropout,
+                    min_float,
+                    AttentionForward::new(output, logsumexp),
+                    grad,
+                );
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        // Only the inputs, the output and the log-sum-exp of the scores are kept for the backward
+        // pass, which recomputes the attention weights, so the fused forward pass of the backend
+        // is used. The dropout mask is generated again from its seed.
+        match Attention
+            .prepare(
+                [query.node, key.node, value.node],
+                [query.graph, key.graph, value.graph],
+            )
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let forward = B::attention_forward(
+                    query.primitive.clone(),
+                    key.primitive.clone(),
+                    value.primitive.clone(),
+                    mask.clone(),
+                    dropout,
+                    min_float,
+                );
+
+                prep.finish(
+                    (
+                        query.primitive,
+                        key.primitive,
+                        value.primitive,
+                        mask,
+                        dropout,
+                        min_float,
+                        forward.output.clone(),
+                        forward.logsumexp,
+                    ),
+                    forward.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                mask,
+                dropout,
+                min_float,
+            )),
+        }
+    }
+
+    fn attention_dropout_seed(device: &Device<B>) -> u32 {
+        B::attention_dropout_seed(device)
     }
 
     fn conv2d(
@@ -499,6 +591,10 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     //     todo!()
     // }
 
+    // The grid sampling isn't overridden, its default implementation is composed of gathers
+    // and element-wise operations, which provides the gradients of both the input and the grid.
+    // Custom grid sampling operations defined by backends won't be used with autodiff.
+
     fn avg_pool1d(
         x: AutodiffTensor<B, 3>,
         kernel_size: usize,
@@ -618,14 +714,48 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     }
 
     fn avg_pool2d_backward(
-        _x: AutodiffTensor<B, 4>,
-        _grad: AutodiffTensor<B, 4>,
-        _kernel_size: [usize; 2],
-        _stride: [usize; 2],
-        _padding: [usize; 2],
-        _count_include_pad: bool,
+        x: AutodiffTensor<B, 4>,
+        grad: AutodiffTensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
     ) -> AutodiffTensor<B, 4> {
-        panic!("Can't differentiate avg pool 2d backward.");
+        #[derive(Debug)]
+        struct AvgPool2DBackward;
+
+        // The backward pass is linear with respect to the output gradient, so its own backward
+        // pass is the forward pass of the pooling.
+        impl<B: Backend> Backward<B, 4, 1> for AvgPool2DBackward {
+            type State = ([usize; 2], [usize; 2], [usize; 2], bool);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (kernel_size, stride, padding, count_include_pad) = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::avg_pool2d(grad, kernel_size, stride, padding, count_include_pad)
+                });
+            }
+        }
+
+        let output = B::avg_pool2d_backward(
+            x.primitive,
+            grad.primitive,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+
+        match AvgPool2DBackward
+            .prepare([grad.node], [grad.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish((kernel_size, stride, padding, count_include_pad), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
     }
 
     fn max_pool1d(
@@ -880,10 +1010,123 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     }
 
     fn adaptive_avg_pool2d_backward(
+        x: AutodiffTensor<B, 4>,
+        grad: AutodiffTensor<B, 4>,
+    ) -> <Autodiff<B> as Backend>::TensorPrimitive<4> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool2DBackward;
+
+        // The backward pass is linear with respect to the output gradient, so its own backward
+        // pass is the forward pass of the pooling.
+        impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2DBackward {
+            type State = [usize; 2];
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let output_size = ops.state;
+
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::adaptive_avg_pool2d(grad, output_size)
+                });
+            }
+        }
+
+        let [_, _, height, width] = B::shape(&grad.primitive).dims;
+        let output = B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive);
+
+        match AdaptiveAvgPool2DBackward
+            .prepare([grad.node], [grad.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish([height, width], output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn adaptive_max_pool2d(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> AutodiffTensor<B, 4> {
+        match AdaptiveMaxPool2D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive.clone(), output_size);
+                prep.finish((x.primitive, output.indices), output.output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_max_pool2d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Autodiff<B>> {
+        match AdaptiveMaxPool2D.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive.clone(), output_size);
+                let output_tensor =
+                    prep.finish((x.primitive, output.indices.clone()), output.output);
+
+                MaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        _x: AutodiffTensor<B, 4>,
+        _output_grad: AutodiffTensor<B, 4>,
+        _indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<Autodiff<B>> {
+        panic!("Can't differentiate adaptive max pool2d with indices backward.");
+    }
+
+    fn interpolate(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct Interpolate;
+
+        impl<B: Backend> Backward<B, 4, 1> for Interpolate {
+            type State = (B::TensorPrimitive<4>, [usize; 2], InterpolateOptions);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+                let (x, output_size, options) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate_backward(x, grad, output_size, options);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match Interpolate.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (x.primitive.clone(), output_size, options.clone()),
+                B::interpolate(x.primitive, output_size, options),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::interpolate(x.primitive, output_size, options))
+            }
+        }
+    }
+
+    fn interpolate_backward(
         _x: AutodiffTensor<B, 4>,
         _grad: AutodiffTensor<B, 4>,
+        _output_size: [usize; 2],
+        _options: InterpolateOptions,
     ) -> <Autodiff<B> as Backend>::TensorPrimitive<4> {
-        panic!("Can't differentiate adaptive avg pool2d backward.");
+        panic!("Can't differentiate interpolate backward.");
     }
 }
 
@@ -954,3 +1197,22 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct AdaptiveMaxPool2D;
+
+impl<B: Backend> Backward<B, 4, 1> for AdaptiveMaxPool2D {
+    type State = (B::TensorPrimitive<4>, IntTensor<B, 4>);
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 4>(&ops.node);
+        let (x, indices) = ops.state;
+
+        if let Some(node) = node_parent {
+            let grad = B::adaptive_max_pool2d_with_indices_backward(x, grad, indices);
+
+            grads.register::<B, 4>(node, grad.x_grad);
+        }
+    }
+}
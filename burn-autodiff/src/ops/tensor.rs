@@ -661,10 +661,10 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         struct IndexSelectDimAssign<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>, Shape<D>, B::Device);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
+                let (dim, indices, shape_lhs, device) = ops.state;
                 let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
 
                 binary::<B, D, D, D, _, _>(
@@ -675,10 +675,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
                         let zeros = B::zeros(shape_lhs, &device);
                         B::select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
                     },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| B::select(grad, dim, indices_4rhs.unwrap()),
                 );
             }
         }
@@ -692,7 +689,6 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
                     dim,
                     indices.clone(),
                     B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
                     B::device(&value.primitive),
                 ),
                 B::select_assign(tensor.primitive, dim, indices, value.primitive),
@@ -1077,6 +1073,53 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match CumSum.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumProd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+            type State = (B::TensorPrimitive<D>, usize);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (input, dim) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    cumprod_backward::<B, D>(input, grad, dim)
+                });
+            }
+        }
+
+        match CumProd.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (tensor.primitive.clone(), dim),
+                B::cumprod(tensor.primitive, dim),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::cumprod(tensor.primitive, dim)),
+        }
+    }
+
     fn to_full_precision<const D: usize>(
         tensor: &FloatTensor<Self, D>,
     ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
@@ -1407,12 +1450,16 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
                 self.nodes
                     .into_iter()
                     .zip(self.dim_sizes)
-                    .filter_map(|(node, dim_size)| node.map(|node| (node, dim_size)))
                     .for_each(|(node, dim_size)| {
-                        let mut ranges = ranges.clone();
-                        ranges[self.dim] = current_index..dim_size + current_index;
+                        let start = current_index;
                         current_index += dim_size;
-                        grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+
+                        // Tensors that don't require grad still occupy a range of the output.
+                        if let Some(node) = node {
+                            let mut ranges = ranges.clone();
+                            ranges[self.dim] = start..current_index;
+                            grads.register::<B, D>(node, B::slice(grad.clone(), ranges));
+                        }
                     });
             }
 
@@ -1455,7 +1502,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::max_dim(tensor.primitive, dim)),
         }
@@ -1468,7 +1515,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
@@ -1485,7 +1532,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indices(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::min_dim(tensor.primitive, dim)),
         }
@@ -1498,7 +1545,7 @@ impl<B: Backend> TensorOps<Self> for Autodiff<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indices(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
@@ -1552,3 +1599,43 @@ impl<const D: usize> BinaryOpsBroadcast<D> {
         }
     }
 }
+
+/// Cumulative sum starting from the end of the given dimension, which is the gradient of the
+/// cumulative sum.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let total = B::sum_dim(tensor.clone(), dim);
+    let cumsum = B::cumsum(tensor.clone(), dim);
+
+    B::add(B::sub(tensor, cumsum), total)
+}
+
+/// The gradient of the cumulative product, which stays finite when the input contains zeros.
+///
+/// Without zeros, the gradient is the reverse cumulative sum of `grad * output` divided by the
+/// input. Every element after the first zero of a line is multiplied by it, so its gradient is
+/// already zero, but the first zero itself needs the products skipping it, which are computed by
+/// replacing it with one.
+fn cumprod_backward<B: Backend, const D: usize>(
+    input: B::TensorPrimitive<D>,
+    grad: B::TensorPrimitive<D>,
+    dim: usize,
+) -> B::TensorPrimitive<D> {
+    let is_zero = B::equal_elem(input.clone(), 0.elem());
+    let zeros = B::bool_into_float(is_zero.clone());
+    let num_zeros = B::cumsum(zeros.clone(), dim);
+    let first_zero = B::equal_elem(B::mul(zeros, num_zeros), 1.elem());
+
+    let output = B::cumprod(input.clone(), dim);
+    let grad_input = B::div(
+        reverse_cumsum::<B, D>(B::mul(grad.clone(), output), dim),
+        B::mask_fill(input.clone(), is_zero, 1.elem()),
+    );
+
+    let output_skipped = B::cumprod(B::mask_fill(input, first_zero.clone(), 1.elem()), dim);
+    let grad_first_zero = reverse_cumsum::<B, D>(B::mul(grad, output_skipped), dim);
+
+    B::mask_where(grad_input, first_zero, grad_first_zero)
+}
@@ -73,9 +73,13 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
         parent_graphs: I,
         requirement: Requirement,
     ) -> Self {
-        let graph = parent_graphs
-            .reduce(|acc, graph| acc.merge(graph))
-            .unwrap_or_else(Graph::new);
+        // Untracked tensors never register steps, so they don't need the graph of their parents.
+        let graph = match requirement.is_none() {
+            true => Graph::new(),
+            false => parent_graphs
+                .reduce(|acc, graph| acc.merge(graph))
+                .unwrap_or_else(Graph::new),
+        };
 
         let order = parent_nodes
             .iter()
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool2d;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_adaptive_max_pool2d_overlapping_windows() {
+        let x = TestAutodiffTensor::from_floats([[[
+            [0.5, -1.0, 3.0, 2.0, 0.0],
+            [1.5, 4.0, -2.0, 0.5, 1.0],
+            [-0.5, 2.5, 1.0, 3.5, -1.5],
+            [2.0, 0.0, 5.0, -3.0, 0.5],
+        ]]])
+        .require_grad();
+
+        let output = adaptive_max_pool2d(x.clone(), [3, 3]);
+        let grads = output.backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        // The maximum of overlapping windows receives the gradient of each of them.
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[
+                [0.0, 0.0, 0.0, 1.0, 0.0],
+                [0.0, 4.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 2.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+    }
+}
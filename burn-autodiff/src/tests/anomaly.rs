@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(ad_anomaly)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{anomaly_scope, detect_anomaly};
+    use burn_tensor::Data;
+
+    #[test]
+    #[should_panic(expected = "Anomaly detected: the backward step of Sqrt in block.layer")]
+    fn should_report_the_operation_producing_non_finite_gradients() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0], [4.0, 9.0]]);
+        let tensor = TestAutodiffTensor::from_data(data).require_grad();
+
+        detect_anomaly(|| {
+            let output = anomaly_scope("block", || {
+                let tensor = tensor.clone().mul_scalar(2.0);
+                anomaly_scope("layer", || tensor.sqrt())
+            });
+            output.sum().backward()
+        });
+    }
+
+    #[test]
+    fn should_not_report_finite_gradients() {
+        let data = Data::<f32, 2>::from([[1.0, 1.0], [4.0, 9.0]]);
+        let tensor = TestAutodiffTensor::from_data(data).require_grad();
+
+        let grads = detect_anomaly(|| tensor.clone().sqrt().sum().backward());
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [0.25, 0.16666667]]), 3);
+    }
+}
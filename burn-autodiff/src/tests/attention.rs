@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{attention, attention_dropout_mask, attention_with_dropout};
+    use burn_tensor::ops::AttentionDropout;
+    use burn_tensor::{activation, Bool, Data, Tensor};
+
+    #[test]
+    fn should_diff_attention() {
+        let query = TestAutodiffTensor::zeros([1, 1, 2, 1]).require_grad();
+        let key = TestAutodiffTensor::from_data([[[[1.0], [-1.0]]]]).require_grad();
+        let value = TestAutodiffTensor::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+
+        let output = attention(query.clone(), key.clone(), value.clone(), None, -1.0e4);
+        let grads = output.sum().backward();
+
+        let query_grad = query.grad(&grads).unwrap();
+        let key_grad = key.grad(&grads).unwrap();
+        let value_grad = value.grad(&grads).unwrap();
+        query_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[-2.0], [-2.0]]]]), 3);
+        key_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.0], [0.0]]]]), 3);
+        value_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 1.0], [1.0, 1.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_attention_with_mask() {
+        let query = TestAutodiffTensor::from_data([[[[1.0, 0.0], [0.5, -1.0]]]]).require_grad();
+        let key =
+            TestAutodiffTensor::from_data([[[[0.5, 1.0], [-1.0, 2.0], [1.5, 0.5]]]]).require_grad();
+        let value =
+            TestAutodiffTensor::from_data([[[[1.0, -1.0], [2.0, 0.5], [0.0, 3.0]]]]).require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 3, Bool>::from_data([[
+            [false, false, true],
+            [false, true, false],
+        ]]);
+        let weights = Tensor::<TestAutodiffBackend, 4>::from_data([[[[1.0, 2.0], [-1.0, 0.5]]]]);
+
+        let output = attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            Some(mask.clone()),
+            -1.0e4,
+        );
+        let grads = (output * weights.clone()).sum().backward();
+
+        // The gradients of the decomposed attention, which materializes the attention weights.
+        let query_ref = TestAutodiffTensor::from_data(query.to_data()).require_grad();
+        let key_ref = TestAutodiffTensor::from_data(key.to_data()).require_grad();
+        let value_ref = TestAutodiffTensor::from_data(value.to_data()).require_grad();
+        let scores = query_ref
+            .clone()
+            .matmul(key_ref.clone().transpose())
+            .div_scalar(2.0f64.sqrt())
+            .mask_fill(mask.unsqueeze(), -1.0e4);
+        let output_ref = activation::softmax(scores, 3).matmul(value_ref.clone());
+        let grads_ref = (output_ref * weights).sum().backward();
+
+        for (tensor, tensor_ref) in [(query, query_ref), (key, key_ref), (value, value_ref)] {
+            tensor
+                .grad(&grads)
+                .unwrap()
+                .to_data()
+                .assert_approx_eq(&tensor_ref.grad(&grads_ref).unwrap().to_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_diff_attention_with_dropout() {
+        let query = TestAutodiffTensor::from_data([[[[1.0, 0.0], [0.5, -1.0]]]]).require_grad();
+        let key =
+            TestAutodiffTensor::from_data([[[[0.5, 1.0], [-1.0, 2.0], [1.5, 0.5]]]]).require_grad();
+        let value =
+            TestAutodiffTensor::from_data([[[[1.0, -1.0], [2.0, 0.5], [0.0, 3.0]]]]).require_grad();
+        let dropout = AttentionDropout::new(0.5, 7);
+        let weights = Tensor::<TestAutodiffBackend, 4>::from_data([[[[1.0, 2.0], [-1.0, 0.5]]]]);
+
+        let output = attention_with_dropout(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            Some(dropout),
+            -1.0e4,
+        );
+        let grads = (output * weights.clone()).sum().backward();
+
+        // The gradients of the decomposed attention, which materializes the attention weights
+        // and the dropout mask.
+        let dropout_mask =
+            attention_dropout_mask::<TestAutodiffBackend>(dropout, [1, 1, 2, 3], &query.device());
+        let query_ref = TestAutodiffTensor::from_data(query.to_data()).require_grad();
+        let key_ref = TestAutodiffTensor::from_data(key.to_data()).require_grad();
+        let value_ref = TestAutodiffTensor::from_data(value.to_data()).require_grad();
+        let scores = query_ref
+            .clone()
+            .matmul(key_ref.clone().transpose())
+            .div_scalar(2.0f64.sqrt());
+        let output_ref = (activation::softmax(scores, 3) * dropout_mask).matmul(value_ref.clone());
+        let grads_ref = (output_ref * weights).sum().backward();
+
+        for (tensor, tensor_ref) in [(query, query_ref), (key, key_ref), (value, value_ref)] {
+            tensor
+                .grad(&grads)
+                .unwrap()
+                .to_data()
+                .assert_approx_eq(&tensor_ref.grad(&grads_ref).unwrap().to_data(), 3);
+        }
+    }
+}
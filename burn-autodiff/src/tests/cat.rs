@@ -73,4 +73,18 @@ mod tests {
         assert_eq!(tensor_1.dims(), grad_1.dims());
         assert_eq!(tensor_2.dims(), grad_2.dims());
     }
+
+    #[test]
+    fn should_diff_cat_with_tensor_not_requiring_grad() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0]]);
+        let tensor_2 = TestAutodiffTensor::from_data([[3.0, 4.0], [5.0, 6.0]]).require_grad();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let tensor_3 = TestAutodiffTensor::cat(vec![tensor_1, tensor_2.clone()], 0);
+        let grads = tensor_3.mul(weights).sum().backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_2.into_data(), Data::from([[3.0, 4.0], [5.0, 6.0]]));
+    }
 }
@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(ad_checkpoint)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_checkpoint_like_the_function() {
+        let data_1 = Data::<f32, 2>::from([[0.0, 1.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, 10.0]]);
+
+        let func =
+            |x: TestAutodiffTensor<2>, weight: TestAutodiffTensor<2>| x.matmul(weight).tanh().exp();
+
+        // Each backward pass consumes its graph, so the expected gradients use their own tensors.
+        let tensor_1 = TestAutodiffTensor::from_data(data_1.clone()).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone()).require_grad();
+        let tensor_3 = tensor_1.clone().mul_scalar(0.1);
+        let weight = tensor_2.clone();
+        let grads = checkpoint(tensor_3, move |x| func(x, weight.clone()))
+            .sum()
+            .backward();
+
+        let tensor_1_expected = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2_expected = TestAutodiffTensor::from_data(data_2).require_grad();
+        let tensor_3_expected = tensor_1_expected.clone().mul_scalar(0.1);
+        let grads_expected = func(tensor_3_expected, tensor_2_expected.clone())
+            .sum()
+            .backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &tensor_1_expected.grad(&grads_expected).unwrap().to_data(),
+            3,
+        );
+        grad_2.to_data().assert_approx_eq(
+            &tensor_2_expected.grad(&grads_expected).unwrap().to_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_checkpoint_with_untracked_input() {
+        let data_1 = Data::<f32, 2>::from([[0.0, 1.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, 10.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1);
+        let tensor_2 = TestAutodiffTensor::from_data(data_2).require_grad();
+
+        let weight = tensor_2.clone();
+        let tensor_3 = checkpoint(tensor_1, move |x| weight.clone().matmul(x));
+        let grads = tensor_3.sum().backward();
+
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 7.0], [1.0, 7.0]]), 3);
+    }
+
+    #[test]
+    fn should_not_track_checkpoint_without_tracked_tensors() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0], [3.0, 4.0]]);
+        let tensor = TestAutodiffTensor::from_data(data);
+
+        let output = checkpoint(tensor, |x: TestAutodiffTensor<2>| x.exp());
+
+        assert!(!output.is_require_grad());
+    }
+}
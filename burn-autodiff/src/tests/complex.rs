@@ -78,4 +78,46 @@ mod tests {
         );
         assert_eq!(grad_2.to_data(), Data::from([[223.0, 279.0], [63.0, 79.0]]));
     }
+
+    #[test]
+    fn should_diff_with_discarded_branch_sharing_parents() {
+        let data_1: Data<f32, 2> = Data::from([[1.0, 7.0], [13.0, -3.0]]);
+        let data_2: Data<f32, 2> = Data::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().mul_scalar(2.0);
+        // Merges the graph of tensor_3 into the one of tensor_2, the result is never used.
+        let _tensor_4 = tensor_2.mul(tensor_3.clone());
+        let tensor_5 = tensor_3.mul_scalar(3.0);
+
+        let grads = tensor_5.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[6.0, 6.0], [6.0, 6.0]]));
+    }
+
+    #[test]
+    fn should_diff_two_losses_sharing_a_parameter_separately() {
+        let data_1: Data<f32, 2> = Data::from([[1.0, 7.0], [13.0, -3.0]]);
+        let data_2: Data<f32, 2> = Data::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2);
+
+        // Both losses are computed before any backward pass, so they share the graph of tensor_1.
+        let loss_1 = tensor_1.clone().mul(tensor_2);
+        let loss_2 = tensor_1.clone().mul_scalar(3.0);
+
+        let grads_1 = loss_1.backward();
+        let grads_2 = loss_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads_1).unwrap();
+        let grad_2 = tensor_1.grad(&grads_2).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[4.0, 7.0], [2.0, 3.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[3.0, 3.0], [3.0, 3.0]]));
+    }
 }
@@ -0,0 +1,246 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Shape};
+
+    #[test]
+    fn test_conv3d_basic() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels: [2, 2],
+            kernel_size: [2, 2, 2],
+            padding: [1, 1, 1],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 3, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [
+                        [
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                        ],
+                        [
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                        ],
+                    ],
+                    [
+                        [
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                        ],
+                        [
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                        ],
+                    ],
+                ],
+                [
+                    [
+                        [
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                        ],
+                        [
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                            [184.0, 184.0, 184.0],
+                        ],
+                    ],
+                    [
+                        [
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                        ],
+                        [
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                            [312.0, 312.0, 312.0],
+                        ],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [
+                        [[954.0, 954.0], [954.0, 954.0]],
+                        [[954.0, 954.0], [954.0, 954.0]],
+                    ],
+                    [
+                        [[1602.0, 1602.0], [1602.0, 1602.0]],
+                        [[1602.0, 1602.0], [1602.0, 1602.0]],
+                    ],
+                ],
+                [
+                    [
+                        [[954.0, 954.0], [954.0, 954.0]],
+                        [[954.0, 954.0], [954.0, 954.0]],
+                    ],
+                    [
+                        [[1602.0, 1602.0], [1602.0, 1602.0]],
+                        [[1602.0, 1602.0], [1602.0, 1602.0]],
+                    ],
+                ],
+            ]),
+            bias: TestTensor::from_floats([96.0, 96.0]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_stride_dilation_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels: [2, 4],
+            kernel_size: [2, 2, 2],
+            padding: [0, 1, 0],
+            stride: [2, 1, 1],
+            dilation: [1, 1, 2],
+            groups: 2,
+            size: [3, 3, 4],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [
+                        [20.0, 20.0, 24.0, 24.0],
+                        [20.0, 20.0, 24.0, 24.0],
+                        [20.0, 20.0, 24.0, 24.0],
+                    ],
+                    [
+                        [36.0, 36.0, 40.0, 40.0],
+                        [36.0, 36.0, 40.0, 40.0],
+                        [36.0, 36.0, 40.0, 40.0],
+                    ],
+                    [
+                        [0.0, 0.0, 0.0, 0.0],
+                        [0.0, 0.0, 0.0, 0.0],
+                        [0.0, 0.0, 0.0, 0.0],
+                    ],
+                ],
+                [
+                    [
+                        [84.0, 84.0, 88.0, 88.0],
+                        [84.0, 84.0, 88.0, 88.0],
+                        [84.0, 84.0, 88.0, 88.0],
+                    ],
+                    [
+                        [100.0, 100.0, 104.0, 104.0],
+                        [100.0, 100.0, 104.0, 104.0],
+                        [100.0, 100.0, 104.0, 104.0],
+                    ],
+                    [
+                        [0.0, 0.0, 0.0, 0.0],
+                        [0.0, 0.0, 0.0, 0.0],
+                        [0.0, 0.0, 0.0, 0.0],
+                    ],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[[27.0, 39.0], [27.0, 39.0]], [[99.0, 111.0], [99.0, 111.0]]]],
+                [[[[27.0, 39.0], [27.0, 39.0]], [[99.0, 111.0], [99.0, 111.0]]]],
+                [[
+                    [[243.0, 255.0], [243.0, 255.0]],
+                    [[315.0, 327.0], [315.0, 327.0]],
+                ]],
+                [[
+                    [[243.0, 255.0], [243.0, 255.0]],
+                    [[315.0, 327.0], [315.0, 327.0]],
+                ]],
+            ]),
+            bias: TestTensor::from_floats([8.0, 8.0, 8.0, 8.0]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels: [usize; 2],
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels[0],
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels[1],
+                self.channels[0] / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let weight = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..self.channels[1])
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(self.stride, self.padding, self.dilation, self.groups),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -559,6 +559,53 @@ mod tests {
         test.assert_grads(grads);
     }
 
+    #[test]
+    fn test_conv_transpose2d_groups_padding_out_dilation() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 2,
+            channels: [2, 4],
+            kernel_size: [3, 2],
+            padding: [1, 0],
+            padding_out: [1, 1],
+            stride: [2, 2],
+            dilation: [1, 2],
+            groups: 2,
+            size: [3, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([
+                [
+                    [[52.0, 52.0, 52.0], [66.0, 66.0, 66.0], [66.0, 66.0, 66.0]],
+                    [
+                        [148.0, 148.0, 148.0],
+                        [210.0, 210.0, 210.0],
+                        [210.0, 210.0, 210.0],
+                    ],
+                ],
+                [
+                    [[52.0, 52.0, 52.0], [66.0, 66.0, 66.0], [66.0, 66.0, 66.0]],
+                    [
+                        [148.0, 148.0, 148.0],
+                        [210.0, 210.0, 210.0],
+                        [210.0, 210.0, 210.0],
+                    ],
+                ],
+            ]),
+            weight: TestTensor::from_floats([
+                [
+                    [[174.0, 174.0], [234.0, 234.0], [234.0, 234.0]],
+                    [[174.0, 174.0], [234.0, 234.0], [234.0, 234.0]],
+                ],
+                [
+                    [[282.0, 282.0], [396.0, 396.0], [396.0, 396.0]],
+                    [[282.0, 282.0], [396.0, 396.0], [396.0, 396.0]],
+                ],
+            ]),
+            bias: TestTensor::from_floats([96.0, 96.0, 96.0, 96.0]),
+        };
+        test.assert_grads(grads);
+    }
+
     struct ConvTranspose2dTestCase {
         batch_size: usize,
         channels: [usize; 2],
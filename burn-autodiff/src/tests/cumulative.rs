@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let data_2 = Data::<f32, 2>::from([[1.0, -1.0, 2.0], [0.5, 3.0, -2.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2);
+
+        let tensor_3 = tensor_1.clone().cumsum(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 1.0, 2.0], [1.5, 1.0, -2.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.0], [2.0, 0.5, 4.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[9.0, 4.0, 2.0], [3.5, 10.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let data = Data::<f32, 2>::from([[2.0, 0.0, 3.0], [0.0, 0.0, 4.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 8.0, 0.0], [1.0, 0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_multiple_zeros() {
+        let data_1 = Data::<f32, 2>::from([[3.0, 0.0, 2.0, 0.0, 5.0]]);
+        let data_2 = Data::<f32, 2>::from([[1.0, 2.0, 1.0, 3.0, 2.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2);
+
+        let tensor_3 = tensor_1.clone().cumprod(1).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 12.0, 0.0, 0.0, 0.0]]), 3);
+    }
+}
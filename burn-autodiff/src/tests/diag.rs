@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(ad_diag)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_diagonal() {
+        let tensor = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let weights = TestAutodiffTensor::from_data([2.0, 3.0]);
+
+        let output = tensor.clone().diagonal_dim::<1>(0, 0, 1) * weights;
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        assert_eq!(grad.to_data(), Data::from([[2.0, 0.0], [0.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_diff_diag_embed() {
+        let tensor = TestAutodiffTensor::from_data([1.0, 2.0]).require_grad();
+        let weights =
+            TestAutodiffTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let output = tensor.clone().diag(1) * weights;
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        assert_eq!(grad.to_data(), Data::from([2.0, 6.0]));
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{fft, Data};
+
+    #[test]
+    fn should_diff_rfft() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0, 3.0, 4.0]]).require_grad();
+
+        let (real, imag) = fft::rfft(tensor_1.clone(), 1);
+        let grads = (real + imag).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Sum over the frequencies k of cos(2 pi k j / n) - sin(2 pi k j / n).
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, -1.0, 1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_irfft() {
+        let real = TestAutodiffTensor::from_data([[4.0, 2.0, 0.0]]).require_grad();
+        let imag = TestAutodiffTensor::from_data([[0.0, 1.0, 0.0]]).require_grad();
+
+        let output = fft::irfft(real.clone(), imag.clone(), 1, 4);
+        let grads = output.slice([0..1, 1..2]).sum().backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.25, 0.0, -0.25]]), 3);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, -0.5, 0.0]]), 3);
+    }
+}
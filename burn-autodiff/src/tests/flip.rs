@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(ad_flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_flip() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().flip(&[1]).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 1.0], [4.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_diff_roll() {
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0, 3.0]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([1.0, 2.0, 3.0]);
+
+        let tensor_3 = tensor_1.clone().roll(&[1], &[0]).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([2.0, 3.0, 1.0]));
+    }
+
+    #[test]
+    fn should_diff_rot90() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_3 = tensor_1.clone().rot90(1, [0, 1]).mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 1.0], [4.0, 2.0]]));
+    }
+}
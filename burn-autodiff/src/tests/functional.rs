@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(ad_functional)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{hvp, jacobian};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_compute_jacobian() {
+        let input = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        let output = jacobian(
+            |x: TestAutodiffTensor<1>| {
+                let sum = x.clone().sum();
+                Tensor::cat(vec![x.powf(2.0).slice([0..2]), sum], 0)
+            },
+            input,
+        );
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [1.0, 1.0, 1.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_hessian_vector_product() {
+        let input = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let vector = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.5], [0.0, -1.0]]));
+
+        let output = hvp(|x| x.powf(3.0).sum(), input, vector);
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 6.0], [0.0, -24.0]]), 3);
+    }
+}
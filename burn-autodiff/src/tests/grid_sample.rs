@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_grid_sample_bilinear() {
+        let x = TestAutodiffTensor::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+        let grid = TestAutodiffTensor::from_data([[[[0.0, 0.0]]]]).require_grad();
+        let options = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            true,
+        );
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), options);
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.25, 0.25], [0.25, 0.25]]]]), 3);
+        grid_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.5, 1.0]]]]), 3);
+    }
+}
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::avg_pool2d, Data, Tensor};
+
+    type TestAutodiffBackend2 = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+    #[test]
+    fn should_diff_grad_of_grad() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_inner = TestAutodiffTensor::from_data(data).require_grad();
+        let tensor =
+            Tensor::<TestAutodiffBackend2, 2>::from_inner(tensor_inner.clone()).require_grad();
+
+        let grads = tensor.clone().powf(3.0).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+        let grads = grad.clone().sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[3.0, 12.0], [27.0, 48.0]]), 3);
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 12.0], [18.0, 24.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[-1.0, 0.5], [2.0, 3.0]]);
+
+        let weight_inner = TestAutodiffTensor::from_data(data_2).require_grad();
+        let weight = Tensor::<TestAutodiffBackend2, 2>::from_inner(weight_inner.clone());
+        let input = Tensor::<TestAutodiffBackend2, 2>::from_data(data_1).require_grad();
+
+        let grads = input.clone().mul(weight).sum().backward();
+        let penalty = input.grad(&grads).unwrap().powf(2.0).sum();
+        let grads = penalty.backward();
+        let grad_weight = weight_inner.grad(&grads).unwrap();
+
+        grad_weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[-2.0, 1.0], [4.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_grad_of_grad_avg_pool2d() {
+        let data = Data::<f32, 4>::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let tensor_inner = TestAutodiffTensor::from_data(data).require_grad();
+        let tensor =
+            Tensor::<TestAutodiffBackend2, 4>::from_inner(tensor_inner.clone()).require_grad();
+
+        let output = avg_pool2d(tensor.clone(), [2, 2], [2, 2], [0, 0], true);
+        let grads = output.powf(2.0).sum().backward();
+        let grads = tensor.grad(&grads).unwrap().sum().backward();
+        let grad_grad = tensor_inner.grad(&grads).unwrap();
+
+        grad_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[0.5, 0.5], [0.5, 0.5]]]]), 3);
+    }
+}
@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(ad_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_interpolate_nearest() {
+        let x = TestAutodiffTensor::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+        let options = InterpolateOptions::new(InterpolateMode::Nearest, false);
+
+        let output = interpolate(x.clone(), [3, 3], options);
+        let grads = output.sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[4.0, 2.0], [2.0, 1.0]]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_interpolate_bilinear() {
+        let x = TestAutodiffTensor::from_data([[[[1.0, 2.0], [3.0, 4.0]]]]).require_grad();
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, true);
+
+        let output = interpolate(x.clone(), [3, 3], options);
+        let weights =
+            TestAutodiffTensor::from_data([[[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]]]);
+        let grads = (output * weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        x_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[[1.25, 0.25], [0.25, 0.25]]]]), 3);
+    }
+}
@@ -0,0 +1,120 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_inverse() {
+        let tensor_1 = TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 6.0]]).require_grad();
+
+        let tensor_2 = linalg::inverse(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // -A^-T @ ones @ A^-T
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.04, -0.08], [-0.03, 0.06]]), 3);
+    }
+
+    #[test]
+    fn should_diff_det() {
+        let tensor_1 = TestAutodiffTensor::from_data([[0.0, 1.0], [2.0, 3.0]]).require_grad();
+
+        let tensor_2 = linalg::det(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // det(A) A^-T
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, -2.0], [-1.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let lhs = TestAutodiffTensor::from_data([[3.0, 1.0], [1.0, 2.0]]).require_grad();
+        let rhs = TestAutodiffTensor::from_data([[9.0], [8.0]]).require_grad();
+
+        let output = linalg::solve(lhs.clone(), rhs.clone());
+        let grads = output.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        // grad_rhs = A^-T @ ones, grad_lhs = -grad_rhs @ x^T
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.2], [0.4]]), 3);
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.4, -0.6], [-0.8, -1.2]]), 3);
+    }
+
+    #[test]
+    fn should_diff_triangular_solve() {
+        let lhs = TestAutodiffTensor::from_data([[2.0, 9.0], [1.0, 3.0]]).require_grad();
+        let rhs = TestAutodiffTensor::from_data([[4.0], [11.0]]).require_grad();
+
+        let output = linalg::triangular_solve(lhs.clone(), rhs.clone(), false, false);
+        let grads = output.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        // Same as solve, the upper triangle not being read.
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.3333], [0.3333]]), 3);
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.6667, 0.0], [-0.6667, -1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let tensor_1 = TestAutodiffTensor::from_data([[4.0, 2.0], [2.0, 5.0]]).require_grad();
+
+        let tensor_2 = linalg::cholesky(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Only the lower triangle is used.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.1875, 0.0], [0.25, 0.25]]), 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let tensor_1 = TestAutodiffTensor::from_data([[2.0, 1.0], [1.0, 2.0]]).require_grad();
+
+        let (values, _vectors) = linalg::eigh(tensor_1.clone());
+        let grads = values.slice([0..1, 1..2]).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // v v^T for the eigenvector v of the largest eigenvalue.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [0.5, 0.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_svd() {
+        let tensor_1 = TestAutodiffTensor::from_data([[2.0, 1.0], [1.0, 2.0]]).require_grad();
+
+        let (_u, s, _vh) = linalg::svd(tensor_1.clone());
+        let grads = s.slice([0..1, 0..1]).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // u v^T for the singular vectors of the largest singular value.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [0.5, 0.5]]), 3);
+    }
+}
@@ -51,4 +51,24 @@ mod tests {
             .into_data()
             .assert_approx_eq(&Data::from([[15., 18.], [23., 29.]]), 3);
     }
+
+    #[test]
+    fn should_diff_masked_scatter() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 7.0], [2.0, 3.0]]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([5.0, 6.0, 7.0]).require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_bool(Data::from([
+            [true, false],
+            [false, true],
+        ]));
+
+        let tensor_3 = tensor_1.clone().masked_scatter(mask, tensor_2.clone());
+        let tensor_4 = tensor_3.mul_scalar(3.0).sum();
+        let grads = tensor_4.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[0.0, 3.0], [3.0, 0.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([3.0, 3.0, 0.0]));
+    }
 }
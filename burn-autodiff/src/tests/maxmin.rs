@@ -42,4 +42,15 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[10.0, 8.0], [15.0, 56.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_max_dim_first_dim() {
+        let tensor = TestAutodiffTensor::from_floats([[1.0, 5.0], [3.0, 2.0]]).require_grad();
+
+        let output = tensor.clone().max_dim(0);
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        assert_eq!(grad.to_data(), Data::from([[0.0, 1.0], [1.0, 0.0]]));
+    }
 }
@@ -3,26 +3,41 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
+mod anomaly;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
+mod checkpoint;
 mod complex;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
+mod diag;
 mod div;
 mod erf;
 mod exp;
+mod fft;
+mod flip;
+mod functional;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod higher_order;
+mod interpolate;
+mod linalg;
 mod log;
 mod log1p;
 mod mask;
@@ -33,7 +48,11 @@ mod maxpool2d;
 mod mul;
 mod multithread;
 mod neg;
+mod no_grad;
+mod norm;
+mod pad;
 mod pow;
+mod product;
 mod recip;
 mod relu;
 mod reshape;
@@ -55,6 +74,10 @@ macro_rules! testgen_all {
         // Behavior
         burn_autodiff::testgen_ad_broadcast!();
         burn_autodiff::testgen_gradients!();
+        burn_autodiff::testgen_ad_higher_order!();
+        burn_autodiff::testgen_ad_functional!();
+        burn_autodiff::testgen_ad_no_grad!();
+        burn_autodiff::testgen_ad_anomaly!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -63,15 +86,22 @@ macro_rules! testgen_all {
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv3d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
+        burn_autodiff::testgen_ad_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_attention!();
         burn_autodiff::testgen_module_backward!();
+        burn_autodiff::testgen_ad_checkpoint!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -82,11 +112,16 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
+        burn_autodiff::testgen_ad_diag!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_linalg!();
         burn_autodiff::testgen_ad_select!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
@@ -94,7 +129,10 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_matmul!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_norm!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_product!();
         burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_sin!();
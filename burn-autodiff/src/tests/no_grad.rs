@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_no_grad)]
+mod tests {
+    use super::*;
+    use burn_autodiff::no_grad;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_not_track_operations_in_no_grad_scope() {
+        let data_1 = Data::<f32, 2>::from([[0.0, 1.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, 10.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = no_grad(|| tensor_1.clone().matmul(tensor_2.clone()).exp());
+
+        assert!(!tensor_3.is_require_grad());
+    }
+
+    #[test]
+    fn should_track_operations_after_no_grad_scope() {
+        let data_1 = Data::<f32, 2>::from([[0.0, 1.0], [3.0, 4.0]]);
+        let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, 10.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = no_grad(|| tensor_1.clone().mul(tensor_2.clone()));
+        let tensor_4 = tensor_1.clone().mul(tensor_3);
+        let grads = tensor_4.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert!(tensor_2.grad(&grads).is_none());
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 7.0], [27.0, 40.0]]), 3);
+    }
+}
@@ -0,0 +1,31 @@
+#[burn_tensor_testgen::testgen(ad_norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Norm};
+
+    #[test]
+    fn should_diff_l2_norm() {
+        let tensor = TestAutodiffTensor::from_data([[3.0, 4.0], [0.0, -2.0]]).require_grad();
+
+        let output = tensor.clone().norm(Norm::L2, 1);
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.6, 0.8], [0.0, -1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_normalize_zero_vector() {
+        let tensor = TestAutodiffTensor::from_data([[3.0, 4.0], [0.0, 0.0]]).require_grad();
+
+        let output = tensor.clone().normalize(Norm::L2, 1, 0.5);
+        let grads = (output * TestAutodiffTensor::from_data([[1.0, 0.0], [1.0, 0.0]]))
+            .sum()
+            .backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.128, -0.096], [2.0, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::PadMode, Data};
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad([(1, 1), (0, 1)], PadMode::Constant(5.0));
+        let tensor_3 = tensor_2.clone().mul(tensor_2).sum();
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 4.0], [6.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0, 3.0]).require_grad();
+
+        let tensor_2 = tensor_1.clone().pad([(2, 1)], PadMode::Reflect);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([1.0, 3.0, 2.0]));
+    }
+}
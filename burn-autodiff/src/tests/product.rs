@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(ad_product)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_cross() {
+        let tensor_1 = TestAutodiffTensor::from_data([1.0, 2.0, 3.0]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([4.0, 5.0, 6.0]).require_grad();
+
+        let output = tensor_1.clone().cross(tensor_2.clone(), 0);
+        let grads = output.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([-1.0, 2.0, -1.0]));
+        assert_eq!(grad_2.to_data(), Data::from([1.0, -2.0, 1.0]));
+    }
+
+    #[test]
+    fn should_diff_tensordot() {
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]).require_grad();
+
+        let output: Tensor<TestAutodiffBackend, 2> =
+            tensor_1.clone().tensordot(tensor_2.clone(), &[1], &[0]);
+        let grads = output.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.to_data(),
+            Data::from([[3.0, 7.0, 11.0], [3.0, 7.0, 11.0]])
+        );
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[5.0, 5.0], [7.0, 7.0], [9.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_kron() {
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0, 2.0]]).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[3.0], [4.0]]).require_grad();
+
+        let output = tensor_1.clone().kron(tensor_2.clone());
+        let grads = output.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[7.0, 7.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[3.0], [3.0]]));
+    }
+}
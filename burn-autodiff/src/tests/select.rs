@@ -51,4 +51,50 @@ mod tests {
             Data::from([[64., 64., 64.], [19., 19., 19.]])
         );
     }
+
+    #[test]
+    fn test_select_assign_grad_fewer_values() {
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]))
+                .require_grad();
+        let values = TestAutodiffTensor::from_data(Data::from([[10.0, 20.0]])).require_grad();
+        let weights =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+        let indices = Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([2]));
+
+        let tensor_2 = tensor_1.clone().select_assign(0, indices, values.clone());
+        let tensor_3 = tensor_2.mul(weights).sum();
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+        );
+        assert_eq!(grad_2.into_data(), Data::from([[5.0, 6.0]]));
+    }
+
+    #[test]
+    fn test_index_grad() {
+        let tensor_1 =
+            TestAutodiffTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]))
+                .require_grad();
+        let rows = Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([1, 0, 1]));
+        let columns = Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([2, 0, 2]));
+
+        let tensor_2 = tensor_1.clone().index([rows, columns]);
+        let tensor_3 = tensor_2.mul_scalar(2.0).sum();
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[2.0, 0.0, 0.0], [0.0, 0.0, 4.0]])
+        );
+    }
 }
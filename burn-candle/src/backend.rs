@@ -87,4 +87,20 @@ impl<F: FloatCandleElement, I: IntCandleElement> Backend for Candle<F, I> {
         // TODO submit an issue at Candle
         panic!("Manual seed not supported by Candle. ")
     }
+
+    fn devices() -> Vec<Self::Device> {
+        let mut devices = vec![CandleDevice::Cpu];
+
+        // Candle doesn't expose the number of Cuda devices, the ordinals are probed until the
+        // creation of the device fails.
+        if candle_core::utils::cuda_is_available() {
+            devices.extend(
+                (0..)
+                    .take_while(|ordinal| candle_core::Device::new_cuda(*ordinal).is_ok())
+                    .map(CandleDevice::Cuda),
+            );
+        }
+
+        devices
+    }
 }
@@ -47,6 +47,7 @@ mod tests {
     // burn_tensor::testgen_module_avg_pool2d!();
     // burn_tensor::testgen_module_adaptive_avg_pool1d!();
     // burn_tensor::testgen_module_adaptive_avg_pool2d!();
+    burn_tensor::testgen_module_adaptive_max_pool2d!();
 
     // test ops
     burn_tensor::testgen_add!();
@@ -110,6 +111,7 @@ mod tests {
     // burn_autodiff::testgen_ad_avg_pool2d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
     // burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+    burn_autodiff::testgen_ad_adaptive_max_pool2d!();
     burn_autodiff::testgen_module_backward!();
 
     // Tensor
@@ -23,3 +23,20 @@ pub(crate) fn fill_like<E: CandleElement, const D: usize>(
         reference_tensor.device(),
     )
 }
+
+/// Applies a reduction in f32 when the tensor has a half precision type, avoiding overflows and
+/// the precision loss of 16 bits accumulators.
+pub(crate) fn reduce_upcasted<R>(tensor: &Tensor, reduce: R) -> Tensor
+where
+    R: FnOnce(&Tensor) -> candle_core::Result<Tensor>,
+{
+    let dtype = tensor.dtype();
+
+    match dtype {
+        DType::F16 | DType::BF16 => reduce(&tensor.to_dtype(DType::F32).unwrap())
+            .unwrap()
+            .to_dtype(dtype)
+            .unwrap(),
+        _ => reduce(tensor).unwrap(),
+    }
+}
@@ -2,6 +2,8 @@ mod activation;
 mod base;
 mod bool_tensor;
 mod candle_utils;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
@@ -83,20 +83,17 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvTransposeOptions<1>,
     ) -> FloatTensor<Self, 3> {
-        assert!(
-            options.groups == 1,
-            "Candle does not support groups in transposed convolutions"
-        );
-        let conv_transpose = x
-            .tensor
-            .conv_transpose1d(
-                &weight.tensor,
-                options.padding[0],
-                options.padding_out[0],
-                options.stride[0],
-                options.dilation[0],
-            )
-            .unwrap();
+        let conv_transpose =
+            conv_transpose_groups(&x.tensor, &weight.tensor, options.groups, |x, weight| {
+                x.conv_transpose1d(
+                    weight,
+                    options.padding[0],
+                    options.padding_out[0],
+                    options.stride[0],
+                    options.dilation[0],
+                )
+                .unwrap()
+            });
         CandleTensor::new(match bias {
             Some(bias) => conv_transpose
                 .broadcast_add(&bias.tensor.unsqueeze(0).unwrap().unsqueeze(2).unwrap())
@@ -118,20 +115,17 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
                 && options.stride[0] == options.stride[1],
             "Candle does not support per dimension options in transposed convolutions"
         );
-        assert!(
-            options.groups == 1,
-            "Candle does not support groups in transposed convolutions"
-        );
-        let conv_transpose = x
-            .tensor
-            .conv_transpose2d(
-                &weight.tensor,
-                options.padding[0],
-                options.padding_out[0],
-                options.stride[0],
-                options.dilation[0],
-            )
-            .unwrap();
+        let conv_transpose =
+            conv_transpose_groups(&x.tensor, &weight.tensor, options.groups, |x, weight| {
+                x.conv_transpose2d(
+                    weight,
+                    options.padding[0],
+                    options.padding_out[0],
+                    options.stride[0],
+                    options.dilation[0],
+                )
+                .unwrap()
+            });
         CandleTensor::new(match bias {
             Some(bias) => conv_transpose
                 .broadcast_add(
@@ -239,4 +233,130 @@ impl<F: FloatCandleElement, I: IntCandleElement> ModuleOps<Self> for Candle<F, I
     ) -> FloatTensor<Self, 4> {
         panic!("adaptive_avg_pool2d_backward is not supported by Candle")
     }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Candle<F, I>> {
+        let (batch_size, channels, height, width) = x.tensor.dims4().unwrap();
+        let [output_height, output_width] = output_size;
+
+        // The flattened index of each input position, gathered with the argmax of the windows.
+        let positions =
+            candle_core::Tensor::arange(0u32, (height * width) as u32, x.tensor.device())
+                .unwrap()
+                .reshape((height, width))
+                .unwrap();
+
+        let mut outputs = Vec::with_capacity(output_height * output_width);
+        let mut indices = Vec::with_capacity(output_height * output_width);
+
+        for oh in 0..output_height {
+            let ih_start = start_index(oh, output_height, height);
+            let ih_end = end_index(oh, output_height, height);
+
+            for ow in 0..output_width {
+                let iw_start = start_index(ow, output_width, width);
+                let iw_end = end_index(ow, output_width, width);
+
+                let window = x
+                    .tensor
+                    .narrow(2, ih_start, ih_end - ih_start)
+                    .unwrap()
+                    .narrow(3, iw_start, iw_end - iw_start)
+                    .unwrap()
+                    .flatten_from(2)
+                    .unwrap();
+                let window_positions = positions
+                    .narrow(0, ih_start, ih_end - ih_start)
+                    .unwrap()
+                    .narrow(1, iw_start, iw_end - iw_start)
+                    .unwrap()
+                    .flatten_all()
+                    .unwrap()
+                    .broadcast_as(window.dims())
+                    .unwrap()
+                    .contiguous()
+                    .unwrap();
+
+                let argmax = window.argmax_keepdim(2).unwrap();
+                outputs.push(window.max_keepdim(2).unwrap());
+                indices.push(window_positions.gather(&argmax, 2).unwrap());
+            }
+        }
+
+        let shape = (batch_size, channels, output_height, output_width);
+        let output = candle_core::Tensor::cat(&outputs, 2)
+            .unwrap()
+            .reshape(shape)
+            .unwrap();
+        let indices = candle_core::Tensor::cat(&indices, 2)
+            .unwrap()
+            .reshape(shape)
+            .unwrap()
+            .to_dtype(I::DTYPE)
+            .unwrap();
+
+        MaxPool2dWithIndices::new(CandleTensor::new(output), CandleTensor::new(indices))
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Candle<F, I>> {
+        let (batch_size, channels, height, width) = x.tensor.dims4().unwrap();
+
+        // The gradient of each output is added to the input position selected by its index.
+        let output_grad = output_grad.tensor.flatten_from(2).unwrap();
+        let indices = indices.tensor.flatten_from(2).unwrap();
+        let x_grad = candle_core::Tensor::zeros(
+            (batch_size, channels, height * width),
+            F::DTYPE,
+            x.tensor.device(),
+        )
+        .unwrap()
+        .scatter_add(&indices, &output_grad, 2)
+        .unwrap()
+        .reshape((batch_size, channels, height, width))
+        .unwrap();
+
+        MaxPool2dBackward::new(CandleTensor::new(x_grad))
+    }
+}
+
+fn start_index(output_index: usize, output_size: usize, input_size: usize) -> usize {
+    (output_index * input_size) / output_size
+}
+
+fn end_index(output_index: usize, output_size: usize, input_size: usize) -> usize {
+    let index = ((output_index + 1) * input_size + output_size - 1) / output_size;
+
+    usize::min(index, input_size)
+}
+
+/// Candle doesn't support groups in transposed convolutions, so each group is computed separately
+/// and the outputs are concatenated along the channel dimension.
+fn conv_transpose_groups<F>(
+    x: &candle_core::Tensor,
+    weight: &candle_core::Tensor,
+    groups: usize,
+    conv_transpose: F,
+) -> candle_core::Tensor
+where
+    F: Fn(&candle_core::Tensor, &candle_core::Tensor) -> candle_core::Tensor,
+{
+    if groups == 1 {
+        return conv_transpose(x, weight);
+    }
+
+    let x = x.chunk(groups, 1).unwrap();
+    let weight = weight.chunk(groups, 0).unwrap();
+    let outputs = x
+        .iter()
+        .zip(weight.iter())
+        .map(|(x, weight)| conv_transpose(x, weight))
+        .collect::<Vec<_>>();
+
+    candle_core::Tensor::cat(&outputs, 1).unwrap()
 }
@@ -332,16 +332,24 @@ impl<F: FloatCandleElement, I: IntCandleElement> TensorOps<Self> for Candle<F, I
     }
 
     fn sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
-        let sum = tensor.tensor.sum_all().unwrap().to_scalar::<F>().unwrap();
+        let sum = super::candle_utils::reduce_upcasted(&tensor.tensor, |tensor| tensor.sum_all())
+            .to_scalar::<F>()
+            .unwrap();
         CandleTensor::from_data(Data::new([sum].into(), [1].into()), Self::device(&tensor))
     }
 
     fn sum_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
-        CandleTensor::new(tensor.tensor.sum_keepdim(dim).unwrap())
+        CandleTensor::new(super::candle_utils::reduce_upcasted(
+            &tensor.tensor,
+            |tensor| tensor.sum_keepdim(dim),
+        ))
     }
 
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
-        CandleTensor::new(tensor.tensor.mean_keepdim(dim).unwrap())
+        CandleTensor::new(super::candle_utils::reduce_upcasted(
+            &tensor.tensor,
+            |tensor| tensor.mean_keepdim(dim),
+        ))
     }
 
     fn to_full_precision<const D: usize>(
@@ -43,3 +43,100 @@ where
     }
     rng.as_mut().unwrap().gen()
 }
+
+/// Random number generators of a backend, one per device.
+///
+/// Each device owns its generator, so the random stream of a device doesn't depend on the random
+/// operations executed on the other devices. Generators are created lazily on first use, either
+/// from the seed given to [seed_all](DeviceGenerators::seed_all) or from entropy when the backend
+/// was never seeded.
+#[derive(Debug)]
+pub struct DeviceGenerators<D> {
+    seed: Option<u64>,
+    generators: alloc::vec::Vec<(D, StdRng)>,
+}
+
+impl<D: PartialEq + Clone> DeviceGenerators<D> {
+    /// Creates the generators of a backend that was never seeded.
+    pub const fn new() -> Self {
+        Self {
+            seed: None,
+            generators: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Seeds the generators of all devices, including the ones not used yet.
+    pub fn seed_all(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.generators.clear();
+    }
+
+    /// Seeds the generator of the given device, the other devices keep their current state.
+    pub fn seed_device(&mut self, device: &D, seed: u64) {
+        *self.get(device) = StdRng::seed_from_u64(seed);
+    }
+
+    /// Returns the generator of the given device.
+    pub fn get(&mut self, device: &D) -> &mut StdRng {
+        let index = match self.generators.iter().position(|(d, _)| d == device) {
+            Some(index) => index,
+            None => {
+                let rng = match self.seed {
+                    Some(seed) => StdRng::seed_from_u64(seed),
+                    None => get_seeded_rng(),
+                };
+                self.generators.push((device.clone(), rng));
+                self.generators.len() - 1
+            }
+        };
+
+        &mut self.generators[index].1
+    }
+}
+
+impl<D: PartialEq + Clone> Default for DeviceGenerators<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(generators: &mut DeviceGenerators<usize>, device: usize) -> [u64; 4] {
+        let rng = generators.get(&device);
+        [rng.gen(), rng.gen(), rng.gen(), rng.gen()]
+    }
+
+    #[test]
+    fn seed_all_should_reset_every_device() {
+        let mut generators = DeviceGenerators::new();
+        generators.seed_all(42);
+        let first = (stream(&mut generators, 0), stream(&mut generators, 1));
+
+        generators.seed_all(42);
+        let second = (stream(&mut generators, 1), stream(&mut generators, 0));
+
+        assert_eq!(first.0, second.1);
+        assert_eq!(first.1, second.0);
+        assert_eq!(first.0, first.1);
+    }
+
+    #[test]
+    fn seed_device_should_not_affect_other_devices() {
+        let mut generators = DeviceGenerators::new();
+        generators.seed_all(42);
+        let expected = stream(&mut generators, 1);
+
+        generators.seed_all(42);
+        generators.seed_device(&0, 7);
+        let seeded = stream(&mut generators, 0);
+        let other = stream(&mut generators, 1);
+        generators.seed_device(&0, 7);
+
+        assert_eq!(other, expected);
+        assert_eq!(seeded, stream(&mut generators, 0));
+        assert_ne!(seeded, expected);
+    }
+}
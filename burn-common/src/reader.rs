@@ -87,6 +87,17 @@ impl<T> Reader<T> {
         }
     }
 
+    /// Read the data asynchronously on every target.
+    ///
+    /// On targets where readers are sync, the data is read when the returned future is polled.
+    pub async fn read_async(self) -> T {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        return self.read().await;
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        self.read()
+    }
+
     /// Read the data only if sync, returns None if an async reader.
     pub fn read_sync(self) -> Option<T> {
         match self {
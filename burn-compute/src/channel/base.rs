@@ -1,4 +1,7 @@
-use crate::server::{ComputeServer, Handle};
+use crate::{
+    memory_management::MemoryUsage,
+    server::{ComputeServer, Handle},
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 
@@ -19,4 +22,7 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug {
 
     /// Wait for the completion of every task in the server.
     fn sync(&self);
+
+    /// Returns the memory usage of the server.
+    fn memory_usage(&self) -> MemoryUsage;
 }
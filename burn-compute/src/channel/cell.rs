@@ -1,4 +1,5 @@
 use super::ComputeChannel;
+use crate::memory_management::MemoryUsage;
 use crate::server::{ComputeServer, Handle};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
@@ -63,4 +64,8 @@ where
     fn sync(&self) {
         self.server.borrow_mut().sync()
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.borrow().memory_usage()
+    }
 }
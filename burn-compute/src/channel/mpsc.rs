@@ -6,6 +6,7 @@ use std::{
 use burn_common::reader::Reader;
 
 use super::ComputeChannel;
+use crate::memory_management::MemoryUsage;
 use crate::server::{ComputeServer, Handle};
 
 /// Create a channel using the [multi-producer, single-consumer channel](mpsc) to communicate with
@@ -38,6 +39,7 @@ where
     Empty(usize, Callback<Handle<Server>>),
     ExecuteKernel(Server::Kernel, Vec<Handle<Server>>),
     Sync(Callback<()>),
+    MemoryUsage(Callback<MemoryUsage>),
 }
 
 impl<Server> MpscComputeChannel<Server>
@@ -71,6 +73,9 @@ where
                         server.sync();
                         callback.send(()).unwrap();
                     }
+                    Message::MemoryUsage(callback) => {
+                        callback.send(server.memory_usage()).unwrap();
+                    }
                 };
             }
         });
@@ -146,6 +151,17 @@ where
 
         self.response(response)
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::MemoryUsage(callback))
+            .unwrap();
+
+        self.response(response)
+    }
 }
 
 impl<Server: ComputeServer> MpscComputeChannel<Server> {
@@ -1,4 +1,5 @@
 use super::ComputeChannel;
+use crate::memory_management::MemoryUsage;
 use crate::server::{ComputeServer, Handle};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
@@ -54,4 +55,8 @@ where
     fn sync(&self) {
         self.server.lock().sync()
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.server.lock().memory_usage()
+    }
 }
@@ -1,4 +1,6 @@
 use crate::storage::ComputeStorage;
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 /// The MemoryHandle trait is an abstract way to refer to some memory segment.
 /// It should not contain actual references to data.
@@ -10,6 +12,84 @@ pub trait MemoryHandle: Clone + Send + core::fmt::Debug {
     fn can_mut(&self) -> bool;
 }
 
+/// The memory usage of a [memory management](MemoryManagement) strategy, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The memory of the resources in use.
+    pub bytes_in_use: usize,
+    /// The peak memory of the resources in use.
+    pub peak_bytes_in_use: usize,
+    /// The memory allocated in the storage, including the memory kept for reuse.
+    pub bytes_reserved: usize,
+    /// The peak memory allocated in the storage.
+    pub peak_bytes_reserved: usize,
+}
+
+/// Running counters of the memory usage of a [memory management](MemoryManagement) strategy.
+///
+/// The memory reserved is counted when it is allocated or deallocated in the storage, while the
+/// memory in use is counted until the last clone of the handle it was reserved for is dropped.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryUsageCounters {
+    bytes_in_use: Arc<AtomicUsize>,
+    bytes_reserved: usize,
+    peak_bytes_in_use: usize,
+    peak_bytes_reserved: usize,
+}
+
+/// Counts the memory of a handle as in use until the handle and all its clones are dropped.
+#[derive(Debug, Clone)]
+pub struct MemoryInUse {
+    _count: Arc<InUseCount>,
+}
+
+#[derive(Debug)]
+struct InUseCount {
+    size: usize,
+    bytes_in_use: Arc<AtomicUsize>,
+}
+
+impl Drop for InUseCount {
+    fn drop(&mut self) {
+        self.bytes_in_use.fetch_sub(self.size, Ordering::Relaxed);
+    }
+}
+
+impl MemoryUsageCounters {
+    /// Counts memory allocated in the storage.
+    pub(crate) fn allocated(&mut self, size: usize) {
+        self.bytes_reserved += size;
+        self.peak_bytes_reserved = usize::max(self.peak_bytes_reserved, self.bytes_reserved);
+    }
+
+    /// Counts memory deallocated from the storage.
+    pub(crate) fn deallocated(&mut self, size: usize) {
+        self.bytes_reserved -= size;
+    }
+
+    /// Counts memory in use until the returned value and all its clones are dropped.
+    pub(crate) fn in_use(&mut self, size: usize) -> MemoryInUse {
+        let bytes_in_use = self.bytes_in_use.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes_in_use = usize::max(self.peak_bytes_in_use, bytes_in_use);
+
+        MemoryInUse {
+            _count: Arc::new(InUseCount {
+                size,
+                bytes_in_use: self.bytes_in_use.clone(),
+            }),
+        }
+    }
+
+    pub(crate) fn usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            bytes_in_use: self.bytes_in_use.load(Ordering::Relaxed),
+            peak_bytes_in_use: self.peak_bytes_in_use,
+            bytes_reserved: self.bytes_reserved,
+            peak_bytes_reserved: self.peak_bytes_reserved,
+        }
+    }
+}
+
 /// The MemoryManagement trait encapsulates strategies for (de)allocating memory.
 /// It is bound to the ComputeStorage trait, which does the actual (de)allocations.
 ///
@@ -50,4 +130,7 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// This is useful if you need to time the deallocations based on async computation, or to
     /// change the mode of storage for different reasons.
     fn storage(&mut self) -> &mut Storage;
+
+    /// Returns the current and peak memory usage.
+    fn memory_usage(&self) -> MemoryUsage;
 }
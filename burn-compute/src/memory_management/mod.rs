@@ -1,5 +1,7 @@
 mod base;
+mod pool;
 mod simple;
 
 pub use base::*;
+pub use pool::*;
 pub use simple::*;
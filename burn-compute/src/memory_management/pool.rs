@@ -0,0 +1,335 @@
+use super::{
+    DeallocStrategy, MemoryHandle, MemoryInUse, MemoryManagement, MemoryUsage, MemoryUsageCounters,
+};
+use crate::{
+    memory_id_type,
+    storage::{ComputeStorage, StorageHandle, StorageUtilization},
+};
+use alloc::{sync::Arc, vec::Vec};
+use hashbrown::HashMap;
+
+// The BufferId allows to keep track of how many references there are to a specific buffer.
+memory_id_type!(BufferId);
+
+impl BufferId {
+    /// A buffer is free if it is only referred by the buffer hashmap and its bucket, or the list
+    /// of the buffers allocated manually.
+    fn is_free(&self) -> bool {
+        Arc::strong_count(&self.id) <= 2
+    }
+}
+
+/// The smallest bucket, in bytes.
+const MIN_BUCKET_SIZE: usize = 256;
+/// The number of buckets between two powers of two, bounding the unused memory of a buffer to
+/// 1/8 of its size.
+const BUCKETS_PER_POWER_OF_TWO: usize = 8;
+
+/// The PoolHandle is a memory handle, referring to a buffer of the pool.
+#[derive(Debug, Clone)]
+pub struct PoolHandle {
+    id: BufferId,
+    size: usize,
+    _in_use: MemoryInUse,
+}
+
+impl MemoryHandle for PoolHandle {
+    /// Returns true if referenced by only one tensor, and only once by the
+    /// memory management hashmap and bucket.
+    fn can_mut(&self) -> bool {
+        // One reference in the buffer hashmap, one in the bucket or the list of the buffers
+        // allocated manually, and another owned by one tensor.
+        const REFERENCE_LIMIT: usize = 3;
+
+        Arc::strong_count(&self.id.id) <= REFERENCE_LIMIT
+    }
+}
+
+struct Buffer {
+    resource: StorageHandle,
+    /// Whether the buffer was reserved since the last cleanup.
+    reused: bool,
+}
+
+/// Reserves the memory in buffers of a few fixed sizes, the buckets, which are recycled for the
+/// reservations of the same bucket once they are no longer used.
+///
+/// Contrary to the [simple memory management](super::SimpleMemoryManagement), which deallocates
+/// all the free chunks, the cleanup only deallocates the buffers that weren't reused since the
+/// previous cleanup, so the buffers of a training step are reused by the next one.
+pub struct PoolMemoryManagement<Storage> {
+    buffers: HashMap<BufferId, Buffer>,
+    buckets: HashMap<usize, Vec<BufferId>>,
+    manual: Vec<BufferId>,
+    dealloc_strategy: DeallocStrategy,
+    usage: MemoryUsageCounters,
+    storage: Storage,
+}
+
+impl<Storage> core::fmt::Debug for PoolMemoryManagement<Storage> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            alloc::format!(
+                "PoolMemoryManagement {:?} - {:?}",
+                self.dealloc_strategy,
+                core::any::type_name::<Storage>(),
+            )
+            .as_str(),
+        )
+    }
+}
+
+impl<Storage: ComputeStorage> MemoryManagement<Storage> for PoolMemoryManagement<Storage> {
+    type Handle = PoolHandle;
+
+    /// Returns the resource from the storage, for the specified handle.
+    fn get(&mut self, handle: &Self::Handle) -> Storage::Resource {
+        let buffer = self.buffers.get(&handle.id).unwrap();
+
+        // The buffer can be larger than the reserved memory, which is the part used by the handle.
+        // Slices can't be empty, so the empty reservations use the whole buffer.
+        let size = buffer.resource.size();
+        let utilization = match handle.size == size || handle.size == 0 {
+            true => StorageUtilization::Full(size),
+            false => StorageUtilization::Slice(0, handle.size),
+        };
+
+        self.storage
+            .get(&StorageHandle::new(buffer.resource.id.clone(), utilization))
+    }
+
+    /// Reserves memory of specified size in a free buffer of its bucket, allocating a new one
+    /// if none is available.
+    ///
+    /// Also deallocates the buffers that weren't reused if permitted by the deallocation strategy.
+    fn reserve(&mut self, size: usize) -> Self::Handle {
+        if self.dealloc_strategy.should_dealloc() {
+            self.cleanup_buffers();
+        }
+
+        let bucket_size = bucket_size(size);
+        let free = self
+            .buckets
+            .get(&bucket_size)
+            .and_then(|bucket| bucket.iter().find(|id| id.is_free()))
+            .cloned();
+
+        let id = match free {
+            Some(id) => {
+                self.buffers.get_mut(&id).unwrap().reused = true;
+                id
+            }
+            None => {
+                let id = self.create_buffer(bucket_size);
+                self.buckets
+                    .entry(bucket_size)
+                    .or_default()
+                    .push(id.clone());
+                id
+            }
+        };
+
+        self.handle(id, size, bucket_size)
+    }
+
+    /// Allocates a buffer of exactly the given size, which isn't recycled by the pool.
+    fn alloc(&mut self, size: usize) -> Self::Handle {
+        let id = self.create_buffer(size);
+        self.manual.push(id.clone());
+
+        self.handle(id, size, size)
+    }
+
+    fn dealloc(&mut self, handle: &Self::Handle) {
+        if let Some(buffer) = self.buffers.remove(&handle.id) {
+            for bucket in self.buckets.values_mut() {
+                bucket.retain(|id| id != &handle.id);
+            }
+            self.manual.retain(|id| id != &handle.id);
+            self.usage.deallocated(buffer.resource.size());
+            self.storage.dealloc(buffer.resource.id);
+        }
+    }
+
+    fn storage(&mut self) -> &mut Storage {
+        &mut self.storage
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.usage.usage()
+    }
+}
+
+impl<Storage: ComputeStorage> PoolMemoryManagement<Storage> {
+    /// Creates a new instance using the given storage and deallocation strategy.
+    pub fn new(storage: Storage, dealloc_strategy: DeallocStrategy) -> Self {
+        Self {
+            buffers: HashMap::new(),
+            buckets: HashMap::new(),
+            manual: Vec::new(),
+            dealloc_strategy,
+            usage: MemoryUsageCounters::default(),
+            storage,
+        }
+    }
+
+    fn create_buffer(&mut self, size: usize) -> BufferId {
+        let resource = self.storage.alloc(size);
+        let id = BufferId::new();
+        self.usage.allocated(size);
+
+        self.buffers.insert(
+            id.clone(),
+            Buffer {
+                resource,
+                reused: true,
+            },
+        );
+
+        id
+    }
+
+    /// Deallocates the free buffers that weren't reused since the previous cleanup.
+    fn cleanup_buffers(&mut self) {
+        let mut ids_to_remove = Vec::new();
+
+        for bucket in self.buckets.values_mut() {
+            bucket.retain(|id| {
+                let buffer = self.buffers.get_mut(id).unwrap();
+                let remove = id.is_free() && !buffer.reused;
+                buffer.reused = false;
+
+                if remove {
+                    ids_to_remove.push(id.clone());
+                }
+                !remove
+            });
+        }
+
+        ids_to_remove
+            .iter()
+            .map(|id| self.buffers.remove(id).unwrap())
+            .for_each(|buffer| {
+                self.usage.deallocated(buffer.resource.size());
+                self.storage.dealloc(buffer.resource.id);
+            });
+    }
+
+    /// Creates the handle of `size` bytes reserved in a buffer of `buffer_size` bytes, whose
+    /// memory is in use until the handle is dropped.
+    fn handle(&mut self, id: BufferId, size: usize, buffer_size: usize) -> PoolHandle {
+        PoolHandle {
+            id,
+            size,
+            _in_use: self.usage.in_use(buffer_size),
+        }
+    }
+}
+
+/// The size of the buffers used to reserve `size` bytes.
+///
+/// The sizes between two powers of two are split in a few buckets, so the buffers are reused for
+/// tensors of similar sizes while wasting a bounded fraction of the memory.
+fn bucket_size(size: usize) -> usize {
+    if size <= MIN_BUCKET_SIZE {
+        return MIN_BUCKET_SIZE;
+    }
+
+    let step = size.next_power_of_two() / BUCKETS_PER_POWER_OF_TWO;
+    (size + step - 1) / step * step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::BytesStorage;
+
+    fn pool() -> PoolMemoryManagement<BytesStorage> {
+        PoolMemoryManagement::new(BytesStorage::default(), DeallocStrategy::Never)
+    }
+
+    #[test]
+    fn bucket_size_should_bound_the_unused_memory() {
+        assert_eq!(bucket_size(1), 256);
+        assert_eq!(bucket_size(256), 256);
+        assert_eq!(bucket_size(257), 320);
+        assert_eq!(bucket_size(1000), 1024);
+        assert_eq!(bucket_size(1025), 1280);
+
+        for size in (1..100_000).step_by(37) {
+            let bucket = bucket_size(size);
+            assert!(bucket >= size);
+            assert!(size <= MIN_BUCKET_SIZE || bucket - size <= size / 4);
+        }
+    }
+
+    #[test]
+    fn free_buffer_should_be_reused_for_the_same_bucket() {
+        let mut pool = pool();
+
+        drop(pool.reserve(1000));
+        let _handle = pool.reserve(1010);
+
+        assert_eq!(pool.buffers.len(), 1);
+        assert_eq!(pool.buckets[&1024].len(), 1);
+    }
+
+    #[test]
+    fn used_buffer_should_not_be_reused() {
+        let mut pool = pool();
+
+        let _handle_1 = pool.reserve(1000);
+        let _handle_2 = pool.reserve(1000);
+
+        assert_eq!(pool.buffers.len(), 2);
+    }
+
+    #[test]
+    fn cleanup_should_dealloc_the_buffers_not_reused() {
+        let mut pool = pool();
+
+        drop(pool.reserve(1000));
+        drop(pool.reserve(5000));
+        pool.cleanup_buffers();
+        assert_eq!(pool.buffers.len(), 2);
+
+        drop(pool.reserve(1000));
+        pool.cleanup_buffers();
+        assert_eq!(pool.buffers.len(), 1);
+    }
+
+    #[test]
+    fn memory_usage_should_track_the_peaks() {
+        let mut pool = pool();
+
+        let handle_1 = pool.reserve(1024);
+        let handle_2 = pool.reserve(1024);
+        drop(handle_1);
+        let usage = pool.memory_usage();
+
+        assert_eq!(usage.bytes_in_use, 1024);
+        assert_eq!(usage.peak_bytes_in_use, 2048);
+        assert_eq!(usage.bytes_reserved, 2048);
+        assert_eq!(usage.peak_bytes_reserved, 2048);
+        assert!(handle_2.can_mut());
+    }
+
+    #[test]
+    fn memory_usage_should_count_the_deallocated_buffers() {
+        let mut pool = pool();
+
+        drop(pool.reserve(1000));
+        let handle = pool.alloc(100);
+        pool.cleanup_buffers();
+        pool.cleanup_buffers();
+        pool.dealloc(&handle);
+        let usage = pool.memory_usage();
+
+        assert_eq!(usage.bytes_in_use, 100);
+        assert_eq!(usage.bytes_reserved, 0);
+        assert_eq!(usage.peak_bytes_reserved, 1124);
+
+        drop(handle);
+        assert_eq!(pool.memory_usage().bytes_in_use, 0);
+    }
+}
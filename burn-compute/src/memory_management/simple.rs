@@ -1,4 +1,4 @@
-use super::{MemoryHandle, MemoryManagement};
+use super::{MemoryHandle, MemoryInUse, MemoryManagement, MemoryUsage, MemoryUsageCounters};
 use crate::{
     memory_id_type,
     storage::{ComputeStorage, StorageHandle, StorageUtilization},
@@ -25,13 +25,14 @@ impl SliceId {
     }
 }
 
-/// The SimpleHandle is a memory handle, referring to either a chunk or a slice.
+/// The SimpleHandle is a memory handle, referring to either a chunk or a slice, whose memory is
+/// counted as in use until it is dropped.
 #[derive(Debug, Clone)]
 pub enum SimpleHandle {
     /// A whole chunk of memory.
-    Chunk(ChunkId),
+    Chunk(ChunkId, MemoryInUse),
     /// A slice of a chunk of memory.
-    Slice(SliceId),
+    Slice(SliceId, MemoryInUse),
 }
 
 /// The strategy defines the frequency at which deallocation of unused memory chunks should occur.
@@ -91,7 +92,7 @@ impl DeallocStrategy {
         DeallocStrategy::PeriodTick { period, state: 0 }
     }
 
-    fn should_dealloc(&mut self) -> bool {
+    pub(crate) fn should_dealloc(&mut self) -> bool {
         match self {
             DeallocStrategy::PeriodTick { period, state } => {
                 *state = (*state + 1) % *period;
@@ -117,6 +118,7 @@ pub struct SimpleMemoryManagement<Storage> {
     slices: HashMap<SliceId, (StorageHandle, ChunkId)>,
     dealloc_strategy: DeallocStrategy,
     slice_strategy: SliceStrategy,
+    usage: MemoryUsageCounters,
     storage: Storage,
 }
 
@@ -144,8 +146,8 @@ impl MemoryHandle for SimpleHandle {
         const REFERENCE_LIMIT_SLICE: usize = 3;
 
         match &self {
-            SimpleHandle::Chunk(id) => Arc::strong_count(&id.id) <= REFERENCE_LIMIT_CHUNK,
-            SimpleHandle::Slice(id) => Arc::strong_count(&id.id) <= REFERENCE_LIMIT_SLICE,
+            SimpleHandle::Chunk(id, _) => Arc::strong_count(&id.id) <= REFERENCE_LIMIT_CHUNK,
+            SimpleHandle::Slice(id, _) => Arc::strong_count(&id.id) <= REFERENCE_LIMIT_SLICE,
         }
     }
 }
@@ -156,8 +158,8 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     /// Returns the resource from the storage, for the specified handle.
     fn get(&mut self, handle: &Self::Handle) -> Storage::Resource {
         let resource = match &handle {
-            SimpleHandle::Chunk(id) => &self.chunks.get(id).unwrap().0,
-            SimpleHandle::Slice(id) => &self.slices.get(id).unwrap().0,
+            SimpleHandle::Chunk(id, _) => &self.chunks.get(id).unwrap().0,
+            SimpleHandle::Slice(id, _) => &self.slices.get(id).unwrap().0,
         };
 
         self.storage.get(resource)
@@ -185,18 +187,23 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
 
     fn dealloc(&mut self, handle: &Self::Handle) {
         match handle {
-            SimpleHandle::Chunk(id) => {
+            SimpleHandle::Chunk(id, _) => {
                 if let Some((handle, _slices)) = self.chunks.remove(id) {
+                    self.usage.deallocated(handle.size());
                     self.storage.dealloc(handle.id);
                 }
             }
-            SimpleHandle::Slice(_) => panic!("Can't dealloc slice manually"),
+            SimpleHandle::Slice(..) => panic!("Can't dealloc slice manually"),
         }
     }
 
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.usage.usage()
+    }
 }
 
 impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
@@ -211,6 +218,7 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
             slices: HashMap::new(),
             dealloc_strategy,
             slice_strategy,
+            usage: MemoryUsageCounters::default(),
             storage,
         }
     }
@@ -223,7 +231,7 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
             Some((chunk_id, chunk_size)) => {
                 if size == chunk_size {
                     // If there is one of exactly the same size, it reuses it.
-                    SimpleHandle::Chunk(chunk_id.clone())
+                    SimpleHandle::Chunk(chunk_id, self.usage.in_use(size))
                 } else {
                     // Otherwise creates a slice of the right size upon it, always starting at zero.
                     self.create_slice(size, chunk_id)
@@ -289,17 +297,18 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
 
         slices.push(slice_id.clone());
 
-        SimpleHandle::Slice(slice_id)
+        SimpleHandle::Slice(slice_id, self.usage.in_use(size))
     }
 
     /// Creates a chunk of given size by allocating on the storage.
     fn create_chunk(&mut self, size: usize) -> SimpleHandle {
         let resource = self.storage.alloc(size);
         let chunk_id = ChunkId::new();
+        self.usage.allocated(size);
 
         self.chunks.insert(chunk_id.clone(), (resource, Vec::new()));
 
-        SimpleHandle::Chunk(chunk_id)
+        SimpleHandle::Chunk(chunk_id, self.usage.in_use(size))
     }
 
     /// Deallocates free chunks and remove them from chunks map.
@@ -316,6 +325,7 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
             .iter()
             .map(|chunk_id| self.chunks.remove(chunk_id).unwrap())
             .for_each(|(resource, _slices)| {
+                self.usage.deallocated(resource.size());
                 self.storage.dealloc(resource.id);
             });
     }
@@ -415,6 +425,24 @@ mod tests {
         assert_eq!(memory_management.chunks.len(), 0);
     }
 
+    #[test]
+    fn memory_usage_should_count_the_chunks_and_slices_in_use() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Ratio(0.5),
+        );
+        let chunk_handle = memory_management.reserve(100);
+        drop(chunk_handle);
+        let slice_handle = memory_management.reserve(80);
+        let usage = memory_management.memory_usage();
+
+        assert_eq!(usage.bytes_in_use, 80);
+        assert_eq!(usage.peak_bytes_in_use, 100);
+        assert_eq!(usage.bytes_reserved, 100);
+        assert!(slice_handle.can_mut());
+    }
+
     #[test]
     fn never_dealloc_strategy_never_deallocs() {
         let mut never_dealloc = DeallocStrategy::Never;
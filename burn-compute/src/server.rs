@@ -1,7 +1,7 @@
 use core::fmt::Debug;
 
 use crate::{
-    memory_management::{MemoryHandle, MemoryManagement},
+    memory_management::{MemoryHandle, MemoryManagement, MemoryUsage},
     storage::ComputeStorage,
     tune::AutotuneKey,
 };
@@ -42,6 +42,9 @@ where
 
     /// Wait for the completion of every task in the server.
     fn sync(&mut self);
+
+    /// Returns the memory usage of the server.
+    fn memory_usage(&self) -> MemoryUsage;
 }
 
 /// Server handle containing the [memory handle](MemoryManagement::Handle).
@@ -4,10 +4,19 @@ use super::AutotuneOperationSet;
 use alloc::boxed::Box;
 use hashbrown::HashMap;
 
+#[cfg(feature = "autotune-persistent-cache")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "autotune-persistent-cache")]
+use alloc::vec::Vec;
+
 /// Use to find and reuse the best kernel for some input
 #[derive(Debug, Default)]
 pub(crate) struct TuneCache<K> {
-    cache: HashMap<K, usize>,
+    in_memory_cache: HashMap<K, usize>,
+    #[cfg(feature = "autotune-persistent-cache")]
+    persistent_cache: HashMap<String, usize>,
+    #[cfg(feature = "autotune-persistent-cache")]
+    device_id: String,
 }
 
 /// Result of the cache try
@@ -19,25 +28,127 @@ pub enum TuneCacheResult<K> {
 }
 
 impl<K: AutotuneKey> TuneCache<K> {
-    pub(crate) fn new() -> Self {
-        TuneCache {
-            cache: HashMap::new(),
+    pub(crate) fn new(
+        #[cfg_attr(not(feature = "autotune-persistent-cache"), allow(unused_variables))]
+        device_id: &str,
+    ) -> Self {
+        #[cfg(feature = "autotune-persistent-cache")]
+        {
+            let mut cache = TuneCache {
+                in_memory_cache: HashMap::new(),
+                persistent_cache: HashMap::new(),
+                device_id: device_id.to_string(),
+            };
+            if let Err(err) = cache.load() {
+                log::warn!("Unable to load the autotune cache, it will be recreated: {err}");
+            }
+            cache
+        }
+
+        #[cfg(not(feature = "autotune-persistent-cache"))]
+        {
+            TuneCache {
+                in_memory_cache: HashMap::new(),
+            }
         }
     }
 
     #[allow(clippy::borrowed_box)]
     pub(crate) fn try_cache(
-        &self,
+        &mut self,
         autotune_operation_set: Box<dyn AutotuneOperationSet<K>>,
     ) -> TuneCacheResult<K> {
-        let index = self.cache.get(&autotune_operation_set.key());
-        if let Some(&i) = index {
-            return TuneCacheResult::Hit(autotune_operation_set.fastest(i));
+        let key = autotune_operation_set.key();
+        if let Some(&index) = self.in_memory_cache.get(&key) {
+            return TuneCacheResult::Hit(autotune_operation_set.fastest(index));
         }
+
+        #[cfg(feature = "autotune-persistent-cache")]
+        if let Some(&index) = self.persistent_cache.get(&key.to_string()) {
+            self.in_memory_cache.insert(key, index);
+            return TuneCacheResult::Hit(autotune_operation_set.fastest(index));
+        }
+
         TuneCacheResult::Miss(autotune_operation_set)
     }
 
     pub(crate) fn cache_insert(&mut self, key: K, fastest_index: usize) {
-        self.cache.insert(key, fastest_index);
+        #[cfg(feature = "autotune-persistent-cache")]
+        {
+            self.persistent_cache.insert(key.to_string(), fastest_index);
+            if let Err(err) = self.save() {
+                log::warn!("Unable to save the autotune cache: {err}");
+            }
+        }
+
+        self.in_memory_cache.insert(key, fastest_index);
+    }
+}
+
+#[cfg(feature = "autotune-persistent-cache")]
+impl<K> TuneCache<K> {
+    /// Loads the fastest indices of the previous runs on the same device.
+    ///
+    /// Each line of the file is the fastest index followed by the key.
+    fn load(&mut self) -> std::io::Result<()> {
+        let Some(path) = get_persistent_cache_file_path(&self.device_id) else {
+            return Ok(());
+        };
+        if !path.exists() {
+            return Ok(());
+        }
+
+        for line in std::fs::read_to_string(path)?.lines() {
+            let Some((index, key)) = line.split_once(' ') else {
+                continue;
+            };
+            if let Ok(index) = index.parse() {
+                self.persistent_cache.insert(key.to_string(), index);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn save(&self) -> std::io::Result<()> {
+        let Some(path) = get_persistent_cache_file_path(&self.device_id) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut lines = self
+            .persistent_cache
+            .iter()
+            .map(|(key, index)| alloc::format!("{index} {key}\n"))
+            .collect::<Vec<_>>();
+        lines.sort();
+
+        std::fs::write(path, lines.concat())
     }
 }
+
+/// The file of the autotune cache of a device, if the platform has a cache directory.
+///
+/// The fastest kernels are stored per version, since the kernels and their order change between
+/// versions.
+#[cfg(feature = "autotune-persistent-cache")]
+pub fn get_persistent_cache_file_path(device_id: &str) -> Option<std::path::PathBuf> {
+    // The device id is the name of the file, so it must not contain a path separator.
+    let file_name = device_id
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            true => c,
+            false => '_',
+        })
+        .collect::<String>();
+
+    let path = dirs::cache_dir()?
+        .join("burn")
+        .join("autotune")
+        .join(env!("CARGO_PKG_VERSION"))
+        .join(alloc::format!("{file_name}.txt"));
+
+    Some(path)
+}
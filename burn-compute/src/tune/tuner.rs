@@ -19,10 +19,14 @@ pub struct Tuner<S: ComputeServer, C> {
 }
 
 impl<S: ComputeServer, C: ComputeChannel<S>> Tuner<S, C> {
-    /// Returns a tuner with empty cache
-    pub fn new() -> Self {
+    /// Returns a tuner with the cache of the given device.
+    ///
+    /// With the `autotune-persistent-cache` feature, the fastest operations found on previous
+    /// runs of the same device are loaded from disk, so the device id should be unique per
+    /// device model.
+    pub fn new(device_id: &str) -> Self {
         Self {
-            tune_cache: TuneCache::new(),
+            tune_cache: TuneCache::new(device_id),
             _channel: PhantomData,
         }
     }
@@ -19,14 +19,17 @@ pub type DummyClient = ComputeClient<DummyServer, DummyChannel>;
 static COMPUTE: Compute<DummyDevice, DummyServer, DummyChannel> = Compute::new();
 
 pub fn client(device: &DummyDevice) -> DummyClient {
-    COMPUTE.client(device, || {
-        let storage = BytesStorage::default();
-        let memory_management =
-            SimpleMemoryManagement::new(storage, DeallocStrategy::Never, SliceStrategy::Never);
-        let server = DummyServer::new(memory_management);
-        let channel = MutexComputeChannel::new(server);
-        let tuner = Arc::new(Mutex::new(Tuner::new()));
+    COMPUTE.client(device, || create_client("dummy-device"))
+}
+
+/// Creates a client that isn't shared, with the autotune cache of the given device id.
+pub fn create_client(device_id: &str) -> DummyClient {
+    let storage = BytesStorage::default();
+    let memory_management =
+        SimpleMemoryManagement::new(storage, DeallocStrategy::Never, SliceStrategy::Never);
+    let server = DummyServer::new(memory_management);
+    let channel = MutexComputeChannel::new(server);
+    let tuner = Arc::new(Mutex::new(Tuner::new(device_id)));
 
-        ComputeClient::new(channel, tuner)
-    })
+    ComputeClient::new(channel, tuner)
 }
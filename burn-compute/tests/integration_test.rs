@@ -27,6 +27,21 @@ fn empty_allocates_memory() {
     assert_eq!(empty_resource.read().len(), 4);
 }
 
+#[test]
+fn memory_usage_counts_the_live_resources() {
+    let client = dummy::create_client("dummy-memory-usage");
+    let resource = client.empty(100);
+    let other = client.empty(100);
+    core::mem::drop(other);
+
+    let usage = client.memory_usage();
+
+    assert_eq!(usage.bytes_in_use, 100);
+    assert_eq!(usage.peak_bytes_in_use, 200);
+    assert_eq!(usage.bytes_reserved, 200);
+    core::mem::drop(resource);
+}
+
 #[test]
 fn execute_elementwise_addition() {
     let client = client(&DummyDevice);
@@ -146,3 +161,40 @@ fn autotune_cache_miss_test() {
     // Cache should be missed, so CacheTestSlowOn3 (but faster on 5) should be used, returning rhs
     assert_eq!(obtained_resource.read(), Vec::from([5, 6, 7, 8, 9]));
 }
+
+#[test]
+#[serial]
+#[cfg(feature = "autotune-persistent-cache")]
+fn autotune_persistent_cache_hit_test() {
+    let device_id = "dummy-persistent-cache-test";
+    if let Some(path) = burn_compute::tune::get_persistent_cache_file_path(device_id) {
+        let _ = std::fs::remove_file(path);
+    }
+
+    // The first client autotunes on 3 elements and stores the result on disk.
+    let client = dummy::create_client(device_id);
+    let shapes_1 = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs_1 = client.create(&[0, 1, 2]);
+    let rhs_1 = client.create(&[4, 4, 4]);
+    let out_1 = client.empty(3);
+    let handles_1 = vec![lhs_1, rhs_1, out_1];
+    let cache_test_autotune_kernel_1 =
+        dummy::CacheTestAutotuneOperationSet::new(client.clone(), shapes_1, handles_1);
+    client.execute_autotune(Box::new(cache_test_autotune_kernel_1));
+
+    // A new client of the same device, with an empty in-memory cache.
+    let client = dummy::create_client(device_id);
+    let shapes_2 = vec![vec![1, 4], vec![1, 4], vec![1, 4]];
+    let lhs_2 = client.create(&[0, 1, 2, 3]);
+    let rhs_2 = client.create(&[5, 6, 7, 8]);
+    let out_2 = client.empty(4);
+    let handles_2 = vec![lhs_2, rhs_2, out_2.clone()];
+    let cache_test_autotune_kernel_2 =
+        dummy::CacheTestAutotuneOperationSet::new(client.clone(), shapes_2, handles_2);
+    client.execute_autotune(Box::new(cache_test_autotune_kernel_2));
+
+    let obtained_resource = client.read(&out_2);
+
+    // The persistent cache should be hit, so CacheTestFastOn3 should be used, returning lhs
+    assert_eq!(obtained_resource.read(), Vec::from([0, 1, 2, 3]));
+}
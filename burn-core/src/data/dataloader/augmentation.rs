@@ -0,0 +1,244 @@
+use crate::tensor::{backend::Backend, Data, Int, Shape, Tensor};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rand_distr::{Beta, Distribution};
+use std::sync::Mutex;
+
+/// Mixes the items of a batch with the items of a shuffled copy of the batch, as described in
+/// [mixup: Beyond Empirical Risk Minimization](https://arxiv.org/abs/1710.09412).
+///
+/// The inputs and the targets are interpolated with the same weight, sampled from a
+/// `Beta(alpha, alpha)` distribution for each batch. The targets should be probabilities, e.g.
+/// one-hot encoded labels. It is applied by the [batcher](super::batcher::Batcher), so it runs
+/// in the workers of the data loaders.
+///
+/// # Example
+///
+/// ```ignore
+/// impl<B: Backend> Batcher<ImageItem, ImageBatch<B>> for ImageBatcher<B> {
+///     fn batch(&self, items: Vec<ImageItem>) -> ImageBatch<B> {
+///         let (images, targets) = self.stack(items);
+///         let (images, targets) = self.mixup.apply(images, targets);
+///
+///         ImageBatch { images, targets }
+///     }
+/// }
+/// ```
+pub struct MixUp {
+    distribution: Beta<f64>,
+    rng: Mutex<StdRng>,
+}
+
+impl MixUp {
+    /// Creates a mixup whose weights are sampled from `Beta(alpha, alpha)`.
+    ///
+    /// # Panics
+    ///
+    /// When alpha isn't positive.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            distribution: beta(alpha),
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Sets the seed of the mixup, which is different for each run otherwise.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Mixes the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - inputs: `[batch_size, ...]`
+    /// - targets: `[batch_size, num_classes]`
+    pub fn apply<B: Backend, const D: usize>(
+        &self,
+        inputs: Tensor<B, D>,
+        targets: Tensor<B, 2>,
+    ) -> (Tensor<B, D>, Tensor<B, 2>) {
+        let [batch_size, _] = targets.dims();
+        let mut rng = self.rng.lock().unwrap();
+        let lambda = self.distribution.sample(&mut *rng);
+        let indices = permutation::<B>(batch_size, &inputs.device(), &mut rng);
+
+        let inputs = mix(inputs, indices.clone(), lambda);
+        let targets = mix(targets, indices, lambda);
+
+        (inputs, targets)
+    }
+}
+
+/// Replaces a random box of the images of a batch with the same box of the images of a shuffled
+/// copy of the batch, as described in
+/// [CutMix: Regularization Strategy to Train Strong Classifiers with Localizable Features](https://arxiv.org/abs/1905.04899).
+///
+/// The area of the box is sampled from a `Beta(alpha, alpha)` distribution for each batch, and
+/// the targets are interpolated with the proportion of the images that is kept. Like the
+/// [mixup](MixUp), it is applied by the [batcher](super::batcher::Batcher).
+pub struct CutMix {
+    distribution: Beta<f64>,
+    rng: Mutex<StdRng>,
+}
+
+impl CutMix {
+    /// Creates a cutmix whose proportions are sampled from `Beta(alpha, alpha)`.
+    ///
+    /// # Panics
+    ///
+    /// When alpha isn't positive.
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            distribution: beta(alpha),
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Sets the seed of the cutmix, which is different for each run otherwise.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Mixes the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - images: `[batch_size, channels, height, width]`
+    /// - targets: `[batch_size, num_classes]`
+    pub fn apply<B: Backend>(
+        &self,
+        images: Tensor<B, 4>,
+        targets: Tensor<B, 2>,
+    ) -> (Tensor<B, 4>, Tensor<B, 2>) {
+        let [batch_size, channels, height, width] = images.dims();
+        let mut rng = self.rng.lock().unwrap();
+        let lambda = self.distribution.sample(&mut *rng);
+        let indices = permutation::<B>(batch_size, &images.device(), &mut rng);
+
+        // The box covers a proportion `1 - lambda` of the image, centered anywhere in the image.
+        let ratio = f64::sqrt(1.0 - lambda);
+        let (y, x) = (rng.gen_range(0..height), rng.gen_range(0..width));
+        let [y_start, y_end] = clamped_box(y, (height as f64 * ratio) as usize, height);
+        let [x_start, x_end] = clamped_box(x, (width as f64 * ratio) as usize, width);
+
+        let area = (y_end - y_start) * (x_end - x_start);
+        if area == 0 {
+            return (images, targets);
+        }
+
+        let ranges = [0..batch_size, 0..channels, y_start..y_end, x_start..x_end];
+        let patches = images
+            .clone()
+            .select(0, indices.clone())
+            .slice(ranges.clone());
+        let images = images.slice_assign(ranges, patches);
+
+        // The proportion of the image that is kept, once the box is clamped.
+        let lambda = 1.0 - area as f64 / (height * width) as f64;
+        let targets = mix(targets, indices, lambda);
+
+        (images, targets)
+    }
+}
+
+fn beta(alpha: f64) -> Beta<f64> {
+    Beta::new(alpha, alpha).expect("The alpha parameter should be positive.")
+}
+
+/// A random permutation of the indices of the batch.
+fn permutation<B: Backend>(
+    batch_size: usize,
+    device: &B::Device,
+    rng: &mut StdRng,
+) -> Tensor<B, 1, Int> {
+    let mut indices = (0..batch_size as i64).collect::<Vec<_>>();
+    indices.shuffle(rng);
+
+    Tensor::from_data_device(
+        Data::new(indices, Shape::new([batch_size])).convert(),
+        device,
+    )
+}
+
+/// Interpolates the tensor with its items in the order of the indices.
+fn mix<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    indices: Tensor<B, 1, Int>,
+    lambda: f64,
+) -> Tensor<B, D> {
+    let shuffled = tensor.clone().select(0, indices);
+
+    tensor.mul_scalar(lambda) + shuffled.mul_scalar(1.0 - lambda)
+}
+
+/// The start and the end of a box of the given size and center, clamped to the dimension.
+fn clamped_box(center: usize, size: usize, dim: usize) -> [usize; 2] {
+    let start = center.saturating_sub(size / 2);
+    let end = usize::min(center + size / 2, dim);
+
+    [start, end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn batch() -> (Tensor<TestBackend, 4>, Tensor<TestBackend, 2>) {
+        // The images of each item are filled with the index of the item.
+        let images = Tensor::<TestBackend, 1, Int>::arange(0..4)
+            .float()
+            .reshape([4, 1, 1, 1])
+            .repeat(1, 3)
+            .repeat(2, 8)
+            .repeat(3, 8);
+        let targets = Tensor::<TestBackend, 1, Int>::arange(0..4)
+            .float()
+            .reshape([4, 1])
+            .repeat(1, 2);
+
+        (images, targets)
+    }
+
+    #[test]
+    fn mixup_should_interpolate_inputs_and_targets_with_the_same_weight() {
+        let (images, targets) = batch();
+
+        let (images, targets) = MixUp::new(0.4).with_seed(42).apply(images, targets);
+
+        // Each pixel and each target equals the mix of the item indices.
+        let expected = targets.clone().slice([0..4, 0..1]).reshape([4, 1, 1, 1]);
+        images
+            .sub(expected)
+            .abs()
+            .max()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 5);
+        targets
+            .sum()
+            .into_data()
+            .assert_approx_eq(&Data::from([12.0]), 4);
+    }
+
+    #[test]
+    fn cutmix_should_mix_targets_with_the_area_of_the_box() {
+        let (images, targets) = batch();
+
+        let (images, targets) = CutMix::new(1.0).with_seed(42).apply(images, targets);
+
+        // The mean of each image is the mix of the item indices.
+        let means = images.mean_dim(3).mean_dim(2).mean_dim(1).reshape([4, 1]);
+        means
+            .sub(targets.clone().slice([0..4, 0..1]))
+            .abs()
+            .max()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 5);
+        targets
+            .sum()
+            .into_data()
+            .assert_approx_eq(&Data::from([12.0]), 4);
+    }
+}
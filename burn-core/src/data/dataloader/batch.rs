@@ -1,6 +1,6 @@
 use super::{
     batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, MultiThreadDataLoader,
-    Progress,
+    Progress, SampledDataset, Sampler,
 };
 use burn_dataset::{
     transform::{PartialDataset, ShuffledDataset},
@@ -15,6 +15,14 @@ pub struct BatchDataLoader<I, O> {
     dataset: Arc<dyn Dataset<I>>,
     batcher: Arc<dyn Batcher<I, O>>,
     rng: Option<spin::Mutex<rand::rngs::StdRng>>,
+    sampler: Option<WorkerSampler>,
+}
+
+/// The sampler of a data loader, with the part of the sampled items loaded by the worker.
+struct WorkerSampler {
+    sampler: Arc<dyn Sampler>,
+    worker: usize,
+    num_workers: usize,
 }
 
 impl<I, O> BatchDataLoader<I, O> {
@@ -42,6 +50,52 @@ impl<I, O> BatchDataLoader<I, O> {
             dataset,
             batcher,
             rng: rng.map(spin::Mutex::new),
+            sampler: None,
+        }
+    }
+
+    /// Creates a new batch data loader loading the items chosen by a sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    /// * `dataset` - The dataset.
+    /// * `batcher` - The batcher.
+    /// * `sampler` - The sampler choosing the items of each iteration.
+    /// * `rng`     - The rng of the sampler, advanced each time a dataloader iterator is created.
+    ///
+    /// # Returns
+    ///
+    /// The batch data loader.
+    pub fn sampled(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        sampler: Arc<dyn Sampler>,
+        rng: rand::rngs::StdRng,
+    ) -> Self {
+        Self::sampled_worker(strategy, dataset, batcher, sampler, rng, 0, 1)
+    }
+
+    fn sampled_worker(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        sampler: Arc<dyn Sampler>,
+        rng: rand::rngs::StdRng,
+        worker: usize,
+        num_workers: usize,
+    ) -> Self {
+        Self {
+            strategy,
+            dataset,
+            batcher,
+            rng: Some(spin::Mutex::new(rng)),
+            sampler: Some(WorkerSampler {
+                sampler,
+                worker,
+                num_workers,
+            }),
         }
     }
 }
@@ -98,15 +152,72 @@ where
         }
         MultiThreadDataLoader::new(dataloaders)
     }
+
+    /// Creates a new multi-threaded batch data loader loading the items chosen by a sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    /// * `dataset` - The dataset.
+    /// * `batcher` - The batcher.
+    /// * `sampler` - The sampler choosing the items of each iteration.
+    /// * `num_threads` - The number of threads.
+    /// * `rng` - The rng of the sampler.
+    ///
+    /// # Returns
+    ///
+    /// The multi-threaded batch data loader.
+    pub fn multi_thread_sampled(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        sampler: Arc<dyn Sampler>,
+        num_threads: usize,
+        rng: rand::rngs::StdRng,
+    ) -> MultiThreadDataLoader<O> {
+        // Every worker samples the same items with a copy of the rng, then loads its own part.
+        let dataloaders = (0..num_threads)
+            .map(|worker| {
+                let dataloader = BatchDataLoader::sampled_worker(
+                    strategy.new_like(),
+                    dataset.clone(),
+                    batcher.clone(),
+                    sampler.clone(),
+                    rng.clone(),
+                    worker,
+                    num_threads,
+                );
+                Arc::new(dataloader) as Arc<dyn DataLoader<_> + Send + Sync>
+            })
+            .collect();
+
+        MultiThreadDataLoader::new(dataloaders)
+    }
 }
 
 impl<I: Send + Sync + Clone + 'static, O: Send + Sync> DataLoader<O> for BatchDataLoader<I, O> {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
         // When starting a new iteration, we first check if the dataloader was created with an rng,
         // implying that we should shuffle the dataset beforehand, while advancing the current
-        // rng to ensure that each new iteration shuffles the dataset differently.
-        let dataset = match &self.rng {
-            Some(rng) => {
+        // rng to ensure that each new iteration shuffles the dataset differently. With a sampler,
+        // the rng samples the items of the iteration instead.
+        let dataset: Arc<dyn Dataset<I>> = match (&self.sampler, &self.rng) {
+            (Some(sampler), Some(rng)) => {
+                let indices = sampler.sampler.indices(self.dataset.len(), &mut rng.lock());
+                // The last worker also loads the remaining items, as when splitting a dataset.
+                let size = indices.len() / sampler.num_workers;
+                let start = sampler.worker * size;
+                let end = match sampler.worker == sampler.num_workers - 1 {
+                    true => indices.len(),
+                    false => start + size,
+                };
+
+                Arc::new(SampledDataset::new(
+                    self.dataset.clone(),
+                    indices[start..end].to_vec(),
+                ))
+            }
+            (None, Some(rng)) => {
                 let mut rng = rng.lock();
 
                 Arc::new(ShuffledDataset::with_seed(
@@ -114,7 +225,7 @@ impl<I: Send + Sync + Clone + 'static, O: Send + Sync> DataLoader<O> for BatchDa
                     rng.sample(Standard),
                 ))
             }
-            None => self.dataset.clone(),
+            (_, None) => self.dataset.clone(),
         };
         Box::new(BatchDataloaderIterator::new(
             self.strategy.new_like(),
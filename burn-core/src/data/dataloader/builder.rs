@@ -1,14 +1,28 @@
-use super::{batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
-use burn_dataset::Dataset;
+use super::{
+    batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy,
+    IterableBatchDataLoader, MultiThreadDataLoader, PermutationSampler, PrefetchDataLoader,
+    Sampler, ShardedSampler, StageFn,
+};
+use burn_dataset::{transform::ShuffleBufferDataset, Dataset, IterableDataset};
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
 
+/// The number of items of the buffer shuffling an [iterable dataset](IterableDataset).
+const SHUFFLE_BUFFER_SIZE: usize = 1000;
+
+/// The number of batches prepared in advance by a [stage](DataLoaderBuilder::stage).
+const STAGE_PREFETCH: usize = 2;
+
 /// A builder for data loaders.
 pub struct DataLoaderBuilder<I, O> {
     strategy: Option<Box<dyn BatchStrategy<I>>>,
     batcher: Arc<dyn Batcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    sampler: Option<Arc<dyn Sampler>>,
+    shard: Option<(usize, usize)>,
+    prefetch: Option<usize>,
+    stage: Option<StageFn<O>>,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -34,6 +48,10 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            sampler: None,
+            shard: None,
+            prefetch: None,
+            stage: None,
         }
     }
 
@@ -52,9 +70,29 @@ where
         self
     }
 
+    /// Sets the strategy to batch the items, e.g. a
+    /// [bucket batch strategy](super::BucketBatchStrategy) for variable-length items.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn batch_strategy<S>(mut self, strategy: S) -> Self
+    where
+        S: BatchStrategy<I> + 'static,
+    {
+        self.strategy = Some(Box::new(strategy));
+        self
+    }
+
     /// Sets the seed for shuffling.
     ///
-    /// Each time the dataloader starts a new iteration, the dataset will be shuffled.
+    /// Each time the dataloader starts a new iteration, the dataset will be shuffled. An
+    /// [iterable dataset](IterableDataset) is shuffled with a buffer of 1000 items, wrap it in a
+    /// [shuffle buffer dataset](ShuffleBufferDataset) instead to use another size.
     ///
     /// # Arguments
     ///
@@ -68,6 +106,55 @@ where
         self
     }
 
+    /// Sets the sampler choosing the items of each iteration, e.g. a
+    /// [weighted random sampler](super::WeightedRandomSampler) to balance the classes.
+    ///
+    /// The sampler replaces the shuffling, and uses the [seed](Self::shuffle) when it is set to be
+    /// reproducible. It isn't used by the data loaders of [iterable datasets](IterableDataset),
+    /// whose items can't be accessed by index.
+    ///
+    /// # Arguments
+    ///
+    /// * `sampler` - The sampler.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn sampler<S>(mut self, sampler: S) -> Self
+    where
+        S: Sampler + 'static,
+    {
+        self.sampler = Some(Arc::new(sampler));
+        self
+    }
+
+    /// Loads the shard of the given rank among `world_size` disjoint shards of the dataset, e.g.
+    /// the items of a process of a distributed training.
+    ///
+    /// Each iteration shuffles or [samples](Self::sampler) the items of the whole dataset, then
+    /// keeps the items of the shard, like a
+    /// [sharded dataset](burn_dataset::transform::ShardedDataset). The data loaders of all the
+    /// ranks should use the same [seed](Self::shuffle), so they choose the same items and the
+    /// shards stay disjoint at each iteration. It isn't used by the data loaders of
+    /// [iterable datasets](IterableDataset).
+    ///
+    /// # Arguments
+    ///
+    /// * `world_size` - The number of shards.
+    /// * `rank` - The rank of the shard, smaller than the number of shards.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn shard(mut self, world_size: usize, rank: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be smaller than the number of shards {world_size}."
+        );
+        self.shard = Some((world_size, rank));
+        self
+    }
+
     /// Sets the number of workers.
     ///
     /// # Arguments
@@ -82,6 +169,53 @@ where
         self
     }
 
+    /// Sets the number of batches prepared in advance.
+    ///
+    /// The workers of a multi-threaded data loader prepare 100 batches in advance by default,
+    /// while the other data loaders prepare the batches when they are requested, unless a
+    /// prefetch is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_batches` - The number of batches.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn prefetch(mut self, num_batches: usize) -> Self {
+        self.prefetch = Some(num_batches);
+        self
+    }
+
+    /// Sets a function applied to each batch on a background thread before it is returned,
+    /// usually to move the batch to the device of the model while the previous batches are
+    /// processed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let dataloader = DataLoaderBuilder::new(batcher)
+    ///     .batch_size(64)
+    ///     .num_workers(4)
+    ///     .stage(move |batch: MnistBatch<B>| batch.to_device(&device))
+    ///     .build(dataset);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `stage` - The function.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn stage<F>(mut self, stage: F) -> Self
+    where
+        F: Fn(O) -> O + Send + Sync + 'static,
+    {
+        self.stage = Some(Arc::new(stage));
+        self
+    }
+
     /// Builds the data loader.
     ///
     /// # Arguments
@@ -102,16 +236,107 @@ where
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
-        if let Some(num_threads) = self.num_threads {
-            return Arc::new(BatchDataLoader::multi_thread(
+        let sampler = match (self.sampler, self.shard) {
+            (sampler, Some((world_size, rank))) => {
+                let sampler = sampler
+                    .unwrap_or_else(|| Arc::new(PermutationSampler::new(self.shuffle.is_some())));
+                Some(Arc::new(ShardedSampler::new(sampler, world_size, rank)) as Arc<dyn Sampler>)
+            }
+            (sampler, None) => sampler,
+        };
+
+        let dataloader: Arc<dyn DataLoader<O> + Send + Sync> = match (sampler, self.num_threads) {
+            (Some(sampler), Some(num_threads)) => Arc::new(with_prefetch(
+                BatchDataLoader::multi_thread_sampled(
+                    strategy,
+                    dataset,
+                    self.batcher,
+                    sampler,
+                    num_threads,
+                    rng.unwrap_or_else(StdRng::from_entropy),
+                ),
+                self.prefetch,
+            )),
+            (Some(sampler), None) => Arc::new(BatchDataLoader::sampled(
                 strategy,
                 dataset,
                 self.batcher,
-                num_threads,
-                rng,
-            ));
-        }
+                sampler,
+                rng.unwrap_or_else(StdRng::from_entropy),
+            )),
+            (None, Some(num_threads)) => Arc::new(with_prefetch(
+                BatchDataLoader::multi_thread(strategy, dataset, self.batcher, num_threads, rng),
+                self.prefetch,
+            )),
+            (None, None) => Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng)),
+        };
+
+        // The workers of a multi-threaded data loader already prefetch the batches.
+        let prefetch = match self.num_threads {
+            Some(_) => None,
+            None => self.prefetch,
+        };
+        prefetched(dataloader, prefetch, self.stage)
+    }
+
+    /// Builds the data loader of an [iterable dataset](IterableDataset).
+    ///
+    /// The items are loaded on the thread iterating over the data loader, since an iterable
+    /// dataset can't be split between multiple workers.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The iterable dataset.
+    ///
+    /// # Returns
+    ///
+    /// The data loader.
+    pub fn build_iterable<D>(self, dataset: D) -> Arc<dyn DataLoader<O>>
+    where
+        D: IterableDataset<I> + 'static,
+    {
+        let dataset: Arc<dyn IterableDataset<I>> = match self.shuffle {
+            Some(seed) => Arc::new(ShuffleBufferDataset::with_seed(
+                dataset,
+                SHUFFLE_BUFFER_SIZE,
+                seed,
+            )),
+            None => Arc::new(dataset),
+        };
+        let strategy = match self.strategy {
+            Some(strategy) => strategy,
+            None => Box::new(FixBatchStrategy::new(1)),
+        };
+
+        let dataloader = IterableBatchDataLoader::new(strategy, dataset, self.batcher);
+
+        prefetched(Arc::new(dataloader), self.prefetch, self.stage)
+    }
+}
+
+fn with_prefetch<O>(
+    dataloader: MultiThreadDataLoader<O>,
+    prefetch: Option<usize>,
+) -> MultiThreadDataLoader<O> {
+    match prefetch {
+        Some(num_batches) => dataloader.with_prefetch(num_batches),
+        None => dataloader,
+    }
+}
+
+/// Prepares the batches on a background thread when a prefetch or a stage is set.
+fn prefetched<O: Send + 'static>(
+    dataloader: Arc<dyn DataLoader<O> + Send + Sync>,
+    prefetch: Option<usize>,
+    stage: Option<StageFn<O>>,
+) -> Arc<dyn DataLoader<O>> {
+    if prefetch.is_none() && stage.is_none() {
+        return dataloader;
+    }
 
-        Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
+    let dataloader = PrefetchDataLoader::new(dataloader, prefetch.unwrap_or(STAGE_PREFETCH));
+    match stage {
+        Some(stage) => Arc::new(dataloader.with_stage(stage)),
+        None => Arc::new(dataloader),
     }
 }
@@ -0,0 +1,140 @@
+use super::{batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, Progress};
+use burn_dataset::IterableDataset;
+use std::iter::Peekable;
+use std::sync::Arc;
+
+/// A data loader that can be used to iterate over an [iterable dataset](IterableDataset) in
+/// batches.
+pub struct IterableBatchDataLoader<I, O> {
+    strategy: Box<dyn BatchStrategy<I>>,
+    dataset: Arc<dyn IterableDataset<I>>,
+    batcher: Arc<dyn Batcher<I, O>>,
+}
+
+impl<I, O> IterableBatchDataLoader<I, O> {
+    /// Creates a new iterable batch data loader.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    /// * `dataset` - The iterable dataset.
+    /// * `batcher` - The batcher.
+    ///
+    /// # Returns
+    ///
+    /// The iterable batch data loader.
+    pub fn new(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn IterableDataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+    ) -> Self {
+        Self {
+            strategy,
+            dataset,
+            batcher,
+        }
+    }
+}
+
+/// A data loader iterator over the items of an iterable dataset.
+struct IterableBatchDataLoaderIterator<'a, I, O> {
+    items: Peekable<Box<dyn Iterator<Item = I> + 'a>>,
+    items_processed: usize,
+    size_hint: Option<usize>,
+    exhausted: bool,
+    strategy: Box<dyn BatchStrategy<I>>,
+    batcher: Arc<dyn Batcher<I, O>>,
+}
+
+impl<I, O> DataLoader<O> for IterableBatchDataLoader<I, O> {
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        Box::new(IterableBatchDataLoaderIterator {
+            items: self.dataset.iter().peekable(),
+            items_processed: 0,
+            size_hint: self.dataset.size_hint(),
+            exhausted: false,
+            strategy: self.strategy.new_like(),
+            batcher: self.batcher.clone(),
+        })
+    }
+}
+
+impl<'a, I, O> Iterator for IterableBatchDataLoaderIterator<'a, I, O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        let mut batch = None;
+
+        for item in self.items.by_ref() {
+            self.items_processed += 1;
+            self.strategy.add(item);
+
+            if let Some(items) = self.strategy.batch(false) {
+                batch = Some(items);
+                break;
+            }
+        }
+
+        // Looking at the next item tells if the last batch is reached, the number of items being
+        // unknown.
+        self.exhausted = self.items.peek().is_none();
+
+        if batch.is_none() {
+            batch = self.strategy.batch(true);
+        }
+
+        batch.map(|items| self.batcher.batch(items))
+    }
+}
+
+impl<'a, I, O> DataLoaderIterator<O> for IterableBatchDataLoaderIterator<'a, I, O> {
+    fn progress(&self) -> Progress {
+        // While items remain, at least one more item is expected.
+        let items_total = match (self.exhausted, self.size_hint) {
+            (true, _) => self.items_processed,
+            (false, Some(size)) => usize::max(size, self.items_processed + 1),
+            (false, None) => self.items_processed + 1,
+        };
+
+        Progress {
+            items_processed: self.items_processed,
+            items_total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::FixBatchStrategy;
+    use crate::data::dataset::IterFnDataset;
+
+    #[test]
+    fn test_iterable_batch_dataloader() {
+        let dataset = IterFnDataset::new(|| (0..12).map(|i| i.to_string()));
+        let dataloader = IterableBatchDataLoader::<String, Vec<String>>::new(
+            Box::new(FixBatchStrategy::new(5)),
+            Arc::new(dataset),
+            Arc::new(TestBatcher::new()),
+        );
+
+        let mut iterator = dataloader.iter();
+        let mut batches = Vec::new();
+        let mut progresses = Vec::new();
+
+        while let Some(batch) = iterator.next() {
+            batches.push(batch);
+            let progress = iterator.progress();
+            progresses.push((progress.items_processed, progress.items_total));
+        }
+
+        let items = batches.iter().flatten().cloned().collect::<Vec<_>>();
+        assert_eq!(
+            batches.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![5, 5, 2]
+        );
+        assert_eq!(items, (0..12).map(|i| i.to_string()).collect::<Vec<_>>());
+        assert_eq!(progresses, vec![(5, 6), (10, 11), (12, 12)]);
+    }
+}
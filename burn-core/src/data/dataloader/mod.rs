@@ -1,14 +1,24 @@
+mod augmentation;
 mod base;
 mod batch;
 mod builder;
+mod iterable;
 mod multithread;
+mod prefetch;
+mod sampler;
 mod strategy;
+mod text;
 
 /// Module for batching items.
 pub mod batcher;
 
+pub use augmentation::*;
 pub use base::*;
 pub use batch::*;
 pub use builder::*;
+pub use iterable::*;
 pub use multithread::*;
+pub use prefetch::*;
+pub use sampler::*;
 pub use strategy::*;
+pub use text::*;
@@ -8,6 +8,7 @@ const MAX_QUEUED_ITEMS: usize = 100;
 /// A multi-threaded data loader that can be used to iterate over a dataset.
 pub struct MultiThreadDataLoader<O> {
     dataloaders: Vec<Arc<dyn DataLoader<O> + Send + Sync>>,
+    max_queued_items: usize,
 }
 
 /// A message that can be sent between threads.
@@ -38,7 +39,24 @@ impl<O> MultiThreadDataLoader<O> {
     ///
     /// The multi-threaded data loader.
     pub fn new(dataloaders: Vec<Arc<dyn DataLoader<O> + Send + Sync>>) -> Self {
-        Self { dataloaders }
+        Self {
+            dataloaders,
+            max_queued_items: MAX_QUEUED_ITEMS,
+        }
+    }
+
+    /// Sets the number of batches prepared by the workers in advance, 100 by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_batches` - The number of batches.
+    ///
+    /// # Returns
+    ///
+    /// The multi-threaded data loader.
+    pub fn with_prefetch(mut self, num_batches: usize) -> Self {
+        self.max_queued_items = num_batches;
+        self
     }
 }
 
@@ -47,7 +65,7 @@ where
     O: Send + 'static + std::fmt::Debug,
 {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
-        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
+        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(self.max_queued_items);
 
         let handlers: Vec<_> = self
             .dataloaders
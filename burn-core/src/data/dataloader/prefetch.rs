@@ -0,0 +1,153 @@
+use super::{DataLoader, DataLoaderIterator, Message, Progress};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+/// A function applied to each batch on the prefetching thread, e.g. to move it to a device.
+pub type StageFn<O> = Arc<dyn Fn(O) -> O + Send + Sync>;
+
+/// A data loader preparing the batches of an inner data loader on a background thread, so they
+/// are ready when the training loop requests them.
+///
+/// A [stage](PrefetchDataLoader::with_stage) can be applied to each batch on the background
+/// thread, typically to move its tensors to the device of the model, which overlaps the copies
+/// with the computations of the previous batches.
+pub struct PrefetchDataLoader<O> {
+    dataloader: Arc<dyn DataLoader<O> + Send + Sync>,
+    num_batches: usize,
+    stage: Option<StageFn<O>>,
+}
+
+struct PrefetchDataLoaderIterator<O> {
+    worker: Option<thread::JoinHandle<()>>,
+    receiver: mpsc::Receiver<Message<O>>,
+    progress: Progress,
+}
+
+impl<O> PrefetchDataLoader<O> {
+    /// Creates a new prefetching data loader.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The inner data loader.
+    /// * `num_batches` - The number of batches prepared in advance.
+    ///
+    /// # Returns
+    ///
+    /// The prefetching data loader.
+    pub fn new(dataloader: Arc<dyn DataLoader<O> + Send + Sync>, num_batches: usize) -> Self {
+        Self {
+            dataloader,
+            num_batches,
+            stage: None,
+        }
+    }
+
+    /// Sets the function applied to each batch on the background thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `stage` - The function.
+    ///
+    /// # Returns
+    ///
+    /// The prefetching data loader.
+    pub fn with_stage(mut self, stage: StageFn<O>) -> Self {
+        self.stage = Some(stage);
+        self
+    }
+}
+
+impl<O> DataLoader<O> for PrefetchDataLoader<O>
+where
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(self.num_batches);
+        let dataloader = self.dataloader.clone();
+        let stage = self.stage.clone();
+
+        let worker = thread::spawn(move || {
+            let mut iterator = dataloader.iter();
+            while let Some(item) = iterator.next() {
+                let progress = iterator.progress();
+                let item = match &stage {
+                    Some(stage) => stage(item),
+                    None => item,
+                };
+
+                // The receiver is gone when the iterator is dropped, no need to continue.
+                if sender.send(Message::Batch(0, item, progress)).is_err() {
+                    return;
+                }
+            }
+            sender.send(Message::Done).ok();
+        });
+
+        Box::new(PrefetchDataLoaderIterator {
+            worker: Some(worker),
+            receiver,
+            progress: Progress {
+                items_processed: 0,
+                items_total: 0,
+            },
+        })
+    }
+}
+
+impl<O> DataLoaderIterator<O> for PrefetchDataLoaderIterator<O> {
+    fn progress(&self) -> Progress {
+        self.progress.clone()
+    }
+}
+
+impl<O> Iterator for PrefetchDataLoaderIterator<O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        match self.receiver.recv() {
+            Ok(Message::Batch(_, item, progress)) => {
+                self.progress = progress;
+                Some(item)
+            }
+            // The channel is closed without a message when the worker panicked.
+            Ok(Message::Done) | Err(_) => {
+                if let Some(worker) = self.worker.take() {
+                    worker.join().unwrap();
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::{batcher::TestBatcher, BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::InMemDataset;
+
+    #[test]
+    fn prefetch_dataloader_should_stage_each_batch_in_order() {
+        let dataloader = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(3)),
+            Arc::new(InMemDataset::new((0..10).collect::<Vec<usize>>())),
+            Arc::new(TestBatcher::new()),
+            None,
+        );
+        let stage: StageFn<Vec<usize>> =
+            Arc::new(|items: Vec<usize>| items.into_iter().map(|item| item * 10).collect());
+        let dataloader = PrefetchDataLoader::new(Arc::new(dataloader), 2).with_stage(stage);
+
+        for _ in 0..2 {
+            let mut iterator = dataloader.iter();
+            let mut items = Vec::new();
+            for batch in iterator.by_ref() {
+                items.extend(batch);
+            }
+
+            assert_eq!(items, (0..10).map(|item| item * 10).collect::<Vec<_>>());
+            assert_eq!(iterator.progress().items_processed, 10);
+            assert_eq!(iterator.progress().items_total, 10);
+        }
+    }
+}
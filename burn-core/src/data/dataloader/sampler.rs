@@ -0,0 +1,316 @@
+use burn_dataset::Dataset;
+use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, seq::SliceRandom};
+use std::sync::Arc;
+
+/// A strategy to choose the items of each iteration of a [data loader](super::DataLoader).
+///
+/// Samplers change the distribution of the items without modifying the dataset, e.g. to
+/// oversample the rare classes of an imbalanced dataset.
+pub trait Sampler: Send + Sync {
+    /// Returns the indices of the items of an iteration over a dataset of the given length.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The length of the dataset.
+    /// * `rng` - The random generator of the data loader.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the items, in the order they are loaded.
+    fn indices(&self, len: usize, rng: &mut StdRng) -> Vec<usize>;
+}
+
+/// Samples the items with a probability proportional to their weight.
+#[derive(Clone, Debug)]
+pub struct WeightedRandomSampler {
+    weights: Vec<f64>,
+    num_samples: usize,
+    replacement: bool,
+}
+
+impl WeightedRandomSampler {
+    /// Creates a new sampler drawing `num_samples` items with replacement.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weight of each item of the dataset.
+    /// * `num_samples` - The number of items of each iteration.
+    ///
+    /// # Returns
+    ///
+    /// The weighted random sampler.
+    pub fn new(weights: Vec<f64>, num_samples: usize) -> Self {
+        Self {
+            weights,
+            num_samples,
+            replacement: true,
+        }
+    }
+
+    /// Creates a new sampler drawing `num_samples` distinct items.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weight of each item of the dataset.
+    /// * `num_samples` - The number of items of each iteration, at most the number of items with
+    ///   a positive weight.
+    ///
+    /// # Returns
+    ///
+    /// The weighted random sampler.
+    pub fn without_replacement(weights: Vec<f64>, num_samples: usize) -> Self {
+        Self {
+            weights,
+            num_samples,
+            replacement: false,
+        }
+    }
+
+    /// Creates a new sampler drawing each class with the same probability, with replacement.
+    ///
+    /// The weight of each item is the inverse of the number of items of its class, so the rare
+    /// classes are oversampled and the frequent ones undersampled.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - The class of each item of the dataset.
+    /// * `num_samples` - The number of items of each iteration.
+    ///
+    /// # Returns
+    ///
+    /// The weighted random sampler.
+    pub fn class_balanced(labels: &[usize], num_samples: usize) -> Self {
+        let num_classes = labels.iter().max().map_or(0, |label| label + 1);
+        let mut counts = vec![0; num_classes];
+        for label in labels {
+            counts[*label] += 1;
+        }
+
+        let weights = labels
+            .iter()
+            .map(|label| 1.0 / counts[*label] as f64)
+            .collect();
+
+        Self::new(weights, num_samples)
+    }
+}
+
+impl Sampler for WeightedRandomSampler {
+    fn indices(&self, len: usize, rng: &mut StdRng) -> Vec<usize> {
+        assert_eq!(
+            self.weights.len(),
+            len,
+            "The sampler should have a weight per item of the dataset."
+        );
+
+        if self.replacement {
+            let distribution = WeightedIndex::new(&self.weights)
+                .expect("The weights should be positive, with at least one non-zero weight.");
+            return distribution
+                .sample_iter(rng)
+                .take(self.num_samples)
+                .collect();
+        }
+
+        let indices = (0..len).collect::<Vec<_>>();
+        indices
+            .choose_multiple_weighted(rng, self.num_samples, |index| self.weights[*index])
+            .expect("The weights should be positive.")
+            .copied()
+            .collect()
+    }
+}
+
+/// Samples the items of a subset of the dataset in a random order, without replacement.
+///
+/// This is useful to split a dataset in training and validation sets without copying it.
+#[derive(new, Clone, Debug)]
+pub struct SubsetRandomSampler {
+    indices: Vec<usize>,
+}
+
+impl Sampler for SubsetRandomSampler {
+    fn indices(&self, len: usize, rng: &mut StdRng) -> Vec<usize> {
+        assert!(
+            self.indices.iter().all(|index| *index < len),
+            "The indices of the subset should be smaller than the length of the dataset."
+        );
+
+        let mut indices = self.indices.clone();
+        indices.shuffle(rng);
+
+        indices
+    }
+}
+
+/// Samples all the items of the dataset, in a random order when shuffled.
+pub(crate) struct PermutationSampler {
+    shuffle: bool,
+}
+
+impl PermutationSampler {
+    pub(crate) fn new(shuffle: bool) -> Self {
+        Self { shuffle }
+    }
+}
+
+impl Sampler for PermutationSampler {
+    fn indices(&self, len: usize, rng: &mut StdRng) -> Vec<usize> {
+        let mut indices = (0..len).collect::<Vec<_>>();
+        if self.shuffle {
+            indices.shuffle(rng);
+        }
+
+        indices
+    }
+}
+
+/// Keeps the part of the indices of an inner sampler of a shard, as a
+/// [sharded dataset](burn_dataset::transform::ShardedDataset) does with the items.
+///
+/// The shards sample the same indices when their samplers are seeded with the same seed, so
+/// they stay disjoint while the indices change at each iteration.
+pub(crate) struct ShardedSampler {
+    sampler: Arc<dyn Sampler>,
+    world_size: usize,
+    rank: usize,
+}
+
+impl ShardedSampler {
+    pub(crate) fn new(sampler: Arc<dyn Sampler>, world_size: usize, rank: usize) -> Self {
+        Self {
+            sampler,
+            world_size,
+            rank,
+        }
+    }
+}
+
+impl Sampler for ShardedSampler {
+    fn indices(&self, len: usize, rng: &mut StdRng) -> Vec<usize> {
+        let indices = self.sampler.indices(len, rng);
+        let shard_len = indices.len() / self.world_size;
+
+        indices
+            .into_iter()
+            .skip(self.rank)
+            .step_by(self.world_size)
+            .take(shard_len)
+            .collect()
+    }
+}
+
+/// The items of an inner dataset at the indices chosen by a [sampler](Sampler).
+pub(crate) struct SampledDataset<I> {
+    dataset: Arc<dyn Dataset<I>>,
+    indices: Vec<usize>,
+}
+
+impl<I> SampledDataset<I> {
+    pub(crate) fn new(dataset: Arc<dyn Dataset<I>>, indices: Vec<usize>) -> Self {
+        Self { dataset, indices }
+    }
+}
+
+impl<I: Send + Sync> Dataset<I> for SampledDataset<I> {
+    fn get(&self, index: usize) -> Option<I> {
+        self.dataset.get(*self.indices.get(index)?)
+    }
+
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::{batcher::TestBatcher, DataLoaderBuilder};
+    use crate::data::dataset::InMemDataset;
+    use rand::SeedableRng;
+
+    #[test]
+    fn weighted_random_sampler_should_follow_the_weights() {
+        let sampler = WeightedRandomSampler::new(vec![3.0, 0.0, 1.0], 1000);
+
+        let indices = sampler.indices(3, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(indices.len(), 1000);
+        assert!(!indices.contains(&1));
+        let count = indices.iter().filter(|index| **index == 2).count();
+        assert!((200..300).contains(&count));
+    }
+
+    #[test]
+    fn weighted_random_sampler_without_replacement_should_sample_distinct_items() {
+        let sampler = WeightedRandomSampler::without_replacement(vec![1.0, 0.0, 5.0, 2.0], 3);
+
+        let mut indices = sampler.indices(4, &mut StdRng::seed_from_u64(42));
+
+        indices.sort();
+        assert_eq!(indices, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn class_balanced_sampler_should_sample_each_class_equally() {
+        let labels = [vec![0; 90], vec![1; 9], vec![2; 1]].concat();
+        let sampler = WeightedRandomSampler::class_balanced(&labels, 3000);
+
+        let indices = sampler.indices(labels.len(), &mut StdRng::seed_from_u64(42));
+
+        for class in 0..3 {
+            let count = indices
+                .iter()
+                .filter(|index| labels[**index] == class)
+                .count();
+            assert!((900..1100).contains(&count));
+        }
+    }
+
+    #[test]
+    fn sharded_data_loaders_should_load_disjoint_items_at_each_iteration() {
+        let dataloaders = (0..3)
+            .map(|rank| {
+                DataLoaderBuilder::new(TestBatcher::new())
+                    .batch_size(4)
+                    .shuffle(42)
+                    .shard(3, rank)
+                    .build(InMemDataset::new((0..31).collect::<Vec<usize>>()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut previous = Vec::new();
+        for _ in 0..2 {
+            let shards = dataloaders
+                .iter()
+                .map(|dataloader| dataloader.iter().flatten().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            let mut items = shards.concat();
+            assert!(shards.iter().all(|shard| shard.len() == 10));
+            items.sort();
+            items.dedup();
+            assert_eq!(items.len(), 30);
+            assert_ne!(shards, previous);
+            previous = shards;
+        }
+    }
+
+    #[test]
+    fn data_loader_with_subset_sampler_should_load_each_item_of_the_subset_once() {
+        let dataset = InMemDataset::new((0..100).collect::<Vec<usize>>());
+        let subset = (0..100).filter(|index| index % 3 == 0).collect::<Vec<_>>();
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(4)
+            .sampler(SubsetRandomSampler::new(subset.clone()))
+            .shuffle(42)
+            .num_workers(3)
+            .build(dataset);
+
+        let mut items = dataloader.iter().flatten().collect::<Vec<_>>();
+
+        assert_ne!(items, subset);
+        items.sort();
+        assert_eq!(items, subset);
+    }
+}
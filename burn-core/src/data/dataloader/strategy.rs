@@ -1,3 +1,5 @@
+use std::{collections::VecDeque, sync::Arc};
+
 /// A strategy to batch items.
 pub trait BatchStrategy<I>: Send + Sync {
     /// Adds an item to the strategy.
@@ -74,3 +76,184 @@ impl<I: Send + Sync + 'static> BatchStrategy<I> for FixBatchStrategy<I> {
         Box::new(Self::new(self.batch_size))
     }
 }
+
+/// A strategy to batch items of similar length together, to minimize the padding of
+/// variable-length items such as sentences or audio clips.
+///
+/// The items are grouped in buckets by length, each bucket being batched independently once it
+/// has `batch_size` items. When a budget of tokens is set, a bucket is also batched before the
+/// next item would make the padded batch, i.e. the length of its longest item times its number of
+/// items, exceed the budget.
+pub struct BucketBatchStrategy<I> {
+    length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+    boundaries: Vec<usize>,
+    batch_size: usize,
+    max_tokens: Option<usize>,
+    buckets: Vec<Bucket<I>>,
+    ready: VecDeque<Vec<I>>,
+}
+
+struct Bucket<I> {
+    items: Vec<I>,
+    max_length: usize,
+}
+
+impl<I> Default for Bucket<I> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            max_length: 0,
+        }
+    }
+}
+
+impl<I> BucketBatchStrategy<I> {
+    /// Creates a new strategy to batch items of similar length.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - The maximum batch size.
+    /// * `boundaries` - The sorted upper bounds of the lengths of each bucket, the items longer
+    ///   than the last boundary being put in an additional bucket.
+    /// * `length` - The function returning the length of an item.
+    ///
+    /// # Returns
+    ///
+    /// The strategy.
+    pub fn new<F>(batch_size: usize, boundaries: Vec<usize>, length: F) -> Self
+    where
+        F: Fn(&I) -> usize + Send + Sync + 'static,
+    {
+        Self::from_parts(batch_size, boundaries, Arc::new(length), None)
+    }
+
+    /// Sets the maximum number of tokens of a batch, padding included.
+    ///
+    /// An item longer than the budget is batched alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - The maximum number of tokens.
+    ///
+    /// # Returns
+    ///
+    /// The strategy.
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    fn from_parts(
+        batch_size: usize,
+        boundaries: Vec<usize>,
+        length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+        max_tokens: Option<usize>,
+    ) -> Self {
+        let buckets = (0..boundaries.len() + 1)
+            .map(|_| Bucket::default())
+            .collect();
+
+        Self {
+            length,
+            boundaries,
+            batch_size,
+            max_tokens,
+            buckets,
+            ready: VecDeque::new(),
+        }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for BucketBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        let length = (self.length)(&item);
+        let index = self
+            .boundaries
+            .partition_point(|boundary| *boundary < length);
+        let bucket = &mut self.buckets[index];
+
+        let max_length = usize::max(bucket.max_length, length);
+        if let Some(max_tokens) = self.max_tokens {
+            if !bucket.items.is_empty() && max_length * (bucket.items.len() + 1) > max_tokens {
+                self.ready.push_back(std::mem::take(&mut bucket.items));
+                bucket.max_length = 0;
+            }
+        }
+
+        bucket.max_length = usize::max(bucket.max_length, length);
+        bucket.items.push(item);
+
+        if bucket.items.len() >= self.batch_size {
+            self.ready.push_back(std::mem::take(&mut bucket.items));
+            bucket.max_length = 0;
+        }
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        if let Some(items) = self.ready.pop_front() {
+            return Some(items);
+        }
+
+        if !force {
+            return None;
+        }
+
+        // The remaining items are batched one bucket at a time.
+        let bucket = self
+            .buckets
+            .iter_mut()
+            .find(|bucket| !bucket.items.is_empty())?;
+        bucket.max_length = 0;
+
+        Some(std::mem::take(&mut bucket.items))
+    }
+
+    fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
+        Box::new(Self::from_parts(
+            self.batch_size,
+            self.boundaries.clone(),
+            self.length.clone(),
+            self.max_tokens,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batches(mut strategy: BucketBatchStrategy<Vec<u8>>, lengths: &[usize]) -> Vec<Vec<usize>> {
+        let mut batches = Vec::new();
+        let length_of = |items: Vec<Vec<u8>>| items.iter().map(Vec::len).collect();
+
+        for length in lengths {
+            strategy.add(vec![0; *length]);
+            if let Some(items) = strategy.batch(false) {
+                batches.push(length_of(items));
+            }
+        }
+        while let Some(items) = strategy.batch(true) {
+            batches.push(length_of(items));
+        }
+
+        batches
+    }
+
+    #[test]
+    fn bucket_batch_strategy_should_batch_items_of_similar_length() {
+        let strategy = BucketBatchStrategy::new(2, vec![4, 8], Vec::len);
+
+        let batches = batches(strategy, &[1, 10, 6, 3, 12, 5, 2]);
+
+        assert_eq!(batches, vec![vec![1, 3], vec![10, 12], vec![6, 5], vec![2]]);
+    }
+
+    #[test]
+    fn bucket_batch_strategy_should_respect_the_token_budget() {
+        let strategy = BucketBatchStrategy::new(8, vec![], Vec::len).with_max_tokens(10);
+
+        let batches = batches(strategy, &[2, 3, 3, 4, 12, 1]);
+
+        assert_eq!(batches, vec![vec![2, 3, 3], vec![4], vec![12], vec![1]]);
+    }
+}
@@ -0,0 +1,133 @@
+use super::batcher::Batcher;
+use crate::data::dataset::text::Tokenizer;
+use crate::nn::attention::generate_padding_mask;
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+use std::sync::Arc;
+
+/// A batch of token sequences padded to the same length.
+#[derive(Clone, Debug)]
+pub struct TokenBatch<B: Backend> {
+    /// The token ids, of shape `[batch_size, seq_length]`.
+    pub tokens: Tensor<B, 2, Int>,
+
+    /// The attention mask, true at the padded positions, as expected by the
+    /// [attention modules](crate::nn::attention).
+    pub mask_pad: Tensor<B, 2, Bool>,
+}
+
+/// Pads the token sequences to the length of the longest one, truncating the sequences longer
+/// than `max_seq_length`.
+///
+/// # Arguments
+///
+/// * `tokens_list` - The token ids of each sequence.
+/// * `pad_token` - The token id of the padding.
+/// * `max_seq_length` - The maximum length of the sequences.
+/// * `device` - The device of the tensors.
+///
+/// # Returns
+///
+/// The padded token ids and their attention mask.
+pub fn pad_tokens<B: Backend>(
+    tokens_list: Vec<Vec<usize>>,
+    pad_token: usize,
+    max_seq_length: Option<usize>,
+    device: &B::Device,
+) -> TokenBatch<B> {
+    let mask = generate_padding_mask(pad_token, tokens_list, max_seq_length, device);
+
+    TokenBatch {
+        tokens: mask.tensor,
+        mask_pad: mask.mask,
+    }
+}
+
+/// Tokenizes the texts of a batch and pads their tokens, e.g. to train a language model on a
+/// text dataset.
+///
+/// Batchers of items with other fields, e.g. a label, can use [pad_tokens] instead.
+pub struct TokenBatcher<B: Backend> {
+    tokenizer: Arc<dyn Tokenizer>,
+    device: B::Device,
+    max_seq_length: Option<usize>,
+}
+
+impl<B: Backend> TokenBatcher<B> {
+    /// Creates a new token batcher.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokenizer` - The tokenizer.
+    /// * `device` - The device of the tensors.
+    ///
+    /// # Returns
+    ///
+    /// The token batcher.
+    pub fn new(tokenizer: Arc<dyn Tokenizer>, device: B::Device) -> Self {
+        Self {
+            tokenizer,
+            device,
+            max_seq_length: None,
+        }
+    }
+
+    /// Sets the maximum length of the sequences, the longer ones being truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_seq_length` - The maximum length.
+    ///
+    /// # Returns
+    ///
+    /// The token batcher.
+    pub fn with_max_seq_length(mut self, max_seq_length: usize) -> Self {
+        self.max_seq_length = Some(max_seq_length);
+        self
+    }
+}
+
+impl<B: Backend, I: AsRef<str>> Batcher<I, TokenBatch<B>> for TokenBatcher<B> {
+    fn batch(&self, items: Vec<I>) -> TokenBatch<B> {
+        let tokens_list = items
+            .iter()
+            .map(|item| self.tokenizer.encode(item.as_ref()))
+            .collect();
+
+        pad_tokens(
+            tokens_list,
+            self.tokenizer.pad_token(),
+            self.max_seq_length,
+            &self.device,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataset::text::WhitespaceTokenizer;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn token_batcher_should_pad_and_truncate_the_sequences() {
+        let tokenizer = WhitespaceTokenizer::from_texts(["a b c d e"], 1);
+        let batcher = TokenBatcher::<TestBackend>::new(Arc::new(tokenizer), Default::default())
+            .with_max_seq_length(4);
+
+        let batch = batcher.batch(vec!["a b", "e d c b a", "c"]);
+
+        assert_eq!(
+            batch.tokens.into_data(),
+            Data::from([[2, 3, 0, 0], [6, 5, 4, 3], [4, 0, 0, 0]])
+        );
+        assert_eq!(
+            batch.mask_pad.into_data(),
+            Data::from([
+                [false, false, true, true],
+                [false, false, false, false],
+                [false, true, true, true],
+            ])
+        );
+    }
+}
@@ -11,6 +11,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all parameters by their global norm.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +26,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -36,6 +40,13 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all parameters by their global norm, i.e. the norm of all the
+    /// gradients concatenated together, so they are all scaled by the same factor.
+    ///
+    /// The global norm is computed by the optimizers. When clipping a single gradient, its global
+    /// norm is its norm.
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
@@ -52,6 +63,7 @@ impl GradientClipping {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(max_norm) => self.clip_by_norm(grad, *max_norm),
         }
     }
 
@@ -9,6 +9,11 @@ pub trait LrScheduler: Send + Sync {
     /// learning rate.
     fn step(&mut self) -> LearningRate;
 
+    /// Report the value of the metric monitored by the scheduler, e.g. the validation loss at
+    /// the end of an epoch. The default implementation ignores it, since most schedulers only
+    /// depend on the number of steps.
+    fn report_metric(&mut self, _value: f64) {}
+
     /// Get the current state of the scheduler as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
@@ -0,0 +1,148 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [cosine annealing](CosineAnnealingLrScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct CosineAnnealingLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps of the first cycle, after which the minimum learning rate is reached.
+    num_iters: usize,
+    /// The minimum learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    /// Whether the learning rate restarts from its initial value at the end of each cycle.
+    #[config(default = false)]
+    restarts: bool,
+    /// The factor by which the number of steps of a cycle grows after each restart.
+    #[config(default = 1)]
+    cycle_mult: usize,
+}
+
+/// Cosine annealing learning rate scheduler, with optional warm restarts as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+#[derive(Clone, Debug)]
+pub struct CosineAnnealingLrScheduler {
+    init_lr: LearningRate,
+    min_lr: LearningRate,
+    num_iters: usize,
+    restarts: bool,
+    cycle_mult: usize,
+    step: usize,
+}
+
+impl CosineAnnealingLrSchedulerConfig {
+    /// Initialize a new [cosine annealing](CosineAnnealingLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> CosineAnnealingLrScheduler {
+        assert!(
+            self.num_iters > 0,
+            "The number of steps of a cycle must be positive."
+        );
+        assert!(
+            self.cycle_mult > 0,
+            "The cycle multiplier must be positive."
+        );
+
+        CosineAnnealingLrScheduler {
+            init_lr: self.init_lr,
+            min_lr: self.min_lr,
+            num_iters: self.num_iters,
+            restarts: self.restarts,
+            cycle_mult: self.cycle_mult,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for CosineAnnealingLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let mut step_cycle = self.step;
+        let mut num_iters = self.num_iters;
+        self.step += 1;
+
+        if self.restarts {
+            while step_cycle >= num_iters {
+                step_cycle -= num_iters;
+                num_iters *= self.cycle_mult;
+            }
+        } else {
+            step_cycle = usize::min(step_cycle, num_iters);
+        }
+
+        cosine_annealing(
+            self.init_lr,
+            self.min_lr,
+            step_cycle as f64 / num_iters as f64,
+        )
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+/// Interpolates from `start` to `end` following half a cosine period, `pct` being the progress
+/// between 0 and 1.
+pub(crate) fn cosine_annealing(start: LearningRate, end: LearningRate, pct: f64) -> LearningRate {
+    end + (start - end) * (1.0 + (core::f64::consts::PI * pct).cos()) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_decreases_to_min_lr() {
+        let mut scheduler = CosineAnnealingLrSchedulerConfig::new(1.0, 10)
+            .with_min_lr(0.1)
+            .init();
+
+        let lrs = (0..12).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs[0], 1.0);
+        assert!((lrs[5] - 0.55).abs() < 1e-9);
+        assert!(lrs.windows(2).all(|lrs| lrs[1] <= lrs[0]));
+        assert!((lrs[10] - 0.1).abs() < 1e-9);
+        assert!((lrs[11] - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lr_restarts_with_longer_cycles() {
+        let mut scheduler = CosineAnnealingLrSchedulerConfig::new(1.0, 2)
+            .with_restarts(true)
+            .with_cycle_mult(2)
+            .init();
+
+        let lrs = (0..7).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        // Cycles of 2 and 4 steps.
+        assert_eq!(lrs[0], 1.0);
+        assert!((lrs[1] - 0.5).abs() < 1e-9);
+        assert_eq!(lrs[2], 1.0);
+        assert!((lrs[4] - 0.5).abs() < 1e-9);
+        assert_eq!(lrs[6], 1.0);
+    }
+
+    #[test]
+    fn test_load_record_resumes_schedule() {
+        let config = CosineAnnealingLrSchedulerConfig::new(1.0, 10);
+        let mut scheduler = config.init();
+        for _ in 0..4 {
+            scheduler.step();
+        }
+
+        let mut resumed = config.init().load_record(scheduler.to_record());
+
+        assert_eq!(resumed.step(), scheduler.step());
+    }
+}
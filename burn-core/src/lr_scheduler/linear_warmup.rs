@@ -0,0 +1,142 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{
+    config::Config,
+    record::{PrecisionSettings, Record},
+    LearningRate,
+};
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create a [linear warmup](LinearWarmupLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct LinearWarmupLrSchedulerConfig {
+    /// The number of steps during which the learning rate increases.
+    warmup_steps: usize,
+    /// The factor applied to the learning rate at the start of the warmup.
+    #[config(default = 0.0)]
+    start_factor: f64,
+}
+
+/// Learning rate scheduler increasing linearly the learning rate of another
+/// [scheduler](LrScheduler) during the first steps.
+///
+/// The wrapped scheduler is stepped during the warmup as well, so its learning rate is scaled by
+/// a factor growing from `start_factor` to one.
+#[derive(Clone, Debug)]
+pub struct LinearWarmupLrScheduler<S> {
+    scheduler: S,
+    warmup_steps: usize,
+    start_factor: f64,
+    step: usize,
+}
+
+/// Record of the [linear warmup](LinearWarmupLrScheduler) learning rate scheduler.
+pub struct LinearWarmupLrSchedulerRecord<R: Record> {
+    step: usize,
+    scheduler: R,
+}
+
+/// Record item of the [linear warmup](LinearWarmupLrScheduler) learning rate scheduler.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct LinearWarmupLrSchedulerRecordItem<R: Record, S: PrecisionSettings> {
+    step: usize,
+    scheduler: R::Item<S>,
+}
+
+impl<R: Record> Record for LinearWarmupLrSchedulerRecord<R> {
+    type Item<S: PrecisionSettings> = LinearWarmupLrSchedulerRecordItem<R, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        LinearWarmupLrSchedulerRecordItem {
+            step: self.step,
+            scheduler: self.scheduler.into_item(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            step: item.step,
+            scheduler: R::from_item(item.scheduler),
+        }
+    }
+}
+
+impl LinearWarmupLrSchedulerConfig {
+    /// Initialize a new [linear warmup](LinearWarmupLrScheduler) learning rate scheduler
+    /// wrapping the given scheduler.
+    pub fn init<S: LrScheduler>(&self, scheduler: S) -> LinearWarmupLrScheduler<S> {
+        LinearWarmupLrScheduler {
+            scheduler,
+            warmup_steps: self.warmup_steps,
+            start_factor: self.start_factor,
+            step: 0,
+        }
+    }
+}
+
+impl<S: LrScheduler> LrScheduler for LinearWarmupLrScheduler<S> {
+    type Record = LinearWarmupLrSchedulerRecord<S::Record>;
+
+    fn step(&mut self) -> LearningRate {
+        self.step += 1;
+
+        let factor = match self.step >= self.warmup_steps {
+            true => 1.0,
+            false => {
+                self.start_factor
+                    + (1.0 - self.start_factor) * self.step as f64 / self.warmup_steps as f64
+            }
+        };
+
+        self.scheduler.step() * factor
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        self.scheduler.report_metric(value);
+    }
+
+    fn to_record(&self) -> Self::Record {
+        LinearWarmupLrSchedulerRecord {
+            step: self.step,
+            scheduler: self.scheduler.to_record(),
+        }
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record.step;
+        self.scheduler = self.scheduler.load_record(record.scheduler);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lr_scheduler::step::StepLrSchedulerConfig;
+
+    #[test]
+    fn test_lr_increases_linearly_during_warmup() {
+        let mut scheduler = LinearWarmupLrSchedulerConfig::new(4).init(1.0);
+
+        let lrs = (0..6).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.25, 0.5, 0.75, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_load_record_restores_wrapped_scheduler() {
+        let config = LinearWarmupLrSchedulerConfig::new(2).with_start_factor(0.5);
+        let inner = StepLrSchedulerConfig::new(1.0, 2);
+        let mut scheduler = config.init(inner.init());
+        for _ in 0..3 {
+            scheduler.step();
+        }
+
+        let mut resumed = config.init(inner.init()).load_record(scheduler.to_record());
+
+        assert_eq!(resumed.step(), scheduler.step());
+        assert_eq!(resumed.step(), scheduler.step());
+    }
+}
@@ -1,9 +1,24 @@
 /// Constant learning rate scheduler
 pub mod constant;
 
+/// Cosine annealing learning rate scheduler
+pub mod cosine;
+
+/// Linear warmup learning rate scheduler
+pub mod linear_warmup;
+
 /// Noam Learning rate schedule
 pub mod noam;
 
+/// One cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Reduce on plateau learning rate scheduler
+pub mod plateau;
+
+/// Step learning rate schedulers
+pub mod step;
+
 mod base;
 
 pub use base::*;
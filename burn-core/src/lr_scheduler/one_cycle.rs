@@ -0,0 +1,104 @@
+use crate as burn;
+
+use super::{cosine::cosine_annealing, LrScheduler};
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [one cycle](OneCycleLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    /// The maximum learning rate, reached at the end of the first phase.
+    max_lr: LearningRate,
+    /// The total number of steps of the cycle.
+    total_steps: usize,
+    /// The percentage of the steps spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    /// The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    /// The final learning rate is the initial learning rate divided by `final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+/// One cycle learning rate scheduler as described in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// The learning rate is annealed from the initial learning rate to the maximum learning rate,
+/// then down to the final learning rate, both phases following a cosine.
+#[derive(Clone, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    warmup_steps: usize,
+    total_steps: usize,
+    step: usize,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initialize a new [one cycle](OneCycleLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.pct_start > 0.0 && self.pct_start < 1.0,
+            "The percentage of steps spent increasing the learning rate must be between 0 and 1."
+        );
+        let initial_lr = self.max_lr / self.div_factor;
+        let warmup_steps = (self.pct_start * self.total_steps as f64) as usize;
+
+        OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            final_lr: initial_lr / self.final_div_factor,
+            warmup_steps: usize::max(warmup_steps, 1),
+            total_steps: self.total_steps,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let step = self.step;
+        self.step += 1;
+
+        if step < self.warmup_steps {
+            let pct = step as f64 / self.warmup_steps as f64;
+            return cosine_annealing(self.initial_lr, self.max_lr, pct);
+        }
+
+        let num_steps = usize::max(self.total_steps.saturating_sub(self.warmup_steps), 1);
+        let pct = f64::min((step - self.warmup_steps) as f64 / num_steps as f64, 1.0);
+
+        cosine_annealing(self.max_lr, self.final_lr, pct)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_increases_then_decreases() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(1.0, 100).init();
+
+        let lrs = (0..101).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert!((lrs[0] - 0.04).abs() < 1e-9);
+        assert!(lrs[..30].windows(2).all(|lrs| lrs[1] > lrs[0]));
+        assert!((lrs[30] - 1.0).abs() < 1e-9);
+        assert!(lrs[30..].windows(2).all(|lrs| lrs[1] <= lrs[0]));
+        assert!((lrs[100] - 4e-6).abs() < 1e-12);
+    }
+}
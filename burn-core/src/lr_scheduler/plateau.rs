@@ -0,0 +1,211 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{
+    config::Config,
+    record::{PrecisionSettings, Record},
+    LearningRate,
+};
+use serde::{Deserialize, Serialize};
+
+/// Whether the metric monitored by the [reduce on plateau](ReduceOnPlateauLrScheduler) learning
+/// rate scheduler should be minimized or maximized.
+#[derive(Config, Debug)]
+pub enum PlateauMode {
+    /// Lower values are better, e.g. a loss.
+    Min,
+    /// Higher values are better, e.g. an accuracy.
+    Max,
+}
+
+/// Configuration to create a [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct ReduceOnPlateauLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// Whether the monitored metric should be minimized or maximized.
+    #[config(default = "PlateauMode::Min")]
+    mode: PlateauMode,
+    /// The factor applied to the learning rate when it is reduced.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of reports without improvement after which the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    /// The minimum relative change of the metric counted as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    /// The number of reports to wait after a reduction before counting reports without
+    /// improvement again.
+    #[config(default = 0)]
+    cooldown: usize,
+    /// The minimum learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+/// Learning rate scheduler reducing the learning rate when a metric stops improving.
+///
+/// The metric is given with [report_metric](LrScheduler::report_metric), which the learner calls
+/// at the end of each epoch when a metric is registered for the scheduler.
+#[derive(Clone, Debug)]
+pub struct ReduceOnPlateauLrScheduler {
+    mode: PlateauMode,
+    factor: f64,
+    patience: usize,
+    threshold: f64,
+    cooldown: usize,
+    min_lr: LearningRate,
+    state: ReduceOnPlateauLrSchedulerRecord,
+}
+
+/// Record of the [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate scheduler.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReduceOnPlateauLrSchedulerRecord {
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_reports: usize,
+    cooldown_counter: usize,
+}
+
+impl Record for ReduceOnPlateauLrSchedulerRecord {
+    type Item<S: PrecisionSettings> = Self;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        self
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        item
+    }
+}
+
+impl ReduceOnPlateauLrSchedulerConfig {
+    /// Initialize a new [reduce on plateau](ReduceOnPlateauLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> ReduceOnPlateauLrScheduler {
+        ReduceOnPlateauLrScheduler {
+            mode: self.mode.clone(),
+            factor: self.factor,
+            patience: self.patience,
+            threshold: self.threshold,
+            cooldown: self.cooldown,
+            min_lr: self.min_lr,
+            state: ReduceOnPlateauLrSchedulerRecord {
+                lr: self.init_lr,
+                best: None,
+                num_bad_reports: 0,
+                cooldown_counter: 0,
+            },
+        }
+    }
+}
+
+impl ReduceOnPlateauLrScheduler {
+    fn is_improvement(&self, value: f64) -> bool {
+        let best = match self.state.best {
+            Some(best) => best,
+            None => return true,
+        };
+
+        match self.mode {
+            PlateauMode::Min => value < best - best.abs() * self.threshold,
+            PlateauMode::Max => value > best + best.abs() * self.threshold,
+        }
+    }
+}
+
+impl LrScheduler for ReduceOnPlateauLrScheduler {
+    type Record = ReduceOnPlateauLrSchedulerRecord;
+
+    fn step(&mut self) -> LearningRate {
+        self.state.lr
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        if self.is_improvement(value) {
+            self.state.best = Some(value);
+            self.state.num_bad_reports = 0;
+        } else {
+            self.state.num_bad_reports += 1;
+        }
+
+        if self.state.cooldown_counter > 0 {
+            self.state.cooldown_counter -= 1;
+            self.state.num_bad_reports = 0;
+        }
+
+        if self.state.num_bad_reports > self.patience {
+            self.state.lr = f64::max(self.state.lr * self.factor, self.min_lr);
+            self.state.cooldown_counter = self.cooldown;
+            self.state.num_bad_reports = 0;
+            log::info!("Reducing the learning rate to {}", self.state.lr);
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.state.clone()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.state = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_reduced_after_patience() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_patience(2)
+            .with_factor(0.5)
+            .init();
+
+        scheduler.report_metric(1.0);
+        scheduler.report_metric(0.5);
+        scheduler.report_metric(0.6);
+        scheduler.report_metric(0.5);
+        assert_eq!(scheduler.step(), 1.0);
+
+        scheduler.report_metric(0.7);
+        assert_eq!(scheduler.step(), 0.5);
+    }
+
+    #[test]
+    fn test_lr_not_reduced_below_min_lr() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_mode(PlateauMode::Max)
+            .with_patience(0)
+            .with_min_lr(0.05)
+            .init();
+
+        for _ in 0..4 {
+            scheduler.report_metric(0.5);
+        }
+
+        assert_eq!(scheduler.step(), 0.05);
+    }
+
+    #[test]
+    fn test_cooldown_delays_next_reduction() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(1.0)
+            .with_patience(0)
+            .with_factor(0.5)
+            .with_cooldown(2)
+            .init();
+
+        scheduler.report_metric(1.0);
+        scheduler.report_metric(1.0);
+        assert_eq!(scheduler.step(), 0.5);
+
+        scheduler.report_metric(1.0);
+        scheduler.report_metric(1.0);
+        assert_eq!(scheduler.step(), 0.5);
+
+        scheduler.report_metric(1.0);
+        assert_eq!(scheduler.step(), 0.25);
+    }
+}
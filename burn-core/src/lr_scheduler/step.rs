@@ -0,0 +1,143 @@
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [step](StepLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct StepLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps between each decay of the learning rate.
+    step_size: usize,
+    /// The factor applied to the learning rate at each decay.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Learning rate scheduler decaying the learning rate by a constant factor every `step_size`
+/// steps.
+#[derive(Clone, Debug)]
+pub struct StepLrScheduler {
+    init_lr: LearningRate,
+    step_size: usize,
+    gamma: f64,
+    step: usize,
+}
+
+impl StepLrSchedulerConfig {
+    /// Initialize a new [step](StepLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> StepLrScheduler {
+        assert!(self.step_size > 0, "The step size must be positive.");
+
+        StepLrScheduler {
+            init_lr: self.init_lr,
+            step_size: self.step_size,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for StepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self.step / self.step_size;
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+/// Configuration to create a [multi step](MultiStepLrScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct MultiStepLrSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The steps at which the learning rate is decayed.
+    milestones: Vec<usize>,
+    /// The factor applied to the learning rate at each milestone.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Learning rate scheduler decaying the learning rate by a constant factor once the number of
+/// steps reaches each milestone.
+#[derive(Clone, Debug)]
+pub struct MultiStepLrScheduler {
+    init_lr: LearningRate,
+    milestones: Vec<usize>,
+    gamma: f64,
+    step: usize,
+}
+
+impl MultiStepLrSchedulerConfig {
+    /// Initialize a new [multi step](MultiStepLrScheduler) learning rate scheduler.
+    pub fn init(&self) -> MultiStepLrScheduler {
+        MultiStepLrScheduler {
+            init_lr: self.init_lr,
+            milestones: self.milestones.clone(),
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LrScheduler for MultiStepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self
+            .milestones
+            .iter()
+            .filter(|milestone| **milestone <= self.step)
+            .count();
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_lr_decays_every_step_size() {
+        let mut scheduler = StepLrSchedulerConfig::new(1.0, 2).with_gamma(0.5).init();
+
+        let lrs = (0..5).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![1.0, 1.0, 0.5, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_multi_step_lr_decays_at_milestones() {
+        let mut scheduler = MultiStepLrSchedulerConfig::new(1.0, vec![1, 4])
+            .with_gamma(0.5)
+            .init();
+
+        let lrs = (0..5).map(|_| scheduler.step()).collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![1.0, 0.5, 0.5, 0.5, 0.25]);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{AutodiffModule, ModulePath, ModuleVisitor, ParamId};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use burn_tensor::{backend::AutodiffBackend, Tensor};
+
+/// The path of the module owning each parameter of the modules executed in
+/// [detect_anomaly](detect_anomaly).
+static PARAM_MODULES: spin::Mutex<Vec<(ParamId, String)>> = spin::Mutex::new(Vec::new());
+
+/// Executes the function with the [anomaly detection mode](burn_autodiff::detect_anomaly)
+/// enabled, reporting the path of the module executing each operation.
+///
+/// When a gradient contains NaN or infinite values, the backward step producing it is reported
+/// with the path of the module whose parameter was used last during the forward pass, e.g.
+/// `encoder.layers.0.linear`, so the forward pass must be executed in the function.
+///
+/// # Example
+///
+/// ```ignore
+/// let grads = detect_anomaly(&model, || model.forward(input).backward());
+/// ```
+pub fn detect_anomaly<B, M, O, F>(module: &M, func: F) -> O
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    F: FnOnce() -> O,
+{
+    struct ModulesGuard {
+        num_params: usize,
+    }
+
+    impl Drop for ModulesGuard {
+        fn drop(&mut self) {
+            let mut params = PARAM_MODULES.lock();
+            let len = params.len();
+            params.truncate(len.saturating_sub(self.num_params));
+        }
+    }
+
+    let mut visitor = ParamModulesVisitor::default();
+    module.visit(&mut visitor);
+
+    let _guard = ModulesGuard {
+        num_params: visitor.params.len(),
+    };
+    PARAM_MODULES.lock().extend(visitor.params);
+
+    burn_autodiff::detect_anomaly(func)
+}
+
+/// Reports the module owning the parameter as the one executing the next operations, when the
+/// anomaly detection mode is enabled.
+pub(crate) fn use_param(id: &ParamId) {
+    if !burn_autodiff::is_detect_anomaly() {
+        return;
+    }
+
+    let params = PARAM_MODULES.lock();
+    if let Some((_, path)) = params.iter().rev().find(|(param, _)| param == id) {
+        burn_autodiff::set_anomaly_module(Some(path.clone()));
+    }
+}
+
+/// Visitor listing the path of the module owning each parameter.
+#[derive(Default)]
+struct ParamModulesVisitor {
+    path: ModulePath,
+    params: Vec<(ParamId, String)>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamModulesVisitor {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name);
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        // The last segment is the name of the parameter in its module.
+        let segments = self.path.segments();
+        let module = match segments.split_last() {
+            Some((_, module)) if !module.is_empty() => module.join("."),
+            _ => "root".to_string(),
+        };
+
+        self.params.push((id.clone(), module));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestAutodiffBackend;
+    use crate::{self as burn, module::Module};
+    use burn_tensor::Distribution;
+
+    #[derive(Module, Debug)]
+    struct Block<B: burn_tensor::backend::Backend> {
+        first: Linear<B>,
+        second: Linear<B>,
+    }
+
+    #[test]
+    #[should_panic(expected = "Anomaly detected: the backward step of Log in module second")]
+    fn should_report_the_module_of_the_operation() {
+        let block = Block {
+            first: LinearConfig::new(4, 4).init::<TestAutodiffBackend>(),
+            second: LinearConfig::new(4, 4).init::<TestAutodiffBackend>(),
+        };
+        let input = Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default);
+
+        detect_anomaly(&block, || {
+            let x = block.first.forward(input);
+            let x = block.second.forward(x).mul_scalar(0.0);
+            x.log().sum().backward()
+        });
+    }
+}
@@ -124,6 +124,33 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
         )
     }
 
+    /// Freeze the float parameters whose [path](super::ModulePath) matches the given
+    /// [pattern](super::PathPattern), so that they don't require grad anymore.
+    ///
+    /// Frozen parameters don't receive gradients during the backward pass, so optimizers neither
+    /// update them nor keep any state for them. This is useful for transfer learning, where only
+    /// parts of a pre-trained model are fine-tuned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Only train the classification head.
+    /// let model = model.freeze_matching("encoder.*");
+    /// ```
+    fn freeze_matching(self, pattern: &str) -> Self {
+        let mut mapper = super::RequireGradMapper::new(pattern.into(), false);
+        self.map(&mut mapper)
+    }
+
+    /// Unfreeze the float parameters whose [path](super::ModulePath) matches the given
+    /// [pattern](super::PathPattern), so that they require grad again.
+    ///
+    /// See [freeze_matching](Module::freeze_matching).
+    fn unfreeze_matching(self, pattern: &str) -> Self {
+        let mut mapper = super::RequireGradMapper::new(pattern.into(), true);
+        self.map(&mut mapper)
+    }
+
     /// Get the number of parameters the module has, including all of its sub-modules.
     fn num_params(&self) -> usize {
         module!(
@@ -135,6 +162,39 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
             init = || 0
         )
     }
+
+    /// Get a [summary](super::ModuleSummary) of the module, with the shape and the number of
+    /// parameters of each sub-module and parameter, which can be displayed as a table.
+    fn summary(&self) -> super::ModuleSummary {
+        let mut visitor = super::SummaryVisitor::default();
+        self.visit(&mut visitor);
+        visitor.into_summary()
+    }
+
+    /// Get a [summary](super::ModuleSummary) of the module like [summary](Module::summary),
+    /// tracing the given forward pass on the input to also report the input and output shapes,
+    /// and the output shape and number of floating point operations of each layer.
+    ///
+    /// Only the layers executed on the current thread are traced, which requires the `std`
+    /// feature.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let summary = model.summary_with_input(input, |model, input| model.forward(input));
+    /// println!("{summary}");
+    /// ```
+    fn summary_with_input<const D: usize, const D2: usize, F>(
+        &self,
+        input: Tensor<B, D>,
+        forward: F,
+    ) -> super::ModuleSummary
+    where
+        F: FnOnce(&Self, Tensor<B, D>) -> Tensor<B, D2>,
+    {
+        super::summary_with_input(self, input, forward)
+    }
+
     /// Visit each tensor parameter in the module with a [visitor](ModuleVisitor).
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V);
 
@@ -196,6 +256,12 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 
 /// Module visitor trait.
 pub trait ModuleVisitor<B: Backend> {
+    /// Called before visiting the sub-module or parameter stored under the given name.
+    ///
+    /// The name is the field name for structs and the index for collections.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting the sub-module or parameter stored under the given name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D>) {}
     /// Visit an int tensor in the module.
@@ -206,6 +272,12 @@ pub trait ModuleVisitor<B: Backend> {
 
 /// Module mapper trait.
 pub trait ModuleMapper<B: Backend> {
+    /// Called before mapping the sub-module or parameter stored under the given name.
+    ///
+    /// The name is the field name for structs and the index for collections.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after mapping the sub-module or parameter stored under the given name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Map a float tensor in the module.
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         tensor
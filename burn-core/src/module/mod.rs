@@ -1,5 +1,13 @@
+#[cfg(any(feature = "autodiff", all(test, feature = "std")))]
+mod anomaly;
 mod base;
 mod param;
+mod path;
+mod summary;
 
+#[cfg(any(feature = "autodiff", all(test, feature = "std")))]
+pub use anomaly::*;
 pub use base::*;
 pub use param::*;
+pub use path::*;
+pub use summary::*;
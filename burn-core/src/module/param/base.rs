@@ -21,6 +21,9 @@ impl<T: Clone> Param<T> {
     ///
     /// The parameter value.
     pub fn val(&self) -> T {
+        #[cfg(any(feature = "autodiff", all(test, feature = "std")))]
+        crate::module::use_param(&self.id);
+
         self.value.clone()
     }
 
@@ -1,4 +1,5 @@
 use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor};
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use burn_tensor::backend::{AutodiffBackend, Backend};
 use core::fmt::Debug;
@@ -75,13 +76,25 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.into_iter().map(|module| module.map(mapper)).collect()
+        self.into_iter()
+            .enumerate()
+            .map(|(i, module)| {
+                let name = i.to_string();
+                mapper.enter_module(&name);
+                let module = module.map(mapper);
+                mapper.exit_module(&name);
+                module
+            })
+            .collect()
     }
 
     fn into_record(self) -> Self::Record {
@@ -152,13 +165,24 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = i.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.map(|module| module.map(mapper))
+        let mut i = 0;
+        self.map(|module| {
+            let name = i.to_string();
+            mapper.enter_module(&name);
+            let module = module.map(mapper);
+            mapper.exit_module(&name);
+            i += 1;
+            module
+        })
     }
 
     fn load_record(self, record: Self::Record) -> Self {
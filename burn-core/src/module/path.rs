@@ -0,0 +1,279 @@
+use super::{ModuleMapper, ParamId};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Tensor};
+
+/// The path of a sub-module or a parameter inside a module tree.
+///
+/// Each segment is either a field name or the index of an element in a collection, and the
+/// segments are joined with dots when displayed, e.g. `encoder.layers.0.weight`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ModulePath {
+    segments: Vec<String>,
+}
+
+impl ModulePath {
+    /// Create an empty path, pointing to the root module.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a segment at the end of the path.
+    pub fn push(&mut self, name: &str) {
+        self.segments.push(name.to_string());
+    }
+
+    /// Remove the last segment of the path.
+    pub fn pop(&mut self) -> Option<String> {
+        self.segments.pop()
+    }
+
+    /// The segments of the path, starting from the root module.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+}
+
+impl From<&str> for ModulePath {
+    fn from(value: &str) -> Self {
+        Self {
+            segments: value
+                .split('.')
+                .filter(|segment| !segment.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl core::fmt::Display for ModulePath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.segments.join(".").as_str())
+    }
+}
+
+/// A glob pattern selecting parts of a module tree based on their [path](ModulePath).
+///
+/// The wildcard `*` matches any sequence of characters, including dots, and `?` matches exactly
+/// one character. A path is selected when the pattern matches the path itself or the path of one
+/// of its parent modules, so `encoder` and `encoder.*` both select every parameter of the
+/// `encoder` sub-module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    pattern: String,
+}
+
+impl PathPattern {
+    /// Create a new pattern.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+        }
+    }
+
+    /// If the given path or one of its parent modules is selected by the pattern.
+    pub fn matches(&self, path: &ModulePath) -> bool {
+        let mut prefix = String::new();
+
+        for segment in path.segments() {
+            if !prefix.is_empty() {
+                prefix.push('.');
+            }
+            prefix.push_str(segment);
+
+            if glob_match(self.pattern.as_bytes(), prefix.as_bytes()) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl From<&str> for PathPattern {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let mut p = 0;
+    let mut t = 0;
+    // Position of the last wildcard and of the text it started matching.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == b'?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((wildcard, start)) => {
+                    // Let the last wildcard consume one more character.
+                    backtrack = Some((wildcard, start + 1));
+                    p = wildcard + 1;
+                    t = start + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+/// Set the `require_grad` flag of the float parameters selected by a pattern.
+pub(crate) struct RequireGradMapper {
+    path: ModulePath,
+    pattern: PathPattern,
+    require_grad: bool,
+}
+
+impl RequireGradMapper {
+    pub(crate) fn new(pattern: PathPattern, require_grad: bool) -> Self {
+        Self {
+            path: ModulePath::new(),
+            pattern,
+            require_grad,
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for RequireGradMapper {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name);
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if self.pattern.matches(&self.path) {
+            tensor.set_require_grad(self.require_grad)
+        } else {
+            tensor
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_should_match_wildcards() {
+        let path = ModulePath::from("encoder.layers.0.weight");
+
+        assert!(PathPattern::new("encoder.*").matches(&path));
+        assert!(PathPattern::new("*.weight").matches(&path));
+        assert!(PathPattern::new("encoder.layers.?.weight").matches(&path));
+        assert!(PathPattern::new("*layers*").matches(&path));
+        assert!(!PathPattern::new("decoder.*").matches(&path));
+        assert!(!PathPattern::new("*.bias").matches(&path));
+    }
+
+    #[test]
+    fn pattern_should_match_parent_modules() {
+        let path = ModulePath::from("encoder.layers.0.weight");
+
+        assert!(PathPattern::new("encoder").matches(&path));
+        assert!(PathPattern::new("encoder.layers.0").matches(&path));
+        assert!(!PathPattern::new("encoder.layer").matches(&path));
+        assert!(!PathPattern::new("layers").matches(&path));
+    }
+
+    #[test]
+    fn path_should_display_segments_joined_with_dots() {
+        let mut path = ModulePath::new();
+        path.push("encoder");
+        path.push("0");
+        path.push("bias");
+
+        assert_eq!(path.to_string(), "encoder.0.bias");
+        assert_eq!(path, ModulePath::from("encoder.0.bias"));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests_module {
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::GradientsParams,
+        tensor::{backend::Backend, Tensor},
+        TestAutodiffBackend,
+    };
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        encoder: Vec<Linear<B>>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new() -> Self {
+            Self {
+                encoder: vec![
+                    LinearConfig::new(4, 4).init(),
+                    LinearConfig::new(4, 4).init(),
+                ],
+                head: LinearConfig::new(4, 2).init(),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self
+                .encoder
+                .iter()
+                .fold(input, |x, linear| linear.forward(x));
+
+            self.head.forward(x)
+        }
+    }
+
+    #[test]
+    fn freeze_matching_should_only_freeze_selected_params() {
+        let model = Model::<TestAutodiffBackend>::new().freeze_matching("encoder.1.*");
+
+        assert!(model.encoder[0].weight.is_require_grad());
+        assert!(!model.encoder[1].weight.is_require_grad());
+        assert!(!model.encoder[1].bias.as_ref().unwrap().is_require_grad());
+        assert!(model.head.weight.is_require_grad());
+    }
+
+    #[test]
+    fn unfreeze_matching_should_restore_require_grad() {
+        let model = Model::<TestAutodiffBackend>::new()
+            .freeze_matching("encoder")
+            .unfreeze_matching("*.weight");
+
+        assert!(model.encoder[0].weight.is_require_grad());
+        assert!(!model.encoder[0].bias.as_ref().unwrap().is_require_grad());
+        assert!(model.head.weight.is_require_grad());
+    }
+
+    #[test]
+    fn frozen_params_should_not_have_gradients() {
+        let model = Model::<TestAutodiffBackend>::new().freeze_matching("encoder.*");
+
+        let output = model.forward(Tensor::ones([3, 4]));
+        let grads = GradientsParams::from_grads(output.sum().backward(), &model);
+
+        for linear in model.encoder.iter() {
+            assert!(grads
+                .get::<crate::TestBackend, 2>(&linear.weight.id)
+                .is_none());
+        }
+        assert!(grads
+            .get::<crate::TestBackend, 2>(&model.head.weight.id)
+            .is_some());
+        assert_eq!(grads.len(), 2);
+    }
+}
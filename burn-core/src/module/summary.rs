@@ -0,0 +1,521 @@
+use super::{Module, ModulePath, ModuleVisitor, ParamId};
+use alloc::format;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, Tensor};
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The layers executed by the forward pass traced on the current thread, identified by their
+    /// weight.
+    static TRACES: core::cell::RefCell<Option<Vec<(ParamId, LayerTrace)>>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+#[derive(Debug, Clone)]
+struct LayerTrace {
+    output_shape: Vec<usize>,
+    flops: usize,
+}
+
+/// Summary of a sub-module or a parameter in a [module summary](ModuleSummary).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryEntry {
+    /// The path of the sub-module or parameter.
+    pub path: ModulePath,
+    /// The shape of the parameter, if the entry is a float parameter.
+    pub shape: Option<Vec<usize>>,
+    /// The number of float parameters.
+    pub num_params: usize,
+    /// The number of float parameters requiring grad.
+    pub num_trainable_params: usize,
+    /// The output shape of the layer, if the entry is a layer executed by the traced forward
+    /// pass.
+    pub output_shape: Option<Vec<usize>>,
+    /// The number of floating point operations of the traced forward pass.
+    pub flops: usize,
+}
+
+/// Summary of the parameters of a module tree, created with
+/// [summary](super::Module::summary).
+///
+/// It contains one entry per sub-module and float parameter, in the order they are declared.
+/// Sub-modules without any parameter are omitted. The summary is displayed as a table.
+///
+/// When created with [summary_with_input](super::Module::summary_with_input), it also contains
+/// the input and output shapes of the forward pass, and the output shape and floating point
+/// operations of the linear, convolution and embedding layers it executed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleSummary {
+    entries: Vec<SummaryEntry>,
+    num_params: usize,
+    num_trainable_params: usize,
+    input_shape: Option<Vec<usize>>,
+    output_shape: Option<Vec<usize>>,
+    flops: usize,
+}
+
+impl ModuleSummary {
+    /// The entries of the summary.
+    pub fn entries(&self) -> &[SummaryEntry] {
+        &self.entries
+    }
+
+    /// The total number of float parameters.
+    pub fn num_params(&self) -> usize {
+        self.num_params
+    }
+
+    /// The total number of float parameters requiring grad.
+    pub fn num_trainable_params(&self) -> usize {
+        self.num_trainable_params
+    }
+
+    /// The shape of the input of the traced forward pass.
+    pub fn input_shape(&self) -> Option<&[usize]> {
+        self.input_shape.as_deref()
+    }
+
+    /// The shape of the output of the traced forward pass.
+    pub fn output_shape(&self) -> Option<&[usize]> {
+        self.output_shape.as_deref()
+    }
+
+    /// The total number of floating point operations of the traced forward pass.
+    pub fn flops(&self) -> usize {
+        self.flops
+    }
+}
+
+impl core::fmt::Display for ModuleSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let traced = self.input_shape.is_some();
+        let format_shape = |shape: &Option<Vec<usize>>| {
+            shape
+                .as_ref()
+                .map(|shape| format!("{shape:?}"))
+                .unwrap_or_default()
+        };
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let depth = entry.path.segments().len().saturating_sub(1);
+                let name = entry.path.segments().last().cloned().unwrap_or_default();
+
+                (
+                    format!("{}{}", "  ".repeat(depth), name),
+                    format_shape(&entry.shape),
+                    format_shape(&entry.output_shape),
+                    entry,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let width_name = rows
+            .iter()
+            .map(|(name, _, _, _)| name.len())
+            .chain([6])
+            .max()
+            .unwrap_or_default();
+        let width_shape = rows
+            .iter()
+            .map(|(_, shape, _, _)| shape.len())
+            .chain([5])
+            .max()
+            .unwrap_or_default();
+        let width_output = rows
+            .iter()
+            .map(|(_, _, output, _)| output.len())
+            .chain([6])
+            .max()
+            .unwrap_or_default();
+
+        write!(
+            f,
+            "{:<width_name$}  {:<width_shape$}  {:>12}  {:>12}",
+            "Module", "Shape", "Params", "Trainable"
+        )?;
+        match traced {
+            true => writeln!(f, "  {:<width_output$}  {:>14}", "Output", "FLOPs")?,
+            false => writeln!(f)?,
+        }
+        for (name, shape, output, entry) in rows.iter() {
+            write!(
+                f,
+                "{:<width_name$}  {:<width_shape$}  {:>12}  {:>12}",
+                name, shape, entry.num_params, entry.num_trainable_params
+            )?;
+            match traced {
+                true => writeln!(f, "  {:<width_output$}  {:>14}", output, entry.flops)?,
+                false => writeln!(f)?,
+            }
+        }
+        if traced {
+            writeln!(
+                f,
+                "Input shape: {}, output shape: {}, FLOPs: {}",
+                format_shape(&self.input_shape),
+                format_shape(&self.output_shape),
+                self.flops
+            )?;
+        }
+        write!(
+            f,
+            "Total params: {}, trainable params: {}",
+            self.num_params, self.num_trainable_params
+        )
+    }
+}
+
+/// Creates the [summary](super::Module::summary_with_input) of a module with a traced forward
+/// pass.
+pub(crate) fn summary_with_input<B, M, F, const D: usize, const D2: usize>(
+    module: &M,
+    input: Tensor<B, D>,
+    forward: F,
+) -> ModuleSummary
+where
+    B: Backend,
+    M: Module<B>,
+    F: FnOnce(&M, Tensor<B, D>) -> Tensor<B, D2>,
+{
+    let input_shape = input.dims().to_vec();
+
+    #[cfg(feature = "std")]
+    let previous = TRACES.with(|traces| traces.replace(Some(Vec::new())));
+    let output = forward(module, input);
+    // Only the layers executed on the current thread are traced, the forward passes executed
+    // concurrently by other threads aren't reported.
+    #[cfg(feature = "std")]
+    let traces = TRACES
+        .with(|traces| traces.replace(previous))
+        .unwrap_or_default();
+    #[cfg(not(feature = "std"))]
+    let traces = Vec::new();
+
+    let mut visitor = SummaryVisitor {
+        traces,
+        ..Default::default()
+    };
+    module.visit(&mut visitor);
+
+    let mut summary = visitor.into_summary();
+    summary.input_shape = Some(input_shape);
+    summary.output_shape = Some(output.dims().to_vec());
+    summary
+}
+
+/// Reports the forward pass of a layer, identified by its weight, to the summary being traced on
+/// the current thread.
+///
+/// The number of floating point operations is only computed while tracing.
+#[cfg(feature = "std")]
+pub(crate) fn trace_layer<B: Backend, const D: usize, F: FnOnce() -> usize>(
+    weight: &ParamId,
+    output: &Tensor<B, D>,
+    flops: F,
+) {
+    TRACES.with(|traces| {
+        let mut traces = traces.borrow_mut();
+        let traces = match traces.as_mut() {
+            Some(traces) => traces,
+            None => return,
+        };
+        let output_shape = output.dims().to_vec();
+        let flops = flops();
+
+        // A layer executed many times keeps its last output shape.
+        match traces.iter_mut().find(|(id, _)| id == weight) {
+            Some((_, trace)) => {
+                trace.output_shape = output_shape;
+                trace.flops += flops;
+            }
+            None => traces.push((
+                weight.clone(),
+                LayerTrace {
+                    output_shape,
+                    flops,
+                },
+            )),
+        }
+    });
+}
+
+/// Forward passes can't be traced without the standard library.
+#[cfg(not(feature = "std"))]
+pub(crate) fn trace_layer<B: Backend, const D: usize, F: FnOnce() -> usize>(
+    _weight: &ParamId,
+    _output: &Tensor<B, D>,
+    _flops: F,
+) {
+}
+
+/// Visitor creating a [module summary](ModuleSummary).
+#[derive(Default)]
+pub(crate) struct SummaryVisitor {
+    path: ModulePath,
+    summary: ModuleSummary,
+    // Index of the entry of each module currently entered.
+    stack: Vec<usize>,
+    traces: Vec<(ParamId, LayerTrace)>,
+}
+
+impl SummaryVisitor {
+    pub(crate) fn into_summary(self) -> ModuleSummary {
+        self.summary
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for SummaryVisitor {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name);
+        self.stack.push(self.summary.entries.len());
+        self.summary.entries.push(SummaryEntry {
+            path: self.path.clone(),
+            shape: None,
+            num_params: 0,
+            num_trainable_params: 0,
+            output_shape: None,
+            flops: 0,
+        });
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+
+        let index = match self.stack.pop() {
+            Some(index) => index,
+            None => return,
+        };
+        let entry = &self.summary.entries[index];
+        let (num_params, num_trainable_params, flops) =
+            (entry.num_params, entry.num_trainable_params, entry.flops);
+
+        if num_params == 0 {
+            // The whole sub-tree doesn't have any parameter.
+            self.summary.entries.truncate(index);
+            return;
+        }
+
+        match self.stack.last() {
+            Some(parent) => {
+                let parent = &mut self.summary.entries[*parent];
+                parent.num_params += num_params;
+                parent.num_trainable_params += num_trainable_params;
+                parent.flops += flops;
+            }
+            None => {
+                self.summary.num_params += num_params;
+                self.summary.num_trainable_params += num_trainable_params;
+                self.summary.flops += flops;
+            }
+        }
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        // The trace of a layer is reported on the entry of the module owning the weight.
+        if let Some(position) = self.traces.iter().position(|(trace_id, _)| trace_id == id) {
+            let (_, trace) = self.traces.swap_remove(position);
+
+            match self.stack.len().checked_sub(2) {
+                Some(layer) => {
+                    let entry = &mut self.summary.entries[self.stack[layer]];
+                    entry.output_shape = Some(trace.output_shape);
+                    entry.flops += trace.flops;
+                }
+                None => self.summary.flops += trace.flops,
+            }
+        }
+
+        let shape = tensor.shape();
+        let num_params = shape.num_elements();
+        let num_trainable_params = match tensor.is_require_grad() {
+            true => num_params,
+            false => 0,
+        };
+
+        match self.stack.last() {
+            Some(index) => {
+                let entry = &mut self.summary.entries[*index];
+                entry.shape = Some(shape.dims.to_vec());
+                entry.num_params += num_params;
+                entry.num_trainable_params += num_trainable_params;
+            }
+            None => {
+                self.summary.num_params += num_params;
+                self.summary.num_trainable_params += num_trainable_params;
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate as burn;
+    use crate::{
+        module::{Module, ModulePath},
+        nn::{Dropout, DropoutConfig, Linear, LinearConfig},
+        tensor::{backend::Backend, Tensor},
+        TestAutodiffBackend,
+    };
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        encoder: Vec<Linear<B>>,
+        dropout: Dropout,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new() -> Self {
+            Self {
+                encoder: vec![
+                    LinearConfig::new(4, 8).init(),
+                    LinearConfig::new(8, 8).with_bias(false).init(),
+                ],
+                dropout: DropoutConfig::new(0.1).init(),
+                head: LinearConfig::new(8, 2).init(),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self
+                .encoder
+                .iter()
+                .fold(input, |x, linear| linear.forward(x));
+
+            self.head.forward(self.dropout.forward(x))
+        }
+    }
+
+    #[test]
+    fn summary_should_count_params_of_each_sub_module() {
+        let model = Model::<TestAutodiffBackend>::new();
+
+        let summary = model.summary();
+        let entries = summary
+            .entries()
+            .iter()
+            .map(|entry| (entry.path.to_string(), entry.num_params))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("encoder".to_string(), 4 * 8 + 8 + 8 * 8),
+                ("encoder.0".to_string(), 4 * 8 + 8),
+                ("encoder.0.weight".to_string(), 4 * 8),
+                ("encoder.0.bias".to_string(), 8),
+                ("encoder.1".to_string(), 8 * 8),
+                ("encoder.1.weight".to_string(), 8 * 8),
+                ("head".to_string(), 8 * 2 + 2),
+                ("head.weight".to_string(), 8 * 2),
+                ("head.bias".to_string(), 2),
+            ]
+        );
+        assert_eq!(summary.num_params(), model.num_params());
+        assert_eq!(
+            summary.entries()[2].shape,
+            Some(vec![4, 8]),
+            "Shape of {}",
+            ModulePath::from("encoder.0.weight")
+        );
+    }
+
+    #[test]
+    fn summary_should_count_trainable_params() {
+        let model = Model::<TestAutodiffBackend>::new().freeze_matching("encoder");
+
+        let summary = model.summary();
+
+        assert_eq!(summary.num_params(), model.num_params());
+        assert_eq!(summary.num_trainable_params(), 8 * 2 + 2);
+    }
+
+    #[test]
+    fn summary_should_display_a_table() {
+        let model = Model::<TestAutodiffBackend>::new();
+
+        let summary = model.summary().to_string();
+        let lines = summary.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 11);
+        assert!(lines[0].starts_with("Module"));
+        assert!(lines[3].trim_start().starts_with("weight"));
+        assert!(lines[3].contains("[4, 8]"));
+        assert_eq!(lines[10], "Total params: 122, trainable params: 122");
+    }
+
+    #[test]
+    fn summary_with_input_should_trace_the_layers() {
+        let model = Model::<TestAutodiffBackend>::new();
+
+        let summary =
+            model.summary_with_input(Tensor::zeros([3, 4]), |model, input| model.forward(input));
+        let entries = summary
+            .entries()
+            .iter()
+            .filter(|entry| entry.shape.is_none())
+            .map(|entry| {
+                (
+                    entry.path.to_string(),
+                    entry.output_shape.clone(),
+                    entry.flops,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(summary.input_shape(), Some([3, 4].as_slice()));
+        assert_eq!(summary.output_shape(), Some([3, 2].as_slice()));
+        assert_eq!(
+            entries,
+            vec![
+                ("encoder".to_string(), None, 216 + 384),
+                (
+                    "encoder.0".to_string(),
+                    Some(vec![3, 8]),
+                    2 * 3 * 8 * 4 + 3 * 8
+                ),
+                ("encoder.1".to_string(), Some(vec![3, 8]), 2 * 3 * 8 * 8),
+                ("head".to_string(), Some(vec![3, 2]), 2 * 3 * 2 * 8 + 3 * 2),
+            ]
+        );
+        assert_eq!(summary.flops(), 216 + 384 + 102);
+    }
+
+    #[test]
+    fn summary_with_input_should_display_the_output_shapes_and_flops() {
+        let model = Model::<TestAutodiffBackend>::new();
+
+        let summary = model
+            .summary_with_input(Tensor::zeros([3, 4]), |model, input| model.forward(input))
+            .to_string();
+        let lines = summary.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 12);
+        assert!(lines[0].ends_with("FLOPs"));
+        assert!(lines[2].contains("[3, 8]"));
+        assert_eq!(
+            lines[10],
+            "Input shape: [3, 4], output shape: [3, 2], FLOPs: 702"
+        );
+    }
+
+    #[test]
+    fn summary_with_input_should_ignore_the_forward_passes_of_other_threads() {
+        let model = Model::<TestAutodiffBackend>::new();
+
+        let summary = model.summary_with_input(Tensor::zeros([3, 4]), |model, input| {
+            std::thread::scope(|scope| {
+                scope
+                    .spawn(|| model.forward(Tensor::zeros([5, 4])))
+                    .join()
+                    .unwrap();
+            });
+            model.forward(input)
+        });
+
+        assert_eq!(summary.flops(), 216 + 384 + 102);
+        assert_eq!(summary.entries()[1].output_shape, Some(vec![3, 8]));
+    }
+}
@@ -0,0 +1,105 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// The value of the [SELU](crate::tensor::activation) activation for large negative inputs,
+/// `-scale * alpha`.
+const ALPHA_PRIME: f64 = -1.758_099_340_847_376_6;
+
+/// Configuration to create an [AlphaDropout](AlphaDropout) layer.
+#[derive(Config, Debug)]
+pub struct AlphaDropoutConfig {
+    /// The probability of randomly dropping some elements of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random some elements of the input tensor to the negative saturation value of the SELU
+/// activation during training, keeping the mean and the variance of the input unchanged.
+///
+/// This is the dropout variant used by self-normalizing networks, as described in the paper
+/// [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515).
+#[derive(Module, Clone, Debug)]
+pub struct AlphaDropout {
+    prob: f64,
+}
+
+impl AlphaDropoutConfig {
+    /// Initialize a new [alpha dropout](AlphaDropout) module.
+    pub fn init(&self) -> AlphaDropout {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The alpha dropout probability must be in [0, 1), got {}",
+            self.prob
+        );
+
+        AlphaDropout { prob: self.prob }
+    }
+}
+
+impl AlphaDropout {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let a = (prob_keep * (1.0 + self.prob * ALPHA_PRIME.powi(2))).powf(-0.5);
+        let b = -a * ALPHA_PRIME * self.prob;
+
+        let random = input.random_like(Distribution::Bernoulli(prob_keep));
+        let dropped = random.clone().neg().add_scalar(1.0) * ALPHA_PRIME;
+        let x = input * random + dropped;
+
+        x * a + b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_keep_mean_and_variance() {
+        let tensor = Tensor::<TestAutodiffBackend, 2>::random(
+            Shape::new([200, 200]),
+            Distribution::Normal(0.0, 1.0),
+        );
+        let dropout = AlphaDropoutConfig::new(0.2).init();
+
+        let output = dropout.forward(tensor.clone());
+        assert_ne!(tensor.to_data(), output.to_data());
+
+        let mean = output.clone().mean().into_scalar();
+        let variance = output.sub_scalar(mean).powf(2.0).mean().into_scalar();
+
+        assert!(mean.abs() < 0.05, "mean {mean}");
+        assert!((variance - 1.0).abs() < 0.05, "variance {variance}");
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]));
+        let dropout = AlphaDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+}
@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Int, Tensor};
+
+use libm::powf;
+
+/// Configuration to create an [Alibi](Alibi) layer.
+#[derive(Config, Debug)]
+pub struct AlibiConfig {
+    /// The number of attention heads.
+    pub n_heads: usize,
+}
+
+/// Attention with linear biases (ALiBi), as introduced in
+/// [Train Short, Test Long](https://arxiv.org/abs/2108.12409).
+///
+/// No positional information is added to the embeddings, instead each attention score is
+/// penalized proportionally to the distance between the query and the key, with a different slope
+/// for each head.
+#[derive(Module, Clone, Debug)]
+pub struct Alibi {
+    n_heads: usize,
+}
+
+impl AlibiConfig {
+    /// Initialize a new [Alibi](Alibi) module.
+    pub fn init(&self) -> Alibi {
+        Alibi {
+            n_heads: self.n_heads,
+        }
+    }
+}
+
+impl Alibi {
+    /// Adds the linear biases to the attention scores.
+    ///
+    /// The queries are assumed to be the last `seq_length_1` tokens of the keys sequence, which is
+    /// the case for self-attention and for autoregressive inference with a cache.
+    ///
+    /// # Shapes
+    ///
+    /// * attn_scores: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    /// * output: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    pub fn forward<B: Backend>(&self, attn_scores: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_, n_heads, seq_length_1, seq_length_2] = attn_scores.dims();
+        assert_eq!(
+            n_heads, self.n_heads,
+            "The attention scores should have {} heads, but got {n_heads}",
+            self.n_heads
+        );
+
+        let device = attn_scores.device();
+        let offset = seq_length_2.saturating_sub(seq_length_1);
+
+        let positions_query =
+            Tensor::<B, 1, Int>::arange_device(offset..offset + seq_length_1, &device)
+                .reshape([seq_length_1, 1]);
+        let positions_key =
+            Tensor::<B, 1, Int>::arange_device(0..seq_length_2, &device).reshape([1, seq_length_2]);
+        let distances = positions_query.sub(positions_key).abs().float();
+
+        let slopes = Tensor::<B, 1>::from_data_device(
+            Data::new(self.slopes(), [n_heads].into()).convert(),
+            &device,
+        );
+
+        let biases = distances.unsqueeze::<4>() * slopes.reshape([1, n_heads, 1, 1]);
+
+        attn_scores - biases
+    }
+
+    /// Geometric sequence of slopes, one for each head.
+    ///
+    /// When the number of heads isn't a power of two, the slopes of the closest lower power of two
+    /// are completed with the odd slopes of the next power of two, as in the reference
+    /// implementation.
+    fn slopes(&self) -> Vec<f32> {
+        let power_of_two_slopes = |n: usize| {
+            (1..=n)
+                .map(|i| powf(2.0, -8.0 * i as f32 / n as f32))
+                .collect::<Vec<_>>()
+        };
+
+        if self.n_heads.is_power_of_two() {
+            return power_of_two_slopes(self.n_heads);
+        }
+
+        let closest = 1 << (usize::BITS - 1 - self.n_heads.leading_zeros());
+        let mut slopes = power_of_two_slopes(closest);
+        slopes.extend(
+            power_of_two_slopes(2 * closest)
+                .into_iter()
+                .step_by(2)
+                .take(self.n_heads - closest),
+        );
+
+        slopes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_slopes() {
+        let slopes = AlibiConfig::new(4).init().slopes();
+        assert_eq!(slopes, [0.25, 0.0625, 0.015625, 0.00390625]);
+
+        let slopes = AlibiConfig::new(3).init().slopes();
+        assert_eq!(slopes, [0.0625, 0.00390625, 0.25]);
+    }
+
+    #[test]
+    fn test_biases() {
+        let alibi = AlibiConfig::new(2).init();
+        let scores = Tensor::<TestBackend, 4>::zeros([1, 2, 2, 3]);
+
+        let output = alibi.forward(scores);
+
+        let expected = Tensor::<TestBackend, 4>::from_floats([[
+            [[-0.0625, 0.0, -0.0625], [-0.125, -0.0625, 0.0]],
+            [
+                [-0.00390625, 0.0, -0.00390625],
+                [-0.0078125, -0.00390625, 0.0],
+            ],
+        ]]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
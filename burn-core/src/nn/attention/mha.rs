@@ -1,12 +1,14 @@
 use crate as burn;
+use alloc::vec;
 
+use crate::nn::attention::{Alibi, AlibiConfig};
 use crate::nn::cache::TensorCache;
-use crate::nn::Initializer;
+use crate::nn::{Initializer, RotaryEncoding, RotaryEncodingConfig};
 use crate::{
     config::Config,
     module::Module,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, module, ops::AttentionDropout, Bool, Tensor},
 };
 use libm::sqrtf;
 
@@ -17,6 +19,10 @@ pub struct MultiHeadAttentionConfig {
     d_model: usize,
     /// The number of heads.
     n_heads: usize,
+    /// The number of heads used for the keys and values, each one being shared by
+    /// `n_heads / num_key_value_heads` query heads. Use `1` for multi-query attention and a
+    /// divisor of `n_heads` for grouped-query attention. Default: `n_heads`
+    pub num_key_value_heads: Option<usize>,
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     dropout: f64,
@@ -30,6 +36,12 @@ pub struct MultiHeadAttentionConfig {
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// Rotary positional encoding applied on the queries and keys of each head, its `d_model`
+    /// should be the size of a head. Default: None
+    pub rotary_encoding: Option<RotaryEncodingConfig>,
+    /// Add linear biases (ALiBi) to the attention scores. Default: false
+    #[config(default = false)]
+    pub alibi: bool,
 }
 
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -37,9 +49,13 @@ pub struct MultiHeadAttentionConfig {
 /// # Params
 ///
 /// - query: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
-/// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input features and `d_k` output features
+///   for each key-value head.
+/// - value: [Linear](nn::Linear) layer with `d_model` input features and `d_k` output features
+///   for each key-value head.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - rotary_encoding: optional [rotary encoding](RotaryEncoding) applied on the queries and keys.
+/// - alibi: optional [linear biases](Alibi) added to the attention scores.
 #[derive(Module, Debug)]
 pub struct MultiHeadAttention<B: Backend> {
     query: nn::Linear<B>,
@@ -48,7 +64,10 @@ pub struct MultiHeadAttention<B: Backend> {
     output: nn::Linear<B>,
     dropout: nn::Dropout,
     activation: nn::GELU,
+    rotary_encoding: Option<RotaryEncoding<B>>,
+    alibi: Option<Alibi>,
     n_heads: usize,
+    n_key_value_heads: usize,
     d_k: usize,
     min_float: f64,
 }
@@ -66,20 +85,24 @@ pub struct MhaInput<B: Backend> {
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self) -> MultiHeadAttention<B> {
-        let linear = |config: &Self| {
-            nn::LinearConfig::new(config.d_model, config.d_model)
+        let d_key_value = self.d_key_value();
+        let linear = |config: &Self, d_output| {
+            nn::LinearConfig::new(config.d_model, d_output)
                 .with_initializer(self.initializer.clone())
                 .init()
         };
 
         MultiHeadAttention {
-            query: linear(self),
-            key: linear(self),
-            value: linear(self),
-            output: linear(self),
+            query: linear(self, self.d_model),
+            key: linear(self, d_key_value),
+            value: linear(self, d_key_value),
+            output: linear(self, self.d_model),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
+            rotary_encoding: self.init_rotary_encoding(),
+            alibi: self.init_alibi(),
             n_heads: self.n_heads,
+            n_key_value_heads: self.n_key_value_heads(),
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
         }
@@ -91,22 +114,58 @@ impl MultiHeadAttentionConfig {
         &self,
         record: MultiHeadAttentionRecord<B>,
     ) -> MultiHeadAttention<B> {
-        let linear = |config: &Self, record| {
-            nn::LinearConfig::new(config.d_model, config.d_model).init_with(record)
+        let d_key_value = self.d_key_value();
+        let linear = |config: &Self, d_output, record| {
+            nn::LinearConfig::new(config.d_model, d_output).init_with(record)
         };
 
         MultiHeadAttention {
-            query: linear(self, record.query),
-            key: linear(self, record.key),
-            value: linear(self, record.value),
-            output: linear(self, record.output),
+            query: linear(self, self.d_model, record.query),
+            key: linear(self, d_key_value, record.key),
+            value: linear(self, d_key_value, record.value),
+            output: linear(self, self.d_model, record.output),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
             activation: nn::GELU::new(),
+            rotary_encoding: self.init_rotary_encoding(),
+            alibi: self.init_alibi(),
             n_heads: self.n_heads,
+            n_key_value_heads: self.n_key_value_heads(),
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
         }
     }
+
+    fn n_key_value_heads(&self) -> usize {
+        let n_key_value_heads = self.num_key_value_heads.unwrap_or(self.n_heads);
+
+        assert!(
+            n_key_value_heads > 0 && self.n_heads.is_multiple_of(n_key_value_heads),
+            "The number of heads ({}) should be a multiple of the number of key-value heads ({})",
+            self.n_heads,
+            n_key_value_heads
+        );
+
+        n_key_value_heads
+    }
+
+    fn d_key_value(&self) -> usize {
+        self.n_key_value_heads() * (self.d_model / self.n_heads)
+    }
+
+    fn init_rotary_encoding<B: Backend>(&self) -> Option<RotaryEncoding<B>> {
+        self.rotary_encoding.as_ref().map(|config| {
+            assert_eq!(
+                config.d_model,
+                self.d_model / self.n_heads,
+                "The rotary encoding d_model should be the size of an attention head"
+            );
+            config.init()
+        })
+    }
+
+    fn init_alibi(&self) -> Option<Alibi> {
+        self.alibi.then(|| AlibiConfig::new(self.n_heads).init())
+    }
 }
 
 impl<B: Backend> MhaInput<B> {
@@ -149,7 +208,7 @@ impl<B: Backend> MhaInput<B> {
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights [batch_size, seq_length_1, seq_length_2].
+    /// The attention weights [batch_size, seq_length_1, seq_length_2], before the dropout.
     pub weights: Tensor<B, 4>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
@@ -167,14 +226,17 @@ impl<B: Backend> MultiHeadAttention<B> {
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        let query = self.attention_linear(input.query, &self.query);
-        let key = self.attention_linear(input.key, &self.key);
-        let value = self.attention_linear(input.value, &self.value);
+        let query = self.rotate(self.query_linear(input.query), 0);
+        let key = self.rotate(self.key_value_linear(input.key, &self.key), 0);
+        let value = self.key_value_linear(input.value, &self.value);
+
+        let key = self.repeat_key_value_heads(key);
+        let value = self.repeat_key_value_heads(value);
 
         let attn_scores = self.attn_scores(query, key);
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
-        let context = weights.clone().matmul(value);
+        let context = self.dropout.forward(weights.clone()).matmul(value);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -183,6 +245,52 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass on the input tensors, without computing the attention weights.
+    ///
+    /// When the attention scores aren't modified by ALiBi, the
+    /// [attention](crate::tensor::module::attention_with_dropout) of the backend is used, which
+    /// can be fused so that the attention weights are never stored, reducing the memory usage
+    /// from quadratic to linear in the sequence length. The dropout is generated by the backend
+    /// from a seed, and applied to the attention weights like in
+    /// [forward](MultiHeadAttention::forward).
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    pub fn forward_context(&self, input: MhaInput<B>) -> Tensor<B, 3> {
+        if self.alibi.is_some() {
+            return self.forward(input).context;
+        }
+
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+
+        let query = self.rotate(self.query_linear(input.query), 0);
+        let key = self.rotate(self.key_value_linear(input.key, &self.key), 0);
+        let value = self.key_value_linear(input.value, &self.value);
+
+        let key = self.repeat_key_value_heads(key);
+        let value = self.repeat_key_value_heads(value);
+
+        let mask = Self::merge_masks(input.mask_pad, input.mask_attn, seq_length_1);
+        let dropout = (B::ad_enabled() && self.dropout.prob > 0.0).then(|| {
+            AttentionDropout::new(
+                self.dropout.prob,
+                B::attention_dropout_seed(&query.device()),
+            )
+        });
+
+        let context =
+            module::attention_with_dropout(query, key, value, mask, dropout, self.min_float);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+
+        self.output.forward(context)
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -193,21 +301,28 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
         let [batch_size, seq_length_1, d_model] = input.query.dims();
-
-        let query = cache
-            .query
-            .forward(input.query, |t| self.attention_linear(t, &self.query));
-        let key = cache
-            .key
-            .forward(input.key, |t| self.attention_linear(t, &self.key));
+        let [_, seq_length_2, _] = input.key.dims();
+
+        // The cache only computes the projections of the new tokens, which are the last ones.
+        let query = cache.query.forward(input.query, |t| {
+            let offset = seq_length_1 - t.dims()[1];
+            self.rotate(self.query_linear(t), offset)
+        });
+        let key = cache.key.forward(input.key, |t| {
+            let offset = seq_length_2 - t.dims()[1];
+            self.rotate(self.key_value_linear(t, &self.key), offset)
+        });
         let value = cache
             .value
-            .forward(input.value, |t| self.attention_linear(t, &self.value));
+            .forward(input.value, |t| self.key_value_linear(t, &self.value));
+
+        let key = self.repeat_key_value_heads(key);
+        let value = self.repeat_key_value_heads(value);
 
         let attn_scores = self.attn_scores(query, key);
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
-        let context = weights.clone().matmul(value);
+        let context = self.dropout.forward(weights.clone()).matmul(value);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -217,12 +332,64 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass on the new tokens only, using a cache of the keys and values of
+    /// the previous tokens.
+    ///
+    /// Unlike [forward_cache](MultiHeadAttention::forward_cache), the input only contains the new
+    /// tokens, usually a single one per step, so the cost of each step grows linearly with the
+    /// sequence length instead of quadratically. The new queries attend to all the cached tokens
+    /// and to the new ones, so when multiple tokens are decoded at once (e.g. the prompt), a causal
+    /// attention mask should be provided.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_new, d_model]`
+    /// - key: `[batch_size, seq_length_new, d_model]`
+    /// - value: `[batch_size, seq_length_new, d_model]`
+    /// - mask_pad: `[batch_size, seq_length_cached + seq_length_new]`
+    /// - mask_attn: `[batch_size, seq_length_new, seq_length_cached + seq_length_new]`
+    /// - output: `[batch_size, seq_length_new, d_model]`
+    pub fn forward_kv_cache(&self, input: MhaInput<B>, cache: &mut MhaKvCache<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let offset = cache.len();
+
+        let query = self.rotate(self.query_linear(input.query), offset);
+        let key = self.rotate(self.key_value_linear(input.key, &self.key), offset);
+        let value = self.key_value_linear(input.value, &self.value);
+
+        // Only the key-value heads are cached, they are shared by the query heads afterward.
+        let (key, value) = cache.append(key, value);
+        let key = self.repeat_key_value_heads(key);
+        let value = self.repeat_key_value_heads(value);
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+
+        let context = self.dropout.forward(weights.clone()).matmul(value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
             .div_scalar(sqrtf(self.d_k as f32));
 
-        self.dropout.forward(attn_scores)
+        match &self.alibi {
+            Some(alibi) => alibi.forward(attn_scores),
+            None => attn_scores,
+        }
+    }
+
+    fn rotate(&self, x: Tensor<B, 4>, offset: usize) -> Tensor<B, 4> {
+        match &self.rotary_encoding {
+            Some(rotary_encoding) => rotary_encoding.forward_offset(x, offset),
+            None => x,
+        }
     }
 
     fn attn_weights(
@@ -252,13 +419,63 @@ impl<B: Backend> MultiHeadAttention<B> {
         activation::softmax(attn_scores, 3)
     }
 
-    fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+    /// Merge the padding mask and the attention mask into a single attention mask.
+    fn merge_masks(
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        seq_length_1: usize,
+    ) -> Option<Tensor<B, 3, Bool>> {
+        let mask_pad = mask_pad.map(|mask_pad| {
+            let [batch_size, seq_length_2] = mask_pad.dims();
+
+            mask_pad
+                .reshape([batch_size, 1, seq_length_2])
+                .repeat(1, seq_length_1)
+        });
+
+        match (mask_pad, mask_attn) {
+            (Some(mask_pad), Some(mask_attn)) => Some(mask_pad.logical_or(mask_attn)),
+            (Some(mask_pad), None) => Some(mask_pad),
+            (None, mask_attn) => mask_attn,
+        }
+    }
+
+    fn query_linear(&self, x: Tensor<B, 3>) -> Tensor<B, 4> {
+        self.attention_linear(x, &self.query, self.n_heads)
+    }
+
+    fn key_value_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
+        self.attention_linear(x, linear, self.n_key_value_heads)
+    }
+
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
         let [batch_size, seq_length, _d_model] = x.dims();
         linear
             .forward(x)
-            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
             .swap_dims(1, 2)
     }
+
+    /// Repeat each key-value head for the query heads of its group, so that the query head `i`
+    /// attends using the key-value head `i / (n_heads / n_key_value_heads)`.
+    fn repeat_key_value_heads(&self, x: Tensor<B, 4>) -> Tensor<B, 4> {
+        let n_groups = self.n_heads / self.n_key_value_heads;
+
+        if n_groups == 1 {
+            return x;
+        }
+
+        let [batch_size, n_key_value_heads, seq_length, d_k] = x.dims();
+
+        x.reshape([batch_size, n_key_value_heads, 1, seq_length, d_k])
+            .repeat(2, n_groups)
+            .reshape([batch_size, self.n_heads, seq_length, d_k])
+    }
 }
 
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
@@ -299,6 +516,74 @@ impl<B: Backend> MhaCache<B> {
     }
 }
 
+/// Cache of the keys and values of the previous tokens for the
+/// [Multi Head Attention](MultiHeadAttention) layer.
+///
+/// To be used with [forward_kv_cache](MultiHeadAttention::forward_kv_cache) during inference
+/// when decoding tokens one at a time.
+#[derive(Debug, Clone)]
+pub struct MhaKvCache<B: Backend> {
+    key: Option<Tensor<B, 4>>,
+    value: Option<Tensor<B, 4>>,
+}
+
+impl<B: Backend> MhaKvCache<B> {
+    /// Create an empty cache.
+    pub fn empty() -> Self {
+        Self {
+            key: None,
+            value: None,
+        }
+    }
+
+    /// The number of cached tokens.
+    pub fn len(&self) -> usize {
+        match &self.key {
+            Some(key) => key.dims()[2],
+            None => 0,
+        }
+    }
+
+    /// Returns true if no token is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all the cached tokens, to start decoding a new sequence.
+    pub fn reset(&mut self) {
+        self.key = None;
+        self.value = None;
+    }
+
+    /// The cached keys and values, with the shape
+    /// `[batch_size, n_key_value_heads, seq_length, d_k]`.
+    pub fn key_value(&self) -> Option<(Tensor<B, 4>, Tensor<B, 4>)> {
+        self.key.clone().zip(self.value.clone())
+    }
+
+    fn append(&mut self, key: Tensor<B, 4>, value: Tensor<B, 4>) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let key = match self.key.take() {
+            Some(cached) => Tensor::cat(vec![cached, key], 2),
+            None => key,
+        };
+        let value = match self.value.take() {
+            Some(cached) => Tensor::cat(vec![cached, value], 2),
+            None => value,
+        };
+
+        self.key = Some(key.clone());
+        self.value = Some(value.clone());
+
+        (key, value)
+    }
+}
+
+impl<B: Backend> Default for MhaKvCache<B> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
     pub fn forward<F: Fn(Tensor<B, 3>) -> Tensor<B, D>>(
         &mut self,
@@ -317,7 +602,7 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{module::Param, nn::attention::generate_autoregressive_mask, TestBackend};
     use alloc::vec::Vec;
     use burn::tensor::{Distribution, Shape};
     use burn_tensor::Int;
@@ -417,10 +702,203 @@ mod tests {
     }
 
     #[test]
-    fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
-        let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
+    fn test_forward_context_should_have_same_output_as_forward() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 6, 32, 4];
         let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
 
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let mask_pad = Tensor::<TestBackend, 2, Int>::zeros([batch_size, seq_length])
+            .slice_assign(
+                [0..batch_size, seq_length - 2..seq_length],
+                Tensor::ones([batch_size, 2]),
+            )
+            .equal_elem(1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input.clone());
+        let output_2 = mha.forward_context(input);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads] = [12, 2];
+
+        test_autoregressive_decoding(MultiHeadAttentionConfig::new(d_model, n_heads));
+    }
+
+    #[test]
+    fn test_positional_biases_should_have_same_output_as_autoregressive_decoding() {
+        let [d_model, n_heads, max_sequence_length] = [12, 2, 8];
+
+        test_autoregressive_decoding(
+            MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_rotary_encoding(Some(RotaryEncodingConfig::new(
+                    max_sequence_length,
+                    d_model / n_heads,
+                )))
+                .with_alibi(true),
+        );
+    }
+
+    #[test]
+    fn test_rotary_encoding_makes_self_attention_position_aware() {
+        let [d_model, n_heads, max_sequence_length] = [12, 2, 8];
+        let tensor = Tensor::<TestBackend, 3>::random([1, 4, d_model], Distribution::Default);
+        let reversed = tensor.clone().flip(&[1]);
+        let output = |config: MultiHeadAttentionConfig| {
+            let mha = config.init::<TestBackend>();
+            let output_1 = mha.forward(MhaInput::self_attn(tensor.clone())).context;
+            let output_2 = mha.forward(MhaInput::self_attn(reversed.clone())).context;
+
+            (output_1, output_2.flip(&[1]))
+        };
+
+        // Without positional information, self-attention is permutation equivariant.
+        let (output_1, output_2) = output(MultiHeadAttentionConfig::new(d_model, n_heads));
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+
+        let (output_1, output_2) = output(
+            MultiHeadAttentionConfig::new(d_model, n_heads).with_rotary_encoding(Some(
+                RotaryEncodingConfig::new(max_sequence_length, d_model / n_heads),
+            )),
+        );
+        assert!(output_1.sub(output_2).abs().max().into_scalar() > 1e-3);
+    }
+
+    #[test]
+    fn test_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [seq_length, d_model, n_heads] = [5, 12, 2];
+
+        test_kv_cache(
+            MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_rotary_encoding(Some(RotaryEncodingConfig::new(
+                    seq_length,
+                    d_model / n_heads,
+                )))
+                .with_alibi(true),
+            seq_length,
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [seq_length, d_model, n_heads, n_key_value_heads] = [5, 16, 4, 2];
+
+        test_kv_cache(
+            MultiHeadAttentionConfig::new(d_model, n_heads)
+                .with_num_key_value_heads(Some(n_key_value_heads))
+                .with_rotary_encoding(Some(RotaryEncodingConfig::new(
+                    seq_length,
+                    d_model / n_heads,
+                ))),
+            seq_length,
+        );
+    }
+
+    #[test]
+    fn test_grouped_query_attention_should_match_repeated_key_value_heads() {
+        let [batch_size, seq_length, d_model, n_heads, n_key_value_heads] = [2, 4, 16, 4, 2];
+        let d_k = d_model / n_heads;
+        let n_groups = n_heads / n_key_value_heads;
+
+        let gqa = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_num_key_value_heads(Some(n_key_value_heads))
+            .init::<TestBackend>();
+        assert_eq!(gqa.key.weight.dims(), [d_model, n_key_value_heads * d_k]);
+
+        // Standard attention where each key-value head is duplicated for its group.
+        let repeat_heads = |linear: &nn::Linear<TestBackend>| nn::Linear {
+            weight: Param::from(
+                linear
+                    .weight
+                    .val()
+                    .reshape([d_model, n_key_value_heads, 1, d_k])
+                    .repeat(2, n_groups)
+                    .reshape([d_model, d_model]),
+            ),
+            bias: linear.bias.as_ref().map(|bias| {
+                Param::from(
+                    bias.val()
+                        .reshape([n_key_value_heads, 1, d_k])
+                        .repeat(1, n_groups)
+                        .reshape([d_model]),
+                )
+            }),
+        };
+        let mut mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+        mha.query = gqa.query.clone();
+        mha.key = repeat_heads(&gqa.key);
+        mha.value = repeat_heads(&gqa.value);
+        mha.output = gqa.output.clone();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let output_1 = gqa.forward(MhaInput::self_attn(tensor.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor));
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
+
+    fn test_kv_cache(config: MultiHeadAttentionConfig, seq_length: usize) {
+        let [batch_size, d_model] = [3, config.d_model];
+        let mha = config.init::<TestBackend>();
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let output_1 = mha.forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn));
+
+        // The prompt is decoded at once with a causal mask, then the remaining tokens one by one.
+        let prompt_length = 2;
+        let mut cache = MhaKvCache::empty();
+        let prompt = tensor
+            .clone()
+            .slice([0..batch_size, 0..prompt_length, 0..d_model]);
+        let mask_attn = generate_autoregressive_mask(batch_size, prompt_length, &tensor.device());
+        let mut output_2 = vec![
+            mha.forward_kv_cache(MhaInput::self_attn(prompt).mask_attn(mask_attn), &mut cache)
+                .context,
+        ];
+
+        for i in prompt_length..seq_length {
+            let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            output_2.push(
+                mha.forward_kv_cache(MhaInput::self_attn(token), &mut cache)
+                    .context,
+            );
+        }
+
+        assert_eq!(cache.len(), seq_length);
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&Tensor::cat(output_2, 1).into_data(), 3);
+    }
+
+    fn test_autoregressive_decoding(config: MultiHeadAttentionConfig) {
+        let [batch_size, seq_length, d_model] = [3, 4, config.d_model];
+        let mha = config.init::<TestBackend>();
+
         let tensor = Tensor::<TestBackend, 3>::random(
             [batch_size, seq_length, d_model],
             Distribution::Default,
@@ -1,5 +1,7 @@
+mod alibi;
 mod mask;
 mod mha;
 
+pub use alibi::*;
 pub use mask::*;
 pub use mha::*;
@@ -2,15 +2,15 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
-use crate::module::Param;
-use crate::nn::{Initializer, PaddingConfig1d};
+use crate::module::{trace_layer, Param};
+use crate::nn::{Forward, Initializer, PaddingConfig1d, WeightedModule};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::conv1d;
 use burn_tensor::ops::ConvOptions;
 use libm::sqrt;
 
-use super::checks;
+use super::{checks, conv_flops};
 
 /// Configuration to create an [1D convolution](Conv1d) layer.
 #[derive(Config, Debug)]
@@ -118,12 +118,43 @@ impl<B: Backend> Conv1d<B> {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
-        conv1d(
+        let output = conv1d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new([self.stride], [padding], [self.dilation], self.groups),
-        )
+        );
+
+        trace_layer(&self.weight.id, &output, || {
+            conv_flops(&output, &self.weight, self.bias.is_some())
+        });
+
+        output
+    }
+}
+
+impl<B: Backend> WeightedModule<B> for Conv1d<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        let [channels_out, ..] = self.weight.dims();
+
+        self.weight.val().reshape([channels_out as i32, -1])
+    }
+
+    fn with_weight_matrix(&self, weight: Tensor<B, 2>) -> Self {
+        let weight = weight.reshape(self.weight.shape());
+
+        Self {
+            weight: Param::new(self.weight.id.clone(), weight),
+            ..self.clone()
+        }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>> for Conv1d<B> {
+    type Output = Tensor<B, 3>;
+
+    fn forward(&self, input: Tensor<B, 3>) -> Self::Output {
+        Conv1d::forward(self, input)
     }
 }
 
@@ -2,15 +2,15 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
-use crate::module::Param;
-use crate::nn::Initializer;
+use crate::module::{trace_layer, Param};
+use crate::nn::{Initializer, WeightedModule};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::conv_transpose1d;
 use burn_tensor::ops::ConvTransposeOptions;
 use libm::sqrt;
 
-use super::checks;
+use super::{checks, conv_transpose_flops};
 
 /// Configuration to create an [1D transposed convolution](ConvTranspose1d) layer.
 #[derive(Config, Debug)]
@@ -65,6 +65,7 @@ impl ConvTranspose1dConfig {
     /// Initialize a new [conv transpose 1d](ConvTranspose1d) module.
     pub fn init<B: Backend>(&self) -> ConvTranspose1d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out([self.padding_out], [self.stride], [self.dilation]);
 
         let shape = [
             self.channels[0],
@@ -118,7 +119,8 @@ impl<B: Backend> ConvTranspose1d<B> {
     /// - input: [batch_size, channels_in, length_in],
     /// - output: [batch_size, channels_out, length_out],
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
-        conv_transpose1d(
+        let num_inputs = input.shape().num_elements();
+        let output = conv_transpose1d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
@@ -129,7 +131,36 @@ impl<B: Backend> ConvTranspose1d<B> {
                 [self.dilation],
                 self.groups,
             ),
-        )
+        );
+
+        trace_layer(&self.weight.id, &output, || {
+            conv_transpose_flops(num_inputs, &output, &self.weight, self.bias.is_some())
+        });
+
+        output
+    }
+}
+
+impl<B: Backend> WeightedModule<B> for ConvTranspose1d<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        let [_, channels_out, ..] = self.weight.dims();
+
+        // The weight is stored as `[channels_in, channels_out / groups, ...]`.
+        self.weight
+            .val()
+            .swap_dims(0, 1)
+            .reshape([channels_out as i32, -1])
+    }
+
+    fn with_weight_matrix(&self, weight: Tensor<B, 2>) -> Self {
+        let mut shape = self.weight.shape();
+        shape.dims.swap(0, 1);
+        let weight = weight.reshape(shape).swap_dims(0, 1);
+
+        Self {
+            weight: Param::new(self.weight.id.clone(), weight),
+            ..self.clone()
+        }
     }
 }
 
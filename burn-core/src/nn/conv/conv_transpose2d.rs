@@ -2,15 +2,15 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
-use crate::module::Param;
-use crate::nn::Initializer;
+use crate::module::{trace_layer, Param};
+use crate::nn::{Initializer, WeightedModule};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::conv_transpose2d;
 use burn_tensor::ops::ConvTransposeOptions;
 use libm::sqrt;
 
-use super::checks;
+use super::{checks, conv_transpose_flops};
 
 /// Configuration to create an [2D transposed convolution](ConvTranspose2d) layer.
 #[derive(Config, Debug)]
@@ -65,6 +65,7 @@ impl ConvTranspose2dConfig {
     /// Initialize a new [conv transpose 2d](ConvTranspose2d) module.
     pub fn init<B: Backend>(&self) -> ConvTranspose2d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out(self.padding_out, self.stride, self.dilation);
 
         let shape = [
             self.channels[0],
@@ -119,7 +120,8 @@ impl<B: Backend> ConvTranspose2d<B> {
     /// - input: [batch_size, channels_in, height_in, width_in],
     /// - output: [batch_size, channels_out, height_out, width_out],
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
-        conv_transpose2d(
+        let num_inputs = input.shape().num_elements();
+        let output = conv_transpose2d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
@@ -130,7 +132,36 @@ impl<B: Backend> ConvTranspose2d<B> {
                 self.dilation,
                 self.groups,
             ),
-        )
+        );
+
+        trace_layer(&self.weight.id, &output, || {
+            conv_transpose_flops(num_inputs, &output, &self.weight, self.bias.is_some())
+        });
+
+        output
+    }
+}
+
+impl<B: Backend> WeightedModule<B> for ConvTranspose2d<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        let [_, channels_out, ..] = self.weight.dims();
+
+        // The weight is stored as `[channels_in, channels_out / groups, ...]`.
+        self.weight
+            .val()
+            .swap_dims(0, 1)
+            .reshape([channels_out as i32, -1])
+    }
+
+    fn with_weight_matrix(&self, weight: Tensor<B, 2>) -> Self {
+        let mut shape = self.weight.shape();
+        shape.dims.swap(0, 1);
+        let weight = weight.reshape(shape).swap_dims(0, 1);
+
+        Self {
+            weight: Param::new(self.weight.id.clone(), weight),
+            ..self.clone()
+        }
     }
 }
 
@@ -165,4 +196,29 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn output_shape_with_padding_out_and_dilation() {
+        let config = ConvTranspose2dConfig::new([4, 6], [3, 2])
+            .with_stride([2, 3])
+            .with_padding([1, 0])
+            .with_padding_out([1, 2])
+            .with_dilation([1, 2])
+            .with_groups(2);
+        let conv = config.init::<TestBackend>();
+
+        let output = conv.forward(Tensor::zeros([2, 4, 5, 4]));
+
+        // (size - 1) * stride - 2 * padding + dilation * (kernel_size - 1) + padding_out + 1
+        assert_eq!(output.dims(), [2, 6, 10, 14]);
+    }
+
+    #[test]
+    #[should_panic = "The output padding must be smaller than either the stride or the dilation"]
+    fn padding_out_must_be_smaller_than_stride_or_dilation() {
+        ConvTranspose2dConfig::new([2, 2], [3, 3])
+            .with_stride([2, 2])
+            .with_padding_out([2, 0])
+            .init::<TestBackend>();
+    }
 }
@@ -1,11 +1,43 @@
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 
 pub(crate) mod checks;
 
 pub use conv1d::*;
 pub use conv2d::*;
+pub use conv3d::*;
 pub use conv_transpose1d::*;
 pub use conv_transpose2d::*;
+pub use conv_transpose3d::*;
+
+use crate::tensor::{backend::Backend, Tensor};
+
+/// The floating point operations of a convolution, each output element being the dot product of
+/// `channels_in / groups * kernel_size` elements, plus the bias.
+pub(crate) fn conv_flops<B: Backend, const D: usize>(
+    output: &Tensor<B, D>,
+    weight: &Tensor<B, D>,
+    bias: bool,
+) -> usize {
+    let num_outputs = output.shape().num_elements();
+    let fan_in = weight.shape().num_elements() / weight.dims()[0];
+
+    2 * num_outputs * fan_in + bias as usize * num_outputs
+}
+
+/// The floating point operations of a transposed convolution, each input element being
+/// multiplied by `channels_out / groups * kernel_size` weights, plus the bias.
+pub(crate) fn conv_transpose_flops<B: Backend, const D: usize>(
+    num_inputs: usize,
+    output: &Tensor<B, D>,
+    weight: &Tensor<B, D>,
+    bias: bool,
+) -> usize {
+    let fan_out = weight.shape().num_elements() / weight.dims()[0];
+
+    2 * num_inputs * fan_out + bias as usize * output.shape().num_elements()
+}
@@ -0,0 +1,110 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [DropPath](DropPath) layer.
+#[derive(Config, Debug)]
+pub struct DropPathConfig {
+    /// The probability of dropping the whole path of a sample during training.
+    pub prob: f64,
+    /// If true, the kept samples are scaled during training by `1 / (1 - prob)`. Default: true
+    #[config(default = true)]
+    pub scale_by_keep: bool,
+}
+
+/// Drop paths per sample during training, also known as stochastic depth.
+///
+/// Usually applied on the main path of residual blocks, so that the block is skipped entirely for
+/// the dropped samples, as described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382).
+#[derive(Module, Clone, Debug)]
+pub struct DropPath {
+    prob: f64,
+    scale_by_keep: bool,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The drop path probability must be in [0, 1), got {}",
+            self.prob
+        );
+
+        DropPath {
+            prob: self.prob,
+            scale_by_keep: self.scale_by_keep,
+        }
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let mut shape = [1; D];
+        shape[0] = input.dims()[0];
+        let random =
+            Tensor::random_device(shape, Distribution::Bernoulli(prob_keep), &input.device());
+        let x = input * random;
+
+        match self.scale_by_keep {
+            true => x * (1.0 / prob_keep),
+            false => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        let tensor = Tensor::<TestAutodiffBackend, 3>::ones(Shape::new([100, 4, 5]));
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor).into_data();
+
+        let mut num_dropped = 0;
+        for sample in output.value.chunks(4 * 5) {
+            assert!(sample.iter().all(|x| *x == sample[0]));
+            assert!(sample[0] == 0.0 || sample[0] == 2.0);
+            if sample[0] == 0.0 {
+                num_dropped += 1;
+            }
+        }
+        assert!(num_dropped > 0 && num_dropped < 100);
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([100, 4, 5]));
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+}
@@ -2,6 +2,7 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
+use crate::nn::Forward;
 use crate::tensor::backend::Backend;
 use crate::tensor::{Distribution, Tensor};
 
@@ -20,7 +21,7 @@ pub struct DropoutConfig {
 /// The input is also scaled during training to `1 / (1 - prob_keep)`.
 #[derive(Module, Clone, Debug)]
 pub struct Dropout {
-    prob: f64,
+    pub(crate) prob: f64,
 }
 
 impl DropoutConfig {
@@ -50,6 +51,14 @@ impl Dropout {
     }
 }
 
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for Dropout {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        Dropout::forward(self, input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,110 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [Dropout2d](Dropout2d) layer.
+#[derive(Config, Debug)]
+pub struct Dropout2dConfig {
+    /// The probability of randomly zeroing entire channels of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random entire channels of the input tensor to zero during training.
+///
+/// Adjacent pixels of feature maps are strongly correlated, so dropping whole channels regularizes
+/// convolutional layers better than dropping single elements, as described in the paper
+/// [Efficient Object Localization Using Convolutional Networks](https://arxiv.org/abs/1411.4280).
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+#[derive(Module, Clone, Debug)]
+pub struct Dropout2d {
+    prob: f64,
+}
+
+impl Dropout2dConfig {
+    /// Initialize a new [dropout 2d](Dropout2d) module.
+    pub fn init(&self) -> Dropout2d {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The dropout 2d probability must be in [0, 1), got {}",
+            self.prob
+        );
+
+        Dropout2d { prob: self.prob }
+    }
+}
+
+impl Dropout2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let [batch_size, channels, _, _] = input.dims();
+        let prob_keep = 1.0 - self.prob;
+        let random = Tensor::random_device(
+            [batch_size, channels, 1, 1],
+            Distribution::Bernoulli(prob_keep),
+            &input.device(),
+        );
+        let x = input * random;
+
+        x * (1.0 / prob_keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_channels() {
+        let tensor = Tensor::<TestAutodiffBackend, 4>::ones(Shape::new([10, 10, 3, 3]));
+        let dropout = Dropout2dConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor).into_data();
+
+        let mut num_dropped = 0;
+        for channel in output.value.chunks(3 * 3) {
+            assert!(channel.iter().all(|x| *x == channel[0]));
+            assert!(channel[0] == 0.0 || channel[0] == 2.0);
+            if channel[0] == 0.0 {
+                num_dropped += 1;
+            }
+        }
+        assert!(num_dropped > 0 && num_dropped < 100);
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([10, 10, 3, 3]));
+        let dropout = Dropout2dConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn init_should_panic_when_prob_is_one() {
+        Dropout2dConfig::new(1.0).init();
+    }
+}
@@ -1,9 +1,9 @@
 use crate as burn;
 
-use super::Initializer;
+use super::{Forward, Initializer};
 use crate::config::Config;
 use crate::module::Module;
-use crate::module::Param;
+use crate::module::{trace_layer, Param};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::Int;
@@ -18,6 +18,9 @@ pub struct EmbeddingConfig {
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
     pub initializer: Initializer,
+    /// If specified, the vector at this index is initialized with zeros and isn't updated
+    /// during training, which is useful for padding tokens.
+    pub padding_idx: Option<usize>,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -25,28 +28,44 @@ pub struct EmbeddingConfig {
 /// # Params
 ///
 /// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
-///     `N(0, 1)`
+///     `N(0, 1)`, except for the padding vector which is initialized with zeros.
 #[derive(Module, Debug)]
 pub struct Embedding<B: Backend> {
     weight: Param<Tensor<B, 2>>,
+    padding_idx: Option<usize>,
 }
 
 impl EmbeddingConfig {
     /// Initialize a new [embedding](Embedding) module.
+    ///
+    /// # Panics
+    ///
+    /// If the padding index is greater or equal than the number of embedding vectors.
     pub fn init<B: Backend>(&self) -> Embedding<B> {
-        let weight = self
-            .initializer
-            .init([self.n_embedding, self.d_model])
-            .require_grad();
+        let mut weight = self.initializer.init([self.n_embedding, self.d_model]);
+
+        if let Some(padding_idx) = self.padding_idx {
+            assert!(
+                padding_idx < self.n_embedding,
+                "The padding index ({padding_idx}) must be smaller than the number of \
+                embedding vectors ({})",
+                self.n_embedding
+            );
+
+            let slices = [padding_idx..padding_idx + 1, 0..self.d_model];
+            weight = weight.slice_assign(slices, Tensor::zeros([1, self.d_model]));
+        }
 
         Embedding {
             weight: Param::from(weight),
+            padding_idx: self.padding_idx,
         }
     }
     /// Initialize a new [embedding](Embedding) module with a [record](EmbeddingRecord).
     pub fn init_with<B: Backend>(&self, record: EmbeddingRecord<B>) -> Embedding<B> {
         Embedding {
             weight: record.weight,
+            padding_idx: self.padding_idx,
         }
     }
 }
@@ -59,14 +78,64 @@ impl<B: Backend> Embedding<B> {
     /// - input: [batch_size, seq_length]
     /// - output: [batch_size, d_model]
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        burn_tensor::module::embedding(self.weight.val(), input)
+        let output = burn_tensor::module::embedding(self.weight(), input);
+
+        // The lookup doesn't compute anything.
+        trace_layer(&self.weight.id, &output, || 0);
+
+        output
+    }
+
+    /// Computes the dot product of the input with each embedding vector.
+    ///
+    /// This is the reverse of the lookup done in the forward pass, so an output layer can share
+    /// its weight with the embedding, e.g. a language model head tied to the token embedding.
+    /// The weight isn't duplicated, so it receives the gradients of both usages and stays the same
+    /// parameter when saving, loading and optimizing the module. See [TiedLinear](super::TiedLinear)
+    /// for an output layer with a bias.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, n_embedding]`
+    pub fn attend<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        input.matmul(self.weight().transpose().unsqueeze())
+    }
+
+    /// The weight used in the forward pass, where the padding vector doesn't receive any gradient.
+    fn weight(&self) -> Tensor<B, 2> {
+        let weight = self.weight.val();
+
+        match self.padding_idx {
+            Some(padding_idx) => {
+                let [_, d_model] = weight.dims();
+                let slices = [padding_idx..padding_idx + 1, 0..d_model];
+                let padding = weight.clone().slice(slices.clone()).detach();
+
+                weight.slice_assign(slices, padding)
+            }
+            None => weight,
+        }
+    }
+
+    /// The index of the padding vector, if any.
+    pub fn padding_idx(&self) -> Option<usize> {
+        self.padding_idx
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 2, Int>> for Embedding<B> {
+    type Output = Tensor<B, 3>;
+
+    fn forward(&self, input: Tensor<B, 2, Int>) -> Self::Output {
+        Embedding::forward(self, input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
+    use crate::{TestAutodiffBackend, TestBackend};
     use burn_tensor::Data;
 
     #[test]
@@ -104,4 +173,47 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(embed.weight.shape()), 3);
     }
+
+    #[test]
+    fn padding_vector_should_be_zeros() {
+        TestBackend::seed(0);
+
+        let embed = EmbeddingConfig::new(4, 3)
+            .with_padding_idx(Some(1))
+            .init::<TestBackend>();
+        let output = embed.forward(Tensor::from_ints([[1, 1]]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::zeros([1, 2, 3]), 3);
+    }
+
+    #[test]
+    fn padding_vector_should_not_receive_gradients() {
+        TestAutodiffBackend::seed(0);
+
+        let embed = EmbeddingConfig::new(4, 2)
+            .with_padding_idx(Some(0))
+            .init::<TestAutodiffBackend>();
+        let output = embed.forward(Tensor::from_ints([[0, 2, 0, 3]]));
+        let grads = output.sum().backward();
+
+        let grad = embed.weight.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(
+            &Data::from([[0.0, 0.0], [0.0, 0.0], [1.0, 1.0], [1.0, 1.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn attend_should_compute_dot_product_with_each_vector() {
+        let mut embed = EmbeddingConfig::new(3, 2).init::<TestBackend>();
+        embed.weight = Param::from(Tensor::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 2.0]]));
+
+        let output = embed.attend(Tensor::from_floats([[[2.0, 3.0]]]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[2.0, 3.0, 8.0]]]), 3);
+    }
 }
@@ -0,0 +1,276 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use super::{Embedding, EmbeddingConfig, Initializer};
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Data, Int, Tensor};
+
+/// The reduction applied over the vectors of each bag of an [EmbeddingBag](EmbeddingBag).
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// Sum of the vectors.
+    Sum,
+    /// Mean of the vectors.
+    Mean,
+    /// Element-wise maximum of the vectors.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer.
+#[derive(Config)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The reduction applied over each bag. Default: Mean
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+    /// If specified, the vector at this index is initialized with zeros, isn't updated during
+    /// training and is excluded from the reduction.
+    pub padding_idx: Option<usize>,
+}
+
+/// Computes the sum, mean or maximum of bags of embedding vectors, without instantiating the
+/// intermediate embeddings of each bag separately.
+///
+/// Empty bags, or bags only containing the padding index, produce vectors of zeros.
+///
+/// # Params
+///
+/// - embedding: The lookup table of shape `[n_embedding, d_model]`.
+#[derive(Module, Debug)]
+pub struct EmbeddingBag<B: Backend> {
+    embedding: Embedding<B>,
+    mode: EmbeddingBagMode,
+    d_model: usize,
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self) -> EmbeddingBag<B> {
+        EmbeddingBag {
+            embedding: self.embedding_config().init(),
+            mode: self.mode.clone(),
+            d_model: self.d_model,
+        }
+    }
+
+    /// Initialize a new [embedding bag](EmbeddingBag) module with a [record](EmbeddingBagRecord).
+    pub fn init_with<B: Backend>(&self, record: EmbeddingBagRecord<B>) -> EmbeddingBag<B> {
+        EmbeddingBag {
+            embedding: self.embedding_config().init_with(record.embedding),
+            mode: self.mode.clone(),
+            d_model: self.d_model,
+        }
+    }
+
+    fn embedding_config(&self) -> EmbeddingConfig {
+        EmbeddingConfig::new(self.n_embedding, self.d_model)
+            .with_initializer(self.initializer.clone())
+            .with_padding_idx(self.padding_idx)
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// Applies the forward pass on bags of the same size.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[n_bags, bag_size]`
+    /// - output: `[n_bags, d_model]`
+    pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+        let [n_bags, bag_size] = input.dims();
+        let mask = Tensor::<B, 2>::ones_device([n_bags, bag_size], &input.device()).equal_elem(1);
+
+        self.forward_masked(input, mask)
+    }
+
+    /// Applies the forward pass on bags of variable sizes.
+    ///
+    /// The indices of all bags are concatenated, and the bag `i` contains the indices from
+    /// `offsets[i]` (inclusive) to `offsets[i + 1]` (exclusive), the last bag ending with the
+    /// input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[n_indices]`
+    /// - offsets: `[n_bags]`
+    /// - output: `[n_bags, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the offsets aren't sorted, or if an offset is greater than the number of indices.
+    pub fn forward_offsets(
+        &self,
+        input: Tensor<B, 1, Int>,
+        offsets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 2> {
+        let [n_indices] = input.dims();
+        let device = input.device();
+
+        let mut starts = offsets
+            .into_data()
+            .convert::<i64>()
+            .value
+            .into_iter()
+            .map(|offset| offset as usize)
+            .collect::<Vec<_>>();
+        let n_bags = starts.len();
+        starts.push(n_indices);
+
+        let bag_sizes = starts
+            .windows(2)
+            .map(|window| {
+                assert!(
+                    window[0] <= window[1] && window[1] <= n_indices,
+                    "The offsets must be sorted and smaller or equal than the number of \
+                    indices ({n_indices}), got {:?}",
+                    &starts[..n_bags]
+                );
+                window[1] - window[0]
+            })
+            .collect::<Vec<_>>();
+        let bag_size = bag_sizes.iter().copied().max().unwrap_or(0).max(1);
+
+        // Each bag is padded to the size of the largest one, the padded positions being masked.
+        let mut positions = Vec::with_capacity(n_bags * bag_size);
+        let mut mask = Vec::with_capacity(n_bags * bag_size);
+        for (start, size) in starts.iter().zip(bag_sizes.iter()) {
+            for i in 0..bag_size {
+                positions.push(if i < *size { (start + i) as i64 } else { 0 });
+                mask.push(i < *size);
+            }
+        }
+
+        if n_indices == 0 {
+            return Tensor::zeros_device([n_bags, self.d_model], &device);
+        }
+
+        let positions = Tensor::<B, 1, Int>::from_data_device(
+            Data::new(positions, [n_bags * bag_size].into()).convert(),
+            &device,
+        );
+        let mask = Tensor::<B, 2, Bool>::from_bool_device(
+            Data::new(mask, [n_bags, bag_size].into()),
+            &device,
+        );
+        let input = input.select(0, positions).reshape([n_bags, bag_size]);
+
+        self.forward_masked(input, mask)
+    }
+
+    fn forward_masked(&self, input: Tensor<B, 2, Int>, mask: Tensor<B, 2, Bool>) -> Tensor<B, 2> {
+        let [n_bags, bag_size] = input.dims();
+        let d_model = self.d_model;
+
+        let mut mask = mask.float();
+        if let Some(padding_idx) = self.embedding.padding_idx() {
+            mask = mask.mask_fill(input.clone().equal_elem(padding_idx as i64), 0.0);
+        }
+
+        let embeddings = self.embedding.forward(input);
+        let mask = mask.reshape([n_bags, bag_size, 1]);
+        let counts = mask.clone().sum_dim(1).reshape([n_bags, 1]);
+
+        match self.mode {
+            EmbeddingBagMode::Sum => embeddings.mul(mask).sum_dim(1).reshape([n_bags, d_model]),
+            EmbeddingBagMode::Mean => embeddings
+                .mul(mask)
+                .sum_dim(1)
+                .reshape([n_bags, d_model])
+                .div(counts.clamp_min(1.0)),
+            EmbeddingBagMode::Max => {
+                let excluded = mask.repeat(2, d_model).equal_elem(0.0);
+                let empty = counts.repeat(1, d_model).equal_elem(0.0);
+
+                embeddings
+                    .mask_fill(excluded, f32::NEG_INFINITY)
+                    .max_dim(1)
+                    .reshape([n_bags, d_model])
+                    .mask_fill(empty, 0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{ConstantRecord, Param};
+    use crate::nn::EmbeddingRecord;
+    use crate::TestBackend;
+
+    fn embedding_bag(
+        mode: EmbeddingBagMode,
+        padding_idx: Option<usize>,
+    ) -> EmbeddingBag<TestBackend> {
+        let config = EmbeddingBagConfig::new(4, 2)
+            .with_mode(mode)
+            .with_padding_idx(padding_idx);
+        let weight = Tensor::from_floats([[1.0, -1.0], [2.0, 0.0], [-3.0, 4.0], [0.5, 5.0]]);
+        let mut embedding_bag = config.init::<TestBackend>();
+        embedding_bag.embedding = embedding_bag.embedding.load_record(EmbeddingRecord {
+            weight: Param::from(weight),
+            padding_idx: Some(ConstantRecord),
+        });
+
+        embedding_bag
+    }
+
+    #[test]
+    fn forward_sum() {
+        let embedding_bag = embedding_bag(EmbeddingBagMode::Sum, None);
+
+        let output = embedding_bag.forward(Tensor::from_ints([[0, 2], [1, 1]]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-2.0, 3.0], [4.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn forward_offsets_mean_with_empty_bag() {
+        let embedding_bag = embedding_bag(EmbeddingBagMode::Mean, None);
+
+        let output = embedding_bag.forward_offsets(
+            Tensor::from_ints([0, 2, 3, 1]),
+            Tensor::from_ints([0, 3, 3]),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.5, 8.0 / 3.0], [0.0, 0.0], [2.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn forward_offsets_max() {
+        let embedding_bag = embedding_bag(EmbeddingBagMode::Max, None);
+
+        let output = embedding_bag.forward_offsets(
+            Tensor::from_ints([0, 2, 3, 1]),
+            Tensor::from_ints([0, 1, 1]),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -1.0], [0.0, 0.0], [2.0, 5.0]]), 3);
+    }
+
+    #[test]
+    fn padding_idx_should_be_excluded_from_the_reduction() {
+        let embedding_bag = embedding_bag(EmbeddingBagMode::Mean, Some(0));
+
+        let output = embedding_bag.forward(Tensor::from_ints([[0, 2, 3], [0, 0, 0]]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.25, 4.5], [0.0, 0.0]]), 3);
+    }
+}
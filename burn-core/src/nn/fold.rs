@@ -0,0 +1,64 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use burn_tensor::backend::Backend;
+use burn_tensor::module::fold4d;
+use burn_tensor::ops::UnfoldOptions;
+use burn_tensor::Tensor;
+
+/// Configuration to create a [fold 4D](Fold4d) layer.
+#[derive(Config, Debug)]
+pub struct Fold4dConfig {
+    /// The spatial size of the output.
+    pub output_size: [usize; 2],
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// The padding configuration.
+    #[config(default = "[0, 0]")]
+    pub padding: [usize; 2],
+}
+
+/// Four-dimensional folding, combining sliding blocks into a tensor.
+///
+/// This is the inverse of [unfold 4D](crate::nn::Unfold4d), the overlapping values are summed.
+#[derive(Module, Clone, Debug)]
+pub struct Fold4d {
+    config: Fold4dConfig,
+}
+
+impl Fold4dConfig {
+    /// Initialize a new [fold 4d](Fold4d) module.
+    pub fn init(&self) -> Fold4d {
+        Fold4d {
+            config: self.clone(),
+        }
+    }
+}
+
+impl Fold4d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// input:   `[batch_size, channels_in * kernel_size_1 * kernel_size_2, number of blocks]`,
+    /// returns: `[batch_size, channels_in, output_size_1, output_size_2]`,
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+        fold4d(
+            input,
+            self.config.output_size,
+            self.config.kernel_size,
+            UnfoldOptions::new(
+                self.config.stride,
+                self.config.padding,
+                self.config.dilation,
+            ),
+        )
+    }
+}
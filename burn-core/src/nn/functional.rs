@@ -0,0 +1,349 @@
+//! Stateless versions of the neural network layers, taking their weights as explicit tensors.
+//!
+//! This is useful when the weights aren't owned by a module, e.g. when they are generated by a
+//! hypernetwork or adapted in the inner loop of meta-learning algorithms.
+
+use crate::nn::loss::{
+    BinaryCrossEntropyWithLogitsLoss, CrossEntropyLoss, HuberLossConfig, MSELoss, Reduction,
+};
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+
+pub use burn_tensor::activation::{
+    gelu, log_sigmoid, log_softmax, relu, sigmoid, silu, softmax, tanh,
+};
+pub use burn_tensor::module::{
+    adaptive_avg_pool1d, adaptive_avg_pool2d, adaptive_max_pool2d, avg_pool1d, avg_pool2d, conv1d,
+    conv2d, conv3d, conv_transpose1d, conv_transpose2d, conv_transpose3d, embedding, interpolate,
+    max_pool1d, max_pool2d,
+};
+
+/// Applies a linear transformation to the input tensor: `O = IW + b`.
+///
+/// # Shapes
+///
+/// - input: `[..., any, d_input]`
+/// - weight: `[d_input, d_output]`
+/// - bias: `[d_output]`
+/// - output: `[..., any, d_output]`
+pub fn linear<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    weight: Tensor<B, 2>,
+    bias: Option<Tensor<B, 1>>,
+) -> Tensor<B, D> {
+    let output = input.matmul(weight.unsqueeze());
+
+    match bias {
+        Some(bias) => output + bias.unsqueeze(),
+        None => output,
+    }
+}
+
+/// Applies Layer Normalization over the last dimension of the input tensor, like the
+/// [layer norm](crate::nn::LayerNorm) module.
+///
+/// # Shapes
+///
+/// - input: `[..., any, d_model]`
+/// - gamma: `[d_model]`
+/// - beta: `[d_model]`
+/// - output: `[..., any, d_model]`
+pub fn layer_norm<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    let (var, mean) = input.clone().var_mean_bias(D - 1);
+    let output = input.sub(mean).div(var.sqrt().add_scalar(epsilon));
+
+    affine(output, gamma, beta, D - 1)
+}
+
+/// Applies Batch Normalization over the channel dimension of the input tensor.
+///
+/// When the running statistics are provided, they are used to normalize the input, like the
+/// [batch norm](crate::nn::BatchNorm) module during inference. Otherwise, the statistics of the
+/// batch are used.
+///
+/// # Shapes
+///
+/// - input: `[batch_size, channels, ...]`
+/// - running_stats: `([channels], [channels])` for the mean and the variance
+/// - gamma: `[channels]`
+/// - beta: `[channels]`
+/// - output: `[batch_size, channels, ...]`
+pub fn batch_norm<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    running_stats: Option<(Tensor<B, 1>, Tensor<B, 1>)>,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    let dims = input.dims();
+    let channels = dims[1];
+    let mut shape = [1; D];
+    shape[1] = channels;
+
+    let (mean, var) = match running_stats {
+        Some((mean, var)) => (mean.reshape(shape), var.reshape(shape)),
+        None => {
+            let flatten_size = dims.iter().product::<usize>() / channels;
+            let (var, mean) = input
+                .clone()
+                .swap_dims(0, 1)
+                .reshape([channels, flatten_size])
+                .var_mean_bias(1);
+
+            (mean.reshape(shape), var.reshape(shape))
+        }
+    };
+
+    let output = input.sub(mean).div(var.add_scalar(epsilon).sqrt());
+
+    affine(output, gamma, beta, 1)
+}
+
+/// Applies Group Normalization over the input tensor, the channels being separated into
+/// `num_groups` groups normalized independently.
+///
+/// # Shapes
+///
+/// - input: `[batch_size, channels, ...]`
+/// - gamma: `[channels]`
+/// - beta: `[channels]`
+/// - output: `[batch_size, channels, ...]`
+pub fn group_norm<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    num_groups: usize,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    let shape = input.shape();
+    let batch_size = shape.dims[0];
+    let channels = shape.dims[1];
+
+    assert!(
+        channels % num_groups == 0,
+        "The number of channels ({channels}) should be divisible by the number of groups ({num_groups})."
+    );
+
+    let hidden_size = shape.num_elements() / (batch_size * num_groups);
+    let input = input.reshape([batch_size, num_groups, hidden_size]);
+
+    let (var, mean) = input.clone().var_mean_bias(2);
+    let output = input
+        .sub(mean)
+        .div(var.add_scalar(epsilon).sqrt())
+        .reshape(shape);
+
+    affine(output, gamma, beta, 1)
+}
+
+/// Computes the scaled dot-product attention `softmax(QK^T / sqrt(d_k))V`, as in the
+/// [multi-head attention](crate::nn::attention::MultiHeadAttention) module.
+///
+/// The attention scores where the mask is `true` are replaced with `min_float` before the
+/// softmax.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, n_heads, seq_length_1, d_k]`
+/// - key: `[batch_size, n_heads, seq_length_2, d_k]`
+/// - value: `[batch_size, n_heads, seq_length_2, d_v]`
+/// - mask: `[batch_size, seq_length_1, seq_length_2]`
+/// - output: `[batch_size, n_heads, seq_length_1, d_v]`
+pub fn scaled_dot_product_attention<B: Backend>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 3, Bool>>,
+    min_float: f64,
+) -> Tensor<B, 4> {
+    burn_tensor::module::attention(query, key, value, mask, min_float)
+}
+
+/// Computes the cross entropy loss between the logits and the target classes, see
+/// [CrossEntropyLoss](CrossEntropyLoss).
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, num_targets]`
+/// - targets: `[batch_size]`
+pub fn cross_entropy<B: Backend>(logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+    CrossEntropyLoss::new(None).forward(logits, targets)
+}
+
+/// Computes the binary cross entropy loss between the logits and the targets, see
+/// [BinaryCrossEntropyWithLogitsLoss](BinaryCrossEntropyWithLogitsLoss).
+pub fn binary_cross_entropy_with_logits<B: Backend, const D: usize>(
+    logits: Tensor<B, D>,
+    targets: Tensor<B, D>,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    BinaryCrossEntropyWithLogitsLoss::default().forward(logits, targets, reduction)
+}
+
+/// Computes the mean squared error loss between the predictions and the targets, see
+/// [MSELoss](MSELoss).
+pub fn mse_loss<B: Backend, const D: usize>(
+    predictions: Tensor<B, D>,
+    targets: Tensor<B, D>,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    MSELoss::new().forward(predictions, targets, reduction)
+}
+
+/// Computes the Huber loss between the predictions and the targets, see
+/// [HuberLoss](crate::nn::loss::HuberLoss).
+pub fn huber_loss<B: Backend, const D: usize>(
+    predictions: Tensor<B, D>,
+    targets: Tensor<B, D>,
+    delta: f32,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    HuberLossConfig::new()
+        .with_delta(delta)
+        .init()
+        .forward(predictions, targets, reduction)
+}
+
+/// Scales and shifts the normalized tensor along the given dimension.
+fn affine<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    dim: usize,
+) -> Tensor<B, D> {
+    let mut shape = [1; D];
+    shape[dim] = tensor.dims()[dim];
+
+    let tensor = match gamma {
+        Some(gamma) => tensor.mul(gamma.reshape(shape)),
+        None => tensor,
+    };
+
+    match beta {
+        Some(beta) => tensor.add(beta.reshape(shape)),
+        None => tensor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::attention::generate_autoregressive_mask;
+    use crate::nn::{LayerNormConfig, LinearConfig};
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn linear_should_match_the_module() {
+        let module = LinearConfig::new(4, 3).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default);
+
+        let output = linear(
+            input.clone(),
+            module.weight.val(),
+            module.bias.as_ref().map(|bias| bias.val()),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&module.forward(input).into_data(), 4);
+    }
+
+    #[test]
+    fn layer_norm_should_match_the_module() {
+        let module = LayerNormConfig::new(4).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default);
+
+        let output = layer_norm(
+            input.clone(),
+            Some(Tensor::ones([4])),
+            Some(Tensor::zeros([4])),
+            1e-5,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&module.forward(input).into_data(), 4);
+    }
+
+    #[test]
+    fn batch_norm_should_normalize_each_channel() {
+        let input = Tensor::<TestBackend, 3>::random([4, 2, 5], Distribution::Default);
+
+        let output = batch_norm(input, None, None, None, 1e-5);
+        let [_, channels, _] = output.dims();
+        let output = output.swap_dims(0, 1).reshape([channels, 20]);
+        let (var, mean) = output.var_mean_bias(1);
+
+        mean.into_data().assert_approx_eq(&Data::zeros([2, 1]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 2);
+    }
+
+    #[test]
+    fn batch_norm_should_use_the_running_stats() {
+        let input = Tensor::<TestBackend, 4>::ones([2, 2, 3, 3]);
+        let running_stats = (
+            Tensor::from_floats([1.0, 0.0]),
+            Tensor::from_floats([4.0, 1.0]),
+        );
+
+        let output = batch_norm(input, Some(running_stats), None, None, 0.0);
+
+        output
+            .mean_dim(2)
+            .mean_dim(3)
+            .reshape([2, 2])
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0], [0.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn group_norm_should_normalize_each_group() {
+        let input = Tensor::<TestBackend, 3>::random([2, 4, 3], Distribution::Default);
+
+        let output = group_norm(input, 2, None, None, 1e-5);
+        let (var, mean) = output.reshape([2, 2, 6]).var_mean_bias(2);
+
+        mean.into_data()
+            .assert_approx_eq(&Data::zeros([2, 2, 1]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[[1.0], [1.0]], [[1.0], [1.0]]]), 2);
+    }
+
+    #[test]
+    fn attention_should_not_attend_to_masked_positions() {
+        let query = Tensor::<TestBackend, 4>::random([2, 3, 4, 8], Distribution::Default);
+        let key = Tensor::<TestBackend, 4>::random([2, 3, 4, 8], Distribution::Default);
+        let value = Tensor::<TestBackend, 4>::random([2, 3, 4, 5], Distribution::Default);
+        let mask = generate_autoregressive_mask::<TestBackend>(2, 4, &Default::default());
+
+        let output = scaled_dot_product_attention(query, key, value.clone(), Some(mask), -1.0e4);
+
+        assert_eq!(output.dims(), [2, 3, 4, 5]);
+        // The first position can only attend to itself.
+        output
+            .slice([0..2, 0..3, 0..1])
+            .into_data()
+            .assert_approx_eq(&value.slice([0..2, 0..3, 0..1]).into_data(), 3);
+    }
+
+    #[test]
+    fn cross_entropy_should_match_the_module() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 3], Distribution::Default);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 1, 2]);
+
+        let loss = cross_entropy(logits.clone(), targets.clone());
+
+        loss.into_data().assert_approx_eq(
+            &CrossEntropyLoss::new(None)
+                .forward(logits, targets)
+                .into_data(),
+            4,
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::module::Module;
+use crate::nn::Forward;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -24,3 +25,11 @@ impl GELU {
         crate::tensor::activation::gelu(input)
     }
 }
+
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for GELU {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        GELU::forward(self, input)
+    }
+}
@@ -0,0 +1,286 @@
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    container::TensorContainer,
+    Tensor,
+};
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+/// A module with a single input forward pass.
+///
+/// This allows generic wrappers such as [Hooked](Hooked) to call the forward pass of the modules
+/// they wrap.
+pub trait Forward<I> {
+    /// The output of the forward pass.
+    type Output;
+
+    /// Applies the forward pass on the input.
+    fn forward(&self, input: I) -> Self::Output;
+}
+
+/// Thread safe storage of named activations, filled by [hooked](Hooked) modules during their
+/// forward pass.
+///
+/// The recorder can be cloned and shared between many modules, all clones pointing to the same
+/// storage. When the backend has autodiff enabled, the recorded activations are part of the
+/// autodiff graph, so their gradients can be retrieved after the backward pass, e.g. for
+/// Grad-CAM.
+pub struct ActivationRecorder<B: Backend> {
+    activations: Arc<spin::Mutex<TensorContainer<String>>>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> ActivationRecorder<B> {
+    /// Create a new empty recorder.
+    pub fn new() -> Self {
+        Self {
+            activations: Arc::new(spin::Mutex::new(TensorContainer::new())),
+            backend: PhantomData,
+        }
+    }
+
+    /// Record an activation under the given name, replacing the previous one if any.
+    pub fn record<const D: usize>(&self, name: &str, activation: Tensor<B, D>) {
+        self.activations
+            .lock()
+            .register(name.to_string(), activation);
+    }
+
+    /// Get the activation recorded under the given name.
+    ///
+    /// # Panics
+    ///
+    /// If the recorded activation doesn't have `D` dimensions.
+    pub fn get<const D: usize>(&self, name: &str) -> Option<Tensor<B, D>> {
+        self.activations.lock().get(&name.to_string())
+    }
+
+    /// Remove the activation recorded under the given name and return it.
+    ///
+    /// # Panics
+    ///
+    /// If the recorded activation doesn't have `D` dimensions.
+    pub fn remove<const D: usize>(&self, name: &str) -> Option<Tensor<B, D>> {
+        self.activations.lock().remove(&name.to_string())
+    }
+
+    /// Remove all the recorded activations.
+    pub fn clear(&self) {
+        *self.activations.lock() = TensorContainer::new();
+    }
+
+    /// The number of recorded activations.
+    pub fn len(&self) -> usize {
+        self.activations.lock().len()
+    }
+
+    /// If no activation is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<B: Backend> Default for ActivationRecorder<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> Clone for ActivationRecorder<B> {
+    fn clone(&self) -> Self {
+        Self {
+            activations: self.activations.clone(),
+            backend: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> core::fmt::Debug for ActivationRecorder<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ActivationRecorder")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Wraps a module to capture its output during the forward pass, without changing the forward
+/// methods of the modules using it.
+///
+/// The wrapper is transparent for parameters and records, so it can be added to an existing
+/// model without invalidating its checkpoints.
+///
+/// # Example
+///
+/// ```ignore
+/// let recorder = ActivationRecorder::new();
+/// model.conv.register_recorder("conv", &recorder);
+///
+/// let output = model.forward(input);
+/// let features = recorder.get::<4>("conv").unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hooked<B: Backend, M> {
+    module: M,
+    hook: Option<(String, ActivationRecorder<B>)>,
+}
+
+impl<B: Backend, M> Hooked<B, M> {
+    /// Wraps the module, without any recorder registered.
+    pub fn new(module: M) -> Self {
+        Self { module, hook: None }
+    }
+
+    /// Register a recorder, storing the output of each forward pass under the given name.
+    ///
+    /// It replaces the previously registered recorder, if any.
+    pub fn register_recorder(&mut self, name: &str, recorder: &ActivationRecorder<B>) {
+        self.hook = Some((name.to_string(), recorder.clone()));
+    }
+
+    /// Remove the registered recorder, if any.
+    pub fn remove_recorder(&mut self) {
+        self.hook = None;
+    }
+
+    /// Returns the wrapped module.
+    pub fn inner(&self) -> &M {
+        &self.module
+    }
+
+    /// Consumes the wrapper, returning the wrapped module.
+    pub fn into_inner(self) -> M {
+        self.module
+    }
+
+    /// Applies the forward pass of the wrapped module, recording its output.
+    pub fn forward<I, const D: usize>(&self, input: I) -> Tensor<B, D>
+    where
+        M: Forward<I, Output = Tensor<B, D>>,
+    {
+        let output = self.module.forward(input);
+
+        if let Some((name, recorder)) = &self.hook {
+            recorder.record(name, output.clone());
+        }
+
+        output
+    }
+}
+
+impl<B, M> Module<B> for Hooked<B, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    type Record = M::Record;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.module.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.fork(device),
+            hook: self.hook,
+        }
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.to_device(device),
+            hook: self.hook,
+        }
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.module.visit(visitor);
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        Self {
+            module: self.module.map(mapper),
+            hook: self.hook,
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self {
+            module: self.module.load_record(record),
+            hook: self.hook,
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.module.into_record()
+    }
+}
+
+impl<B, M> AutodiffModule<B> for Hooked<B, M>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    type InnerModule = Hooked<B::InnerBackend, M::InnerModule>;
+
+    /// The registered recorder is not kept, since it stores activations of another backend.
+    fn valid(&self) -> Self::InnerModule {
+        Hooked::new(self.module.valid())
+    }
+}
+
+impl<B: Backend, M: core::fmt::Display> core::fmt::Display for Hooked<B, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Hooked[{}]", self.module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::list_param_ids;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::{LinearConfig, ReLU};
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn should_record_the_output_of_the_forward_pass() {
+        let recorder = ActivationRecorder::new();
+        let mut conv = Hooked::new(Conv2dConfig::new([2, 3], [3, 3]).init::<TestBackend>());
+        let mut relu = Hooked::new(ReLU::new());
+        conv.register_recorder("conv", &recorder);
+        relu.register_recorder("relu", &recorder);
+
+        let input = Tensor::random([1, 2, 5, 5], Distribution::Default);
+        let output = relu.forward(conv.forward(input));
+
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(recorder.get::<4>("conv").unwrap().dims(), [1, 3, 3, 3]);
+        output
+            .to_data()
+            .assert_approx_eq(&recorder.get::<4>("relu").unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn should_not_record_once_the_recorder_is_removed() {
+        let recorder = ActivationRecorder::new();
+        let mut linear = Hooked::new(LinearConfig::new(4, 2).init::<TestBackend>());
+        linear.register_recorder("linear", &recorder);
+        linear.remove_recorder();
+
+        linear.forward(Tensor::<TestBackend, 2>::ones([3, 4]));
+
+        assert!(recorder.is_empty());
+    }
+
+    #[test]
+    fn should_be_transparent_for_params() {
+        let linear = LinearConfig::new(4, 2).init::<TestBackend>();
+        let hooked = Hooked::new(linear.clone());
+
+        assert_eq!(list_param_ids(&linear), list_param_ids(&hooked));
+        assert_eq!(linear.num_params(), hooked.num_params());
+    }
+}
@@ -0,0 +1,175 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::interpolate;
+use burn_tensor::ops::InterpolateOptions;
+
+/// The interpolation method used by the [Interpolate](Interpolate) layer.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum InterpolateMode {
+    /// Takes the value of the nearest input element.
+    Nearest,
+    /// Linear interpolation between the two nearest input elements along each axis.
+    Bilinear,
+    /// Cubic convolution of the four nearest input elements along each axis.
+    Bicubic,
+}
+
+impl From<InterpolateMode> for burn_tensor::ops::InterpolateMode {
+    fn from(mode: InterpolateMode) -> Self {
+        match mode {
+            InterpolateMode::Nearest => Self::Nearest,
+            InterpolateMode::Bilinear => Self::Bilinear,
+            InterpolateMode::Bicubic => Self::Bicubic,
+        }
+    }
+}
+
+/// Configuration to create an [Interpolate](Interpolate) layer.
+///
+/// Exactly one of `output_size` and `scale_factor` must be provided.
+#[derive(Config, Debug)]
+pub struct InterpolateConfig {
+    /// The size of the output.
+    pub output_size: Option<[usize; 2]>,
+    /// The multiplier applied to the input height and width, the output size being rounded down.
+    pub scale_factor: Option<[f32; 2]>,
+    /// The interpolation method. Default: Nearest
+    #[config(default = "InterpolateMode::Nearest")]
+    pub mode: InterpolateMode,
+    /// If true, the centers of the corner elements of the input and output are aligned. Ignored
+    /// by the nearest mode. Default: false
+    #[config(default = false)]
+    pub align_corners: bool,
+}
+
+/// Resizes the height and width of the input, also known as upsampling when the output is bigger.
+#[derive(Module, Clone, Debug)]
+pub struct Interpolate {
+    output_size: Option<[usize; 2]>,
+    scale_factor: Option<[f32; 2]>,
+    mode: InterpolateMode,
+    align_corners: bool,
+}
+
+impl InterpolateConfig {
+    /// Initialize a new [interpolate](Interpolate) module.
+    ///
+    /// # Panics
+    ///
+    /// If both or none of `output_size` and `scale_factor` are provided, or if a scale factor
+    /// isn't positive.
+    pub fn init(&self) -> Interpolate {
+        assert!(
+            self.output_size.is_some() != self.scale_factor.is_some(),
+            "Exactly one of the output size ({:?}) and the scale factor ({:?}) must be provided",
+            self.output_size,
+            self.scale_factor
+        );
+        if let Some(scale_factor) = self.scale_factor {
+            assert!(
+                scale_factor.iter().all(|scale| *scale > 0.0),
+                "The scale factor must be positive, got {scale_factor:?}"
+            );
+        }
+
+        Interpolate {
+            output_size: self.output_size,
+            scale_factor: self.scale_factor,
+            mode: self.mode.clone(),
+            align_corners: self.align_corners,
+        }
+    }
+}
+
+impl Interpolate {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height_in, width_in]`
+    /// - output: `[batch_size, channels, height_out, width_out]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_, _, height, width] = input.dims();
+        let output_size = self.output_size([height, width]);
+        let options = InterpolateOptions::new(self.mode.clone().into(), self.align_corners);
+
+        interpolate(input, output_size, options)
+    }
+
+    fn output_size(&self, input_size: [usize; 2]) -> [usize; 2] {
+        match (self.output_size, self.scale_factor) {
+            (Some(output_size), _) => output_size,
+            (None, Some([scale_height, scale_width])) => [
+                (input_size[0] as f32 * scale_height) as usize,
+                (input_size[1] as f32 * scale_width) as usize,
+            ],
+            (None, None) => input_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn scale_factor_should_multiply_the_input_size() {
+        let interpolate = InterpolateConfig::new()
+            .with_scale_factor(Some([2.0, 1.5]))
+            .init();
+        let input = Tensor::<TestBackend, 4>::zeros([2, 3, 4, 5]);
+
+        let output = interpolate.forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 8, 7]);
+    }
+
+    #[test]
+    fn nearest_upsampling_should_repeat_the_values() {
+        let interpolate = InterpolateConfig::new()
+            .with_scale_factor(Some([2.0, 2.0]))
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = interpolate.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn bilinear_with_output_size() {
+        let interpolate = InterpolateConfig::new()
+            .with_output_size(Some([3, 3]))
+            .with_mode(InterpolateMode::Bilinear)
+            .with_align_corners(true)
+            .init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = interpolate.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic = "Exactly one of the output size"]
+    fn size_or_scale_factor_should_be_provided() {
+        InterpolateConfig::new().init();
+    }
+}
@@ -0,0 +1,470 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::nn::conv::{Conv1d, Conv1dConfig, Conv2d, Conv2dConfig};
+use crate::nn::{Forward, Initializer, Linear, LinearConfig, PaddingConfig1d, PaddingConfig2d};
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+use libm::sqrt;
+
+/// Configuration of a module whose input dimension is only known on its first forward pass.
+pub trait LazyConfig: Config + Clone + Send + Sync + core::fmt::Debug {
+    /// The module created once the input dimension is known.
+    type Module<B: Backend>: Module<B>;
+
+    /// Initialize the module for the given input dimension.
+    fn init_with_input<B: Backend>(&self, d_input: usize) -> Self::Module<B>;
+
+    /// Initialize the module from a record, the input dimension being inferred from its weight.
+    fn init_with_record<B: Backend>(
+        &self,
+        record: <Self::Module<B> as Module<B>>::Record,
+    ) -> Self::Module<B>;
+}
+
+/// A module whose parameters are created on its first forward pass, when the input dimension is
+/// known.
+///
+/// This is useful when the input dimension is tedious to compute by hand, e.g. the number of
+/// features after flattening the output of convolutional layers.
+///
+/// # Notes
+///
+/// The module doesn't have any parameter before its first forward pass, so it should be called
+/// once with a dummy input before being used with an optimizer or any other utility working on
+/// its parameters, such as [freeze_matching](Module::freeze_matching). Loading a record also
+/// creates the parameters.
+#[derive(Debug)]
+pub struct Lazy<B: Backend, C: LazyConfig> {
+    config: C,
+    module: spin::Mutex<Option<C::Module<B>>>,
+}
+
+/// [Linear](Linear) layer inferring its input dimension on its first forward pass.
+pub type LazyLinear<B> = Lazy<B, LazyLinearConfig>;
+
+/// [1D convolution](Conv1d) layer inferring its input channels on its first forward pass.
+pub type LazyConv1d<B> = Lazy<B, LazyConv1dConfig>;
+
+/// [2D convolution](Conv2d) layer inferring its input channels on its first forward pass.
+pub type LazyConv2d<B> = Lazy<B, LazyConv2dConfig>;
+
+impl<B: Backend, C: LazyConfig> Lazy<B, C> {
+    /// Create a new lazy module, without any parameter.
+    pub fn new(config: C) -> Self {
+        Self {
+            config,
+            module: spin::Mutex::new(None),
+        }
+    }
+
+    /// If the parameters of the module are created.
+    pub fn is_initialized(&self) -> bool {
+        self.module.lock().is_some()
+    }
+
+    /// Returns the module, if it is initialized.
+    pub fn module(&self) -> Option<C::Module<B>> {
+        self.module.lock().clone()
+    }
+
+    /// Returns the module, creating it on the given device if it isn't initialized yet.
+    fn get_or_init(&self, d_input: usize, device: &B::Device) -> C::Module<B> {
+        let mut module = self.module.lock();
+
+        match module.as_ref() {
+            Some(module) => module.clone(),
+            None => {
+                let initialized = self.config.init_with_input::<B>(d_input).fork(device);
+                *module = Some(initialized.clone());
+                initialized
+            }
+        }
+    }
+
+    fn map_module<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Option<C::Module<B>>) -> Option<C::Module<B>>,
+    {
+        Self {
+            module: spin::Mutex::new(func(self.module.into_inner())),
+            config: self.config,
+        }
+    }
+}
+
+impl<B: Backend, C: LazyConfig> Clone for Lazy<B, C> {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            module: spin::Mutex::new(self.module.lock().clone()),
+        }
+    }
+}
+
+impl<B: Backend, C: LazyConfig> Module<B> for Lazy<B, C> {
+    type Record = Option<<C::Module<B> as Module<B>>::Record>;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.module.lock().collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.map_module(|module| module.fork(device))
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        self.map_module(|module| module.to_device(device))
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.module.lock().visit(visitor);
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        self.map_module(|module| Module::<B>::map(module, mapper))
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let config = self.config.clone();
+
+        self.map_module(|module| match (module, record) {
+            (Some(module), Some(record)) => Some(module.load_record(record)),
+            (None, Some(record)) => Some(config.init_with_record::<B>(record)),
+            (module, None) => module,
+        })
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.module.into_inner().map(Module::into_record)
+    }
+}
+
+impl<B, C> AutodiffModule<B> for Lazy<B, C>
+where
+    B: AutodiffBackend,
+    C: LazyConfig,
+    C::Module<B>: AutodiffModule<B, InnerModule = C::Module<B::InnerBackend>>,
+{
+    type InnerModule = Lazy<B::InnerBackend, C>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Lazy {
+            config: self.config.clone(),
+            module: spin::Mutex::new(self.module.lock().as_ref().map(|module| module.valid())),
+        }
+    }
+}
+
+impl<B: Backend, C: LazyConfig> core::fmt::Display for Lazy<B, C>
+where
+    C::Module<B>: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.module.lock().as_ref() {
+            Some(module) => write!(f, "Lazy[{}]", module),
+            None => write!(f, "Lazy[uninitialized]"),
+        }
+    }
+}
+
+/// Configuration to create a [LazyLinear](LazyLinear) layer.
+#[derive(Config, Debug)]
+pub struct LazyLinearConfig {
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0), fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+impl LazyLinearConfig {
+    /// Initialize a new [lazy linear](LazyLinear) module.
+    pub fn init<B: Backend>(&self) -> LazyLinear<B> {
+        Lazy::new(self.clone())
+    }
+
+    fn linear(&self, d_input: usize) -> LinearConfig {
+        LinearConfig::new(d_input, self.d_output)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl LazyConfig for LazyLinearConfig {
+    type Module<B: Backend> = Linear<B>;
+
+    fn init_with_input<B: Backend>(&self, d_input: usize) -> Linear<B> {
+        self.linear(d_input).init()
+    }
+
+    fn init_with_record<B: Backend>(&self, record: <Linear<B> as Module<B>>::Record) -> Linear<B> {
+        let [d_input, _] = record.weight.dims();
+        self.linear(d_input).init_with(record)
+    }
+}
+
+impl<B: Backend> LazyLinear<B> {
+    /// Applies the forward pass on the input tensor, creating the parameters on the first call.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let d_input = input.dims()[D - 1];
+
+        self.get_or_init(d_input, &input.device()).forward(input)
+    }
+}
+
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for LazyLinear<B> {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        LazyLinear::forward(self, input)
+    }
+}
+
+/// Configuration to create a [LazyConv1d](LazyConv1d) layer.
+#[derive(Config, Debug)]
+pub struct LazyConv1dConfig {
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The stride of the convolution.
+    #[config(default = "1")]
+    pub stride: usize,
+    /// Spacing between kernel elements.
+    #[config(default = "1")]
+    pub dilation: usize,
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "PaddingConfig1d::Valid")]
+    pub padding: PaddingConfig1d,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+impl LazyConv1dConfig {
+    /// Initialize a new [lazy conv1d](LazyConv1d) module.
+    pub fn init<B: Backend>(&self) -> LazyConv1d<B> {
+        Lazy::new(self.clone())
+    }
+
+    fn conv(&self, channels_in: usize) -> Conv1dConfig {
+        Conv1dConfig::new(channels_in, self.channels_out, self.kernel_size)
+            .with_stride(self.stride)
+            .with_dilation(self.dilation)
+            .with_groups(self.groups)
+            .with_padding(self.padding.clone())
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl LazyConfig for LazyConv1dConfig {
+    type Module<B: Backend> = Conv1d<B>;
+
+    fn init_with_input<B: Backend>(&self, channels_in: usize) -> Conv1d<B> {
+        self.conv(channels_in).init()
+    }
+
+    fn init_with_record<B: Backend>(&self, record: <Conv1d<B> as Module<B>>::Record) -> Conv1d<B> {
+        let [_, channels_per_group, _] = record.weight.dims();
+        self.conv(channels_per_group * self.groups)
+            .init_with(record)
+    }
+}
+
+impl<B: Backend> LazyConv1d<B> {
+    /// Applies the forward pass on the input tensor, creating the parameters on the first call.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length_in]`
+    /// - output: `[batch_size, channels_out, length_out]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, channels_in, _] = input.dims();
+
+        self.get_or_init(channels_in, &input.device())
+            .forward(input)
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>> for LazyConv1d<B> {
+    type Output = Tensor<B, 3>;
+
+    fn forward(&self, input: Tensor<B, 3>) -> Self::Output {
+        LazyConv1d::forward(self, input)
+    }
+}
+
+/// Configuration to create a [LazyConv2d](LazyConv2d) layer.
+#[derive(Config, Debug)]
+pub struct LazyConv2dConfig {
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "PaddingConfig2d::Valid")]
+    pub padding: PaddingConfig2d,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+impl LazyConv2dConfig {
+    /// Initialize a new [lazy conv2d](LazyConv2d) module.
+    pub fn init<B: Backend>(&self) -> LazyConv2d<B> {
+        Lazy::new(self.clone())
+    }
+
+    fn conv(&self, channels_in: usize) -> Conv2dConfig {
+        Conv2dConfig::new([channels_in, self.channels_out], self.kernel_size)
+            .with_stride(self.stride)
+            .with_dilation(self.dilation)
+            .with_groups(self.groups)
+            .with_padding(self.padding.clone())
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl LazyConfig for LazyConv2dConfig {
+    type Module<B: Backend> = Conv2d<B>;
+
+    fn init_with_input<B: Backend>(&self, channels_in: usize) -> Conv2d<B> {
+        self.conv(channels_in).init()
+    }
+
+    fn init_with_record<B: Backend>(&self, record: <Conv2d<B> as Module<B>>::Record) -> Conv2d<B> {
+        let [_, channels_per_group, _, _] = record.weight.dims();
+        self.conv(channels_per_group * self.groups)
+            .init_with(record)
+    }
+}
+
+impl<B: Backend> LazyConv2d<B> {
+    /// Applies the forward pass on the input tensor, creating the parameters on the first call.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_, channels_in, _, _] = input.dims();
+
+        self.get_or_init(channels_in, &input.device())
+            .forward(input)
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>> for LazyConv2d<B> {
+    type Output = Tensor<B, 4>;
+
+    fn forward(&self, input: Tensor<B, 4>) -> Self::Output {
+        LazyConv2d::forward(self, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn linear_should_infer_input_features_on_first_forward() {
+        let linear = LazyLinearConfig::new(3).init::<TestBackend>();
+        assert!(!linear.is_initialized());
+        assert_eq!(linear.num_params(), 0);
+
+        let output = linear.forward(Tensor::random([2, 5, 7], Distribution::Default));
+
+        assert!(linear.is_initialized());
+        assert_eq!(output.dims(), [2, 5, 3]);
+        assert_eq!(linear.num_params(), 7 * 3 + 3);
+    }
+
+    #[test]
+    fn conv2d_should_infer_input_channels_on_first_forward() {
+        let conv = LazyConv2dConfig::new(4, [3, 3])
+            .with_groups(2)
+            .init::<TestBackend>();
+
+        let output = conv.forward(Tensor::random([1, 6, 5, 5], Distribution::Default));
+
+        assert_eq!(output.dims(), [1, 4, 3, 3]);
+        assert_eq!(conv.num_params(), 4 * 3 * 3 * 3 + 4);
+    }
+
+    #[test]
+    fn conv1d_should_infer_input_channels_on_first_forward() {
+        let conv = LazyConv1dConfig::new(4, 3).init::<TestBackend>();
+
+        let output = conv.forward(Tensor::random([1, 2, 5], Distribution::Default));
+
+        assert_eq!(output.dims(), [1, 4, 3]);
+    }
+
+    #[test]
+    fn should_reuse_params_after_first_forward() {
+        let linear = LazyLinearConfig::new(3).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::random([2, 4], Distribution::Default);
+
+        let output_1 = linear.forward(input.clone());
+        let output_2 = linear.clone().forward(input);
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 5);
+    }
+
+    #[test]
+    fn uninitialized_module_should_load_record() {
+        let linear = LazyLinearConfig::new(3).init::<TestBackend>();
+        linear.forward(Tensor::<TestBackend, 2>::ones([1, 4]));
+
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(linear.clone().into_record(), ()).unwrap();
+        let loaded = LazyLinearConfig::new(3)
+            .init::<TestBackend>()
+            .load_record(recorder.load(bytes).unwrap());
+
+        assert!(loaded.is_initialized());
+        loaded
+            .module()
+            .unwrap()
+            .weight
+            .to_data()
+            .assert_approx_eq(&linear.module().unwrap().weight.to_data(), 5);
+    }
+}
@@ -2,11 +2,11 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
-use crate::module::Param;
+use crate::module::{trace_layer, Param};
 use crate::tensor::{backend::Backend, Tensor};
 use libm::sqrt;
 
-use super::Initializer;
+use super::{Forward, Initializer, WeightedModule};
 
 /// Configuration to create a [Linear](Linear) layer.
 #[derive(Config, Debug)]
@@ -77,14 +77,43 @@ impl<B: Backend> Linear<B> {
     /// - output: `[..., any, d_output]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
         let output = input.matmul(self.weight.val().unsqueeze());
-
-        match &self.bias {
+        let output = match &self.bias {
             Some(bias) => output + bias.val().unsqueeze(),
             None => output,
+        };
+
+        trace_layer(&self.weight.id, &output, || {
+            let [d_input, _] = self.weight.dims();
+            let num_outputs = output.shape().num_elements();
+
+            2 * num_outputs * d_input + self.bias.is_some() as usize * num_outputs
+        });
+
+        output
+    }
+}
+
+impl<B: Backend> WeightedModule<B> for Linear<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        self.weight.val().transpose()
+    }
+
+    fn with_weight_matrix(&self, weight: Tensor<B, 2>) -> Self {
+        Self {
+            weight: Param::new(self.weight.id.clone(), weight.transpose()),
+            bias: self.bias.clone(),
         }
     }
 }
 
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for Linear<B> {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        Linear::forward(self, input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
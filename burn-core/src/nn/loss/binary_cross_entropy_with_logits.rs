@@ -0,0 +1,150 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a
+/// [Binary Cross-entropy with logits loss](BinaryCrossEntropyWithLogitsLoss).
+#[derive(Config, Debug)]
+pub struct BinaryCrossEntropyWithLogitsLossConfig {
+    /// Create weighted binary cross-entropy.
+    ///
+    /// The loss of each element is multiplied by `weights[0]` for the negative targets and by
+    /// `weights[1]` for the positive ones, soft targets interpolating between both weights.
+    pub weights: Option<[f32; 2]>,
+}
+
+impl BinaryCrossEntropyWithLogitsLossConfig {
+    /// Initialize [Binary Cross-entropy with logits loss](BinaryCrossEntropyWithLogitsLoss).
+    pub fn init<B: Backend>(&self) -> BinaryCrossEntropyWithLogitsLoss<B> {
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of binary cross-entropy have to be positive."
+            );
+        }
+
+        BinaryCrossEntropyWithLogitsLoss {
+            weights: self.weights,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the binary cross entropy loss from the input logits and the targets.
+///
+/// Combines the sigmoid activation with the binary cross entropy in a numerically stable way,
+/// using `max(x, 0) - x * y + log(1 + exp(-|x|))`. The targets can be probabilities, which is
+/// useful for multi-label classification and soft labels.
+#[derive(Module, Debug)]
+pub struct BinaryCrossEntropyWithLogitsLoss<B: Backend> {
+    weights: Option<[f32; 2]>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for BinaryCrossEntropyWithLogitsLoss<B> {
+    fn default() -> Self {
+        BinaryCrossEntropyWithLogitsLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> BinaryCrossEntropyWithLogitsLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With class weights, the mean is weighted by the weights of the targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(logits, targets.clone());
+
+        match (reduction, self.weights) {
+            (Reduction::Mean | Reduction::Auto, Some(weights)) => {
+                loss.sum() / Self::target_weights(targets, weights).sum()
+            }
+            (Reduction::Mean | Reduction::Auto, None) => loss.mean(),
+            (Reduction::Sum, _) => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        assert!(
+            logits.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of logits ({:?}).",
+            targets.dims(),
+            logits.dims()
+        );
+
+        let loss = logits.clone().clamp_min(0.) - logits.clone() * targets.clone()
+            + logits.abs().neg().exp().log1p();
+
+        match self.weights {
+            Some(weights) => loss * Self::target_weights(targets, weights),
+            None => loss,
+        }
+    }
+
+    fn target_weights<const D: usize>(targets: Tensor<B, D>, weights: [f32; 2]) -> Tensor<B, D> {
+        targets
+            .mul_scalar(weights[1] - weights[0])
+            .add_scalar(weights[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::BinaryCrossEntropyLossConfig;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution, Int};
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_should_match_binary_cross_entropy() {
+        let logits = Tensor::<TestBackend, 1>::random([4], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 0, 1]);
+        let weights = [3., 7.];
+
+        let loss_1 = BinaryCrossEntropyWithLogitsLossConfig::new()
+            .with_weights(Some(weights))
+            .init()
+            .forward(logits.clone(), targets.clone().float(), Reduction::Auto);
+        let loss_2 = BinaryCrossEntropyLossConfig::new()
+            .with_weights(Some(weights))
+            .init()
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_should_be_stable() {
+        let logits = Tensor::<TestBackend, 2>::from_floats([[100.0, -100.0], [0.0, -100.0]]);
+        let targets = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0], [0.5, 1.0]]);
+
+        let loss = BinaryCrossEntropyWithLogitsLoss::default();
+        let loss_no_reduction = loss.forward_no_reduction(logits.clone(), targets.clone());
+        let loss_sum = loss.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[100.0, 0.0], [core::f32::consts::LN_2, 100.0]]),
+            3,
+        );
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([200.0 + core::f32::consts::LN_2]), 3);
+    }
+}
@@ -0,0 +1,136 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Int, Norm, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The cosine similarity under which dissimilar pairs don't contribute to the loss, should be
+    /// in the interval [-1, 1]. Default: 0.0
+    #[config(default = 0.0)]
+    pub margin: f32,
+    /// A value required for numerical stability. Default: 1e-8
+    #[config(default = 1e-8)]
+    pub epsilon: f32,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [Cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init<B: Backend>(&self) -> CosineEmbeddingLoss<B> {
+        assert!(
+            (-1.0..=1.).contains(&self.margin),
+            "Margin of cosine embedding loss should be in interval [-1, 1]. Got {}",
+            self.margin
+        );
+
+        CosineEmbeddingLoss {
+            margin: self.margin,
+            epsilon: self.epsilon,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the cosine embedding loss, measuring whether pairs of embeddings are similar or
+/// dissimilar:
+///
+/// - `1 - cos(x1, x2)` if the target is 1
+/// - `max(0, cos(x1, x2) - margin)` if the target is -1
+#[derive(Module, Debug)]
+pub struct CosineEmbeddingLoss<B: Backend> {
+    margin: f32,
+    epsilon: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for CosineEmbeddingLoss<B> {
+    fn default() -> Self {
+        CosineEmbeddingLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> CosineEmbeddingLoss<B> {
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, d_model]`
+    /// - input2: `[batch_size, d_model]`
+    /// - targets: `[batch_size]`, with values in {1, -1}
+    pub fn forward(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, d_model]`
+    /// - input2: `[batch_size, d_model]`
+    /// - targets: `[batch_size]`, with values in {1, -1}
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = input1.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of the inputs ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let epsilon = self.epsilon as f64;
+        let cosine = input1
+            .normalize(Norm::L2, 1, epsilon)
+            .mul(input2.normalize(Norm::L2, 1, epsilon))
+            .sum_dim(1)
+            .reshape([batch_size]);
+
+        let similar = cosine.clone().neg().add_scalar(1.);
+        let dissimilar = cosine.sub_scalar(self.margin).clamp_min(0.);
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let input1 = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [1.0, 1.0], [0.0, 2.0]]);
+        let input2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0], [1.0, 0.0], [0.0, -1.0]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([1, -1, -1]);
+
+        let loss = CosineEmbeddingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_sum = loss.forward(input1, input2, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.292893, 0.207107, 0.0]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([0.5]), 3);
+    }
+}
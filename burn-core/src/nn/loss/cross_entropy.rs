@@ -165,13 +165,8 @@ impl<B: Backend> CrossEntropyLoss<B> {
         targets: Tensor<B, 1, Int>,
         alpha: f32,
     ) -> Tensor<B, 2> {
-        let [batch_size, nr_classes] = shape;
-        let device = &targets.device();
-        let targets_matrix = Tensor::<B, 2>::zeros_device(shape, device).scatter(
-            1,
-            targets.reshape([batch_size, 1]),
-            Tensor::ones_device([batch_size, 1], device),
-        );
+        let [_, nr_classes] = shape;
+        let targets_matrix = targets.one_hot(nr_classes).float();
         targets_matrix * (1. - alpha) + alpha / nr_classes as f32
     }
 
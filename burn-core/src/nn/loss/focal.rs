@@ -0,0 +1,171 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+use burn_tensor::activation::log_softmax;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [Focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, reducing the loss of well-classified samples. A value of 0 gives
+    /// the cross-entropy loss. Default: 2.0
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// Create weighted focal loss.
+    ///
+    /// The loss of each sample is multiplied by the weight of its target class, also known as the
+    /// alpha balancing factor.
+    ///
+    /// # Pre-conditions
+    ///   - The order of the weight vector should correspond to the label integer assignment.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [Focal loss](FocalLoss).
+    pub fn init<B: Backend>(&self) -> FocalLoss<B> {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<B, 1>::from_floats(e.as_slice())),
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.,
+            "Gamma of focal loss has to be positive or zero. Got {}",
+            self.gamma
+        );
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of focal loss have to be positive."
+            );
+        }
+    }
+}
+
+/// Calculate the focal loss from the input logits and the targets, as described in
+/// [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+///
+/// `loss = -weight * (1 - p) ^ gamma * log(p)`, where `p` is the probability of the target class.
+#[derive(Module, Debug)]
+pub struct FocalLoss<B: Backend> {
+    gamma: f32,
+    /// Weights for each class.
+    pub weights: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> Default for FocalLoss<B> {
+    fn default() -> Self {
+        FocalLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> FocalLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With class weights, the mean is weighted by the weights of the targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    pub fn forward(
+        &self,
+        logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(logits, targets.clone());
+
+        match (reduction, &self.weights) {
+            (Reduction::Mean | Reduction::Auto, Some(weights)) => {
+                loss.sum() / weights.clone().gather(0, targets).sum()
+            }
+            (Reduction::Mean | Reduction::Auto, None) => loss.mean(),
+            (Reduction::Sum, _) => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = logits.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of logits ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let log_p = log_softmax(logits, 1)
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .reshape([batch_size]);
+        let modulating_factor = log_p.clone().exp().neg().add_scalar(1.).powf(self.gamma);
+        let loss = modulating_factor.mul(log_p).neg();
+
+        match &self.weights {
+            Some(weights) => loss.mul(weights.clone().gather(0, targets)),
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLossConfig;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn test_focal_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_floats([[2.0, 0.0, -1.0], [0.5, 0.5, 1.0]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([0, 2]);
+
+        let loss = FocalLossConfig::new()
+            .init()
+            .forward_no_reduction(logits, targets);
+
+        // The probabilities of the targets are [0.8438, 0.4519].
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.004144, 0.238674]), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_with_gamma_zero_should_be_cross_entropy() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 4, 1]);
+        let weights = vec![1.0, 2., 3., 4., 5.];
+
+        let loss_1 = FocalLossConfig::new()
+            .with_gamma(0.)
+            .with_weights(Some(weights.clone()))
+            .init()
+            .forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_2 = CrossEntropyLossConfig::new()
+            .with_weights(Some(weights))
+            .init()
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
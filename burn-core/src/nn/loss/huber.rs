@@ -0,0 +1,112 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Huber loss](HuberLoss).
+#[derive(Config, Debug)]
+pub struct HuberLossConfig {
+    /// The threshold at which the loss changes from quadratic to linear. Default: 1.0
+    #[config(default = 1.0)]
+    pub delta: f32,
+}
+
+impl HuberLossConfig {
+    /// Initialize [Huber loss](HuberLoss).
+    pub fn init<B: Backend>(&self) -> HuberLoss<B> {
+        assert!(
+            self.delta > 0.,
+            "Delta of Huber loss has to be positive. Got {}",
+            self.delta
+        );
+
+        HuberLoss {
+            delta: self.delta,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Huber loss from the predictions and the targets.
+///
+/// The loss is quadratic for small errors and linear for large ones, making it less sensitive to
+/// outliers than the mean squared error:
+///
+/// - `0.5 * error ^ 2` if `|error| <= delta`
+/// - `delta * (|error| - 0.5 * delta)` otherwise
+#[derive(Module, Debug)]
+pub struct HuberLoss<B: Backend> {
+    delta: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for HuberLoss<B> {
+    fn default() -> Self {
+        HuberLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> HuberLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = predictions.sub(targets).abs();
+        let linear = error
+            .clone()
+            .sub_scalar(0.5 * self.delta)
+            .mul_scalar(self.delta);
+        let quadratic = error.clone().powf(2.0).mul_scalar(0.5);
+
+        quadratic.mask_where(error.greater_elem(self.delta), linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_huber_loss() {
+        let predictions = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [-3.0, 0.5]]);
+        let targets = Tensor::<TestBackend, 2>::from_floats([[1.5, 0.0], [1.0, 0.0]]);
+
+        let huber = HuberLossConfig::new().with_delta(2.0).init();
+        let loss_no_reduction = huber.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss = huber.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = huber.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.125, 2.0], [6.0, 0.125]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([2.0625]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([8.25]), 3);
+    }
+}
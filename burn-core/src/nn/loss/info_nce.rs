@@ -0,0 +1,145 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use alloc::vec;
+use burn_tensor::activation::log_softmax;
+use burn_tensor::{backend::Backend, Int, Norm, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create an [InfoNCE loss](InfoNceLoss).
+#[derive(Config, Debug)]
+pub struct InfoNceLossConfig {
+    /// The temperature dividing the cosine similarities. Default: 0.07
+    #[config(default = 0.07)]
+    pub temperature: f32,
+    /// A value required for numerical stability. Default: 1e-8
+    #[config(default = 1e-8)]
+    pub epsilon: f32,
+}
+
+impl InfoNceLossConfig {
+    /// Initialize [InfoNCE loss](InfoNceLoss).
+    pub fn init<B: Backend>(&self) -> InfoNceLoss<B> {
+        assert!(
+            self.temperature > 0.,
+            "Temperature of InfoNCE loss has to be positive. Got {}",
+            self.temperature
+        );
+
+        InfoNceLoss {
+            temperature: self.temperature,
+            epsilon: self.epsilon,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the InfoNCE loss between two views of the same batch, in its normalized
+/// temperature-scaled cross entropy (NT-Xent) form introduced in
+/// [A Simple Framework for Contrastive Learning of Visual Representations](https://arxiv.org/abs/2002.05709).
+///
+/// Each of the `2 * batch_size` embeddings must identify its other view among all the other
+/// embeddings of both views, using the cosine similarities divided by the temperature as logits.
+#[derive(Module, Debug)]
+pub struct InfoNceLoss<B: Backend> {
+    temperature: f32,
+    epsilon: f32,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for InfoNceLoss<B> {
+    fn default() -> Self {
+        InfoNceLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> InfoNceLoss<B> {
+    /// Compute the criterion on the embeddings of both views.
+    ///
+    /// # Shapes
+    ///
+    /// - view1: `[batch_size, d_model]`
+    /// - view2: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        view1: Tensor<B, 2>,
+        view2: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(view1, view2);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the embeddings of both views without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - view1: `[batch_size, d_model]`
+    /// - view2: `[batch_size, d_model]`
+    /// - output: `[2 * batch_size]`, the losses of the first view followed by the second one.
+    pub fn forward_no_reduction(&self, view1: Tensor<B, 2>, view2: Tensor<B, 2>) -> Tensor<B, 1> {
+        assert!(
+            view1.dims() == view2.dims(),
+            "Shape of both views should be equal, got {:?} and {:?}.",
+            view1.dims(),
+            view2.dims()
+        );
+        let [batch_size, _] = view1.dims();
+        let size = 2 * batch_size;
+        let device = view1.device();
+
+        let embeddings = Tensor::cat(vec![view1, view2], 0);
+        let embeddings = embeddings.normalize(Norm::L2, 1, self.epsilon as f64);
+
+        // An embedding can't be used as its own negative sample.
+        let diagonal = Tensor::<B, 1, Int>::arange_device(0..size, &device)
+            .one_hot::<2>(size)
+            .equal_elem(1);
+        let logits = embeddings
+            .clone()
+            .matmul(embeddings.transpose())
+            .div_scalar(self.temperature)
+            .mask_fill(diagonal, f32::NEG_INFINITY);
+
+        let targets = Tensor::cat(
+            vec![
+                Tensor::<B, 1, Int>::arange_device(batch_size..size, &device),
+                Tensor::<B, 1, Int>::arange_device(0..batch_size, &device),
+            ],
+            0,
+        );
+
+        log_softmax(logits, 1)
+            .gather(1, targets.reshape([size, 1]))
+            .reshape([size])
+            .neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_info_nce_loss() {
+        let view1 = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [0.0, 1.0]]);
+        let view2 = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.5], [-0.5, 1.0]]);
+
+        let loss = InfoNceLossConfig::new().with_temperature(0.5).init();
+        let loss_no_reduction = loss.forward_no_reduction(view1.clone(), view2.clone());
+        let loss_mean = loss.forward(view1, view2, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.211468, 0.454886, 0.454886, 0.211468]), 3);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.333177]), 3);
+    }
+}
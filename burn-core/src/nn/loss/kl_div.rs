@@ -0,0 +1,138 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Kullback-Leibler divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// If true, the targets are log-probabilities instead of probabilities. Default: false
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [Kullback-Leibler divergence loss](KlDivLoss).
+    pub fn init<B: Backend>(&self) -> KlDivLoss<B> {
+        KlDivLoss {
+            log_target: self.log_target,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence between the target distribution and the
+/// distribution predicted by the input.
+///
+/// The input must contain log-probabilities, e.g. computed with
+/// [log_softmax](burn_tensor::activation::log_softmax), and the pointwise loss is given by
+/// `target * (log(target) - input)`, the elements with a target of zero not contributing.
+#[derive(Module, Debug)]
+pub struct KlDivLoss<B: Backend> {
+    log_target: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for KlDivLoss<B> {
+    fn default() -> Self {
+        KlDivLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> KlDivLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// The [auto](Reduction::Auto) reduction divides the sum of the losses by the batch size,
+    /// which gives the actual divergence when each row is a distribution, while the
+    /// [mean](Reduction::Mean) reduction averages over all the elements.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_classes]`
+    /// - targets: `[batch_size, num_classes]`
+    pub fn forward<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let batch_size = input.dims()[0];
+        let tensor = self.forward_no_reduction(input, targets);
+        match reduction {
+            Reduction::Auto => tensor.sum().div_scalar(batch_size as f32),
+            Reduction::Mean => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        assert!(
+            input.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of the input ({:?}).",
+            targets.dims(),
+            input.dims()
+        );
+
+        if self.log_target {
+            return targets.clone().exp().mul(targets.sub(input));
+        }
+
+        let zeros = targets.clone().equal_elem(0.);
+        targets
+            .clone()
+            .mul(targets.log().sub(input))
+            .mask_fill(zeros, 0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::activation::log_softmax;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let input =
+            Tensor::<TestBackend, 2>::from_floats([[0.2, 0.3, 0.5], [0.5, 0.25, 0.25]]).log();
+        let targets = Tensor::<TestBackend, 2>::from_floats([[0.5, 0.5, 0.0], [0.5, 0.25, 0.25]]);
+
+        let kl_div = KlDivLoss::default();
+        let loss_no_reduction = kl_div.forward_no_reduction(input.clone(), targets.clone());
+        let loss = kl_div.forward(input.clone(), targets.clone(), Reduction::Auto);
+        let loss_mean = kl_div.forward(input, targets, Reduction::Mean);
+
+        // 0.5 * ln(0.5 / 0.2) = 0.4581, 0.5 * ln(0.5 / 0.3) = 0.2554
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.458145, 0.255413, 0.0], [0.0, 0.0, 0.0]]), 3);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.356779]), 3);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.118926]), 3);
+    }
+
+    #[test]
+    fn test_kl_div_loss_with_log_target() {
+        let input = log_softmax(Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 0.5]]), 1);
+        let targets = log_softmax(Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 3.0]]), 1);
+
+        let loss_1 = KlDivLossConfig::new().with_log_target(true).init().forward(
+            input.clone(),
+            targets.clone(),
+            Reduction::Sum,
+        );
+        let loss_2 = KlDivLoss::default().forward(input, targets.exp(), Reduction::Sum);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
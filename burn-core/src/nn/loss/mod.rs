@@ -1,9 +1,23 @@
 mod binary_cross_entropy;
+mod binary_cross_entropy_with_logits;
+mod cosine_embedding;
 mod cross_entropy;
+mod focal;
+mod huber;
+mod info_nce;
+mod kl_div;
 mod mse;
 mod reduction;
+mod triplet_margin;
 
 pub use binary_cross_entropy::*;
+pub use binary_cross_entropy_with_logits::*;
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use focal::*;
+pub use huber::*;
+pub use info_nce::*;
+pub use kl_div::*;
 pub use mse::*;
 pub use reduction::*;
+pub use triplet_margin::*;
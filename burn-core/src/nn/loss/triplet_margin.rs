@@ -0,0 +1,164 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+use crate::{config::Config, module::Module};
+use burn_tensor::{backend::Backend, Norm, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create a [Triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The minimum difference between the negative and the positive distances. Default: 1.0
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The norm degree used to compute the distances. Default: 2.0
+    #[config(default = 2.0)]
+    pub p: f32,
+    /// A value added to the differences to avoid a zero norm. Default: 1e-6
+    #[config(default = 1e-6)]
+    pub epsilon: f32,
+    /// If true, the negative distance is the smallest of the anchor-negative and the
+    /// positive-negative distances, as described in
+    /// [Learning local feature descriptors with triplets and shallow convolutional neural networks](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+    /// Default: false
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [Triplet margin loss](TripletMarginLoss).
+    pub fn init<B: Backend>(&self) -> TripletMarginLoss<B> {
+        assert!(
+            self.p > 0.,
+            "The norm degree of triplet margin loss has to be positive. Got {}",
+            self.p
+        );
+
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            epsilon: self.epsilon,
+            swap: self.swap,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the triplet margin loss, pulling each anchor closer to its positive sample than to
+/// its negative one:
+///
+/// `loss = max(d(anchor, positive) - d(anchor, negative) + margin, 0)`
+#[derive(Module, Debug)]
+pub struct TripletMarginLoss<B: Backend> {
+    margin: f32,
+    p: f32,
+    epsilon: f32,
+    swap: bool,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for TripletMarginLoss<B> {
+    fn default() -> Self {
+        TripletMarginLossConfig::new().init()
+    }
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    /// Compute the criterion on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(anchor, positive, negative);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let distance_positive = self.distance(anchor.clone(), positive.clone());
+        let mut distance_negative = self.distance(anchor, negative.clone());
+
+        if self.swap {
+            let distance_swap = self.distance(positive, negative);
+            let closer = distance_swap.clone().lower(distance_negative.clone());
+            distance_negative = distance_negative.mask_where(closer, distance_swap);
+        }
+
+        distance_positive
+            .sub(distance_negative)
+            .add_scalar(self.margin)
+            .clamp_min(0.)
+    }
+
+    fn distance(&self, x1: Tensor<B, 2>, x2: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = x1.dims();
+
+        x1.sub(x2)
+            .add_scalar(self.epsilon)
+            .norm(Norm::Lp(self.p), 1)
+            .reshape([batch_size])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let anchor = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [0.0, 2.0]]);
+        let positive = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0], [0.5, 2.0]]);
+        let negative = Tensor::<TestBackend, 2>::from_floats([[3.0, 0.0], [0.0, 1.5]]);
+
+        let loss = TripletMarginLoss::default();
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchor.clone(), positive.clone(), negative.clone());
+        let loss_sum = loss.forward(anchor, positive, negative, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([1.0]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_with_swap() {
+        let anchor = Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [0.0, 0.0]]);
+        let positive = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0], [1.0, 0.0]]);
+        let negative = Tensor::<TestBackend, 2>::from_floats([[3.0, 0.0], [2.0, 0.0]]);
+
+        let loss = TripletMarginLossConfig::new()
+            .with_swap(true)
+            .init()
+            .forward_no_reduction(anchor, positive, negative);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0]), 3);
+    }
+}
@@ -7,6 +7,9 @@ pub mod cache;
 /// Convolution module
 pub mod conv;
 
+/// Functional module
+pub mod functional;
+
 /// Loss module
 pub mod loss;
 
@@ -16,26 +19,46 @@ pub mod pool;
 /// Transformer module
 pub mod transformer;
 
+mod alpha_dropout;
+mod drop_path;
 mod dropout;
+mod dropout2d;
 mod embedding;
+mod embedding_bag;
+mod fold;
 mod gelu;
+mod hook;
 mod initializer;
+mod interpolate;
+mod lazy;
 mod linear;
 mod norm;
 mod padding;
 mod pos_encoding;
 mod relu;
 mod rnn;
+mod rotary_encoding;
+mod tied_linear;
 mod unfold;
 
+pub use alpha_dropout::*;
+pub use drop_path::*;
 pub use dropout::*;
+pub use dropout2d::*;
 pub use embedding::*;
+pub use embedding_bag::*;
+pub use fold::*;
 pub use gelu::*;
+pub use hook::*;
 pub use initializer::*;
+pub use interpolate::*;
+pub use lazy::*;
 pub use linear::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rotary_encoding::*;
+pub use tied_linear::*;
 pub use unfold::*;
@@ -3,6 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
+use crate::nn::Forward;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -67,6 +68,14 @@ impl<B: Backend> LayerNorm<B> {
     }
 }
 
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for LayerNorm<B> {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        LayerNorm::forward(self, input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,11 @@
 mod batch;
 mod group;
 mod layer;
+mod spectral_norm;
+mod weight_norm;
 
 pub use batch::*;
 pub use group::*;
 pub use layer::*;
+pub use spectral_norm::*;
+pub use weight_norm::*;
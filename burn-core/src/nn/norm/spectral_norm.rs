@@ -0,0 +1,377 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{
+    AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor, Param, RunningState,
+};
+use crate::nn::WeightedModule;
+use crate::record::{PrecisionSettings, Record};
+use serde::{Deserialize, Serialize};
+
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    Distribution, Tensor,
+};
+
+/// Configuration to create a [SpectralNorm](SpectralNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The number of power iterations performed at each training forward pass. Default: 1
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Spectral normalization wrapper, as described in the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957).
+///
+/// The weight of the wrapped module is divided by its largest singular value `σ`, which is
+/// estimated with the power iteration method:
+///
+/// `W = V / σ(V)`
+///
+/// The singular vectors are updated during the forward pass when the backend has autodiff
+/// enabled, and are reused as is otherwise.
+///
+/// # Params
+///
+/// - module: The wrapped module, its weight being the unnormalized weight `V`.
+/// - u: Left singular vector of shape `[d_output]`.
+/// - v: Right singular vector of shape `[d_input]`.
+#[derive(Debug, Clone)]
+pub struct SpectralNorm<B: Backend, M> {
+    module: M,
+    u: RunningState<Tensor<B, 1>>,
+    v: RunningState<Tensor<B, 1>>,
+    n_power_iterations: usize,
+    epsilon: f64,
+}
+
+/// The record type for the [SpectralNorm](SpectralNorm) wrapper.
+pub struct SpectralNormRecord<B: Backend, M: Module<B>> {
+    /// The record of the wrapped module.
+    pub module: M::Record,
+    /// The left singular vector.
+    pub u: Param<Tensor<B, 1>>,
+    /// The right singular vector.
+    pub v: Param<Tensor<B, 1>>,
+}
+
+/// The record item type for the [SpectralNorm](SpectralNorm) wrapper.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SpectralNormRecordItem<B: Backend, M: Module<B>, S: PrecisionSettings> {
+    /// Field to be serialized.
+    pub module: <M::Record as Record>::Item<S>,
+    /// Field to be serialized.
+    pub u: <Param<Tensor<B, 1>> as Record>::Item<S>,
+    /// Field to be serialized.
+    pub v: <Param<Tensor<B, 1>> as Record>::Item<S>,
+}
+
+impl<B: Backend, M: Module<B>> Record for SpectralNormRecord<B, M> {
+    type Item<S: PrecisionSettings> = SpectralNormRecordItem<B, M, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        SpectralNormRecordItem {
+            module: self.module.into_item(),
+            u: self.u.into_item(),
+            v: self.v.into_item(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            module: Record::from_item(item.module),
+            u: Record::from_item(item.u),
+            v: Record::from_item(item.v),
+        }
+    }
+}
+
+impl SpectralNormConfig {
+    /// Wraps the module in a new [spectral norm](SpectralNorm) wrapper.
+    pub fn init<B: Backend, M: WeightedModule<B>>(&self, module: M) -> SpectralNorm<B, M> {
+        let weight = module.weight_matrix().detach();
+        let [d_output, _] = weight.dims();
+        let device = weight.device();
+
+        let u = Tensor::random_device([d_output], Distribution::Normal(0.0, 1.0), &device);
+        let u = normalize(u, self.epsilon);
+        let v = normalize(matvec(weight.transpose(), u.clone()), self.epsilon);
+
+        SpectralNorm {
+            module,
+            u: RunningState::new(u),
+            v: RunningState::new(v),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [spectral norm](SpectralNorm) wrapper with a
+    /// [record](SpectralNormRecord).
+    pub fn init_with<B: Backend, M: WeightedModule<B>>(
+        &self,
+        module: M,
+        record: SpectralNormRecord<B, M>,
+    ) -> SpectralNorm<B, M> {
+        SpectralNorm {
+            module: module.load_record(record.module),
+            u: RunningState::from_record(record.u),
+            v: RunningState::from_record(record.v),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, M: WeightedModule<B>> SpectralNorm<B, M> {
+    /// Returns the wrapped module with its normalized weight, ready for the forward pass.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let output = spectral_norm.normalized().forward(input);
+    /// ```
+    pub fn normalized(&self) -> M {
+        let weight = self.module.weight_matrix();
+        let (u, v) = match B::ad_enabled() {
+            true => self.power_iteration(weight.clone().detach()),
+            false => (self.u.value(), self.v.value()),
+        };
+
+        let sigma = u.mul(matvec(weight.clone(), v)).sum().unsqueeze::<2>();
+
+        self.module.with_weight_matrix(weight.div(sigma))
+    }
+
+    /// Returns the wrapped module, with its unnormalized weight.
+    pub fn inner(&self) -> &M {
+        &self.module
+    }
+
+    fn power_iteration(&self, weight: Tensor<B, 2>) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        let mut u = self.u.value_sync();
+        let mut v = self.v.value_sync();
+
+        for _ in 0..self.n_power_iterations {
+            v = normalize(matvec(weight.clone().transpose(), u), self.epsilon);
+            u = normalize(matvec(weight.clone(), v.clone()), self.epsilon);
+        }
+
+        self.u.update(u.clone());
+        self.v.update(v.clone());
+
+        (u, v)
+    }
+}
+
+/// Matrix-vector product.
+fn matvec<B: Backend>(matrix: Tensor<B, 2>, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+    let [d_output, _] = matrix.dims();
+
+    matrix
+        .matmul(vector.unsqueeze::<2>().transpose())
+        .reshape([d_output])
+}
+
+fn normalize<B: Backend>(vector: Tensor<B, 1>, epsilon: f64) -> Tensor<B, 1> {
+    let norm = vector.clone().powf(2.0).sum().sqrt().clamp_min(epsilon);
+
+    vector.div(norm)
+}
+
+impl<B, M> Module<B> for SpectralNorm<B, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    type Record = SpectralNormRecord<B, M>;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        let devices = self.module.collect_devices(devices);
+        let devices = self.u.collect_devices(devices);
+        self.v.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.fork(device),
+            u: self.u.fork(device),
+            v: self.v.fork(device),
+            ..self
+        }
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.to_device(device),
+            u: self.u.to_device(device),
+            v: self.v.to_device(device),
+            ..self
+        }
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        visitor.enter_module("module");
+        self.module.visit(visitor);
+        visitor.exit_module("module");
+        visitor.enter_module("u");
+        self.u.visit(visitor);
+        visitor.exit_module("u");
+        visitor.enter_module("v");
+        self.v.visit(visitor);
+        visitor.exit_module("v");
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        mapper.enter_module("module");
+        let module = self.module.map(mapper);
+        mapper.exit_module("module");
+        mapper.enter_module("u");
+        let u = self.u.map(mapper);
+        mapper.exit_module("u");
+        mapper.enter_module("v");
+        let v = self.v.map(mapper);
+        mapper.exit_module("v");
+
+        Self {
+            module,
+            u,
+            v,
+            ..self
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self {
+            module: self.module.load_record(record.module),
+            u: self.u.load_record(record.u),
+            v: self.v.load_record(record.v),
+            ..self
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        SpectralNormRecord {
+            module: self.module.into_record(),
+            u: self.u.into_record(),
+            v: self.v.into_record(),
+        }
+    }
+}
+
+impl<B, M> AutodiffModule<B> for SpectralNorm<B, M>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    type InnerModule = SpectralNorm<B::InnerBackend, M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        SpectralNorm {
+            module: self.module.valid(),
+            u: self.u.valid(),
+            v: self.v.valid(),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, M: core::fmt::Display> core::fmt::Display for SpectralNorm<B, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SpectralNorm[{}]", self.module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv1dConfig;
+    use crate::nn::LinearConfig;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn power_iteration_should_estimate_the_largest_singular_value() {
+        TestAutodiffBackend::seed(0);
+        let mut linear = LinearConfig::new(2, 2).init::<TestAutodiffBackend>();
+        // The singular values of the weight are 4 and 1.
+        linear.weight = Param::from(Tensor::from_floats([[3.0, 1.0], [1.0, 3.0]]));
+        let spectral_norm = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init(linear);
+
+        let weight = spectral_norm.normalized().weight.val();
+
+        weight
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.75, 0.25], [0.25, 0.75]]), 3);
+    }
+
+    #[test]
+    fn singular_vectors_should_only_be_updated_during_training() {
+        TestAutodiffBackend::seed(0);
+        let conv = Conv1dConfig::new(2, 3, 2).init::<TestAutodiffBackend>();
+        let spectral_norm = SpectralNormConfig::new().init(conv);
+        let u = spectral_norm.u.value();
+
+        let valid = spectral_norm.valid();
+        valid.normalized();
+        valid
+            .u
+            .value()
+            .into_data()
+            .assert_approx_eq(&u.clone().into_data(), 5);
+
+        spectral_norm.normalized();
+        let u_updated = spectral_norm.u.value_sync();
+        assert_ne!(u_updated.into_data().value, u.into_data().value);
+    }
+
+    #[test]
+    fn gradients_should_flow_to_the_wrapped_weight() {
+        TestAutodiffBackend::seed(0);
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>();
+        let spectral_norm = SpectralNormConfig::new().init(linear);
+
+        let input = Tensor::random([4, 3], Distribution::Default);
+        let output = spectral_norm.normalized().forward(input);
+        let grads = output.sum().backward();
+
+        let grad = spectral_norm.inner().weight.grad(&grads);
+        assert_eq!(grad.map(|grad| grad.dims()), Some([3, 2]));
+    }
+
+    #[test]
+    fn record_should_restore_the_singular_vectors() {
+        TestBackend::seed(0);
+        let config = SpectralNormConfig::new();
+        let spectral_norm = config.init(LinearConfig::new(3, 2).init::<TestBackend>());
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder
+            .record(spectral_norm.clone().into_record(), ())
+            .unwrap();
+
+        let loaded = config.init_with(
+            LinearConfig::new(3, 2).init::<TestBackend>(),
+            recorder.load(bytes).unwrap(),
+        );
+
+        loaded
+            .u
+            .value()
+            .into_data()
+            .assert_approx_eq(&spectral_norm.u.value().into_data(), 5);
+        loaded
+            .normalized()
+            .weight
+            .to_data()
+            .assert_approx_eq(&spectral_norm.normalized().weight.to_data(), 5);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor, Param};
+use crate::record::{PrecisionSettings, Record};
+use serde::{Deserialize, Serialize};
+
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+
+/// A module with a weight that can be reparameterized by a [WeightNorm](WeightNorm) or a
+/// [SpectralNorm](crate::nn::SpectralNorm) wrapper.
+///
+/// The weight is viewed as a matrix where each row corresponds to an output feature.
+pub trait WeightedModule<B: Backend>: Module<B> {
+    /// Returns the weight as a matrix of shape `[d_output, d_input]`.
+    fn weight_matrix(&self) -> Tensor<B, 2>;
+
+    /// Returns a copy of the module with the weight replaced by the given matrix of shape
+    /// `[d_output, d_input]`.
+    ///
+    /// The parameter keeps its id, so that the gradients of the new weight flow back to the
+    /// tensors it was computed from.
+    fn with_weight_matrix(&self, weight: Tensor<B, 2>) -> Self;
+}
+
+/// Weight normalization wrapper, as described in the paper
+/// [Weight Normalization](https://arxiv.org/abs/1602.07868).
+///
+/// The weight of the wrapped module is decoupled into a direction and a magnitude:
+///
+/// `W = g * V / ||V||`
+///
+/// where `V` is the weight stored in the wrapped module and `g` has one value per output feature.
+///
+/// # Params
+///
+/// - module: The wrapped module, its weight being the direction `V`.
+/// - magnitude: Tensor of shape `[d_output]`, initialized with the norm of the initial weight
+///   so that the wrapped module computes the same function.
+#[derive(Debug, Clone)]
+pub struct WeightNorm<B: Backend, M> {
+    module: M,
+    magnitude: Param<Tensor<B, 1>>,
+}
+
+/// The record type for the [WeightNorm](WeightNorm) wrapper.
+pub struct WeightNormRecord<B: Backend, M: Module<B>> {
+    /// The record of the wrapped module.
+    pub module: M::Record,
+    /// The magnitude of the weight.
+    pub magnitude: Param<Tensor<B, 1>>,
+}
+
+/// The record item type for the [WeightNorm](WeightNorm) wrapper.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct WeightNormRecordItem<B: Backend, M: Module<B>, S: PrecisionSettings> {
+    /// Field to be serialized.
+    pub module: <M::Record as Record>::Item<S>,
+    /// Field to be serialized.
+    pub magnitude: <Param<Tensor<B, 1>> as Record>::Item<S>,
+}
+
+impl<B: Backend, M: Module<B>> Record for WeightNormRecord<B, M> {
+    type Item<S: PrecisionSettings> = WeightNormRecordItem<B, M, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        WeightNormRecordItem {
+            module: self.module.into_item(),
+            magnitude: self.magnitude.into_item(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            module: Record::from_item(item.module),
+            magnitude: Record::from_item(item.magnitude),
+        }
+    }
+}
+
+impl<B: Backend, M: WeightedModule<B>> WeightNorm<B, M> {
+    /// Wraps the module, reparameterizing its weight.
+    pub fn new(module: M) -> Self {
+        let weight = module.weight_matrix().detach();
+        let [d_output, _] = weight.dims();
+        let magnitude = row_norm(weight).reshape([d_output]);
+
+        Self {
+            module,
+            magnitude: Param::from(magnitude),
+        }
+    }
+
+    /// Returns the wrapped module with its reparameterized weight, ready for the forward pass.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let output = weight_norm.normalized().forward(input);
+    /// ```
+    pub fn normalized(&self) -> M {
+        let direction = self.module.weight_matrix();
+        let [d_output, _] = direction.dims();
+        let norm = row_norm(direction.clone());
+        let magnitude = self.magnitude.val().reshape([d_output, 1]);
+
+        self.module
+            .with_weight_matrix(direction.div(norm).mul(magnitude))
+    }
+
+    /// Returns the wrapped module, its weight being the direction `V`.
+    pub fn inner(&self) -> &M {
+        &self.module
+    }
+}
+
+/// Euclidean norm of each row, keeping the dimension.
+fn row_norm<B: Backend>(matrix: Tensor<B, 2>) -> Tensor<B, 2> {
+    matrix.powf(2.0).sum_dim(1).sqrt()
+}
+
+impl<B, M> Module<B> for WeightNorm<B, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    type Record = WeightNormRecord<B, M>;
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        let devices = self.module.collect_devices(devices);
+        self.magnitude.collect_devices(devices)
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.fork(device),
+            magnitude: self.magnitude.fork(device),
+        }
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            module: self.module.to_device(device),
+            magnitude: self.magnitude.to_device(device),
+        }
+    }
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        visitor.enter_module("module");
+        self.module.visit(visitor);
+        visitor.exit_module("module");
+        visitor.enter_module("magnitude");
+        self.magnitude.visit(visitor);
+        visitor.exit_module("magnitude");
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        mapper.enter_module("module");
+        let module = self.module.map(mapper);
+        mapper.exit_module("module");
+        mapper.enter_module("magnitude");
+        let magnitude = Module::<B>::map(self.magnitude, mapper);
+        mapper.exit_module("magnitude");
+
+        Self { module, magnitude }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self {
+            module: self.module.load_record(record.module),
+            magnitude: self.magnitude.load_record(record.magnitude),
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        WeightNormRecord {
+            module: self.module.into_record(),
+            magnitude: self.magnitude.into_record(),
+        }
+    }
+}
+
+impl<B, M> AutodiffModule<B> for WeightNorm<B, M>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    type InnerModule = WeightNorm<B::InnerBackend, M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        WeightNorm {
+            module: self.module.valid(),
+            magnitude: self.magnitude.valid(),
+        }
+    }
+}
+
+impl<B: Backend, M: core::fmt::Display> core::fmt::Display for WeightNorm<B, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WeightNorm[{}]", self.module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::LinearConfig;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn initial_weight_should_be_unchanged() {
+        TestBackend::seed(0);
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>();
+        let weight_norm = WeightNorm::new(linear.clone());
+
+        weight_norm
+            .normalized()
+            .weight
+            .to_data()
+            .assert_approx_eq(&linear.weight.to_data(), 5);
+    }
+
+    #[test]
+    fn weight_should_have_the_magnitude_norm() {
+        let linear = LinearConfig::new(2, 3).init::<TestBackend>();
+        let mut weight_norm = WeightNorm::new(linear);
+        weight_norm.magnitude = Param::from(Tensor::from_floats([1.0, 2.0, 3.0]));
+
+        // Each column of the linear weight corresponds to an output feature.
+        let norms = weight_norm
+            .normalized()
+            .weight
+            .val()
+            .powf(2.0)
+            .sum_dim(0)
+            .sqrt();
+
+        norms
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0, 3.0]]), 5);
+    }
+
+    #[test]
+    fn conv_weight_should_be_normalized_per_output_channel() {
+        let conv = Conv2dConfig::new([2, 3], [2, 2]).init::<TestBackend>();
+        let mut weight_norm = WeightNorm::new(conv);
+        weight_norm.magnitude = Param::from(Tensor::from_floats([1.0, 2.0, 3.0]));
+
+        let norms = weight_norm
+            .normalized()
+            .weight_matrix()
+            .powf(2.0)
+            .sum_dim(1)
+            .sqrt();
+
+        norms
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [2.0], [3.0]]), 5);
+
+        let input = Tensor::random([1, 2, 4, 4], Distribution::Default);
+        let output = weight_norm.normalized().forward(input);
+        assert_eq!(output.dims(), [1, 3, 3, 3]);
+    }
+
+    #[test]
+    fn record_should_restore_the_wrapper() {
+        let weight_norm = WeightNorm::new(LinearConfig::new(2, 3).init::<TestBackend>());
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder
+            .record(weight_norm.clone().into_record(), ())
+            .unwrap();
+
+        let loaded = WeightNorm::new(LinearConfig::new(2, 3).init::<TestBackend>())
+            .load_record(recorder.load(bytes).unwrap());
+
+        loaded
+            .normalized()
+            .weight
+            .to_data()
+            .assert_approx_eq(&weight_norm.normalized().weight.to_data(), 5);
+        assert_eq!(loaded.num_params(), 2 * 3 + 3 + 3);
+    }
+}
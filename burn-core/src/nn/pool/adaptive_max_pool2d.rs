@@ -0,0 +1,41 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::adaptive_max_pool2d;
+
+/// Configuration to create a [2D adaptive max pooling](AdaptiveMaxPool2d) layer.
+#[derive(Config)]
+pub struct AdaptiveMaxPool2dConfig {
+    /// The size of the output.
+    pub output_size: [usize; 2],
+}
+
+/// Applies a 2D adaptive max pooling over input tensors.
+#[derive(Module, Debug, Clone)]
+pub struct AdaptiveMaxPool2d {
+    output_size: [usize; 2],
+}
+
+impl AdaptiveMaxPool2dConfig {
+    /// Initialize a new [adaptive max pool 2d](AdaptiveMaxPool2d) module.
+    pub fn init(&self) -> AdaptiveMaxPool2d {
+        AdaptiveMaxPool2d {
+            output_size: self.output_size,
+        }
+    }
+}
+
+impl AdaptiveMaxPool2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        adaptive_max_pool2d(input, self.output_size)
+    }
+}
@@ -1,5 +1,6 @@
 mod adaptive_avg_pool1d;
 mod adaptive_avg_pool2d;
+mod adaptive_max_pool2d;
 mod avg_pool1d;
 mod avg_pool2d;
 mod max_pool1d;
@@ -7,6 +8,7 @@ mod max_pool2d;
 
 pub use adaptive_avg_pool1d::*;
 pub use adaptive_avg_pool2d::*;
+pub use adaptive_max_pool2d::*;
 pub use avg_pool1d::*;
 pub use avg_pool2d::*;
 pub use max_pool1d::*;
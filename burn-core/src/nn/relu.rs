@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::module::Module;
+use crate::nn::Forward;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -25,3 +26,11 @@ impl ReLU {
         crate::tensor::activation::relu(input)
     }
 }
+
+impl<B: Backend, const D: usize> Forward<Tensor<B, D>> for ReLU {
+    type Output = Tensor<B, D>;
+
+    fn forward(&self, input: Tensor<B, D>) -> Self::Output {
+        ReLU::forward(self, input)
+    }
+}
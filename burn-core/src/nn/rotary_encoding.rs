@@ -0,0 +1,192 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::Data;
+
+use libm::{cosf, powf, sinf};
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config, Debug)]
+pub struct RotaryEncodingConfig {
+    /// Maximum sequence length of the input.
+    pub max_sequence_length: usize,
+
+    /// The size of each vector to rotate, usually the size of an attention head.
+    pub d_model: usize,
+
+    /// Base of the geometric progression used to compute the rotation frequencies.
+    #[config(default = "10000.0")]
+    pub theta: f32,
+}
+
+/// Rotary positional encoding layer, as introduced in
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// Instead of adding the positional information to the input embeddings, pairs of features of the
+/// queries and keys are rotated by an angle proportional to their position, so that the attention
+/// scores only depend on the relative position between tokens.
+///
+/// The feature `i` is paired with the feature `i + d_model / 2`, which matches the layout used by
+/// most pre-trained models (e.g. Llama).
+#[derive(Module, Debug)]
+pub struct RotaryEncoding<B: Backend> {
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [RotaryEncoding](RotaryEncoding) module.
+    ///
+    /// # Panics
+    ///
+    /// If `d_model` is not even.
+    pub fn init<B: Backend>(&self) -> RotaryEncoding<B> {
+        assert!(
+            self.d_model.is_multiple_of(2),
+            "d_model({}) must be even to apply rotary encoding",
+            self.d_model
+        );
+
+        let half = self.d_model / 2;
+        let frequencies = (0..half)
+            .map(|i| 1.0 / powf(self.theta, (2 * i) as f32 / self.d_model as f32))
+            .collect::<Vec<_>>();
+
+        let mut cos = Vec::with_capacity(self.max_sequence_length * self.d_model);
+        let mut sin = Vec::with_capacity(self.max_sequence_length * self.d_model);
+
+        for position in 0..self.max_sequence_length {
+            // The same angles are used for both halves of the features.
+            for _ in 0..2 {
+                for frequency in frequencies.iter() {
+                    let angle = position as f32 * frequency;
+                    cos.push(cosf(angle));
+                    sin.push(sinf(angle));
+                }
+            }
+        }
+
+        let shape = [self.max_sequence_length, self.d_model];
+
+        RotaryEncoding {
+            cos: Tensor::from_data(Data::new(cos, shape.into()).convert()),
+            sin: Tensor::from_data(Data::new(sin, shape.into()).convert()),
+        }
+    }
+}
+
+impl<B: Backend> RotaryEncoding<B> {
+    /// Applies the rotation on the input tensor, the first token being at position 0.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, n_heads, seq_length, d_model]`
+    /// * output: `[batch_size, n_heads, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.forward_offset(input, 0)
+    }
+
+    /// Applies the rotation on the input tensor, the first token being at position `offset`.
+    ///
+    /// Useful during autoregressive inference, where only the newest tokens are encoded.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, n_heads, seq_length, d_model]`
+    /// * output: `[batch_size, n_heads, seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `offset + seq_length` is greater than the maximum sequence length.
+    /// * Panics if the input d_model is not equal to the d_model of the encoding.
+    pub fn forward_offset(&self, input: Tensor<B, 4>, offset: usize) -> Tensor<B, 4> {
+        let [batch_size, n_heads, seq_length, d_model_input] = input.dims();
+        let [max_sequence_length, d_model] = self.cos.dims();
+
+        assert!(
+            max_sequence_length >= offset + seq_length,
+            "max_sequence_length({max_sequence_length}) must be greater or equal than \
+            offset + length({})",
+            offset + seq_length
+        );
+        assert!(
+            d_model_input == d_model,
+            "d_model({d_model_input}) of the input must be equal to d_model of encoding({d_model})",
+        );
+
+        let slices = [offset..offset + seq_length, 0..d_model];
+        let cos = self.cos.clone().slice(slices.clone()).unsqueeze::<4>();
+        let sin = self.sin.clone().slice(slices).unsqueeze::<4>();
+
+        let half = d_model / 2;
+        let x1 = input
+            .clone()
+            .slice([0..batch_size, 0..n_heads, 0..seq_length, 0..half]);
+        let x2 = input
+            .clone()
+            .slice([0..batch_size, 0..n_heads, 0..seq_length, half..d_model]);
+        let rotated = Tensor::cat(vec![x2.neg(), x1], 3);
+
+        input.mul(cos).add(rotated.mul(sin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_rotation_values() {
+        let rope = RotaryEncodingConfig::new(4, 4).init::<TestBackend>();
+        let input =
+            Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 4.0]]]]);
+
+        let output = rope.forward_offset(input, 1);
+
+        // Frequencies are [1, 0.01], the token at position p is rotated by [p, 0.01 * p].
+        let (c1, s1) = (cosf(1.0), sinf(1.0));
+        let (c2, s2) = (cosf(0.01), sinf(0.01));
+        let (c3, s3) = (cosf(2.0), sinf(2.0));
+        let (c4, s4) = (cosf(0.02), sinf(0.02));
+        let expected = Tensor::<TestBackend, 4>::from_floats([[[
+            [
+                1.0 * c1 - 3.0 * s1,
+                2.0 * c2 - 4.0 * s2,
+                3.0 * c1 + s1,
+                4.0 * c2 + 2.0 * s2,
+            ],
+            [
+                1.0 * c3 - 3.0 * s3,
+                2.0 * c4 - 4.0 * s4,
+                3.0 * c3 + s3,
+                4.0 * c4 + 2.0 * s4,
+            ],
+        ]]]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_dot_product_depends_on_relative_position() {
+        let rope = RotaryEncodingConfig::new(8, 4).init::<TestBackend>();
+        let query = Tensor::<TestBackend, 4>::from_floats([[[[0.3, -1.2, 0.5, 0.8]]]]);
+        let key = Tensor::<TestBackend, 4>::from_floats([[[[1.1, 0.4, -0.7, 0.2]]]]);
+
+        let score = |position_query, position_key| {
+            rope.forward_offset(query.clone(), position_query)
+                .mul(rope.forward_offset(key.clone(), position_key))
+                .sum()
+                .into_data()
+        };
+
+        score(3, 1).assert_approx_eq(&score(6, 4), 4);
+    }
+}
@@ -0,0 +1,144 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::Embedding;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [TiedLinear](TiedLinear) layer.
+#[derive(Config, Debug)]
+pub struct TiedLinearConfig {
+    /// The size of the output features, which is the number of embedding vectors.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = false)]
+    pub bias: bool,
+}
+
+/// Applies a linear transformation to the input tensor, using the weight of an
+/// [embedding](Embedding) layer:
+///
+/// `O = IE^T + b`
+///
+/// The weight is only owned by the embedding, so it is saved, loaded and optimized once, and it
+/// receives the gradients of both the embedding lookup and the linear transformation. This is
+/// commonly used to tie the output layer of a language model to its token embedding, as described
+/// in the paper [Using the Output Embedding to Improve Language Models](https://arxiv.org/abs/1608.05859).
+///
+/// # Params
+///
+/// - bias: Vector of size `d_output` initialized with zeros.
+#[derive(Module, Debug)]
+pub struct TiedLinear<B: Backend> {
+    /// Vector of size `d_output` initialized with zeros.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl TiedLinearConfig {
+    /// Initialize a new [tied linear](TiedLinear) module.
+    pub fn init<B: Backend>(&self) -> TiedLinear<B> {
+        let bias = match self.bias {
+            true => Some(Param::from(Tensor::zeros([self.d_output]))),
+            false => None,
+        };
+
+        TiedLinear { bias }
+    }
+
+    /// Initialize a new [tied linear](TiedLinear) module with a [record](TiedLinearRecord).
+    pub fn init_with<B: Backend>(&self, record: TiedLinearRecord<B>) -> TiedLinear<B> {
+        TiedLinear { bias: record.bias }
+    }
+}
+
+impl<B: Backend> TiedLinear<B> {
+    /// Applies the forward pass on the input tensor, using the weight of the given embedding.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, n_embedding]`
+    pub fn forward<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        embedding: &Embedding<B>,
+    ) -> Tensor<B, D> {
+        let output = embedding.attend(input);
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::module::list_param_ids;
+    use crate::nn::EmbeddingConfig;
+    use crate::optim::GradientsParams;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Int;
+
+    #[derive(Module, Debug)]
+    struct LanguageModel<B: Backend> {
+        embedding: Embedding<B>,
+        head: TiedLinear<B>,
+    }
+
+    impl<B: Backend> LanguageModel<B> {
+        fn new() -> Self {
+            Self {
+                embedding: EmbeddingConfig::new(5, 4).init(),
+                head: TiedLinearConfig::new(5).with_bias(true).init(),
+            }
+        }
+
+        fn forward(&self, tokens: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+            let hidden = self.embedding.forward(tokens);
+
+            self.head.forward(hidden, &self.embedding)
+        }
+    }
+
+    #[test]
+    fn output_should_have_one_score_per_embedding_vector() {
+        let model = LanguageModel::<TestAutodiffBackend>::new();
+
+        let output = model.forward(Tensor::from_ints([[0, 1, 4]]));
+
+        assert_eq!(output.dims(), [1, 3, 5]);
+    }
+
+    #[test]
+    fn tied_weight_should_be_a_single_param() {
+        let model = LanguageModel::<TestAutodiffBackend>::new();
+
+        // The embedding weight and the head bias.
+        assert_eq!(list_param_ids(&model).len(), 2);
+        assert_eq!(model.num_params(), 5 * 4 + 5);
+    }
+
+    #[test]
+    fn tied_weight_should_receive_gradients_of_both_usages() {
+        let model = LanguageModel::<TestAutodiffBackend>::new();
+        let tokens = Tensor::from_ints([[0, 1]]);
+
+        let weight_id = &list_param_ids(&model.embedding)[0];
+
+        let grads_lookup = model.embedding.forward(tokens.clone()).sum().backward();
+        let grads_lookup = GradientsParams::from_grads(grads_lookup, &model);
+        let grads_tied = model.forward(tokens).sum().backward();
+        let grads_tied = GradientsParams::from_grads(grads_tied, &model);
+
+        let grad_lookup = grads_lookup.get::<TestBackend, 2>(weight_id).unwrap();
+        let grad_tied = grads_tied.get::<TestBackend, 2>(weight_id).unwrap();
+
+        // Rows not looked up only receive gradients from the head.
+        assert_ne!(grad_lookup.to_data(), grad_tied.to_data());
+        assert!(grad_lookup.slice([2..5, 0..4]).abs().sum().into_scalar() == 0.0);
+        assert!(grad_tied.slice([2..5, 0..4]).abs().sum().into_scalar() > 0.0);
+    }
+}
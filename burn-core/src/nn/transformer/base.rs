@@ -0,0 +1,230 @@
+use burn_tensor::Bool;
+
+use super::{
+    TransformerDecoder, TransformerDecoderConfig, TransformerDecoderInput, TransformerEncoder,
+    TransformerEncoderConfig, TransformerEncoderInput,
+};
+use crate::{
+    self as burn,
+    config::Config,
+    module::Module,
+    nn::{attention::generate_autoregressive_mask, Initializer},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create a [Transformer](Transformer) module.
+#[derive(Config)]
+pub struct TransformerConfig {
+    /// The size of the model.
+    pub d_model: usize,
+    /// The size of the position-wise feed-forward network.
+    pub d_ff: usize,
+    /// The number of attention heads.
+    pub n_heads: usize,
+    /// The number of encoder layers.
+    pub n_encoder_layers: usize,
+    /// The number of decoder layers.
+    pub n_decoder_layers: usize,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// Layer norm will be applied first instead of after the other modules.
+    #[config(default = false)]
+    pub norm_first: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The encoder-decoder transformer module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
+///
+/// # Params
+///
+/// - encoder: transformer encoder applied on the source sequence.
+/// - decoder: transformer decoder applied on the target sequence, attending to the encoded source.
+#[derive(Module, Debug)]
+pub struct Transformer<B: Backend> {
+    encoder: TransformerEncoder<B>,
+    decoder: TransformerDecoder<B>,
+}
+
+impl TransformerConfig {
+    /// Initialize a new [transformer](Transformer) module.
+    pub fn init<B: Backend>(&self) -> Transformer<B> {
+        Transformer {
+            encoder: self.encoder_config().init(),
+            decoder: self.decoder_config().init(),
+        }
+    }
+
+    /// Initialize a new [transformer](Transformer) module with a [record](TransformerRecord).
+    pub fn init_with<B: Backend>(&self, record: TransformerRecord<B>) -> Transformer<B> {
+        Transformer {
+            encoder: self.encoder_config().init_with(record.encoder),
+            decoder: self.decoder_config().init_with(record.decoder),
+        }
+    }
+
+    /// The configuration of the [encoder](TransformerEncoder).
+    pub fn encoder_config(&self) -> TransformerEncoderConfig {
+        TransformerEncoderConfig::new(self.d_model, self.d_ff, self.n_heads, self.n_encoder_layers)
+            .with_dropout(self.dropout)
+            .with_norm_first(self.norm_first)
+            .with_initializer(self.initializer.clone())
+    }
+
+    /// The configuration of the [decoder](TransformerDecoder).
+    pub fn decoder_config(&self) -> TransformerDecoderConfig {
+        TransformerDecoderConfig::new(self.d_model, self.d_ff, self.n_heads, self.n_decoder_layers)
+            .with_dropout(self.dropout)
+            .with_norm_first(self.norm_first)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+/// [Transformer](Transformer) forward pass input argument.
+#[derive(Debug)]
+pub struct TransformerInput<B: Backend> {
+    source: Tensor<B, 3>,
+    source_mask_pad: Option<Tensor<B, 2, Bool>>,
+    target: Tensor<B, 3>,
+    target_mask_pad: Option<Tensor<B, 2, Bool>>,
+    target_mask_attn: Option<Tensor<B, 3, Bool>>,
+}
+
+impl<B: Backend> TransformerInput<B> {
+    /// Create a [transformer](Transformer) input argument.
+    pub fn new(source: Tensor<B, 3>, target: Tensor<B, 3>) -> Self {
+        Self {
+            source,
+            source_mask_pad: None,
+            target,
+            target_mask_pad: None,
+            target_mask_attn: None,
+        }
+    }
+
+    /// Register the source padding mask.
+    ///
+    /// The mask is used by the encoder self-attention and by the decoder cross-attention.
+    pub fn source_mask_pad(mut self, mask_pad: Tensor<B, 2, Bool>) -> Self {
+        self.source_mask_pad = Some(mask_pad);
+        self
+    }
+
+    /// Register the target padding mask.
+    pub fn target_mask_pad(mut self, mask_pad: Tensor<B, 2, Bool>) -> Self {
+        self.target_mask_pad = Some(mask_pad);
+        self
+    }
+
+    /// Register the target attention mask, replacing the default causal mask.
+    pub fn target_mask_attn(mut self, mask_attn: Tensor<B, 3, Bool>) -> Self {
+        self.target_mask_attn = Some(mask_attn);
+        self
+    }
+}
+
+impl<B: Backend> Transformer<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// The target is decoded with a causal mask, so that each position only attends to the
+    /// previous ones, unless another attention mask is registered on the input.
+    ///
+    /// # Shapes
+    ///
+    /// - source: `[batch_size, source_length, d_model]`
+    /// - target: `[batch_size, target_length, d_model]`
+    /// - output: `[batch_size, target_length, d_model]`
+    pub fn forward(&self, input: TransformerInput<B>) -> Tensor<B, 3> {
+        let mut encoder_input = TransformerEncoderInput::new(input.source);
+        if let Some(mask_pad) = &input.source_mask_pad {
+            encoder_input = encoder_input.mask_pad(mask_pad.clone());
+        }
+
+        let memory = self.encode(encoder_input);
+
+        let [batch_size, target_length, _] = input.target.dims();
+        let target_mask_attn = input.target_mask_attn.unwrap_or_else(|| {
+            generate_autoregressive_mask(batch_size, target_length, &input.target.device())
+        });
+
+        let mut decoder_input =
+            TransformerDecoderInput::new(input.target, memory).target_mask_attn(target_mask_attn);
+        if let Some(mask_pad) = input.target_mask_pad {
+            decoder_input = decoder_input.target_mask_pad(mask_pad);
+        }
+        if let Some(mask_pad) = input.source_mask_pad {
+            decoder_input = decoder_input.memory_mask_pad(mask_pad);
+        }
+
+        self.decode(decoder_input)
+    }
+
+    /// Encodes the source sequence into the memory attended by the decoder.
+    ///
+    /// Useful during inference, where the source is encoded once and the target is decoded
+    /// token by token with [decode](Transformer::decode).
+    pub fn encode(&self, input: TransformerEncoderInput<B>) -> Tensor<B, 3> {
+        self.encoder.forward(input)
+    }
+
+    /// Decodes the target sequence while attending to the encoded memory.
+    pub fn decode(&self, input: TransformerDecoderInput<B>) -> Tensor<B, 3> {
+        self.decoder.forward(input)
+    }
+
+    /// Returns the encoder.
+    pub fn encoder(&self) -> &TransformerEncoder<B> {
+        &self.encoder
+    }
+
+    /// Returns the decoder.
+    pub fn decoder(&self) -> &TransformerDecoder<B> {
+        &self.decoder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_forward_shape() {
+        TestBackend::seed(0);
+        let transformer = TransformerConfig::new(12, 24, 2, 2, 3).init::<TestBackend>();
+
+        let source = Tensor::random([3, 5, 12], Distribution::Default);
+        let target = Tensor::random([3, 4, 12], Distribution::Default);
+
+        let output = transformer.forward(TransformerInput::new(source, target));
+
+        assert_eq!(output.dims(), [3, 4, 12]);
+    }
+
+    #[test]
+    fn test_target_is_causally_masked() {
+        TestBackend::seed(0);
+        let transformer = TransformerConfig::new(12, 24, 2, 2, 2).init::<TestBackend>();
+
+        let source = Tensor::<TestBackend, 3>::random([2, 5, 12], Distribution::Default);
+        let target = Tensor::<TestBackend, 3>::random([2, 4, 12], Distribution::Default);
+        let target_changed = target.clone().slice_assign(
+            [0..2, 3..4, 0..12],
+            Tensor::random([2, 1, 12], Distribution::Default),
+        );
+
+        let output_1 = transformer.forward(TransformerInput::new(source.clone(), target));
+        let output_2 = transformer.forward(TransformerInput::new(source, target_changed));
+
+        // Changing the last target token shouldn't change the previous outputs.
+        output_1
+            .slice([0..2, 0..3, 0..12])
+            .into_data()
+            .assert_approx_eq(&output_2.slice([0..2, 0..3, 0..12]).into_data(), 3);
+    }
+}
@@ -259,8 +259,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             self_attn_input = self_attn_input.mask_attn(mask_attn.clone());
         }
 
-        let x_1 = self.self_attn.forward(self_attn_input);
-        let x_1 = self.dropout.forward(x_1.context) + x_0;
+        let x_1 = self.self_attn.forward_context(self_attn_input);
+        let x_1 = self.dropout.forward(x_1) + x_0;
         let x_1 = self.norm_1.forward(x_1);
 
         let mut cross_attn_input =
@@ -272,8 +272,8 @@ impl<B: Backend> TransformerDecoderLayer<B> {
             cross_attn_input = cross_attn_input.mask_attn(mask_attn.clone());
         }
 
-        let x_2 = self.cross_attn.forward(cross_attn_input);
-        let x_2 = self.dropout.forward(x_2.context) + x_1;
+        let x_2 = self.cross_attn.forward_context(cross_attn_input);
+        let x_2 = self.dropout.forward(x_2) + x_1;
         let x_2 = self.norm_2.forward(x_2);
 
         let x_3 = self.pwff.forward(x_2.clone());
@@ -236,8 +236,8 @@ impl<B: Backend> TransformerEncoderLayer<B> {
             input_mhs = input_mhs.mask_attn(mask_attn);
         }
 
-        let x_1 = self.mha.forward(input_mhs);
-        let x_1 = self.dropout.forward(x_1.context) + input;
+        let x_1 = self.mha.forward_context(input_mhs);
+        let x_1 = self.dropout.forward(x_1) + input;
         let x_1 = self.norm_1.forward(x_1);
 
         let x_2 = self.pwff.forward(x_1.clone());
@@ -1,7 +1,9 @@
+mod base;
 mod decoder;
 mod encoder;
 mod pwff;
 
+pub use base::*;
 pub use decoder::*;
 pub use encoder::*;
 pub use pwff::*;
@@ -0,0 +1,165 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// AdaDelta configuration.
+#[derive(Config)]
+pub struct AdaDeltaConfig {
+    /// Coefficient used to compute the running averages of the squared gradients and updates.
+    #[config(default = 0.9)]
+    rho: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// AdaDelta optimizer as described in the paper
+/// [ADADELTA: An Adaptive Learning Rate Method](https://arxiv.org/abs/1212.5701).
+///
+/// The update is already scaled by the running average of the previous updates, so the learning
+/// rate is usually set to `1.0`.
+pub struct AdaDelta<B: Backend> {
+    rho: f32,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// AdaDelta state.
+#[derive(Record, Clone, new)]
+pub struct AdaDeltaState<B: Backend, const D: usize> {
+    square_avg: Tensor<B, D>,
+    acc_delta: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for AdaDelta<B> {
+    type State<const D: usize> = AdaDeltaState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let (square_avg, acc_delta) = match state {
+            Some(state) => (state.square_avg, state.acc_delta),
+            None => (grad.zeros_like(), grad.zeros_like()),
+        };
+
+        let square_avg = square_avg
+            .mul_scalar(self.rho)
+            .add(grad.clone().powf(2.0).mul_scalar(1.0 - self.rho));
+        let delta = acc_delta
+            .clone()
+            .add_scalar(self.epsilon)
+            .sqrt()
+            .div(square_avg.clone().add_scalar(self.epsilon).sqrt())
+            .mul(grad);
+        let acc_delta = acc_delta
+            .mul_scalar(self.rho)
+            .add(delta.clone().powf(2.0).mul_scalar(1.0 - self.rho));
+
+        let state = AdaDeltaState::new(square_avg, acc_delta);
+
+        (tensor - delta.mul_scalar(lr), Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.square_avg = state.square_avg.to_device(device);
+        state.acc_delta = state.acc_delta.to_device(device);
+        state
+    }
+}
+
+impl AdaDeltaConfig {
+    /// Initialize AdaDelta optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<AdaDelta<B::InnerBackend>, M, B> {
+        let optim = AdaDelta {
+            rho: self.rho,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn test_adadelta_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = AdaDeltaConfig::new().init();
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let _linear = optimizer.step(1.0, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer = AdaDeltaConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adadelta_optimizer_with_numbers() {
+        let linear = nn::LinearConfig::new(2, 1).init_with(nn::LinearRecord {
+            weight: Tensor::from_floats([[0.5], [-0.5]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optimizer = AdaDeltaConfig::new().init();
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optimizer.step(1.0, linear, grads);
+
+        // The first update is sqrt(epsilon / (1 - rho)) in the direction of the gradient.
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.496838], [-0.503162]]), 5);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.003162]), 5);
+    }
+}
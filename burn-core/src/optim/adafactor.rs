@@ -0,0 +1,242 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create the [Adafactor](Adafactor) optimizer.
+#[derive(Config)]
+pub struct AdafactorConfig {
+    /// Exponent used to compute the decay rate of the second moment at each step.
+    #[config(default = -0.8)]
+    decay_rate: f32,
+    /// Regularization constant added to the squared gradient.
+    #[config(default = 1e-30)]
+    epsilon_1: f32,
+    /// Minimum scale of the parameters used when scaling the learning rate.
+    #[config(default = 1e-3)]
+    epsilon_2: f32,
+    /// Threshold of the root mean square of the update above which it is clipped.
+    #[config(default = 1.0)]
+    clip_threshold: f32,
+    /// Whether the learning rate is scaled by the root mean square of the parameters.
+    #[config(default = true)]
+    scale_parameter: bool,
+    /// Decoupled weight decay, applied to the parameters proportionally to the learning rate.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adafactor optimizer as described in the paper
+/// [Adafactor: Adaptive Learning Rates with Sublinear Memory Cost](https://arxiv.org/abs/1804.04235).
+///
+/// The second moment of tensors with at least two dimensions is factored into a row and a column
+/// estimate over their last two dimensions, so the memory used by the state grows with the sum
+/// of the dimensions instead of their product.
+pub struct Adafactor<B: Backend> {
+    decay_rate: f32,
+    epsilon_1: f32,
+    epsilon_2: f32,
+    clip_threshold: f32,
+    scale_parameter: bool,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Adafactor state.
+///
+/// Only the row and column estimates are kept for tensors with at least two dimensions, the full
+/// second moment is kept otherwise.
+#[derive(Record, Clone, new)]
+pub struct AdafactorState<B: Backend, const D: usize> {
+    time: usize,
+    row: Option<Tensor<B, D>>,
+    col: Option<Tensor<B, D>>,
+    moment_2: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adafactor<B> {
+    type State<const D: usize> = AdafactorState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (time, row, col, moment_2) = match state {
+            Some(state) => (state.time, state.row, state.col, state.moment_2),
+            None => (0, None, None, None),
+        };
+        let time = time + 1;
+        let beta_2 = 1.0 - (time as f64).powf(self.decay_rate as f64);
+
+        let grad_squared = grad.clone().powf(2.0).add_scalar(self.epsilon_1);
+        let ema = |previous: Option<Tensor<B, D>>, current: Tensor<B, D>| match previous {
+            Some(previous) => previous
+                .mul_scalar(beta_2)
+                .add(current.mul_scalar(1.0 - beta_2)),
+            None => current,
+        };
+
+        let (state, moment_2_estimate) = if D >= 2 {
+            let row = ema(row, grad_squared.clone().mean_dim(D - 1));
+            let col = ema(col, grad_squared.mean_dim(D - 2));
+            let estimate = row
+                .clone()
+                .div(row.clone().mean_dim(D - 2))
+                .mul(col.clone());
+
+            (
+                AdafactorState::new(time, Some(row), Some(col), None),
+                estimate,
+            )
+        } else {
+            let moment_2 = ema(moment_2, grad_squared);
+
+            (
+                AdafactorState::new(time, None, None, Some(moment_2.clone())),
+                moment_2,
+            )
+        };
+
+        let update = grad.div(moment_2_estimate.sqrt());
+        let update_rms = root_mean_square(update.clone());
+        let update = update.div(
+            update_rms
+                .div_scalar(self.clip_threshold)
+                .clamp_min(1.0)
+                .reshape([1; D]),
+        );
+
+        let update = match self.scale_parameter {
+            true => update.mul(
+                root_mean_square(tensor.clone())
+                    .clamp_min(self.epsilon_2)
+                    .reshape([1; D]),
+            ),
+            false => update,
+        };
+
+        let tensor_updated = tensor.clone() - tensor.mul_scalar(lr * self.weight_decay as f64);
+        let tensor_updated = tensor_updated - update.mul_scalar(lr);
+
+        (tensor_updated, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.row = state.row.map(|row| row.to_device(device));
+        state.col = state.col.map(|col| col.to_device(device));
+        state.moment_2 = state.moment_2.map(|moment| moment.to_device(device));
+        state
+    }
+}
+
+fn root_mean_square<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powf(2.0).mean().sqrt()
+}
+
+impl AdafactorConfig {
+    /// Initialize Adafactor optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adafactor<B::InnerBackend>, M, B> {
+        let optim = Adafactor {
+            decay_rate: self.decay_rate,
+            epsilon_1: self.epsilon_1,
+            epsilon_2: self.epsilon_2,
+            clip_threshold: self.clip_threshold,
+            scale_parameter: self.scale_parameter,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn test_adafactor_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = AdafactorConfig::new().init();
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let _linear = optimizer.step(0.01, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer = AdafactorConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adafactor_optimizer_with_numbers() {
+        let linear = nn::LinearConfig::new(2, 1).init_with(nn::LinearRecord {
+            weight: Tensor::from_floats([[0.5], [-0.5]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optimizer = AdafactorConfig::new().with_scale_parameter(false).init();
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optimizer.step(0.1, linear, grads);
+
+        // The first update of each value is the sign of its gradient.
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.4], [-0.6]]), 3);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.1]), 3);
+    }
+
+    #[test]
+    fn test_adafactor_optimizer_converges() {
+        let mut linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([4, 6], Distribution::Default);
+        let mut optimizer = AdafactorConfig::new().init();
+        let loss_initial = linear.forward(x.clone()).powf(2.0).sum().into_scalar();
+
+        for _ in 0..100 {
+            let loss = linear.forward(x.clone()).powf(2.0).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &linear);
+            linear = optimizer.step(0.1, linear, grads);
+        }
+
+        let loss = linear.forward(x).powf(2.0).sum().into_scalar();
+        assert!(loss < loss_initial * 0.5);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
 };
 use std::marker::PhantomData;
 
-use super::{Optimizer, SimpleOptimizer};
+use super::{GradScaler, MixedPrecisionAdaptor, Optimizer, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
@@ -84,7 +84,32 @@ impl AdamWConfig {
     ///
     /// Returns an optimizer that can be used to optimize a module.
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
-        let optim = AdamW {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize AdamW optimizer updating full precision master weights, for a module trained
+    /// in half precision with the given [gradient scaler](GradScaler).
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grad_scaler: GradScaler,
+    ) -> impl Optimizer<M, B> {
+        let mut optim = MixedPrecisionAdaptor::new(self.optimizer(), grad_scaler);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> AdamW<B> {
+        AdamW {
             momentum: AdaptiveMomentumW {
                 beta_1: self.beta_1,
                 beta_2: self.beta_2,
@@ -92,13 +117,7 @@ impl AdamWConfig {
             },
             weight_decay: self.weight_decay,
             _phantom: Default::default(),
-        };
-
-        let mut optim = OptimizerAdaptor::from(optim);
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
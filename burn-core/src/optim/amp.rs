@@ -0,0 +1,550 @@
+use crate::{
+    self as burn,
+    config::Config,
+    constant,
+    grad_clipping::GradientClipping,
+    module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId},
+    optim::{record::AdaptorRecord, GradientsParams, Optimizer, SimpleOptimizer},
+    record::{PrecisionSettings, Record},
+    LearningRate,
+};
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    ops::FullPrecisionBackend,
+    DataSerialize, ElementConversion, Tensor,
+};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// The full precision backend used for the master weights of a module trained on the
+/// autodiff backend `B`.
+type MasterBackend<B> = FullPrecisionBackend<<B as AutodiffBackend>::InnerBackend>;
+
+/// Configuration to create a [gradient scaler](GradScaler).
+#[derive(Config)]
+pub struct GradScalerConfig {
+    /// The initial scale of the loss.
+    #[config(default = 65536.0)]
+    init_scale: f64,
+    /// The factor by which the scale grows after `growth_interval` steps without overflow.
+    #[config(default = 2.0)]
+    growth_factor: f64,
+    /// The factor by which the scale is reduced when the gradients overflow.
+    #[config(default = 0.5)]
+    backoff_factor: f64,
+    /// The number of consecutive steps without overflow after which the scale grows.
+    #[config(default = 2000)]
+    growth_interval: usize,
+}
+
+/// Dynamic loss scaling for mixed precision training.
+///
+/// The loss is multiplied by the scale before the backward pass, so the small gradients computed
+/// in half precision don't underflow to zero. The [mixed precision adaptor](MixedPrecisionAdaptor)
+/// divides the gradients by the same scale before the optimizer step, skips the steps where the
+/// gradients overflowed and adjusts the scale accordingly.
+///
+/// The scaler is a handle shared by all its clones. With the learner of `burn-train`, it is given to
+/// both the optimizer and the learner builder, which scales the losses of the training steps:
+///
+/// ```ignore
+/// let grad_scaler = GradScalerConfig::new().init();
+/// let learner = LearnerBuilder::new(ARTIFACT_DIR)
+///     .mixed_precision(grad_scaler.clone())
+///     .build(model, AdamConfig::new().init_mixed_precision(grad_scaler), 1e-4);
+/// ```
+///
+/// The training step then creates its output from the loss:
+///
+/// ```ignore
+/// TrainOutput::from_loss(self, output.loss.clone(), output)
+/// ```
+///
+/// The scaler is also a constant [module](crate::module::Module), so it can be stored in the model
+/// to scale the loss in custom training loops.
+#[derive(Clone, Debug)]
+pub struct GradScaler {
+    growth_factor: f64,
+    backoff_factor: f64,
+    growth_interval: usize,
+    state: Arc<Mutex<GradScalerRecord>>,
+}
+
+/// Record of the [gradient scaler](GradScaler).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GradScalerRecord {
+    scale: f64,
+    num_steps_without_overflow: usize,
+}
+
+impl Record for GradScalerRecord {
+    type Item<S: PrecisionSettings> = Self;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        self
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        item
+    }
+}
+
+constant!(GradScaler);
+
+impl GradScalerConfig {
+    /// Initialize a new [gradient scaler](GradScaler).
+    pub fn init(&self) -> GradScaler {
+        assert!(
+            self.init_scale > 0.0,
+            "The initial scale of the loss must be positive."
+        );
+
+        GradScaler {
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            state: Arc::new(Mutex::new(GradScalerRecord {
+                scale: self.init_scale,
+                num_steps_without_overflow: 0,
+            })),
+        }
+    }
+}
+
+impl GradScaler {
+    /// The current scale of the loss.
+    pub fn scale(&self) -> f64 {
+        self.state.lock().unwrap().scale
+    }
+
+    /// Multiply the loss by the current scale, before calling `backward` on it.
+    pub fn scale_loss<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale())
+    }
+
+    /// Update the scale after a step, reducing it if the gradients overflowed and growing it
+    /// after enough steps without overflow.
+    pub(crate) fn update(&self, overflow: bool) {
+        let mut state = self.state.lock().unwrap();
+
+        if overflow {
+            state.scale *= self.backoff_factor;
+            state.num_steps_without_overflow = 0;
+            log::warn!(
+                "Gradients overflowed, skipping the step and reducing the loss scale to {}",
+                state.scale
+            );
+            return;
+        }
+
+        state.num_steps_without_overflow += 1;
+        if state.num_steps_without_overflow >= self.growth_interval {
+            state.scale *= self.growth_factor;
+            state.num_steps_without_overflow = 0;
+        }
+    }
+
+    pub(crate) fn to_record(&self) -> GradScalerRecord {
+        self.state.lock().unwrap().clone()
+    }
+
+    pub(crate) fn load_record(self, record: GradScalerRecord) -> Self {
+        *self.state.lock().unwrap() = record;
+        self
+    }
+}
+
+/// Wrapper struct that adapts any [simple optimizer](SimpleOptimizer) running in full precision
+/// into an [optimizer](Optimizer) for a module trained in half precision.
+///
+/// A full precision copy of each parameter, the master weights, is updated by the optimizer with
+/// the unscaled gradients, then converted to the precision of the module. Small updates are
+/// therefore accumulated in the master weights instead of being rounded away.
+///
+/// The master weights are saved in full precision in the records, so resuming the training doesn't
+/// lose the updates that were too small to be represented in the parameters of the module.
+pub struct MixedPrecisionAdaptor<O, M, B>
+where
+    O: SimpleOptimizer<MasterBackend<B>>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    optim: O,
+    records: HashMap<ParamId, AdaptorRecord<O, MasterBackend<B>>>,
+    master_weights: HashMap<ParamId, Tensor<MasterBackend<B>, 1>>,
+    grad_scaler: GradScaler,
+    grad_clipping: Option<GradientClipping>,
+    module: PhantomData<M>,
+}
+
+/// Record of the [mixed precision adaptor](MixedPrecisionAdaptor).
+pub struct MixedPrecisionAdaptorRecord<R: Record, B: Backend> {
+    optim: R,
+    master_weights: HashMap<ParamId, Tensor<B, 1>>,
+    grad_scaler: GradScalerRecord,
+}
+
+/// Record item of the [mixed precision adaptor](MixedPrecisionAdaptor).
+///
+/// The master weights are always serialized in full precision, whatever the precision settings.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct MixedPrecisionAdaptorRecordItem<R: Record, S: PrecisionSettings> {
+    optim: R::Item<S>,
+    master_weights: HashMap<String, DataSerialize<f32>>,
+    grad_scaler: GradScalerRecord,
+}
+
+impl<R: Record, B: Backend> Record for MixedPrecisionAdaptorRecord<R, B> {
+    type Item<S: PrecisionSettings> = MixedPrecisionAdaptorRecordItem<R, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        MixedPrecisionAdaptorRecordItem {
+            optim: self.optim.into_item(),
+            master_weights: self
+                .master_weights
+                .into_iter()
+                .map(|(id, weight)| (id.to_string(), weight.into_data().convert().serialize()))
+                .collect(),
+            grad_scaler: self.grad_scaler,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            optim: R::from_item(item.optim),
+            master_weights: item
+                .master_weights
+                .into_iter()
+                .map(|(id, data)| (ParamId::from(id), Tensor::from_data(data.convert())))
+                .collect(),
+            grad_scaler: item.grad_scaler,
+        }
+    }
+}
+
+impl<O, M, B> MixedPrecisionAdaptor<O, M, B>
+where
+    O: SimpleOptimizer<MasterBackend<B>>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Create a new mixed precision adaptor for the given optimizer, unscaling the gradients with
+    /// the given [gradient scaler](GradScaler).
+    pub fn new(optim: O, grad_scaler: GradScaler) -> Self {
+        Self {
+            optim,
+            records: HashMap::new(),
+            master_weights: HashMap::new(),
+            grad_scaler,
+            grad_clipping: None,
+            module: PhantomData,
+        }
+    }
+
+    /// Sets the gradient clipping, applied to the unscaled gradients.
+    pub fn with_grad_clipping(mut self, gradient_clipping: GradientClipping) -> Self {
+        self.grad_clipping = Some(gradient_clipping);
+        self
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for MixedPrecisionAdaptor<O, M, B>
+where
+    O: SimpleOptimizer<MasterBackend<B>>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type Record = MixedPrecisionAdaptorRecord<
+        HashMap<ParamId, AdaptorRecord<O, MasterBackend<B>>>,
+        MasterBackend<B>,
+    >;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let mut grads_unscaled = GradientsParams::new();
+        let mut unscaler = GradientsUnscaler::<M, B>::new(
+            grads,
+            &mut grads_unscaled,
+            1.0 / self.grad_scaler.scale(),
+        );
+        module.visit(&mut unscaler);
+
+        let squared_sum = match unscaler.squared_sum {
+            Some(squared_sum) => squared_sum,
+            None => return module,
+        };
+        // The squared sum is only finite if all gradients are finite.
+        let overflow = !squared_sum.clone().into_scalar().elem::<f64>().is_finite();
+        self.grad_scaler.update(overflow);
+
+        if overflow {
+            return module;
+        }
+
+        let global_norm_scale = match &self.grad_clipping {
+            Some(GradientClipping::GlobalNorm(max_norm)) => Some(
+                squared_sum
+                    .sqrt()
+                    .add_scalar(1e-6)
+                    .recip()
+                    .mul_scalar(*max_norm)
+                    .clamp_max(1.0),
+            ),
+            _ => None,
+        };
+
+        let mut mapper = MasterWeightsMapper::<M, B, O>::new(
+            &self.optim,
+            &mut self.records,
+            &mut self.master_weights,
+            &mut grads_unscaled,
+            lr,
+            self.grad_clipping.as_ref(),
+            global_norm_scale,
+        );
+        module.map(&mut mapper)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        MixedPrecisionAdaptorRecord {
+            optim: self.records.clone(),
+            master_weights: self.master_weights.clone(),
+            grad_scaler: self.grad_scaler.to_record(),
+        }
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.records = record.optim;
+        self.master_weights = record.master_weights;
+        self.grad_scaler = self.grad_scaler.load_record(record.grad_scaler);
+        self
+    }
+}
+
+#[derive(new)]
+struct GradientsUnscaler<'a, M, B: AutodiffBackend> {
+    grads: GradientsParams,
+    grads_unscaled: &'a mut GradientsParams,
+    factor: f64,
+    #[new(default)]
+    squared_sum: Option<Tensor<MasterBackend<B>, 1>>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsUnscaler<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            let grad = grad.to_full_precision().mul_scalar(self.factor);
+            let squared_sum = grad.clone().powf(2.0).sum();
+
+            self.squared_sum = Some(match self.squared_sum.take() {
+                Some(acc) => {
+                    let device = acc.device();
+                    acc + squared_sum.to_device(&device)
+                }
+                None => squared_sum,
+            });
+            self.grads_unscaled
+                .register::<MasterBackend<B>, D>(id.clone(), grad);
+        }
+    }
+}
+
+#[derive(new)]
+struct MasterWeightsMapper<'a, M, B, O>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+    O: SimpleOptimizer<MasterBackend<B>>,
+{
+    optimizer: &'a O,
+    records: &'a mut HashMap<ParamId, AdaptorRecord<O, MasterBackend<B>>>,
+    master_weights: &'a mut HashMap<ParamId, Tensor<MasterBackend<B>, 1>>,
+    grads: &'a mut GradientsParams,
+    lr: LearningRate,
+    grad_clipping: Option<&'a GradientClipping>,
+    global_norm_scale: Option<Tensor<MasterBackend<B>, 1>>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, M, B, O> ModuleMapper<B> for MasterWeightsMapper<'a, M, B, O>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+    O: SimpleOptimizer<MasterBackend<B>>,
+{
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let grad = match self.grads.remove::<MasterBackend<B>, D>(id) {
+            Some(grad) => grad,
+            None => return tensor,
+        };
+
+        let device = grad.device();
+        let is_require_grad = tensor.is_require_grad();
+        let (key, record) = self.records.remove_entry(id).unzip();
+        // The master weights are flattened, since their rank isn't known when saving the record.
+        let shape = tensor.shape();
+        let master_weight = match self.master_weights.remove(id) {
+            Some(master_weight) if master_weight.dims()[0] == shape.num_elements() => {
+                master_weight.reshape(shape).to_device(&device)
+            }
+            _ => tensor.inner().to_full_precision().to_device(&device),
+        };
+
+        let clipped_grad = match (self.grad_clipping, &self.global_norm_scale) {
+            (Some(GradientClipping::GlobalNorm(_)), Some(scale)) => {
+                grad.mul(scale.clone().to_device(&device).reshape([1; D]))
+            }
+            (Some(g_clipping), _) => g_clipping.clip_gradient(grad),
+            (None, _) => grad,
+        };
+
+        let (master_weight, state) = self.optimizer.step(
+            self.lr,
+            master_weight,
+            clipped_grad,
+            record.map(|record| O::to_device(record.into_state(), &device)),
+        );
+
+        if let Some(state) = state {
+            self.records.insert(
+                key.unwrap_or_else(|| id.clone()),
+                AdaptorRecord::from_state(state),
+            );
+        }
+
+        let mut tensor = Tensor::from_inner(Tensor::<B::InnerBackend, D>::from_full_precision(
+            master_weight.clone(),
+        ));
+        let num_elements = master_weight.shape().num_elements();
+        self.master_weights
+            .insert(id.clone(), master_weight.reshape([num_elements]));
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        optim::{Sgd, SgdConfig},
+        tensor::Distribution,
+        TestAutodiffBackend,
+    };
+
+    #[test]
+    fn test_loss_scale_reduced_on_overflow_and_grown_after_interval() {
+        let grad_scaler = GradScalerConfig::new()
+            .with_init_scale(8.0)
+            .with_growth_interval(2)
+            .init();
+
+        grad_scaler.update(true);
+        assert_eq!(grad_scaler.scale(), 4.0);
+
+        grad_scaler.update(false);
+        assert_eq!(grad_scaler.scale(), 4.0);
+        grad_scaler.update(false);
+        assert_eq!(grad_scaler.scale(), 8.0);
+    }
+
+    #[test]
+    fn test_step_unscales_gradients() {
+        let grad_scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+        let mut optim = sgd(grad_scaler.clone());
+        let linear = layer();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default);
+
+        let mut optim_reference = SgdConfig::new().init();
+        let grads = linear.forward(x.clone()).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let expected = optim_reference.step(0.1, linear.clone(), grads);
+
+        let grads = grad_scaler.scale_loss(linear.forward(x).sum()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optim.step(0.1, linear, grads);
+
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 3);
+        assert!(linear.weight.val().is_require_grad());
+    }
+
+    #[test]
+    fn test_step_skipped_on_overflow() {
+        let grad_scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+        let mut optim = sgd(grad_scaler.clone());
+        let linear = layer();
+        let weight = linear.weight.to_data();
+
+        let grads = linear
+            .forward(Tensor::ones([1, 4]).mul_scalar(f32::INFINITY))
+            .sum()
+            .backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optim.step(0.1, linear, grads);
+
+        linear.weight.to_data().assert_approx_eq(&weight, 3);
+        assert_eq!(grad_scaler.scale(), 2.0);
+    }
+
+    #[test]
+    fn test_records_save_grad_scaler_state() {
+        let grad_scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+        let optim = sgd(grad_scaler.clone());
+        grad_scaler.update(true);
+
+        let record = optim.to_record();
+        let grad_scaler_loaded = GradScalerConfig::new().init();
+        sgd(grad_scaler_loaded.clone()).load_record(record);
+
+        assert_eq!(grad_scaler_loaded.scale(), 2.0);
+    }
+
+    #[test]
+    fn test_records_save_master_weights() {
+        let grad_scaler = GradScalerConfig::new().init();
+        let mut optim = sgd(grad_scaler.clone());
+        let linear = layer();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default);
+
+        let grads = grad_scaler.scale_loss(linear.forward(x).sum()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optim.step(0.1, linear, grads);
+
+        let item = optim
+            .to_record()
+            .into_item::<crate::record::HalfPrecisionSettings>();
+        let optim_loaded = sgd(GradScalerConfig::new().init()).load_record(Record::from_item(item));
+
+        let id = linear.weight.id.clone();
+        optim.master_weights[&id]
+            .to_data()
+            .assert_approx_eq(&optim_loaded.master_weights[&id].to_data(), 6);
+    }
+
+    fn sgd(
+        grad_scaler: GradScaler,
+    ) -> MixedPrecisionAdaptor<
+        Sgd<MasterBackend<TestAutodiffBackend>>,
+        Linear<TestAutodiffBackend>,
+        TestAutodiffBackend,
+    > {
+        SgdConfig::new().init_mixed_precision(grad_scaler)
+    }
+
+    fn layer() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(4, 2).init()
+    }
+}
@@ -2,6 +2,7 @@ use burn_tensor::backend::Backend;
 
 use crate as burn;
 use crate::record::Record;
+use crate::LearningRate;
 
 use crate::config::Config;
 use crate::tensor::{ElementConversion, Tensor};
@@ -49,6 +50,21 @@ impl<B: Backend> WeightDecay<B> {
     ) -> Tensor<B, D> {
         tensor.mul_scalar(self.penalty).add(grad)
     }
+
+    /// Decays a tensor param directly, proportionally to the learning rate, instead of
+    /// transforming its gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor param of the last iteration.
+    /// * `lr` - Learning rate.
+    ///
+    /// # Returns
+    ///
+    /// * `tensor` - Decayed tensor param.
+    pub fn decay<const D: usize>(&self, tensor: Tensor<B, D>, lr: LearningRate) -> Tensor<B, D> {
+        tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.penalty)
+    }
 }
 
 impl<B: Backend, const D: usize> WeightDecayState<B, D> {
@@ -0,0 +1,273 @@
+use crate::{
+    self as burn,
+    config::Config,
+    module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId},
+    record::{PrecisionSettings, Record},
+};
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    container::TensorContainer,
+    Tensor,
+};
+use serde::{Deserialize, Serialize};
+
+/// Configuration to create a [model exponential moving average](ModelEma).
+#[derive(Config)]
+pub struct ModelEmaConfig {
+    /// The weight of the average when it is updated, the weight of the model being `1 - decay`.
+    #[config(default = 0.999)]
+    decay: f64,
+    /// The number of calls to [update](ModelEma::update) between each update of the average.
+    #[config(default = 1)]
+    update_every: usize,
+}
+
+/// Exponential moving average of the parameters of a model, maintained during training.
+///
+/// The averaged parameters usually generalize better than the parameters of the last step, so
+/// they can be used for validation with [valid](ModelEma::valid), or swapped into the trained
+/// model with [apply](ModelEma::apply) before saving it.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut ema = ModelEmaConfig::new().init(&model);
+///
+/// for batch in dataloader.iter() {
+///     let grads = model.forward(batch).backward();
+///     model = optim.step(lr, model, GradientsParams::from_grads(grads, &model));
+///     ema.update(&model);
+/// }
+///
+/// let model_valid = ema.valid();
+/// ```
+pub struct ModelEma<B: AutodiffBackend, M: AutodiffModule<B>> {
+    ema: M::InnerModule,
+    decay: f64,
+    update_every: usize,
+    step: usize,
+}
+
+/// Record of the [model exponential moving average](ModelEma).
+pub struct ModelEmaRecord<R: Record> {
+    module: R,
+    step: usize,
+}
+
+/// Record item of the [model exponential moving average](ModelEma).
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ModelEmaRecordItem<R: Record, S: PrecisionSettings> {
+    module: R::Item<S>,
+    step: usize,
+}
+
+impl<R: Record> Record for ModelEmaRecord<R> {
+    type Item<S: PrecisionSettings> = ModelEmaRecordItem<R, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ModelEmaRecordItem {
+            module: self.module.into_item(),
+            step: self.step,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        Self {
+            module: R::from_item(item.module),
+            step: item.step,
+        }
+    }
+}
+
+impl ModelEmaConfig {
+    /// Initialize the average with the parameters of the given model.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self, module: &M) -> ModelEma<B, M> {
+        assert!(
+            self.update_every > 0,
+            "The number of calls between each update must be positive."
+        );
+
+        ModelEma {
+            ema: module.valid(),
+            decay: self.decay,
+            update_every: self.update_every,
+            step: 0,
+        }
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModelEma<B, M> {
+    /// Update the average with the parameters of the given model.
+    pub fn update(&mut self, module: &M) {
+        self.step += 1;
+
+        if self.step % self.update_every != 0 {
+            return;
+        }
+
+        let mut collector = ParamsCollector::default();
+        module.valid().visit(&mut collector);
+
+        let mut mapper = EmaUpdater::new(collector.params, self.decay);
+        self.ema = self.ema.clone().map(&mut mapper);
+    }
+
+    /// The model with the averaged parameters, to be used for validation.
+    pub fn valid(&self) -> M::InnerModule {
+        self.ema.clone()
+    }
+
+    /// Replace the parameters of the given model by the averaged parameters.
+    pub fn apply(&self, module: M) -> M {
+        let mut collector = ParamsCollector::default();
+        self.ema.visit(&mut collector);
+
+        let mut mapper = ParamsReplacer::new(collector.params);
+        module.map(&mut mapper)
+    }
+
+    /// Get the current state of the average as a [record](Record).
+    pub fn to_record(&self) -> ModelEmaRecord<<M::InnerModule as Module<B::InnerBackend>>::Record> {
+        ModelEmaRecord {
+            module: self.ema.clone().into_record(),
+            step: self.step,
+        }
+    }
+
+    /// Load the state of the average from a [record](Record).
+    pub fn load_record(
+        mut self,
+        record: ModelEmaRecord<<M::InnerModule as Module<B::InnerBackend>>::Record>,
+    ) -> Self {
+        self.ema = self.ema.load_record(record.module);
+        self.step = record.step;
+        self
+    }
+}
+
+#[derive(Default)]
+struct ParamsCollector {
+    params: TensorContainer<ParamId>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamsCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone());
+    }
+}
+
+#[derive(new)]
+struct EmaUpdater {
+    params: TensorContainer<ParamId>,
+    decay: f64,
+}
+
+impl<B: Backend> ModuleMapper<B> for EmaUpdater {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B, D>(id) {
+            Some(param) => tensor
+                .mul_scalar(self.decay)
+                .add(param.mul_scalar(1.0 - self.decay)),
+            None => tensor,
+        }
+    }
+}
+
+#[derive(new)]
+struct ParamsReplacer {
+    params: TensorContainer<ParamId>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for ParamsReplacer {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B::InnerBackend, D>(id) {
+            Some(param) => {
+                let param = Tensor::from_inner(param);
+                match tensor.is_require_grad() {
+                    true => param.require_grad(),
+                    false => param,
+                }
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{module::Param, nn, tensor::Data, TestAutodiffBackend};
+
+    #[test]
+    fn test_ema_averages_parameters() {
+        let linear = given_linear(1.0);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .init::<TestAutodiffBackend, _>(&linear);
+
+        ema.update(&with_weight(&linear, 3.0));
+        ema.update(&with_weight(&linear, 5.0));
+
+        ema.valid()
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.5, 3.5]]), 3);
+    }
+
+    #[test]
+    fn test_ema_updated_every_n_calls() {
+        let linear = given_linear(1.0);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .with_update_every(2)
+            .init::<TestAutodiffBackend, _>(&linear);
+
+        ema.update(&with_weight(&linear, 3.0));
+        ema.update(&with_weight(&linear, 5.0));
+
+        ema.valid()
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn test_ema_applied_keeps_require_grad() {
+        let linear = given_linear(1.0);
+        let mut ema = ModelEmaConfig::new()
+            .with_decay(0.5)
+            .init::<TestAutodiffBackend, _>(&linear);
+        ema.update(&with_weight(&linear, 3.0));
+
+        let linear = ema.apply(linear);
+
+        assert!(linear.weight.val().is_require_grad());
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0]]), 3);
+    }
+
+    fn given_linear(value: f32) -> nn::Linear<TestAutodiffBackend> {
+        nn::LinearConfig::new(1, 2)
+            .with_bias(false)
+            .init_with(nn::LinearRecord {
+                weight: Tensor::from_floats([[value, value]]).into(),
+                bias: None,
+            })
+    }
+
+    fn with_weight(
+        linear: &nn::Linear<TestAutodiffBackend>,
+        value: f32,
+    ) -> nn::Linear<TestAutodiffBackend> {
+        linear.clone().load_record(nn::LinearRecord {
+            weight: Param::new(
+                linear.weight.id.clone(),
+                Tensor::from_floats([[value, value]]),
+            ),
+            bias: None,
+        })
+    }
+}
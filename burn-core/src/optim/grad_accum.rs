@@ -9,6 +9,7 @@ use super::GradientsParams;
 /// Accumulate gradients into a single [Gradients](AutodiffBackend::Gradients) object.
 pub struct GradientsAccumulator<M> {
     grads: GradientsParams,
+    num_accumulated: usize,
     phantom: PhantomData<M>,
 }
 
@@ -23,6 +24,7 @@ impl<M> GradientsAccumulator<M> {
     pub fn new() -> Self {
         Self {
             grads: GradientsParams::new(),
+            num_accumulated: 0,
             phantom: PhantomData,
         }
     }
@@ -36,12 +38,38 @@ impl<M> GradientsAccumulator<M> {
     {
         let mut visitor = ModuleGradsAccumulator::<M>::new(&mut self.grads, grads);
         module.visit(&mut visitor);
+        self.num_accumulated += 1;
+    }
+
+    /// The number of gradients accumulated since the last reset.
+    pub fn num_accumulated(&self) -> usize {
+        self.num_accumulated
     }
 
     /// Return the accumulated gradients and reset the accumulator state.
     pub fn grads(&mut self) -> GradientsParams {
         let mut grads = GradientsParams::new();
         core::mem::swap(&mut self.grads, &mut grads);
+        self.num_accumulated = 0;
+
+        grads
+    }
+
+    /// Return the mean of the accumulated gradients and reset the accumulator state.
+    ///
+    /// When each loss is averaged over its batch, the mean of the gradients of `n` batches is the
+    /// gradients of the loss averaged over the `n` batches together.
+    pub fn grads_mean<B: AutodiffBackend>(&mut self, module: &M) -> GradientsParams
+    where
+        M: AutodiffModule<B>,
+    {
+        let num_accumulated = self.num_accumulated;
+        let mut grads = self.grads();
+
+        if num_accumulated > 1 {
+            let mut visitor = ModuleGradsScaler::<M>::new(&mut grads, 1.0 / num_accumulated as f64);
+            module.visit(&mut visitor);
+        }
 
         grads
     }
@@ -74,6 +102,22 @@ impl<'a, B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B>
     }
 }
 
+#[derive(new)]
+struct ModuleGradsScaler<'a, M> {
+    grads: &'a mut GradientsParams,
+    factor: f64,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for ModuleGradsScaler<'a, M> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.mul_scalar(self.factor));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +156,34 @@ mod tests {
         assert_eq!(grads.len(), 2)
     }
 
+    #[test]
+    fn test_accumulate_gradients_mean() {
+        let mut accumulator = GradientsAccumulator::new();
+        let layer = layer();
+        let input = random_tensor();
+        let loss_1 = layer.forward(input.clone());
+        let loss_2 = layer.forward(input.clone());
+        let loss_3 = layer.forward(input);
+        let grads_1 = GradientsParams::from_grads(loss_1.backward(), &layer);
+        let grads_2 = GradientsParams::from_grads(loss_2.backward(), &layer);
+        let grads_3 = GradientsParams::from_grads(loss_3.backward(), &layer);
+        let expected = grads_3
+            .get::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 2>(&layer.weight.id)
+            .unwrap();
+
+        accumulator.accumulate(&layer, grads_1);
+        accumulator.accumulate(&layer, grads_2);
+        assert_eq!(accumulator.num_accumulated(), 2);
+
+        let grads = accumulator.grads_mean(&layer);
+        assert_eq!(accumulator.num_accumulated(), 0);
+        grads
+            .get::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
     fn layer() -> Linear<TestAutodiffBackend> {
         LinearConfig::new(20, 20).with_bias(true).init()
     }
@@ -6,7 +6,7 @@ use burn_tensor::{
 
 use crate::module::{AutodiffModule, ParamId};
 
-use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
+use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter, GradientsParamsNorm};
 
 /// Data type that contains gradients for parameters.
 #[derive(Default)]
@@ -74,6 +74,19 @@ impl GradientsParams {
         self
     }
 
+    /// Compute the global L2 norm of the gradients of the given [module](AutodiffModule), i.e.
+    /// the norm of all its gradients concatenated together.
+    ///
+    /// Returns `None` if no gradient is registered for the module.
+    pub fn norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+    ) -> Option<Tensor<B::InnerBackend, 1>> {
+        let mut visitor = GradientsParamsNorm::<M, B>::new(self);
+        module.visit(&mut visitor);
+        visitor.norm()
+    }
+
     /// Extract each tensor gradients for the given [module](AutodiffModule).
     pub fn from_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
         grads: B::Gradients,
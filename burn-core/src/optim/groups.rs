@@ -0,0 +1,254 @@
+use super::{GradientsParams, Optimizer};
+use crate::module::{AutodiffModule, ModulePath, ModuleVisitor, ParamId, PathPattern};
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use crate::LearningRate;
+use alloc::vec::Vec;
+
+/// A group of parameters optimized with their own [optimizer](Optimizer) and learning rate.
+///
+/// The parameters are selected with a [pattern](PathPattern) on their path in the module tree,
+/// e.g. `*.bias` or `encoder.*`.
+pub struct ParamGroup<O> {
+    pattern: PathPattern,
+    optim: O,
+    lr_scale: f64,
+}
+
+impl<O> ParamGroup<O> {
+    /// Create a new parameter group optimizing the parameters matching the given pattern with the
+    /// given optimizer.
+    pub fn new(pattern: &str, optim: O) -> Self {
+        Self {
+            pattern: PathPattern::new(pattern),
+            optim,
+            lr_scale: 1.0,
+        }
+    }
+
+    /// Set the factor applied to the learning rate for the parameters of the group. Default: 1.0
+    pub fn with_lr_scale(mut self, lr_scale: f64) -> Self {
+        self.lr_scale = lr_scale;
+        self
+    }
+}
+
+/// Optimizer applying different optimizer settings to different [groups](ParamGroup) of
+/// parameters, such as disabling weight decay for biases or using a lower learning rate for a
+/// pre-trained backbone.
+///
+/// Each parameter belongs to the first group matching its path, or to the default group when no
+/// group matches.
+///
+/// # Example
+///
+/// ```ignore
+/// let optim = GroupedOptimizer::new(AdamWConfig::new().with_weight_decay(0.01).init())
+///     .with_group(ParamGroup::new(
+///         "*.bias",
+///         AdamWConfig::new().with_weight_decay(0.0).init(),
+///     ))
+///     .with_group(ParamGroup::new("encoder.*", AdamWConfig::new().init()).with_lr_scale(0.1));
+/// ```
+pub struct GroupedOptimizer<O> {
+    default: O,
+    groups: Vec<ParamGroup<O>>,
+}
+
+impl<O> GroupedOptimizer<O> {
+    /// Create a new grouped optimizer using the given optimizer for the parameters not matching
+    /// any group.
+    pub fn new(default: O) -> Self {
+        Self {
+            default,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Add a parameter group, which has a lower priority than the groups already added.
+    pub fn with_group(mut self, group: ParamGroup<O>) -> Self {
+        self.groups.push(group);
+        self
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for GroupedOptimizer<O>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// The record of the default optimizer followed by the records of each group.
+    type Record = Vec<O::Record>;
+
+    fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let mut groups_grads = self
+            .groups
+            .iter()
+            .map(|_| GradientsParams::new())
+            .collect::<Vec<_>>();
+
+        let mut splitter = GradientsGroupSplitter {
+            path: ModulePath::new(),
+            groups: &self.groups,
+            grads: &mut grads,
+            groups_grads: &mut groups_grads,
+        };
+        module.visit(&mut splitter);
+
+        let mut module = self.default.step(lr, module, grads);
+
+        for (group, grads) in self.groups.iter_mut().zip(groups_grads) {
+            if !grads.is_empty() {
+                module = group.optim.step(lr * group.lr_scale, module, grads);
+            }
+        }
+
+        module
+    }
+
+    fn to_record(&self) -> Self::Record {
+        let mut records = Vec::with_capacity(self.groups.len() + 1);
+        records.push(self.default.to_record());
+        records.extend(self.groups.iter().map(|group| group.optim.to_record()));
+
+        records
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let mut records = record.into_iter();
+
+        if let Some(record) = records.next() {
+            self.default = self.default.load_record(record);
+        }
+
+        self.groups = self
+            .groups
+            .into_iter()
+            .map(|mut group| {
+                if let Some(record) = records.next() {
+                    group.optim = group.optim.load_record(record);
+                }
+                group
+            })
+            .collect();
+
+        self
+    }
+}
+
+/// Move the gradients of the parameters belonging to a group into the gradients of that group.
+struct GradientsGroupSplitter<'a, O> {
+    path: ModulePath,
+    groups: &'a [ParamGroup<O>],
+    grads: &'a mut GradientsParams,
+    groups_grads: &'a mut [GradientsParams],
+}
+
+impl<'a, O, B: AutodiffBackend> ModuleVisitor<B> for GradientsGroupSplitter<'a, O> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name);
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let index = self
+            .groups
+            .iter()
+            .position(|group| group.pattern.matches(&self.path));
+
+        if let Some(index) = index {
+            if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+                self.groups_grads[index].register::<B::InnerBackend, D>(id.clone(), grad);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::{adaptor::OptimizerAdaptor, momentum::MomentumConfig, Sgd, SgdConfig};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Distribution;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn group_with_zero_lr_scale_should_not_update_its_params() {
+        let layer = layer();
+        let mut optim = GroupedOptimizer::new(SgdConfig::new().init())
+            .with_group(ParamGroup::new("bias", SgdConfig::new().init()).with_lr_scale(0.0));
+
+        let grads = layer.forward(random_tensor()).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+
+        layer_updated
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&layer.bias.unwrap().to_data(), 5);
+        assert_ne!(layer_updated.weight.to_data(), layer.weight.to_data());
+    }
+
+    #[test]
+    fn first_matching_group_should_be_used() {
+        let layer = layer();
+        let mut optim = GroupedOptimizer::new(SgdConfig::new().init())
+            .with_group(ParamGroup::new("weight", SgdConfig::new().init()).with_lr_scale(0.0))
+            .with_group(ParamGroup::new("*", SgdConfig::new().init()));
+
+        let grads = layer.forward(random_tensor()).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let layer_updated = optim.step(LEARNING_RATE, layer.clone(), grads);
+
+        layer_updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&layer.weight.to_data(), 5);
+        assert_ne!(
+            layer_updated.bias.unwrap().to_data(),
+            layer.bias.unwrap().to_data()
+        );
+    }
+
+    #[test]
+    fn should_record_the_state_of_each_group() {
+        let layer = layer();
+        let mut optim = sgd_with_momentum_groups();
+
+        let grads = layer.forward(random_tensor()).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        let record = optim.to_record();
+        assert_eq!(record.len(), 2);
+        assert_eq!(record[0].len(), 1);
+        assert_eq!(record[1].len(), 1);
+
+        let optim_new = sgd_with_momentum_groups().load_record(record);
+        let record_restored = optim_new.to_record();
+        assert_eq!(record_restored[0].len(), 1);
+        assert_eq!(record_restored[1].len(), 1);
+    }
+
+    fn sgd_with_momentum_groups() -> GroupedOptimizer<
+        OptimizerAdaptor<Sgd<TestBackend>, Linear<TestAutodiffBackend>, TestAutodiffBackend>,
+    > {
+        let config = SgdConfig::new().with_momentum(Some(MomentumConfig::new()));
+
+        GroupedOptimizer::new(config.init()).with_group(ParamGroup::new("bias", config.init()))
+    }
+
+    fn layer() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(20, 20).with_bias(true).init()
+    }
+
+    fn random_tensor() -> Tensor<TestAutodiffBackend, 2> {
+        Tensor::<TestAutodiffBackend, 2>::random([2, 20], Distribution::Default)
+    }
+}
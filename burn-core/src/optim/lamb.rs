@@ -0,0 +1,208 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create the [LAMB](Lamb) optimizer.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for LAMB.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for LAMB.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay, added to the update before computing the trust ratio.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LAMB optimizer as described in the paper
+/// [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The Adam update of each parameter tensor is scaled by the ratio between the norm of the
+/// parameter and the norm of the update, which keeps training stable with very large batches.
+pub struct Lamb<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// LAMB state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    time: usize,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb<B> {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (time, moment_1, moment_2) = match state {
+            Some(state) => (state.time, state.moment_1, state.moment_2),
+            None => (0, grad.zeros_like(), grad.zeros_like()),
+        };
+        let time = time + 1;
+
+        let moment_1 = moment_1
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        let moment_2 = moment_2
+            .mul_scalar(self.beta_2)
+            .add(grad.powf(2.0).mul_scalar(1.0 - self.beta_2));
+
+        let moment_1_corrected = moment_1
+            .clone()
+            .div_scalar(1.0 - (self.beta_1 as f64).powi(time as i32));
+        let moment_2_corrected = moment_2
+            .clone()
+            .div_scalar(1.0 - (self.beta_2 as f64).powi(time as i32));
+
+        let update = moment_1_corrected
+            .div(moment_2_corrected.sqrt().add_scalar(self.epsilon))
+            .add(tensor.clone().mul_scalar(self.weight_decay));
+
+        let param_norm = tensor.clone().powf(2.0).sum().sqrt();
+        let update_norm = update.clone().powf(2.0).sum().sqrt();
+
+        // The trust ratio falls back to one when either norm is zero, e.g. for zero initialized
+        // biases.
+        let trust_ratio = param_norm
+            .clone()
+            .div(update_norm.clone())
+            .mask_fill(param_norm.equal_elem(0.0), 1.0)
+            .mask_fill(update_norm.equal_elem(0.0), 1.0);
+
+        let delta = update.mul(trust_ratio.reshape([1; D])).mul_scalar(lr);
+        let state = LambState::new(time, moment_1, moment_2);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+}
+
+impl LambConfig {
+    /// Initialize LAMB optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lamb<B::InnerBackend>, M, B> {
+        let optim = Lamb {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = LambConfig::new().init();
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let _linear = optimizer.step(0.01, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer = LambConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_optimizer_with_numbers() {
+        let linear = nn::LinearConfig::new(2, 1).init_with(nn::LinearRecord {
+            weight: Tensor::from_floats([[3.0], [4.0]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optimizer = LambConfig::new()
+            .with_epsilon(0.0)
+            .with_weight_decay(0.0)
+            .init();
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optimizer.step(0.1, linear, grads);
+
+        // The first update is the sign of the gradient, scaled to the norm of the parameter.
+        let scale = 5.0 / 2.0f32.sqrt();
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0 - 0.1 * scale], [4.0 - 0.1 * scale]]), 3);
+        // The parameter norm of the bias is zero, so its trust ratio is one.
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.1]), 3);
+    }
+
+    #[test]
+    fn test_lamb_optimizer_converges() {
+        let mut linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([4, 6], Distribution::Default);
+        let mut optimizer = LambConfig::new().init();
+        let loss_initial = linear.forward(x.clone()).powf(2.0).sum().into_scalar();
+
+        for _ in 0..100 {
+            let loss = linear.forward(x.clone()).powf(2.0).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &linear);
+            linear = optimizer.step(0.05, linear, grads);
+        }
+
+        let loss = linear.forward(x).powf(2.0).sum().into_scalar();
+        assert!(loss < loss_initial * 0.5);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create the [Lion](Lion) optimizer.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Coefficient used to interpolate the momentum and the gradient for the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Coefficient used to update the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay, applied to the parameters proportionally to the learning rate.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion optimizer as described in the paper
+/// [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// Only the sign of the update is used, so every parameter moves by the learning rate at each
+/// step. The learning rate should be 3 to 10 times smaller than the one used with AdamW.
+pub struct Lion<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion<B> {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        let update = update
+            .zeros_like()
+            .mask_fill(update.clone().greater_elem(0.0), 1.0)
+            .mask_fill(update.lower_elem(0.0), -1.0);
+
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        let tensor_updated = tensor.clone() - tensor.mul_scalar(lr * self.weight_decay as f64);
+        let tensor_updated = tensor_updated - update.mul_scalar(lr);
+
+        (tensor_updated, Some(LionState::new(momentum)))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lion<B::InnerBackend>, M, B> {
+        let optim = Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn test_lion_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = LionConfig::new().init();
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let _linear = optimizer.step(0.01, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer = LionConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lion_optimizer_with_numbers() {
+        let linear = nn::LinearConfig::new(2, 1).init_with(nn::LinearRecord {
+            weight: Tensor::from_floats([[0.5], [-0.5]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optimizer = LionConfig::new().with_weight_decay(0.1).init();
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optimizer.step(0.1, linear, grads);
+
+        // Every parameter moves by the learning rate in the direction opposite to its gradient.
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.395], [-0.595]]), 3);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.1]), 3);
+    }
+
+    #[test]
+    fn test_lion_optimizer_converges() {
+        let mut linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([4, 6], Distribution::Default);
+        let mut optimizer = LionConfig::new().init();
+        let loss_initial = linear.forward(x.clone()).powf(2.0).sum().into_scalar();
+
+        for _ in 0..100 {
+            let loss = linear.forward(x.clone()).powf(2.0).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &linear);
+            linear = optimizer.step(0.01, linear, grads);
+        }
+
+        let loss = linear.forward(x).powf(2.0).sum().into_scalar();
+        assert!(loss < loss_initial * 0.5);
+    }
+}
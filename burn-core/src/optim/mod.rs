@@ -4,23 +4,41 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adadelta;
+mod adafactor;
 mod adagrad;
 mod adam;
 mod adamw;
+mod amp;
 mod base;
+mod ema;
 mod grad_accum;
 mod grads;
+mod groups;
+mod lamb;
+mod lion;
+mod per_sample;
+mod radam;
 mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+pub use adadelta::*;
+pub use adafactor::*;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
+pub use amp::*;
 pub use base::*;
+pub use ema::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use groups::*;
+pub use lamb::*;
+pub use lion::*;
+pub use per_sample::*;
+pub use radam::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
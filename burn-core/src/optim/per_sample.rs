@@ -0,0 +1,403 @@
+use super::GradientsParams;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::nn::Linear;
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::Tensor;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// Records the inputs and the output gradients of linear layers, to compute
+/// [per-sample gradients](PerSampleGradients) with a single backward pass.
+///
+/// The gradients of each sample are computed from the layer inputs and the gradients of the
+/// layer outputs, which is much faster than a backward pass per sample. This is useful for
+/// differentially private training (DP-SGD) and influence functions.
+///
+/// The recorder can be cloned and shared between many layers, all clones pointing to the same
+/// storage. Note that the gradients are computed for the loss as it is defined, so the loss
+/// should be summed over the batch, not averaged, to obtain the gradients of each sample.
+///
+/// Only [linear](Linear) layers are supported, computing the gradients of a module with other
+/// trainable parameters returns an [error](PerSampleError).
+///
+/// # Example
+///
+/// ```ignore
+/// let recorder = PerSampleRecorder::new();
+/// let output = recorder.forward_linear(&model.linear, input);
+///
+/// let grads = loss(output).backward();
+/// let grads = recorder.gradients(&model, &grads)?;
+/// let grads = grads.clip_and_sum(1.0);
+/// ```
+pub struct PerSampleRecorder<B: AutodiffBackend> {
+    entries: Arc<spin::Mutex<Vec<LinearEntry<B>>>>,
+}
+
+struct LinearEntry<B: AutodiffBackend> {
+    weight: ParamId,
+    bias: Option<ParamId>,
+    input: Tensor<B::InnerBackend, 3>,
+    probe: Tensor<B, 3>,
+}
+
+impl<B: AutodiffBackend> PerSampleRecorder<B> {
+    /// Create a new empty recorder.
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(spin::Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Applies the forward pass of the linear layer, recording what is needed to compute the
+    /// gradients of its parameters for each sample.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ..., d_input]`
+    /// - output: `[batch_size, ..., d_output]`
+    pub fn forward_linear<const D: usize>(
+        &self,
+        linear: &Linear<B>,
+        input: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let dims = input.dims();
+        let [d_input, d_output] = linear.weight.dims();
+        let batch_size = dims[0];
+        let length = dims[1..D - 1].iter().product::<usize>();
+
+        let output = linear.forward(input.clone());
+
+        // The gradient of a zero tensor added to the output is the gradient of the output, which
+        // is otherwise freed during the backward pass.
+        let probe =
+            Tensor::zeros_device([batch_size, length, d_output], &output.device()).require_grad();
+
+        let output_dims = output.dims();
+        self.entries.lock().push(LinearEntry {
+            weight: linear.weight.id.clone(),
+            bias: linear.bias.as_ref().map(|bias| bias.id.clone()),
+            input: input.inner().reshape([batch_size, length, d_input]),
+            probe: probe.clone(),
+        });
+
+        output + probe.reshape(output_dims)
+    }
+
+    /// Computes the per-sample gradients of the recorded layers, then clears the recorder.
+    ///
+    /// When a layer is applied many times, the gradients of each application are summed.
+    ///
+    /// # Errors
+    ///
+    /// When a parameter of the module has a gradient but wasn't recorded, e.g. because it isn't
+    /// the parameter of a linear layer, since its per-sample gradients can't be computed.
+    pub fn gradients<M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        grads: &B::Gradients,
+    ) -> Result<PerSampleGradients<B::InnerBackend>, PerSampleError> {
+        let entries = core::mem::take(&mut *self.entries.lock());
+
+        let mut visitor = UnrecordedParams {
+            recorded: entries
+                .iter()
+                .flat_map(|entry| core::iter::once(&entry.weight).chain(entry.bias.as_ref()))
+                .cloned()
+                .collect(),
+            unrecorded: Vec::new(),
+            grads,
+        };
+        module.visit(&mut visitor);
+
+        if !visitor.unrecorded.is_empty() {
+            return Err(PerSampleError::UnsupportedParams(visitor.unrecorded));
+        }
+
+        let mut gradients = PerSampleGradients::default();
+
+        for entry in entries {
+            let grad = match entry.probe.grad(grads) {
+                Some(grad) => grad,
+                None => continue,
+            };
+            let [batch_size, _, d_output] = grad.dims();
+
+            let grad_weight = entry.input.swap_dims(1, 2).matmul(grad.clone());
+            add_or_register(&mut gradients.weights, entry.weight, grad_weight);
+
+            if let Some(bias) = entry.bias {
+                let grad_bias = grad.sum_dim(1).reshape([batch_size, d_output]);
+                add_or_register(&mut gradients.biases, bias, grad_bias);
+            }
+        }
+
+        Ok(gradients)
+    }
+
+    /// Removes all the recorded layers.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+impl<B: AutodiffBackend> Default for PerSampleRecorder<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: AutodiffBackend> Clone for PerSampleRecorder<B> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<B: AutodiffBackend> core::fmt::Debug for PerSampleRecorder<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PerSampleRecorder")
+            .field("len", &self.entries.lock().len())
+            .finish()
+    }
+}
+
+/// Error returned when the per-sample gradients of a module can't be computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PerSampleError {
+    /// The parameters have gradients but weren't recorded, so their per-sample gradients would
+    /// be missing.
+    UnsupportedParams(Vec<ParamId>),
+}
+
+impl core::fmt::Display for PerSampleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedParams(ids) => f.write_str(
+                format!(
+                    "The per-sample gradients of the parameters {ids:?} can't be computed, only \
+                     the linear layers applied with the recorder are supported."
+                )
+                .as_str(),
+            ),
+        }
+    }
+}
+
+// TODO: Move from std to core after Error is core (see https://github.com/rust-lang/rust/issues/103765)
+#[cfg(feature = "std")]
+impl std::error::Error for PerSampleError {}
+
+/// Collects the parameters with a gradient which weren't recorded.
+struct UnrecordedParams<'a, B: AutodiffBackend> {
+    recorded: Vec<ParamId>,
+    unrecorded: Vec<ParamId>,
+    grads: &'a B::Gradients,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for UnrecordedParams<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if !self.recorded.contains(id) && tensor.grad(self.grads).is_some() {
+            self.unrecorded.push(id.clone());
+        }
+    }
+}
+
+/// Gradients of each sample of a batch, computed by a [recorder](PerSampleRecorder).
+///
+/// The gradients of each parameter have an additional leading dimension of size `batch_size`.
+#[derive(Debug)]
+pub struct PerSampleGradients<B: Backend> {
+    weights: Vec<(ParamId, Tensor<B, 3>)>,
+    biases: Vec<(ParamId, Tensor<B, 2>)>,
+}
+
+impl<B: Backend> Default for PerSampleGradients<B> {
+    fn default() -> Self {
+        Self {
+            weights: Vec::new(),
+            biases: Vec::new(),
+        }
+    }
+}
+
+impl<B: Backend> PerSampleGradients<B> {
+    /// Get the per-sample gradients of a linear weight, of shape
+    /// `[batch_size, d_input, d_output]`.
+    pub fn weight(&self, id: &ParamId) -> Option<Tensor<B, 3>> {
+        find(&self.weights, id)
+    }
+
+    /// Get the per-sample gradients of a linear bias, of shape `[batch_size, d_output]`.
+    pub fn bias(&self, id: &ParamId) -> Option<Tensor<B, 2>> {
+        find(&self.biases, id)
+    }
+
+    /// The L2 norm of the gradients of all parameters for each sample, of shape `[batch_size]`.
+    ///
+    /// # Panics
+    ///
+    /// If no gradient is recorded.
+    pub fn norms(&self) -> Tensor<B, 1> {
+        let weights = self.weights.iter().map(|(_, grad)| {
+            let [batch_size, _, _] = grad.dims();
+            grad.clone()
+                .powf(2.0)
+                .sum_dim(2)
+                .sum_dim(1)
+                .reshape([batch_size])
+        });
+        let biases = self.biases.iter().map(|(_, grad)| {
+            let [batch_size, _] = grad.dims();
+            grad.clone().powf(2.0).sum_dim(1).reshape([batch_size])
+        });
+
+        weights
+            .chain(biases)
+            .reduce(|acc, norm| acc + norm)
+            .expect("Per-sample gradients should have been recorded.")
+            .sqrt()
+    }
+
+    /// Clips the gradients of each sample to the given L2 norm, then sums them over the batch,
+    /// as done by DP-SGD before adding noise.
+    pub fn clip_and_sum(self, max_norm: f64) -> GradientsParams {
+        let mut grads = GradientsParams::new();
+
+        if self.weights.is_empty() && self.biases.is_empty() {
+            return grads;
+        }
+
+        let scales = self
+            .norms()
+            .add_scalar(1e-6)
+            .recip()
+            .mul_scalar(max_norm)
+            .clamp_max(1.0);
+        let [batch_size] = scales.dims();
+
+        for (id, grad) in self.weights {
+            let [_, d_input, d_output] = grad.dims();
+            let grad = grad.mul(scales.clone().reshape([batch_size, 1, 1]));
+            grads.register(id, grad.sum_dim(0).reshape([d_input, d_output]));
+        }
+
+        for (id, grad) in self.biases {
+            let [_, d_output] = grad.dims();
+            let grad = grad.mul(scales.clone().reshape([batch_size, 1]));
+            grads.register(id, grad.sum_dim(0).reshape([d_output]));
+        }
+
+        grads
+    }
+}
+
+fn find<B: Backend, const D: usize>(
+    grads: &[(ParamId, Tensor<B, D>)],
+    id: &ParamId,
+) -> Option<Tensor<B, D>> {
+    grads
+        .iter()
+        .find(|(param, _)| param == id)
+        .map(|(_, grad)| grad.clone())
+}
+
+fn add_or_register<B: Backend, const D: usize>(
+    grads: &mut Vec<(ParamId, Tensor<B, D>)>,
+    id: ParamId,
+    grad: Tensor<B, D>,
+) {
+    match grads.iter_mut().find(|(param, _)| *param == id) {
+        Some((_, existing)) => *existing = existing.clone() + grad,
+        None => grads.push((id, grad)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::TestAutodiffBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn per_sample_gradients_should_match_one_backward_per_sample() {
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>();
+        let input = Tensor::<TestAutodiffBackend, 3>::random([2, 5, 4], Distribution::Default);
+        let recorder = PerSampleRecorder::new();
+
+        let output = recorder.forward_linear(&linear, input.clone());
+        let grads = output.tanh().sum().backward();
+        let grads = recorder.gradients(&linear, &grads).unwrap();
+
+        for i in 0..2 {
+            let sample = input.clone().slice([i..i + 1]);
+            let grads_sample = linear.forward(sample).tanh().sum().backward();
+            let expected_weight = linear.weight.grad(&grads_sample).unwrap();
+            let expected_bias = linear.bias.as_ref().unwrap().grad(&grads_sample).unwrap();
+
+            let weight = grads.weight(&linear.weight.id).unwrap();
+            let bias = grads.bias(&linear.bias.as_ref().unwrap().id).unwrap();
+
+            weight
+                .slice([i..i + 1])
+                .reshape([4, 3])
+                .into_data()
+                .assert_approx_eq(&expected_weight.into_data(), 3);
+            bias.slice([i..i + 1])
+                .reshape([3])
+                .into_data()
+                .assert_approx_eq(&expected_bias.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn clip_and_sum_should_bound_the_contribution_of_each_sample() {
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>();
+        let input = Tensor::<TestAutodiffBackend, 2>::random([8, 4], Distribution::Default);
+        let recorder = PerSampleRecorder::new();
+
+        let output = recorder.forward_linear(&linear, input.mul_scalar(100.0));
+        let grads = recorder
+            .gradients(&linear, &output.sum().backward())
+            .unwrap();
+        let grads = grads.clip_and_sum(0.1);
+
+        let weight = grads
+            .get::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 2>(&linear.weight.id)
+            .unwrap();
+        let bias = grads
+            .get::<<TestAutodiffBackend as AutodiffBackend>::InnerBackend, 1>(
+                &linear.bias.as_ref().unwrap().id,
+            )
+            .unwrap();
+        let norm = (weight.powf(2.0).sum() + bias.powf(2.0).sum())
+            .sqrt()
+            .into_scalar();
+
+        assert!(norm <= 8.0 * 0.1 + 1e-3);
+    }
+
+    #[test]
+    fn gradients_should_fail_with_unrecorded_params() {
+        let linear_1 = LinearConfig::new(4, 3).init::<TestAutodiffBackend>();
+        let linear_2 = LinearConfig::new(3, 2).init::<TestAutodiffBackend>();
+        let input = Tensor::<TestAutodiffBackend, 2>::random([8, 4], Distribution::Default);
+        let recorder = PerSampleRecorder::new();
+
+        let output = linear_2.forward(recorder.forward_linear(&linear_1, input));
+        let result =
+            recorder.gradients(&vec![linear_1, linear_2.clone()], &output.sum().backward());
+
+        assert_eq!(
+            result.unwrap_err(),
+            PerSampleError::UnsupportedParams(vec![
+                linear_2.weight.id.clone(),
+                linear_2.bias.unwrap().id.clone()
+            ])
+        );
+    }
+}
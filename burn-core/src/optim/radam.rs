@@ -0,0 +1,212 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    decay::{WeightDecay, WeightDecayConfig},
+    SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create the [RAdam](RAdam) optimizer.
+#[derive(Config)]
+pub struct RAdamConfig {
+    /// Parameter for RAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for RAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// RAdam optimizer as described in the paper
+/// [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// The adaptive learning rate is only used once the variance of the second moment estimate is
+/// tractable, the first steps use the first moment only, which removes the need for a warmup.
+pub struct RAdam<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// RAdam state.
+#[derive(Record, Clone, new)]
+pub struct RAdamState<B: Backend, const D: usize> {
+    time: usize,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for RAdam<B> {
+    type State<const D: usize> = RAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let (time, moment_1, moment_2) = match state {
+            Some(state) => (state.time, state.moment_1, state.moment_2),
+            None => (0, grad.zeros_like(), grad.zeros_like()),
+        };
+        let time = time + 1;
+
+        let moment_1 = moment_1
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+        let moment_2 = moment_2
+            .mul_scalar(self.beta_2)
+            .add(grad.powf(2.0).mul_scalar(1.0 - self.beta_2));
+
+        let beta_1 = self.beta_1 as f64;
+        let beta_2 = self.beta_2 as f64;
+        let beta_1_t = beta_1.powi(time as i32);
+        let beta_2_t = beta_2.powi(time as i32);
+
+        // Maximum and current length of the approximated simple moving average.
+        let rho_inf = 2.0 / (1.0 - beta_2) - 1.0;
+        let rho_t = rho_inf - 2.0 * time as f64 * beta_2_t / (1.0 - beta_2_t);
+
+        let moment_1_corrected = moment_1.clone().div_scalar(1.0 - beta_1_t);
+
+        let delta = if rho_t > 5.0 {
+            let rectification = ((rho_t - 4.0) * (rho_t - 2.0) * rho_inf
+                / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho_t))
+                .sqrt();
+            let adaptive_lr = moment_2
+                .clone()
+                .div_scalar(1.0 - beta_2_t)
+                .sqrt()
+                .add_scalar(self.epsilon)
+                .recip();
+
+            moment_1_corrected
+                .mul(adaptive_lr)
+                .mul_scalar(rectification)
+        } else {
+            moment_1_corrected
+        };
+
+        let state = RAdamState::new(time, moment_1, moment_2);
+
+        (tensor - delta.mul_scalar(lr), Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+}
+
+impl RAdamConfig {
+    /// Initialize RAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<RAdam<B::InnerBackend>, M, B> {
+        let optim = RAdam {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend};
+
+    #[test]
+    fn test_radam_optimizer_save_load_state() {
+        let linear = nn::LinearConfig::new(6, 6).init();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default);
+        let mut optimizer = RAdamConfig::new().init();
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let _linear = optimizer.step(0.01, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let optimizer = RAdamConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(optimizer.to_record());
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_radam_optimizer_first_steps_use_momentum_only() {
+        let linear = nn::LinearConfig::new(2, 1).init_with(nn::LinearRecord {
+            weight: Tensor::from_floats([[0.5], [-0.5]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optimizer = RAdamConfig::new().init();
+
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optimizer.step(0.1, linear, grads);
+
+        // The bias corrected first moment is the gradient.
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.4], [-0.7]]), 3);
+        linear
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.1]), 3);
+    }
+
+    #[test]
+    fn test_radam_optimizer_converges() {
+        let mut linear = nn::LinearConfig::new(6, 6).init::<TestAutodiffBackend>();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([4, 6], Distribution::Default);
+        let mut optimizer = RAdamConfig::new().init();
+        let loss_initial = linear.forward(x.clone()).powf(2.0).sum().into_scalar();
+
+        for _ in 0..100 {
+            let loss = linear.forward(x.clone()).powf(2.0).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &linear);
+            linear = optimizer.step(0.01, linear, grads);
+        }
+
+        let loss = linear.forward(x).powf(2.0).sum().into_scalar();
+        assert!(loss < loss_initial * 0.5);
+    }
+}
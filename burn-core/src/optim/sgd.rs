@@ -4,18 +4,24 @@ use crate::{self as burn, LearningRate};
 
 use super::decay::{WeightDecay, WeightDecayConfig};
 use super::momentum::{Momentum, MomentumConfig, MomentumState};
-use super::SimpleOptimizer;
+use super::{GradScaler, MixedPrecisionAdaptor, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::record::Record;
 use crate::tensor::Tensor;
 use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::ops::FullPrecisionBackend;
 
 /// Configuration to create the [Sgd](Sgd) optimizer.
 #[derive(Config)]
 pub struct SgdConfig {
     /// [Weight decay](WeightDecayConfig) config.
     weight_decay: Option<WeightDecayConfig>,
+    /// Applies the weight decay directly to the parameters instead of adding it to the gradient,
+    /// as in SGDW from [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101),
+    /// so it isn't accumulated by the momentum.
+    #[config(default = false)]
+    decoupled_weight_decay: bool,
     /// [Momentum](MomentumConfig) config.
     momentum: Option<MomentumConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
@@ -28,6 +34,7 @@ pub struct SgdConfig {
 pub struct Sgd<B: Backend> {
     momentum: Option<Momentum<B>>,
     weight_decay: Option<WeightDecay<B>>,
+    decoupled_weight_decay: bool,
 }
 
 /// State of [Sgd](Sgd).
@@ -41,18 +48,33 @@ impl SgdConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<Sgd<B::InnerBackend>, M, B> {
-        let momentum = self.momentum.as_ref().map(Momentum::new);
-        let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.gradient_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
 
-        let mut optim = OptimizerAdaptor::from(Sgd {
-            momentum,
-            weight_decay,
-        });
+    /// Creates a new [Sgd](Sgd) optimizer updating full precision master weights, for a module
+    /// trained in half precision with the given [gradient scaler](GradScaler).
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grad_scaler: GradScaler,
+    ) -> MixedPrecisionAdaptor<Sgd<FullPrecisionBackend<B::InnerBackend>>, M, B> {
+        let mut optim = MixedPrecisionAdaptor::new(self.optimizer(), grad_scaler);
         if let Some(config) = &self.gradient_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
         optim
     }
+
+    fn optimizer<B: Backend>(&self) -> Sgd<B> {
+        Sgd {
+            momentum: self.momentum.as_ref().map(Momentum::new),
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+            decoupled_weight_decay: self.decoupled_weight_decay,
+        }
+    }
 }
 
 impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
@@ -61,7 +83,7 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
     fn step<const D: usize>(
         &self,
         lr: LearningRate,
-        tensor: Tensor<B, D>,
+        mut tensor: Tensor<B, D>,
         mut grad: Tensor<B, D>,
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
@@ -72,7 +94,10 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         }
 
         if let Some(weight_decay) = &self.weight_decay {
-            grad = weight_decay.transform(grad, tensor.clone());
+            match self.decoupled_weight_decay {
+                true => tensor = weight_decay.decay(tensor, lr),
+                false => grad = weight_decay.transform(grad, tensor.clone()),
+            }
         }
 
         if let Some(momentum) = &self.momentum {
@@ -98,9 +123,9 @@ mod tests {
     use super::*;
     use crate::{
         grad_clipping::GradientClipping,
-        nn::{Linear, LinearConfig},
+        nn::{Linear, LinearConfig, LinearRecord},
         optim::{GradientsParams, Optimizer},
-        tensor::{Distribution, Shape},
+        tensor::{Data, Distribution, Shape},
         TestAutodiffBackend, TestBackend,
     };
 
@@ -133,6 +158,66 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn global_norm_clipping_should_scale_all_gradients_together() {
+        let layer = layer();
+        let mut optim = SgdConfig::new()
+            .with_gradient_clipping(Some(GradientClippingConfig::GlobalNorm(0.5)))
+            .init();
+        let loss = layer.forward(random_tensor().mul_scalar(100.0));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let norm = grads
+            .norm::<TestAutodiffBackend, _>(&layer)
+            .unwrap()
+            .into_scalar();
+
+        let layer_updated = optim.step(1.0, layer.clone(), grads);
+
+        let delta_weight = layer.weight.val().inner() - layer_updated.weight.val().inner();
+        let delta_bias = layer.bias.as_ref().unwrap().val().inner()
+            - layer_updated.bias.as_ref().unwrap().val().inner();
+        let norm_delta = (delta_weight.powf(2.0).sum() + delta_bias.powf(2.0).sum())
+            .sqrt()
+            .into_scalar();
+
+        assert!(norm > 0.5);
+        assert!((norm_delta - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn decoupled_weight_decay_should_not_be_accumulated_by_momentum() {
+        let mut layer = LinearConfig::new(2, 1).init_with(LinearRecord {
+            weight: Tensor::from_floats([[0.5], [-0.5]]).into(),
+            bias: Some(Tensor::from_floats([0.0]).into()),
+        });
+        let x = Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]]);
+        let mut optim = SgdConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig { penalty: 0.5 }))
+            .with_decoupled_weight_decay(true)
+            .with_momentum(Some(MomentumConfig {
+                momentum: 0.9,
+                dampening: 0.0,
+                nesterov: false,
+            }))
+            .init();
+
+        for _ in 0..2 {
+            let grads = GradientsParams::from_grads(layer.forward(x.clone()).backward(), &layer);
+            layer = optim.step(0.1, layer, grads);
+        }
+
+        // The velocity only accumulates the gradients, the parameters are decayed directly.
+        layer
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.16625], [-1.02125]]), 5);
+        layer
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.285]), 5);
+    }
+
     #[test]
     fn should_load_state() {
         let layer = layer();
@@ -169,6 +254,7 @@ mod tests {
                 dampening: 0.1,
                 nesterov: true,
             }),
+            decoupled_weight_decay: false,
             gradient_clipping: None,
         }
         .init()
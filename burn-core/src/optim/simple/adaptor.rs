@@ -74,12 +74,25 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B::InnerBackend>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        // The scale applied to all gradients must be computed before updating any parameter.
+        let global_norm_scale = match &self.grad_clipping {
+            Some(GradientClipping::GlobalNorm(max_norm)) => {
+                grads.norm::<B, M>(&module).map(|norm| {
+                    norm.add_scalar(1e-6)
+                        .recip()
+                        .mul_scalar(*max_norm)
+                        .clamp_max(1.0)
+                })
+            }
+            _ => None,
+        };
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            global_norm_scale,
         );
         module.map(&mut mapper)
     }
@@ -107,6 +120,7 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    global_norm_scale: Option<Tensor<B::InnerBackend, 1>>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -123,10 +137,12 @@ where
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
 
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
+            let clipped_grad = match (self.grad_clipping, &self.global_norm_scale) {
+                (Some(GradientClipping::GlobalNorm(_)), Some(scale)) => {
+                    grad.mul(scale.clone().to_device(&device).reshape([1; D]))
+                }
+                (Some(g_clipping), _) => g_clipping.clip_gradient(grad),
+                (None, _) => grad,
             };
 
             let (tensor, state) = self.optimizer.step(
@@ -17,6 +17,20 @@ pub struct GradientsParamsChangeDevice<'a, M: AutodiffModule<B>, B: AutodiffBack
     phatom: PhantomData<M>,
 }
 
+#[derive(new)]
+pub struct GradientsParamsNorm<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    #[new(default)]
+    squared_sum: Option<Tensor<B::InnerBackend, 1>>,
+    phatom: PhantomData<M>,
+}
+
+impl<'a, M: AutodiffModule<B>, B: AutodiffBackend> GradientsParamsNorm<'a, M, B> {
+    pub fn norm(self) -> Option<Tensor<B::InnerBackend, 1>> {
+        self.squared_sum.map(|squared_sum| squared_sum.sqrt())
+    }
+}
+
 impl<'a, B, M> ModuleVisitor<B> for GradientsParamsConverter<'a, M, B>
 where
     B: AutodiffBackend,
@@ -42,3 +56,23 @@ where
         }
     }
 }
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsNorm<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let squared_sum = grad.powf(2.0).sum();
+
+            self.squared_sum = Some(match self.squared_sum.take() {
+                Some(acc) => {
+                    let device = acc.device();
+                    acc + squared_sum.to_device(&device)
+                }
+                None => squared_sum,
+            });
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use super::{read_audio, resample, MelSpectrogram};
+use crate::Dataset;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The extensions of the audio files read by the [audio folder dataset](AudioFolderDataset).
+const AUDIO_EXTENSIONS: [&str; 2] = ["wav", "flac"];
+
+/// Error type for [AudioFolderDataset](AudioFolderDataset).
+#[derive(Error, Debug)]
+pub enum AudioFolderError {
+    /// Fail to read the directories.
+    #[error("io error: `{0}`")]
+    Io(#[from] std::io::Error),
+
+    /// No audio file found in the root directory.
+    #[error("no audio file found in `{0}`")]
+    Empty(PathBuf),
+}
+
+/// An item of the [audio folder dataset](AudioFolderDataset).
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioFolderItem {
+    /// The mono waveform in the range [-1.0, 1.0], or the values of the mel spectrogram.
+    pub audio: Vec<f32>,
+
+    /// The shape of the audio, i.e. `[1, samples]` for the waveform and `[n_mels, frames]` for
+    /// the mel spectrogram.
+    pub shape: [usize; 2],
+
+    /// The sample rate of the waveform.
+    pub sample_rate: usize,
+
+    /// The index of the class of the audio.
+    pub label: usize,
+}
+
+/// A dataset of audio files stored in a directory per class, e.g. `root/yes/1.wav`.
+///
+/// The classes are the sorted names of the directories, the label of each audio file being the
+/// index of its class. The WAV and FLAC files of each directory and its subdirectories are decoded
+/// when they are accessed, mixed to mono, then resampled to the [sample rate](Self::with_sample_rate)
+/// and converted to a [mel spectrogram](MelSpectrogram) when they are set.
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_dataset::audio::{AudioFolderDataset, MelSpectrogram};
+/// use burn_dataset::Dataset;
+///
+/// let dataset = AudioFolderDataset::new("data/train")
+///     .unwrap()
+///     .with_sample_rate(16000)
+///     .with_mel_spectrogram(MelSpectrogram::new(16000, 400, 160, 80));
+/// let item = dataset.get(0).unwrap();
+///
+/// println!("{} {:?}", dataset.classes()[item.label], item.shape);
+/// ```
+pub struct AudioFolderDataset {
+    files: Vec<(PathBuf, usize)>,
+    classes: Vec<String>,
+    sample_rate: Option<usize>,
+    mel_spectrogram: Option<MelSpectrogram>,
+}
+
+impl AudioFolderDataset {
+    /// Creates a dataset with the audio files of the class directories of the root directory.
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, AudioFolderError> {
+        let root = root.as_ref();
+        let mut directories = Vec::new();
+
+        for entry in std::fs::read_dir(root)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            }
+        }
+        directories.sort();
+
+        let mut files = Vec::new();
+        let mut classes = Vec::with_capacity(directories.len());
+
+        for (label, directory) in directories.into_iter().enumerate() {
+            let mut paths = Vec::new();
+            find_audio_files(&directory, &mut paths)?;
+            paths.sort();
+
+            let class = directory.file_name().unwrap().to_string_lossy();
+            classes.push(class.into_owned());
+            files.extend(paths.into_iter().map(|path| (path, label)));
+        }
+
+        if files.is_empty() {
+            return Err(AudioFolderError::Empty(root.to_path_buf()));
+        }
+
+        Ok(Self {
+            files,
+            classes,
+            sample_rate: None,
+            mel_spectrogram: None,
+        })
+    }
+
+    /// Resamples the audio to the given sample rate, instead of keeping the rate of each file.
+    pub fn with_sample_rate(mut self, sample_rate: usize) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    /// Converts the waveforms to mel spectrograms.
+    ///
+    /// The mel spectrogram should be created with the [sample rate](Self::with_sample_rate) of
+    /// the waveforms.
+    pub fn with_mel_spectrogram(mut self, mel_spectrogram: MelSpectrogram) -> Self {
+        self.mel_spectrogram = Some(mel_spectrogram);
+        self
+    }
+
+    /// The names of the classes, in the order of their labels.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+}
+
+impl Dataset<AudioFolderItem> for AudioFolderDataset {
+    fn get(&self, index: usize) -> Option<AudioFolderItem> {
+        let (path, label) = self.files.get(index)?;
+        let (mut samples, mut sample_rate) = read_audio(path)
+            .unwrap_or_else(|err| panic!("Can't decode the audio {}: {err}", path.display()));
+
+        if let Some(target) = self.sample_rate {
+            samples = resample(&samples, sample_rate, target);
+            sample_rate = target;
+        }
+
+        let (audio, shape) = match &self.mel_spectrogram {
+            Some(mel_spectrogram) => mel_spectrogram.apply(&samples),
+            None => {
+                let shape = [1, samples.len()];
+                (samples, shape)
+            }
+        };
+
+        Some(AudioFolderItem {
+            audio,
+            shape,
+            sample_rate,
+            label: *label,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.files.len()
+    }
+}
+
+/// Adds the audio files of the directory and its subdirectories to the paths.
+fn find_audio_files(directory: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            find_audio_files(&path, paths)?;
+        } else if let Some(extension) = path.extension() {
+            let extension = extension.to_string_lossy().to_lowercase();
+            if AUDIO_EXTENSIONS.contains(&extension.as_str()) {
+                paths.push(path);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_wav(path: PathBuf, sample_rate: u32, length: usize) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for _ in 0..length {
+            writer.write_sample(0_i16).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_audio_folder_dataset_resamples_the_audio() {
+        let root = tempfile::tempdir().unwrap();
+        write_wav(root.path().join("yes/1.wav"), 8000, 800);
+        write_wav(root.path().join("no/1.wav"), 16000, 1600);
+        std::fs::write(root.path().join("no/notes.txt"), "not audio").unwrap();
+
+        let dataset = AudioFolderDataset::new(root.path())
+            .unwrap()
+            .with_sample_rate(16000);
+
+        assert_eq!(dataset.classes(), ["no", "yes"]);
+        assert_eq!(dataset.len(), 2);
+        for (index, item) in dataset.iter().enumerate() {
+            assert_eq!(item.label, index);
+            assert_eq!(item.sample_rate, 16000);
+            assert_eq!(item.shape, [1, 1600]);
+        }
+    }
+
+    #[test]
+    fn test_audio_folder_dataset_with_mel_spectrogram() {
+        let root = tempfile::tempdir().unwrap();
+        write_wav(root.path().join("yes/1.wav"), 16000, 16000);
+
+        let dataset = AudioFolderDataset::new(root.path())
+            .unwrap()
+            .with_mel_spectrogram(MelSpectrogram::new(16000, 400, 160, 80));
+        let item = dataset.get(0).unwrap();
+
+        assert_eq!(item.shape, [80, 98]);
+        assert_eq!(item.audio.len(), 80 * 98);
+    }
+}
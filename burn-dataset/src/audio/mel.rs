@@ -0,0 +1,153 @@
+use realfft::{RealFftPlanner, RealToComplex};
+use std::{f32::consts::PI, sync::Arc};
+
+/// The smallest value of the mel spectrogram before its logarithm, to avoid `log(0)`.
+const LOG_FLOOR: f32 = 1e-10;
+
+/// Computes the mel spectrogram of the samples, the usual input of the speech models.
+///
+/// The samples are split in frames of `n_fft` samples every `hop_length` samples, each frame
+/// weighted by a Hann window. The power spectrum of each frame is projected on `n_mels` triangular
+/// filters evenly spaced on the mel scale, from 0 Hz to the Nyquist frequency, then the natural
+/// logarithm is taken unless [disabled](MelSpectrogram::with_log).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_dataset::audio::MelSpectrogram;
+///
+/// // Frames of 25 ms every 10 ms at 16 kHz.
+/// let mel = MelSpectrogram::new(16000, 400, 160, 80);
+/// let (values, shape) = mel.apply(&vec![0.0; 16000]);
+///
+/// assert_eq!(shape, [80, 98]);
+/// ```
+#[derive(Clone)]
+pub struct MelSpectrogram {
+    n_fft: usize,
+    hop_length: usize,
+    log: bool,
+    window: Vec<f32>,
+    filters: Vec<Vec<f32>>,
+    fft: Arc<dyn RealToComplex<f32>>,
+}
+
+impl MelSpectrogram {
+    /// Creates a mel spectrogram of `n_mels` mel bands for the samples of the given rate.
+    pub fn new(sample_rate: usize, n_fft: usize, hop_length: usize, n_mels: usize) -> Self {
+        let window = (0..n_fft)
+            .map(|index| 0.5 - 0.5 * f32::cos(2.0 * PI * index as f32 / n_fft as f32))
+            .collect();
+
+        Self {
+            n_fft,
+            hop_length,
+            log: true,
+            window,
+            filters: mel_filters(sample_rate, n_fft, n_mels),
+            fft: RealFftPlanner::new().plan_fft_forward(n_fft),
+        }
+    }
+
+    /// Whether to take the logarithm of the mel spectrogram, true by default.
+    pub fn with_log(mut self, log: bool) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// Computes the mel spectrogram and returns its values with their shape `[n_mels, frames]`.
+    ///
+    /// The samples shorter than a frame are padded with zeros.
+    pub fn apply(&self, samples: &[f32]) -> (Vec<f32>, [usize; 2]) {
+        let length = usize::max(samples.len(), self.n_fft);
+        let num_frames = 1 + (length - self.n_fft) / self.hop_length;
+        let num_mels = self.filters.len();
+
+        let mut input = self.fft.make_input_vec();
+        let mut spectrum = self.fft.make_output_vec();
+        let mut values = vec![0.0; num_mels * num_frames];
+
+        for frame in 0..num_frames {
+            let start = frame * self.hop_length;
+            for (index, value) in input.iter_mut().enumerate() {
+                *value = samples.get(start + index).copied().unwrap_or(0.0) * self.window[index];
+            }
+            self.fft.process(&mut input, &mut spectrum).unwrap();
+
+            for (mel, filter) in self.filters.iter().enumerate() {
+                let energy = filter
+                    .iter()
+                    .zip(spectrum.iter())
+                    .map(|(weight, bin)| weight * bin.norm_sqr())
+                    .sum::<f32>();
+
+                values[mel * num_frames + frame] = match self.log {
+                    true => f32::max(energy, LOG_FLOOR).ln(),
+                    false => energy,
+                };
+            }
+        }
+
+        (values, [num_mels, num_frames])
+    }
+}
+
+/// Creates the triangular filters of the mel bands, with a weight per frequency bin.
+fn mel_filters(sample_rate: usize, n_fft: usize, n_mels: usize) -> Vec<Vec<f32>> {
+    let to_mel = |hz: f32| 2595.0 * f32::log10(1.0 + hz / 700.0);
+    let to_hz = |mel: f32| 700.0 * (f32::powf(10.0, mel / 2595.0) - 1.0);
+
+    // The edges of the filters, the center of each filter being the edge of its neighbours.
+    let max_mel = to_mel(sample_rate as f32 / 2.0);
+    let edges = (0..n_mels + 2)
+        .map(|index| to_hz(max_mel * index as f32 / (n_mels + 1) as f32))
+        .collect::<Vec<_>>();
+
+    (0..n_mels)
+        .map(|mel| {
+            let (left, center, right) = (edges[mel], edges[mel + 1], edges[mel + 2]);
+
+            (0..n_fft / 2 + 1)
+                .map(|bin| {
+                    let hz = bin as f32 * sample_rate as f32 / n_fft as f32;
+                    let rising = (hz - left) / (center - left);
+                    let falling = (right - hz) / (right - center);
+                    f32::max(0.0, f32::min(rising, falling))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mel_spectrogram_of_a_sine_peaks_at_its_frequency() {
+        let samples = (0..16000)
+            .map(|index| f32::sin(2.0 * PI * 1000.0 * index as f32 / 16000.0))
+            .collect::<Vec<_>>();
+
+        let (values, shape) = MelSpectrogram::new(16000, 512, 256, 40)
+            .with_log(false)
+            .apply(&samples);
+
+        assert_eq!(shape, [40, 61]);
+        let energies = (0..40).map(|mel| values[mel * 61 + 30]).collect::<Vec<_>>();
+        let peak = (0..40)
+            .max_by(|a, b| energies[*a].total_cmp(&energies[*b]))
+            .unwrap();
+        let filters = mel_filters(16000, 512, 40);
+        // The bin of 1000 Hz.
+        assert!(filters[peak][32] > 0.0);
+    }
+
+    #[test]
+    fn test_mel_spectrogram_pads_short_samples() {
+        let (values, shape) = MelSpectrogram::new(16000, 400, 160, 80).apply(&[0.0; 100]);
+
+        assert_eq!(shape, [80, 1]);
+        assert!(values.iter().all(|value| *value == LOG_FLOOR.ln()));
+    }
+}
@@ -1,3 +1,9 @@
+mod folder;
+mod mel;
 mod speech_commands;
+mod waveform;
 
+pub use folder::*;
+pub use mel::*;
 pub use speech_commands::*;
+pub use waveform::*;
@@ -0,0 +1,185 @@
+use std::{f64::consts::PI, path::Path};
+use thiserror::Error;
+
+/// The number of zero crossings of the sinc function on each side of the resampled samples.
+const ZERO_CROSSINGS: f64 = 16.0;
+
+/// Error type for [read_audio](read_audio).
+#[derive(Error, Debug)]
+pub enum AudioError {
+    /// Fail to read the WAV file.
+    #[error("wav error: `{0}`")]
+    Wav(#[from] hound::Error),
+
+    /// Fail to read the FLAC file.
+    #[error("flac error: `{0}`")]
+    Flac(#[from] claxon::Error),
+
+    /// The extension of the file isn't `wav` nor `flac`.
+    #[error("unsupported audio format: `{0}`")]
+    UnsupportedFormat(String),
+}
+
+/// Reads a WAV or FLAC file, returning its samples in the range [-1.0, 1.0] and its sample rate.
+///
+/// The channels are averaged, so the samples are mono.
+pub fn read_audio<P: AsRef<Path>>(path: P) -> Result<(Vec<f32>, usize), AudioError> {
+    let path = path.as_ref();
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "wav" => read_wav(path),
+        "flac" => read_flac(path),
+        _ => Err(AudioError::UnsupportedFormat(path.display().to_string())),
+    }
+}
+
+fn read_wav(path: &Path) -> Result<(Vec<f32>, usize), AudioError> {
+    let reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+
+    let samples = match spec.sample_format {
+        hound::SampleFormat::Float => reader.into_samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let max_value = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .into_samples::<i32>()
+                .map(|sample| sample.map(|sample| sample as f32 / max_value))
+                .collect::<Result<_, _>>()?
+        }
+    };
+
+    Ok((
+        to_mono(samples, spec.channels as usize),
+        spec.sample_rate as usize,
+    ))
+}
+
+fn read_flac(path: &Path) -> Result<(Vec<f32>, usize), AudioError> {
+    let mut reader = claxon::FlacReader::open(path)?;
+    let info = reader.streaminfo();
+    let max_value = (1_i64 << (info.bits_per_sample - 1)) as f32;
+
+    let samples = reader
+        .samples()
+        .map(|sample| sample.map(|sample| sample as f32 / max_value))
+        .collect::<Result<_, _>>()?;
+
+    Ok((
+        to_mono(samples, info.channels as usize),
+        info.sample_rate as usize,
+    ))
+}
+
+/// Averages the interleaved samples of the channels.
+fn to_mono(samples: Vec<f32>, channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples;
+    }
+
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect()
+}
+
+/// Resamples the samples from a sample rate to another one.
+///
+/// The samples are interpolated with a windowed sinc filter, which also removes the frequencies
+/// above the Nyquist frequency of the new rate when downsampling.
+pub fn resample(samples: &[f32], from: usize, to: usize) -> Vec<f32> {
+    if from == to || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let ratio = to as f64 / from as f64;
+    // The cutoff frequency relative to the Nyquist frequency of the input.
+    let cutoff = f64::min(ratio, 1.0);
+    // The half width of the filter, in input samples.
+    let width = ZERO_CROSSINGS / cutoff;
+    let length = (samples.len() as f64 * ratio).round() as usize;
+
+    (0..length)
+        .map(|index| {
+            let position = index as f64 / ratio;
+            let first = (position - width).ceil().max(0.0) as usize;
+            let last = usize::min((position + width).floor() as usize, samples.len() - 1);
+
+            (first..=last)
+                .map(|input| {
+                    let distance = position - input as f64;
+                    let window = 0.5 * (1.0 + f64::cos(PI * distance / width));
+                    samples[input] as f64 * cutoff * sinc(distance * cutoff) * window
+                })
+                .sum::<f64>() as f32
+        })
+        .collect()
+}
+
+fn sinc(x: f64) -> f64 {
+    match x == 0.0 {
+        true => 1.0,
+        false => f64::sin(PI * x) / (PI * x),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(frequency: f64, sample_rate: usize, length: usize) -> Vec<f32> {
+        (0..length)
+            .map(|index| f64::sin(2.0 * PI * frequency * index as f64 / sample_rate as f64) as f32)
+            .collect()
+    }
+
+    #[test]
+    fn test_read_wav_averages_the_channels() {
+        let file = tempfile::Builder::new().suffix(".wav").tempfile().unwrap();
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(file.path(), spec).unwrap();
+        for sample in [16384, 0, -16384, -16384] {
+            writer.write_sample(sample as i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, sample_rate) = read_audio(file.path()).unwrap();
+
+        assert_eq!(sample_rate, 8000);
+        assert_eq!(samples, vec![0.25, -0.5]);
+    }
+
+    #[test]
+    fn test_resample_keeps_the_signal() {
+        let samples = sine(440.0, 8000, 8000);
+
+        let upsampled = resample(&samples, 8000, 16000);
+
+        assert_eq!(upsampled.len(), 16000);
+        let expected = sine(440.0, 16000, 16000);
+        // The edges lack the samples around them.
+        for (sample, expected) in upsampled.iter().zip(expected).take(15000).skip(1000) {
+            assert!((sample - expected).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_resample_removes_the_frequencies_above_the_new_nyquist_frequency() {
+        let samples = sine(6000.0, 16000, 16000);
+
+        let downsampled = resample(&samples, 16000, 8000);
+
+        assert_eq!(downsampled.len(), 8000);
+        assert!(downsampled[500..7500]
+            .iter()
+            .all(|sample| sample.abs() < 0.05));
+    }
+}
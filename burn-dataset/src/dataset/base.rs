@@ -1,6 +1,11 @@
-use std::sync::Arc;
+use std::{hash::Hash, sync::Arc};
 
-use crate::DatasetIterator;
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+use crate::{transform::CachedDataset, SqliteDatasetStorage};
+use crate::{
+    transform::{split_dataset, split_dataset_stratified, ShardedDataset, SubsetDataset},
+    DatasetIterator,
+};
 
 /// The dataset trait defines a basic collection of items with a predefined size.
 pub trait Dataset<I>: Send + Sync {
@@ -22,6 +27,54 @@ pub trait Dataset<I>: Send + Sync {
     {
         DatasetIterator::new(self)
     }
+
+    /// Returns the shard of the given rank among `world_size` disjoint shards of the same length,
+    /// e.g. the items of a process of a distributed training.
+    fn shard(self, world_size: usize, rank: usize) -> ShardedDataset<Self, I>
+    where
+        Self: Sized,
+    {
+        ShardedDataset::new(self, world_size, rank)
+    }
+
+    /// Splits the dataset randomly in subsets whose lengths are proportional to the ratios, e.g.
+    /// `[0.8, 0.1, 0.1]` for the training, validation and test sets. The same seed always gives
+    /// the same splits.
+    fn split(self, ratios: &[f64], seed: u64) -> Vec<SubsetDataset<Arc<Self>, I>>
+    where
+        Self: Sized,
+    {
+        split_dataset(self, ratios, seed)
+    }
+
+    /// Splits the dataset like [split](Dataset::split), with the same proportion of each label in
+    /// every subset, which keeps the rare labels in the small subsets. All the items are read once
+    /// to get their label.
+    fn split_stratified<L, F>(
+        self,
+        ratios: &[f64],
+        seed: u64,
+        label: F,
+    ) -> Vec<SubsetDataset<Arc<Self>, I>>
+    where
+        Self: Sized,
+        L: Hash + Eq,
+        F: Fn(&I) -> L,
+    {
+        split_dataset_stratified(self, ratios, seed, label)
+    }
+
+    /// Returns a dataset storing the items in the table of the split of a SQLite database when
+    /// they are first accessed, so expensive transformations are computed only once, even
+    /// between runs.
+    #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+    fn cached(self, storage: SqliteDatasetStorage, split: &str) -> CachedDataset<Self, I>
+    where
+        Self: Sized,
+        I: Clone + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        CachedDataset::new(self, storage, split)
+    }
 }
 
 impl<D, I> Dataset<I> for Arc<D>
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+/// The iterable dataset trait defines a stream of items, without random access nor a
+/// predefined size.
+///
+/// It suits the corpora too large to be indexed, e.g. streamed from files, and the items
+/// generated on the fly. Each call to [iter](IterableDataset::iter) starts a new pass over the
+/// items.
+pub trait IterableDataset<I>: Send + Sync {
+    /// Returns an iterator over the items, from the beginning of the dataset.
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_>;
+
+    /// Gets the number of items when it is known, only used to report the progress.
+    fn size_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// An [iterable dataset](IterableDataset) creating its iterator with a function.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_dataset::{IterFnDataset, IterableDataset};
+///
+/// let dataset = IterFnDataset::new(|| (0..).map(|i| i * 2).take(3));
+///
+/// assert_eq!(dataset.iter().collect::<Vec<_>>(), vec![0, 2, 4]);
+/// ```
+pub struct IterFnDataset<F> {
+    func: F,
+    size_hint: Option<usize>,
+}
+
+impl<F> IterFnDataset<F> {
+    /// Creates a new dataset iterating over the items of the iterator returned by the function.
+    pub fn new(func: F) -> Self {
+        Self {
+            func,
+            size_hint: None,
+        }
+    }
+
+    /// Sets the number of items returned by the iterators, to report the progress.
+    pub fn with_size_hint(mut self, size: usize) -> Self {
+        self.size_hint = Some(size);
+        self
+    }
+}
+
+impl<I, F, It> IterableDataset<I> for IterFnDataset<F>
+where
+    F: Fn() -> It + Send + Sync,
+    It: Iterator<Item = I> + 'static,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        Box::new((self.func)())
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.size_hint
+    }
+}
+
+impl<D, I> IterableDataset<I> for Arc<D>
+where
+    D: IterableDataset<I>,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        self.as_ref().iter()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.as_ref().size_hint()
+    }
+}
+
+impl<I> IterableDataset<I> for Arc<dyn IterableDataset<I>> {
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        self.as_ref().iter()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.as_ref().size_hint()
+    }
+}
+
+impl<D, I> IterableDataset<I> for Box<D>
+where
+    D: IterableDataset<I>,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        self.as_ref().iter()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.as_ref().size_hint()
+    }
+}
+
+impl<I> IterableDataset<I> for Box<dyn IterableDataset<I>> {
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        self.as_ref().iter()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.as_ref().size_hint()
+    }
+}
@@ -2,6 +2,7 @@ mod base;
 #[cfg(any(test, feature = "fake"))]
 mod fake;
 mod in_memory;
+mod iterable;
 mod iterator;
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 mod sqlite;
@@ -10,6 +11,7 @@ mod sqlite;
 pub use self::fake::*;
 pub use base::*;
 pub use in_memory::*;
+pub use iterable::*;
 pub use iterator::*;
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 pub use sqlite::*;
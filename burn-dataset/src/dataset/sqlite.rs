@@ -554,6 +554,30 @@ where
         Ok(index)
     }
 
+    /// Writes all the items of a dataset to the table of the split, in the order of their index,
+    /// so the item at an index of the dataset is stored at the same index of the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `split` - A string slice that defines the data split for writing (e.g., "train", "test").
+    /// * `dataset` - The dataset whose items are written.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the number of written items if successful, an error otherwise.
+    pub fn write_dataset<D: Dataset<I>>(&self, split: &str, dataset: &D) -> Result<usize> {
+        // Create the table even if the dataset is empty, so it can be read afterward
+        self.create_table(split)?;
+
+        let mut count = 0;
+        for item in dataset.iter() {
+            self.write(split, &item)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
     /// Marks the dataset as completed and persists the temporary database file.
     pub fn set_completed(&mut self) -> Result<()> {
         let mut is_completed = self.is_completed.write().unwrap();
@@ -15,10 +15,17 @@ pub mod source;
 /// Transformations to be used with datasets.
 pub mod transform;
 
+/// Text tokenization.
+pub mod text;
+
 /// Audio datasets.
 #[cfg(feature = "audio")]
 pub mod audio;
 
+/// Vision datasets.
+#[cfg(feature = "vision")]
+pub mod vision;
+
 mod dataset;
 pub use dataset::*;
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
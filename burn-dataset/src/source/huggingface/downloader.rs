@@ -36,6 +36,14 @@ pub enum ImporterError {
     /// venv environment is not initialized.
     #[error("venv environment is not initialized")]
     VenvNotInitialized,
+
+    /// The python importer failed.
+    #[error("fail to import the dataset: `{0}`")]
+    FailToImport(String),
+
+    /// The dataset can't be imported in offline mode.
+    #[error("dataset not available offline: `{0}`")]
+    NotAvailableOffline(String),
 }
 
 /// Load a dataset from [huggingface datasets](https://huggingface.co/datasets).
@@ -63,17 +71,30 @@ pub struct HuggingfaceDatasetLoader {
     base_dir: Option<PathBuf>,
     huggingface_token: Option<String>,
     huggingface_cache_dir: Option<String>,
+    revision: Option<String>,
+    offline: bool,
 }
 
 impl HuggingfaceDatasetLoader {
     /// Create a huggingface dataset loader.
+    ///
+    /// The loader is in offline mode when the `HF_DATASETS_OFFLINE` or `HF_HUB_OFFLINE`
+    /// environment variable is set to `1`, as with the huggingface libraries.
     pub fn new(name: &str) -> Self {
+        let offline = ["HF_DATASETS_OFFLINE", "HF_HUB_OFFLINE"].iter().any(|var| {
+            std::env::var(var)
+                .map(|value| value == "1")
+                .unwrap_or(false)
+        });
+
         Self {
             name: name.to_string(),
             subset: None,
             base_dir: None,
             huggingface_token: None,
             huggingface_cache_dir: None,
+            revision: None,
+            offline,
         }
     }
 
@@ -97,7 +118,9 @@ impl HuggingfaceDatasetLoader {
 
     /// Specify a huggingface token to download datasets behind authentication.
     ///
-    /// You can get a token from [tokens settings](https://huggingface.co/settings/tokens)
+    /// You can get a token from [tokens settings](https://huggingface.co/settings/tokens). If not
+    /// specified, the token of the `HF_TOKEN` environment variable or of `huggingface-cli login`
+    /// is used.
     pub fn with_huggingface_token(mut self, huggingface_token: &str) -> Self {
         self.huggingface_token = Some(huggingface_token.to_string());
         self
@@ -111,6 +134,26 @@ impl HuggingfaceDatasetLoader {
         self
     }
 
+    /// Pin the revision of the dataset to download, i.e. a branch, a tag or a commit hash of the
+    /// dataset repository.
+    ///
+    /// Each revision is stored in its own database, so changing the revision downloads it again.
+    pub fn with_revision(mut self, revision: &str) -> Self {
+        self.revision = Some(revision.to_string());
+        self
+    }
+
+    /// Only use the datasets already available locally, without any network access.
+    ///
+    /// A dataset already imported is loaded as usual, otherwise it is imported from the
+    /// huggingface cache directory, which must have been populated beforehand, e.g. by loading
+    /// the dataset once on a machine with network access and copying the cache directory. The
+    /// python environment must also have been installed by a previous import.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     /// Load the dataset.
     pub fn dataset<I: DeserializeOwned + Clone>(
         self,
@@ -126,7 +169,7 @@ impl HuggingfaceDatasetLoader {
     /// If the database file does not exist, it will be downloaded and imported.
     pub fn db_file(self) -> Result<PathBuf, ImporterError> {
         // determine (and create if needed) the base directory
-        let base_dir = SqliteDatasetStorage::base_dir(self.base_dir);
+        let base_dir = SqliteDatasetStorage::base_dir(self.base_dir.clone());
 
         if !base_dir.exists() {
             create_dir_all(&base_dir).expect("Failed to create base directory");
@@ -136,72 +179,95 @@ impl HuggingfaceDatasetLoader {
         let name = sanitize(self.name.as_str());
 
         // create the db file path
-        let db_file_name = if let Some(subset) = self.subset.clone() {
-            format!("{}-{}.db", name, sanitize(subset.as_str()))
+        let mut db_file_name = if let Some(subset) = self.subset.clone() {
+            format!("{}-{}", name, sanitize(subset.as_str()))
         } else {
-            format!("{}.db", name)
+            name
         };
+        if let Some(revision) = &self.revision {
+            db_file_name = format!("{}@{}", db_file_name, sanitize(revision.as_str()));
+        }
 
-        let db_file = base_dir.join(db_file_name);
+        let db_file = base_dir.join(format!("{}.db", db_file_name));
 
         // import the dataset if needed
         if !Path::new(&db_file).exists() {
-            import(
-                self.name,
-                self.subset,
-                db_file.clone(),
-                base_dir,
-                self.huggingface_token,
-                self.huggingface_cache_dir,
-            )?;
+            self.import(&db_file, &base_dir)?;
         }
 
         Ok(db_file)
     }
-}
 
-/// Import a dataset from huggingface. The transformed dataset is stored as sqlite database.
-fn import(
-    name: String,
-    subset: Option<String>,
-    base_file: PathBuf,
-    base_dir: PathBuf,
-    huggingface_token: Option<String>,
-    huggingface_cache_dir: Option<String>,
-) -> Result<(), ImporterError> {
-    let venv_python_path = install_python_deps(&base_dir)?;
+    /// Import the dataset from huggingface. The transformed dataset is stored as sqlite database.
+    ///
+    /// The dataset is first written to a temporary file, only moved to the database file once
+    /// complete, so an interrupted import starts again on the next call, the files already
+    /// downloaded being reused from the huggingface cache.
+    fn import(&self, db_file: &Path, base_dir: &Path) -> Result<(), ImporterError> {
+        let venv_python_path = match self.offline {
+            true => installed_python(base_dir)?,
+            false => install_python_deps(base_dir)?,
+        };
 
-    let mut command = Command::new(venv_python_path);
+        let partial_file = db_file.with_extension("db.partial");
+        if partial_file.exists() {
+            fs::remove_file(&partial_file)
+                .map_err(|err| ImporterError::Unknown(format!("{err:?}")))?;
+        }
 
-    command.arg(importer_script_path(&base_dir));
+        let mut command = Command::new(venv_python_path);
 
-    command.arg("--name");
-    command.arg(name);
+        command.arg(importer_script_path(base_dir));
 
-    command.arg("--file");
-    command.arg(base_file);
+        command.arg("--name");
+        command.arg(&self.name);
 
-    if let Some(subset) = subset {
-        command.arg("--subset");
-        command.arg(subset);
-    }
+        command.arg("--file");
+        command.arg(&partial_file);
 
-    if let Some(huggingface_token) = huggingface_token {
-        command.arg("--token");
-        command.arg(huggingface_token);
-    }
+        if let Some(subset) = &self.subset {
+            command.arg("--subset");
+            command.arg(subset);
+        }
 
-    if let Some(huggingface_cache_dir) = huggingface_cache_dir {
-        command.arg("--cache_dir");
-        command.arg(huggingface_cache_dir);
-    }
+        if let Some(revision) = &self.revision {
+            command.arg("--revision");
+            command.arg(revision);
+        }
 
-    let mut handle = command.spawn().unwrap();
-    handle
-        .wait()
-        .map_err(|err| ImporterError::Unknown(format!("{err:?}")))?;
+        if let Some(huggingface_token) = &self.huggingface_token {
+            command.arg("--token");
+            command.arg(huggingface_token);
+        }
+
+        if let Some(huggingface_cache_dir) = &self.huggingface_cache_dir {
+            command.arg("--cache_dir");
+            command.arg(huggingface_cache_dir);
+        }
+
+        if self.offline {
+            command.env("HF_DATASETS_OFFLINE", "1");
+            command.env("HF_HUB_OFFLINE", "1");
+        }
+
+        let mut handle = command.spawn().unwrap();
+        let status = handle
+            .wait()
+            .map_err(|err| ImporterError::Unknown(format!("{err:?}")))?;
+
+        match (status.success(), self.offline) {
+            (true, _) => {}
+            (false, true) => {
+                return Err(ImporterError::NotAvailableOffline(format!(
+                    "the dataset {} isn't in the huggingface cache ({status})",
+                    self.name
+                )))
+            }
+            (false, false) => return Err(ImporterError::FailToImport(status.to_string())),
+        }
 
-    Ok(())
+        fs::rename(&partial_file, db_file).map_err(|err| ImporterError::Unknown(format!("{err:?}")))
+    }
 }
 
 /// check python --version output is `Python 3.x.x`
@@ -243,6 +309,18 @@ fn importer_script_path(base_dir: &Path) -> PathBuf {
     path_file
 }
 
+/// The python of the venv environment installed by a previous import, without installing anything.
+fn installed_python(base_dir: &Path) -> Result<PathBuf, ImporterError> {
+    let venv_python_path = base_dir.join("venv").join(VENV_BIN_PYTHON);
+
+    match check_python_version_is_3(venv_python_path.to_str().unwrap()) {
+        true => Ok(venv_python_path),
+        false => Err(ImporterError::NotAvailableOffline(
+            "the python environment of the importer isn't installed".to_string(),
+        )),
+    }
+}
+
 fn install_python_deps(base_dir: &Path) -> Result<PathBuf, ImporterError> {
     let venv_dir = base_dir.join("venv");
     let venv_python_path = venv_dir.join(VENV_BIN_PYTHON);
@@ -1,12 +1,12 @@
 import argparse
 
 import pyarrow as pa
-from datasets import Audio, Image, load_dataset
+from datasets import Audio, DownloadConfig, Image, load_dataset
 from sqlalchemy import Column, Integer, Table, create_engine, event, inspect
 from sqlalchemy.types import LargeBinary
 
 
-def download_and_export(name: str, subset: str, db_file: str, token: str, cache_dir: str):
+def download_and_export(name: str, subset: str, revision: str, db_file: str, token: str, cache_dir: str):
     """
     Download a dataset from using HuggingFace dataset and export it to a sqlite database.
     """
@@ -19,14 +19,16 @@ def download_and_export(name: str, subset: str, db_file: str, token: str, cache_
     print("Starting huggingface dataset download and export")
     print(f"Dataset Name: {name}")
     print(f"Subset Name: {subset}")
+    print(f"Revision: {revision}")
     print(f"Sqlite database file: {db_file}")
     if cache_dir is None:
         print(f"Custom cache dir: {cache_dir}")
     print("*"*80)
 
-    # Load the dataset
+    # Load the dataset, resuming the downloads interrupted by a previous run
     dataset_all = load_dataset(
-        name, subset, cache_dir=cache_dir, use_auth_token=token)
+        name, subset, revision=revision, cache_dir=cache_dir, token=token,
+        download_config=DownloadConfig(resume_download=True))
 
     print(f"Dataset: {dataset_all}")
 
@@ -150,6 +152,9 @@ def parse_args():
     parser.add_argument(
         "--subset", type=str, help="Subset name", required=False, default=None
     )
+    parser.add_argument(
+        "--revision", type=str, help="Revision (branch, tag or commit hash)", required=False, default=None
+    )
     parser.add_argument(
         "--token", type=str, help="HuggingFace authentication token", required=False, default=None
     )
@@ -166,6 +171,7 @@ def run():
     download_and_export(
         args.name,
         args.subset,
+        args.revision,
         args.file,
         args.token,
         args.cache_dir,
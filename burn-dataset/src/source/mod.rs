@@ -1,3 +1,10 @@
 /// Huggingface source
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 pub mod huggingface;
+
+/// Tabular sources, e.g. CSV and Parquet files.
+pub mod tabular;
+
+/// WebDataset source, i.e. tar shards of samples.
+#[cfg(feature = "webdataset")]
+pub mod webdataset;
@@ -0,0 +1,195 @@
+use super::TabularError;
+use crate::IterableDataset;
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::FileReader;
+use arrow_schema::{ArrowError, Schema};
+use serde::de::DeserializeOwned;
+use std::{
+    fs::File,
+    io::BufReader,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// An [iterable dataset](IterableDataset) reading the rows of an Arrow IPC file, also known as a
+/// Feather file.
+///
+/// The rows are deserialized into the items with serde, each column being a field of the items.
+/// The missing values are absent fields, so nullable columns should be `Option` fields. The file
+/// is read one record batch at a time, so it doesn't need to fit in memory.
+///
+/// # Panics
+///
+/// The iterators panic when a batch can't be read or a row can't be deserialized.
+pub struct ArrowDataset<I> {
+    path: PathBuf,
+    schema: Schema,
+    projection: Option<Vec<usize>>,
+    item: PhantomData<I>,
+}
+
+impl<I> ArrowDataset<I> {
+    /// Creates a dataset reading the given Arrow IPC file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, TabularError> {
+        let path = path.as_ref().to_path_buf();
+        let reader = FileReader::try_new(BufReader::new(File::open(&path)?), None)?;
+
+        Ok(Self {
+            path,
+            schema: reader.schema().as_ref().clone(),
+            projection: None,
+            item: PhantomData,
+        })
+    }
+
+    /// Only reads the given columns, the other columns being skipped.
+    pub fn with_columns(mut self, columns: &[&str]) -> Result<Self, TabularError> {
+        self.projection = Some(column_indices(&self.schema, columns)?);
+        Ok(self)
+    }
+}
+
+impl<I> IterableDataset<I> for ArrowDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        let file = File::open(&self.path)
+            .unwrap_or_else(|err| panic!("Can't open {}: {err}", self.path.display()));
+        let reader = FileReader::try_new(BufReader::new(file), self.projection.clone())
+            .unwrap_or_else(|err| panic!("Can't read {}: {err}", self.path.display()));
+
+        Box::new(RecordBatchItems::new(reader))
+    }
+}
+
+/// Gets the indices of the given columns in the schema.
+pub(crate) fn column_indices(
+    schema: &Schema,
+    columns: &[&str],
+) -> Result<Vec<usize>, TabularError> {
+    columns
+        .iter()
+        .map(|column| {
+            schema
+                .index_of(column)
+                .map_err(|_| TabularError::MissingColumn(column.to_string()))
+        })
+        .collect()
+}
+
+/// Deserializes the rows of the record batches, one batch at a time.
+pub(crate) struct RecordBatchItems<R, I> {
+    batches: R,
+    items: std::vec::IntoIter<I>,
+}
+
+impl<R, I> RecordBatchItems<R, I> {
+    pub(crate) fn new(batches: R) -> Self {
+        Self {
+            batches,
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<R, I> Iterator for RecordBatchItems<R, I>
+where
+    R: Iterator<Item = Result<RecordBatch, ArrowError>>,
+    I: DeserializeOwned,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(item);
+            }
+
+            let batch = self
+                .batches
+                .next()?
+                .unwrap_or_else(|err| panic!("Can't read the record batch: {err}"));
+            self.items = deserialize_rows::<I>(&batch).into_iter();
+        }
+    }
+}
+
+/// Deserializes the rows of the record batch, going through their JSON representation.
+fn deserialize_rows<I: DeserializeOwned>(batch: &RecordBatch) -> Vec<I> {
+    let mut writer = arrow_json::ArrayWriter::new(Vec::new());
+    writer
+        .write(batch)
+        .and_then(|_| writer.finish())
+        .unwrap_or_else(|err| panic!("Can't convert the record batch: {err}"));
+
+    serde_json::from_slice(&writer.into_inner())
+        .unwrap_or_else(|err| panic!("Can't deserialize the rows: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Float32Array, Int64Array, StringArray};
+    use arrow_ipc::writer::FileWriter;
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Clone, Debug, Deserialize, PartialEq)]
+    struct Row {
+        name: Option<String>,
+        value: f32,
+    }
+
+    fn record_batch(offset: i64) -> RecordBatch {
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            ("id", Arc::new(Int64Array::from(vec![offset, offset + 1]))),
+            ("name", Arc::new(StringArray::from(vec![Some("a"), None]))),
+            ("value", Arc::new(Float32Array::from(vec![0.5, 1.5]))),
+        ];
+        RecordBatch::try_from_iter(columns).unwrap()
+    }
+
+    #[test]
+    fn test_arrow_dataset_reads_every_batch() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let batch = record_batch(0);
+        let mut writer = FileWriter::try_new(file.reopen().unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&record_batch(2)).unwrap();
+        writer.finish().unwrap();
+
+        let dataset = ArrowDataset::<Row>::new(file.path())
+            .unwrap()
+            .with_columns(&["name", "value"])
+            .unwrap();
+
+        let first = Row {
+            name: Some("a".to_string()),
+            value: 0.5,
+        };
+        let second = Row {
+            name: None,
+            value: 1.5,
+        };
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            vec![first.clone(), second.clone(), first, second]
+        );
+    }
+
+    #[test]
+    fn test_arrow_dataset_with_missing_column() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let batch = record_batch(0);
+        let mut writer = FileWriter::try_new(file.reopen().unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let result = ArrowDataset::<Row>::new(file.path())
+            .unwrap()
+            .with_columns(&["label"]);
+
+        assert!(matches!(result, Err(TabularError::MissingColumn(_))));
+    }
+}
@@ -0,0 +1,118 @@
+use super::TabularError;
+use crate::IterableDataset;
+use serde::de::DeserializeOwned;
+use std::{
+    fs::File,
+    io::BufReader,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// An [iterable dataset](IterableDataset) reading the rows of a CSV file.
+///
+/// The rows are deserialized into the items with [serde](https://docs.rs/csv/latest/csv/tutorial/index.html#reading-with-serde),
+/// the header of the file containing the names of the fields of the items. The file is read while
+/// iterating, so it doesn't need to fit in memory; the smaller files can be loaded in an
+/// [in memory dataset](crate::InMemDataset) with [from_csv](crate::InMemDataset::from_csv) instead.
+///
+/// # Panics
+///
+/// The iterators panic when a row can't be read or deserialized.
+pub struct CsvDataset<I> {
+    path: PathBuf,
+    delimiter: u8,
+    item: PhantomData<I>,
+}
+
+impl<I> CsvDataset<I> {
+    /// Creates a dataset reading the given CSV file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, TabularError> {
+        let path = path.as_ref().to_path_buf();
+        // Fail early when the file can't be read.
+        ::csv::Reader::from_path(&path)?.headers()?;
+
+        Ok(Self {
+            path,
+            delimiter: b',',
+            item: PhantomData,
+        })
+    }
+
+    /// Sets the delimiter of the fields, e.g. `b'\t'` for TSV files.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+}
+
+impl<I> IterableDataset<I> for CsvDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        let file = File::open(&self.path)
+            .unwrap_or_else(|err| panic!("Can't open {}: {err}", self.path.display()));
+        let reader = ::csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .from_reader(BufReader::new(file));
+
+        Box::new(
+            reader
+                .into_deserialize()
+                .map(|row| row.unwrap_or_else(|err| panic!("Can't deserialize the row: {err}"))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        column_str: String,
+        column_int: i64,
+        column_bool: bool,
+    }
+
+    #[test]
+    fn test_csv_dataset() {
+        let dataset = CsvDataset::<Row>::new("tests/data/dataset.csv").unwrap();
+
+        let rows = dataset.iter().collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[1],
+            Row {
+                column_str: "HI2".to_string(),
+                column_int: 1,
+                column_bool: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_csv_dataset_with_delimiter() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            "column_str\tcolumn_int\tcolumn_bool\na,1,b\t2\ttrue\n",
+        )
+        .unwrap();
+
+        let dataset = CsvDataset::<Row>::new(file.path())
+            .unwrap()
+            .with_delimiter(b'\t');
+
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            vec![Row {
+                column_str: "a,1,b".to_string(),
+                column_int: 2,
+                column_bool: true,
+            }]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(feature = "arrow")]
+mod arrow;
+mod csv;
+#[cfg(feature = "parquet")]
+mod parquet;
+
+#[cfg(feature = "arrow")]
+pub use self::arrow::*;
+pub use self::csv::*;
+#[cfg(feature = "parquet")]
+pub use self::parquet::*;
+
+use thiserror::Error;
+
+/// Error type for the tabular datasets.
+#[derive(Error, Debug)]
+pub enum TabularError {
+    /// Fail to open the file.
+    #[error("io error: `{0}`")]
+    Io(#[from] std::io::Error),
+
+    /// Fail to read the CSV file.
+    #[error("csv error: `{0}`")]
+    Csv(#[from] ::csv::Error),
+
+    /// Fail to read the Arrow file.
+    #[cfg(feature = "arrow")]
+    #[error("arrow error: `{0}`")]
+    Arrow(#[from] arrow_schema::ArrowError),
+
+    /// Fail to read the Parquet file.
+    #[cfg(feature = "parquet")]
+    #[error("parquet error: `{0}`")]
+    Parquet(#[from] ::parquet::errors::ParquetError),
+
+    /// The selected column isn't in the file.
+    #[error("missing column: `{0}`")]
+    MissingColumn(String),
+}
@@ -0,0 +1,126 @@
+use super::{arrow::column_indices, arrow::RecordBatchItems, TabularError};
+use crate::IterableDataset;
+use ::parquet::arrow::{
+    arrow_reader::{ArrowReaderMetadata, ParquetRecordBatchReaderBuilder},
+    ProjectionMask,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    fs::File,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// An [iterable dataset](IterableDataset) reading the rows of a Parquet file.
+///
+/// The rows are deserialized into the items with serde, each column being a field of the items.
+/// The missing values are absent fields, so nullable columns should be `Option` fields. The file
+/// is read in batches of rows, 1024 by default, so it doesn't need to fit in memory.
+///
+/// # Panics
+///
+/// The iterators panic when a batch can't be read or a row can't be deserialized.
+pub struct ParquetDataset<I> {
+    path: PathBuf,
+    metadata: ArrowReaderMetadata,
+    columns: Option<Vec<usize>>,
+    batch_size: usize,
+    item: PhantomData<I>,
+}
+
+impl<I> ParquetDataset<I> {
+    /// Creates a dataset reading the given Parquet file.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, TabularError> {
+        let path = path.as_ref().to_path_buf();
+        let metadata = ArrowReaderMetadata::load(&File::open(&path)?, Default::default())?;
+
+        Ok(Self {
+            path,
+            metadata,
+            columns: None,
+            batch_size: 1024,
+            item: PhantomData,
+        })
+    }
+
+    /// Only reads the given columns, the other columns being skipped.
+    pub fn with_columns(mut self, columns: &[&str]) -> Result<Self, TabularError> {
+        self.columns = Some(column_indices(self.metadata.schema(), columns)?);
+        Ok(self)
+    }
+
+    /// Sets the number of rows read at once.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+impl<I> IterableDataset<I> for ParquetDataset<I>
+where
+    I: DeserializeOwned + Send + Sync,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        let file = File::open(&self.path)
+            .unwrap_or_else(|err| panic!("Can't open {}: {err}", self.path.display()));
+        // The metadata read when creating the dataset is reused by each pass.
+        let mut builder =
+            ParquetRecordBatchReaderBuilder::new_with_metadata(file, self.metadata.clone())
+                .with_batch_size(self.batch_size);
+
+        if let Some(columns) = &self.columns {
+            let mask = ProjectionMask::roots(self.metadata.parquet_schema(), columns.clone());
+            builder = builder.with_projection(mask);
+        }
+
+        let reader = builder
+            .build()
+            .unwrap_or_else(|err| panic!("Can't read {}: {err}", self.path.display()));
+
+        Box::new(RecordBatchItems::new(reader))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        usize::try_from(self.metadata.metadata().file_metadata().num_rows()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::parquet::arrow::ArrowWriter;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        id: i64,
+    }
+
+    #[test]
+    fn test_parquet_dataset_reads_the_file_in_batches() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            ("id", Arc::new(Int64Array::from_iter_values(0..10))),
+            ("name", Arc::new(StringArray::from_iter_values(["a"; 10]))),
+        ];
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(file.reopen().unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let dataset = ParquetDataset::<Row>::new(file.path())
+            .unwrap()
+            .with_columns(&["id"])
+            .unwrap()
+            .with_batch_size(3);
+
+        assert_eq!(dataset.size_hint(), Some(10));
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            (0..10).map(|id| Row { id }).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use super::tar::{TarEntry, TarReader};
+use crate::IterableDataset;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+/// A sample of a [WebDataset](WebDataset), i.e. the files of a shard sharing the same key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebDatasetSample {
+    /// The key of the sample, i.e. the path of its files up to the first dot of their name.
+    pub key: String,
+
+    /// The content of each file, by extension, e.g. `jpg`, `cls` or `json`.
+    pub files: HashMap<String, Vec<u8>>,
+}
+
+/// A dataset streaming the samples of tar shards in the
+/// [WebDataset](https://github.com/webdataset/webdataset) format, the usual format of the large
+/// pretraining corpora.
+///
+/// Each shard is a tar archive whose consecutive files sharing the same key, e.g. `000.jpg` and
+/// `000.cls`, form a sample. The shards are read sequentially from the local files, or from HTTP
+/// with the `webdataset-http` feature, without being downloaded first. The archives compressed
+/// with gzip, i.e. ending with `.gz` or `.tgz`, are decompressed on the fly.
+///
+/// The samples are streamed in the order of the shards, so they should be shuffled with a
+/// [shuffle buffer](crate::transform::ShuffleBufferDataset).
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_dataset::source::webdataset::WebDataset;
+/// use burn_dataset::IterableDataset;
+///
+/// let dataset = WebDataset::from_pattern("data/train-{000000..000099}.tar");
+///
+/// for sample in dataset.iter().take(10) {
+///     println!("{} {:?}", sample.key, sample.files.keys());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebDataset {
+    shards: Vec<String>,
+    size_hint: Option<usize>,
+}
+
+impl WebDataset {
+    /// Creates a dataset streaming the given shards, paths or URLs.
+    pub fn new<S: Into<String>>(shards: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            shards: shards.into_iter().map(Into::into).collect(),
+            size_hint: None,
+        }
+    }
+
+    /// Creates a dataset streaming the shards matching the pattern, where the braces are expanded
+    /// to a range, e.g. `{000..002}` to `000`, `001` and `002`, or to a list, e.g. `{a,b}`.
+    pub fn from_pattern(pattern: &str) -> Self {
+        Self::new(expand_braces(pattern))
+    }
+
+    /// Sets the number of samples of the shards, to report the progress.
+    pub fn with_size_hint(mut self, size: usize) -> Self {
+        self.size_hint = Some(size);
+        self
+    }
+
+    /// The paths or URLs of the shards.
+    pub fn shards(&self) -> &[String] {
+        &self.shards
+    }
+}
+
+impl IterableDataset<WebDatasetSample> for WebDataset {
+    fn iter(&self) -> Box<dyn Iterator<Item = WebDatasetSample> + '_> {
+        Box::new(self.shards.iter().flat_map(|shard| {
+            let entries = TarReader::new(open_shard(shard)).map(move |entry| {
+                entry.unwrap_or_else(|err| panic!("Can't read the shard {shard}: {err}"))
+            });
+
+            Samples {
+                entries,
+                next: None,
+            }
+        }))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.size_hint
+    }
+}
+
+/// Groups the consecutive files sharing the same key.
+struct Samples<E> {
+    entries: E,
+    next: Option<TarEntry>,
+}
+
+impl<E: Iterator<Item = TarEntry>> Iterator for Samples<E> {
+    type Item = WebDatasetSample;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.next.take().or_else(|| self.entries.next())?;
+        let (key, extension) = split_key(&first.path);
+        let mut sample = WebDatasetSample {
+            key: key.to_string(),
+            files: HashMap::from([(extension.to_string(), first.data)]),
+        };
+
+        for entry in self.entries.by_ref() {
+            let (key, extension) = split_key(&entry.path);
+            if key != sample.key {
+                self.next = Some(entry);
+                break;
+            }
+            sample.files.insert(extension.to_string(), entry.data);
+        }
+
+        Some(sample)
+    }
+}
+
+/// Splits the path at the first dot of the file name, e.g. `a/b.c.jpg` into `a/b` and `c.jpg`.
+fn split_key(path: &str) -> (&str, &str) {
+    let name_start = path.rfind('/').map_or(0, |index| index + 1);
+
+    match path[name_start..].find('.') {
+        Some(index) => (&path[..name_start + index], &path[name_start + index + 1..]),
+        None => (path, ""),
+    }
+}
+
+fn open_shard(shard: &str) -> Box<dyn Read + Send> {
+    let reader: Box<dyn Read + Send> =
+        if shard.starts_with("http://") || shard.starts_with("https://") {
+            open_url(shard)
+        } else {
+            let file = File::open(shard)
+                .unwrap_or_else(|err| panic!("Can't open the shard {shard}: {err}"));
+            Box::new(file)
+        };
+    let reader = BufReader::new(reader);
+
+    match shard.ends_with(".gz") || shard.ends_with(".tgz") {
+        true => Box::new(flate2::read::GzDecoder::new(reader)),
+        false => Box::new(reader),
+    }
+}
+
+#[cfg(feature = "webdataset-http")]
+fn open_url(url: &str) -> Box<dyn Read + Send> {
+    let response = ureq::get(url)
+        .call()
+        .unwrap_or_else(|err| panic!("Can't download the shard {url}: {err}"));
+
+    response.into_reader()
+}
+
+#[cfg(not(feature = "webdataset-http"))]
+fn open_url(url: &str) -> Box<dyn Read + Send> {
+    panic!("Can't download the shard {url}, the `webdataset-http` feature is disabled.")
+}
+
+/// Expands the braces of the pattern, the first one first.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let (start, end) = match (pattern.find('{'), pattern.find('}')) {
+        (Some(start), Some(end)) if start < end => (start, end),
+        _ => return vec![pattern.to_string()],
+    };
+    let (prefix, content, suffix) = (
+        &pattern[..start],
+        &pattern[start + 1..end],
+        &pattern[end + 1..],
+    );
+
+    let values = match content.split_once("..") {
+        Some((first, last)) => match (first.parse::<usize>(), last.parse::<usize>()) {
+            // The numbers are padded with zeros to the width of the first number.
+            (Ok(start), Ok(end)) => (start..=end)
+                .map(|value| format!("{value:0width$}", width = first.len()))
+                .collect(),
+            _ => vec![content.to_string()],
+        },
+        None => content.split(',').map(str::to_string).collect::<Vec<_>>(),
+    };
+
+    values
+        .into_iter()
+        .flat_map(|value| expand_braces(&format!("{prefix}{value}{suffix}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::webdataset::tar::tests::tar;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    #[test]
+    fn test_web_dataset_groups_the_files_by_key() {
+        let directory = tempfile::tempdir().unwrap();
+        let first = directory.path().join("shard-0.tar");
+        let second = directory.path().join("shard-1.tar.gz");
+        std::fs::write(
+            &first,
+            tar(&[
+                ("a/000.jpg", b"image 0"),
+                ("a/000.cls", b"0"),
+                ("a/001.jpg", b"image 1"),
+            ]),
+        )
+        .unwrap();
+        let mut encoder = GzEncoder::new(File::create(&second).unwrap(), Compression::default());
+        encoder
+            .write_all(&tar(&[("b/002.seg.png", b"mask 2"), ("b/002.cls", b"2")]))
+            .unwrap();
+        encoder.finish().unwrap();
+
+        let dataset = WebDataset::new([first, second].map(|path| path.display().to_string()));
+        let samples = dataset.iter().collect::<Vec<_>>();
+
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].key, "a/000");
+        assert_eq!(samples[0].files["jpg"], b"image 0");
+        assert_eq!(samples[0].files["cls"], b"0");
+        assert_eq!(samples[1].key, "a/001");
+        assert_eq!(samples[1].files.len(), 1);
+        assert_eq!(samples[2].key, "b/002");
+        assert_eq!(samples[2].files["seg.png"], b"mask 2");
+    }
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(
+            expand_braces("train-{008..010}-{a,b}.tar"),
+            vec![
+                "train-008-a.tar",
+                "train-008-b.tar",
+                "train-009-a.tar",
+                "train-009-b.tar",
+                "train-010-a.tar",
+                "train-010-b.tar",
+            ]
+        );
+        assert_eq!(expand_braces("train.tar"), vec!["train.tar"]);
+    }
+}
@@ -0,0 +1,4 @@
+mod dataset;
+mod tar;
+
+pub use dataset::*;
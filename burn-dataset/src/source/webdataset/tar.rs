@@ -0,0 +1,241 @@
+use std::io::{self, Read};
+
+/// The size of the headers and of the blocks of a tar archive.
+const BLOCK_SIZE: usize = 512;
+
+/// A regular file of a tar archive.
+#[derive(Debug, PartialEq)]
+pub(crate) struct TarEntry {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// Reads the regular files of a tar archive sequentially, without seeking, so the archive can be
+/// streamed.
+///
+/// The ustar, GNU and pax formats are supported, including their long paths. The other entries,
+/// e.g. directories and links, are skipped.
+pub(crate) struct TarReader<R> {
+    reader: R,
+    long_path: Option<String>,
+    done: bool,
+}
+
+impl<R: Read> TarReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            long_path: None,
+            done: false,
+        }
+    }
+
+    fn next_entry(&mut self) -> io::Result<Option<TarEntry>> {
+        let mut header = [0; BLOCK_SIZE];
+        let mut padding = [0; BLOCK_SIZE];
+
+        loop {
+            if !read_block(&mut self.reader, &mut header)? || header.iter().all(|byte| *byte == 0) {
+                return Ok(None);
+            }
+
+            let size = parse_size(&header[124..136])?;
+            let mut data = vec![0; size];
+            self.reader.read_exact(&mut data)?;
+            // The data is padded to a whole number of blocks.
+            let padding_size = (BLOCK_SIZE - size % BLOCK_SIZE) % BLOCK_SIZE;
+            self.reader.read_exact(&mut padding[..padding_size])?;
+
+            match header[156] {
+                b'0' | b'\0' | b'7' => {
+                    let path = match self.long_path.take() {
+                        Some(path) => path,
+                        None => header_path(&header),
+                    };
+                    return Ok(Some(TarEntry { path, data }));
+                }
+                // GNU long path of the next entry.
+                b'L' => self.long_path = Some(c_string(&data)),
+                // Pax extended header of the next entry.
+                b'x' => {
+                    if let Some(path) = pax_path(&data) {
+                        self.long_path = Some(path);
+                    }
+                }
+                _ => self.long_path = None,
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for TarReader<R> {
+    type Item = io::Result<TarEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let entry = self.next_entry().transpose();
+        if !matches!(entry, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        entry
+    }
+}
+
+/// Fills the block, returning false when the reader is at its end.
+fn read_block<R: Read>(reader: &mut R, block: &mut [u8; BLOCK_SIZE]) -> io::Result<bool> {
+    let mut filled = 0;
+
+    while filled < BLOCK_SIZE {
+        match reader.read(&mut block[filled..])? {
+            0 if filled == 0 => return Ok(false),
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            read => filled += read,
+        }
+    }
+
+    Ok(true)
+}
+
+/// The size is written in octal, or in base 256 when its first bit is set.
+fn parse_size(field: &[u8]) -> io::Result<usize> {
+    if field[0] & 0x80 != 0 {
+        let size = field[1..]
+            .iter()
+            .fold(u64::from(field[0] & 0x7f), |size, byte| {
+                (size << 8) | u64::from(*byte)
+            });
+        return Ok(size as usize);
+    }
+
+    let digits = c_string(field);
+    let digits = digits.trim_matches(|c: char| c == ' ' || c == '\0');
+    match digits.is_empty() {
+        true => Ok(0),
+        false => usize::from_str_radix(digits, 8).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid size in tar header: `{digits}`"),
+            )
+        }),
+    }
+}
+
+/// The path of the header, with the prefix of the ustar format.
+fn header_path(header: &[u8; BLOCK_SIZE]) -> String {
+    let name = c_string(&header[0..100]);
+
+    if &header[257..262] != b"ustar" {
+        return name;
+    }
+
+    match c_string(&header[345..500]) {
+        prefix if prefix.is_empty() => name,
+        prefix => format!("{prefix}/{name}"),
+    }
+}
+
+/// The path of the records of a pax extended header, each record being `<length> <key>=<value>\n`.
+fn pax_path(data: &[u8]) -> Option<String> {
+    let mut records = data;
+
+    while !records.is_empty() {
+        let space = records.iter().position(|byte| *byte == b' ')?;
+        let length = std::str::from_utf8(&records[..space])
+            .ok()?
+            .parse::<usize>()
+            .ok()?;
+        let record = records.get(space + 1..length)?;
+        if let Some(path) = record.strip_prefix(b"path=") {
+            let path = path.strip_suffix(b"\n").unwrap_or(path);
+            return Some(String::from_utf8_lossy(path).into_owned());
+        }
+        records = &records[length..];
+    }
+
+    None
+}
+
+/// The string of a field ending with a null byte, unless it fills the field.
+fn c_string(field: &[u8]) -> String {
+    let end = field
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(field.len());
+
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Writes a ustar archive of the files.
+    pub(crate) fn tar(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut archive = Vec::new();
+
+        for (path, data) in files {
+            let mut header = [0; BLOCK_SIZE];
+            header[..path.len()].copy_from_slice(path.as_bytes());
+            header[100..107].copy_from_slice(b"0000644");
+            header[124..135].copy_from_slice(format!("{:011o}", data.len()).as_bytes());
+            header[156] = b'0';
+            header[257..263].copy_from_slice(b"ustar\0");
+            header[263..265].copy_from_slice(b"00");
+            // The checksum is computed with spaces in its field.
+            header[148..156].copy_from_slice(b"        ");
+            let checksum = header.iter().map(|byte| *byte as u32).sum::<u32>();
+            header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+
+            archive.extend_from_slice(&header);
+            archive.extend_from_slice(data);
+            archive.resize((archive.len() + BLOCK_SIZE - 1) / BLOCK_SIZE * BLOCK_SIZE, 0);
+        }
+
+        archive.resize(archive.len() + 2 * BLOCK_SIZE, 0);
+        archive
+    }
+
+    #[test]
+    fn test_tar_reader_reads_the_files() {
+        let archive = tar(&[("a/1.txt", b"hello"), ("a/1.cls", b"3")]);
+
+        let entries = TarReader::new(archive.as_slice())
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                TarEntry {
+                    path: "a/1.txt".to_string(),
+                    data: b"hello".to_vec(),
+                },
+                TarEntry {
+                    path: "a/1.cls".to_string(),
+                    data: b"3".to_vec(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tar_reader_fails_on_truncated_archives() {
+        let archive = tar(&[("1.txt", b"hello")]);
+
+        let entries = TarReader::new(&archive[..600]).collect::<Vec<_>>();
+
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].is_err());
+    }
+
+    #[test]
+    fn test_pax_path() {
+        let record = b"29 path=a/very/long/path.txt\n";
+
+        assert_eq!(pax_path(record), Some("a/very/long/path.txt".to_string()));
+    }
+}
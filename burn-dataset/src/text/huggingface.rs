@@ -0,0 +1,114 @@
+use super::Tokenizer;
+use std::path::Path;
+
+/// A tokenizer of the [tokenizers](https://github.com/huggingface/tokenizers) library, e.g. the
+/// BPE or WordPiece tokenizer of a pretrained model.
+///
+/// The tokenizer of a model is usually loaded from the `tokenizer.json` file of its repository
+/// with [from_file](HuggingfaceTokenizer::from_file).
+pub struct HuggingfaceTokenizer {
+    tokenizer: tokenizers::Tokenizer,
+    special_tokens: bool,
+    pad_token: usize,
+}
+
+impl HuggingfaceTokenizer {
+    /// Creates a tokenizer from a [tokenizers](tokenizers::Tokenizer) tokenizer.
+    ///
+    /// The padding token is the one of the padding parameters of the tokenizer, or the `[PAD]`
+    /// or `<pad>` token of its vocabulary, and the id 0 otherwise, e.g. for GPT-2, which is fine
+    /// since the padded positions are masked.
+    pub fn new(tokenizer: tokenizers::Tokenizer) -> Self {
+        let pad_token = match tokenizer.get_padding() {
+            Some(padding) => padding.pad_id,
+            None => tokenizer
+                .token_to_id("[PAD]")
+                .or_else(|| tokenizer.token_to_id("<pad>"))
+                .unwrap_or(0),
+        };
+
+        Self {
+            tokenizer,
+            special_tokens: true,
+            pad_token: pad_token as usize,
+        }
+    }
+
+    /// Loads a tokenizer from a `tokenizer.json` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, tokenizers::Error> {
+        tokenizers::Tokenizer::from_file(path).map(Self::new)
+    }
+
+    /// Sets whether the special tokens of the model, e.g. `[CLS]` and `[SEP]`, are added when
+    /// encoding, which is the default.
+    pub fn with_special_tokens(mut self, special_tokens: bool) -> Self {
+        self.special_tokens = special_tokens;
+        self
+    }
+
+    /// Sets the token id used to pad the sequences.
+    pub fn with_pad_token(mut self, pad_token: usize) -> Self {
+        self.pad_token = pad_token;
+        self
+    }
+}
+
+impl Tokenizer for HuggingfaceTokenizer {
+    fn encode(&self, text: &str) -> Vec<usize> {
+        let encoding = self
+            .tokenizer
+            .encode(text, self.special_tokens)
+            .unwrap_or_else(|err| panic!("Can't encode the text: {err}"));
+
+        encoding.get_ids().iter().map(|id| *id as usize).collect()
+    }
+
+    fn decode(&self, tokens: &[usize]) -> String {
+        let tokens = tokens.iter().map(|id| *id as u32).collect::<Vec<_>>();
+
+        self.tokenizer
+            .decode(&tokens, true)
+            .unwrap_or_else(|err| panic!("Can't decode the tokens: {err}"))
+    }
+
+    fn vocab_size(&self) -> usize {
+        self.tokenizer.get_vocab_size(true)
+    }
+
+    fn pad_token(&self) -> usize {
+        self.pad_token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const TOKENIZER: &str = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": { "type": "Whitespace" },
+        "post_processor": null,
+        "decoder": null,
+        "model": {
+            "type": "WordLevel",
+            "vocab": { "[UNK]": 0, "<pad>": 1, "hello": 2, "world": 3 },
+            "unk_token": "[UNK]"
+        }
+    }"#;
+
+    #[test]
+    fn huggingface_tokenizer_should_encode_with_the_model() {
+        let tokenizer = tokenizers::Tokenizer::from_str(TOKENIZER).unwrap();
+        let tokenizer = HuggingfaceTokenizer::new(tokenizer);
+
+        assert_eq!(tokenizer.pad_token(), 1);
+        assert_eq!(tokenizer.vocab_size(), 4);
+        assert_eq!(tokenizer.encode("hello world again"), vec![2, 3, 0]);
+        assert_eq!(tokenizer.decode(&[3, 2]), "world hello");
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "tokenizers")]
+mod huggingface;
+mod tokenizer;
+
+#[cfg(feature = "tokenizers")]
+pub use huggingface::*;
+pub use tokenizer::*;
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+/// Converts texts into sequences of token ids, and back.
+///
+/// The `Send + Sync` bounds allow the tokenizer to be shared by the workers of the data loaders,
+/// usually in an `Arc<dyn Tokenizer>` held by the batcher.
+pub trait Tokenizer: Send + Sync {
+    /// Converts a text into a sequence of token ids.
+    fn encode(&self, text: &str) -> Vec<usize>;
+
+    /// Converts a sequence of token ids back into a text.
+    fn decode(&self, tokens: &[usize]) -> String;
+
+    /// Gets the number of tokens of the vocabulary.
+    fn vocab_size(&self) -> usize;
+
+    /// Gets the token id used to pad the sequences to the same length.
+    fn pad_token(&self) -> usize;
+}
+
+/// The tokens of a [whitespace](WhitespaceTokenizer) or [character](CharTokenizer) tokenizer.
+///
+/// The padding token [PAD](Vocabulary::PAD) has the id 0 and the unknown token
+/// [UNK](Vocabulary::UNK) the id 1, followed by the other tokens.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vocabulary {
+    tokens: Vec<String>,
+    ids: HashMap<String, usize>,
+}
+
+impl Vocabulary {
+    /// The padding token.
+    pub const PAD: &'static str = "[PAD]";
+
+    /// The token replacing the tokens missing from the vocabulary.
+    pub const UNK: &'static str = "[UNK]";
+
+    /// Creates a vocabulary with the given tokens after the special tokens.
+    pub fn new<S: Into<String>>(tokens: impl IntoIterator<Item = S>) -> Self {
+        let mut vocabulary = Self {
+            tokens: Vec::new(),
+            ids: HashMap::new(),
+        };
+
+        for token in [Self::PAD.to_string(), Self::UNK.to_string()]
+            .into_iter()
+            .chain(tokens.into_iter().map(Into::into))
+        {
+            if !vocabulary.ids.contains_key(&token) {
+                vocabulary
+                    .ids
+                    .insert(token.clone(), vocabulary.tokens.len());
+                vocabulary.tokens.push(token);
+            }
+        }
+
+        vocabulary
+    }
+
+    /// Creates a vocabulary with the tokens appearing at least `min_frequency` times, the most
+    /// frequent first.
+    pub fn from_tokens<S: AsRef<str>>(
+        tokens: impl IntoIterator<Item = S>,
+        min_frequency: usize,
+    ) -> Self {
+        let mut counts = HashMap::<String, usize>::new();
+        for token in tokens {
+            *counts.entry(token.as_ref().to_string()).or_default() += 1;
+        }
+
+        let mut counts = counts
+            .into_iter()
+            .filter(|(_, count)| *count >= min_frequency)
+            .collect::<Vec<_>>();
+        // The ties are sorted alphabetically, so the ids don't depend on the order of the texts.
+        counts.sort_by(|(a, count_a), (b, count_b)| count_b.cmp(count_a).then(a.cmp(b)));
+
+        Self::new(counts.into_iter().map(|(token, _)| token))
+    }
+
+    /// Gets the id of the token, or the id of the unknown token when it is missing.
+    pub fn id(&self, token: &str) -> usize {
+        self.ids.get(token).copied().unwrap_or(1)
+    }
+
+    /// Gets the token of the id.
+    pub fn token(&self, id: usize) -> Option<&str> {
+        self.tokens.get(id).map(String::as_str)
+    }
+
+    /// Gets the number of tokens, including the special tokens.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Checks if the vocabulary only contains the special tokens.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.len() == 2
+    }
+
+    /// The tokens, ordered by id.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+}
+
+/// A tokenizer splitting the texts on whitespaces, with one token per word.
+///
+/// It doesn't require any pretrained model, which makes it a simple baseline for small corpora,
+/// while the `HuggingfaceTokenizer` of the `tokenizers` feature
+/// handles the subwords of the pretrained models.
+#[derive(Clone, Debug)]
+pub struct WhitespaceTokenizer {
+    vocabulary: Vocabulary,
+}
+
+impl WhitespaceTokenizer {
+    /// Creates a tokenizer with the given vocabulary.
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self { vocabulary }
+    }
+
+    /// Creates a tokenizer with the words appearing at least `min_frequency` times in the texts.
+    pub fn from_texts<S: AsRef<str>>(
+        texts: impl IntoIterator<Item = S>,
+        min_frequency: usize,
+    ) -> Self {
+        let texts = texts.into_iter().collect::<Vec<_>>();
+        let words = texts
+            .iter()
+            .flat_map(|text| text.as_ref().split_whitespace());
+
+        Self::new(Vocabulary::from_tokens(words, min_frequency))
+    }
+
+    /// The vocabulary of the tokenizer.
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+}
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn encode(&self, text: &str) -> Vec<usize> {
+        text.split_whitespace()
+            .map(|word| self.vocabulary.id(word))
+            .collect()
+    }
+
+    fn decode(&self, tokens: &[usize]) -> String {
+        decode(&self.vocabulary, tokens)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn vocab_size(&self) -> usize {
+        self.vocabulary.len()
+    }
+
+    fn pad_token(&self) -> usize {
+        0
+    }
+}
+
+/// A tokenizer with one token per character, e.g. for character-level language models.
+#[derive(Clone, Debug)]
+pub struct CharTokenizer {
+    vocabulary: Vocabulary,
+}
+
+impl CharTokenizer {
+    /// Creates a tokenizer with the given vocabulary.
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self { vocabulary }
+    }
+
+    /// Creates a tokenizer with the characters appearing at least `min_frequency` times in the
+    /// texts.
+    pub fn from_texts<S: AsRef<str>>(
+        texts: impl IntoIterator<Item = S>,
+        min_frequency: usize,
+    ) -> Self {
+        let texts = texts.into_iter().collect::<Vec<_>>();
+        let chars = texts
+            .iter()
+            .flat_map(|text| text.as_ref().chars())
+            .map(String::from);
+
+        Self::new(Vocabulary::from_tokens(chars, min_frequency))
+    }
+
+    /// The vocabulary of the tokenizer.
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+}
+
+impl Tokenizer for CharTokenizer {
+    fn encode(&self, text: &str) -> Vec<usize> {
+        let mut buffer = [0; 4];
+
+        text.chars()
+            .map(|char| self.vocabulary.id(char.encode_utf8(&mut buffer)))
+            .collect()
+    }
+
+    fn decode(&self, tokens: &[usize]) -> String {
+        decode(&self.vocabulary, tokens).collect()
+    }
+
+    fn vocab_size(&self) -> usize {
+        self.vocabulary.len()
+    }
+
+    fn pad_token(&self) -> usize {
+        0
+    }
+}
+
+/// The tokens of the ids, without the padding tokens.
+fn decode<'a>(vocabulary: &'a Vocabulary, tokens: &'a [usize]) -> impl Iterator<Item = &'a str> {
+    tokens
+        .iter()
+        .filter(|id| **id != 0)
+        .map(|id| vocabulary.token(*id).unwrap_or(Vocabulary::UNK))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitespace_tokenizer_should_encode_the_words_by_frequency() {
+        let tokenizer = WhitespaceTokenizer::from_texts(["the cat", "the dog  sat", "a cat"], 2);
+
+        assert_eq!(
+            tokenizer.vocabulary().tokens(),
+            ["[PAD]", "[UNK]", "cat", "the"]
+        );
+        assert_eq!(tokenizer.encode("the bird\tcat"), vec![3, 1, 2]);
+        assert_eq!(tokenizer.decode(&[3, 2, 0, 0]), "the cat");
+        assert_eq!(tokenizer.vocab_size(), 4);
+    }
+
+    #[test]
+    fn char_tokenizer_should_encode_each_character() {
+        let tokenizer = CharTokenizer::from_texts(["abbé"], 1);
+
+        assert_eq!(tokenizer.vocab_size(), 5);
+        assert_eq!(tokenizer.encode("béa!"), vec![2, 4, 3, 1]);
+        assert_eq!(tokenizer.decode(&[3, 2, 2, 4, 0]), "abbé");
+    }
+}
@@ -0,0 +1,250 @@
+use crate::Dataset;
+use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, Rng, SeedableRng};
+use std::{marker::PhantomData, sync::Mutex};
+
+/// A random transformation of the items, e.g. a data augmentation.
+///
+/// The transformations are applied by the [transform dataset](TransformDataset) when the items
+/// are accessed, so they run in the workers of the data loaders. The random generator is given
+/// to each call, so the transformations are reproducible with a seed.
+pub trait Transform<I>: Send + Sync {
+    /// Transforms the item.
+    fn apply(&self, item: I, rng: &mut StdRng) -> I;
+}
+
+impl<I, F> Transform<I> for F
+where
+    F: Fn(I, &mut StdRng) -> I + Send + Sync,
+{
+    fn apply(&self, item: I, rng: &mut StdRng) -> I {
+        self(item, rng)
+    }
+}
+
+/// Applies a sequence of transformations.
+pub struct Compose<I> {
+    transforms: Vec<Box<dyn Transform<I>>>,
+}
+
+impl<I> Compose<I> {
+    /// Creates an empty sequence of transformations.
+    pub fn new() -> Self {
+        Self {
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Appends a transformation to the sequence.
+    pub fn then<T: Transform<I> + 'static>(mut self, transform: T) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+}
+
+impl<I> Default for Compose<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> Transform<I> for Compose<I> {
+    fn apply(&self, item: I, rng: &mut StdRng) -> I {
+        self.transforms
+            .iter()
+            .fold(item, |item, transform| transform.apply(item, rng))
+    }
+}
+
+/// Applies a transformation with the given probability, otherwise keeps the item.
+#[derive(new)]
+pub struct RandomApply<T> {
+    transform: T,
+    probability: f64,
+}
+
+impl<I, T> Transform<I> for RandomApply<T>
+where
+    T: Transform<I>,
+{
+    fn apply(&self, item: I, rng: &mut StdRng) -> I {
+        match rng.gen_bool(self.probability) {
+            true => self.transform.apply(item, rng),
+            false => item,
+        }
+    }
+}
+
+/// Applies one of the transformations, chosen with a probability proportional to its weight.
+pub struct RandomChoice<I> {
+    transforms: Vec<Box<dyn Transform<I>>>,
+    weights: Vec<f64>,
+}
+
+impl<I> RandomChoice<I> {
+    /// Creates a choice without transformation.
+    pub fn new() -> Self {
+        Self {
+            transforms: Vec::new(),
+            weights: Vec::new(),
+        }
+    }
+
+    /// Adds a transformation chosen with a probability proportional to the given weight.
+    pub fn choice<T: Transform<I> + 'static>(mut self, transform: T, weight: f64) -> Self {
+        self.transforms.push(Box::new(transform));
+        self.weights.push(weight);
+        self
+    }
+}
+
+impl<I> Default for RandomChoice<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> Transform<I> for RandomChoice<I> {
+    fn apply(&self, item: I, rng: &mut StdRng) -> I {
+        if self.transforms.is_empty() {
+            return item;
+        }
+
+        let distribution = WeightedIndex::new(&self.weights)
+            .expect("The weights of the choices should be positive.");
+        self.transforms[distribution.sample(rng)].apply(item, rng)
+    }
+}
+
+/// Dataset applying a random [transformation](Transform) to the items of an inner dataset lazily.
+///
+/// Each access transforms the item again, so each epoch sees different variations of the items.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_dataset::transform::{Compose, RandomApply, TransformDataset};
+/// use burn_dataset::{Dataset, InMemDataset};
+/// use rand::{rngs::StdRng, Rng};
+///
+/// let dataset = InMemDataset::new(vec![1.0, 2.0, 3.0]);
+/// let noise = |item: f64, rng: &mut StdRng| item + rng.gen_range(-0.1..0.1);
+/// let negate = |item: f64, _: &mut StdRng| -item;
+/// let transform = Compose::new()
+///     .then(noise)
+///     .then(RandomApply::new(negate, 0.5));
+///
+/// let dataset = TransformDataset::with_seed(dataset, transform, 42);
+/// assert!(dataset.get(0).unwrap().abs() < 1.1);
+/// ```
+pub struct TransformDataset<D, T, I> {
+    dataset: D,
+    transform: T,
+    rng: Mutex<StdRng>,
+    input: PhantomData<I>,
+}
+
+impl<D, T, I> TransformDataset<D, T, I>
+where
+    D: Dataset<I>,
+    T: Transform<I>,
+{
+    /// Creates a new transform dataset.
+    pub fn new(dataset: D, transform: T, rng: StdRng) -> Self {
+        Self {
+            dataset,
+            transform,
+            rng: Mutex::new(rng),
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new transform dataset with a fixed seed.
+    pub fn with_seed(dataset: D, transform: T, seed: u64) -> Self {
+        Self::new(dataset, transform, StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<D, T, I> Dataset<I> for TransformDataset<D, T, I>
+where
+    D: Dataset<I>,
+    T: Transform<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let item = self.dataset.get(index)?;
+        // Each item gets its own generator, so the workers don't share the lock while transforming.
+        let mut rng = StdRng::seed_from_u64(self.rng.lock().unwrap().gen());
+
+        Some(self.transform.apply(item, &mut rng))
+    }
+
+    fn len(&self) -> usize {
+        self.dataset.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    fn add(value: i32) -> impl Transform<i32> {
+        move |item: i32, _: &mut StdRng| item + value
+    }
+
+    #[test]
+    fn test_compose_applies_the_transforms_in_order() {
+        let transform = Compose::new()
+            .then(add(1))
+            .then(|item: i32, _: &mut StdRng| item * 10);
+
+        assert_eq!(transform.apply(1, &mut StdRng::seed_from_u64(0)), 20);
+    }
+
+    #[test]
+    fn test_random_apply_with_probability() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let always = RandomApply::new(add(1), 1.0);
+        let never = RandomApply::new(add(1), 0.0);
+        let sometimes = RandomApply::new(add(1), 0.5);
+
+        assert_eq!(always.apply(0, &mut rng), 1);
+        assert_eq!(never.apply(0, &mut rng), 0);
+        let applied = (0..1000).map(|_| sometimes.apply(0, &mut rng)).sum::<i32>();
+        assert!((400..600).contains(&applied));
+    }
+
+    #[test]
+    fn test_random_choice_follows_the_weights() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let transform = RandomChoice::new()
+            .choice(add(1), 3.0)
+            .choice(add(100), 0.0)
+            .choice(add(1000), 1.0);
+
+        let items = (0..1000)
+            .map(|_| transform.apply(0, &mut rng))
+            .collect::<Vec<_>>();
+
+        assert!(items.iter().all(|item| *item == 1 || *item == 1000));
+        let thousands = items.iter().filter(|item| **item == 1000).count();
+        assert!((200..300).contains(&thousands));
+    }
+
+    #[test]
+    fn test_transform_dataset_is_reproducible_with_a_seed() {
+        let noise = |item: f64, rng: &mut StdRng| item + rng.gen::<f64>();
+        let dataset =
+            || TransformDataset::with_seed(InMemDataset::new(vec![0.0, 10.0, 20.0]), noise, 42);
+
+        let first = dataset().iter().collect::<Vec<_>>();
+        let second = dataset().iter().collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert!(first
+            .iter()
+            .zip([0.0, 10.0, 20.0])
+            .all(|(item, original)| *item > original && *item < original + 1.0));
+    }
+}
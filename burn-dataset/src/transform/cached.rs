@@ -0,0 +1,132 @@
+use crate::{Dataset, SqliteDataset, SqliteDatasetStorage};
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::OnceLock;
+
+/// Dataset storing the items of an inner dataset in a SQLite database, created with
+/// [cached](Dataset::cached).
+///
+/// The items of the inner dataset, e.g. the output of an expensive
+/// [mapper](crate::transform::MapperDataset), are computed and written to the database of the
+/// storage when the first item is accessed. The next items, of this pass and the later ones, are
+/// read from the database. The database is kept between the runs, so the items are only computed
+/// once, until the database is deleted.
+///
+/// The database doesn't track the transformations of the inner dataset, so a storage with another
+/// name should be used when they change.
+pub struct CachedDataset<D, I> {
+    dataset: D,
+    storage: SqliteDatasetStorage,
+    split: String,
+    cache: OnceLock<SqliteDataset<I>>,
+}
+
+impl<D, I> CachedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    /// Creates a dataset caching the items of the inner dataset in the table of the split of the
+    /// storage.
+    pub fn new(dataset: D, storage: SqliteDatasetStorage, split: &str) -> Self {
+        Self {
+            dataset,
+            storage,
+            split: split.to_string(),
+            cache: OnceLock::new(),
+        }
+    }
+
+    /// The dataset reading the cached items, writing them first when the database doesn't exist.
+    fn cache(&self) -> &SqliteDataset<I> {
+        self.cache.get_or_init(|| {
+            let db_file = self.storage.db_file();
+
+            if !self.storage.exists() {
+                let mut writer = self.storage.writer::<I>(false).unwrap_or_else(|err| {
+                    panic!("Can't create the cache {}: {err}", db_file.display())
+                });
+                writer
+                    .write_dataset(&self.split, &self.dataset)
+                    .and_then(|_| writer.set_completed())
+                    .unwrap_or_else(|err| {
+                        panic!("Can't write the cache {}: {err}", db_file.display())
+                    });
+            }
+
+            self.storage
+                .reader(&self.split)
+                .unwrap_or_else(|err| panic!("Can't read the cache {}: {err}", db_file.display()))
+        })
+    }
+}
+
+impl<D, I> Dataset<I> for CachedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.cache().get(index)
+    }
+
+    fn len(&self) -> usize {
+        // The length of the inner dataset is known without computing its items.
+        match self.cache.get() {
+            Some(cache) => cache.len(),
+            None => self.dataset.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transform::{Mapper, MapperDataset},
+        InMemDataset,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    struct Square {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Mapper<i64, i64> for Square {
+        fn map(&self, item: &i64) -> i64 {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            item * item
+        }
+    }
+
+    #[test]
+    fn cached_dataset_should_compute_the_items_once() {
+        let directory = tempfile::tempdir().unwrap();
+        let storage = SqliteDatasetStorage::from_name("squares").with_base_dir(directory.path());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cached = || {
+            let square = Square {
+                calls: calls.clone(),
+            };
+            MapperDataset::new(InMemDataset::new((0..10).collect::<Vec<i64>>()), square)
+                .cached(storage.clone(), "train")
+        };
+
+        let dataset = cached();
+        assert_eq!(dataset.len(), 10);
+        for _ in 0..2 {
+            let items = dataset.iter().collect::<Vec<_>>();
+            assert_eq!(items, (0..10).map(|item| item * item).collect::<Vec<_>>());
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 10);
+        assert!(storage.exists());
+
+        // A later run reads the items of the database.
+        let dataset = cached();
+        assert_eq!(dataset.get(3), Some(9));
+        assert_eq!(dataset.get(10), None);
+        assert_eq!(calls.load(Ordering::Relaxed), 10);
+    }
+}
@@ -1,11 +1,23 @@
+mod augmentation;
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+mod cached;
 mod composed;
 mod mapper;
 mod partial;
 mod random;
 mod sampler;
+mod shard;
+mod shuffle_buffer;
+mod split;
 
+pub use augmentation::*;
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+pub use cached::*;
 pub use composed::*;
 pub use mapper::*;
 pub use partial::*;
 pub use random::*;
 pub use sampler::*;
+pub use shard::*;
+pub use shuffle_buffer::*;
+pub use split::*;
@@ -125,7 +125,7 @@ mod tests {
             FakeDataset::<String>::new(len_original),
             len_original * factor,
         );
-        let mut buckets = HashMap::new();
+        let mut buckets: HashMap<String, usize> = HashMap::new();
 
         for item in dataset_sampler.iter() {
             let count = match buckets.get(&item) {
@@ -0,0 +1,78 @@
+use crate::Dataset;
+use std::marker::PhantomData;
+
+/// The shard of a dataset loaded by a process of a distributed training, created with
+/// [shard](Dataset::shard).
+///
+/// The shard of rank `r` contains the items at the indices `r`, `r + world_size`,
+/// `r + 2 * world_size`, etc. All the shards have the same length, so the last items are dropped
+/// when the length of the dataset isn't a multiple of the number of shards, which keeps the
+/// processes synchronized.
+pub struct ShardedDataset<D, I> {
+    dataset: D,
+    world_size: usize,
+    rank: usize,
+    input: PhantomData<I>,
+}
+
+impl<D, I> ShardedDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    /// Creates the shard of the given rank among `world_size` shards.
+    ///
+    /// # Panics
+    ///
+    /// When the rank isn't smaller than the number of shards.
+    pub fn new(dataset: D, world_size: usize, rank: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be smaller than the number of shards {world_size}."
+        );
+
+        Self {
+            dataset,
+            world_size,
+            rank,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<D, I> Dataset<I> for ShardedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if index >= self.len() {
+            return None;
+        }
+
+        self.dataset.get(index * self.world_size + self.rank)
+    }
+
+    fn len(&self) -> usize {
+        self.dataset.len() / self.world_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    #[test]
+    fn test_shards_are_disjoint_with_the_same_length() {
+        let shards = (0..3)
+            .map(|rank| {
+                InMemDataset::new((0..10).collect::<Vec<_>>())
+                    .shard(3, rank)
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(shards, vec![vec![0, 3, 6], vec![1, 4, 7], vec![2, 5, 8]]);
+    }
+}
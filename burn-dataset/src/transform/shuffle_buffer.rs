@@ -0,0 +1,122 @@
+use crate::IterableDataset;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{marker::PhantomData, sync::Mutex};
+
+/// Shuffles an [iterable dataset](IterableDataset) with a buffer of items.
+///
+/// The buffer is filled with the first items of the dataset, then each item is sampled from the
+/// buffer and replaced by the next item of the dataset. Only the items close to each other are
+/// shuffled, so the buffer should be larger than the runs of similar items in the dataset. Each
+/// pass over the dataset uses a different order.
+pub struct ShuffleBufferDataset<D, I> {
+    dataset: D,
+    buffer_size: usize,
+    rng: Mutex<StdRng>,
+    input: PhantomData<I>,
+}
+
+impl<D, I> ShuffleBufferDataset<D, I>
+where
+    D: IterableDataset<I>,
+{
+    /// Creates a new shuffled dataset with a buffer of the given size.
+    pub fn new(dataset: D, buffer_size: usize, rng: StdRng) -> Self {
+        Self {
+            dataset,
+            buffer_size: usize::max(buffer_size, 1),
+            rng: Mutex::new(rng),
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new shuffled dataset with a buffer of the given size and a fixed seed.
+    pub fn with_seed(dataset: D, buffer_size: usize, seed: u64) -> Self {
+        Self::new(dataset, buffer_size, StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<D, I> IterableDataset<I> for ShuffleBufferDataset<D, I>
+where
+    D: IterableDataset<I>,
+    I: Send + Sync,
+{
+    fn iter(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        // Each pass gets its own generator, so the iterators don't share the lock.
+        let rng = StdRng::seed_from_u64(self.rng.lock().unwrap().gen());
+
+        Box::new(ShuffleBufferIterator {
+            items: self.dataset.iter(),
+            buffer: Vec::with_capacity(self.buffer_size),
+            buffer_size: self.buffer_size,
+            rng,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.dataset.size_hint()
+    }
+}
+
+struct ShuffleBufferIterator<'a, I> {
+    items: Box<dyn Iterator<Item = I> + 'a>,
+    buffer: Vec<I>,
+    buffer_size: usize,
+    rng: StdRng,
+}
+
+impl<'a, I> Iterator for ShuffleBufferIterator<'a, I> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        while self.buffer.len() < self.buffer_size {
+            match self.items.next() {
+                Some(item) => self.buffer.push(item),
+                None => break,
+            }
+        }
+
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let index = self.rng.gen_range(0..self.buffer.len());
+        Some(self.buffer.swap_remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IterFnDataset;
+
+    #[test]
+    fn test_shuffle_buffer_returns_every_item_once() {
+        let dataset = ShuffleBufferDataset::with_seed(IterFnDataset::new(|| 0..100), 10, 42);
+
+        let mut items = dataset.iter().collect::<Vec<_>>();
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+
+        items.sort();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_buffer_only_moves_items_within_the_buffer() {
+        let dataset = ShuffleBufferDataset::with_seed(IterFnDataset::new(|| 0..100), 10, 42);
+
+        for (position, item) in dataset.iter().enumerate() {
+            // An item is only sampled once it is in the buffer.
+            assert!(item < position + 10);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_buffer_changes_the_order_of_each_pass() {
+        let dataset = ShuffleBufferDataset::with_seed(IterFnDataset::new(|| 0..100), 10, 42);
+
+        let first = dataset.iter().collect::<Vec<_>>();
+        let second = dataset.iter().collect::<Vec<_>>();
+
+        assert_ne!(first, second);
+    }
+}
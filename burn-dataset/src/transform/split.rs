@@ -0,0 +1,171 @@
+use crate::Dataset;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::{collections::HashMap, hash::Hash, marker::PhantomData, sync::Arc};
+
+/// The items of an inner dataset at the given indices, e.g. a split created with
+/// [split](Dataset::split).
+pub struct SubsetDataset<D, I> {
+    dataset: D,
+    indices: Vec<usize>,
+    input: PhantomData<I>,
+}
+
+impl<D, I> SubsetDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    /// Creates the subset of the dataset at the given indices.
+    pub fn new(dataset: D, indices: Vec<usize>) -> Self {
+        Self {
+            dataset,
+            indices,
+            input: PhantomData,
+        }
+    }
+
+    /// The indices of the items in the inner dataset.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl<D, I> Dataset<I> for SubsetDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        self.dataset.get(*self.indices.get(index)?)
+    }
+
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+/// Splits the dataset randomly in subsets whose lengths are proportional to the ratios, created
+/// with [split](Dataset::split).
+pub fn split_dataset<D, I>(dataset: D, ratios: &[f64], seed: u64) -> Vec<SubsetDataset<Arc<D>, I>>
+where
+    D: Dataset<I>,
+{
+    let mut indices = (0..dataset.len()).collect::<Vec<_>>();
+    indices.shuffle(&mut StdRng::seed_from_u64(seed));
+
+    let dataset = Arc::new(dataset);
+    partition(&indices, ratios)
+        .into_iter()
+        .map(|indices| SubsetDataset::new(dataset.clone(), indices.to_vec()))
+        .collect()
+}
+
+/// Splits the dataset randomly in subsets whose lengths are proportional to the ratios, with the
+/// same proportion of each label in every subset, created with
+/// [split_stratified](Dataset::split_stratified).
+pub fn split_dataset_stratified<D, I, L, F>(
+    dataset: D,
+    ratios: &[f64],
+    seed: u64,
+    label: F,
+) -> Vec<SubsetDataset<Arc<D>, I>>
+where
+    D: Dataset<I>,
+    L: Hash + Eq,
+    F: Fn(&I) -> L,
+{
+    // The indices of each label, in the order of their first item.
+    let mut labels = HashMap::new();
+    let mut groups = Vec::<Vec<usize>>::new();
+    for (index, item) in dataset.iter().enumerate() {
+        let group = *labels.entry(label(&item)).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(index);
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut splits = vec![Vec::new(); ratios.len()];
+    for mut group in groups {
+        group.shuffle(&mut rng);
+        for (split, indices) in splits.iter_mut().zip(partition(&group, ratios)) {
+            split.extend_from_slice(indices);
+        }
+    }
+
+    let dataset = Arc::new(dataset);
+    splits
+        .into_iter()
+        .map(|mut indices| {
+            // The labels are interleaved, so the items of a label aren't contiguous.
+            indices.shuffle(&mut rng);
+            SubsetDataset::new(dataset.clone(), indices)
+        })
+        .collect()
+}
+
+/// Partitions the indices in consecutive parts whose lengths are proportional to the ratios.
+fn partition<'a>(indices: &'a [usize], ratios: &[f64]) -> Vec<&'a [usize]> {
+    assert!(
+        !ratios.is_empty() && ratios.iter().all(|ratio| *ratio >= 0.0),
+        "The ratios of the splits should be positive."
+    );
+    let total = ratios.iter().sum::<f64>();
+    assert!(total > 0.0, "The sum of the ratios should be positive.");
+
+    // The bounds are rounded from the cumulative ratios, so the lengths add up to the length.
+    let mut cumulative = 0.0;
+    let mut start = 0;
+    ratios
+        .iter()
+        .map(|ratio| {
+            cumulative += ratio;
+            let end = (indices.len() as f64 * cumulative / total).round() as usize;
+            let part = &indices[start..end.min(indices.len())];
+            start = end.min(indices.len());
+            part
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    #[test]
+    fn split_should_partition_the_items_reproducibly() {
+        let split =
+            || InMemDataset::new((0..100).collect::<Vec<usize>>()).split(&[0.8, 0.1, 0.1], 42);
+
+        let splits = split();
+        let lengths = splits.iter().map(|split| split.len()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![80, 10, 10]);
+
+        let mut items = splits
+            .iter()
+            .flat_map(|split| split.iter())
+            .collect::<Vec<_>>();
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+
+        let other = split();
+        for (split, other) in splits.iter().zip(other.iter()) {
+            assert_eq!(split.indices(), other.indices());
+        }
+    }
+
+    #[test]
+    fn stratified_split_should_keep_the_proportion_of_each_label() {
+        // 90 items below 90 and 10 rare items.
+        let dataset = InMemDataset::new((0..100).collect::<Vec<usize>>());
+        let splits = dataset.split_stratified(&[0.5, 0.5], 42, |item| *item < 90);
+
+        for split in splits.iter() {
+            let items = split.iter().collect::<Vec<_>>();
+            assert_eq!(items.len(), 50);
+            assert_eq!(items.iter().filter(|item| **item >= 90).count(), 5);
+        }
+    }
+}
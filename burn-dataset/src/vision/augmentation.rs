@@ -0,0 +1,159 @@
+use crate::transform::Transform;
+use image::{DynamicImage, GenericImageView, Rgb};
+use rand::{rngs::StdRng, Rng};
+
+/// Crops the images at a random position.
+///
+/// The images must be at least as large as the crop, e.g. [resized](super::Resize) beforehand,
+/// otherwise the crop is clamped to the image.
+#[derive(new, Clone, Debug)]
+pub struct RandomCrop {
+    width: u32,
+    height: u32,
+}
+
+impl Transform<DynamicImage> for RandomCrop {
+    fn apply(&self, image: DynamicImage, rng: &mut StdRng) -> DynamicImage {
+        let (width, height) = image.dimensions();
+        let x = rng.gen_range(0..=width.saturating_sub(self.width));
+        let y = rng.gen_range(0..=height.saturating_sub(self.height));
+
+        image.crop_imm(x, y, self.width, self.height)
+    }
+}
+
+/// Flips the images horizontally, usually applied to half of the images with
+/// [random apply](crate::transform::RandomApply).
+#[derive(Clone, Debug)]
+pub struct HorizontalFlip;
+
+impl Transform<DynamicImage> for HorizontalFlip {
+    fn apply(&self, image: DynamicImage, _rng: &mut StdRng) -> DynamicImage {
+        image.fliph()
+    }
+}
+
+/// Flips the images vertically, usually applied to half of the images with
+/// [random apply](crate::transform::RandomApply).
+#[derive(Clone, Debug)]
+pub struct VerticalFlip;
+
+impl Transform<DynamicImage> for VerticalFlip {
+    fn apply(&self, image: DynamicImage, _rng: &mut StdRng) -> DynamicImage {
+        image.flipv()
+    }
+}
+
+/// Changes the brightness, the contrast and the saturation of the images randomly.
+///
+/// Each property is scaled by a factor sampled uniformly between `1 - variation` and
+/// `1 + variation`, so a variation of zero keeps the property. The images are converted to RGB.
+#[derive(new, Clone, Debug)]
+pub struct ColorJitter {
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+}
+
+impl ColorJitter {
+    fn factor(variation: f32, rng: &mut StdRng) -> f32 {
+        match variation > 0.0 {
+            true => rng.gen_range(f32::max(0.0, 1.0 - variation)..=1.0 + variation),
+            false => 1.0,
+        }
+    }
+}
+
+impl Transform<DynamicImage> for ColorJitter {
+    fn apply(&self, image: DynamicImage, rng: &mut StdRng) -> DynamicImage {
+        let brightness = Self::factor(self.brightness, rng);
+        let contrast = Self::factor(self.contrast, rng);
+        let saturation = Self::factor(self.saturation, rng);
+
+        let gray = |pixel: [f32; 3]| 0.299 * pixel[0] + 0.587 * pixel[1] + 0.114 * pixel[2];
+        let mut image = image.into_rgb8();
+        let num_pixels = (image.width() * image.height()).max(1) as f32;
+        // The contrast is relative to the mean intensity after the brightness change.
+        let mean = image
+            .pixels()
+            .map(|pixel| gray(pixel.0.map(|value| value as f32 * brightness)))
+            .sum::<f32>()
+            / num_pixels;
+
+        for pixel in image.pixels_mut() {
+            let mut values = pixel.0.map(|value| value as f32 * brightness);
+            values = values.map(|value| (value - mean) * contrast + mean);
+            let intensity = gray(values);
+            values = values.map(|value| (value - intensity) * saturation + intensity);
+
+            *pixel = Rgb(values.map(|value| value.round().clamp(0.0, 255.0) as u8));
+        }
+
+        DynamicImage::ImageRgb8(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+    use rand::SeedableRng;
+
+    fn image() -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(4, 2, |x, y| {
+            Rgb([x as u8 * 10, y as u8 * 10, 100])
+        }))
+    }
+
+    #[test]
+    fn test_random_crop_returns_a_region_of_the_image() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let original = image().into_rgb8();
+
+        for _ in 0..10 {
+            let crop = RandomCrop::new(2, 2).apply(image(), &mut rng).into_rgb8();
+
+            assert_eq!(crop.dimensions(), (2, 2));
+            let x = crop.get_pixel(0, 0).0[0] as u32 / 10;
+            assert_eq!(crop.get_pixel(1, 1), original.get_pixel(x + 1, 1));
+        }
+    }
+
+    #[test]
+    fn test_flips() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let horizontal = HorizontalFlip.apply(image(), &mut rng).into_rgb8();
+        let vertical = VerticalFlip.apply(image(), &mut rng).into_rgb8();
+
+        assert_eq!(horizontal.get_pixel(0, 0), &Rgb([30, 0, 100]));
+        assert_eq!(vertical.get_pixel(0, 0), &Rgb([0, 10, 100]));
+    }
+
+    #[test]
+    fn test_color_jitter_without_variation_keeps_the_image() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let jittered = ColorJitter::new(0.0, 0.0, 0.0).apply(image(), &mut rng);
+
+        assert_eq!(jittered, image());
+    }
+
+    #[test]
+    fn test_color_jitter_changes_the_brightness() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let original = image().into_rgb8();
+
+        let jittered = ColorJitter::new(0.5, 0.0, 0.0)
+            .apply(image(), &mut rng)
+            .into_rgb8();
+
+        let ratio = jittered.get_pixel(0, 0).0[2] as f32 / 100.0;
+        assert!((0.5..=1.5).contains(&ratio));
+        for (pixel, original) in jittered.pixels().zip(original.pixels()) {
+            for (value, original) in pixel.0.iter().zip(original.0) {
+                assert!((*value as f32 - original as f32 * ratio).abs() <= 1.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use super::ImagePipeline;
+use crate::Dataset;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The extensions of the image files read by the [image folder dataset](ImageFolderDataset).
+const IMAGE_EXTENSIONS: [&str; 3] = ["jpg", "jpeg", "png"];
+
+/// Error type for [ImageFolderDataset](ImageFolderDataset).
+#[derive(Error, Debug)]
+pub enum ImageFolderError {
+    /// Fail to read the directories.
+    #[error("io error: `{0}`")]
+    Io(#[from] std::io::Error),
+
+    /// No image found in the root directory.
+    #[error("no image found in `{0}`")]
+    Empty(PathBuf),
+}
+
+/// An item of the [image folder dataset](ImageFolderDataset).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageFolderItem {
+    /// The values of the transformed image, to create a tensor of the given shape.
+    pub image: Vec<f32>,
+
+    /// The shape of the image, i.e. `[channels, height, width]`.
+    pub shape: [usize; 3],
+
+    /// The index of the class of the image.
+    pub label: usize,
+}
+
+/// A dataset of images stored in a directory per class, e.g. `root/cat/1.png`.
+///
+/// The classes are the sorted names of the directories, the label of each image being the index
+/// of its class. The JPEG and PNG images of each directory and its subdirectories are decoded
+/// when they are accessed, then converted by an [image pipeline](ImagePipeline).
+///
+/// # Example
+///
+/// ```no_run
+/// use burn_dataset::vision::{ImageFolderDataset, ImagePipeline};
+/// use burn_dataset::Dataset;
+///
+/// let dataset = ImageFolderDataset::new("data/train")
+///     .unwrap()
+///     .with_pipeline(ImagePipeline::new().resize(32, 32));
+/// let item = dataset.get(0).unwrap();
+///
+/// println!("{} {:?}", dataset.classes()[item.label], item.shape);
+/// ```
+pub struct ImageFolderDataset {
+    images: Vec<(PathBuf, usize)>,
+    classes: Vec<String>,
+    pipeline: ImagePipeline,
+}
+
+impl ImageFolderDataset {
+    /// Creates a dataset with the images of the class directories of the root directory.
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, ImageFolderError> {
+        let root = root.as_ref();
+        let mut directories = Vec::new();
+
+        for entry in std::fs::read_dir(root)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            }
+        }
+        directories.sort();
+
+        let mut images = Vec::new();
+        let mut classes = Vec::with_capacity(directories.len());
+
+        for (label, directory) in directories.into_iter().enumerate() {
+            let mut paths = Vec::new();
+            find_images(&directory, &mut paths)?;
+            paths.sort();
+
+            let class = directory.file_name().unwrap().to_string_lossy();
+            classes.push(class.into_owned());
+            images.extend(paths.into_iter().map(|path| (path, label)));
+        }
+
+        if images.is_empty() {
+            return Err(ImageFolderError::Empty(root.to_path_buf()));
+        }
+
+        Ok(Self {
+            images,
+            classes,
+            pipeline: ImagePipeline::new(),
+        })
+    }
+
+    /// Sets the pipeline converting the images.
+    pub fn with_pipeline(mut self, pipeline: ImagePipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// The names of the classes, in the order of their labels.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+}
+
+impl Dataset<ImageFolderItem> for ImageFolderDataset {
+    fn get(&self, index: usize) -> Option<ImageFolderItem> {
+        let (path, label) = self.images.get(index)?;
+        let image = image::open(path)
+            .unwrap_or_else(|err| panic!("Can't decode the image {}: {err}", path.display()));
+        let (image, shape) = self.pipeline.apply(image);
+
+        Some(ImageFolderItem {
+            image,
+            shape,
+            label: *label,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.images.len()
+    }
+}
+
+/// Adds the image files of the directory and its subdirectories to the paths.
+fn find_images(directory: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            find_images(&path, paths)?;
+        } else if let Some(extension) = path.extension() {
+            let extension = extension.to_string_lossy().to_lowercase();
+            if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+                paths.push(path);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    #[test]
+    fn test_image_folder_dataset() {
+        let root = tempfile::tempdir().unwrap();
+        let save = |path: &str, color: [u8; 3]| {
+            let path = root.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            RgbImage::from_pixel(4, 4, Rgb(color)).save(path).unwrap();
+        };
+        save("dog/1.png", [0, 255, 0]);
+        save("cat/2.png", [255, 0, 0]);
+        save("cat/nested/1.JPG", [255, 0, 0]);
+        std::fs::write(root.path().join("cat/labels.txt"), "not an image").unwrap();
+
+        let dataset = ImageFolderDataset::new(root.path())
+            .unwrap()
+            .with_pipeline(ImagePipeline::new().resize(2, 2).grayscale());
+
+        assert_eq!(dataset.classes(), ["cat", "dog"]);
+        assert_eq!(dataset.len(), 3);
+        let labels = dataset.iter().map(|item| item.label).collect::<Vec<_>>();
+        assert_eq!(labels, vec![0, 0, 1]);
+
+        let item = dataset.get(2).unwrap();
+        assert_eq!(item.shape, [1, 2, 2]);
+        assert_eq!(item.image, vec![item.image[0]; 4]);
+    }
+
+    #[test]
+    fn test_image_folder_without_images() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("cat")).unwrap();
+
+        let result = ImageFolderDataset::new(root.path());
+
+        assert!(matches!(result, Err(ImageFolderError::Empty(_))));
+    }
+}
@@ -0,0 +1,7 @@
+mod augmentation;
+mod image_folder;
+mod transform;
+
+pub use augmentation::*;
+pub use image_folder::*;
+pub use transform::*;
@@ -0,0 +1,257 @@
+use super::{ColorJitter, HorizontalFlip, RandomCrop};
+use crate::transform::{RandomApply, Transform};
+use image::{imageops::FilterType, DynamicImage, GenericImageView};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::sync::Mutex;
+
+/// Resizes the images.
+#[derive(Clone, Debug)]
+pub struct Resize {
+    width: u32,
+    height: u32,
+    keep_aspect_ratio: bool,
+}
+
+impl Resize {
+    /// Resizes the images to the given size, ignoring their aspect ratio.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            keep_aspect_ratio: false,
+        }
+    }
+
+    /// Resizes the images so their shorter side has the given size, keeping their aspect ratio.
+    pub fn shorter_side(size: u32) -> Self {
+        Self {
+            width: size,
+            height: size,
+            keep_aspect_ratio: true,
+        }
+    }
+}
+
+impl Transform<DynamicImage> for Resize {
+    fn apply(&self, image: DynamicImage, _rng: &mut StdRng) -> DynamicImage {
+        if !self.keep_aspect_ratio {
+            return image.resize_exact(self.width, self.height, FilterType::Triangle);
+        }
+
+        let (width, height) = image.dimensions();
+        let scale = self.width as f64 / u32::min(width, height) as f64;
+        let width = (width as f64 * scale).round() as u32;
+        let height = (height as f64 * scale).round() as u32;
+
+        image.resize_exact(width, height, FilterType::Triangle)
+    }
+}
+
+/// Crops the center of the images.
+///
+/// The images must be at least as large as the crop, e.g. [resized](Resize) beforehand,
+/// otherwise the crop is clamped to the image.
+#[derive(new, Clone, Debug)]
+pub struct CenterCrop {
+    width: u32,
+    height: u32,
+}
+
+impl Transform<DynamicImage> for CenterCrop {
+    fn apply(&self, image: DynamicImage, _rng: &mut StdRng) -> DynamicImage {
+        let (width, height) = image.dimensions();
+        let x = width.saturating_sub(self.width) / 2;
+        let y = height.saturating_sub(self.height) / 2;
+
+        image.crop_imm(x, y, self.width, self.height)
+    }
+}
+
+/// Converts the decoded images to the values of a tensor of shape `[channels, height, width]`,
+/// after applying a sequence of [transformations](Transform).
+///
+/// The pixels are scaled between 0 and 1, then normalized when a mean and a standard deviation
+/// are given for each channel. The random transformations, i.e. the data augmentations, use a
+/// different generator for each image, which is derived from the [seed](ImagePipeline::seed) of
+/// the pipeline.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_dataset::vision::ImagePipeline;
+///
+/// // The usual pipeline of the models pretrained on ImageNet.
+/// let pipeline = ImagePipeline::new()
+///     .resize_shorter_side(256)
+///     .center_crop(224, 224)
+///     .normalize([0.485, 0.456, 0.406], [0.229, 0.224, 0.225]);
+///
+/// // The same pipeline, with the usual data augmentations for the training.
+/// let pipeline = ImagePipeline::new()
+///     .resize_shorter_side(256)
+///     .random_crop(224, 224)
+///     .random_horizontal_flip()
+///     .color_jitter(0.4, 0.4, 0.4)
+///     .normalize([0.485, 0.456, 0.406], [0.229, 0.224, 0.225]);
+/// ```
+pub struct ImagePipeline {
+    transforms: Vec<Box<dyn Transform<DynamicImage>>>,
+    grayscale: bool,
+    normalization: Option<(Vec<f32>, Vec<f32>)>,
+    rng: Mutex<StdRng>,
+}
+
+impl Default for ImagePipeline {
+    fn default() -> Self {
+        Self {
+            transforms: Vec::new(),
+            grayscale: false,
+            normalization: None,
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+}
+
+impl ImagePipeline {
+    /// Creates a pipeline converting the RGB images without transformation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a transformation to the pipeline.
+    pub fn transform<T: Transform<DynamicImage> + 'static>(mut self, transform: T) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+
+    /// Sets the seed of the random transformations, which are different for each run otherwise.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Appends a [resize](Resize::new) to the given size, ignoring the aspect ratio.
+    pub fn resize(self, width: u32, height: u32) -> Self {
+        self.transform(Resize::new(width, height))
+    }
+
+    /// Appends a [resize](Resize::shorter_side) of the shorter side, keeping the aspect ratio.
+    pub fn resize_shorter_side(self, size: u32) -> Self {
+        self.transform(Resize::shorter_side(size))
+    }
+
+    /// Appends a [center crop](CenterCrop) of the given size.
+    pub fn center_crop(self, width: u32, height: u32) -> Self {
+        self.transform(CenterCrop::new(width, height))
+    }
+
+    /// Appends a [random crop](RandomCrop) of the given size.
+    pub fn random_crop(self, width: u32, height: u32) -> Self {
+        self.transform(RandomCrop::new(width, height))
+    }
+
+    /// Appends a [horizontal flip](HorizontalFlip) of half of the images.
+    pub fn random_horizontal_flip(self) -> Self {
+        self.transform(RandomApply::new(HorizontalFlip, 0.5))
+    }
+
+    /// Appends a [color jitter](ColorJitter) with the given maximum variations.
+    pub fn color_jitter(self, brightness: f32, contrast: f32, saturation: f32) -> Self {
+        self.transform(ColorJitter::new(brightness, contrast, saturation))
+    }
+
+    /// Converts the images to a single grayscale channel instead of three RGB channels.
+    pub fn grayscale(mut self) -> Self {
+        self.grayscale = true;
+        self
+    }
+
+    /// Normalizes each channel with the given mean and standard deviation.
+    pub fn normalize<const C: usize>(mut self, mean: [f32; C], std: [f32; C]) -> Self {
+        self.normalization = Some((mean.to_vec(), std.to_vec()));
+        self
+    }
+
+    /// Transforms the image and returns its values with their shape `[channels, height, width]`.
+    ///
+    /// # Panics
+    ///
+    /// When the normalization doesn't have a value per channel.
+    pub fn apply(&self, image: DynamicImage) -> (Vec<f32>, [usize; 3]) {
+        let mut rng = StdRng::seed_from_u64(self.rng.lock().unwrap().gen());
+        let image = self
+            .transforms
+            .iter()
+            .fold(image, |image, transform| transform.apply(image, &mut rng));
+
+        let (width, height) = image.dimensions();
+        let (channels, pixels) = match self.grayscale {
+            true => (1, image.into_luma8().into_raw()),
+            false => (3, image.into_rgb8().into_raw()),
+        };
+        let size = width as usize * height as usize;
+
+        // The pixels are interleaved, the tensor has a plane per channel.
+        let mut data = vec![0.0; channels * size];
+        for (index, pixel) in pixels.into_iter().enumerate() {
+            data[(index % channels) * size + index / channels] = pixel as f32 / 255.0;
+        }
+
+        if let Some((mean, std)) = &self.normalization {
+            assert_eq!(
+                mean.len(),
+                channels,
+                "The normalization should have a mean and a standard deviation per channel."
+            );
+            for (channel, values) in data.chunks_mut(size).enumerate() {
+                values
+                    .iter_mut()
+                    .for_each(|value| *value = (*value - mean[channel]) / std[channel]);
+            }
+        }
+
+        (data, [channels, height as usize, width as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    fn image() -> DynamicImage {
+        // A 4x2 image, the left half red and the right half blue.
+        DynamicImage::ImageRgb8(RgbImage::from_fn(4, 2, |x, _| match x < 2 {
+            true => Rgb([255, 0, 0]),
+            false => Rgb([0, 0, 255]),
+        }))
+    }
+
+    #[test]
+    fn test_pipeline_returns_channels_first_values() {
+        let (data, shape) = ImagePipeline::new().apply(image());
+
+        assert_eq!(shape, [3, 2, 4]);
+        let red = [1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0];
+        let blue = [0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0];
+        assert_eq!(data, [red, [0.0; 8], blue].concat());
+    }
+
+    #[test]
+    fn test_pipeline_crops_and_normalizes() {
+        let (data, shape) = ImagePipeline::new()
+            .center_crop(2, 1)
+            .normalize([0.5, 0.0, 0.5], [0.5, 1.0, 0.25])
+            .apply(image());
+
+        assert_eq!(shape, [3, 1, 2]);
+        assert_eq!(data, vec![1.0, -1.0, 0.0, 0.0, -2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_resize_shorter_side_keeps_the_aspect_ratio() {
+        let image = Resize::shorter_side(4).apply(image(), &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(image.dimensions(), (8, 4));
+    }
+}
@@ -27,8 +27,11 @@ impl ModuleCodegen for StructModuleCodegen {
 
     fn gen_visit(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
+            let name_str = name.to_string();
             quote! {
+                visitor.enter_module(#name_str);
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(#name_str);
             }
         });
 
@@ -93,8 +96,11 @@ impl ModuleCodegen for StructModuleCodegen {
 
     fn gen_map(&self) -> TokenStream {
         let (names, body) = self.gen_fields_fn_names(|name| {
+            let name_str = name.to_string();
             quote! {
+                mapper.enter_module(#name_str);
                 let #name = burn::module::Module::<B>::map(self.#name, mapper);
+                mapper.exit_module(#name_str);
             }
         });
 
@@ -0,0 +1,70 @@
+//! Start the processes of a distributed training on the current node.
+//!
+//! ```sh
+//! burn-launch --nproc-per-node 2 --nnodes 2 --node-rank 0 --master-addr 10.0.0.1:29500 -- ./train
+//! ```
+
+use burn_distributed::Launcher;
+
+const USAGE: &str = "Usage: burn-launch [--nproc-per-node N] [--nnodes N] [--node-rank R] \
+                     [--master-addr HOST:PORT] -- <program> [args...]";
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let (launcher, program, args) = match parse(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(err) = launcher.launch(&program, &args) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn parse(args: &[String]) -> Result<(Launcher, String, Vec<String>), String> {
+    let separator = args
+        .iter()
+        .position(|arg| arg == "--")
+        .ok_or("The program to launch is missing.")?;
+    let (options, command) = (&args[..separator], &args[separator + 1..]);
+    let (program, program_args) = command
+        .split_first()
+        .ok_or("The program to launch is missing.")?;
+
+    let mut nproc_per_node = 1;
+    let mut nnodes = 1;
+    let mut node_rank = 0;
+    let mut master_addr = None;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let value = options
+            .next()
+            .ok_or(format!("The option {option} requires a value."))?;
+        let number = || {
+            value
+                .parse::<usize>()
+                .map_err(|err| format!("Invalid value {value} for {option}: {err}"))
+        };
+
+        match option.as_str() {
+            "--nproc-per-node" => nproc_per_node = number()?,
+            "--nnodes" => nnodes = number()?,
+            "--node-rank" => node_rank = number()?,
+            "--master-addr" => master_addr = Some(value.clone()),
+            _ => return Err(format!("Unknown option {option}.")),
+        }
+    }
+
+    let mut launcher = Launcher::new(nproc_per_node).with_nodes(nnodes, node_rank);
+    if let Some(master_addr) = master_addr {
+        launcher = launcher.with_master_addr(&master_addr);
+    }
+
+    Ok((launcher, program.clone(), program_args.to_vec()))
+}
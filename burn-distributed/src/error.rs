@@ -0,0 +1,40 @@
+use core::fmt::Display;
+
+/// The error type of the distributed training.
+#[derive(Debug)]
+pub enum DistributedError {
+    /// IO error, e.g. a connection lost with another process.
+    IOError(std::io::Error),
+
+    /// The processes can't agree on the group, e.g. a missing process or a duplicated rank.
+    Rendezvous(String),
+
+    /// The processes called different collectives, or with buffers of different sizes.
+    Collective(String),
+
+    /// The configuration is invalid or missing from the environment.
+    Config(String),
+
+    /// A process started by the launcher failed.
+    Process(String),
+}
+
+impl Display for DistributedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IOError(err) => write!(f, "IO error: {err}"),
+            Self::Rendezvous(message) => write!(f, "Rendezvous error: {message}"),
+            Self::Collective(message) => write!(f, "Collective error: {message}"),
+            Self::Config(message) => write!(f, "Config error: {message}"),
+            Self::Process(message) => write!(f, "Process error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DistributedError {}
+
+impl From<std::io::Error> for DistributedError {
+    fn from(err: std::io::Error) -> Self {
+        Self::IOError(err)
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{DistributedError, ProcessGroup, ReduceOp};
+use burn_core::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Data, Tensor};
+use std::marker::PhantomData;
+
+/// Average the gradients of the module over all the processes of the group.
+///
+/// The gradients are concatenated in a single buffer, so that the processes only exchange one
+/// message per neighbor and step of the ring. A parameter without gradient on a process, e.g. not
+/// used in its batch, contributes zeros to the average.
+pub fn all_reduce_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+    group: &ProcessGroup,
+    module: &M,
+    mut grads: GradientsParams,
+) -> Result<GradientsParams, DistributedError> {
+    if group.world_size() == 1 {
+        return Ok(grads);
+    }
+
+    let mut flattener = GradsFlattener::<B>::new(&mut grads);
+    module.visit(&mut flattener);
+    let mut values = flattener.values;
+
+    group.all_reduce(&mut values, ReduceOp::Mean)?;
+
+    let mut unflattener = GradsUnflattener::<B>::new(&mut grads, &values);
+    module.visit(&mut unflattener);
+
+    Ok(grads)
+}
+
+/// Replace the parameters of the module by those of the process of rank 0, so that all the
+/// processes start the training with the same weights.
+pub fn sync_module<B: Backend, M: Module<B>>(
+    group: &ProcessGroup,
+    module: M,
+) -> Result<M, DistributedError> {
+    if group.world_size() == 1 {
+        return Ok(module);
+    }
+
+    let mut mapper = ParamsBroadcaster { group, error: None };
+    let module = module.map(&mut mapper);
+
+    match mapper.error {
+        Some(err) => Err(err),
+        None => Ok(module),
+    }
+}
+
+#[derive(new)]
+struct GradsFlattener<'a, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    #[new(default)]
+    values: Vec<f32>,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradsFlattener<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        match self.grads.remove::<B::InnerBackend, D>(id) {
+            Some(grad) => self.values.extend(grad.into_data().convert::<f32>().value),
+            None => {
+                let len = self.values.len() + tensor.shape().num_elements();
+                self.values.resize(len, 0.0);
+            }
+        }
+    }
+}
+
+#[derive(new)]
+struct GradsUnflattener<'a, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    values: &'a [f32],
+    #[new(default)]
+    offset: usize,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradsUnflattener<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.shape();
+        let end = self.offset + shape.num_elements();
+        let data = Data::new(self.values[self.offset..end].to_vec(), shape);
+        self.offset = end;
+
+        let data = data.convert::<<B::InnerBackend as Backend>::FloatElem>();
+        let grad = Tensor::<B::InnerBackend, D>::from_data_device(data, &tensor.device());
+        self.grads.register(id.clone(), grad);
+    }
+}
+
+struct ParamsBroadcaster<'a> {
+    group: &'a ProcessGroup,
+    error: Option<DistributedError>,
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamsBroadcaster<'_> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if self.error.is_some() {
+            return tensor;
+        }
+
+        let mut data = tensor.to_data().convert::<f32>();
+        if let Err(err) = self.group.broadcast(&mut data.value, 0) {
+            self.error = Some(err);
+            return tensor;
+        }
+
+        let param = Tensor::from_data_device(data.convert::<B::FloatElem>(), &tensor.device());
+        match tensor.is_require_grad() {
+            true => param.require_grad(),
+            false => param,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group::tests::run_group;
+    use burn_core::module::list_param_ids;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::Distribution;
+
+    type TestBackend = burn_autodiff::Autodiff<burn_ndarray::NdArray<f32>>;
+
+    #[test]
+    fn test_grads_averaged_and_params_synced_across_processes() {
+        let results = run_group(2, |group| {
+            let rank = group.rank() as f32;
+            let linear: Linear<TestBackend> = LinearConfig::new(2, 1).init();
+            let linear = sync_module(&group, linear).unwrap();
+
+            // The gradient of the sum of the outputs with respect to the weights is the input,
+            // different on each process.
+            let input = Tensor::from_floats([[rank + 1.0, 2.0 * rank]]);
+            let grads = linear.forward(input).sum().backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            let grads = all_reduce_grads(&group, &linear, grads).unwrap();
+
+            let weight_id = &list_param_ids(&linear)[0];
+            let grad = grads
+                .get::<burn_ndarray::NdArray<f32>, 2>(weight_id)
+                .unwrap();
+            (linear.weight.to_data(), grad.to_data())
+        });
+
+        assert_eq!(results[0].0, results[1].0);
+        for (_, grad) in results {
+            grad.assert_approx_eq(&Data::from([[1.5], [1.0]]), 5);
+        }
+    }
+
+    #[test]
+    fn test_missing_grads_contribute_zeros() {
+        let linear: Linear<TestBackend> = LinearConfig::new(2, 2).init();
+        let input = Tensor::<TestBackend, 2>::random([1, 2], Distribution::Default);
+        let mut grads =
+            GradientsParams::from_grads(linear.forward(input).sum().backward(), &linear);
+        let bias_id = &list_param_ids(&linear)[1];
+        grads.remove::<burn_ndarray::NdArray<f32>, 1>(bias_id);
+
+        let mut flattener = GradsFlattener::<TestBackend>::new(&mut grads);
+        linear.visit(&mut flattener);
+
+        assert_eq!(flattener.values.len(), 6);
+        assert_eq!(&flattener.values[4..], &[0.0, 0.0]);
+    }
+}
@@ -0,0 +1,469 @@
+use crate::ring::{ReduceOp, Ring};
+use crate::DistributedError;
+use burn_core as burn;
+use burn_core::config::Config;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The environment variable holding the rank of the process, set by the launcher.
+pub const ENV_RANK: &str = "BURN_RANK";
+/// The environment variable holding the rank of the process on its node, set by the launcher.
+pub const ENV_LOCAL_RANK: &str = "BURN_LOCAL_RANK";
+/// The environment variable holding the number of processes, set by the launcher.
+pub const ENV_WORLD_SIZE: &str = "BURN_WORLD_SIZE";
+/// The environment variable holding the address of the first process, set by the launcher.
+pub const ENV_MASTER_ADDR: &str = "BURN_MASTER_ADDR";
+
+/// Configuration to create a [process group](ProcessGroup).
+#[derive(Config)]
+pub struct ProcessGroupConfig {
+    /// The rank of the process, from 0 to the world size excluded.
+    pub rank: usize,
+    /// The number of processes.
+    pub world_size: usize,
+    /// The address, e.g. `10.0.0.1:29500`, on which the process of rank 0 waits for the others.
+    ///
+    /// The process of rank 0 may listen on all its interfaces with an unspecified address, e.g.
+    /// `0.0.0.0:29500`, the others being given an address they can reach.
+    pub master_addr: String,
+    /// The number of seconds to wait for the other processes to join the group.
+    #[config(default = 300)]
+    pub timeout_secs: u64,
+}
+
+impl ProcessGroupConfig {
+    /// Read the configuration from the environment variables set by the
+    /// [launcher](crate::Launcher).
+    pub fn from_env() -> Result<Self, DistributedError> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| {
+                DistributedError::Config(format!("The environment variable {name} is not set."))
+            })
+        };
+        let parse = |name: &str| {
+            var(name)?.parse::<usize>().map_err(|err| {
+                DistributedError::Config(format!(
+                    "The environment variable {name} is invalid: {err}"
+                ))
+            })
+        };
+
+        Ok(Self::new(
+            parse(ENV_RANK)?,
+            parse(ENV_WORLD_SIZE)?,
+            var(ENV_MASTER_ADDR)?,
+        ))
+    }
+
+    /// Join the group, waiting until all the processes have joined.
+    ///
+    /// The process of rank 0 listens on the master address, where the other processes send the
+    /// address of their own listener. Once all the addresses are known, each process connects to
+    /// the next rank to form a ring.
+    pub fn init(&self) -> Result<ProcessGroup, DistributedError> {
+        self.join(None)
+    }
+
+    /// Join the group as the process of rank 0, waiting for the other processes on a listener
+    /// already bound to the master address, e.g. to a port chosen by the system.
+    pub fn init_with_listener(
+        &self,
+        listener: TcpListener,
+    ) -> Result<ProcessGroup, DistributedError> {
+        if self.rank != 0 {
+            return Err(DistributedError::Config(format!(
+                "Only the process of rank 0 listens on the master address, not {}.",
+                self.rank
+            )));
+        }
+
+        self.join(Some(listener))
+    }
+
+    fn join(&self, listener: Option<TcpListener>) -> Result<ProcessGroup, DistributedError> {
+        if self.rank >= self.world_size {
+            return Err(DistributedError::Config(format!(
+                "The rank {} should be lower than the world size {}.",
+                self.rank, self.world_size
+            )));
+        }
+
+        if self.world_size == 1 {
+            return Ok(ProcessGroup {
+                rank: 0,
+                world_size: 1,
+                ring: None,
+            });
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(self.timeout_secs);
+        let (next, prev) = match self.rank {
+            0 => self.rendezvous_master(listener, deadline)?,
+            _ => self.rendezvous_worker(deadline)?,
+        };
+        log::info!(
+            "Process {} joined the group of {} processes",
+            self.rank,
+            self.world_size
+        );
+
+        Ok(ProcessGroup {
+            rank: self.rank,
+            world_size: self.world_size,
+            ring: Some(Mutex::new(Ring::new(
+                self.rank,
+                self.world_size,
+                next,
+                prev,
+            ))),
+        })
+    }
+
+    fn rendezvous_master(
+        &self,
+        listener: Option<TcpListener>,
+        deadline: Instant,
+    ) -> Result<(TcpStream, TcpStream), DistributedError> {
+        let listener = match listener {
+            Some(listener) => listener,
+            None => TcpListener::bind(&self.master_addr)?,
+        };
+        let mut workers: Vec<Option<(TcpStream, String)>> =
+            (0..self.world_size).map(|_| None).collect();
+
+        for _ in 1..self.world_size {
+            let (mut stream, addr) = accept(&listener, deadline)?;
+            let rank = read_u32(&mut stream)? as usize;
+            let port = read_u32(&mut stream)? as u16;
+
+            match workers.get(rank) {
+                Some(None) if rank != 0 => {
+                    let addr = SocketAddr::new(addr.ip(), port).to_string();
+                    workers[rank] = Some((stream, addr));
+                }
+                _ => {
+                    return Err(DistributedError::Rendezvous(format!(
+                        "The rank {rank} is invalid or already taken by another process."
+                    )))
+                }
+            }
+        }
+
+        let addresses = workers
+            .iter()
+            .flatten()
+            .map(|(_, addr)| addr.clone())
+            .collect::<Vec<_>>();
+
+        for (stream, _) in workers.iter_mut().flatten() {
+            // The master is advertised with the address the worker reached it on, since the
+            // master address may be unspecified, e.g. `0.0.0.0:29500`.
+            let master_addr = stream.local_addr()?.to_string();
+
+            write_u32(stream, self.world_size as u32)?;
+            for addr in core::iter::once(&master_addr).chain(addresses.iter()) {
+                write_u32(stream, addr.len() as u32)?;
+                stream.write_all(addr.as_bytes())?;
+            }
+        }
+
+        let next = connect(&addresses[0], self.rank, deadline)?;
+        let prev = accept_prev(&listener, self.world_size - 1, deadline)?;
+
+        Ok((next, prev))
+    }
+
+    fn rendezvous_worker(
+        &self,
+        deadline: Instant,
+    ) -> Result<(TcpStream, TcpStream), DistributedError> {
+        let mut master = connect_retry(&self.master_addr, deadline)?;
+        // Listen on the interface used to reach the master, likely reachable by the others.
+        let listener = TcpListener::bind(SocketAddr::new(master.local_addr()?.ip(), 0))?;
+
+        write_u32(&mut master, self.rank as u32)?;
+        write_u32(&mut master, listener.local_addr()?.port() as u32)?;
+
+        let num_addresses = read_u32(&mut master)? as usize;
+        if num_addresses != self.world_size {
+            return Err(DistributedError::Rendezvous(format!(
+                "The world size {} differs from the world size {num_addresses} of the master.",
+                self.world_size
+            )));
+        }
+
+        let mut addresses = Vec::with_capacity(num_addresses);
+        for rank in 0..num_addresses {
+            let mut addr = vec![0; read_u32(&mut master)? as usize];
+            master.read_exact(&mut addr)?;
+            let addr = String::from_utf8_lossy(&addr).to_string();
+
+            if matches!(addr.parse::<SocketAddr>(), Ok(addr) if addr.ip().is_unspecified()) {
+                return Err(DistributedError::Rendezvous(format!(
+                    "The address {addr} of the process {rank} isn't reachable."
+                )));
+            }
+            addresses.push(addr);
+        }
+
+        let next = connect(
+            &addresses[(self.rank + 1) % self.world_size],
+            self.rank,
+            deadline,
+        )?;
+        let prev = accept_prev(&listener, self.rank - 1, deadline)?;
+
+        Ok((next, prev))
+    }
+}
+
+/// A group of processes, possibly on different machines, training the same model together.
+///
+/// All the processes must call the same collectives in the same order with buffers of the same
+/// size, otherwise they wait for each other forever or fail.
+pub struct ProcessGroup {
+    rank: usize,
+    world_size: usize,
+    ring: Option<Mutex<Ring>>,
+}
+
+impl ProcessGroup {
+    /// The rank of the process, from 0 to the world size excluded.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The number of processes.
+    pub fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    /// If the process has the rank 0, e.g. to only save the checkpoints and log the metrics once.
+    pub fn is_main(&self) -> bool {
+        self.rank == 0
+    }
+
+    /// Combine the values of all the processes, each process ending with the same result.
+    pub fn all_reduce(&self, values: &mut [f32], op: ReduceOp) -> Result<(), DistributedError> {
+        match &self.ring {
+            Some(ring) => ring
+                .lock()
+                .expect("The ring lock should not be poisoned.")
+                .all_reduce(values, op),
+            None => Ok(()),
+        }
+    }
+
+    /// Replace the values of all the processes by those of the root process.
+    pub fn broadcast(&self, values: &mut [f32], root: usize) -> Result<(), DistributedError> {
+        match &self.ring {
+            Some(ring) => ring
+                .lock()
+                .expect("The ring lock should not be poisoned.")
+                .broadcast(values, root),
+            None => Ok(()),
+        }
+    }
+
+    /// Wait until all the processes reach the barrier.
+    pub fn barrier(&self) -> Result<(), DistributedError> {
+        self.all_reduce(&mut [0.0], ReduceOp::Sum)
+    }
+}
+
+fn accept(
+    listener: &TcpListener,
+    deadline: Instant,
+) -> Result<(TcpStream, SocketAddr), DistributedError> {
+    listener.set_nonblocking(true)?;
+
+    loop {
+        match listener.accept() {
+            Ok((stream, addr)) => {
+                stream.set_nonblocking(false)?;
+                return Ok((stream, addr));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                if Instant::now() > deadline {
+                    return Err(DistributedError::Rendezvous(
+                        "Timeout while waiting for the other processes to join.".to_string(),
+                    ));
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn accept_prev(
+    listener: &TcpListener,
+    rank_prev: usize,
+    deadline: Instant,
+) -> Result<TcpStream, DistributedError> {
+    let (mut stream, _) = accept(listener, deadline)?;
+    let rank = read_u32(&mut stream)? as usize;
+
+    if rank != rank_prev {
+        return Err(DistributedError::Rendezvous(format!(
+            "Expected a connection from the process {rank_prev}, got one from {rank}."
+        )));
+    }
+
+    Ok(stream)
+}
+
+fn connect(addr: &str, rank: usize, deadline: Instant) -> Result<TcpStream, DistributedError> {
+    let mut stream = connect_retry(addr, deadline)?;
+    write_u32(&mut stream, rank as u32)?;
+
+    Ok(stream)
+}
+
+/// Connect to an address, retrying since the other process may not be listening yet.
+fn connect_retry(addr: &str, deadline: Instant) -> Result<TcpStream, DistributedError> {
+    loop {
+        match TcpStream::connect(addr) {
+            Ok(stream) => return Ok(stream),
+            Err(err) if Instant::now() > deadline => {
+                return Err(DistributedError::Rendezvous(format!(
+                    "Timeout while connecting to {addr}: {err}"
+                )))
+            }
+            Err(_) => std::thread::sleep(Duration::from_millis(100)),
+        }
+    }
+}
+
+fn write_u32(stream: &mut TcpStream, value: u32) -> Result<(), DistributedError> {
+    stream.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+fn read_u32(stream: &mut TcpStream) -> Result<u32, DistributedError> {
+    let mut bytes = [0; 4];
+    stream.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Run the function in a group of threads, the master listening on a port chosen by the
+    /// system so that the tests can run concurrently.
+    pub(crate) fn run_group<F, R>(world_size: usize, func: F) -> Vec<R>
+    where
+        F: Fn(ProcessGroup) -> R + Send + Sync + Copy + 'static,
+        R: Send + 'static,
+    {
+        run_group_bound("127.0.0.1:0", world_size, func)
+    }
+
+    /// Run the function in a group of threads, the master listening on the given address and the
+    /// workers connecting to it through the loopback interface.
+    fn run_group_bound<F, R>(bind_addr: &str, world_size: usize, func: F) -> Vec<R>
+    where
+        F: Fn(ProcessGroup) -> R + Send + Sync + Copy + 'static,
+        R: Send + 'static,
+    {
+        let listener = TcpListener::bind(bind_addr).unwrap();
+        let listener_addr = listener.local_addr().unwrap();
+        let worker_master_addr = format!("127.0.0.1:{}", listener_addr.port());
+        let mut listener = Some(listener);
+
+        let handles = (0..world_size)
+            .map(|rank| {
+                let master_addr = match rank {
+                    0 => listener_addr.to_string(),
+                    _ => worker_master_addr.clone(),
+                };
+                let config =
+                    ProcessGroupConfig::new(rank, world_size, master_addr).with_timeout_secs(10);
+                let listener = listener.take();
+
+                std::thread::spawn(move || {
+                    let group = match listener {
+                        Some(listener) => config.init_with_listener(listener),
+                        None => config.init(),
+                    };
+                    func(group.unwrap())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_all_reduce_with_more_values_than_processes() {
+        let results = run_group(3, |group| {
+            let rank = group.rank() as f32;
+            let mut values = vec![rank, 2.0 * rank, 1.0, 0.0, -rank];
+            group.all_reduce(&mut values, ReduceOp::Sum).unwrap();
+            let mut mean = vec![rank; 2];
+            group.all_reduce(&mut mean, ReduceOp::Mean).unwrap();
+            let mut max = vec![rank];
+            group.all_reduce(&mut max, ReduceOp::Max).unwrap();
+
+            (values, mean, max)
+        });
+
+        for (values, mean, max) in results {
+            assert_eq!(values, vec![3.0, 6.0, 3.0, 0.0, -3.0]);
+            assert_eq!(mean, vec![1.0, 1.0]);
+            assert_eq!(max, vec![2.0]);
+        }
+    }
+
+    #[test]
+    fn test_master_listening_on_unspecified_address() {
+        let results = run_group_bound("0.0.0.0:0", 3, |group| {
+            let mut values = vec![group.rank() as f32];
+            group.all_reduce(&mut values, ReduceOp::Sum).unwrap();
+            values
+        });
+
+        assert!(results.iter().all(|values| values == &vec![3.0]));
+    }
+
+    #[test]
+    fn test_all_reduce_with_fewer_values_than_processes() {
+        let results = run_group(4, |group| {
+            let mut values = vec![group.rank() as f32 + 1.0];
+            group.all_reduce(&mut values, ReduceOp::Sum).unwrap();
+            values
+        });
+
+        assert!(results.iter().all(|values| values == &vec![10.0]));
+    }
+
+    #[test]
+    fn test_broadcast_from_root() {
+        let results = run_group(3, |group| {
+            let mut values = vec![group.rank() as f32; 3];
+            group.broadcast(&mut values, 1).unwrap();
+            group.barrier().unwrap();
+            values
+        });
+
+        assert!(results.iter().all(|values| values == &vec![1.0; 3]));
+    }
+
+    #[test]
+    fn test_single_process_group() {
+        let group = ProcessGroupConfig::new(0, 1, "127.0.0.1:0".to_string())
+            .init()
+            .unwrap();
+        let mut values = vec![1.0, 2.0];
+
+        group.all_reduce(&mut values, ReduceOp::Mean).unwrap();
+
+        assert_eq!(values, vec![1.0, 2.0]);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::group::{ENV_LOCAL_RANK, ENV_MASTER_ADDR, ENV_RANK, ENV_WORLD_SIZE};
+use crate::DistributedError;
+use std::process::{Child, Command};
+use std::time::Duration;
+
+/// Start the training processes of a node, with the environment read by
+/// [ProcessGroupConfig::from_env](crate::ProcessGroupConfig::from_env).
+///
+/// The same launcher is run on every node with its own node rank, the process of global rank 0
+/// being on the node 0 and listening on the master address.
+#[derive(Debug, Clone)]
+pub struct Launcher {
+    nproc_per_node: usize,
+    nnodes: usize,
+    node_rank: usize,
+    master_addr: String,
+}
+
+impl Launcher {
+    /// Creates a launcher starting the given number of processes on a single node.
+    pub fn new(nproc_per_node: usize) -> Self {
+        Self {
+            nproc_per_node,
+            nnodes: 1,
+            node_rank: 0,
+            master_addr: "127.0.0.1:29500".to_string(),
+        }
+    }
+
+    /// Set the number of nodes and the rank of the current node.
+    pub fn with_nodes(mut self, nnodes: usize, node_rank: usize) -> Self {
+        self.nnodes = nnodes;
+        self.node_rank = node_rank;
+        self
+    }
+
+    /// Set the address on which the process of rank 0 waits for the others, reachable from all
+    /// the nodes.
+    pub fn with_master_addr(mut self, master_addr: &str) -> Self {
+        self.master_addr = master_addr.to_string();
+        self
+    }
+
+    /// The total number of processes over all the nodes.
+    pub fn world_size(&self) -> usize {
+        self.nproc_per_node * self.nnodes
+    }
+
+    /// Run the program in each process of the node and wait for them to finish.
+    ///
+    /// When a process fails, the other processes of the node are killed, since they would wait
+    /// for the failed one forever.
+    pub fn launch(&self, program: &str, args: &[String]) -> Result<(), DistributedError> {
+        if self.nproc_per_node == 0 || self.node_rank >= self.nnodes {
+            return Err(DistributedError::Config(format!(
+                "Invalid launcher configuration {self:?}."
+            )));
+        }
+
+        let mut children = Vec::with_capacity(self.nproc_per_node);
+        for local_rank in 0..self.nproc_per_node {
+            let rank = self.node_rank * self.nproc_per_node + local_rank;
+            let child = Command::new(program)
+                .args(args)
+                .env(ENV_RANK, rank.to_string())
+                .env(ENV_LOCAL_RANK, local_rank.to_string())
+                .env(ENV_WORLD_SIZE, self.world_size().to_string())
+                .env(ENV_MASTER_ADDR, &self.master_addr)
+                .spawn();
+
+            match child {
+                Ok(child) => children.push((rank, child)),
+                Err(err) => {
+                    kill_all(&mut children);
+                    return Err(err.into());
+                }
+            }
+        }
+
+        log::info!(
+            "Launched the processes {} to {} of {}",
+            self.node_rank * self.nproc_per_node,
+            (self.node_rank + 1) * self.nproc_per_node - 1,
+            self.world_size()
+        );
+
+        while !children.is_empty() {
+            let mut failed = None;
+
+            children.retain_mut(|(rank, child)| match child.try_wait() {
+                Ok(Some(status)) if status.success() => false,
+                Ok(Some(status)) => {
+                    failed = Some(format!("The process {rank} failed with {status}."));
+                    false
+                }
+                Ok(None) => true,
+                Err(err) => {
+                    failed = Some(format!("Can't wait for the process {rank}: {err}"));
+                    false
+                }
+            });
+
+            if let Some(message) = failed {
+                kill_all(&mut children);
+                return Err(DistributedError::Process(message));
+            }
+
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        Ok(())
+    }
+}
+
+fn kill_all(children: &mut [(usize, Child)]) {
+    for (rank, child) in children.iter_mut() {
+        if let Err(err) = child.kill() {
+            log::warn!("Can't kill the process {rank}: {err}");
+        }
+        child.wait().ok();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launch_sets_the_environment_of_each_process() {
+        let script = format!(
+            "test \"${ENV_RANK}\" = \"$((${ENV_LOCAL_RANK} + 2))\" \
+             && test \"${ENV_WORLD_SIZE}\" = 4"
+        );
+        let launcher = Launcher::new(2).with_nodes(2, 1);
+
+        launcher.launch("sh", &["-c".to_string(), script]).unwrap();
+    }
+
+    #[test]
+    fn test_launch_fails_with_a_process() {
+        let script = format!("test \"${ENV_LOCAL_RANK}\" = 0 || exit 3; sleep 10");
+        let launcher = Launcher::new(2);
+
+        let result = launcher.launch("sh", &["-c".to_string(), script]);
+
+        assert!(matches!(result, Err(DistributedError::Process(_))));
+    }
+}
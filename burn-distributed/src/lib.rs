@@ -0,0 +1,26 @@
+#![warn(missing_docs)]
+
+//! Distributed training across processes and machines for the Burn framework.
+//!
+//! The processes join a [group](ProcessGroup) connected in a ring over TCP, each one training on
+//! its own [part of the data](DistributedDataset) and averaging its gradients with the others
+//! through a [distributed optimizer](DistributedOptimizer).
+
+#[macro_use]
+extern crate derive_new;
+
+mod error;
+mod grads;
+mod group;
+mod launcher;
+mod optim;
+mod ring;
+mod sampler;
+
+pub use error::*;
+pub use grads::*;
+pub use group::*;
+pub use launcher::*;
+pub use optim::*;
+pub use ring::ReduceOp;
+pub use sampler::*;
@@ -0,0 +1,92 @@
+use crate::{all_reduce_grads, DistributedError, ProcessGroup};
+use burn_core::module::AutodiffModule;
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::LearningRate;
+use std::sync::Arc;
+
+/// An [optimizer](Optimizer) averaging the gradients of all the processes of the group before
+/// updating the module with the wrapped optimizer.
+///
+/// Since every process applies the same averaged gradients to the same
+/// [synchronized](crate::sync_module) weights, the modules stay identical without exchanging
+/// them. It can be used with the learner as any other optimizer.
+///
+/// When the gradients can't be averaged, e.g. because another process failed, the module is no
+/// longer updated and the [error](DistributedOptimizer::error) is kept, so the training can be
+/// stopped instead of diverging from the other processes.
+pub struct DistributedOptimizer<O> {
+    optim: O,
+    group: Arc<ProcessGroup>,
+    error: Option<DistributedError>,
+}
+
+impl<O> DistributedOptimizer<O> {
+    /// Wrap the optimizer to average the gradients over the processes of the group.
+    pub fn new(optim: O, group: Arc<ProcessGroup>) -> Self {
+        Self {
+            optim,
+            group,
+            error: None,
+        }
+    }
+
+    /// The error that stopped the updates of the module, if any.
+    pub fn error(&self) -> Option<&DistributedError> {
+        self.error.as_ref()
+    }
+
+    /// Average the gradients over the processes of the group, then update the module with the
+    /// wrapped optimizer.
+    pub fn try_step<M, B>(
+        &mut self,
+        lr: LearningRate,
+        module: M,
+        grads: GradientsParams,
+    ) -> Result<M, DistributedError>
+    where
+        O: Optimizer<M, B>,
+        M: AutodiffModule<B>,
+        B: AutodiffBackend,
+    {
+        let grads = all_reduce_grads(&self.group, &module, grads)?;
+
+        Ok(self.optim.step(lr, module, grads))
+    }
+}
+
+impl<O, M, B> Optimizer<M, B> for DistributedOptimizer<O>
+where
+    O: Optimizer<M, B>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type Record = O::Record;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        // The ring is broken after an error, so the next collectives would fail or wait forever.
+        if self.error.is_some() {
+            return module;
+        }
+
+        let grads = match all_reduce_grads(&self.group, &module, grads) {
+            Ok(grads) => grads,
+            Err(err) => {
+                log::error!("Can't average the gradients with the other processes: {err}");
+                self.error = Some(err);
+                return module;
+            }
+        };
+
+        self.optim.step(lr, module, grads)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.optim.to_record()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+}
@@ -0,0 +1,158 @@
+use crate::DistributedError;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// The operation combining the values of the processes in an
+/// [all-reduce](crate::ProcessGroup::all_reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReduceOp {
+    /// The sum of the values.
+    Sum,
+
+    /// The mean of the values, e.g. to average the gradients.
+    Mean,
+
+    /// The maximum of the values.
+    Max,
+
+    /// The minimum of the values.
+    Min,
+}
+
+impl ReduceOp {
+    fn combine(&self, lhs: f32, rhs: f32) -> f32 {
+        match self {
+            ReduceOp::Sum | ReduceOp::Mean => lhs + rhs,
+            ReduceOp::Max => lhs.max(rhs),
+            ReduceOp::Min => lhs.min(rhs),
+        }
+    }
+}
+
+/// The connections of a process with its neighbors in the ring: it sends to the next rank and
+/// receives from the previous one.
+pub(crate) struct Ring {
+    rank: usize,
+    world_size: usize,
+    next: TcpStream,
+    prev: TcpStream,
+}
+
+impl Ring {
+    pub(crate) fn new(rank: usize, world_size: usize, next: TcpStream, prev: TcpStream) -> Self {
+        next.set_nodelay(true).ok();
+        prev.set_nodelay(true).ok();
+
+        Self {
+            rank,
+            world_size,
+            next,
+            prev,
+        }
+    }
+
+    /// Ring all-reduce: a reduce-scatter followed by an all-gather, each process sending
+    /// `2 * (n - 1) / n` times the buffer whatever the number of processes.
+    pub(crate) fn all_reduce(
+        &mut self,
+        values: &mut [f32],
+        op: ReduceOp,
+    ) -> Result<(), DistributedError> {
+        let n = self.world_size;
+        let len = values.len();
+        let chunk = |index: usize| (len * index / n)..(len * (index + 1) / n);
+
+        // After the reduce-scatter, the chunk `rank + 1` is reduced over all the processes.
+        for step in 0..n - 1 {
+            let send = chunk((self.rank + n - step) % n);
+            let recv = chunk((self.rank + n - step - 1) % n);
+            let received = self.exchange(&values[send], recv.len())?;
+
+            for (value, other) in values[recv].iter_mut().zip(received) {
+                *value = op.combine(*value, other);
+            }
+        }
+
+        for step in 0..n - 1 {
+            let send = chunk((self.rank + 1 + n - step) % n);
+            let recv = chunk((self.rank + n - step) % n);
+            let received = self.exchange(&values[send], recv.len())?;
+
+            values[recv].copy_from_slice(&received);
+        }
+
+        if op == ReduceOp::Mean {
+            values.iter_mut().for_each(|value| *value /= n as f32);
+        }
+
+        Ok(())
+    }
+
+    /// Pass the values of the root along the ring.
+    pub(crate) fn broadcast(
+        &mut self,
+        values: &mut [f32],
+        root: usize,
+    ) -> Result<(), DistributedError> {
+        let last = (root + self.world_size - 1) % self.world_size;
+
+        if self.rank != root {
+            let received = read_values(&mut self.prev, values.len())?;
+            values.copy_from_slice(&received);
+        }
+        if self.rank != last {
+            write_values(&mut self.next, values)?;
+        }
+
+        Ok(())
+    }
+
+    /// Send values to the next process while receiving those of the previous one, concurrently
+    /// so that the sockets' buffers can't fill up with every process only sending.
+    fn exchange(&mut self, send: &[f32], recv_len: usize) -> Result<Vec<f32>, DistributedError> {
+        let next = &mut self.next;
+        let prev = &mut self.prev;
+
+        std::thread::scope(|scope| {
+            let writer = scope.spawn(move || write_values(next, send));
+            let received = read_values(prev, recv_len);
+
+            writer
+                .join()
+                .expect("The thread sending the values should not panic.")?;
+            received
+        })
+    }
+}
+
+fn write_values(stream: &mut TcpStream, values: &[f32]) -> Result<(), DistributedError> {
+    let mut bytes = Vec::with_capacity(8 + values.len() * 4);
+    bytes.extend((values.len() as u64).to_le_bytes());
+    values
+        .iter()
+        .for_each(|value| bytes.extend(value.to_le_bytes()));
+
+    stream.write_all(&bytes)?;
+    Ok(())
+}
+
+fn read_values(stream: &mut TcpStream, len: usize) -> Result<Vec<f32>, DistributedError> {
+    let mut header = [0; 8];
+    stream.read_exact(&mut header)?;
+
+    let len_received = u64::from_le_bytes(header) as usize;
+    if len_received != len {
+        return Err(DistributedError::Collective(format!(
+            "Expected {len} values from the previous process, received {len_received}, all the \
+             processes should call the same collectives with buffers of the same size."
+        )));
+    }
+
+    let mut bytes = vec![0; len * 4];
+    stream.read_exact(&mut bytes)?;
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect())
+}
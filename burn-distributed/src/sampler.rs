@@ -0,0 +1,165 @@
+use crate::ProcessGroup;
+use burn_core::data::dataset::Dataset;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::marker::PhantomData;
+use std::sync::RwLock;
+
+/// The part of a dataset used by one process of a distributed training.
+///
+/// Each process gets every `world_size` item starting at its rank. The first items are repeated
+/// when the dataset can't be split evenly, so that all the processes have the same number of
+/// batches and call the collectives the same number of times.
+pub struct DistributedDataset<D, I> {
+    dataset: D,
+    indices: RwLock<Vec<usize>>,
+    seed: Option<u64>,
+    rank: usize,
+    world_size: usize,
+    input: PhantomData<I>,
+}
+
+impl<D, I> DistributedDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    /// Creates the part of the dataset of the process with the given rank.
+    pub fn new(dataset: D, rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be lower than the world size {world_size}."
+        );
+        let indices = (0..dataset.len()).collect();
+
+        Self {
+            indices: RwLock::new(split(indices, rank, world_size)),
+            seed: None,
+            dataset,
+            rank,
+            world_size,
+            input: PhantomData,
+        }
+    }
+
+    /// Creates the part of the dataset of the current process of the group.
+    pub fn from_group(dataset: D, group: &ProcessGroup) -> Self {
+        Self::new(dataset, group.rank(), group.world_size())
+    }
+
+    /// Shuffle the dataset before splitting it, differently at each epoch set with
+    /// [set_epoch](DistributedDataset::set_epoch).
+    ///
+    /// The seed must be the same for all the processes, so that their parts don't overlap.
+    pub fn shuffled(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self.set_epoch(0);
+
+        self
+    }
+
+    /// Shuffle the dataset for the given epoch, with a seed derived from the seed of the dataset
+    /// and the epoch.
+    ///
+    /// All the processes should call it at the beginning of each epoch, e.g. from a learner
+    /// callback, so that each process trains on different items at each epoch. It does nothing
+    /// when the dataset isn't [shuffled](DistributedDataset::shuffled).
+    pub fn set_epoch(&self, epoch: usize) {
+        let Some(seed) = self.seed else {
+            return;
+        };
+
+        let mut indices = (0..self.dataset.len()).collect::<Vec<_>>();
+        indices.shuffle(&mut StdRng::seed_from_u64(seed.wrapping_add(epoch as u64)));
+        *self.indices.write().unwrap() = split(indices, self.rank, self.world_size);
+    }
+}
+
+impl<D, I> Dataset<I> for DistributedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let index = *self.indices.read().unwrap().get(index)?;
+        self.dataset.get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.indices.read().unwrap().len()
+    }
+}
+
+fn split(mut indices: Vec<usize>, rank: usize, world_size: usize) -> Vec<usize> {
+    if indices.is_empty() {
+        return indices;
+    }
+
+    let len_padded = (indices.len() + world_size - 1) / world_size * world_size;
+    let padding = len_padded - indices.len();
+    let padding = indices
+        .iter()
+        .cycle()
+        .take(padding)
+        .copied()
+        .collect::<Vec<_>>();
+    indices.extend(padding);
+
+    indices.into_iter().skip(rank).step_by(world_size).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataset::InMemDataset;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parts_padded_to_the_same_length() {
+        let dataset = Arc::new(InMemDataset::new((0..5).collect::<Vec<usize>>()));
+
+        let parts = (0..3)
+            .map(|rank| {
+                let part = DistributedDataset::new(dataset.clone(), rank, 3);
+                part.iter().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(parts, vec![vec![0, 3], vec![1, 4], vec![2, 0]]);
+
+        let single = Arc::new(InMemDataset::new(vec![7]));
+        let part = DistributedDataset::new(single, 2, 3);
+        assert_eq!(part.iter().collect::<Vec<_>>(), vec![7]);
+    }
+
+    #[test]
+    fn test_shuffled_parts_cover_the_dataset() {
+        let dataset = Arc::new(InMemDataset::new((0..10).collect::<Vec<usize>>()));
+
+        let mut items = (0..2)
+            .flat_map(|rank| {
+                let part = DistributedDataset::new(dataset.clone(), rank, 2).shuffled(42);
+                part.iter().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        items.sort();
+
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffled_differently_at_each_epoch() {
+        let dataset = Arc::new(InMemDataset::new((0..10).collect::<Vec<usize>>()));
+        let parts = (0..2)
+            .map(|rank| DistributedDataset::new(dataset.clone(), rank, 2).shuffled(42))
+            .collect::<Vec<_>>();
+        let items = |part: &DistributedDataset<_, usize>| part.iter().collect::<Vec<_>>();
+
+        let epoch_1 = parts.iter().map(items).collect::<Vec<_>>();
+        parts.iter().for_each(|part| part.set_epoch(1));
+        let epoch_2 = parts.iter().map(items).collect::<Vec<_>>();
+
+        assert_ne!(epoch_1, epoch_2);
+        let mut items = epoch_2.concat();
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
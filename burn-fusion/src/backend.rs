@@ -2,7 +2,10 @@ use crate::{
     client::FusionClient, graph::TensorOpsDescription, FusionClientLocator, FusionTensor,
     HandleContainer,
 };
-use burn_tensor::{backend::Backend, Device, Shape};
+use burn_tensor::{
+    backend::{Backend, MemoryStats},
+    Device, Shape,
+};
 use core::marker::PhantomData;
 
 pub(crate) static CLIENTS: FusionClientLocator = FusionClientLocator::new();
@@ -42,11 +45,34 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::seed(seed);
     }
 
+    fn seed_all(seed: u64) {
+        B::seed_all(seed)
+    }
+
+    fn seed_device(seed: u64, device: &Self::Device) {
+        B::seed_device(seed, device)
+    }
+
+    fn set_deterministic(deterministic: bool) -> bool {
+        B::set_deterministic(deterministic)
+    }
+
+    fn devices() -> Vec<Self::Device> {
+        B::devices()
+    }
+
     fn sync(device: &Self::Device) {
         let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
         client.drain_graph();
         B::sync(device)
     }
+
+    fn memory_stats(device: &Self::Device) -> Option<MemoryStats> {
+        // The lazy operations are executed first, so their tensors are allocated.
+        let client = CLIENTS.client::<B::FusionClient>(&device.clone().into());
+        client.drain_graph();
+        B::memory_stats(device)
+    }
 }
 
 /// The status of a [fusion ops](FusionOps).
@@ -104,6 +104,12 @@ pub enum ModuleOpsDescription {
     /// [adaptive avg pool 2d backward](burn_tensor::ops::ModuleOps::adaptive_avg_pool2d_backward).
     AdaptiveAvgPool2dBackward(AdaptiveAvgPool2dBackwardDescription),
     /// Operation corresponding to
+    /// [adaptive max pool 2d with indices](burn_tensor::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+    AdaptiveMaxPool2dWithIndices(AdaptiveMaxPool2dWithIndicesDescription),
+    /// Operation corresponding to
+    /// [adaptive max pool 2d with indices backward](burn_tensor::ops::ModuleOps::adaptive_max_pool2d_with_indices_backward).
+    AdaptiveMaxPool2dWithIndicesBackward(AdaptiveMaxPool2dWithIndicesBackwardDescription),
+    /// Operation corresponding to
     /// [max pool 1d](burn_tensor::ops::ModuleOps::max_pool1d).
     MaxPool1d(MaxPool1dDescription),
     /// Operation corresponding to
@@ -291,6 +297,16 @@ pub enum NumericOpsDescription<E: Element> {
     SumDim(ScalarOpsDescription<usize>),
     /// Operation corresponding to:
     ///
+    /// Float => [cumsum](burn_tensor::ops::TensorOps::cumsum).
+    /// Int => [cumsum](burn_tensor::ops::IntTensorOps::int_cumsum).
+    CumSum(ScalarOpsDescription<usize>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumprod](burn_tensor::ops::TensorOps::cumprod).
+    /// Int => [cumprod](burn_tensor::ops::IntTensorOps::int_cumprod).
+    CumProd(ScalarOpsDescription<usize>),
+    /// Operation corresponding to:
+    ///
     /// Float => [equal elem](burn_tensor::ops::TensorOps::equal_elem).
     /// Int => [equal elem](burn_tensor::ops::IntTensorOps::int_equal_elem).
     EqualElem(ScalarOpsDescription<E>),
@@ -697,6 +713,24 @@ pub struct AdaptiveAvgPool2dBackwardDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug)]
+#[allow(missing_docs)]
+pub struct AdaptiveMaxPool2dWithIndicesDescription {
+    pub x: TensorDescription,
+    pub output_size: [usize; 2],
+    pub out: TensorDescription,
+    pub out_indices: TensorDescription,
+}
+
+#[derive(Clone, Debug)]
+#[allow(missing_docs)]
+pub struct AdaptiveMaxPool2dWithIndicesBackwardDescription {
+    pub x: TensorDescription,
+    pub grad: TensorDescription,
+    pub indices: TensorDescription,
+    pub out: TensorDescription,
+}
+
 #[derive(Clone, Debug)]
 #[allow(missing_docs)]
 pub struct MaxPool1dDescription {
@@ -944,6 +978,12 @@ impl<E: Element> NumericOpsDescription<E> {
             NumericOpsDescription::SumDim(desc) => {
                 handles.cleanup(&desc.lhs);
             }
+            NumericOpsDescription::CumSum(desc) => {
+                handles.cleanup(&desc.lhs);
+            }
+            NumericOpsDescription::CumProd(desc) => {
+                handles.cleanup(&desc.lhs);
+            }
             NumericOpsDescription::Max(desc) => {
                 handles.cleanup(&desc.input);
             }
@@ -1087,6 +1127,14 @@ impl ModuleOpsDescription {
                 handles.cleanup(&desc.x);
                 handles.cleanup(&desc.grad);
             }
+            ModuleOpsDescription::AdaptiveMaxPool2dWithIndices(desc) => {
+                handles.cleanup(&desc.x);
+            }
+            ModuleOpsDescription::AdaptiveMaxPool2dWithIndicesBackward(desc) => {
+                handles.cleanup(&desc.x);
+                handles.cleanup(&desc.grad);
+                handles.cleanup(&desc.indices);
+            }
             ModuleOpsDescription::MaxPool1d(desc) => {
                 handles.cleanup(&desc.x);
             }
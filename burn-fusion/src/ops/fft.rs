@@ -0,0 +1,4 @@
+use crate::{Fusion, FusionBackend};
+use burn_tensor::ops::FftOps;
+
+impl<B: FusionBackend> FftOps<Self> for Fusion<B> {}
@@ -1151,6 +1151,42 @@ impl<B: FusionBackend> TensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        scalar_float_ops!(CumSumOps, B::cumsum, usize, noconvert);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+
+        let desc = ScalarOpsDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::CumSum(desc.clone())),
+            CumSumOps::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        scalar_float_ops!(CumProdOps, B::cumprod, usize, noconvert);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+
+        let desc = ScalarOpsDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::NumericOpsFloat(NumericOpsDescription::CumProd(desc.clone())),
+            CumProdOps::<D>::new(desc),
+        );
+
+        out
+    }
+
     fn mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
         unary_float_ops!(MeanOps, B::mean);
 
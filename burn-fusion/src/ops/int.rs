@@ -958,6 +958,42 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(CumSumOps, B::int_cumsum, usize, noconvert);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+
+        let desc = ScalarOpsDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::NumericOpsInt(NumericOpsDescription::CumSum(desc.clone())),
+            CumSumOps::<D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(CumProdOps, B::int_cumprod, usize, noconvert);
+
+        let out = tensor.client.tensor_uninitialized(tensor.shape.clone());
+
+        let desc = ScalarOpsDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::NumericOpsInt(NumericOpsDescription::CumProd(desc.clone())),
+            CumProdOps::<D>::new(desc),
+        );
+
+        out
+    }
+
     fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
         unary_int_ops!(MeanOps, B::int_mean);
 
@@ -1,7 +1,9 @@
 mod activation;
 mod binary;
 mod boolean;
+mod fft;
 mod float;
 mod int;
+mod linalg;
 mod module;
 mod unary;
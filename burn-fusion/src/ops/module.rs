@@ -3,6 +3,7 @@ use crate::{
     graph::{
         AdaptiveAvgPool1dBackwardDescription, AdaptiveAvgPool1dDescription,
         AdaptiveAvgPool2dBackwardDescription, AdaptiveAvgPool2dDescription,
+        AdaptiveMaxPool2dWithIndicesBackwardDescription, AdaptiveMaxPool2dWithIndicesDescription,
         AvgPool1dBackwardDescription, AvgPool1dDescription, AvgPool2dBackwardDescription,
         AvgPool2dDescription, Conv1dDescription, Conv2dDescription, ConvTranspose1dDescription,
         ConvTranspose2dDescription, MaxPool1dDescription, MaxPool1dWithIndicesBackwardDescription,
@@ -17,7 +18,7 @@ use burn_tensor::ops::{
         calculate_conv_output_size, calculate_conv_transpose_output_size,
         calculate_pool_output_size,
     },
-    ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, MaxPool1dBackward,
+    ConvOptions, ConvTransposeOptions, Device, FloatTensor, IntTensor, MaxPool1dBackward,
     MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
@@ -900,4 +901,83 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
 
         out
     }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        make_ops!(
+            AdaptiveMaxPool2dWithIndicesOps,
+            AdaptiveMaxPool2dWithIndicesDescription,
+            |args: AdaptiveMaxPool2dWithIndicesDescription, handles: &mut HandleContainer<B>| {
+                let x = handles.get_float_tensor(&args.x);
+                let output = B::adaptive_max_pool2d_with_indices(x, args.output_size);
+
+                handles.register_float_tensor(&args.out.id, output.output);
+                handles.register_int_tensor(&args.out_indices.id, output.indices);
+            }
+        );
+
+        let shape = vec![x.shape[0], x.shape[1], output_size[0], output_size[1]];
+        let out = x.client.tensor_uninitialized(shape.clone());
+        let out_indices = x.client.tensor_uninitialized(shape);
+
+        let desc = AdaptiveMaxPool2dWithIndicesDescription {
+            x: x.into_description(),
+            output_size,
+            out: out.to_description_out(),
+            out_indices: out_indices.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::ModuleOps(
+                crate::graph::ModuleOpsDescription::AdaptiveMaxPool2dWithIndices(desc.clone()),
+            ),
+            AdaptiveMaxPool2dWithIndicesOps::new(desc),
+        );
+
+        MaxPool2dWithIndices::new(out, out_indices)
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        make_ops!(
+            AdaptiveMaxPool2dWithIndicesBackwardOps,
+            AdaptiveMaxPool2dWithIndicesBackwardDescription,
+            |args: AdaptiveMaxPool2dWithIndicesBackwardDescription,
+             handles: &mut HandleContainer<B>| {
+                let x = handles.get_float_tensor(&args.x);
+                let grad = handles.get_float_tensor(&args.grad);
+                let indices = handles.get_int_tensor(&args.indices);
+                let output = B::adaptive_max_pool2d_with_indices_backward(x, grad, indices);
+
+                handles.register_float_tensor(&args.out.id, output.x_grad);
+            }
+        );
+
+        let out = x.client.tensor_uninitialized(x.shape.clone());
+
+        let desc = AdaptiveMaxPool2dWithIndicesBackwardDescription {
+            x: x.into_description(),
+            grad: output_grad.into_description(),
+            indices: indices.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            TensorOpsDescription::ModuleOps(
+                crate::graph::ModuleOpsDescription::AdaptiveMaxPool2dWithIndicesBackward(
+                    desc.clone(),
+                ),
+            ),
+            AdaptiveMaxPool2dWithIndicesBackwardOps::new(desc),
+        );
+
+        MaxPool2dBackward::new(out)
+    }
+
+    fn attention_dropout_seed(device: &Device<Self>) -> u32 {
+        B::attention_dropout_seed(device)
+    }
 }
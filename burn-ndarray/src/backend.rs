@@ -1,12 +1,12 @@
 use crate::element::FloatNdArrayElement;
 use crate::NdArrayTensor;
 use alloc::string::String;
-use burn_common::stub::Mutex;
+use burn_common::{rand::DeviceGenerators, stub::Mutex};
 use burn_tensor::backend::Backend;
 use core::marker::PhantomData;
-use rand::{rngs::StdRng, SeedableRng};
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+pub(crate) static SEED: Mutex<DeviceGenerators<NdArrayDevice>> =
+    Mutex::new(DeviceGenerators::new());
 
 /// The device type for the ndarray backend.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -52,8 +52,10 @@ impl<E: FloatNdArrayElement> Backend for NdArray<E> {
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        SEED.lock().unwrap().seed_all(seed);
+    }
+
+    fn seed_device(seed: u64, device: &Self::Device) {
+        SEED.lock().unwrap().seed_device(device, seed);
     }
 }
@@ -90,11 +90,15 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: FloatNdArrayElement>(
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
 
-fn start_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
+pub(crate) fn start_index(
+    output_size_index: usize,
+    output_size: usize,
+    input_size: usize,
+) -> usize {
     libm::floorf((output_size_index as f32 * input_size as f32) / output_size as f32) as usize
 }
 
-fn end_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
+pub(crate) fn end_index(output_size_index: usize, output_size: usize, input_size: usize) -> usize {
     let index =
         libm::ceilf(((output_size_index + 1) as f32 * input_size as f32) / output_size as f32)
             as usize;
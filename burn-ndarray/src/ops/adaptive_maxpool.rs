@@ -0,0 +1,101 @@
+use crate::{
+    element::FloatNdArrayElement,
+    iter_range_par,
+    ops::adaptive_avgpool::{end_index, start_index},
+    run_par,
+    sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_tensor::ElementConversion;
+use ndarray::Array4;
+
+pub(crate) fn adaptive_max_pool2d_with_indices<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+) -> (NdArrayTensor<E, 4>, NdArrayTensor<i64, 4>) {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let inf = (-f32::INFINITY).elem::<E>();
+
+    let x = x.array;
+    let mut output = Array4::from_elem((batch_size, channels, output_size[0], output_size[1]), inf);
+    let mut indices = Array4::<i64>::zeros((batch_size, channels, output_size[0], output_size[1]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+    let unsafe_shared_indices = UnsafeSharedRef::new(&mut indices);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let indices = unsafe_shared_indices.get();
+
+            for h in 0..output_size[0] {
+                for w in 0..output_size[1] {
+                    let ih_start = start_index(h, output_size[0], input_height);
+                    let ih_end = end_index(h, output_size[0], input_height);
+                    let iw_start = start_index(w, output_size[1], input_width);
+                    let iw_end = end_index(w, output_size[1], input_width);
+
+                    let mut max_val = inf;
+                    let mut index = (ih_start * input_width + iw_start) as i64;
+
+                    for ih in ih_start..ih_end {
+                        for iw in iw_start..iw_end {
+                            let val = x[[b, c, ih, iw]];
+
+                            if val > max_val {
+                                max_val = val;
+                                index = (ih * input_width + iw) as i64;
+                            }
+                        }
+                    }
+
+                    output[[b, c, h, w]] = max_val;
+                    indices[[b, c, h, w]] = index;
+                }
+            }
+        })
+    });
+
+    let output = NdArrayTensor::new(output.into_dyn().into_shared());
+    let indices = NdArrayTensor::new(indices.into_dyn().into_shared());
+
+    (output, indices)
+}
+
+pub(crate) fn adaptive_max_pool2d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_grad: NdArrayTensor<E, 4>,
+    indices: NdArrayTensor<i64, 4>,
+) -> NdArrayTensor<E, 4> {
+    let [_, _, output_height, output_width] = output_grad.shape().dims;
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+
+    let output_grad = output_grad.array;
+    let indices = indices.array;
+
+    let mut x_grad = Array4::zeros((batch_size, channels, input_height, input_width));
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut x_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let x_grad = unsafe_shared_out.get();
+
+            for h in 0..output_height {
+                for w in 0..output_width {
+                    let index = indices[[b, c, h, w]] as usize;
+                    let grad = output_grad[[b, c, h, w]];
+
+                    x_grad[[b, c, index / input_width, index % input_width]] += grad;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(x_grad.into_dyn().into_shared())
+}
@@ -4,11 +4,14 @@ use burn_tensor::ElementConversion;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
+use ndarray::ArrayD;
 
+use burn_tensor::ops::PadMode;
 use burn_tensor::Shape;
 use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
+use ndarray::Slice;
 use ndarray::SliceInfoElem;
 
 use crate::element::NdArrayElement;
@@ -225,6 +228,57 @@ where
         }
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn pad<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let size = array.shape()[dim];
+
+            array = match mode {
+                PadMode::Constant(value) => {
+                    let mut shape = array.shape().to_vec();
+                    shape[dim] += before + after;
+
+                    let mut output = ArrayD::from_elem(shape, value.elem::<E>());
+                    output
+                        .slice_axis_mut(Axis(dim), Slice::from(before..before + size))
+                        .assign(&array);
+                    output.into_shared()
+                }
+                _ => {
+                    let indices = (-(before as isize)..(size + after) as isize)
+                        .map(|position| mode.source_index(position, size).unwrap())
+                        .collect::<Vec<_>>();
+                    array.select(Axis(dim), &indices).into_shared()
+                }
+            };
+        }
+
+        NdArrayTensor::new(array)
+    }
+
     pub fn gather<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
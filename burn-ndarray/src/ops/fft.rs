@@ -0,0 +1,171 @@
+use burn_tensor::ops::FftOps;
+
+use crate::{element::FloatNdArrayElement, NdArray};
+
+#[cfg(feature = "std")]
+use crate::tensor::NdArrayTensor;
+#[cfg(feature = "std")]
+use burn_tensor::ElementConversion;
+#[cfg(feature = "std")]
+use ndarray::{ArrayD, IxDyn};
+#[cfg(feature = "std")]
+use rustfft::{num_complex::Complex, FftPlanner};
+
+// The fast Fourier transforms are computed with rustfft, which requires the standard library,
+// the default implementations are used otherwise.
+impl<E: FloatNdArrayElement> FftOps<Self> for NdArray<E> {
+    #[cfg(feature = "std")]
+    fn fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let shape = real.array.shape().to_vec();
+        let size = shape[dim];
+
+        let mut lines = into_lines(&real, Some(&imag), dim);
+        process(&mut lines, size, inverse);
+
+        from_lines(lines, shape, dim)
+    }
+
+    #[cfg(feature = "std")]
+    fn rfft<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let mut shape = tensor.array.shape().to_vec();
+        let size = shape[dim];
+        let num_frequencies = size / 2 + 1;
+
+        let mut lines = into_lines(&tensor, None, dim);
+        process(&mut lines, size, false);
+
+        // Only the non-negative frequencies are kept.
+        let lines = lines
+            .chunks(usize::max(size, 1))
+            .flat_map(|line| line[..num_frequencies].to_vec())
+            .collect();
+        shape[dim] = num_frequencies;
+
+        from_lines(lines, shape, dim)
+    }
+
+    #[cfg(feature = "std")]
+    fn irfft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        size: usize,
+    ) -> NdArrayTensor<E, D> {
+        let mut shape = real.array.shape().to_vec();
+        let num_inputs = shape[dim];
+        let num_frequencies = usize::min(num_inputs, size / 2 + 1);
+
+        // Rebuild the negative frequencies, which are the conjugates of the positive ones, the
+        // missing frequencies being zeros.
+        let mut lines = Vec::new();
+        for line in into_lines(&real, Some(&imag), dim).chunks(usize::max(num_inputs, 1)) {
+            let mut full = vec![Complex::new(0.0, 0.0); size];
+            full[..num_frequencies].copy_from_slice(&line[..num_frequencies]);
+            for k in 1..num_frequencies {
+                if size - k >= num_frequencies {
+                    full[size - k] = line[k].conj();
+                }
+            }
+            lines.extend(full);
+        }
+
+        // Only the real part is kept, so the imaginary parts of the first and middle frequencies
+        // are ignored.
+        process(&mut lines, size, true);
+        shape[dim] = size;
+
+        from_lines(lines, shape, dim).0
+    }
+}
+
+/// Collects the complex values of every line along the dimension `dim`, one line after the
+/// other.
+#[cfg(feature = "std")]
+fn into_lines<E: FloatNdArrayElement, const D: usize>(
+    real: &NdArrayTensor<E, D>,
+    imag: Option<&NdArrayTensor<E, D>>,
+    dim: usize,
+) -> Vec<Complex<f64>> {
+    let real = real.array.view().permuted_axes(axes_dim_last(D, dim));
+
+    match imag {
+        Some(imag) => {
+            let imag = imag.array.view().permuted_axes(axes_dim_last(D, dim));
+
+            real.iter()
+                .zip(imag.iter())
+                .map(|(re, im)| Complex::new(re.elem(), im.elem()))
+                .collect()
+        }
+        None => real.iter().map(|re| Complex::new(re.elem(), 0.0)).collect(),
+    }
+}
+
+/// Creates the real and imaginary tensors from the lines along the dimension `dim`.
+#[cfg(feature = "std")]
+fn from_lines<E: FloatNdArrayElement, const D: usize>(
+    lines: Vec<Complex<f64>>,
+    shape: Vec<usize>,
+    dim: usize,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let axes = axes_dim_last(D, dim);
+    let shape_lines = axes.iter().map(|axis| shape[*axis]).collect::<Vec<_>>();
+
+    // Inverse of the permutation moving the dimension `dim` last.
+    let mut axes_inverse = vec![0; D];
+    for (position, axis) in axes.into_iter().enumerate() {
+        axes_inverse[axis] = position;
+    }
+
+    let tensor = |values: Vec<E>| {
+        let array = ArrayD::from_shape_vec(IxDyn(&shape_lines), values)
+            .unwrap()
+            .permuted_axes(axes_inverse.clone());
+
+        NdArrayTensor::new(array.into_shared())
+    };
+
+    (
+        tensor(lines.iter().map(|value| value.re.elem()).collect()),
+        tensor(lines.iter().map(|value| value.im.elem()).collect()),
+    )
+}
+
+/// The axes permutation moving the dimension `dim` last.
+#[cfg(feature = "std")]
+fn axes_dim_last(rank: usize, dim: usize) -> Vec<usize> {
+    let mut axes = (0..rank).filter(|axis| *axis != dim).collect::<Vec<_>>();
+    axes.push(dim);
+
+    axes
+}
+
+/// Computes the transform of every line of the given size in place, the inverse transform being
+/// normalized by the size.
+#[cfg(feature = "std")]
+fn process(lines: &mut [Complex<f64>], size: usize, inverse: bool) {
+    if size == 0 || lines.is_empty() {
+        return;
+    }
+
+    let mut planner = FftPlanner::new();
+    let fft = match inverse {
+        true => planner.plan_fft_inverse(size),
+        false => planner.plan_fft_forward(size),
+    };
+    fft.process(lines);
+
+    if inverse {
+        for value in lines.iter_mut() {
+            *value /= size as f64;
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+use burn_tensor::ElementConversion;
+use libm::{fabs, floor, rint};
+use ndarray::Array4;
+
+/// The `a` coefficient of the cubic convolution kernel, the same as PyTorch.
+const CUBIC_COEFFICIENT: f64 = -0.75;
+
+pub(crate) fn grid_sample_2d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grid: NdArrayTensor<E, 4>,
+    options: GridSampleOptions,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, height, width] = x.shape().dims;
+    let [_, height_out, width_out, _] = grid.shape().dims;
+
+    let x = x.array;
+    let grid = grid.array;
+    let mut output = Array4::from_elem((batch_size, channels, height_out, width_out), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * height_out).for_each(|k| unsafe {
+            let b = k / height_out;
+            let oh = k % height_out;
+
+            let output = unsafe_shared_out.get();
+            for ow in 0..width_out {
+                let taps_x = axis_taps(grid[[b, oh, ow, 0]].elem(), width, &options);
+                let taps_y = axis_taps(grid[[b, oh, ow, 1]].elem(), height, &options);
+
+                for c in 0..channels {
+                    let mut sum = 0.0;
+
+                    for (ih, weight_y) in taps_y.iter() {
+                        for (iw, weight_x) in taps_x.iter() {
+                            let value: f64 = x[[b, c, *ih, *iw]].elem();
+                            sum += value * weight_y * weight_x;
+                        }
+                    }
+
+                    output[[b, c, oh, ow]] = sum.elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Computes the input indices and weights used to sample a normalized coordinate along one axis,
+/// the indices outside of the input being skipped.
+fn axis_taps(coordinate: f64, size: usize, options: &GridSampleOptions) -> Vec<(usize, f64)> {
+    let coordinate = match options.align_corners {
+        true => (coordinate + 1.0) * (size - 1) as f64 / 2.0,
+        false => ((coordinate + 1.0) * size as f64 - 1.0) / 2.0,
+    };
+    let taps = match options.mode {
+        InterpolateMode::Nearest => {
+            let coordinate = apply_padding(coordinate, size, options);
+            vec![(rint(coordinate), 1.0)]
+        }
+        InterpolateMode::Bilinear => {
+            let coordinate = apply_padding(coordinate, size, options);
+            let index = floor(coordinate);
+            let lambda = coordinate - index;
+
+            vec![(index, 1.0 - lambda), (index + 1.0, lambda)]
+        }
+        InterpolateMode::Bicubic => {
+            let index = floor(coordinate);
+            let t = coordinate - index;
+            let a = CUBIC_COEFFICIENT;
+            let near = |x: f64| ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+            let far = |x: f64| ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+            let weights = [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)];
+
+            // The padding is applied to each tap instead of the sampled coordinate.
+            weights
+                .into_iter()
+                .enumerate()
+                .map(|(offset, weight)| {
+                    let index = index + offset as f64 - 1.0;
+                    (apply_padding(index, size, options), weight)
+                })
+                .collect()
+        }
+    };
+
+    taps.into_iter()
+        .filter(|(index, _)| *index >= 0.0 && *index <= (size - 1) as f64)
+        .map(|(index, weight)| (index as usize, weight))
+        .collect()
+}
+
+/// Moves the coordinates outside of the input back inside, depending on the padding mode.
+fn apply_padding(coordinate: f64, size: usize, options: &GridSampleOptions) -> f64 {
+    let last = (size - 1) as f64;
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => coordinate.clamp(0.0, last),
+        GridSamplePaddingMode::Reflection => {
+            let (min, span) = match options.align_corners {
+                true => (0.0, last),
+                false => (-0.5, size as f64),
+            };
+
+            if span == 0.0 {
+                return 0.0;
+            }
+
+            let coordinate = fabs(coordinate - min);
+            let flips = floor(coordinate / span);
+            let extra = coordinate - flips * span;
+            let reflected = match (flips as i64) % 2 {
+                0 => extra,
+                _ => span - extra,
+            };
+
+            (reflected + min).clamp(0.0, last)
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // Language
 use alloc::vec;
 use alloc::vec::Vec;
-use burn_tensor::ops::IntTensorOps;
+use burn_tensor::ops::{IntTensorOps, PadMode};
 use burn_tensor::Reader;
 
 use burn_tensor::ElementConversion;
@@ -277,6 +277,28 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::pad(tensor, pads, mode)
+    }
+
     fn int_mean<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
         NdArrayMathOps::mean(tensor)
     }
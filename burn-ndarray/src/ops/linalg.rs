@@ -0,0 +1,555 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{ops::LinalgOps, ElementConversion};
+use libm::{fabs, sqrt};
+use ndarray::{ArrayD, IxDyn};
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+
+// The decompositions are computed in pure Rust in f64, so they are available without the standard
+// library, but don't use LAPACK even when a BLAS feature is enabled. They suit small to medium
+// matrices, the cost of the Jacobi eigenvalue and singular value algorithms growing quickly with
+// the size.
+impl<E: FloatNdArrayElement> LinalgOps<Self> for NdArray<E> {
+    fn inverse<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let shape = tensor.array.shape().to_vec();
+        let size = shape[D - 1];
+        let num_matrices = shape[..D - 2].iter().product::<usize>();
+        let identity = (0..num_matrices * size * size)
+            .map(|i| match i % (size * size) % (size + 1) {
+                0 => 1.0,
+                _ => 0.0,
+            })
+            .collect::<Vec<_>>();
+
+        let (solution, _det) =
+            gauss_jordan(to_vec(&tensor), identity, num_matrices, size, size);
+
+        from_vec(solution, shape)
+    }
+
+    fn solve<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let shape = rhs.array.shape().to_vec();
+        let size = shape[D - 2];
+        let num_columns = shape[D - 1];
+        let num_matrices = shape[..D - 2].iter().product::<usize>();
+
+        let (solution, _det) = gauss_jordan(
+            to_vec(&lhs),
+            to_vec(&rhs),
+            num_matrices,
+            size,
+            num_columns,
+        );
+
+        from_vec(solution, shape)
+    }
+
+    fn triangular_solve<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        upper: bool,
+        unit_diagonal: bool,
+    ) -> NdArrayTensor<E, D> {
+        let shape = rhs.array.shape().to_vec();
+        let size = shape[D - 2];
+        let num_columns = shape[D - 1];
+
+        let solution = substitution(
+            to_vec(&lhs),
+            to_vec(&rhs),
+            size,
+            num_columns,
+            upper,
+            unit_diagonal,
+        );
+
+        from_vec(solution, shape)
+    }
+
+    fn det<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let mut shape = tensor.array.shape().to_vec();
+        let size = shape[D - 1];
+        let num_matrices = shape[..D - 2].iter().product::<usize>();
+
+        let (_solution, det) = gauss_jordan(to_vec(&tensor), Vec::new(), num_matrices, size, 0);
+
+        shape[D - 2] = 1;
+        shape[D - 1] = 1;
+        from_vec(det, shape)
+    }
+
+    fn cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let shape = tensor.array.shape().to_vec();
+        let size = shape[D - 1];
+        let values = to_vec(&tensor);
+
+        let mut lower = vec![0.0; values.len()];
+        for (a, l) in values
+            .chunks((size * size).max(1))
+            .zip(lower.chunks_mut((size * size).max(1)))
+        {
+            cholesky(a, l, size);
+        }
+
+        from_vec(lower, shape)
+    }
+
+    fn qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let shape = tensor.array.shape().to_vec();
+        let (num_rows, num_columns) = (shape[D - 2], shape[D - 1]);
+        let size = usize::min(num_rows, num_columns);
+        let values = to_vec(&tensor);
+
+        let mut q = Vec::new();
+        let mut r = Vec::new();
+        for a in values.chunks((num_rows * num_columns).max(1)) {
+            let (q_matrix, r_matrix) = qr(a, num_rows, num_columns);
+            q.extend(q_matrix);
+            r.extend(r_matrix);
+        }
+
+        let mut shape_q = shape.clone();
+        shape_q[D - 1] = size;
+        let mut shape_r = shape;
+        shape_r[D - 2] = size;
+
+        (from_vec(q, shape_q), from_vec(r, shape_r))
+    }
+
+    fn eigh<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let shape = tensor.array.shape().to_vec();
+        let size = shape[D - 1];
+        let values = to_vec(&tensor);
+
+        let mut eigenvalues = Vec::new();
+        let mut eigenvectors = Vec::new();
+        for a in values.chunks((size * size).max(1)) {
+            let (matrix_values, matrix_vectors) = eigh(a, size);
+            eigenvalues.extend(matrix_values);
+            eigenvectors.extend(matrix_vectors);
+        }
+
+        let mut shape_values = shape.clone();
+        shape_values[D - 2] = 1;
+
+        (
+            from_vec(eigenvalues, shape_values),
+            from_vec(eigenvectors, shape),
+        )
+    }
+
+    fn svd<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        let shape = tensor.array.shape().to_vec();
+        let (num_rows, num_columns) = (shape[D - 2], shape[D - 1]);
+        let size = usize::min(num_rows, num_columns);
+        let values = to_vec(&tensor);
+
+        let mut u = Vec::new();
+        let mut s = Vec::new();
+        let mut vh = Vec::new();
+        for a in values.chunks((num_rows * num_columns).max(1)) {
+            let (u_matrix, s_matrix, vh_matrix) = svd(a, num_rows, num_columns);
+            u.extend(u_matrix);
+            s.extend(s_matrix);
+            vh.extend(vh_matrix);
+        }
+
+        let mut shape_u = shape.clone();
+        shape_u[D - 1] = size;
+        let mut shape_s = shape.clone();
+        shape_s[D - 2] = 1;
+        shape_s[D - 1] = size;
+        let mut shape_vh = shape;
+        shape_vh[D - 2] = size;
+
+        (
+            from_vec(u, shape_u),
+            from_vec(s, shape_s),
+            from_vec(vh, shape_vh),
+        )
+    }
+}
+
+fn to_vec<E: FloatNdArrayElement, const D: usize>(tensor: &NdArrayTensor<E, D>) -> Vec<f64> {
+    tensor.array.iter().map(|value| value.elem()).collect()
+}
+
+fn from_vec<E: FloatNdArrayElement, const D: usize>(
+    values: Vec<f64>,
+    shape: Vec<usize>,
+) -> NdArrayTensor<E, D> {
+    let values = values.into_iter().map(|value| value.elem()).collect();
+    let array = ArrayD::from_shape_vec(IxDyn(&shape), values).unwrap();
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+/// Solves `lhs @ x = rhs` for each matrix of the batch with a Gauss-Jordan elimination using
+/// partial pivoting, returning the solutions and the determinants.
+///
+/// The determinant of an empty matrix is one.
+fn gauss_jordan(
+    mut lhs: Vec<f64>,
+    mut rhs: Vec<f64>,
+    num_matrices: usize,
+    size: usize,
+    num_columns: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    let mut dets = Vec::with_capacity(num_matrices);
+
+    for batch in 0..num_matrices {
+        let a = &mut lhs[batch * size * size..(batch + 1) * size * size];
+        let b = &mut rhs[batch * size * num_columns..(batch + 1) * size * num_columns];
+        let mut det = 1.0;
+
+        for k in 0..size {
+            let pivot = (k..size)
+                .max_by(|&i, &j| fabs(a[i * size + k]).total_cmp(&fabs(a[j * size + k])))
+                .unwrap();
+
+            if pivot != k {
+                for column in 0..size {
+                    a.swap(k * size + column, pivot * size + column);
+                }
+                for column in 0..num_columns {
+                    b.swap(k * num_columns + column, pivot * num_columns + column);
+                }
+                det = -det;
+            }
+
+            let pivot_value = a[k * size + k];
+            det *= pivot_value;
+
+            // The whole column is zero below the diagonal, the matrix is singular.
+            if pivot_value == 0.0 {
+                continue;
+            }
+
+            for column in 0..size {
+                a[k * size + column] /= pivot_value;
+            }
+            for column in 0..num_columns {
+                b[k * num_columns + column] /= pivot_value;
+            }
+
+            for row in (0..size).filter(|&row| row != k) {
+                let factor = a[row * size + k];
+
+                for column in 0..size {
+                    a[row * size + column] -= factor * a[k * size + column];
+                }
+                for column in 0..num_columns {
+                    b[row * num_columns + column] -= factor * b[k * num_columns + column];
+                }
+            }
+        }
+
+        dets.push(det);
+    }
+
+    (rhs, dets)
+}
+
+/// Solves `lhs @ x = rhs` for each triangular matrix of the batch with a forward or backward
+/// substitution, only reading the given triangle of `lhs`.
+fn substitution(
+    lhs: Vec<f64>,
+    mut rhs: Vec<f64>,
+    size: usize,
+    num_columns: usize,
+    upper: bool,
+    unit_diagonal: bool,
+) -> Vec<f64> {
+    let num_matrices = match size {
+        0 => 0,
+        _ => lhs.len() / (size * size),
+    };
+
+    for batch in 0..num_matrices {
+        let a = &lhs[batch * size * size..(batch + 1) * size * size];
+        let b = &mut rhs[batch * size * num_columns..(batch + 1) * size * num_columns];
+
+        for step in 0..size {
+            let (row, solved) = match upper {
+                true => (size - 1 - step, size - step..size),
+                false => (step, 0..step),
+            };
+
+            for column in 0..num_columns {
+                let mut value = b[row * num_columns + column];
+
+                for k in solved.clone() {
+                    value -= a[row * size + k] * b[k * num_columns + column];
+                }
+                if !unit_diagonal {
+                    value /= a[row * size + row];
+                }
+
+                b[row * num_columns + column] = value;
+            }
+        }
+    }
+
+    rhs
+}
+
+/// Maximum number of sweeps of the Jacobi methods, they usually converge in less than ten.
+const MAX_JACOBI_SWEEPS: usize = 64;
+
+/// Computes the lower triangular matrix `l` such that `l @ l^T = a`, using the lower triangle of
+/// `a`.
+fn cholesky(a: &[f64], l: &mut [f64], size: usize) {
+    for j in 0..size {
+        let mut diagonal = a[j * size + j];
+        for k in 0..j {
+            diagonal -= l[j * size + k] * l[j * size + k];
+        }
+        let diagonal = sqrt(diagonal);
+        l[j * size + j] = diagonal;
+
+        for i in j + 1..size {
+            let mut value = a[i * size + j];
+            for k in 0..j {
+                value -= l[i * size + k] * l[j * size + k];
+            }
+            l[i * size + j] = value / diagonal;
+        }
+    }
+}
+
+/// Computes the reduced QR decomposition of `a` with a Gram-Schmidt orthogonalization applied
+/// twice, which keeps the columns of `q` orthogonal.
+fn qr(a: &[f64], num_rows: usize, num_columns: usize) -> (Vec<f64>, Vec<f64>) {
+    let size = usize::min(num_rows, num_columns);
+    let mut q = vec![0.0; num_rows * size];
+    let mut r = vec![0.0; size * num_columns];
+
+    for j in 0..size {
+        let mut column = (0..num_rows)
+            .map(|i| a[i * num_columns + j])
+            .collect::<Vec<_>>();
+
+        for _ in 0..2 {
+            for k in 0..j {
+                let dot = (0..num_rows)
+                    .map(|i| q[i * size + k] * column[i])
+                    .sum::<f64>();
+                for (i, value) in column.iter_mut().enumerate() {
+                    *value -= dot * q[i * size + k];
+                }
+            }
+        }
+
+        let norm = sqrt(column.iter().map(|value| value * value).sum::<f64>());
+        let norm = if norm == 0.0 { 1.0 } else { norm };
+        for (i, value) in column.iter().enumerate() {
+            q[i * size + j] = value / norm;
+        }
+    }
+
+    for i in 0..size {
+        for j in i..num_columns {
+            r[i * num_columns + j] = (0..num_rows)
+                .map(|k| q[k * size + i] * a[k * num_columns + j])
+                .sum();
+        }
+    }
+
+    (q, r)
+}
+
+/// Computes the eigenvalues in ascending order and the eigenvectors, stored as columns, of the
+/// symmetric matrix `a` with the cyclic Jacobi method.
+fn eigh(a: &[f64], size: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = a.to_vec();
+    let mut vectors = identity(size);
+    let norm = a.iter().map(|value| value * value).sum::<f64>();
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal = (0..size)
+            .flat_map(|p| (0..size).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * size + q] * a[p * size + q])
+            .sum::<f64>();
+
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                let (cosine, sine) =
+                    jacobi_rotation(a[p * size + p], a[q * size + q], a[p * size + q]);
+
+                rotate_columns(&mut a, size, size, p, q, cosine, sine);
+                rotate_rows(&mut a, size, p, q, cosine, sine);
+                rotate_columns(&mut vectors, size, size, p, q, cosine, sine);
+            }
+        }
+    }
+
+    let values = (0..size).map(|i| a[i * size + i]).collect::<Vec<_>>();
+    let mut order = (0..size).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| values[i].total_cmp(&values[j]));
+
+    (
+        order.iter().map(|&i| values[i]).collect(),
+        permute_columns(&vectors, size, size, &order),
+    )
+}
+
+/// Computes the reduced singular value decomposition of `a` with the one-sided Jacobi method,
+/// the singular values being sorted in descending order.
+fn svd(a: &[f64], num_rows: usize, num_columns: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if num_rows < num_columns {
+        let (u, s, vh) = svd(&transpose(a, num_rows, num_columns), num_columns, num_rows);
+
+        return (
+            transpose(&vh, num_rows, num_rows),
+            s,
+            transpose(&u, num_columns, num_rows),
+        );
+    }
+
+    let mut columns = a.to_vec();
+    let mut vectors = identity(num_columns);
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..num_columns {
+            for q in p + 1..num_columns {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for i in 0..num_rows {
+                    let (value_p, value_q) =
+                        (columns[i * num_columns + p], columns[i * num_columns + q]);
+                    alpha += value_p * value_p;
+                    beta += value_q * value_q;
+                    gamma += value_p * value_q;
+                }
+
+                if fabs(gamma) <= f64::EPSILON * sqrt(alpha * beta) {
+                    continue;
+                }
+                converged = false;
+
+                let (cosine, sine) = jacobi_rotation(alpha, beta, gamma);
+                rotate_columns(&mut columns, num_rows, num_columns, p, q, cosine, sine);
+                rotate_columns(&mut vectors, num_columns, num_columns, p, q, cosine, sine);
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let values = (0..num_columns)
+        .map(|j| {
+            sqrt(
+                (0..num_rows)
+                    .map(|i| columns[i * num_columns + j] * columns[i * num_columns + j])
+                    .sum::<f64>(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..num_rows {
+        for (j, value) in values.iter().enumerate() {
+            if *value != 0.0 {
+                columns[i * num_columns + j] /= value;
+            }
+        }
+    }
+
+    let mut order = (0..num_columns).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| values[j].total_cmp(&values[i]));
+
+    let vectors = permute_columns(&vectors, num_columns, num_columns, &order);
+
+    (
+        permute_columns(&columns, num_rows, num_columns, &order),
+        order.iter().map(|&i| values[i]).collect(),
+        transpose(&vectors, num_columns, num_columns),
+    )
+}
+
+/// Computes the cosine and the sine of the Jacobi rotation that diagonalizes the symmetric
+/// matrix `[[alpha, gamma], [gamma, beta]]`.
+fn jacobi_rotation(alpha: f64, beta: f64, gamma: f64) -> (f64, f64) {
+    if gamma == 0.0 {
+        return (1.0, 0.0);
+    }
+
+    let zeta = (beta - alpha) / (2.0 * gamma);
+    let sign = if zeta >= 0.0 { 1.0 } else { -1.0 };
+    let tangent = sign / (fabs(zeta) + sqrt(zeta * zeta + 1.0));
+    let cosine = 1.0 / sqrt(tangent * tangent + 1.0);
+
+    (cosine, tangent * cosine)
+}
+
+fn rotate_columns(
+    matrix: &mut [f64],
+    num_rows: usize,
+    num_columns: usize,
+    p: usize,
+    q: usize,
+    cosine: f64,
+    sine: f64,
+) {
+    for i in 0..num_rows {
+        let value_p = matrix[i * num_columns + p];
+        let value_q = matrix[i * num_columns + q];
+        matrix[i * num_columns + p] = cosine * value_p - sine * value_q;
+        matrix[i * num_columns + q] = sine * value_p + cosine * value_q;
+    }
+}
+
+fn rotate_rows(matrix: &mut [f64], size: usize, p: usize, q: usize, cosine: f64, sine: f64) {
+    for j in 0..size {
+        let value_p = matrix[p * size + j];
+        let value_q = matrix[q * size + j];
+        matrix[p * size + j] = cosine * value_p - sine * value_q;
+        matrix[q * size + j] = sine * value_p + cosine * value_q;
+    }
+}
+
+fn permute_columns(
+    matrix: &[f64],
+    num_rows: usize,
+    num_columns: usize,
+    order: &[usize],
+) -> Vec<f64> {
+    (0..num_rows * order.len())
+        .map(|i| matrix[(i / order.len()) * num_columns + order[i % order.len()]])
+        .collect()
+}
+
+fn transpose(matrix: &[f64], num_rows: usize, num_columns: usize) -> Vec<f64> {
+    (0..num_rows * num_columns)
+        .map(|i| matrix[(i % num_rows) * num_columns + i / num_rows])
+        .collect()
+}
+
+fn identity(size: usize) -> Vec<f64> {
+    (0..size * size)
+        .map(|i| match i % (size + 1) {
+            0 => 1.0,
+            _ => 0.0,
+        })
+        .collect()
+}
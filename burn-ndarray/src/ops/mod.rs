@@ -1,13 +1,18 @@
 mod activations;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
 
 pub(crate) mod adaptive_avgpool;
+pub(crate) mod adaptive_maxpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+pub(crate) mod grid_sample;
+pub(crate) mod interpolate;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
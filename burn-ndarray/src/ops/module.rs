@@ -1,11 +1,15 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
+    adaptive_maxpool::{adaptive_max_pool2d_backward, adaptive_max_pool2d_with_indices},
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv_transpose2d},
+    grid_sample::grid_sample_2d,
+    interpolate::{interpolate, interpolate_backward},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
-use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray, NdArrayDevice, SEED};
 use burn_tensor::ops::*;
+use rand::Rng;
 
 impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
     fn conv2d(
@@ -99,4 +103,50 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<E, 4> {
         adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<NdArray<E>> {
+        let (output, indices) = adaptive_max_pool2d_with_indices(x, output_size);
+
+        MaxPool2dWithIndices::new(output, indices)
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: NdArrayTensor<E, 4>,
+        output_grad: NdArrayTensor<E, 4>,
+        indices: NdArrayTensor<i64, 4>,
+    ) -> MaxPool2dBackward<NdArray<E>> {
+        MaxPool2dBackward::new(adaptive_max_pool2d_backward(x, output_grad, indices))
+    }
+
+    fn interpolate(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate(x, output_size, options)
+    }
+
+    fn interpolate_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate_backward(x, grad, output_size, options)
+    }
+
+    fn grid_sample_2d(
+        x: NdArrayTensor<E, 4>,
+        grid: NdArrayTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> NdArrayTensor<E, 4> {
+        grid_sample_2d(x, grid, options)
+    }
+
+    fn attention_dropout_seed(device: &NdArrayDevice) -> u32 {
+        SEED.lock().unwrap().get(device).gen()
+    }
 }
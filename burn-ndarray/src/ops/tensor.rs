@@ -9,8 +9,11 @@ use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_common::rand::get_seeded_rng;
-use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
+use burn_tensor::{
+    backend::Backend,
+    ops::{PadMode, TensorOps},
+    Data, ElementConversion, Shape,
+};
 use burn_tensor::{Distribution, Reader};
 
 // External crates
@@ -31,14 +34,8 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         device: &NdArrayDevice,
     ) -> NdArrayTensor<E, D> {
         let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
-        let tensor = Self::from_data(Data::random(shape, distribution, &mut rng), device);
-        *seed = Some(rng);
-        tensor
+        let rng = seed.get(device);
+        Self::from_data(Data::random(shape, distribution, rng), device)
     }
 
     fn shape<const D: usize>(tensor: &NdArrayTensor<E, D>) -> Shape<D> {
@@ -313,6 +310,22 @@ impl<E: FloatNdArrayElement> TensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn pad<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::pad(tensor, pads, mode)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -63,12 +63,9 @@ macro_rules! reshape {
         array $array:expr
     ) => {{
         let dim = $crate::to_typed_dims!($n, $shape.dims, justdim);
-        let safe_into_shape =
-            $array.is_standard_layout() ||
-            (
-                $array.ndim() > 1 &&
-                $array.raw_view().reversed_axes().is_standard_layout()
-            );
+        // Arrays in column-major order would be reinterpreted in that order by `into_shape`, so
+        // only arrays in row-major order are reshaped without relayout.
+        let safe_into_shape = $array.is_standard_layout();
 
         let array: ndarray::ArcArray<$ty, Dim<[usize; $n]>> = match safe_into_shape {
             true => $array
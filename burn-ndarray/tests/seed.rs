@@ -0,0 +1,5 @@
+type TestBackend = burn_ndarray::NdArray<f32>;
+
+// Seeding is global to the backend, the test is kept alone in its binary so that no other test
+// consumes the random streams concurrently.
+burn_tensor::testgen_seed!();
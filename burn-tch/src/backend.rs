@@ -1,6 +1,20 @@
 use super::element::TchElement;
 use super::TchTensor;
 use burn_tensor::backend::Backend;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Panics when the operation can't be deterministic on the device while the
+/// [deterministic mode](Backend::set_deterministic) is enabled.
+pub(crate) fn check_deterministic(operation: &str, device: tch::Device) {
+    if DETERMINISTIC.load(Ordering::Relaxed) && matches!(device, tch::Device::Cuda(_)) {
+        panic!(
+            "The operation {operation} accumulates the values with atomic operations on Cuda \
+             devices, which is nondeterministic and not allowed in deterministic mode."
+        );
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// The device struct when using the `tch` backend.
@@ -90,6 +104,15 @@ impl<E: TchElement> Backend for LibTorch<E> {
         tch::manual_seed(seed as i64);
     }
 
+    fn seed_device(seed: u64, device: &Self::Device) {
+        match device {
+            // LibTorch can't seed a single Cuda device by index, all of them are seeded.
+            LibTorchDevice::Cuda(_) => tch::Cuda::manual_seed_all(seed),
+            // The other devices share the default generator of LibTorch.
+            _ => tch::manual_seed(seed as i64),
+        }
+    }
+
     fn ad_enabled() -> bool {
         false
     }
@@ -98,6 +121,45 @@ impl<E: TchElement> Backend for LibTorch<E> {
         "tch".to_string()
     }
 
+    /// The deterministic mode of LibTorch (`torch.use_deterministic_algorithms`) isn't enabled,
+    /// only the following operations are covered:
+    ///
+    /// - The cuDNN benchmark mode, which selects the fastest convolution algorithms at runtime,
+    ///   is disabled.
+    /// - `scatter` and `select_assign` panic on Cuda devices, where they accumulate with atomic
+    ///   operations.
+    ///
+    /// The other operations, e.g. the cuDNN convolution algorithms or the backward passes of the
+    /// pooling and interpolation modules on Cuda, may still be nondeterministic, so `true` is
+    /// only returned when cuDNN isn't available. `false` is always returned when the
+    /// deterministic mode is disabled.
+    fn set_deterministic(deterministic: bool) -> bool {
+        DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+        if !deterministic {
+            return false;
+        }
+
+        tch::Cuda::cudnn_set_benchmark(false);
+
+        !tch::Cuda::cudnn_is_available()
+    }
+
+    fn devices() -> Vec<Self::Device> {
+        let mut devices = vec![LibTorchDevice::Cpu];
+
+        if tch::Cuda::is_available() {
+            devices.extend((0..tch::Cuda::device_count() as usize).map(LibTorchDevice::Cuda));
+        }
+        if tch::utils::has_mps() {
+            devices.push(LibTorchDevice::Mps);
+        }
+        if tch::utils::has_vulkan() {
+            devices.push(LibTorchDevice::Vulkan);
+        }
+
+        devices
+    }
+
     fn sync(device: &Self::Device) {
         if let LibTorchDevice::Cuda(index) = device {
             tch::Cuda::synchronize(*index as i64);
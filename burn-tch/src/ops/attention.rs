@@ -0,0 +1,182 @@
+use burn_tensor::ops::AttentionDropout;
+
+/// The maximum number of attention weights materialized at once, over all the batch items and
+/// heads, by the attention computed by blocks of queries.
+const BLOCK_NUM_WEIGHTS: i64 = 1 << 22;
+
+/// Computes the scaled dot-product attention by blocks of queries, so that only the attention
+/// weights of a block are materialized, and returns the output with the log-sum-exp of the
+/// attention scores of each query.
+pub(crate) fn attention_forward(
+    query: &tch::Tensor,
+    key: &tch::Tensor,
+    value: &tch::Tensor,
+    mask: Option<&tch::Tensor>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+) -> (tch::Tensor, tch::Tensor) {
+    let mut outputs = Vec::new();
+    let mut logsumexps = Vec::new();
+
+    for (start, length) in blocks(query, key) {
+        let scores = block_scores(query, key, mask, start, length, min_float);
+        let logsumexp = scores.logsumexp([3], true);
+        let mut weights = (scores - &logsumexp).exp();
+        if let Some(dropout) = dropout {
+            weights = weights * dropout_mask(dropout, query, key, start, length);
+        }
+
+        outputs.push(weights.matmul(value));
+        logsumexps.push(logsumexp.squeeze_dim(3));
+    }
+
+    (
+        tch::Tensor::cat(&outputs, 2),
+        tch::Tensor::cat(&logsumexps, 2),
+    )
+}
+
+/// Computes the gradients of the query, the key and the value of the scaled dot-product
+/// attention by blocks of queries, recomputing the attention weights `P` of each block from the
+/// log-sum-exp of the forward pass.
+///
+/// With `dP = dO V^T * M`, `M` being the dropout mask, the gradient of the scores is
+/// `dS = P * (dP - sum(dO * O))`, the sum being over the values.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn attention_backward(
+    query: &tch::Tensor,
+    key: &tch::Tensor,
+    value: &tch::Tensor,
+    mask: Option<&tch::Tensor>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+    output: &tch::Tensor,
+    logsumexp: &tch::Tensor,
+    output_grad: &tch::Tensor,
+) -> (tch::Tensor, tch::Tensor, tch::Tensor) {
+    let d_k = query.size()[3];
+    let scale = 1.0 / (d_k as f64).sqrt();
+
+    let mut query_grads = Vec::new();
+    let mut key_grad = key.zeros_like();
+    let mut value_grad = value.zeros_like();
+
+    for (start, length) in blocks(query, key) {
+        let query_block = query.narrow(2, start, length);
+        let output_grad_block = output_grad.narrow(2, start, length);
+        let output_block = output.narrow(2, start, length);
+
+        let scores = block_scores(query, key, mask, start, length, min_float);
+        let weights = (scores - logsumexp.narrow(2, start, length).unsqueeze(3)).exp();
+        let mut weights_grad = output_grad_block.matmul(&value.transpose(2, 3));
+
+        let dropped_weights = match dropout {
+            Some(dropout) => {
+                let dropout_mask = dropout_mask(dropout, query, key, start, length);
+                weights_grad = weights_grad * &dropout_mask;
+                &weights * dropout_mask
+            }
+            None => weights.shallow_clone(),
+        };
+
+        value_grad = value_grad + dropped_weights.transpose(2, 3).matmul(&output_grad_block);
+
+        let output_dot = (&output_grad_block * output_block).sum_dim_intlist(
+            Some([3_i64].as_slice()),
+            true,
+            output_grad_block.kind(),
+        );
+        let scores_grad = (weights * (weights_grad - output_dot))
+            .f_mul_scalar(scale)
+            .unwrap();
+
+        query_grads.push(scores_grad.matmul(key));
+        key_grad = key_grad + scores_grad.transpose(2, 3).matmul(&query_block);
+    }
+
+    (tch::Tensor::cat(&query_grads, 2), key_grad, value_grad)
+}
+
+/// The start and length of the blocks of queries processed together, so that each block has at
+/// most [BLOCK_NUM_WEIGHTS] attention weights, unless a single query has more.
+fn blocks(query: &tch::Tensor, key: &tch::Tensor) -> Vec<(i64, i64)> {
+    let size = query.size();
+    let (batch_size, n_heads, seq_length_1) = (size[0], size[1], size[2]);
+    let seq_length_2 = key.size()[2];
+    let block_size = i64::max(
+        BLOCK_NUM_WEIGHTS / i64::max(batch_size * n_heads * seq_length_2, 1),
+        1,
+    );
+
+    (0..seq_length_1)
+        .step_by(block_size as usize)
+        .map(|start| (start, i64::min(block_size, seq_length_1 - start)))
+        .collect()
+}
+
+/// The attention scores `QK^T / sqrt(d_k)` of the given queries, the masked scores being
+/// `min_float`.
+fn block_scores(
+    query: &tch::Tensor,
+    key: &tch::Tensor,
+    mask: Option<&tch::Tensor>,
+    start: i64,
+    length: i64,
+    min_float: f64,
+) -> tch::Tensor {
+    let d_k = query.size()[3];
+    let scores = query
+        .narrow(2, start, length)
+        .matmul(&key.transpose(2, 3))
+        .f_div_scalar((d_k as f64).sqrt())
+        .unwrap();
+
+    match mask {
+        Some(mask) => scores.masked_fill(&mask.narrow(1, start, length).unsqueeze(1), min_float),
+        None => scores,
+    }
+}
+
+/// The dropout mask of the attention weights of the given queries, generated on the device with
+/// the [hash](AttentionDropout::hash) of their index.
+///
+/// The unsigned 32 bits operations are computed on 64 bits integers, wrapping the products,
+/// which stay below `2^63` since the multipliers are below `2^31`.
+fn dropout_mask(
+    dropout: AttentionDropout,
+    query: &tch::Tensor,
+    key: &tch::Tensor,
+    start: i64,
+    length: i64,
+) -> tch::Tensor {
+    let size = query.size();
+    let (batch_size, n_heads, seq_length_1) = (size[0], size[1], size[2]);
+    let seq_length_2 = key.size()[2];
+    let options = (tch::Kind::Int64, query.device());
+    let wrap = |x: tch::Tensor| x.bitwise_and(u32::MAX as i64);
+    let xor_shift = |x: &tch::Tensor, bits: i64| {
+        x.bitwise_xor_tensor(&x.bitwise_right_shift_tensor_scalar(bits))
+    };
+
+    let batch_head = tch::Tensor::arange(batch_size * n_heads, options)
+        .f_mul_scalar(seq_length_1 * seq_length_2)
+        .unwrap()
+        .reshape([batch_size, n_heads, 1, 1]);
+    let row = tch::Tensor::arange_start(start, start + length, options)
+        .f_mul_scalar(seq_length_2)
+        .unwrap()
+        .reshape([1, 1, length, 1]);
+    let column = tch::Tensor::arange(seq_length_2, options).reshape([1, 1, 1, seq_length_2]);
+
+    let index = wrap(batch_head + row + column);
+    let x = index.bitwise_xor(dropout.seed.wrapping_mul(0x2c1b3c6d) as i64);
+    let x = wrap(xor_shift(&x, 16).f_mul_scalar(0x7feb352d_i64).unwrap());
+    let x = wrap(xor_shift(&x, 15).f_mul_scalar(0x1b873593_i64).unwrap());
+    let x = xor_shift(&x, 16);
+
+    x.bitwise_right_shift_tensor_scalar(8)
+        .ge(dropout.threshold() as i64)
+        .to_kind(query.kind())
+        .f_mul_scalar(dropout.scale())
+        .unwrap()
+}
@@ -1,4 +1,4 @@
-use burn_tensor::Shape;
+use burn_tensor::{Data, Reader, Shape, SyncReader};
 use tch::Scalar;
 
 use crate::{TchShape, TchTensor};
@@ -9,6 +9,30 @@ pub struct TchOps<E: tch::kind::Element + Copy + Default> {
 }
 
 impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
+    /// Reads the data of the tensor.
+    ///
+    /// Tensors on Cuda devices are copied to the host with a non blocking transfer when the
+    /// reader is created, the device only being synchronized when the data is read.
+    pub fn into_data<const D: usize>(tensor: TchTensor<E, D>) -> Reader<Data<E, D>>
+    where
+        E: Send + 'static,
+    {
+        let shape = tensor.shape();
+        let tensor = tensor.tensor.reshape([shape.num_elements() as i64]);
+
+        match tensor.device() {
+            tch::Device::Cuda(index) => Reader::Sync(Box::new(CudaReader {
+                tensor: tensor.to_device_(tch::Device::Cpu, E::KIND, true, false),
+                index,
+                shape,
+            })),
+            _ => {
+                let values: Result<Vec<E>, tch::TchError> = tensor.try_into();
+                Reader::Concrete(Data::new(values.unwrap(), shape))
+            }
+        }
+    }
+
     pub fn reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -288,21 +312,35 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    /// Kind used to accumulate the reductions, half precision floats are upcasted to f32 to
+    /// avoid overflows and the precision loss of 16 bits accumulators.
+    fn accumulation_kind() -> tch::Kind {
+        match E::KIND {
+            tch::Kind::Half | tch::Kind::BFloat16 => tch::Kind::Float,
+            kind => kind,
+        }
+    }
+
     pub fn mean<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
-        let tensor = tensor.tensor.mean(E::KIND);
-        TchTensor::new(tensor)
+        let tensor = tensor.tensor.mean(Self::accumulation_kind());
+        TchTensor::new(tensor.to_kind(E::KIND))
     }
 
     pub fn sum<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, 1> {
-        let tensor = tensor.tensor.sum(E::KIND);
-        TchTensor::new(tensor)
+        let tensor = tensor.tensor.sum(Self::accumulation_kind());
+        TchTensor::new(tensor.to_kind(E::KIND))
     }
 
     pub fn mean_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
         TchTensor::from_existing(
             tensor
                 .tensor
-                .mean_dim(Some([dim as i64].as_slice()), true, E::KIND),
+                .mean_dim(
+                    Some([dim as i64].as_slice()),
+                    true,
+                    Self::accumulation_kind(),
+                )
+                .to_kind(E::KIND),
             tensor.storage,
         )
     }
@@ -311,7 +349,32 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::from_existing(
             tensor
                 .tensor
-                .sum_dim_intlist(Some([dim as i64].as_slice()), true, E::KIND),
+                .sum_dim_intlist(
+                    Some([dim as i64].as_slice()),
+                    true,
+                    Self::accumulation_kind(),
+                )
+                .to_kind(E::KIND),
+            tensor.storage,
+        )
+    }
+
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(
+            tensor
+                .tensor
+                .cumsum(dim as i64, Self::accumulation_kind())
+                .to_kind(E::KIND),
+            tensor.storage,
+        )
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(
+            tensor
+                .tensor
+                .cumprod(dim as i64, Self::accumulation_kind())
+                .to_kind(E::KIND),
             tensor.storage,
         )
     }
@@ -410,3 +473,19 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::new(tensor)
     }
 }
+
+/// Host tensor being filled by a non blocking transfer from a Cuda device.
+struct CudaReader<const D: usize> {
+    tensor: tch::Tensor,
+    index: usize,
+    shape: Shape<D>,
+}
+
+impl<E: tch::kind::Element + Send, const D: usize> SyncReader<Data<E, D>> for CudaReader<D> {
+    fn read(self: Box<Self>) -> Data<E, D> {
+        tch::Cuda::synchronize(self.index as i64);
+        let values: Result<Vec<E>, tch::TchError> = self.tensor.try_into();
+
+        Data::new(values.unwrap(), self.shape)
+    }
+}
@@ -24,11 +24,7 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
     }
 
     fn bool_into_data<const D: usize>(tensor: TchTensor<bool, D>) -> Reader<Data<bool, D>> {
-        let shape = Self::bool_shape(&tensor);
-        let tensor = Self::bool_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
-        let values: Result<Vec<bool>, tch::TchError> = tensor.tensor.shallow_clone().try_into();
-
-        Reader::Concrete(Data::new(values.unwrap(), shape))
+        TchOps::into_data(tensor)
     }
 
     fn bool_to_device<const D: usize>(
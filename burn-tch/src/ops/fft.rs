@@ -0,0 +1,45 @@
+use crate::{element::TchElement, LibTorch, TchTensor};
+use burn_tensor::ops::FftOps;
+
+impl<E: TchElement> FftOps<Self> for LibTorch<E> {
+    fn fft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let tensor = tch::Tensor::complex(&real.tensor, &imag.tensor);
+        let output = match inverse {
+            true => tensor.fft_ifft(None, dim as i64, "backward"),
+            false => tensor.fft_fft(None, dim as i64, "backward"),
+        };
+
+        (
+            TchTensor::new(output.real().contiguous()),
+            TchTensor::new(output.imag().contiguous()),
+        )
+    }
+
+    fn rfft<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let output = tensor.tensor.fft_rfft(None, dim as i64, "backward");
+
+        (
+            TchTensor::new(output.real().contiguous()),
+            TchTensor::new(output.imag().contiguous()),
+        )
+    }
+
+    fn irfft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        size: usize,
+    ) -> TchTensor<E, D> {
+        let tensor = tch::Tensor::complex(&real.tensor, &imag.tensor);
+
+        TchTensor::new(tensor.fft_irfft(size as i64, dim as i64, "backward"))
+    }
+}
@@ -27,11 +27,7 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
     }
 
     fn int_into_data<const D: usize>(tensor: TchTensor<i64, D>) -> Reader<Data<i64, D>> {
-        let shape = Self::int_shape(&tensor);
-        let tensor = Self::int_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
-        let values: Result<Vec<i64>, tch::TchError> = tensor.tensor.shallow_clone().try_into();
-
-        Reader::Concrete(Data::new(values.unwrap(), shape))
+        TchOps::into_data(tensor)
     }
 
     fn int_to_device<const D: usize>(
@@ -250,6 +246,14 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::sum_dim(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, 1> {
         let tensor: TchTensor<f64, D> =
             TchTensor::new(tensor.tensor.to_dtype(tch::Kind::Float, true, false));
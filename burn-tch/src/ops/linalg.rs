@@ -0,0 +1,61 @@
+use crate::{element::TchElement, LibTorch, TchTensor};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: TchElement> LinalgOps<Self> for LibTorch<E> {
+    fn inverse<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_inv(&tensor.tensor))
+    }
+
+    fn solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&lhs.tensor, &rhs.tensor, true))
+    }
+
+    fn triangular_solve<const D: usize>(
+        lhs: TchTensor<E, D>,
+        rhs: TchTensor<E, D>,
+        upper: bool,
+        unit_diagonal: bool,
+    ) -> TchTensor<E, D> {
+        TchTensor::new(
+            lhs.tensor
+                .linalg_solve_triangular(&rhs.tensor, upper, true, unit_diagonal),
+        )
+    }
+
+    fn det<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let det = tch::Tensor::linalg_det(&tensor.tensor);
+
+        TchTensor::new(det.unsqueeze(-1).unsqueeze(-1))
+    }
+
+    fn cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tch::Tensor::linalg_qr(&tensor.tensor, "reduced");
+
+        (TchTensor::new(q), TchTensor::new(r))
+    }
+
+    fn eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (values, vectors) = tensor.tensor.linalg_eigh("L");
+
+        (
+            TchTensor::new(values.unsqueeze(-2)),
+            TchTensor::new(vectors),
+        )
+    }
+
+    fn svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, v) = tensor.tensor.svd(true, true);
+
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(v.transpose(-2, -1)),
+        )
+    }
+}
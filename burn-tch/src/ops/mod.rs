@@ -1,7 +1,10 @@
 mod activation;
+mod attention;
 mod base;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod module;
 mod tensor;
 
@@ -1,7 +1,9 @@
-use crate::{element::TchElement, LibTorch, TchTensor};
+use super::attention;
+use crate::{element::TchElement, LibTorch, LibTorchDevice, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-    MaxPool2dWithIndices, ModuleOps,
+    AttentionBackward, AttentionDropout, AttentionForward, ConvOptions, ConvTransposeOptions,
+    GridSampleOptions, GridSamplePaddingMode, InterpolateMode, InterpolateOptions,
+    MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
@@ -87,6 +89,45 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.dilation.map(|i| i as i64),
+            options.groups as i64,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn conv_transpose3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv_transpose3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.padding_out.map(|i| i as i64),
+            options.groups as i64,
+            options.dilation.map(|i| i as i64),
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose1d(
         x: TchTensor<E, 3>,
         weight: TchTensor<E, 3>,
@@ -283,4 +324,205 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
 
         TchTensor::new(tensor)
     }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<LibTorch<E>> {
+        let (tensor, indices) =
+            tch::Tensor::adaptive_max_pool2d(&x.tensor, output_size.map(|e| e as i64));
+
+        MaxPool2dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: TchTensor<E, 4>,
+        output_grad: TchTensor<E, 4>,
+        indices: TchTensor<i64, 4>,
+    ) -> MaxPool2dBackward<LibTorch<E>> {
+        let grad = tch::Tensor::adaptive_max_pool2d_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            &indices.tensor,
+        );
+
+        MaxPool2dBackward::new(TchTensor::new(grad))
+    }
+
+    fn interpolate(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> TchTensor<E, 4> {
+        let output_size = output_size.map(|e| e as i64);
+        let align_corners = options.align_corners;
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => x.tensor.upsample_nearest2d(output_size, None, None),
+            InterpolateMode::Bilinear => {
+                x.tensor
+                    .upsample_bilinear2d(output_size, align_corners, None, None)
+            }
+            InterpolateMode::Bicubic => {
+                x.tensor
+                    .upsample_bicubic2d(output_size, align_corners, None, None)
+            }
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn interpolate_backward(
+        x: TchTensor<E, 4>,
+        grad: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> TchTensor<E, 4> {
+        let output_size = output_size.map(|e| e as i64);
+        let input_size = x.tensor.size();
+        let align_corners = options.align_corners;
+
+        let tensor = match options.mode {
+            InterpolateMode::Nearest => tch::Tensor::upsample_nearest2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                None,
+                None,
+            ),
+            InterpolateMode::Bilinear => tch::Tensor::upsample_bilinear2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                align_corners,
+                None,
+                None,
+            ),
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                align_corners,
+                None,
+                None,
+            ),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample_2d(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> TchTensor<E, 4> {
+        let mode = match options.mode {
+            InterpolateMode::Bilinear => 0,
+            InterpolateMode::Nearest => 1,
+            InterpolateMode::Bicubic => 2,
+        };
+        let padding_mode = match options.padding_mode {
+            GridSamplePaddingMode::Zeros => 0,
+            GridSamplePaddingMode::Border => 1,
+            GridSamplePaddingMode::Reflection => 2,
+        };
+
+        let tensor =
+            x.tensor
+                .grid_sampler_2d(&grid.tensor, mode, padding_mode, options.align_corners);
+
+        TchTensor::new(tensor)
+    }
+
+    fn attention(
+        query: TchTensor<E, 4>,
+        key: TchTensor<E, 4>,
+        value: TchTensor<E, 4>,
+        mask: Option<TchTensor<bool, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> TchTensor<E, 4> {
+        // LibTorch samples its own dropout mask, so the dropout is generated from its seed by
+        // the attention computed by blocks instead.
+        if dropout.is_some() {
+            return Self::attention_forward(query, key, value, mask, dropout, min_float).output;
+        }
+
+        // The boolean masks of LibTorch select the positions to attend, so an additive mask is
+        // used instead, which also avoids NaN when all the positions of a row are masked.
+        let mask = mask.map(|mask| {
+            let mask = mask.tensor.unsqueeze(1);
+
+            mask.zeros_like()
+                .to_kind(query.tensor.kind())
+                .masked_fill(&mask, min_float)
+        });
+
+        let tensor = tch::Tensor::scaled_dot_product_attention(
+            &query.tensor,
+            &key.tensor,
+            &value.tensor,
+            mask,
+            0.0,
+            false,
+            None::<f64>,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn attention_forward(
+        query: TchTensor<E, 4>,
+        key: TchTensor<E, 4>,
+        value: TchTensor<E, 4>,
+        mask: Option<TchTensor<bool, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> AttentionForward<Self> {
+        let (output, logsumexp) = attention::attention_forward(
+            &query.tensor,
+            &key.tensor,
+            &value.tensor,
+            mask.as_ref().map(|mask| &mask.tensor),
+            dropout,
+            min_float,
+        );
+
+        AttentionForward::new(TchTensor::new(output), TchTensor::new(logsumexp))
+    }
+
+    fn attention_backward(
+        query: TchTensor<E, 4>,
+        key: TchTensor<E, 4>,
+        value: TchTensor<E, 4>,
+        mask: Option<TchTensor<bool, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+        forward: AttentionForward<Self>,
+        output_grad: TchTensor<E, 4>,
+    ) -> AttentionBackward<Self> {
+        let (query_grad, key_grad, value_grad) = attention::attention_backward(
+            &query.tensor,
+            &key.tensor,
+            &value.tensor,
+            mask.as_ref().map(|mask| &mask.tensor),
+            dropout,
+            min_float,
+            &forward.output.tensor,
+            &forward.logsumexp.tensor,
+            &output_grad.tensor,
+        );
+
+        AttentionBackward::new(
+            TchTensor::new(query_grad),
+            TchTensor::new(key_grad),
+            TchTensor::new(value_grad),
+        )
+    }
+
+    fn attention_dropout_seed(_device: &LibTorchDevice) -> u32 {
+        // Drawn from the generator of LibTorch, so that it follows its seed.
+        tch::Tensor::randint(1 << 32, [1], (tch::Kind::Int64, tch::Device::Cpu)).int64_value(&[0])
+            as u32
+    }
 }
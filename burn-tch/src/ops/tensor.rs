@@ -1,5 +1,7 @@
 use super::TchOps;
-use crate::{element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor};
+use crate::{
+    check_deterministic, element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor,
+};
 use burn_tensor::{
     backend::Backend, ops::TensorOps, Data, Distribution, ElementConversion, Reader, Shape,
 };
@@ -82,11 +84,7 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
     fn into_data<const D: usize>(
         tensor: <LibTorch<E> as Backend>::TensorPrimitive<D>,
     ) -> Reader<Data<<LibTorch<E> as Backend>::FloatElem, D>> {
-        let shape = Self::shape(&tensor);
-        let tensor = Self::reshape(tensor.clone(), Shape::new([shape.num_elements()]));
-        let values: Result<Vec<E>, tch::TchError> = tensor.tensor.try_into();
-
-        Reader::Concrete(Data::new(values.unwrap(), shape))
+        TchOps::into_data(tensor)
     }
 
     fn device<const D: usize>(tensor: &TchTensor<E, D>) -> LibTorchDevice {
@@ -203,6 +201,7 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         indices: TchTensor<i64, D>,
         value: TchTensor<E, D>,
     ) -> TchTensor<E, D> {
+        check_deterministic("scatter", tensor.tensor.device());
         TchOps::scatter(dim, tensor, indices, value)
     }
 
@@ -220,6 +219,7 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         indices: TchTensor<i64, 1>,
         value: TchTensor<E, D>,
     ) -> TchTensor<E, D> {
+        check_deterministic("select_assign", tensor.tensor.device());
         TchOps::select_assign(tensor, dim, indices, value)
     }
 
@@ -323,6 +323,14 @@ impl<E: TchElement> TensorOps<Self> for LibTorch<E> {
         TchOps::sum_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchOps::cumprod(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<f32, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.to_kind(tch::Kind::Float);
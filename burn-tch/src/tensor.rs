@@ -278,4 +278,25 @@ mod tests {
 
         assert_ne!(tensor_3.to_data().value, tensor_1.to_data().value);
     }
+
+    #[test]
+    fn should_accumulate_half_precision_sum_in_full_precision() {
+        // Adding one to 2048 is rounded back to 2048 with a 16 bits accumulator.
+        let tensor = Tensor::<LibTorch<half::f16>, 2>::ones([2, 4096]);
+
+        let sum = tensor.clone().sum().into_data().convert::<f32>();
+        let sum_dim = tensor.sum_dim(1).into_data().convert::<f32>();
+
+        assert_eq!(sum.value, vec![8192.0]);
+        assert_eq!(sum_dim.value, vec![4096.0, 4096.0]);
+    }
+
+    #[test]
+    fn should_support_bf16_mean() {
+        let tensor = Tensor::<LibTorch<half::bf16>, 1>::from_floats([1.0, 2.0, 3.0, 6.0]);
+
+        let mean = tensor.mean().into_data().convert::<f32>();
+
+        assert_eq!(mean.value, vec![3.0]);
+    }
 }
@@ -18,4 +18,4 @@ mod tests;
 pub use half::{bf16, f16};
 pub use tensor::*;
 
-pub use burn_common::reader::Reader; // Useful so that backends don't have to add `burn_common` as
+pub use burn_common::reader::{Reader, SyncReader}; // Useful so that backends don't have to add `burn_common` as
@@ -10,10 +10,11 @@ use alloc::string::String;
 use alloc::vec;
 
 use burn_common::{reader::Reader, stub::Mutex};
-use core::{fmt::Debug, ops::Range};
+use core::{fmt::Debug, future::Future, ops::Range};
 
 use crate::{
-    backend::Backend, check, check::TensorCheck, Bool, Data, Float, Int, Shape, TensorKind,
+    backend::Backend, check, check::TensorCheck, check::TensorCheckError, ops::GridIndexing, Bool,
+    Data, ElementConversion, Float, Int, Shape, TensorKind,
 };
 
 /// A tensor with a given backend, shape and data type.
@@ -101,6 +102,21 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, shape))
     }
 
+    /// Reshape the tensor to have the given shape, returning an error instead of panicking when
+    /// the number of elements doesn't match.
+    ///
+    /// Unlike [reshape](Tensor::reshape), dimensions can't be inferred, the full shape is
+    /// required.
+    pub fn try_reshape<const D2: usize, S: Into<Shape<D2>>>(
+        self,
+        shape: S,
+    ) -> Result<Tensor<B, D2, K>, TensorCheckError> {
+        let shape = shape.into();
+        TensorCheck::reshape_args_usize(&shape, &self.shape()).into_result()?;
+
+        Ok(Tensor::new(K::reshape::<D, D2>(self.primitive, shape)))
+    }
+
     /// Transpose the tensor.
     ///
     /// # Arguments
@@ -129,6 +145,232 @@ where
         Tensor::new(K::swap_dims(self.primitive, dim1, dim2))
     }
 
+    /// Roll the tensor elements along the given dimensions.
+    ///
+    /// Elements shifted beyond the last position are re-introduced at the first position, so that
+    /// `output[(i + shift) % size] = input[i]` along each rolled dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The number of positions to shift along each dimension, can be negative.
+    /// * `dims` - The dimensions to roll.
+    ///
+    /// # Returns
+    ///
+    /// The rolled tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let rolled = tensor.roll(&[1], &[1]);
+    ///     println!("{}", rolled);
+    ///     // [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Tensor<B, D, K> {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+
+        let mut tensor = self;
+
+        for (&shift, &dim) in shifts.iter().zip(dims.iter()) {
+            let size = tensor.dims()[dim];
+            let shift = match size {
+                0 => 0,
+                _ => shift.rem_euclid(size as i64) as usize,
+            };
+
+            if shift == 0 {
+                continue;
+            }
+
+            let mut ranges = tensor.dims().map(|size| 0..size);
+            ranges[dim] = size - shift..size;
+            let tail = tensor.clone().slice(ranges.clone());
+            ranges[dim] = 0..size - shift;
+            let head = tensor.slice(ranges);
+
+            tensor = Tensor::cat(alloc::vec![tail, head], dim);
+        }
+
+        tensor
+    }
+
+    /// Creates coordinate grids from one coordinate tensor per dimension.
+    ///
+    /// With [matrix indexing](GridIndexing::Matrix), the `i`-th grid has the values of the `i`-th
+    /// coordinates repeated along every other dimension, so that
+    /// `grids[i][[j_0, j_1, ..., j_D]] = tensors[i][j_i]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::GridIndexing;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let x = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0]);
+    ///     let y = Tensor::<B, 1>::from_floats([4.0, 5.0]);
+    ///     let [grid_x, grid_y] = Tensor::meshgrid([x, y], GridIndexing::Cartesian);
+    ///     println!("{}", grid_x); // [[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]
+    ///     println!("{}", grid_y); // [[4.0, 4.0, 4.0], [5.0, 5.0, 5.0]]
+    /// }
+    /// ```
+    pub fn meshgrid(tensors: [Tensor<B, 1, K>; D], indexing: GridIndexing) -> [Self; D] {
+        let sizes = tensors
+            .iter()
+            .map(|tensor| tensor.dims()[0])
+            .collect::<Vec<_>>();
+
+        tensors
+            .into_iter()
+            .enumerate()
+            .map(|(dim, tensor)| {
+                let mut shape = [1; D];
+                shape[dim] = sizes[dim];
+                let mut grid = tensor.reshape(shape);
+
+                for (other, size) in sizes.iter().enumerate() {
+                    if other != dim {
+                        grid = grid.repeat(other, *size);
+                    }
+                }
+
+                match indexing {
+                    GridIndexing::Cartesian if D > 1 => grid.swap_dims(0, 1),
+                    _ => grid,
+                }
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Permute the dimensions of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - The new order of the dimensions, where `axes[i]` is the dimension of the
+    ///   current tensor that becomes dimension `i` of the output.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::ones([2, 3, 4]);
+    ///     let permuted = tensor.permute([2, 0, 1]);
+    ///     println!("{:?}", permuted.dims()); // [4, 2, 3]
+    /// }
+    /// ```
+    pub fn permute(self, axes: [usize; D]) -> Tensor<B, D, K> {
+        check!(TensorCheck::permute(&axes));
+
+        // Lowered to a sequence of dimension swaps, so every backend supports it.
+        let mut current: [usize; D] = core::array::from_fn(|i| i);
+        let mut tensor = self;
+
+        for (i, target) in axes.iter().enumerate() {
+            let position = current.iter().position(|axis| axis == target).unwrap();
+
+            if position != i {
+                tensor = tensor.swap_dims(i, position);
+                current.swap(i, position);
+            }
+        }
+
+        tensor
+    }
+
+    /// Returns a narrowed view of the tensor along the given dimension.
+    ///
+    /// The returned tensor contains the `length` elements starting at `start` on the dimension
+    /// `dim`, the other dimensions are unchanged. It is equivalent to [slice](Tensor::slice) with
+    /// a full range on every other dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bound or if `start + length` exceeds its size.
+    pub fn narrow(self, dim: usize, start: usize, length: usize) -> Self {
+        let dims = self.dims();
+        check!(TensorCheck::narrow::<D>(dim, start, length, &dims));
+
+        let mut ranges: [Range<usize>; D] = dims.map(|size| 0..size);
+        ranges[dim] = start..start + length;
+
+        Self::new(K::slice(self.primitive, ranges))
+    }
+
+    /// Splits the tensor into chunks of the same size along the given dimension.
+    ///
+    /// Each chunk has a size of `ceil(dim_size / chunks)`, the last one being smaller when the
+    /// dimension isn't divisible by the number of chunks. Fewer than `chunks` tensors can be
+    /// returned when the dimension is too small, e.g. 5 elements in 4 chunks returns 3 chunks of
+    /// sizes 2, 2 and 1.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bound or if the number of chunks is zero.
+    pub fn chunk(self, chunks: usize, dim: usize) -> Vec<Self> {
+        let dims = self.dims();
+        check!(TensorCheck::chunk::<D>(chunks, dim));
+
+        let size = dims[dim];
+        let chunk_size = usize::max((size + chunks - 1) / chunks, 1);
+        let sizes = (0..size)
+            .step_by(chunk_size)
+            .map(|start| usize::min(chunk_size, size - start))
+            .collect::<Vec<_>>();
+
+        self.split(&sizes, dim)
+    }
+
+    /// Splits the tensor along the given dimension into tensors of the given sizes.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bound or if the sizes don't sum to the size of the dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones([2, 7]);
+    ///     let parts = tensor.split(&[2, 5], 1);
+    ///     println!("{:?} {:?}", parts[0].dims(), parts[1].dims()); // [2, 2] [2, 5]
+    /// }
+    /// ```
+    pub fn split(self, sizes: &[usize], dim: usize) -> Vec<Self> {
+        let dims = self.dims();
+        check!(TensorCheck::split::<D>(sizes, dim, &dims));
+
+        let mut ranges: [Range<usize>; D] = dims.map(|size| 0..size);
+        let mut start = 0;
+
+        sizes
+            .iter()
+            .map(|size| {
+                ranges[dim] = start..start + size;
+                start += size;
+                Self::new(K::slice(self.primitive.clone(), ranges.clone()))
+            })
+            .collect()
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -340,6 +582,19 @@ where
         Self::new(K::slice(self.primitive, ranges))
     }
 
+    /// Returns a tensor containing the elements selected from the given ranges, or an error when
+    /// a range exceeds the number of elements on a dimension.
+    ///
+    /// See [slice](Tensor::slice).
+    pub fn try_slice<const D2: usize>(
+        self,
+        ranges: [core::ops::Range<usize>; D2],
+    ) -> Result<Self, TensorCheckError> {
+        TensorCheck::slice(&self.shape(), &ranges).into_result()?;
+
+        Ok(Self::new(K::slice(self.primitive, ranges)))
+    }
+
     /// Returns a copy of the current tensor with the selected elements changed to the new ones at
     /// the selected indices.
     ///
@@ -408,6 +663,72 @@ where
         Self::into_data(self.clone())
     }
 
+    /// Returns a future resolving to the data of the current tensor.
+    ///
+    /// The readback is started when this function is called, but the backend is only waited
+    /// for when the future is awaited. For instance, wgpu queues the copy of the buffer and
+    /// LibTorch starts a non blocking transfer from Cuda devices, so that metric computation and
+    /// logging can overlap with the computation running on the device.
+    pub fn into_data_async(self) -> impl Future<Output = Data<K::Elem, D>> {
+        let reader = K::into_data(self.primitive);
+
+        async move { reader.read_async().await }
+    }
+
+    /// Returns a future resolving to the data of the current tensor without taking ownership.
+    ///
+    /// See [into_data_async](Tensor::into_data_async).
+    pub fn to_data_async(&self) -> impl Future<Output = Data<K::Elem, D>> {
+        Self::into_data_async(self.clone())
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the indices of the non-zero elements, one int tensor per dimension.
+    ///
+    /// The element at position `i` of every returned tensor forms the coordinates of the `i`-th
+    /// non-zero element, in row-major order. For boolean tensors, the `true` elements are
+    /// considered non-zero. The result can be used with [index](Tensor::index)
+    /// to select those elements from another tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Data, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let mask = Tensor::<B, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+    ///     let indices = mask.nonzero();
+    ///     println!("{}", indices[0]); // [0, 1]
+    ///     println!("{}", indices[1]); // [0, 1]
+    /// }
+    /// ```
+    pub fn nonzero(self) -> Vec<Tensor<B, 1, Int>> {
+        let device = self.device();
+        nonzero_indices(K::nonzero_mask(self.primitive).into_data(), &device)
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns the indices of the non-zero elements, one int tensor per dimension.
+    pub async fn nonzero(self) -> Vec<Tensor<B, 1, Int>> {
+        let device = self.device();
+        nonzero_indices(K::nonzero_mask(self.primitive).into_data().await, &device)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the coordinates of the non-zero elements as a tensor of shape `[num_nonzero, D]`.
+    ///
+    /// Each row contains the coordinates of one non-zero element, in row-major order.
+    pub fn argwhere(self) -> Tensor<B, 2, Int> {
+        argwhere_from_indices(self.nonzero())
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns the coordinates of the non-zero elements as a tensor of shape `[num_nonzero, D]`.
+    pub async fn argwhere(self) -> Tensor<B, 2, Int> {
+        argwhere_from_indices(self.nonzero().await)
+    }
+
     /// Create a tensor from the given data.
     pub fn from_data<T>(data: T) -> Self
     where
@@ -457,6 +778,19 @@ where
         ))
     }
 
+    /// Concatenates all tensors into a new one along the given dimension, or returns an error
+    /// when the tensors are empty, on different devices or have incompatible shapes.
+    ///
+    /// See [cat](Tensor::cat).
+    pub fn try_cat(tensors: Vec<Self>, dim: usize) -> Result<Self, TensorCheckError> {
+        TensorCheck::cat(&tensors, dim).into_result()?;
+
+        Ok(Self::new(K::cat(
+            tensors.into_iter().map(|vector| vector.primitive).collect(),
+            dim,
+        )))
+    }
+
     /// Iterate over slices of tensors alongside a given dimension.
     ///
     /// # Panics
@@ -535,12 +869,37 @@ where
         }
     }
 
+    /// Returns the data to display, only keeping the edge items of the summarized dimensions so
+    /// that large tensors aren't entirely read from their device.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn display_data(&self, print_options: &PrintOptions, summarize: bool) -> Data<K::Elem, D> {
+        let edge_items = print_options.edge_items;
+        let mut tensor = self.clone();
+
+        if summarize {
+            for (dim, size) in self.dims().into_iter().enumerate() {
+                if size > 2 * edge_items {
+                    tensor = Tensor::cat(
+                        vec![
+                            tensor.clone().narrow(dim, 0, edge_items),
+                            tensor.narrow(dim, size - edge_items, edge_items),
+                        ],
+                        dim,
+                    );
+                }
+            }
+        }
+
+        tensor.into_data()
+    }
+
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
     fn fmt_inner_tensor(
         &self,
         acc: &mut String,
         depth: usize,
         multi_index: &mut [usize],
+        display: &DisplayState<'_, K::Elem, D>,
         range: (usize, usize),
     ) {
         let (start, end) = range;
@@ -549,11 +908,12 @@ where
                 acc.push_str(", ");
             }
             multi_index[depth] = i;
-            let range: [core::ops::Range<usize>; D] =
-                core::array::from_fn(|i| multi_index[i]..multi_index[i] + 1);
 
-            let elem = &self.clone().slice(range).into_data().value[0];
-            acc.push_str(&format!("{elem:?}"));
+            let elem = display.elem(&self.dims(), multi_index);
+            match display.precision {
+                Some(precision) => acc.push_str(&format!("{elem:.precision$?}")),
+                None => acc.push_str(&format!("{elem:?}")),
+            }
         }
     }
 
@@ -563,8 +923,7 @@ where
         acc: &mut String,
         depth: usize,
         multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
+        display: &DisplayState<'_, K::Elem, D>,
         range: (usize, usize),
     ) {
         let (start, end) = range;
@@ -575,7 +934,7 @@ where
             }
             acc.push('[');
             multi_index[depth] = i;
-            self.display_recursive(acc, depth + 1, multi_index, print_options, summarize);
+            self.display_recursive(acc, depth + 1, multi_index, display);
             acc.push(']');
         }
     }
@@ -592,16 +951,17 @@ where
     /// * `acc` - A mutable reference to a `String` used as an accumulator for the formatted output.
     /// * `depth` - The current depth of the tensor dimensions being processed.
     /// * `multi_index` - A mutable slice of `usize` representing the current indices in each dimension.
+    /// * `display` - The data read from the tensor along with the display options.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
     fn display_recursive(
         &self,
         acc: &mut String,
         depth: usize,
         multi_index: &mut [usize],
-        print_options: &PrintOptions,
-        summarize: bool,
+        display: &DisplayState<'_, K::Elem, D>,
     ) {
-        let edge_items = print_options.edge_items;
+        let edge_items = display.edge_items;
+        let summarize = display.summarize;
 
         if depth == 0 {
             acc.push('[');
@@ -611,30 +971,24 @@ where
             // if we are at the innermost dimension, just push its elements into the accumulator
             if summarize && self.dims()[depth] > 2 * edge_items {
                 // print the starting `edge_items` elements
-                self.fmt_inner_tensor(acc, depth, multi_index, (0, edge_items));
+                self.fmt_inner_tensor(acc, depth, multi_index, display, (0, edge_items));
                 acc.push_str(", ...");
                 // print the last `edge_items` elements
                 self.fmt_inner_tensor(
                     acc,
                     depth,
                     multi_index,
+                    display,
                     (self.dims()[depth] - edge_items, self.dims()[depth]),
                 );
             } else {
                 // print all the elements
-                self.fmt_inner_tensor(acc, depth, multi_index, (0, self.dims()[depth]));
+                self.fmt_inner_tensor(acc, depth, multi_index, display, (0, self.dims()[depth]));
             }
         } else {
             // otherwise, iterate through the current dimension and recursively display the inner tensors
             if summarize && self.dims()[depth] > 2 * edge_items {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, edge_items),
-                );
+                self.fmt_outer_tensor(acc, depth, multi_index, display, (0, edge_items));
 
                 acc.push(',');
                 Self::push_newline_indent(acc, depth + 1);
@@ -645,19 +999,11 @@ where
                     acc,
                     depth,
                     multi_index,
-                    print_options,
-                    summarize,
+                    display,
                     (self.dims()[depth] - edge_items, self.dims()[depth]),
                 );
             } else {
-                self.fmt_outer_tensor(
-                    acc,
-                    depth,
-                    multi_index,
-                    print_options,
-                    summarize,
-                    (0, self.dims()[depth]),
-                );
+                self.fmt_outer_tensor(acc, depth, multi_index, display, (0, self.dims()[depth]));
             }
         }
 
@@ -667,12 +1013,50 @@ where
     }
 }
 
+/// The data displayed for a tensor, where the summarized dimensions only contain their edge
+/// items.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+struct DisplayState<'a, E, const D: usize> {
+    data: &'a Data<E, D>,
+    edge_items: usize,
+    summarize: bool,
+    precision: Option<usize>,
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<'a, E, const D: usize> DisplayState<'a, E, D> {
+    /// Returns the element at the given index of the tensor with the given dimensions.
+    fn elem(&self, dims: &[usize; D], multi_index: &[usize]) -> &'a E {
+        let mut index = 0;
+
+        for dim in 0..D {
+            let mut position = multi_index[dim];
+            let mut size = dims[dim];
+
+            if self.summarize && size > 2 * self.edge_items {
+                if position >= size - self.edge_items {
+                    position -= size - 2 * self.edge_items;
+                }
+                size = 2 * self.edge_items;
+            }
+
+            index = index * size + position;
+        }
+
+        &self.data.value[index]
+    }
+}
+
 /// Options for Tensor pretty printing
 pub struct PrintOptions {
     /// number of elements to start summarizing tensor
     pub threshold: usize,
     /// number of starting elements and ending elements to display
     pub edge_items: usize,
+    /// number of digits displayed after the decimal point of floats, all of them when `None`.
+    ///
+    /// The precision of the formatter, e.g. `{:.2}`, takes priority over this option.
+    pub precision: Option<usize>,
 }
 
 static PRINT_OPTS: Mutex<PrintOptions> = Mutex::new(PrintOptions::const_default());
@@ -683,10 +1067,17 @@ impl PrintOptions {
         Self {
             threshold: 1000,
             edge_items: 3,
+            precision: None,
         }
     }
 }
 
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
 /// Set print options
 pub fn set_print_options(options: PrintOptions) {
     *PRINT_OPTS.lock().unwrap() = options
@@ -709,8 +1100,15 @@ where
             let mut acc = String::new();
             let mut multi_index = vec![0; D];
             let summarize = self.shape().num_elements() > po.threshold;
+            let data = self.display_data(&po, summarize);
+            let display = DisplayState {
+                data: &data,
+                edge_items: po.edge_items,
+                summarize,
+                precision: f.precision().or(po.precision),
+            };
 
-            self.display_recursive(&mut acc, 0, &mut multi_index, &po, summarize);
+            self.display_recursive(&mut acc, 0, &mut multi_index, &display);
 
             writeln!(f, "  data:")?;
             write!(f, "{acc}")?;
@@ -1056,6 +1454,27 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         rhs: Self::Primitive<D>,
     ) -> Tensor<B, D, Bool>;
 
+    /// Returns a boolean tensor indicating which elements are different from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor of booleans, `true` where the element is non-zero. Boolean tensors are
+    /// returned unchanged.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For finding the non-zero elements, users should prefer the [Tensor::nonzero](Tensor::nonzero)
+    /// function, which is more high-level and designed for public use.
+    fn nonzero_mask<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool>;
+
     /// Returns the name of the element type.
     fn elem_type_name() -> &'static str {
         core::any::type_name::<Self::Elem>()
@@ -1147,6 +1566,10 @@ impl<B: Backend> BasicOps<B> for Float {
     ) -> Tensor<B, D, Bool> {
         Tensor::new(B::equal(lhs, rhs))
     }
+
+    fn nonzero_mask<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        Tensor::new(B::bool_not(B::equal_elem(tensor, 0.elem())))
+    }
 }
 
 impl<B: Backend> BasicOps<B> for Int {
@@ -1234,6 +1657,10 @@ impl<B: Backend> BasicOps<B> for Int {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::int_cat(vectors, dim)
     }
+
+    fn nonzero_mask<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        Tensor::new(B::bool_not(B::int_equal_elem(tensor, 0.elem())))
+    }
 }
 
 impl<B: Backend> BasicOps<B> for Bool {
@@ -1321,6 +1748,51 @@ impl<B: Backend> BasicOps<B> for Bool {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::bool_cat(vectors, dim)
     }
+
+    fn nonzero_mask<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        Tensor::new(tensor)
+    }
+}
+
+fn nonzero_indices<B: Backend, const D: usize>(
+    data: Data<bool, D>,
+    device: &B::Device,
+) -> Vec<Tensor<B, 1, Int>> {
+    let mut indices = (0..D).map(|_| Vec::new()).collect::<Vec<Vec<i64>>>();
+
+    for (position, value) in data.value.iter().enumerate() {
+        if !value {
+            continue;
+        }
+
+        let mut remainder = position;
+        for dim in (0..D).rev() {
+            let size = data.shape.dims[dim];
+            indices[dim].push((remainder % size) as i64);
+            remainder /= size;
+        }
+    }
+
+    indices
+        .into_iter()
+        .map(|values| {
+            let num_elements = values.len();
+            let data = Data::new(values, Shape::new([num_elements]));
+            Tensor::from_data_device(data.convert(), device)
+        })
+        .collect()
+}
+
+fn argwhere_from_indices<B: Backend>(indices: Vec<Tensor<B, 1, Int>>) -> Tensor<B, 2, Int> {
+    let columns = indices
+        .into_iter()
+        .map(|index| {
+            let num_elements = index.dims()[0];
+            index.reshape([num_elements, 1])
+        })
+        .collect();
+
+    Tensor::cat(columns, 1)
 }
 
 /// Trait used for reshape arguments.
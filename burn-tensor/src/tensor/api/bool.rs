@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Bool, Data, Int, Tensor};
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Data, Int, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
 where
@@ -28,4 +28,54 @@ where
     pub fn bool_not(self) -> Self {
         Tensor::new(B::bool_not(self.primitive))
     }
+
+    /// Inverses boolean values.
+    ///
+    /// Alias of [bool_not](Tensor::bool_not).
+    pub fn logical_not(self) -> Self {
+        self.bool_not()
+    }
+
+    /// Applies the element wise logical and between two boolean tensors.
+    pub fn logical_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalAnd", &self, &other));
+        self.int().mul(other.int()).greater_elem(0)
+    }
+
+    /// Applies the element wise logical or between two boolean tensors.
+    pub fn logical_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalOr", &self, &other));
+        self.int().add(other.int()).greater_elem(0)
+    }
+
+    /// Applies the element wise logical exclusive or between two boolean tensors.
+    pub fn logical_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalXor", &self, &other));
+        self.equal(other).bool_not()
+    }
+
+    /// Counts the number of `true` values along the given dimension.
+    ///
+    /// The dimension is kept with a size of one, like [sum_dim](Tensor::sum_dim).
+    pub fn count_nonzero(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::aggregate_dim::<D>("CountNonzero", dim));
+        self.int().sum_dim(dim)
+    }
+
+    /// Tests if any value is `true` along the given dimension.
+    ///
+    /// The dimension is kept with a size of one.
+    pub fn any(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Any", dim));
+        self.count_nonzero(dim).greater_elem(0)
+    }
+
+    /// Tests if all values are `true` along the given dimension.
+    ///
+    /// The dimension is kept with a size of one.
+    pub fn all(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("All", dim));
+        let size = self.dims()[dim];
+        self.count_nonzero(dim).equal_elem(size as i64)
+    }
 }
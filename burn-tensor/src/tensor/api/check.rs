@@ -1,4 +1,8 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{
+    backend::Backend,
+    ops::{AttentionDropout, PadMode, UnfoldOptions},
+    BasicOps, Norm, Shape, Tensor,
+};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -37,7 +41,42 @@ pub(crate) enum TensorCheck {
     Failed(FailedTensorCheck),
 }
 
+/// Error returned by the checked tensor operations, such as [try_matmul](Tensor::try_matmul),
+/// when their arguments are invalid.
+///
+/// The message is the same as the one of the panic raised by the unchecked operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorCheckError {
+    message: String,
+}
+
+impl TensorCheckError {
+    /// Returns the description of the invalid arguments.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl core::fmt::Display for TensorCheckError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TensorCheckError {}
+
 impl TensorCheck {
+    /// Converts the check into a result, for the checked operations that don't panic.
+    pub(crate) fn into_result(self) -> Result<(), TensorCheckError> {
+        match self {
+            Self::Ok => Ok(()),
+            Self::Failed(check) => Err(TensorCheckError {
+                message: check.format(),
+            }),
+        }
+    }
+
     /// Checks device and shape compatibility for element wise binary operations.
     pub(crate) fn binary_ops_ew<B: Backend, const D: usize, K: BasicOps<B>>(
         ops: &str,
@@ -184,80 +223,941 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn unsqueeze<const D1: usize, const D2: usize>() -> Self {
+    pub(crate) fn unsqueeze<const D1: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+        if D2 < D1 {
+            check = check.register(
+                "Unsqueeze",
+                TensorError::new(format!(
+                    "Can't unsqueeze smaller tensor, got dim {D2}, expected > {D1}"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn unsqueeze_dim<const D: usize>(dim: usize) -> Self {
+        let mut check = Self::Ok;
+        if dim > D {
+            check = check.register(
+                "Unsqueeze",
+                TensorError::new(format!(
+                    "Can't unsqueeze at dimension {}, exceeds tensor dimensions (D={})",
+                    dim, D
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim1 > D || dim2 > D {
+            check = check.register(
+                "Swap Dims",
+                TensorError::new("The swap dimensions must be smaller than the tensor dimension")
+                    .details(format!(
+                        "Swap dims ({dim1}, {dim2}) on tensor with ({D}) dimensions."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn permute<const D: usize>(axes: &[usize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, &axis) in axes.iter().enumerate() {
+            if axis >= D {
+                check = check.register(
+                    "Permute",
+                    TensorError::new("The permutation axes must be smaller than the tensor rank.")
+                        .details(format!("Axes {axes:?} on tensor with ({D}) dimensions.")),
+                );
+            } else if axes[..i].contains(&axis) {
+                check = check.register(
+                    "Permute",
+                    TensorError::new("The permutation axes must be unique.")
+                        .details(format!("Axis {axis} is duplicated in {axes:?}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(dims: &[usize]) -> Self {
+        Self::check_unique_dims::<D>(Self::Ok, "Flip", dims)
+    }
+
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let ops = "Roll";
+        let mut check = Self::check_unique_dims::<D>(Self::Ok, ops, dims);
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                ops,
+                TensorError::new("There should be one shift per rolled dimension.").details(
+                    format!(
+                        "Got ({}) shifts for ({}) dimensions.",
+                        shifts.len(),
+                        dims.len()
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn rot90<const D: usize>(dims: &[usize; 2]) -> Self {
+        Self::check_unique_dims::<D>(Self::Ok, "Rot90", dims)
+    }
+
+    fn check_unique_dims<const D: usize>(mut check: Self, ops: &str, dims: &[usize]) -> Self {
+        for (i, &dim) in dims.iter().enumerate() {
+            if dim >= D {
+                check = check.register(
+                    ops,
+                    TensorError::new("The dimensions must be smaller than the tensor rank.")
+                        .details(format!(
+                            "Dimensions {dims:?} on tensor with ({D}) dimensions."
+                        )),
+                );
+            } else if dims[..i].contains(&dim) {
+                check = check.register(
+                    ops,
+                    TensorError::new("The dimensions must be unique.")
+                        .details(format!("Dimension {dim} is duplicated in {dims:?}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn unfold<const D: usize, const D2: usize>(
+        dim: usize,
+        size: usize,
+        step: usize,
+        dims: &[usize; D],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D + 1 {
+            check = check.register(
+                "Unfold",
+                TensorError::new("The output rank must be the input rank plus one.")
+                    .details(format!("Input rank ({D}), output rank ({D2}).")),
+            );
+        }
+
+        if dim >= D {
+            check = check.register(
+                "Unfold",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+            return check;
+        }
+
+        if size == 0 || step == 0 {
+            check = check.register(
+                "Unfold",
+                TensorError::new("The window size and step must be greater than zero.")
+                    .details(format!("Size ({size}), step ({step}).")),
+            );
+        }
+
+        if size > dims[dim] {
+            check = check.register(
+                "Unfold",
+                TensorError::new("The window size can't be larger than the dimension.").details(
+                    format!(
+                        "Window size ({size}), dimension {dim} of size ({}).",
+                        dims[dim]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn narrow<const D: usize>(
+        dim: usize,
+        start: usize,
+        length: usize,
+        dims: &[usize; D],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check.register(
+                "Narrow",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        if start + length > dims[dim] {
+            check = check.register(
+                "Narrow",
+                TensorError::new("The narrowed range exceeds the size of the dimension.").details(
+                    format!(
+                        "Range ({start}..{}), dimension {dim} of size ({}).",
+                        start + length,
+                        dims[dim]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn chunk<const D: usize>(chunks: usize, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                "Chunk",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        if chunks == 0 {
+            check = check.register(
+                "Chunk",
+                TensorError::new("The number of chunks must be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn split<const D: usize>(sizes: &[usize], dim: usize, dims: &[usize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check.register(
+                "Split",
+                TensorError::new("Given dimension is higher than the tensor rank.")
+                    .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        let total: usize = sizes.iter().sum();
+
+        if total != dims[dim] {
+            check = check.register(
+                "Split",
+                TensorError::new("The split sizes must sum to the size of the dimension.").details(
+                    format!(
+                        "Sizes {sizes:?} sum to ({total}), dimension {dim} of size ({}).",
+                        dims[dim]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn one_hot<const D: usize, const D2: usize>(num_classes: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D + 1 {
+            check = check.register(
+                "OneHot",
+                TensorError::new("The output rank must be the input rank plus one.")
+                    .details(format!("Input rank ({D}), output rank ({D2}).")),
+            );
+        }
+
+        if num_classes == 0 {
+            check = check.register(
+                "OneHot",
+                TensorError::new("The number of classes must be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn cross<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>, dim: usize) -> Self {
+        let ops = "Cross";
+        let mut check = Self::dim_ops::<D>(ops, dim).binary_ops_ew_shape(ops, lhs, rhs);
+
+        if dim < D && lhs.dims[dim] != 3 {
+            check = check.register(
+                ops,
+                TensorError::new("The cross product dimension must have a size of 3.")
+                    .details(format!("Dimension {dim} has a size of {}.", lhs.dims[dim])),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn tensordot<const D1: usize, const D2: usize, const D3: usize>(
+        lhs: &Shape<D1>,
+        rhs: &Shape<D2>,
+        lhs_dims: &[usize],
+        rhs_dims: &[usize],
+    ) -> Self {
+        let ops = "Tensordot";
+        let mut check = Self::Ok;
+
+        if lhs_dims.len() != rhs_dims.len() {
+            check = check.register(
+                ops,
+                TensorError::new("The same number of dimensions must be contracted on both sides.")
+                    .details(format!(
+                        "Lhs dimensions {lhs_dims:?}, rhs dimensions {rhs_dims:?}."
+                    )),
+            );
+            return check;
+        }
+
+        for (dims, rank) in [(lhs_dims, D1), (rhs_dims, D2)] {
+            for (i, dim) in dims.iter().enumerate() {
+                if *dim >= rank {
+                    check = check.register(
+                        ops,
+                        TensorError::new("Given dimension is higher than the tensor rank.")
+                            .details(format!("Dimension {dim}, tensor rank {rank}.")),
+                    );
+                    return check;
+                }
+
+                if dims[..i].contains(dim) {
+                    check = check.register(
+                        ops,
+                        TensorError::new("The contracted dimensions must be unique.")
+                            .details(format!("Dimension {dim} is repeated in {dims:?}.")),
+                    );
+                }
+            }
+        }
+
+        for (lhs_dim, rhs_dim) in lhs_dims.iter().zip(rhs_dims) {
+            if lhs.dims[*lhs_dim] != rhs.dims[*rhs_dim] {
+                check = check.register(
+                    ops,
+                    TensorError::new("The contracted dimensions must have the same size.")
+                        .details(format!(
+                            "Lhs dimension {lhs_dim} has a size of {}, rhs dimension {rhs_dim} has a size of {}.",
+                            lhs.dims[*lhs_dim], rhs.dims[*rhs_dim]
+                        )),
+                );
+            }
+        }
+
+        let free = D1 + D2 - 2 * lhs_dims.len();
+        if D3 != free.max(1) {
+            check = check.register(
+                ops,
+                TensorError::new("The output rank must match the number of free dimensions.")
+                    .details(format!(
+                        "Expected an output rank of {}, got {D3}.",
+                        free.max(1)
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial(
+        num_samples: usize,
+        num_categories: usize,
+        replacement: bool,
+    ) -> Self {
+        let ops = "Multinomial";
+        let mut check = Self::Ok;
+
+        if num_samples == 0 || num_categories == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The number of samples and categories must be greater than zero.")
+                    .details(format!(
+                        "Number of samples {num_samples}, number of categories {num_categories}."
+                    )),
+            );
+        }
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                ops,
+                TensorError::new("Can't sample more indices than categories without replacement.")
+                    .details(format!(
+                        "Number of samples {num_samples}, number of categories {num_categories}."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn norm<const D: usize>(norm: Norm, dim: usize) -> Self {
+        let ops = "Norm";
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if norm == Norm::Lp(0.0) {
+            check = check.register(
+                ops,
+                TensorError::new("The order of the norm must not be zero.")
+                    .details("Use Norm::L1, Norm::L2 or a non-zero order with Norm::Lp."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn diagonal<const D1: usize, const D2: usize>(
+        shape: &Shape<D1>,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self {
+        let ops = "Diagonal";
+        let mut check = Self::check_unique_dims::<D1>(Self::Ok, ops, &[dim1, dim2]);
+
+        if D2 + 1 != D1 {
+            check = check.register(
+                ops,
+                TensorError::new("The output rank must be one less than the input rank.")
+                    .details(format!("Input rank {D1}, output rank {D2}.")),
+            );
+        }
+
+        if dim1 < D1 && dim2 < D1 {
+            let (rows, cols) = (shape.dims[dim1] as i64, shape.dims[dim2] as i64);
+
+            if offset >= cols || -offset >= rows {
+                check = check.register(
+                    ops,
+                    TensorError::new("The offset must select a non-empty diagonal.")
+                        .details(format!("Offset {offset}, matrices of size {rows}x{cols}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn diag_embed<const D1: usize, const D2: usize>(dim1: usize, dim2: usize) -> Self {
+        let ops = "DiagEmbed";
+        let mut check = Self::check_unique_dims::<D2>(Self::Ok, ops, &[dim1, dim2]);
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                ops,
+                TensorError::new("The output rank must be one more than the input rank.")
+                    .details(format!("Input rank {D1}, output rank {D2}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn attention(
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_value: &Shape<4>,
+        shape_mask: Option<&Shape<3>>,
+        dropout: Option<&AttentionDropout>,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [batch_size, n_heads, seq_length_1, d_k] = shape_query.dims;
+        let [_, _, seq_length_2, _] = shape_key.dims;
+
+        if shape_key.dims != [batch_size, n_heads, seq_length_2, d_k]
+            || shape_value.dims[..3] != shape_key.dims[..3]
+        {
+            check = check.register(
+                "Attention",
+                TensorError::new(
+                    "The keys and values should have the same batch size, number of heads and sequence length, and the keys the same size as the queries.",
+                )
+                .details(format!(
+                    "Query shape {:?}, key shape {:?}, value shape {:?}.",
+                    shape_query.dims, shape_key.dims, shape_value.dims
+                )),
+            );
+        }
+
+        if let Some(shape_mask) = shape_mask {
+            if shape_mask.dims != [batch_size, seq_length_1, seq_length_2] {
+                check = check.register(
+                    "Attention",
+                    TensorError::new(
+                        "The mask should have the shape [batch_size, seq_length_1, seq_length_2].",
+                    )
+                    .details(format!(
+                        "Query shape {:?}, key shape {:?}, mask shape {:?}.",
+                        shape_query.dims, shape_key.dims, shape_mask.dims
+                    )),
+                );
+            }
+        }
+
+        if let Some(dropout) = dropout {
+            if !(0.0..=1.0).contains(&dropout.prob) {
+                check = check.register(
+                    "Attention",
+                    TensorError::new("The dropout probability should be between 0 and 1.")
+                        .details(format!("Dropout probability {}.", dropout.prob)),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn grid_sample_2d(shape: &Shape<4>, shape_grid: &Shape<4>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_grid.dims[3] != 2 || shape_grid.dims[0] != shape.dims[0] {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new(
+                    "The grid should have the shape [batch_size, height_out, width_out, 2].",
+                )
+                .details(format!(
+                    "Input shape {:?}, grid shape {:?}.",
+                    shape.dims, shape_grid.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fold4d(
+        shape: &Shape<3>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: &UnfoldOptions,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [_batch_size, channels_blocks, num_blocks] = shape.dims;
+        let kernel_area = kernel_size[0] * kernel_size[1];
+
+        if kernel_area == 0 || options.stride.contains(&0) || options.dilation.contains(&0) {
+            check = check.register(
+                "Fold4d",
+                TensorError::new("The kernel size, stride and dilation must be greater than zero.")
+                    .details(format!(
+                        "Kernel size {:?}, stride {:?}, dilation {:?}.",
+                        kernel_size, options.stride, options.dilation
+                    )),
+            );
+            return check;
+        }
+
+        if channels_blocks % kernel_area != 0 {
+            check = check.register(
+                "Fold4d",
+                TensorError::new(
+                    "The number of channels must be divisible by the number of elements of the kernel.",
+                )
+                .details(format!(
+                    "Input shape {:?}, kernel size {:?}.",
+                    shape.dims, kernel_size
+                )),
+            );
+        }
+
+        let mut expected_blocks = 1;
+        for i in 0..2 {
+            let padded = output_size[i] + 2 * options.padding[i];
+            let kernel_extent = options.dilation[i] * (kernel_size[i] - 1) + 1;
+
+            if kernel_extent > padded {
+                check = check.register(
+                    "Fold4d",
+                    TensorError::new("The dilated kernel must fit in the padded output.").details(
+                        format!(
+                            "Output size {:?}, kernel size {:?}, padding {:?}, dilation {:?}.",
+                            output_size, kernel_size, options.padding, options.dilation
+                        ),
+                    ),
+                );
+                return check;
+            }
+
+            expected_blocks *= (padded - kernel_extent) / options.stride[i] + 1;
+        }
+
+        if num_blocks != expected_blocks {
+            check = check.register(
+                "Fold4d",
+                TensorError::new(
+                    "The number of blocks must match the output size, kernel size, stride, padding and dilation.",
+                )
+                .details(format!(
+                    "Number of blocks ({num_blocks}), expected ({expected_blocks}) for the output size {:?}, kernel size {:?} and options {:?}.",
+                    output_size, kernel_size, options
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<const D2: usize>(
+        inputs: &[Vec<char>],
+        output: &[char],
+        shapes: &[Vec<usize>],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shapes.is_empty() || shapes.len() > 2 || inputs.len() > 2 {
+            check = check.register(
+                "Einsum",
+                TensorError::new("Only one or two operands are supported.").details(format!(
+                    "Got {} equation operands and {} tensors.",
+                    inputs.len(),
+                    shapes.len()
+                )),
+            );
+            return check;
+        }
+
+        if inputs.len() != shapes.len() {
+            check = check.register(
+                "Einsum",
+                TensorError::new(
+                    "The number of operands in the equation must match the number of tensors.",
+                )
+                .details(format!(
+                    "Equation operands: {}, tensors: {}.",
+                    inputs.len(),
+                    shapes.len()
+                )),
+            );
+            return check;
+        }
+
+        for (i, labels) in inputs.iter().enumerate() {
+            if labels.iter().any(|label| !label.is_ascii_alphabetic()) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("Labels must be ASCII letters, ellipsis is not supported.")
+                        .details(format!("Operand {i} has labels {labels:?}.")),
+                );
+            }
+
+            let rank = shapes[i].len();
+            if labels.len() != rank {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("Each operand must have one label per dimension.").details(
+                        format!(
+                            "Operand {i} has {} labels, but its tensor has ({rank}) dimensions.",
+                            labels.len()
+                        ),
+                    ),
+                );
+            }
+
+            for (j, label) in labels.iter().enumerate() {
+                if labels[..j].contains(label) {
+                    check = check.register(
+                        "Einsum",
+                        TensorError::new("Repeated labels within an operand are not supported.")
+                            .details(format!("Label '{label}' is repeated in operand {i}.")),
+                    );
+                }
+            }
+        }
+
+        for (j, label) in output.iter().enumerate() {
+            if output[..j].contains(label) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("Output labels must be unique.")
+                        .details(format!("Label '{label}' is repeated in the output.")),
+                );
+            }
+
+            if !inputs.iter().any(|labels| labels.contains(label)) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("Output labels must appear in at least one operand.")
+                        .details(format!("Label '{label}' is not part of any operand.")),
+                );
+            }
+        }
+
+        let expected_rank = usize::max(output.len(), 1);
+        if expected_rank != D2 {
+            check = check.register(
+                "Einsum",
+                TensorError::new("The number of output labels must match the output rank.")
+                    .details(format!(
+                        "Output labels {output:?}, output tensor with ({D2}) dimensions."
+                    )),
+            );
+        }
+
+        if let TensorCheck::Failed(_) = check {
+            return check;
+        }
+
+        if let [lhs, rhs] = inputs {
+            for (i, label) in lhs.iter().enumerate() {
+                if let Some(j) = rhs.iter().position(|l| l == label) {
+                    let (size_lhs, size_rhs) = (shapes[0][i], shapes[1][j]);
+
+                    if size_lhs != size_rhs {
+                        check = check.register(
+                            "Einsum",
+                            TensorError::new("Sizes of a shared label must match.").details(
+                                format!("Label '{label}' has sizes {size_lhs} and {size_rhs}."),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn matmul<B: Backend, const D: usize>(
+        lhs: &Tensor<B, D>,
+        rhs: &Tensor<B, D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        check = check.binary_ops_device("Matmul", &lhs.device(), &rhs.device());
+
+        if D < 2 {
+            return check;
+        }
+
+        let shape_lhs = lhs.shape();
+        let shape_rhs = rhs.shape();
+
+        let dim_lhs = shape_lhs.dims[D - 1];
+        let dim_rhs = shape_rhs.dims[D - 2];
+
+        if dim_lhs != dim_rhs {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The inner dimension of matmul should be the same, but got {dim_lhs} and {dim_rhs}."
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The tensor should have at least 2 dimensions, but got ({D})."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn square_matrix<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let mut check = Self::matrix::<D>(ops);
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape.dims[D - 1] != shape.dims[D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The last two dimensions should form square matrices.")
+                    .details(format!("Got tensor with shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn solve<const D: usize>(
+        ops: &str,
+        shape_lhs: &Shape<D>,
+        shape_rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::square_matrix(ops, shape_lhs);
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_lhs.dims[..D - 1] != shape_rhs.dims[..D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The right hand side should have the same batch dimensions and number of \
+                     rows as the matrices.",
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bincount(length: usize, shape: &Shape<1>, shape_weights: &Shape<1>) -> Self {
+        let ops = "Bincount";
+        let mut check = Self::Ok;
+
+        if length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The number of bins must be greater than zero."),
+            );
+        }
+
+        if shape != shape_weights {
+            check = check.register(
+                ops,
+                TensorError::new("The weights should have the same shape as the tensor.").details(
+                    format!(
+                        "Tensor shape {:?}, weights shape {:?}.",
+                        shape.dims, shape_weights.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histc(bins: usize, min: f64, max: f64) -> Self {
+        let ops = "Histc";
         let mut check = Self::Ok;
-        if D2 < D1 {
+
+        if bins == 0 {
             check = check.register(
-                "Unsqueeze",
-                TensorError::new(format!(
-                    "Can't unsqueeze smaller tensor, got dim {D2}, expected > {D1}"
-                )),
+                ops,
+                TensorError::new("The number of bins must be greater than zero."),
+            );
+        }
+
+        if min >= max {
+            check = check.register(
+                ops,
+                TensorError::new("The lower bound must be smaller than the upper bound.")
+                    .details(format!("Lower bound {min}, upper bound {max}.")),
             );
         }
 
         check
     }
 
-    pub(crate) fn unsqueeze_dim<const D: usize>(dim: usize) -> Self {
+    pub(crate) fn complex_parts<const D: usize>(
+        ops: &str,
+        shape_real: &Shape<D>,
+        shape_imag: &Shape<D>,
+    ) -> Self {
         let mut check = Self::Ok;
-        if dim > D {
+
+        if shape_real != shape_imag {
             check = check.register(
-                "Unsqueeze",
-                TensorError::new(format!(
-                    "Can't unsqueeze at dimension {}, exceeds tensor dimensions (D={})",
-                    dim, D
-                )),
+                ops,
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Real shape {:?}, imaginary shape {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    )),
             );
         }
 
         check
     }
 
-    pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
+    pub(crate) fn irfft(size: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim1 > D || dim2 > D {
+        if size == 0 {
             check = check.register(
-                "Swap Dims",
-                TensorError::new("The swap dimensions must be smaller than the tensor dimension")
-                    .details(format!(
-                        "Swap dims ({dim1}, {dim2}) on tensor with ({D}) dimensions."
-                    )),
+                "IRFFT",
+                TensorError::new("The output size must be greater than zero."),
             );
         }
 
         check
     }
 
-    pub(crate) fn matmul<B: Backend, const D: usize>(
-        lhs: &Tensor<B, D>,
-        rhs: &Tensor<B, D>,
+    pub(crate) fn stft(
+        shape: &Shape<2>,
+        n_fft: usize,
+        hop_length: usize,
+        window_size: Option<usize>,
+        center: bool,
     ) -> Self {
+        let ops = "STFT";
         let mut check = Self::Ok;
+        let num_samples = shape.dims[1];
 
-        check = check.binary_ops_device("Matmul", &lhs.device(), &rhs.device());
-
-        if D < 2 {
-            return check;
+        if n_fft == 0 || hop_length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The frame size and the hop length must be greater than zero.")
+                    .details(format!("Frame size ({n_fft}), hop length ({hop_length}).")),
+            );
         }
 
-        let shape_lhs = lhs.shape();
-        let shape_rhs = rhs.shape();
-
-        let dim_lhs = shape_lhs.dims[D - 1];
-        let dim_rhs = shape_rhs.dims[D - 2];
+        if let Some(window_size) = window_size {
+            if window_size != n_fft {
+                check = check.register(
+                    ops,
+                    TensorError::new("The window size should be equal to the frame size.").details(
+                        format!("Window size ({window_size}), frame size ({n_fft})."),
+                    ),
+                );
+            }
+        }
 
-        if dim_lhs != dim_rhs {
+        if center && n_fft / 2 >= num_samples {
             check = check.register(
-                "Matmul",
-                TensorError::new(format!(
-                    "The inner dimension of matmul should be the same, but got {dim_lhs} and {dim_rhs}."
-                ))
+                ops,
+                TensorError::new(
+                    "The signals should be longer than half the frame size to be centered with \
+                     reflected samples.",
+                )
                 .details(format!(
-                    "Lhs shape {:?}, rhs shape {:?}.",
-                    shape_lhs.dims, shape_rhs.dims
+                    "Frame size ({n_fft}), number of samples ({num_samples})."
                 )),
             );
+        } else if !center && n_fft > num_samples {
+            check = check.register(
+                ops,
+                TensorError::new("The signals should be at least as long as the frame size.")
+                    .details(format!(
+                        "Frame size ({n_fft}), number of samples ({num_samples})."
+                    )),
+            );
         }
 
         check
@@ -489,6 +1389,145 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        pads: &[(usize, usize); D],
+        mode: &PadMode,
+    ) -> Self {
+        let ops = "Pad";
+        let mut check = Self::Ok;
+
+        for (dim, (before, after)) in pads.iter().enumerate() {
+            let size = shape.dims[dim];
+            let padding = usize::max(*before, *after);
+
+            if padding == 0 || matches!(mode, PadMode::Constant(_)) {
+                continue;
+            }
+
+            if size == 0 {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "Can't apply {mode:?} padding on the empty dimension ({dim})."
+                    )),
+                );
+            } else if matches!(mode, PadMode::Reflect) && padding >= size {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Reflect padding should be smaller than the size of the dimension."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "Got padding ({padding}) on dimension ({dim}) of size ({size})."
+                    )),
+                );
+            } else if matches!(mode, PadMode::Circular) && padding > size {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Circular padding should not be greater than the size of the dimension."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "Got padding ({padding}) on dimension ({dim}) of size ({size})."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn masked_select<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "MaskedSelect",
+                TensorError::new("The mask should have the same shape as the tensor.".to_string())
+                    .details(format!(
+                        "The shape differs: {:?} != {:?}",
+                        shape.dims, shape_mask.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn masked_scatter(num_selected: usize, num_elements_source: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if num_elements_source < num_selected {
+            check = check.register(
+                "MaskedScatter",
+                TensorError::new(
+                    "The source tensor should have at least as many elements as there are true \
+                     values in the mask."
+                        .to_string(),
+                )
+                .details(format!(
+                    "The mask selects {num_selected} elements, but the source tensor only has \
+                     {num_elements_source} elements."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn index(shapes_indices: &[Shape<1>]) -> Self {
+        Self::check_index_basic(Self::Ok, "Index", shapes_indices)
+    }
+
+    pub(crate) fn index_assign(shapes_indices: &[Shape<1>], shape_values: &Shape<1>) -> Self {
+        let ops = "IndexAssign";
+        let mut check = Self::check_index_basic(Self::Ok, ops, shapes_indices);
+
+        if let Some(shape_indices) = shapes_indices.first() {
+            if shape_indices != shape_values {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Indices tensors should have the same number of elements as the value \
+                         tensor."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "The shape differs: {:?} != {:?}",
+                        shape_indices.dims, shape_values.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    fn check_index_basic(mut check: Self, ops: &str, shapes_indices: &[Shape<1>]) -> Self {
+        if let Some(first) = shapes_indices.first() {
+            if shapes_indices.iter().any(|shape| shape != first) {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Indices tensors should all have the same number of elements.".to_string(),
+                    )
+                    .details(format!(
+                        "Got indices tensors with shapes {:?}.",
+                        shapes_indices
+                            .iter()
+                            .map(|shape| shape.dims[0])
+                            .collect::<Vec<_>>()
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -577,6 +1616,170 @@ impl TensorCheck {
     }
 
     /// Checks if shapes are compatible for element wise operations supporting broadcasting.
+    pub(crate) fn labeled_names<const D: usize>(ops: &str, names: &[&str; D]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, name) in names.iter().enumerate() {
+            if names[..i].contains(name) {
+                check = check.register(
+                    ops,
+                    TensorError::new("Each dimension should have a different name.").details(
+                        format!("The name '{name}' is used more than once in {names:?}."),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn labeled_dims<const D: usize>(
+        self,
+        ops: &str,
+        lhs: &[&str; D],
+        rhs: &[&str; D],
+    ) -> Self {
+        let mut check = self;
+
+        if lhs != rhs {
+            check = check.register(
+                ops,
+                TensorError::new("The provided tensors have different dimension names.").details(
+                    format!("Lhs tensor names {lhs:?}, Rhs tensor names {rhs:?}."),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn labeled_ew_shape<const D: usize>(
+        self,
+        ops: &str,
+        names: &[&str; D],
+        lhs: &Shape<D>,
+        rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = self;
+
+        for ((name, d_lhs), d_rhs) in names.iter().zip(lhs.dims).zip(rhs.dims) {
+            if d_lhs != d_rhs && d_lhs != 1 && d_rhs != 1 {
+                check = check.register(
+                    ops,
+                    TensorError::new("The provided tensors have incompatible shapes.").details(
+                        format!(
+                            "Incompatible size at dimension '{}' => '{} != {}', which can't be \
+                            broadcasted. Lhs tensor shape {:?}, Rhs tensor shape {:?}.",
+                            name, d_lhs, d_rhs, lhs.dims, rhs.dims,
+                        ),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn labeled_reshape<const D1: usize, const D2: usize>(
+        self,
+        names: &[&str; D1],
+        shape: &Shape<D1>,
+        target_names: &[&str; D2],
+        target_shape: &Shape<D2>,
+    ) -> Self {
+        let mut check = self;
+
+        for (i, name) in names.iter().enumerate() {
+            let target = match target_names.iter().position(|target| target == name) {
+                Some(target) => target,
+                None => continue,
+            };
+
+            if shape.dims[i] != target_shape.dims[target] {
+                check = check.register(
+                    "Reshape",
+                    TensorError::new("A dimension that keeps its name should keep its size.")
+                        .details(format!(
+                            "Dimension '{name}' has size {} but the target size is {}. \
+                            Current dims {names:?} with shape {:?}, target dims {target_names:?} \
+                            with shape {:?}.",
+                            shape.dims[i], target_shape.dims[target], shape.dims, target_shape.dims,
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn labeled_matmul<const D: usize>(
+        lhs_names: &[&str; D],
+        lhs_shape: &Shape<D>,
+        rhs_names: &[&str; D],
+        rhs_shape: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::matrix::<D>("Matmul");
+
+        if D < 2 {
+            return check;
+        }
+
+        let details = || {
+            format!(
+                "Lhs dims {lhs_names:?} with shape {:?}, rhs dims {rhs_names:?} with shape {:?}.",
+                lhs_shape.dims, rhs_shape.dims
+            )
+        };
+
+        let inner_lhs = lhs_names[D - 1];
+        let inner_rhs = rhs_names[D - 2];
+
+        if inner_lhs != inner_rhs {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The contracted dimensions should have the same name, but got '{inner_lhs}' \
+                    and '{inner_rhs}'."
+                ))
+                .details(details()),
+            );
+        } else if lhs_shape.dims[D - 1] != rhs_shape.dims[D - 2] {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The contracted dimension '{inner_lhs}' should have the same size, but got {} \
+                    and {}.",
+                    lhs_shape.dims[D - 1],
+                    rhs_shape.dims[D - 2]
+                ))
+                .details(details()),
+            );
+        }
+
+        if lhs_names[..D - 2] != rhs_names[..D - 2] {
+            check = check.register(
+                "Matmul",
+                TensorError::new("The batch dimensions should have the same names.")
+                    .details(details()),
+            );
+        }
+
+        let output = rhs_names[D - 1];
+
+        if lhs_names[..D - 1].contains(&output) {
+            check = check.register(
+                "Matmul",
+                TensorError::new(format!(
+                    "The output would have the dimension '{output}' twice, rename the dimensions \
+                    of one of the tensors first."
+                ))
+                .details(details()),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn binary_ops_ew_shape<const D: usize>(
         self,
         ops: &str,
@@ -0,0 +1,444 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, PI};
+use core::ops::Range;
+
+use burn_common::reader::Reader;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, BasicOps, Bool, Complex, Data, ElementConversion, Shape, Tensor};
+
+/// A complex number, the element type of [complex](Complex) tensors.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct ComplexElem<E> {
+    /// The real part.
+    pub re: E,
+    /// The imaginary part.
+    pub im: E,
+}
+
+impl<E> ComplexElem<E> {
+    /// Creates a complex number from its real and imaginary parts.
+    pub fn new(re: E, im: E) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<E: core::fmt::Debug> core::fmt::Debug for ComplexElem<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*?}{:+.*?}i", precision, self.re, precision, self.im),
+            None => write!(f, "{:?}{:+?}i", self.re, self.im),
+        }
+    }
+}
+
+/// The primitive of [complex](Complex) tensors, storing the real and imaginary parts in two
+/// float tensors of the same shape.
+pub struct ComplexPrimitive<B: Backend, const D: usize> {
+    /// The real part.
+    pub real: B::TensorPrimitive<D>,
+    /// The imaginary part.
+    pub imag: B::TensorPrimitive<D>,
+}
+
+impl<B: Backend, const D: usize> Clone for ComplexPrimitive<B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            real: self.real.clone(),
+            imag: self.imag.clone(),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> core::fmt::Debug for ComplexPrimitive<B, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComplexPrimitive")
+            .field("real", &self.real)
+            .field("imag", &self.imag)
+            .finish()
+    }
+}
+
+impl<B: Backend, const D: usize> ComplexPrimitive<B, D> {
+    fn map<const D2: usize, F>(self, func: F) -> ComplexPrimitive<B, D2>
+    where
+        F: Fn(B::TensorPrimitive<D>) -> B::TensorPrimitive<D2>,
+    {
+        ComplexPrimitive {
+            real: func(self.real),
+            imag: func(self.imag),
+        }
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the parts don't have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Complex, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let real = Tensor::<B, 1>::from_floats([3.0, 0.0]);
+    ///     let imag = Tensor::<B, 1>::from_floats([4.0, -1.0]);
+    ///     let tensor = Tensor::<B, 1, Complex>::from_parts(real, imag);
+    ///     println!("{}", tensor.clone().norm()); // [5.0, 1.0]
+    ///     println!("{}", tensor.conj()); // [3.0-4.0i, 0.0+1.0i]
+    /// }
+    /// ```
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex_parts(
+            "Complex",
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        Self::new(ComplexPrimitive {
+            real: real.into_primitive(),
+            imag: imag.into_primitive(),
+        })
+    }
+
+    /// Creates a complex tensor with the given real part and a zero imaginary part.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Creates a complex tensor from the absolute values and the angles of its elements.
+    pub fn from_polar(abs: Tensor<B, D>, angle: Tensor<B, D>) -> Self {
+        let real = abs.clone() * angle.clone().cos();
+        let imag = abs * angle.sin();
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Returns the real and imaginary parts.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (
+            Tensor::from_primitive(self.primitive.real),
+            Tensor::from_primitive(self.primitive.imag),
+        )
+    }
+
+    /// Returns the real part.
+    pub fn real(self) -> Tensor<B, D> {
+        Tensor::from_primitive(self.primitive.real)
+    }
+
+    /// Returns the imaginary part.
+    pub fn imag(self) -> Tensor<B, D> {
+        Tensor::from_primitive(self.primitive.imag)
+    }
+
+    /// Returns the complex conjugate, with the sign of the imaginary part flipped.
+    pub fn conj(self) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real, imag.neg())
+    }
+
+    /// Returns the absolute value, also called modulus, of each element.
+    pub fn norm(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+
+        (real.clone() * real + imag.clone() * imag).sqrt()
+    }
+
+    /// Returns the angle, also called argument, of each element in radians, in the range
+    /// `[-pi, pi]`.
+    ///
+    /// # Notes
+    ///
+    /// The arctangent is approximated with a polynomial written with tensor operations, which
+    /// keeps it differentiable on every backend, with an absolute error below `1e-5`.
+    pub fn angle(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+
+        atan2(imag, real)
+    }
+
+    // The arithmetic operations don't reuse the names of the numeric ones, which would conflict
+    // since `Numeric` could be implemented for `Complex` by another crate.
+
+    /// Applies element wise addition operation.
+    pub fn add_complex(self, other: Self) -> Self {
+        let (lhs_real, lhs_imag) = self.into_parts();
+        let (rhs_real, rhs_imag) = other.into_parts();
+
+        Self::from_parts(lhs_real + rhs_real, lhs_imag + rhs_imag)
+    }
+
+    /// Applies element wise subtraction operation.
+    pub fn sub_complex(self, other: Self) -> Self {
+        let (lhs_real, lhs_imag) = self.into_parts();
+        let (rhs_real, rhs_imag) = other.into_parts();
+
+        Self::from_parts(lhs_real - rhs_real, lhs_imag - rhs_imag)
+    }
+
+    /// Applies element wise multiplication operation.
+    pub fn mul_complex(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        // (a + ib)(c + id) = (ac - bd) + i(ad + bc)
+        Self::from_parts(a.clone() * c.clone() - b.clone() * d.clone(), a * d + b * c)
+    }
+
+    /// Applies element wise division operation.
+    pub fn div_complex(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        // (a + ib) / (c + id) = ((ac + bd) + i(bc - ad)) / (c^2 + d^2)
+        let denominator = c.clone() * c.clone() + d.clone() * d.clone();
+
+        Self::from_parts(
+            (a.clone() * c.clone() + b.clone() * d.clone()) / denominator.clone(),
+            (b * c - a * d) / denominator,
+        )
+    }
+
+    /// Switch sign of each element in the tensor.
+    pub fn neg_complex(self) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real.neg(), imag.neg())
+    }
+
+    /// Multiplies each element by the given real values.
+    pub fn mul_real(self, rhs: Tensor<B, D>) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real * rhs.clone(), imag * rhs)
+    }
+
+    /// Computes the discrete Fourier transform along the given dimension.
+    ///
+    /// See [fft](crate::fft::fft).
+    pub fn fft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("FFT", dim));
+
+        let primitive = self.primitive;
+        let (real, imag) = B::fft(primitive.real, primitive.imag, dim, false);
+
+        Self::new(ComplexPrimitive { real, imag })
+    }
+
+    /// Computes the inverse discrete Fourier transform along the given dimension.
+    ///
+    /// See [ifft](crate::fft::ifft).
+    pub fn ifft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("IFFT", dim));
+
+        let primitive = self.primitive;
+        let (real, imag) = B::fft(primitive.real, primitive.imag, dim, true);
+
+        Self::new(ComplexPrimitive { real, imag })
+    }
+
+    /// Computes the inverse of [rfft](Tensor::rfft) along the given dimension, the output
+    /// having the given size along that dimension.
+    ///
+    /// See [irfft](crate::fft::irfft).
+    pub fn irfft(self, dim: usize, size: usize) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+
+        crate::fft::irfft(real, imag, dim, size)
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Computes the discrete Fourier transform of the real values along the given dimension,
+    /// keeping only the non-negative frequencies.
+    ///
+    /// See [rfft](crate::fft::rfft).
+    pub fn rfft(self, dim: usize) -> Tensor<B, D, Complex> {
+        let (real, imag) = crate::fft::rfft(self, dim);
+
+        Tensor::from_parts(real, imag)
+    }
+}
+
+/// Computes the angle of the points `(x, y)`, approximating the arctangent of the ratio between
+/// the smallest and the largest coordinate with a polynomial.
+fn atan2<B: Backend, const D: usize>(y: Tensor<B, D>, x: Tensor<B, D>) -> Tensor<B, D> {
+    let abs_x = x.clone().abs();
+    let abs_y = y.clone().abs();
+    let swapped = abs_y.clone().greater(abs_x.clone());
+
+    let max = abs_x.clone().mask_where(swapped.clone(), abs_y.clone());
+    let min = abs_y.mask_where(swapped.clone(), abs_x);
+    let ratio = min / max.clone().mask_fill(max.equal_elem(0.0), 1.0);
+
+    let square = ratio.clone() * ratio.clone();
+    let mut angle = square
+        .clone()
+        .mul_scalar(-0.013480470)
+        .add_scalar(0.057477314);
+    for coefficient in [-0.121239071, 0.195635925, -0.332994597, 0.999995630] {
+        angle = (angle * square.clone()).add_scalar(coefficient);
+    }
+    let angle = angle * ratio;
+
+    let angle = angle
+        .clone()
+        .mask_where(swapped, angle.neg().add_scalar(FRAC_PI_2));
+    let angle = angle
+        .clone()
+        .mask_where(x.lower_elem(0.0), angle.neg().add_scalar(PI));
+
+    angle.clone().mask_where(y.lower_elem(0.0), angle.neg())
+}
+
+impl<B: Backend> BasicOps<B> for Complex {
+    type Elem = ComplexElem<B::FloatElem>;
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
+        ComplexPrimitive {
+            real: B::empty(shape.clone(), device),
+            imag: B::empty(shape, device),
+        }
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::shape(&tensor.real)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        tensor.map(|tensor| B::reshape(tensor, shape.clone()))
+    }
+
+    fn transpose<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        tensor.map(B::transpose)
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self::Primitive<D> {
+        check!(TensorCheck::swap_dims::<D>(dim1, dim2));
+        tensor.map(|tensor| B::swap_dims(tensor, dim1, dim2))
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+    ) -> Self::Primitive<D1> {
+        tensor.map(|tensor| B::slice(tensor, ranges.clone()))
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        value: Self::Primitive<D1>,
+    ) -> Self::Primitive<D1> {
+        ComplexPrimitive {
+            real: B::slice_assign(tensor.real, ranges.clone(), value.real),
+            imag: B::slice_assign(tensor.imag, ranges, value.imag),
+        }
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
+        B::device(&tensor.real)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        tensor.map(|tensor| B::to_device(tensor, device))
+    }
+
+    fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
+        let shape = B::shape(&tensor.real);
+        let num_elements = shape.num_elements();
+
+        // Both parts are read at once.
+        let flatten = |tensor| B::reshape(tensor, Shape::new([num_elements]));
+        let parts = B::cat(vec![flatten(tensor.real), flatten(tensor.imag)], 0);
+
+        B::into_data(parts).map(move |data| {
+            let (real, imag) = data.value.split_at(num_elements);
+            let value = real
+                .iter()
+                .zip(imag.iter())
+                .map(|(re, im)| ComplexElem::new(*re, *im))
+                .collect();
+
+            Data::new(value, shape)
+        })
+    }
+
+    fn from_data<const D: usize>(
+        data: Data<Self::Elem, D>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        let real = data.value.iter().map(|value| value.re).collect::<Vec<_>>();
+        let imag = data.value.iter().map(|value| value.im).collect::<Vec<_>>();
+
+        ComplexPrimitive {
+            real: B::from_data(Data::new(real, data.shape.clone()), device),
+            imag: B::from_data(Data::new(imag, data.shape), device),
+        }
+    }
+
+    fn repeat<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> Self::Primitive<D> {
+        tensor.map(|tensor| B::repeat(tensor, dim, times))
+    }
+
+    fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
+        let (real, imag) = vectors
+            .into_iter()
+            .map(|tensor| (tensor.real, tensor.imag))
+            .unzip();
+
+        ComplexPrimitive {
+            real: B::cat(real, dim),
+            imag: B::cat(imag, dim),
+        }
+    }
+
+    fn equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        let equal_real = B::bool_into_int(B::equal(lhs.real, rhs.real));
+        let equal_imag = B::bool_into_int(B::equal(lhs.imag, rhs.imag));
+
+        Tensor::new(B::int_equal_elem(
+            B::int_add(equal_real, equal_imag),
+            2.elem(),
+        ))
+    }
+
+    fn nonzero_mask<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        let sum = B::add(B::abs(tensor.real), B::abs(tensor.imag));
+
+        Tensor::new(B::bool_not(B::equal_elem(sum, 0.elem())))
+    }
+}
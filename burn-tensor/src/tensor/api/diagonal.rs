@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Element, Int, Numeric, Tensor};
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Returns the diagonal of the matrices formed by the dimensions `dim1` and `dim2`.
+    ///
+    /// This is the batched `diagonal(offset, dim1, dim2)` of other frameworks, named
+    /// `diagonal_dim` since [diagonal](Tensor::diagonal) creates a diagonal matrix.
+    ///
+    /// The two dimensions are removed and the diagonal is appended as the last dimension, so
+    /// `D2` must be equal to `D - 1`. A positive `offset` selects a diagonal above the main one,
+    /// a negative `offset` a diagonal below it.
+    ///
+    /// # Panics
+    ///
+    /// If the dimensions are out of bound or equal, or if the offset selects an empty diagonal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let main = tensor.clone().diagonal_dim::<1>(0, 0, 1);
+    ///     println!("{}", main); // [1.0, 5.0]
+    ///     let upper = tensor.diagonal_dim::<1>(1, 0, 1);
+    ///     println!("{}", upper); // [2.0, 6.0]
+    /// }
+    /// ```
+    pub fn diagonal_dim<const D2: usize>(
+        self,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diagonal::<D, D2>(
+            &self.shape(),
+            offset,
+            dim1,
+            dim2
+        ));
+
+        let dims = self.dims();
+        let device = self.device();
+        let (rows, cols) = (dims[dim1], dims[dim2]);
+        let batch = (0..D)
+            .filter(|dim| *dim != dim1 && *dim != dim2)
+            .collect::<Vec<_>>();
+
+        // The matrices are moved to the last dimensions and flattened, the diagonal then being
+        // a strided selection.
+        let mut axes = [0; D];
+        for (axis, dim) in batch.iter().chain([dim1, dim2].iter()).enumerate() {
+            axes[axis] = *dim;
+        }
+
+        let (length, indices) = diagonal_indices::<B>(rows, cols, offset, &device);
+        let mut shape = [length; D2];
+        for (axis, dim) in batch.iter().enumerate() {
+            shape[axis] = dims[*dim];
+        }
+        let batch_size = batch.iter().map(|dim| dims[*dim]).product::<usize>();
+
+        self.permute(axes)
+            .reshape([batch_size, rows * cols])
+            .select(1, indices)
+            .reshape(shape)
+    }
+
+    /// Creates a tensor whose matrices formed by the dimensions `dim1` and `dim2` have the last
+    /// dimension of the current tensor as diagonal, all other elements being zero.
+    ///
+    /// This is the inverse of [diagonal_dim](Tensor::diagonal_dim), so `D2` must be equal to `D + 1`.
+    /// The matrices are square, of size `n + |offset|` where `n` is the size of the last
+    /// dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimensions are out of bound or equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let embedded = tensor.diag_embed::<3>(0, 1, 2);
+    ///     println!("{}", embedded);
+    ///     // [[[1.0, 0.0], [0.0, 2.0]],
+    ///     //  [[3.0, 0.0], [0.0, 4.0]]]
+    /// }
+    /// ```
+    pub fn diag_embed<const D2: usize>(
+        self,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diag_embed::<D, D2>(dim1, dim2));
+
+        let dims = self.dims();
+        let device = self.device();
+        let length = dims[D - 1];
+        let size = length + offset.unsigned_abs() as usize;
+        let batch_size = dims[..D - 1].iter().product::<usize>();
+
+        let (_, indices) = diagonal_indices::<B>(size, size, offset, &device);
+        let embedded = Tensor::<B, 2, K>::zeros_device([batch_size, size * size], &device)
+            .select_assign(1, indices, self.reshape([batch_size, length]));
+
+        let mut shape = [size; D2];
+        shape[..D - 1].copy_from_slice(&dims[..D - 1]);
+
+        // The matrices are created in the last dimensions, then moved to the requested ones.
+        let mut batch = 0..D - 1;
+        let mut axes = [0; D2];
+        for (dim, axis) in axes.iter_mut().enumerate() {
+            *axis = match dim {
+                dim if dim == dim1 => D2 - 2,
+                dim if dim == dim2 => D2 - 1,
+                _ => batch.next().unwrap(),
+            };
+        }
+
+        embedded.reshape(shape).permute(axes)
+    }
+
+    /// Computes the sum of the main diagonal of the matrices formed by the last two dimensions.
+    ///
+    /// Like [sum_dim](Tensor::sum_dim), the reduced dimension is kept with a size of 1, so `D2`
+    /// must be equal to `D - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let trace = tensor.trace::<1>();
+    ///     println!("{}", trace); // [5.0]
+    /// }
+    /// ```
+    pub fn trace<const D2: usize>(self) -> Tensor<B, D2, K> {
+        self.diagonal_dim::<D2>(0, D - 2, D - 1).sum_dim(D2 - 1)
+    }
+}
+
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Creates a square matrix with the current tensor as its diagonal shifted by `offset`.
+    ///
+    /// See [diag_embed](Tensor::diag_embed) for the batched version.
+    pub fn diag(self, offset: i64) -> Tensor<B, 2, K> {
+        self.diag_embed::<2>(offset, 0, 1)
+    }
+}
+
+impl<B, K> Tensor<B, 2, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Returns the diagonal of the matrix shifted by `offset`.
+    ///
+    /// See [diagonal_dim](Tensor::diagonal_dim) for the batched version.
+    pub fn diag(self, offset: i64) -> Tensor<B, 1, K> {
+        self.diagonal_dim::<1>(offset, 0, 1)
+    }
+}
+
+/// Returns the length and the row-major linear indices of the diagonal shifted by `offset` in a
+/// matrix of the given size.
+fn diagonal_indices<B: Backend>(
+    rows: usize,
+    cols: usize,
+    offset: i64,
+    device: &B::Device,
+) -> (usize, Tensor<B, 1, Int>) {
+    let shift = offset.unsigned_abs() as usize;
+    let (start, length) = match offset >= 0 {
+        true => (shift, rows.min(cols.saturating_sub(shift))),
+        false => (shift * cols, rows.saturating_sub(shift).min(cols)),
+    };
+    let indices = Tensor::arange_step_device(start..start + length * (cols + 1), cols + 1, device);
+
+    (length, indices)
+}
@@ -0,0 +1,329 @@
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Shape, Tensor};
+
+/// Evaluates the Einstein summation convention on the given tensors of the same rank.
+///
+/// The equation lists the dimension labels of each input separated by commas, optionally
+/// followed by `->` and the labels of the output. When the output is omitted, it is made of
+/// every label appearing exactly once, in alphabetical order. Labels present in the inputs
+/// but absent from the output are summed over.
+///
+/// The equation is lowered to [permute](Tensor::permute), [reshape](Tensor::reshape),
+/// [sum_dim](Tensor::sum_dim) and [matmul](Tensor::matmul), so it works on every backend
+/// and is differentiable.
+///
+/// # Arguments
+///
+/// * `equation` - The einsum equation, e.g. `"bqhd,bkhd->bhqk"`.
+/// * `tensors` - One or two input tensors, each having one label per dimension. Use
+///   [einsum2](einsum2) when the two operands have different ranks.
+///
+/// # Returns
+///
+/// The resulting tensor. An equation with an empty output (a full reduction) returns a tensor
+/// of shape `[1]`.
+///
+/// # Panics
+///
+/// - If the equation is malformed or doesn't match the number or rank of the inputs.
+/// - If the number of output labels doesn't match the output rank.
+/// - If a label is repeated within an operand (diagonals are not supported).
+/// - If the sizes of a shared label don't match.
+/// - If the equation or the tensors have more than two operands.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let query = Tensor::<B, 4>::ones([2, 5, 4, 8]);
+///     let key = Tensor::<B, 4>::ones([2, 7, 4, 8]);
+///
+///     // Attention scores with shape [batch, heads, seq_q, seq_k].
+///     let scores: Tensor<B, 4> = einsum("bqhd,bkhd->bhqk", vec![query, key]);
+///     println!("{:?}", scores.dims()); // [2, 4, 5, 7]
+/// }
+/// ```
+pub fn einsum<B: Backend, const D: usize, const D2: usize>(
+    equation: &str,
+    tensors: Vec<Tensor<B, D>>,
+) -> Tensor<B, D2> {
+    let equation = EinsumEquation::parse(equation);
+    let shapes = tensors
+        .iter()
+        .map(|tensor| tensor.dims().to_vec())
+        .collect::<Vec<_>>();
+
+    check!(TensorCheck::einsum::<D2>(
+        &equation.inputs,
+        &equation.output,
+        &shapes
+    ));
+
+    let mut tensors = tensors.into_iter();
+
+    match (tensors.next(), tensors.next()) {
+        (Some(tensor), None) => einsum_unary(tensor, &equation.inputs[0], &equation.output),
+        (Some(lhs), Some(rhs)) => einsum_binary(
+            lhs,
+            rhs,
+            &equation.inputs[0],
+            &equation.inputs[1],
+            &equation.output,
+        ),
+        _ => unreachable!("Checked by TensorCheck::einsum"),
+    }
+}
+
+/// Evaluates the Einstein summation convention on two tensors, which can have different ranks.
+///
+/// See [einsum](einsum) for the format of the equation and the way it is evaluated.
+///
+/// # Arguments
+///
+/// * `equation` - The einsum equation with two operands, e.g. `"bij,jk->bik"`.
+/// * `lhs` - The tensor of the first operand, having one label per dimension.
+/// * `rhs` - The tensor of the second operand, having one label per dimension.
+///
+/// # Returns
+///
+/// The resulting tensor. An equation with an empty output (a full reduction) returns a tensor
+/// of shape `[1]`.
+///
+/// # Panics
+///
+/// - If the equation is malformed or doesn't have two operands matching the rank of the inputs.
+/// - If the number of output labels doesn't match the output rank.
+/// - If a label is repeated within an operand (diagonals are not supported).
+/// - If the sizes of a shared label don't match.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum2, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let matrix = Tensor::<B, 2>::ones([3, 4]);
+///     let vector = Tensor::<B, 1>::ones([4]);
+///
+///     // Matrix-vector product with shape [3].
+///     let output: Tensor<B, 1> = einsum2("ij,j->i", matrix, vector);
+///     println!("{:?}", output.dims()); // [3]
+/// }
+/// ```
+pub fn einsum2<B: Backend, const D1: usize, const D2: usize, const D3: usize>(
+    equation: &str,
+    lhs: Tensor<B, D1>,
+    rhs: Tensor<B, D2>,
+) -> Tensor<B, D3> {
+    let equation = EinsumEquation::parse(equation);
+
+    check!(TensorCheck::einsum::<D3>(
+        &equation.inputs,
+        &equation.output,
+        &[lhs.dims().to_vec(), rhs.dims().to_vec()]
+    ));
+
+    einsum_binary(
+        lhs,
+        rhs,
+        &equation.inputs[0],
+        &equation.inputs[1],
+        &equation.output,
+    )
+}
+
+struct EinsumEquation {
+    inputs: Vec<Vec<char>>,
+    output: Vec<char>,
+}
+
+impl EinsumEquation {
+    fn parse(equation: &str) -> Self {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<alloc::string::String>();
+
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs = inputs
+            .split(',')
+            .map(|labels| labels.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let output = match output {
+            Some(output) => output.chars().collect(),
+            None => {
+                let mut output = inputs
+                    .iter()
+                    .flatten()
+                    .filter(|&label| inputs.iter().flatten().filter(|l| *l == label).count() == 1)
+                    .copied()
+                    .collect::<Vec<_>>();
+                output.sort();
+                output
+            }
+        };
+
+        Self { inputs, output }
+    }
+}
+
+fn einsum_unary<B: Backend, const D: usize, const D2: usize>(
+    tensor: Tensor<B, D>,
+    labels: &[char],
+    output: &[char],
+) -> Tensor<B, D2> {
+    let dims = tensor.dims();
+    let mut tensor = tensor;
+
+    // Sum over the labels that are not part of the output, the summed dimensions are kept with a
+    // size of one and are moved at the end by the permutation.
+    let mut axes = Vec::with_capacity(D);
+    for (axis, label) in labels.iter().enumerate() {
+        if !output.contains(label) {
+            tensor = tensor.sum_dim(axis);
+        }
+    }
+    for label in output {
+        axes.push(position(labels, label));
+    }
+    for (axis, label) in labels.iter().enumerate() {
+        if !output.contains(label) {
+            axes.push(axis);
+        }
+    }
+
+    let tensor = tensor.permute(axes.try_into().unwrap());
+    let shape = output_shape::<D2>(output.iter().map(|label| dims[position(labels, label)]));
+
+    tensor.reshape(shape)
+}
+
+fn einsum_binary<B: Backend, const D1: usize, const D2: usize, const D3: usize>(
+    lhs: Tensor<B, D1>,
+    rhs: Tensor<B, D2>,
+    lhs_labels: &[char],
+    rhs_labels: &[char],
+    output: &[char],
+) -> Tensor<B, D3> {
+    let lhs_dims = lhs.dims();
+    let rhs_dims = rhs.dims();
+
+    let batch = lhs_labels
+        .iter()
+        .filter(|&label| rhs_labels.contains(label) && output.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+    let contracted = lhs_labels
+        .iter()
+        .filter(|&label| rhs_labels.contains(label) && !output.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+    let lhs_free = lhs_labels
+        .iter()
+        .filter(|&label| !rhs_labels.contains(label) && output.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+    let rhs_free = rhs_labels
+        .iter()
+        .filter(|&label| !lhs_labels.contains(label) && output.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let size = |labels: &[char], dims: &[usize], group: &[char]| -> usize {
+        group
+            .iter()
+            .map(|label| dims[position(labels, label)])
+            .product()
+    };
+    let num_batch = size(lhs_labels, &lhs_dims, &batch);
+    let num_contracted = size(lhs_labels, &lhs_dims, &contracted);
+    let num_lhs_free = size(lhs_labels, &lhs_dims, &lhs_free);
+    let num_rhs_free = size(rhs_labels, &rhs_dims, &rhs_free);
+
+    let lhs = reduce_and_permute(lhs, lhs_labels, &[&batch, &lhs_free, &contracted]);
+    let rhs = reduce_and_permute(rhs, rhs_labels, &[&batch, &contracted, &rhs_free]);
+
+    let lhs: Tensor<B, 3> = lhs.reshape([num_batch, num_lhs_free, num_contracted]);
+    let rhs: Tensor<B, 3> = rhs.reshape([num_batch, num_contracted, num_rhs_free]);
+    let result = lhs.matmul(rhs);
+
+    // The result labels are ordered as [batch, lhs_free, rhs_free].
+    let current = batch
+        .iter()
+        .chain(lhs_free.iter())
+        .chain(rhs_free.iter())
+        .copied()
+        .collect::<Vec<_>>();
+    let shape = output_shape::<D3>(
+        current
+            .iter()
+            .map(|label| match lhs_labels.contains(label) {
+                true => lhs_dims[position(lhs_labels, label)],
+                false => rhs_dims[position(rhs_labels, label)],
+            }),
+    );
+    let result: Tensor<B, D3> = result.reshape(shape);
+
+    if output.is_empty() {
+        return result;
+    }
+
+    let axes = output
+        .iter()
+        .map(|label| position(&current, label))
+        .collect::<Vec<_>>();
+
+    result.permute(axes.try_into().unwrap())
+}
+
+/// Sums the dimensions whose label isn't part of any group, then permutes the tensor so that
+/// the groups follow each other. The summed dimensions, now of size one, are moved at the end.
+fn reduce_and_permute<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    labels: &[char],
+    groups: &[&[char]],
+) -> Tensor<B, D> {
+    let mut tensor = tensor;
+    let mut axes = Vec::with_capacity(D);
+
+    for group in groups {
+        for label in group.iter() {
+            axes.push(position(labels, label));
+        }
+    }
+
+    for (axis, label) in labels.iter().enumerate() {
+        if !groups.iter().any(|group| group.contains(label)) {
+            tensor = tensor.sum_dim(axis);
+            axes.push(axis);
+        }
+    }
+
+    tensor.permute(axes.try_into().unwrap())
+}
+
+fn output_shape<const D2: usize>(dims: impl Iterator<Item = usize>) -> Shape<D2> {
+    let mut shape = [1; D2];
+
+    for (i, dim) in dims.enumerate() {
+        shape[i] = dim;
+    }
+
+    Shape::new(shape)
+}
+
+fn position(labels: &[char], label: &char) -> usize {
+    labels.iter().position(|l| l == label).unwrap()
+}
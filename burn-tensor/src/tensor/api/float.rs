@@ -1,8 +1,9 @@
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
+use super::int::bincount;
 use crate::check;
-use crate::check::TensorCheck;
+use crate::check::{TensorCheck, TensorCheckError};
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
@@ -126,6 +127,46 @@ where
         Tensor::new(B::into_int(self.primitive))
     }
 
+    /// Computes the histogram of the tensor elements, with `bins` bins of equal width between
+    /// `min` and `max`.
+    ///
+    /// Elements outside of `[min, max]` are ignored, and elements equal to `max` are counted in
+    /// the last bin. The histogram is computed on the device.
+    ///
+    /// # Panics
+    ///
+    /// If the number of bins is zero or if `min` isn't smaller than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.5, 1.5, 1.0, 4.0, 2.0]);
+    ///     let histogram = tensor.histc(4, 0.0, 2.0);
+    ///     println!("{}", histogram); // [0.0, 1.0, 1.0, 2.0]
+    /// }
+    /// ```
+    pub fn histc(self, bins: usize, min: f64, max: f64) -> Tensor<B, 1> {
+        check!(TensorCheck::histc(bins, min, max));
+
+        let num_elements = self.shape().num_elements();
+        let values = self.reshape([num_elements]);
+        let weights = values
+            .ones_like()
+            .mask_fill(values.clone().lower_elem(min), 0)
+            .mask_fill(values.clone().greater_elem(max), 0);
+        let indices = values
+            .sub_scalar(min)
+            .mul_scalar(bins as f64 / (max - min))
+            .clamp(0.0, bins as f64 - 1.0)
+            .int();
+
+        bincount(indices, weights, bins)
+    }
+
     /// Returns a new tensor with the same shape and device as the current tensor filled with zeros.
     pub fn zeros_like(&self) -> Self {
         Tensor::new(B::zeros(self.shape(), &self.device()))
@@ -180,6 +221,15 @@ where
         Self::new(B::matmul(self.primitive, other.primitive))
     }
 
+    /// Applies the matrix multiplication operation, returning an error instead of panicking when
+    /// the shapes are incompatible.
+    ///
+    /// See [matmul](Tensor::matmul).
+    pub fn try_matmul(self, other: Self) -> Result<Self, TensorCheckError> {
+        TensorCheck::matmul(&self, &other).into_result()?;
+        Ok(Self::new(B::matmul(self.primitive, other.primitive)))
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -1,4 +1,6 @@
-use crate::{backend::Backend, Data, Float, Int, Tensor};
+use crate::{
+    backend::Backend, check, check::TensorCheck, Data, Element, Float, Int, Numeric, Tensor,
+};
 use core::ops::Range;
 
 impl<B> Tensor<B, 1, Int>
@@ -43,6 +45,56 @@ where
     pub fn arange_step_device(range: Range<usize>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::arange_step(range, step, device))
     }
+
+    /// Counts the occurrences of each value in `[0, length)`.
+    ///
+    /// Values outside of that range are ignored. The counts are computed on the device, so the
+    /// number of bins has to be known in advance.
+    ///
+    /// # Panics
+    ///
+    /// If the length is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 1, 0, 7]);
+    ///     let counts = tensor.bincount(4);
+    ///     println!("{}", counts); // [1, 2, 0, 1]
+    /// }
+    /// ```
+    pub fn bincount(self, length: usize) -> Self {
+        let weights = Self::ones_device(self.shape(), &self.device());
+        check!(TensorCheck::bincount(
+            length,
+            &self.shape(),
+            &weights.shape()
+        ));
+
+        bincount(self, weights, length)
+    }
+
+    /// Sums the weights of each value in `[0, length)`.
+    ///
+    /// This is the same as [bincount](Tensor::bincount), each occurrence adding its weight instead
+    /// of one.
+    ///
+    /// # Panics
+    ///
+    /// If the length is zero or if the weights don't have the same shape as the tensor.
+    pub fn bincount_weighted(self, weights: Tensor<B, 1, Float>, length: usize) -> Tensor<B, 1> {
+        check!(TensorCheck::bincount(
+            length,
+            &self.shape(),
+            &weights.shape()
+        ));
+
+        bincount(self, weights, length)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -83,4 +135,67 @@ where
     pub fn float(self) -> Tensor<B, D, Float> {
         Tensor::new(B::int_into_float(self.primitive))
     }
+
+    /// Encodes the class indices of the tensor as one hot vectors.
+    ///
+    /// The output has an extra last dimension of size `num_classes`, containing a one at the
+    /// position of each index and zeros elsewhere. Indices outside of `[0, num_classes)` are
+    /// encoded as vectors of zeros. Use [float](Tensor::float) on the output to get float
+    /// targets.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `D2`: The rank of the output tensor, which must be `D + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let targets = Tensor::<B, 1, Int>::from_ints([2, 0]);
+    ///     let one_hot: Tensor<B, 2, Int> = targets.one_hot(3);
+    ///     println!("{}", one_hot.to_data());
+    ///     // [[0, 0, 1], [1, 0, 0]]
+    /// }
+    /// ```
+    pub fn one_hot<const D2: usize>(self, num_classes: usize) -> Tensor<B, D2, Int> {
+        check!(TensorCheck::one_hot::<D, D2>(num_classes));
+
+        let device = self.device();
+        let dims = self.dims();
+        let mut shape = [1; D2];
+        shape[..D].copy_from_slice(&dims);
+
+        let indices: Tensor<B, D2, Int> = self.reshape(shape);
+        let values = Tensor::ones_device(shape, &device)
+            .mask_fill(indices.clone().lower_elem(0), 0)
+            .mask_fill(indices.clone().greater_equal_elem(num_classes as i64), 0);
+        let indices = indices.clamp(0, num_classes as i64 - 1);
+
+        shape[D] = num_classes;
+        Tensor::zeros_device(shape, &device).scatter(D, indices, values)
+    }
+}
+
+/// Sums the weights of each index in `[0, length)` with a scatter, the weights of the indices
+/// outside of that range being zeroed.
+pub(crate) fn bincount<B, K>(
+    indices: Tensor<B, 1, Int>,
+    weights: Tensor<B, 1, K>,
+    length: usize,
+) -> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let device = indices.device();
+    let weights = weights
+        .mask_fill(indices.clone().lower_elem(0), 0)
+        .mask_fill(indices.clone().greater_equal_elem(length as i64), 0);
+    let indices = indices.clamp(0, length as i64 - 1);
+
+    Tensor::zeros_device([length], &device).scatter(0, indices, weights)
 }
@@ -1,3 +1,4 @@
+use super::ComplexPrimitive;
 use crate::backend::Backend;
 
 /// A type-level representation of the kind of a float tensor
@@ -12,6 +13,10 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a complex tensor.
+#[derive(Clone, Debug)]
+pub struct Complex;
+
 /// A type-level representation of the kind of a tensor.
 pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     /// The primitive type of the tensor.
@@ -41,3 +46,10 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: Backend> TensorKind<B> for Complex {
+    type Primitive<const D: usize> = ComplexPrimitive<B, D>;
+    fn name() -> &'static str {
+        "Complex"
+    }
+}
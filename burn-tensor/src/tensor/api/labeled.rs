@@ -0,0 +1,323 @@
+use alloc::vec::Vec;
+
+use crate::{
+    backend::Backend, check, check::TensorCheck, BasicOps, Element, Float, Numeric, Shape, Tensor,
+};
+
+/// A tensor where each dimension is annotated with a name, such as `["batch", "seq", "hidden"]`.
+///
+/// Names are validated at runtime in debug builds only: operations check that the dimensions
+/// they combine have matching names, which catches silent broadcasting bugs and produces panic
+/// messages that refer to dimensions by name. In release builds, names are propagated without
+/// any validation, so the only overhead is carrying them around.
+///
+/// Unlike the experimental `NamedTensor`, names aren't part of the type, which makes it
+/// possible to annotate existing code without changing any signature.
+#[derive(Clone, Debug)]
+pub struct LabeledTensor<B, const D: usize, K = Float>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    tensor: Tensor<B, D, K>,
+    names: [&'static str; D],
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Annotate each dimension of the tensor with a name.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the same name is used for more than one dimension.
+    pub fn with_names(self, names: [&'static str; D]) -> LabeledTensor<B, D, K> {
+        LabeledTensor::new(self, names)
+    }
+}
+
+impl<B, const D: usize, K> LabeledTensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Create a labeled tensor from a tensor and the names of its dimensions.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the same name is used for more than one dimension.
+    pub fn new(tensor: Tensor<B, D, K>, names: [&'static str; D]) -> Self {
+        if cfg!(debug_assertions) {
+            check!(TensorCheck::labeled_names("With Names", &names));
+        }
+
+        Self { tensor, names }
+    }
+
+    /// Returns the names of the dimensions.
+    pub fn names(&self) -> [&'static str; D] {
+        self.names
+    }
+
+    /// Returns the shape of the current tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.tensor.shape()
+    }
+
+    /// Returns the index of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If no dimension has the given name.
+    pub fn dim(&self, name: &str) -> usize {
+        match self.names.iter().position(|current| *current == name) {
+            Some(dim) => dim,
+            None => core::panic!(
+                "No dimension named '{name}', available dimensions: {:?}.",
+                self.names
+            ),
+        }
+    }
+
+    /// Returns the size of the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// If no dimension has the given name.
+    pub fn size(&self, name: &str) -> usize {
+        self.shape().dims[self.dim(name)]
+    }
+
+    /// Returns a reference to the underlying tensor.
+    pub fn tensor(&self) -> &Tensor<B, D, K> {
+        &self.tensor
+    }
+
+    /// Drops the names and returns the underlying tensor.
+    pub fn into_tensor(self) -> Tensor<B, D, K> {
+        self.tensor
+    }
+
+    /// Replace the names of the dimensions.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the same name is used for more than one dimension.
+    pub fn rename(self, names: [&'static str; D]) -> Self {
+        Self::new(self.tensor, names)
+    }
+
+    /// Reshape the tensor to the given shape, naming the dimensions of the result.
+    ///
+    /// # Panics
+    ///
+    /// - If the tensor can not be reshaped to the given shape.
+    /// - In debug builds, if a name is used for more than one dimension, or if a dimension that
+    ///   keeps its name doesn't keep its size.
+    pub fn reshape<const D2: usize, S: Into<Shape<D2>>>(
+        self,
+        shape: S,
+        names: [&'static str; D2],
+    ) -> LabeledTensor<B, D2, K> {
+        let shape = shape.into();
+
+        if cfg!(debug_assertions) {
+            check!(
+                TensorCheck::labeled_names("Reshape", &names).labeled_reshape(
+                    &self.names,
+                    &self.shape(),
+                    &names,
+                    &shape
+                )
+            );
+        }
+
+        LabeledTensor {
+            tensor: self.tensor.reshape(shape),
+            names,
+        }
+    }
+
+    /// Swaps the two dimensions with the given names.
+    ///
+    /// # Panics
+    ///
+    /// If one of the names doesn't exist.
+    pub fn swap_dims(self, name1: &str, name2: &str) -> Self {
+        let dim1 = self.dim(name1);
+        let dim2 = self.dim(name2);
+        let mut names = self.names;
+        names.swap(dim1, dim2);
+
+        Self {
+            tensor: self.tensor.swap_dims(dim1, dim2),
+            names,
+        }
+    }
+
+    /// Transpose the last two dimensions, along with their names.
+    pub fn transpose(self) -> Self {
+        let mut names = self.names;
+
+        if D >= 2 {
+            names.swap(D - 2, D - 1);
+        }
+
+        Self {
+            tensor: self.tensor.transpose(),
+            names,
+        }
+    }
+
+    /// Concatenates all tensors into a new one along the dimension with the given name.
+    ///
+    /// # Panics
+    ///
+    /// - If the list of tensors is empty or if the name doesn't exist.
+    /// - If all tensors don't have the same shape.
+    /// - In debug builds, if all tensors don't have the same names.
+    pub fn cat(tensors: Vec<Self>, name: &str) -> Self {
+        let first = match tensors.first() {
+            Some(first) => first,
+            None => core::panic!("Can't concatenate an empty list of labeled tensors."),
+        };
+        let dim = first.dim(name);
+        let names = first.names;
+
+        if cfg!(debug_assertions) {
+            let mut check = TensorCheck::Ok;
+
+            for tensor in tensors.iter() {
+                check = check.labeled_dims("Cat", &names, &tensor.names);
+            }
+
+            check!(check);
+        }
+
+        Self {
+            tensor: Tensor::cat(
+                tensors.into_iter().map(|tensor| tensor.tensor).collect(),
+                dim,
+            ),
+            names,
+        }
+    }
+}
+
+impl<B, const D: usize, K> LabeledTensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Applies element wise addition operation.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the names of the dimensions don't match, or if the sizes of
+    /// dimensions with the same name can't be broadcasted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        self.check_ew("Add", &other);
+        Self {
+            tensor: self.tensor.add(other.tensor),
+            names: self.names,
+        }
+    }
+
+    /// Applies element wise subtraction operation.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the names of the dimensions don't match, or if the sizes of
+    /// dimensions with the same name can't be broadcasted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        self.check_ew("Sub", &other);
+        Self {
+            tensor: self.tensor.sub(other.tensor),
+            names: self.names,
+        }
+    }
+
+    /// Applies element wise multiplication operation.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the names of the dimensions don't match, or if the sizes of
+    /// dimensions with the same name can't be broadcasted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        self.check_ew("Mul", &other);
+        Self {
+            tensor: self.tensor.mul(other.tensor),
+            names: self.names,
+        }
+    }
+
+    /// Applies element wise division operation.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the names of the dimensions don't match, or if the sizes of
+    /// dimensions with the same name can't be broadcasted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        self.check_ew("Div", &other);
+        Self {
+            tensor: self.tensor.div(other.tensor),
+            names: self.names,
+        }
+    }
+
+    fn check_ew(&self, ops: &str, other: &Self) {
+        if cfg!(debug_assertions) {
+            check!(TensorCheck::Ok
+                .labeled_dims(ops, &self.names, &other.names)
+                .labeled_ew_shape(ops, &self.names, &self.shape(), &other.shape()));
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> LabeledTensor<B, D> {
+    /// Applies the matrix multiplication operation.
+    ///
+    /// The last dimension of `self` is contracted with the second to last dimension of `other`,
+    /// the output has the names of `self` with the last one replaced by the last name of `other`.
+    ///
+    /// # Panics
+    ///
+    /// - If the two tensors dont' have a compatible shape.
+    /// - In debug builds, if the contracted dimensions or the batch dimensions don't have the same
+    ///   names, or if the output would have the same name twice.
+    pub fn matmul(self, other: Self) -> Self {
+        if cfg!(debug_assertions) {
+            check!(TensorCheck::labeled_matmul(
+                &self.names,
+                &self.shape(),
+                &other.names,
+                &other.shape()
+            ));
+        }
+
+        let mut names = self.names;
+        names[D - 1] = other.names[D - 1];
+
+        Self {
+            tensor: self.tensor.matmul(other.tensor),
+            names,
+        }
+    }
+}
+
+impl<B, const D: usize, K> From<LabeledTensor<B, D, K>> for Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    fn from(tensor: LabeledTensor<B, D, K>) -> Self {
+        tensor.into_tensor()
+    }
+}
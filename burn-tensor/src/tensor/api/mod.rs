@@ -3,12 +3,24 @@ pub(crate) mod check;
 mod autodiff;
 mod base;
 mod bool;
+mod complex;
+mod diagonal;
+mod einsum;
 mod float;
 mod int;
 mod kind;
+mod labeled;
+mod norm;
 mod numeric;
+mod product;
+mod random;
 
 pub use autodiff::*;
 pub use base::*;
+pub use check::TensorCheckError;
+pub use complex::*;
+pub use einsum::*;
 pub use kind::*;
+pub use labeled::*;
+pub use norm::*;
 pub use numeric::*;
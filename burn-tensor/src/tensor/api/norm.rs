@@ -0,0 +1,127 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Tensor};
+
+/// The vector norms computed by [norm](Tensor::norm).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    /// The sum of the absolute values.
+    L1,
+    /// The Euclidean norm, the square root of the sum of the squares.
+    L2,
+    /// The `p`-norm, `sum(|x|^p)^(1/p)`, `p` must not be zero.
+    Lp(f32),
+    /// The maximum absolute value.
+    Inf,
+    /// The minimum absolute value.
+    NegInf,
+}
+
+/// The matrix norms computed by [matrix_norm](Tensor::matrix_norm).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixNorm {
+    /// The square root of the sum of the squares of all elements.
+    Frobenius,
+    /// The maximum sum of the absolute values of a column.
+    L1,
+    /// The maximum sum of the absolute values of a row.
+    Inf,
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Computes the vector norm of the tensor along the given dimension.
+    ///
+    /// Like [sum_dim](Tensor::sum_dim), the reduced dimension is kept with a size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Norm, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, -4.0], [1.0, 0.0]]);
+    ///     let norm = tensor.norm(Norm::L2, 1);
+    ///     println!("{}", norm); // [[5.0], [1.0]]
+    /// }
+    /// ```
+    pub fn norm(self, norm: Norm, dim: usize) -> Self {
+        check!(TensorCheck::norm::<D>(norm, dim));
+
+        match norm {
+            Norm::L1 => self.abs().sum_dim(dim),
+            Norm::L2 => (self.clone() * self).sum_dim(dim).sqrt(),
+            Norm::Lp(p) => self.abs().powf(p).sum_dim(dim).powf(1.0 / p),
+            Norm::Inf => self.abs().max_dim(dim),
+            Norm::NegInf => self.abs().min_dim(dim),
+        }
+    }
+
+    /// Divides the tensor by its norm along the given dimension, the norm being clamped to at
+    /// least `eps` to avoid dividing by zero.
+    ///
+    /// The L2 and `p`-norms are clamped before their root, so the zero vectors have zero
+    /// gradients instead of NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Norm, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let embeddings = Tensor::<B, 2>::from_floats([[3.0, 4.0], [0.0, 0.0]]);
+    ///     let normalized = embeddings.normalize(Norm::L2, 1, 1e-12);
+    ///     println!("{}", normalized); // [[0.6, 0.8], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn normalize(self, norm: Norm, dim: usize, eps: f64) -> Self {
+        check!(TensorCheck::norm::<D>(norm, dim));
+
+        let norm = match norm {
+            Norm::L2 => (self.clone() * self.clone())
+                .sum_dim(dim)
+                .clamp_min(eps * eps)
+                .sqrt(),
+            Norm::Lp(p) => self
+                .clone()
+                .abs()
+                .powf(p)
+                .sum_dim(dim)
+                .clamp_min(libm::pow(eps, p as f64))
+                .powf(1.0 / p),
+            _ => self.clone().norm(norm, dim).clamp_min(eps),
+        };
+
+        self / norm
+    }
+
+    /// Computes the matrix norm of the matrices formed by the last two dimensions.
+    ///
+    /// The two dimensions are kept with a size of 1, so the result has the shape `[..., 1, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{MatrixNorm, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, -2.0], [3.0, 4.0]]);
+    ///     let norm = tensor.matrix_norm(MatrixNorm::L1);
+    ///     println!("{}", norm); // [[6.0]]
+    /// }
+    /// ```
+    pub fn matrix_norm(self, norm: MatrixNorm) -> Self {
+        check!(TensorCheck::matrix::<D>("Matrix Norm"));
+
+        match norm {
+            MatrixNorm::Frobenius => (self.clone() * self).sum_dim(D - 1).sum_dim(D - 2).sqrt(),
+            MatrixNorm::L1 => self.abs().sum_dim(D - 2).max_dim(D - 1),
+            MatrixNorm::Inf => self.abs().sum_dim(D - 1).max_dim(D - 2),
+        }
+    }
+}
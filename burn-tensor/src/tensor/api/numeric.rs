@@ -1,6 +1,10 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::future::Future;
+
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, check::TensorCheckError, ops::PadMode, BasicOps,
+    Bool, Data, Element, ElementConversion, Float, Int, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -33,6 +37,20 @@ where
         data.value[0]
     }
 
+    /// Returns a future resolving to the scalar of the tensor.
+    ///
+    /// See [into_data_async](Tensor::into_data_async).
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have one element.
+    pub fn into_scalar_async(self) -> impl Future<Output = K::Elem> {
+        check!(TensorCheck::into_scalar(&self.shape()));
+        let data = self.into_data_async();
+
+        async move { data.await.value[0] }
+    }
+
     /// Applies element wise addition operation.
     ///
     /// `y = x2 + x1`
@@ -42,6 +60,13 @@ where
         Self::new(K::add(self.primitive, other.primitive))
     }
 
+    /// Applies element wise addition, returning an error when the shapes can't be
+    /// broadcasted or the tensors are on different devices.
+    pub fn try_add(self, other: Self) -> Result<Self, TensorCheckError> {
+        TensorCheck::binary_ops_ew("Add", &self, &other).into_result()?;
+        Ok(Self::new(K::add(self.primitive, other.primitive)))
+    }
+
     /// Applies element wise addition operation with a scalar.
     ///
     /// `y = x + s`
@@ -58,6 +83,13 @@ where
         Self::new(K::sub(self.primitive, other.primitive))
     }
 
+    /// Applies element wise subtraction, returning an error when the shapes can't be
+    /// broadcasted or the tensors are on different devices.
+    pub fn try_sub(self, other: Self) -> Result<Self, TensorCheckError> {
+        TensorCheck::binary_ops_ew("Sub", &self, &other).into_result()?;
+        Ok(Self::new(K::sub(self.primitive, other.primitive)))
+    }
+
     /// Applies element wise subtraction operation with a scalar.
     ///
     /// `y = x - s`
@@ -74,6 +106,13 @@ where
         Self::new(K::div(self.primitive, other.primitive))
     }
 
+    /// Applies element wise division, returning an error when the shapes can't be
+    /// broadcasted or the tensors are on different devices.
+    pub fn try_div(self, other: Self) -> Result<Self, TensorCheckError> {
+        TensorCheck::binary_ops_ew("Div", &self, &other).into_result()?;
+        Ok(Self::new(K::div(self.primitive, other.primitive)))
+    }
+
     /// Applies element wise division operation with a scalar.
     ///
     /// `y = x / s`
@@ -90,6 +129,13 @@ where
         Self::new(K::mul(self.primitive, other.primitive))
     }
 
+    /// Applies element wise multiplication, returning an error when the shapes can't be
+    /// broadcasted or the tensors are on different devices.
+    pub fn try_mul(self, other: Self) -> Result<Self, TensorCheckError> {
+        TensorCheck::binary_ops_ew("Mul", &self, &other).into_result()?;
+        Ok(Self::new(K::mul(self.primitive, other.primitive)))
+    }
+
     /// Applies element wise multiplication operation with a scalar.
     ///
     /// `y = x * s`
@@ -161,6 +207,149 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let cumsum = tensor.cumsum(1);
+    ///     println!("{}", cumsum);
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let cumprod = tensor.cumprod(1);
+    ///     println!("{}", cumprod);
+    ///     // [[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `pads` - The number of elements to add before and after each dimension.
+    /// * `mode` - How the padded values are computed, see [PadMode](crate::ops::PadMode).
+    ///
+    /// # Panics
+    ///
+    /// - If the reflect padding is not smaller than the size of the dimension.
+    /// - If the circular padding is greater than the size of the dimension.
+    /// - If a non constant padding is applied on an empty dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::PadMode;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let padded = tensor.pad([(0, 0), (2, 1)], PadMode::Reflect);
+    ///     println!("{}", padded);
+    ///     // [[3.0, 2.0, 1.0, 2.0, 3.0, 2.0], [6.0, 5.0, 4.0, 5.0, 6.0, 5.0]]
+    /// }
+    /// ```
+    pub fn pad(self, pads: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad::<D>(&self.shape(), &pads, &mode));
+        Self::new(K::pad(self.primitive, pads, mode))
+    }
+
+    /// Reverse the order of the elements along the given dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - The dimensions to flip.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let flipped = tensor.flip(&[0, 1]);
+    ///     println!("{}", flipped);
+    ///     // [[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]
+    /// }
+    /// ```
+    pub fn flip(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(dims));
+
+        let device = self.device();
+        let mut tensor = self;
+
+        for &dim in dims {
+            let size = tensor.dims()[dim];
+            let indices = Data::new(
+                (0..size as i64).rev().collect::<Vec<_>>(),
+                Shape::new([size]),
+            );
+            let indices = Tensor::from_data_device(indices.convert(), &device);
+
+            tensor = tensor.select(dim, indices);
+        }
+
+        tensor
+    }
+
+    /// Rotate the tensor by 90 degrees `k` times in the plane formed by the given dimensions.
+    ///
+    /// The rotation goes from the first towards the second dimension, a negative `k` rotates in
+    /// the opposite direction. For a matrix with `dims = [0, 1]`, a single rotation is
+    /// counterclockwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let rotated = tensor.rot90(1, [0, 1]);
+    ///     println!("{}", rotated);
+    ///     // [[2.0, 4.0], [1.0, 3.0]]
+    /// }
+    /// ```
+    pub fn rot90(self, k: i64, dims: [usize; 2]) -> Self {
+        check!(TensorCheck::rot90::<D>(&dims));
+
+        let [dim0, dim1] = dims;
+
+        match k.rem_euclid(4) {
+            1 => self.flip(&[dim1]).swap_dims(dim0, dim1),
+            2 => self.flip(&dims),
+            3 => self.flip(&[dim0]).swap_dims(dim0, dim1),
+            _ => self,
+        }
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -242,6 +431,171 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Select the tensor elements where the mask is true and return them as a 1D tensor.
+    ///
+    /// The elements are returned in row-major order. Since the number of selected elements
+    /// depends on the mask values, the mask is read back from the device.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor.
+    pub fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = mask.nonzero();
+        self.index(indices.try_into().unwrap())
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Select the tensor elements where the mask is true and return them as a 1D tensor.
+    pub async fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = mask.nonzero().await;
+        self.index(indices.try_into().unwrap())
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Copy the elements of the source tensor, in row-major order, into the positions where the
+    /// mask is true.
+    ///
+    /// This is the inverse of [masked_select](Tensor::masked_select): the `i`-th true element of
+    /// the mask receives the `i`-th element of the source tensor. The source tensor can have any
+    /// shape, as long as it has at least as many elements as there are true values in the mask.
+    ///
+    /// # Panics
+    ///
+    /// - If the mask doesn't have the same shape as the tensor.
+    /// - If the source tensor has fewer elements than the number of true values in the mask.
+    pub fn masked_scatter<const D2: usize>(
+        self,
+        mask: Tensor<B, D, Bool>,
+        source: Tensor<B, D2, K>,
+    ) -> Self {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = mask.clone().nonzero();
+        self.masked_scatter_indices(mask, indices, source)
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Copy the elements of the source tensor, in row-major order, into the positions where the
+    /// mask is true.
+    pub async fn masked_scatter<const D2: usize>(
+        self,
+        mask: Tensor<B, D, Bool>,
+        source: Tensor<B, D2, K>,
+    ) -> Self {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = mask.clone().nonzero().await;
+        self.masked_scatter_indices(mask, indices, source)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the unique elements of the tensor, sorted in ascending order.
+    ///
+    /// Since the number of unique elements depends on the tensor values, the tensor is read back
+    /// from the device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[2, 0, 2], [1, 0, 2]]);
+    ///     let (values, counts) = tensor.clone().unique_counts();
+    ///     println!("{}", values); // [0, 1, 2]
+    ///     println!("{}", counts); // [2, 1, 3]
+    ///     let (_, inverse) = tensor.unique_inverse();
+    ///     println!("{}", inverse); // [[2, 0, 2], [1, 0, 2]]
+    /// }
+    /// ```
+    pub fn unique(self) -> Tensor<B, 1, K> {
+        let device = self.device();
+        Unique::new(self.into_data()).values(&device)
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns the unique elements of the tensor, sorted in ascending order.
+    pub async fn unique(self) -> Tensor<B, 1, K> {
+        let device = self.device();
+        Unique::new(self.into_data().await).values(&device)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the unique elements of the tensor, sorted in ascending order, along with the
+    /// number of times each of them appears in the tensor.
+    pub fn unique_counts(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        let device = self.device();
+        let unique = Unique::new(self.into_data());
+        (unique.values(&device), unique.counts(&device))
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns the unique elements of the tensor, sorted in ascending order, along with the
+    /// number of times each of them appears in the tensor.
+    pub async fn unique_counts(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        let device = self.device();
+        let unique = Unique::new(self.into_data().await);
+        (unique.values(&device), unique.counts(&device))
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns the unique elements of the tensor, sorted in ascending order, along with the
+    /// inverse indices, a tensor with the same shape as the input containing the position of each
+    /// element in the unique values.
+    ///
+    /// Selecting the unique values with the flattened inverse indices gives back the input.
+    pub fn unique_inverse(self) -> (Tensor<B, 1, K>, Tensor<B, D, Int>) {
+        let device = self.device();
+        let unique = Unique::new(self.into_data());
+        (unique.values(&device), unique.inverse(&device))
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Returns the unique elements of the tensor, sorted in ascending order, along with the
+    /// inverse indices, a tensor with the same shape as the input containing the position of each
+    /// element in the unique values.
+    pub async fn unique_inverse(self) -> (Tensor<B, 1, K>, Tensor<B, D, Int>) {
+        let device = self.device();
+        let unique = Unique::new(self.into_data().await);
+        (unique.values(&device), unique.inverse(&device))
+    }
+
+    #[allow(clippy::single_range_in_vec_init)]
+    fn masked_scatter_indices<const D2: usize>(
+        self,
+        mask: Tensor<B, D, Bool>,
+        indices: Vec<Tensor<B, 1, Int>>,
+        source: Tensor<B, D2, K>,
+    ) -> Self {
+        let num_selected = indices.first().map(|index| index.dims()[0]).unwrap_or(0);
+        let num_elements = source.shape().num_elements();
+
+        check!(TensorCheck::masked_scatter(num_selected, num_elements));
+
+        let values = source.reshape([num_elements]).slice([0..num_selected]);
+
+        self.mask_fill(mask, 0)
+            .index_assign(indices.try_into().unwrap(), values)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -302,6 +656,63 @@ where
         Self::new(K::select(self.primitive, dim, indices))
     }
 
+    /// Extracts all the sliding windows of the given size along a dimension.
+    ///
+    /// The dimension `dim` is replaced by the number of windows and a new dimension of size
+    /// `size` is appended, containing the elements of each window.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which the windows are extracted.
+    /// * `size` - The size of each window.
+    /// * `step` - The number of elements between the start of two consecutive windows.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `D2`: The rank of the output tensor, which must be `D + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones([2, 7]);
+    ///     // Windows of 3 elements every 2 elements along the last dimension.
+    ///     let windows: Tensor<B, 3> = tensor.unfold(1, 3, 2);
+    ///     println!("{:?}", windows.dims()); // [2, 3, 3]
+    /// }
+    /// ```
+    pub fn unfold<const D2: usize>(self, dim: usize, size: usize, step: usize) -> Tensor<B, D2, K> {
+        let dims = self.dims();
+        check!(TensorCheck::unfold::<D, D2>(dim, size, step, &dims));
+
+        let num_windows = (dims[dim] - size) / step + 1;
+        let device = self.device();
+
+        // The indices of the elements of each window, window after window.
+        let starts = Tensor::<B, 1, Int>::arange_device(0..num_windows, &device)
+            .mul_scalar(step as i64)
+            .reshape([num_windows, 1]);
+        let offsets = Tensor::<B, 1, Int>::arange_device(0..size, &device).reshape([1, size]);
+        let indices = (starts + offsets).reshape([num_windows * size]);
+
+        let mut shape = [1; D2];
+        shape[..dim].copy_from_slice(&dims[..dim]);
+        shape[dim] = num_windows;
+        shape[dim + 1] = size;
+        shape[dim + 2..].copy_from_slice(&dims[dim + 1..]);
+
+        // The windows are gathered as [.., num_windows, size, ..], then the size of the windows
+        // is moved to the last dimension.
+        let output = self.select(dim, indices).reshape(shape);
+        let mut axes: [usize; D2] = core::array::from_fn(|i| i);
+        axes[dim + 1..].rotate_left(1);
+
+        output.permute(axes)
+    }
+
     /// Assign the selected elements along the given dimension corresponding to the given indices
     /// from the value tensor to the original tensor using sum reduction.
     ///
@@ -326,6 +737,88 @@ where
         ))
     }
 
+    /// Repeats each element of the tensor `repeats` times along the given dimension.
+    ///
+    /// Unlike [repeat](Tensor::repeat), which repeats the whole tensor, the copies of each
+    /// element are placed next to each other.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is higher than the tensor rank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2], [3, 4]]);
+    ///     let repeated = tensor.repeat_interleave(2, 1);
+    ///     println!("{}", repeated); // [[1, 1, 2, 2], [3, 3, 4, 4]]
+    /// }
+    /// ```
+    pub fn repeat_interleave(self, repeats: usize, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("RepeatInterleave", dim));
+
+        let size = self.dims()[dim];
+        let indices = Tensor::<B, 1, Int>::arange_device(0..size, &self.device())
+            .reshape([size, 1])
+            .repeat(1, repeats)
+            .reshape([size * repeats]);
+
+        self.select(dim, indices)
+    }
+
+    /// Select the tensor elements at the coordinates given by one index tensor per dimension.
+    ///
+    /// This is the equivalent of PyTorch advanced indexing `tensor[indices[0], indices[1], ...]`
+    /// and can be combined with [nonzero](Tensor::nonzero).
+    ///
+    /// `output[i] = input[indices[0][i], indices[1][i], ..., indices[D - 1][i]]`
+    ///
+    /// # Panics
+    ///
+    /// If the index tensors don't have the same number of elements.
+    pub fn index(self, indices: [Tensor<B, 1, Int>; D]) -> Tensor<B, 1, K> {
+        check!(TensorCheck::index(
+            &indices
+                .iter()
+                .map(|index| index.shape())
+                .collect::<Vec<_>>()
+        ));
+
+        let num_elements = self.shape().num_elements();
+        let linear = linear_indices(&self.dims(), indices);
+
+        self.reshape([num_elements]).select(0, linear)
+    }
+
+    /// Assign the values to the coordinates given by one index tensor per dimension using sum
+    /// reduction.
+    ///
+    /// `input[indices[0][i], indices[1][i], ..., indices[D - 1][i]] += values[i]`
+    ///
+    /// # Panics
+    ///
+    /// If the index tensors and the value tensor don't have the same number of elements.
+    pub fn index_assign(self, indices: [Tensor<B, 1, Int>; D], values: Tensor<B, 1, K>) -> Self {
+        check!(TensorCheck::index_assign(
+            &indices
+                .iter()
+                .map(|index| index.shape())
+                .collect::<Vec<_>>(),
+            &values.shape()
+        ));
+
+        let shape = self.shape();
+        let linear = linear_indices(&shape.dims, indices);
+
+        self.reshape([shape.num_elements()])
+            .select_assign(0, linear, values)
+            .reshape(shape)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -462,6 +955,61 @@ where
     pub fn abs(self) -> Self {
         Self::new(K::abs(self.primitive))
     }
+
+    /// Returns the lower triangular part of the matrices formed by the last two dimensions, the
+    /// other elements being set to zero.
+    ///
+    /// The elements on and below the diagonal are kept, a positive `diagonal` keeps that many
+    /// diagonals above the main one while a negative one excludes diagonals below it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let lower = tensor.tril(0);
+    ///     println!("{}", lower); // [[1.0, 0.0], [3.0, 4.0]]
+    /// }
+    /// ```
+    pub fn tril(self, diagonal: i64) -> Self {
+        check!(TensorCheck::matrix::<D>("Tril"));
+
+        let mask = triangle_mask::<B, D>(&self.dims(), &self.device(), |offset| {
+            offset.greater_elem(diagonal)
+        });
+        self.mask_fill(mask, 0)
+    }
+
+    /// Returns the upper triangular part of the matrices formed by the last two dimensions, the
+    /// other elements being set to zero.
+    ///
+    /// The elements on and above the diagonal are kept, a positive `diagonal` excludes diagonals
+    /// above the main one while a negative one keeps that many diagonals below it. A causal
+    /// attention mask is the upper triangle of ones with a `diagonal` of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let upper = tensor.triu(0);
+    ///     println!("{}", upper); // [[1.0, 2.0], [0.0, 4.0]]
+    /// }
+    /// ```
+    pub fn triu(self, diagonal: i64) -> Self {
+        check!(TensorCheck::matrix::<D>("Triu"));
+
+        let mask = triangle_mask::<B, D>(&self.dims(), &self.device(), |offset| {
+            offset.lower_elem(diagonal)
+        });
+        self.mask_fill(mask, 0)
+    }
 }
 
 impl<B, K> Tensor<B, 2, K>
@@ -475,6 +1023,8 @@ where
     /// # Arguments
     ///
     /// * `size` - The size of the square matrix.
+    ///
+    /// See [diagonal_dim](Tensor::diagonal_dim) to extract the diagonals of a tensor.
     pub fn diagonal(size: usize) -> Self {
         let indices = Tensor::<B, 1, Int>::arange(0..size).unsqueeze();
         let ones = K::ones([1, size].into(), &B::Device::default());
@@ -483,6 +1033,121 @@ where
     }
 }
 
+/// Creates the mask of the elements to remove from the matrices formed by the last two
+/// dimensions, given the predicate on the diagonal offset `column - row` of each element.
+fn triangle_mask<B: Backend, const D: usize>(
+    dims: &[usize; D],
+    device: &B::Device,
+    predicate: impl Fn(Tensor<B, 2, Int>) -> Tensor<B, 2, Bool>,
+) -> Tensor<B, D, Bool> {
+    let (num_rows, num_columns) = (dims[D - 2], dims[D - 1]);
+    let rows = Tensor::<B, 1, Int>::arange_device(0..num_rows, device)
+        .reshape([num_rows, 1])
+        .repeat(1, num_columns);
+    let columns = Tensor::<B, 1, Int>::arange_device(0..num_columns, device)
+        .reshape([1, num_columns])
+        .repeat(0, num_rows);
+
+    let mut shape = [1; D];
+    shape[D - 2] = num_rows;
+    shape[D - 1] = num_columns;
+    let mut mask = predicate(columns.sub(rows)).reshape(shape);
+
+    for (dim, size) in dims[..D - 2].iter().enumerate() {
+        mask = mask.repeat(dim, *size);
+    }
+
+    mask
+}
+
+/// Computes the row-major linear indices of the coordinates given by one index tensor per
+/// dimension.
+fn linear_indices<B: Backend, const D: usize>(
+    dims: &[usize; D],
+    indices: [Tensor<B, 1, Int>; D],
+) -> Tensor<B, 1, Int> {
+    let mut linear: Option<Tensor<B, 1, Int>> = None;
+    let mut stride = 1;
+
+    for (dim, index) in indices.into_iter().enumerate().rev() {
+        let index = index.mul_scalar(stride as i64);
+        linear = Some(match linear {
+            Some(linear) => linear.add(index),
+            None => index,
+        });
+        stride *= dims[dim];
+    }
+
+    linear.expect("Can't index a tensor with zero dimension")
+}
+
+/// The unique elements of a tensor, computed on the host.
+struct Unique<E, const D: usize> {
+    values: Vec<E>,
+    counts: Vec<i64>,
+    inverse: Vec<i64>,
+    shape: Shape<D>,
+}
+
+impl<E: Element, const D: usize> Unique<E, D> {
+    fn new(data: Data<E, D>) -> Self {
+        // Integers that can't be represented exactly as `f64` are still ordered by their `i64`
+        // value, which is the same for floats comparing equal.
+        let compare = |lhs: &E, rhs: &E| {
+            let lhs_float = lhs.to_f64().unwrap_or(f64::NAN);
+            let rhs_float = rhs.to_f64().unwrap_or(f64::NAN);
+            lhs_float
+                .total_cmp(&rhs_float)
+                .then_with(|| lhs.to_i64().cmp(&rhs.to_i64()))
+        };
+
+        let mut order = (0..data.value.len()).collect::<Vec<_>>();
+        order.sort_by(|lhs, rhs| compare(&data.value[*lhs], &data.value[*rhs]));
+
+        let mut values = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = vec![0; data.value.len()];
+
+        for position in order {
+            let value = data.value[position];
+            let is_new = match values.last() {
+                Some(last) => compare(last, &value).is_ne(),
+                None => true,
+            };
+
+            if is_new {
+                values.push(value);
+                counts.push(0);
+            }
+
+            *counts.last_mut().unwrap() += 1;
+            inverse[position] = values.len() as i64 - 1;
+        }
+
+        Self {
+            values,
+            counts,
+            inverse,
+            shape: data.shape,
+        }
+    }
+
+    fn values<B: Backend, K: BasicOps<B, Elem = E>>(&self, device: &B::Device) -> Tensor<B, 1, K> {
+        let data = Data::new(self.values.clone(), Shape::new([self.values.len()]));
+        Tensor::from_data_device(data, device)
+    }
+
+    fn counts<B: Backend>(&self, device: &B::Device) -> Tensor<B, 1, Int> {
+        let data = Data::new(self.counts.clone(), Shape::new([self.counts.len()]));
+        Tensor::from_data_device(data.convert(), device)
+    }
+
+    fn inverse<B: Backend>(&self, device: &B::Device) -> Tensor<B, D, Int> {
+        let data = Data::new(self.inverse.clone(), self.shape.clone());
+        Tensor::from_data_device(data.convert(), device)
+    }
+}
+
 /// Trait that list all operations that can be applied on all numerical tensors.
 ///
 /// # Warnings
@@ -842,6 +1507,74 @@ where
     /// the [Tensor::mean_dim](Tensor::mean_dim) function, which is more high-level and designed for public use.
     fn mean_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative sum of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of a tensor along a dimension, users should prefer
+    /// the [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative product of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of a tensor along a dimension, users should prefer
+    /// the [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements to add before and after each dimension.
+    /// * `mode` - How the padded values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For padding a tensor, users should prefer the [Tensor::pad](Tensor::pad) function,
+    /// which is more high-level and designed for public use.
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D>;
+
     /// Element-wise equality between two tensors.
     ///
     /// # Arguments
@@ -1582,6 +2315,19 @@ impl<B: Backend> Numeric<B> for Int {
     fn mean_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::int_mean_dim(tensor, dim)
     }
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::int_pad(tensor, pads, mode)
+    }
 
     fn equal_elem<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Elem) -> Tensor<B, D, Bool> {
         Tensor::new(B::int_equal_elem(lhs, rhs))
@@ -1836,6 +2582,19 @@ impl<B: Backend> Numeric<B> for Float {
     fn mean_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::mean_dim(tensor, dim)
     }
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumsum(tensor, dim)
+    }
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::cumprod(tensor, dim)
+    }
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> Self::Primitive<D> {
+        B::pad(tensor, pads, mode)
+    }
 
     fn equal_elem<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Elem) -> Tensor<B, D, Bool> {
         Tensor::new(B::equal_elem(lhs, rhs))
@@ -0,0 +1,189 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Tensor};
+
+impl<B> Tensor<B, 1>
+where
+    B: Backend,
+{
+    /// Computes the outer product of two vectors.
+    ///
+    /// `output[i, j] = lhs[i] * rhs[j]`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let lhs = Tensor::<B, 1>::from_floats([1.0, 2.0]);
+    ///     let rhs = Tensor::<B, 1>::from_floats([3.0, 4.0, 5.0]);
+    ///     let output = lhs.outer(rhs);
+    ///     println!("{}", output); // [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]
+    /// }
+    /// ```
+    pub fn outer(self, other: Self) -> Tensor<B, 2> {
+        let [size_lhs] = self.dims();
+        let [size_rhs] = other.dims();
+
+        self.reshape([size_lhs, 1]) * other.reshape([1, size_rhs])
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Computes the Kronecker product of two tensors.
+    ///
+    /// Each element of the first tensor scales a full copy of the second one, the output having
+    /// a size of `lhs.dims()[i] * rhs.dims()[i]` along each dimension `i`.
+    ///
+    /// `output[i * n + k, j * m + l] = lhs[i, j] * rhs[k, l]` for matrices, where `[n, m]` is the
+    /// shape of the second matrix.
+    pub fn kron(self, other: Self) -> Self {
+        let dims_lhs = self.dims();
+        let dims_rhs = other.dims();
+        let mut lhs = self;
+        let mut rhs = other;
+
+        for dim in 0..D {
+            lhs = lhs.repeat_interleave(dims_rhs[dim], dim);
+            rhs = Tensor::cat(vec![rhs; dims_lhs[dim]], dim);
+        }
+
+        lhs * rhs
+    }
+
+    /// Computes the cross product of 3-dimensional vectors stored along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors don't have the same shape or if the given dimension doesn't have a size
+    /// of 3.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let x = Tensor::<B, 2>::from_floats([[1.0, 0.0, 0.0]]);
+    ///     let y = Tensor::<B, 2>::from_floats([[0.0, 1.0, 0.0]]);
+    ///     let z = x.cross(y, 1);
+    ///     println!("{}", z); // [[0.0, 0.0, 1.0]]
+    /// }
+    /// ```
+    pub fn cross(self, other: Self, dim: usize) -> Self {
+        check!(TensorCheck::cross(&self.shape(), &other.shape(), dim));
+
+        let component = |tensor: &Self, index: usize| {
+            let mut ranges = tensor.dims().map(|size| 0..size);
+            ranges[dim] = index..index + 1;
+            tensor.clone().slice(ranges)
+        };
+        let [a0, a1, a2] = [0, 1, 2].map(|index| component(&self, index));
+        let [b0, b1, b2] = [0, 1, 2].map(|index| component(&other, index));
+
+        Tensor::cat(
+            vec![
+                a1.clone() * b2.clone() - a2.clone() * b1.clone(),
+                a2 * b0.clone() - a0.clone() * b2,
+                a0 * b1 - a1 * b0,
+            ],
+            dim,
+        )
+    }
+
+    /// Contracts the given dimensions of both tensors, summing the products of their elements.
+    ///
+    /// The output dimensions are the remaining dimensions of the first tensor followed by the
+    /// remaining dimensions of the second one. Contracting every dimension returns a tensor of
+    /// shape `[1]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second tensor.
+    /// * `lhs_dims` - The dimensions of the first tensor to contract.
+    /// * `rhs_dims` - The dimensions of the second tensor to contract, matched in order with
+    ///   `lhs_dims`.
+    ///
+    /// # Panics
+    ///
+    /// If the contracted dimensions don't have the same sizes, or if the output rank doesn't
+    /// match the number of remaining dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let lhs = Tensor::<B, 3>::ones([2, 3, 4]);
+    ///     let rhs = Tensor::<B, 2>::ones([4, 3]);
+    ///     let output: Tensor<B, 1> = lhs.tensordot(rhs, &[1, 2], &[1, 0]);
+    ///     println!("{}", output); // [12.0, 12.0]
+    /// }
+    /// ```
+    pub fn tensordot<const D2: usize, const D3: usize>(
+        self,
+        other: Tensor<B, D2>,
+        lhs_dims: &[usize],
+        rhs_dims: &[usize],
+    ) -> Tensor<B, D3> {
+        check!(TensorCheck::tensordot::<D, D2, D3>(
+            &self.shape(),
+            &other.shape(),
+            lhs_dims,
+            rhs_dims
+        ));
+
+        let shape_lhs = self.dims();
+        let shape_rhs = other.dims();
+        let free_lhs = (0..D)
+            .filter(|dim| !lhs_dims.contains(dim))
+            .collect::<Vec<_>>();
+        let free_rhs = (0..D2)
+            .filter(|dim| !rhs_dims.contains(dim))
+            .collect::<Vec<_>>();
+        let size = |shape: &[usize], dims: &[usize]| -> usize {
+            dims.iter().map(|dim| shape[*dim]).product()
+        };
+
+        // Both tensors are permuted and flattened into matrices, the contracted dimensions being
+        // the columns of the first one and the rows of the second one.
+        let mut axes_lhs = [0; D];
+        for (axis, dim) in free_lhs.iter().chain(lhs_dims).enumerate() {
+            axes_lhs[axis] = *dim;
+        }
+        let mut axes_rhs = [0; D2];
+        for (axis, dim) in rhs_dims.iter().chain(free_rhs.iter()).enumerate() {
+            axes_rhs[axis] = *dim;
+        }
+
+        let contracted = size(&shape_lhs, lhs_dims);
+        let lhs = self
+            .permute(axes_lhs)
+            .reshape([size(&shape_lhs, &free_lhs), contracted]);
+        let rhs = other
+            .permute(axes_rhs)
+            .reshape([contracted, size(&shape_rhs, &free_rhs)]);
+
+        let mut shape = [1; D3];
+        let free = free_lhs
+            .iter()
+            .map(|dim| shape_lhs[*dim])
+            .chain(free_rhs.iter().map(|dim| shape_rhs[*dim]));
+        for (axis, size) in free.enumerate() {
+            shape[axis] = size;
+        }
+
+        lhs.matmul(rhs).reshape(shape)
+    }
+}
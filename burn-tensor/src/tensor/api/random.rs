@@ -0,0 +1,246 @@
+use alloc::vec;
+
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Distribution, Int, Shape, Tensor};
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Samples each element from a normal distribution with its own mean and standard deviation.
+    ///
+    /// The parameters are broadcast together, and the sample is computed as
+    /// `mean + std * noise`, so it is differentiable with respect to both parameters, which is
+    /// the reparameterization used by variational autoencoders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let mean = Tensor::<B, 2>::from_floats([[0.0, 10.0]]);
+    ///     let std = Tensor::<B, 2>::from_floats([[1.0], [0.1]]);
+    ///     let sample = Tensor::random_normal(mean, std);
+    ///     println!("{:?}", sample.dims()); // [2, 2]
+    /// }
+    /// ```
+    pub fn random_normal(mean: Self, std: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Random Normal", &mean, &std));
+
+        let noise = Self::random_device(
+            broadcast_shape(&mean.shape(), &std.shape()),
+            Distribution::Normal(0.0, 1.0),
+            &mean.device(),
+        );
+
+        mean + std * noise
+    }
+
+    /// Samples each element uniformly between its own lower bound (inclusive) and upper bound
+    /// (exclusive).
+    ///
+    /// The bounds are broadcast together, and the sample is computed as
+    /// `low + (high - low) * noise`, so it is differentiable with respect to both bounds.
+    pub fn random_uniform(low: Self, high: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Random Uniform", &low, &high));
+
+        let noise = Self::random_device(
+            broadcast_shape(&low.shape(), &high.shape()),
+            Distribution::Default,
+            &low.device(),
+        );
+
+        low.clone() + (high - low) * noise
+    }
+
+    /// Samples each element from a Bernoulli distribution whose probability of being one is the
+    /// current element.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of the same shape filled with zeros and ones.
+    pub fn bernoulli(self) -> Self {
+        let noise = self.random_like(Distribution::Default);
+
+        noise.lower(self).float()
+    }
+}
+
+impl<B> Tensor<B, 2>
+where
+    B: Backend,
+{
+    /// Samples category indices from the multinomial distributions given by each row.
+    ///
+    /// The rows contain non-negative weights, which don't need to sum to one. A one dimensional
+    /// distribution can be sampled by unsqueezing it first.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of indices sampled from each row.
+    /// * `replacement` - Whether a category can be sampled multiple times. Without replacement,
+    ///   `num_samples` can't exceed the number of categories.
+    ///
+    /// # Returns
+    ///
+    /// The sampled indices with shape `[batch_size, num_samples]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let weights = Tensor::<B, 2>::from_floats([[0.0, 1.0, 3.0], [1.0, 0.0, 0.0]]);
+    ///     let indices = weights.multinomial(2, true);
+    ///     println!("{}", indices); // e.g. [[2, 2], [0, 0]]
+    /// }
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, 2, Int> {
+        let [batch_size, num_categories] = self.dims();
+        check!(TensorCheck::multinomial(
+            num_samples,
+            num_categories,
+            replacement
+        ));
+
+        let device = self.device();
+
+        if !replacement {
+            // Exponential race: the categories with the smallest `E / w` where `E ~ Exp(1)` are
+            // distributed like sequential sampling without replacement.
+            let exponential = self
+                .random_like(Distribution::Default)
+                .neg()
+                .add_scalar(1.0)
+                .log()
+                .neg();
+            let keys = exponential / self;
+
+            return argsort_rows(keys).slice([0..batch_size, 0..num_samples]);
+        }
+
+        // Inverse transform sampling, counting the cumulative weights below each sample.
+        let cumulative = self.cumsum(1);
+        let total = cumulative
+            .clone()
+            .slice([0..batch_size, num_categories - 1..num_categories]);
+        let samples = Tensor::<B, 2>::random_device(
+            [batch_size, num_samples],
+            Distribution::Default,
+            &device,
+        ) * total;
+
+        let cumulative = cumulative
+            .reshape([batch_size, 1, num_categories])
+            .repeat(1, num_samples);
+        let samples = samples
+            .reshape([batch_size, num_samples, 1])
+            .repeat(2, num_categories);
+
+        cumulative
+            .lower_equal(samples)
+            .int()
+            .sum_dim(2)
+            .clamp_max(num_categories as i64 - 1)
+            .reshape([batch_size, num_samples])
+    }
+}
+
+impl<B> Tensor<B, 1, Int>
+where
+    B: Backend,
+{
+    /// Returns a random permutation of the integers from `0` to `n - 1`.
+    ///
+    /// The permutation is computed on the device by sorting random keys.
+    pub fn randperm(n: usize, device: &B::Device) -> Self {
+        let keys = Tensor::<B, 2>::random_device([1, n], Distribution::Default, device);
+
+        argsort_rows(keys).reshape([n])
+    }
+}
+
+/// Returns the shape resulting from broadcasting two shapes together.
+fn broadcast_shape<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Shape<D> {
+    let mut dims = lhs.dims;
+
+    for (dim, size) in dims.iter_mut().zip(rhs.dims) {
+        *dim = usize::max(*dim, size);
+    }
+
+    Shape::new(dims)
+}
+
+/// Returns the indices sorting each row in ascending order.
+///
+/// A bitonic sorting network is used, so the rows are sorted with tensor operations only. The
+/// rows are padded with infinity up to the next power of two. Only the indices are swapped, the
+/// compared keys being gathered from the original ones, so infinite keys are supported.
+fn argsort_rows<B: Backend>(keys: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+    let [batch_size, length] = keys.dims();
+    let device = keys.device();
+    let size = length.next_power_of_two();
+
+    let keys = match size > length {
+        true => {
+            let padding = Tensor::full_device([batch_size, size - length], f32::INFINITY, &device);
+            Tensor::cat(vec![keys, padding], 1)
+        }
+        false => keys,
+    };
+    let mut indices = Tensor::<B, 1, Int>::arange_device(0..size, &device)
+        .reshape([1, size])
+        .repeat(0, batch_size);
+
+    let mut block = 2;
+    while block <= size {
+        let mut distance = block / 2;
+
+        while distance > 0 {
+            // Each row of the reshaped indices holds the pairs compared at this step, the pairs
+            // of a block being sorted in descending order when the block index is odd.
+            let num_rows = size / (2 * distance);
+            let shape = [batch_size, num_rows, 2, distance];
+            let half = [batch_size, size / 2];
+
+            let descending = Tensor::<B, 1, Int>::arange_device(0..num_rows, &device)
+                .mul_scalar(2 * distance as i64)
+                .div_scalar(block as i64)
+                .reshape([1, num_rows, 1, 1])
+                .repeat(0, batch_size)
+                .repeat(3, distance);
+            let descending = descending.clone() - descending.div_scalar(2).mul_scalar(2);
+
+            let pairs = indices.reshape(shape);
+            let lhs = pairs
+                .clone()
+                .slice([0..batch_size, 0..num_rows, 0..1, 0..distance]);
+            let rhs = pairs.slice([0..batch_size, 0..num_rows, 1..2, 0..distance]);
+
+            let keys_lhs = keys.clone().gather(1, lhs.clone().reshape(half));
+            let keys_rhs = keys.clone().gather(1, rhs.clone().reshape(half));
+            let swap =
+                (keys_lhs.greater(keys_rhs).int().reshape(lhs.shape()) + descending).equal_elem(1);
+
+            indices = Tensor::cat(
+                vec![
+                    lhs.clone().mask_where(swap.clone(), rhs.clone()),
+                    rhs.mask_where(swap, lhs),
+                ],
+                2,
+            )
+            .reshape([batch_size, size]);
+
+            distance /= 2;
+        }
+
+        block *= 2;
+    }
+
+    indices.slice([0..batch_size, 0..length])
+}
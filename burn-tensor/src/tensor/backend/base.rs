@@ -1,4 +1,5 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::ops::*;
 use crate::tensor::Element;
@@ -55,6 +56,8 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + FftOps<Self>
+    + LinalgOps<Self>
     + Clone
     + Sized
     + Default
@@ -95,8 +98,67 @@ pub trait Backend:
     /// Seed the backend.
     fn seed(seed: u64);
 
+    /// Seed the random number generators of all devices with the same seed.
+    ///
+    /// Every random operation, including parameter initialization, dropout masks and sampling,
+    /// is reproducible afterward on a given backend and device.
+    fn seed_all(seed: u64) {
+        Self::seed(seed)
+    }
+
+    /// Seed the random number generator of the given device, the other devices keep their
+    /// current state.
+    ///
+    /// Backends sharing a single generator between their devices seed all of them, which is what
+    /// the default implementation does.
+    fn seed_device(seed: u64, device: &Self::Device) {
+        let _ = device;
+        Self::seed(seed)
+    }
+
+    /// Enable or disable the deterministic mode of the backend.
+    ///
+    /// In deterministic mode, the backend only uses kernels giving bitwise identical results for
+    /// the same inputs on the same device, and panics on the operations known to be
+    /// nondeterministic instead of running them. Together with [seed_all](Backend::seed_all), a
+    /// training is then reproducible on a given backend and device.
+    ///
+    /// Returns `false` when the backend can't guarantee deterministic results, e.g. because some
+    /// of its kernels can't be controlled. The default implementation is for the backends that
+    /// are always deterministic.
+    fn set_deterministic(deterministic: bool) -> bool {
+        let _ = deterministic;
+        true
+    }
+
+    /// Returns the devices available on the system for the backend.
+    ///
+    /// Tensors and modules can be moved between those devices with `to_device`. The default
+    /// implementation only returns the default device.
+    fn devices() -> Vec<Self::Device> {
+        alloc::vec![Self::Device::default()]
+    }
+
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device) {}
+
+    /// Returns the memory usage of the device, or `None` when the backend doesn't track it.
+    fn memory_stats(_device: &Self::Device) -> Option<MemoryStats> {
+        None
+    }
+}
+
+/// The memory usage of a device in bytes, returned by [memory_stats](Backend::memory_stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The memory of the live tensors.
+    pub bytes_in_use: usize,
+    /// The peak memory of the live tensors.
+    pub peak_bytes_in_use: usize,
+    /// The memory allocated by the backend, including the memory kept for reuse.
+    pub bytes_reserved: usize,
+    /// The peak memory allocated by the backend.
+    pub peak_bytes_reserved: usize,
 }
 
 /// Trait that allows a backend to support autodiff.
@@ -4,6 +4,8 @@ use alloc::vec::Vec;
 
 use crate::{tensor::Shape, Element, ElementConversion};
 
+use half::{bf16, f16};
+
 use rand::{distributions::Standard, Rng, RngCore};
 
 /// Data structure for serializing and deserializing tensor data.
@@ -141,6 +143,24 @@ impl<const D: usize, E: Element> Data<E, D> {
         }
     }
 
+    /// Converts the data to half precision floats, e.g. to create tensors on a backend running
+    /// in f16.
+    pub fn into_f16(self) -> Data<f16, D> {
+        self.convert()
+    }
+
+    /// Converts the data to brain floating point numbers, e.g. to create tensors on a backend
+    /// running in bf16.
+    pub fn into_bf16(self) -> Data<bf16, D> {
+        self.convert()
+    }
+
+    /// Converts the data to full precision floats, e.g. to read the output of a backend running
+    /// in half precision.
+    pub fn into_f32(self) -> Data<f32, D> {
+        self.convert()
+    }
+
     /// Asserts each value is within a given range.
     ///
     /// # Arguments
@@ -449,6 +469,34 @@ impl<
     }
 }
 
+impl<
+        Elem: core::fmt::Debug + Copy,
+        const A: usize,
+        const B: usize,
+        const C: usize,
+        const D: usize,
+        const E: usize,
+    > From<[[[[[Elem; E]; D]; C]; B]; A]> for Data<Elem, 5>
+{
+    fn from(elems: [[[[[Elem; E]; D]; C]; B]; A]) -> Self {
+        let mut data = Vec::with_capacity(A * B * C * D * E);
+
+        for elem in elems.into_iter().take(A) {
+            for elem in elem.into_iter().take(B) {
+                for elem in elem.into_iter().take(C) {
+                    for elem in elem.into_iter().take(D) {
+                        for elem in elem.into_iter().take(E) {
+                            data.push(elem);
+                        }
+                    }
+                }
+            }
+        }
+
+        Data::new(data, Shape::new([A, B, C, D, E]))
+    }
+}
+
 impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(format!("{:?}", &self.value).as_str())
@@ -507,4 +555,15 @@ mod tests {
 
         data1.assert_approx_eq(&data2, 2);
     }
+
+    #[test]
+    fn should_convert_to_half_precision() {
+        let data = Data::<f32, 1>::from([1.0, 0.1, 65504.0, 1e6]);
+
+        let f16 = data.clone().into_f16().into_f32();
+        let bf16 = data.into_bf16().into_f32();
+
+        assert_eq!(f16.value, vec![1.0, 0.099975586, 65504.0, f32::INFINITY]);
+        assert_eq!(bf16.value, vec![1.0, 0.100097656, 65536.0, 999424.0]);
+    }
 }
@@ -0,0 +1,199 @@
+use core::f64::consts::PI;
+
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::ops::PadMode;
+use crate::{check, Int, Tensor};
+
+/// Computes the discrete Fourier transform of complex values along a dimension.
+///
+/// Complex values are represented by two tensors of the same shape, holding the real and the
+/// imaginary parts.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the same shape as the input.
+pub fn fft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("FFT", dim));
+    check!(TensorCheck::complex_parts(
+        "FFT",
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let (real, imag) = B::fft(real.into_primitive(), imag.into_primitive(), dim, false);
+
+    (Tensor::from_primitive(real), Tensor::from_primitive(imag))
+}
+
+/// Computes the inverse discrete Fourier transform of complex values along a dimension.
+///
+/// The output is normalized by the size of the dimension, so `ifft(fft(x))` is `x`.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the inverse transform, with the same shape as the input.
+pub fn ifft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("IFFT", dim));
+    check!(TensorCheck::complex_parts(
+        "IFFT",
+        &real.shape(),
+        &imag.shape()
+    ));
+
+    let (real, imag) = B::fft(real.into_primitive(), imag.into_primitive(), dim, true);
+
+    (Tensor::from_primitive(real), Tensor::from_primitive(imag))
+}
+
+/// Computes the discrete Fourier transform of real values along a dimension.
+///
+/// The transform of real values is conjugate symmetric, so only the non-negative frequencies
+/// are returned.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, the dimension `dim` having a size of
+/// `n / 2 + 1`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{fft, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0]);
+///     let (real, imag) = fft::rfft(tensor, 0);
+///     println!("{}", real); // [10.0, -2.0, -2.0]
+///     println!("{}", imag); // [0.0, 2.0, 0.0]
+/// }
+/// ```
+pub fn rfft<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("RFFT", dim));
+
+    let (real, imag) = B::rfft(tensor.into_primitive(), dim);
+
+    (Tensor::from_primitive(real), Tensor::from_primitive(imag))
+}
+
+/// Computes the inverse of [rfft] along a dimension.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the non-negative frequencies.
+/// * `imag` - The imaginary part of the non-negative frequencies.
+/// * `dim` - The dimension along which to compute the transform.
+/// * `size` - The size of the output along the dimension `dim`, which can't be inferred from
+///   the number of frequencies. Only the first `size / 2 + 1` frequencies are used, missing
+///   ones are considered to be zeros.
+///
+/// # Returns
+///
+/// The real values, the dimension `dim` having the given size.
+pub fn irfft<B: Backend, const D: usize>(
+    real: Tensor<B, D>,
+    imag: Tensor<B, D>,
+    dim: usize,
+    size: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::dim_ops::<D>("IRFFT", dim));
+    check!(TensorCheck::complex_parts(
+        "IRFFT",
+        &real.shape(),
+        &imag.shape()
+    ));
+    check!(TensorCheck::irfft(size));
+
+    Tensor::from_primitive(B::irfft(
+        real.into_primitive(),
+        imag.into_primitive(),
+        dim,
+        size,
+    ))
+}
+
+/// Creates a periodic Hann window, commonly used with [stft].
+pub fn hann_window<B: Backend>(size: usize, device: &B::Device) -> Tensor<B, 1> {
+    Tensor::<B, 1, Int>::arange_device(0..size, device)
+        .float()
+        .mul_scalar(2.0 * PI / size as f64)
+        .cos()
+        .mul_scalar(-0.5)
+        .add_scalar(0.5)
+}
+
+/// Computes the short-time Fourier transform of signals.
+///
+/// The signals are split into frames of `n_fft` samples every `hop_length` samples, each frame
+/// is multiplied by the window before computing its [rfft].
+///
+/// # Arguments
+///
+/// * `signal` - The signals of shape `[batch_size, num_samples]`.
+/// * `n_fft` - The number of samples of each frame.
+/// * `hop_length` - The number of samples between the start of two consecutive frames.
+/// * `window` - The window of size `n_fft`, no window is applied when `None`.
+/// * `center` - If the signals should be padded with `n_fft / 2` reflected samples on both
+///   sides, so that the frame `t` is centered on the sample `t * hop_length`.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, of shape
+/// `[batch_size, n_fft / 2 + 1, num_frames]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{fft, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let signal = Tensor::<B, 2>::random([2, 16000], burn_tensor::Distribution::Default);
+///     let window = fft::hann_window::<B>(400, &signal.device());
+///
+///     let (real, imag) = fft::stft(signal, 400, 160, Some(window), true);
+///     let power_spectrogram = real.powf(2.0) + imag.powf(2.0);
+///     println!("{:?}", power_spectrogram.dims()); // [2, 201, 101]
+/// }
+/// ```
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    n_fft: usize,
+    hop_length: usize,
+    window: Option<Tensor<B, 1>>,
+    center: bool,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    check!(TensorCheck::stft(
+        &signal.shape(),
+        n_fft,
+        hop_length,
+        window.as_ref().map(|window| window.dims()[0]),
+        center
+    ));
+
+    let signal = match center {
+        true => signal.pad([(0, 0), (n_fft / 2, n_fft / 2)], PadMode::Reflect),
+        false => signal,
+    };
+
+    let mut frames: Tensor<B, 3> = signal.unfold(1, n_fft, hop_length);
+    if let Some(window) = window {
+        frames = frames * window.reshape([1, 1, n_fft]);
+    }
+
+    let (real, imag) = rfft(frames, 2);
+
+    (real.swap_dims(1, 2), imag.swap_dims(1, 2))
+}
@@ -0,0 +1,227 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor};
+
+/// Computes the inverse of square matrices.
+///
+/// The matrices are stored in the last two dimensions, the other dimensions are batch
+/// dimensions.
+///
+/// # Notes
+///
+/// The result is unspecified for singular matrices, use [det] to detect them.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 7.0], [2.0, 6.0]]);
+///     let inverse = linalg::inverse(tensor);
+///     println!("{}", inverse);
+///     // [[0.6, -0.7], [-0.2, 0.4]]
+/// }
+/// ```
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix("Inverse", &tensor.shape()));
+
+    Tensor::from_primitive(B::inverse(tensor.into_primitive()))
+}
+
+/// Solves the linear systems `lhs @ x = rhs` for `x`.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices of shape `[..., n, n]`.
+/// * `rhs` - The right hand side of shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions of shape `[..., n, k]`.
+///
+/// # Notes
+///
+/// This is more accurate and faster than multiplying by the [inverse]. The result is
+/// unspecified for singular matrices.
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::solve("Solve", &lhs.shape(), &rhs.shape()));
+
+    Tensor::from_primitive(B::solve(lhs.into_primitive(), rhs.into_primitive()))
+}
+
+/// Solves the linear systems `lhs @ x = rhs` for `x`, where `lhs` is triangular.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices of shape `[..., n, n]`.
+/// * `rhs` - The right hand side of shape `[..., n, k]`.
+/// * `upper` - Whether `lhs` is upper triangular instead of lower triangular, only that triangle
+///   is read.
+/// * `unit_diagonal` - Whether the diagonal of `lhs` is assumed to be ones.
+///
+/// # Returns
+///
+/// The solutions of shape `[..., n, k]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     // Solving A @ x = b with the Cholesky decomposition A = L @ L^T.
+///     let a = Tensor::<B, 2>::from_floats([[4.0, 2.0], [2.0, 3.0]]);
+///     let b = Tensor::<B, 2>::from_floats([[2.0], [1.0]]);
+///     let lower = linalg::cholesky(a);
+///     let y = linalg::triangular_solve(lower.clone(), b, false, false);
+///     let x = linalg::triangular_solve(lower.transpose(), y, true, false);
+///     println!("{}", x); // [[0.5], [0.0]]
+/// }
+/// ```
+pub fn triangular_solve<B: Backend, const D: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+    upper: bool,
+    unit_diagonal: bool,
+) -> Tensor<B, D> {
+    check!(TensorCheck::solve(
+        "Triangular Solve",
+        &lhs.shape(),
+        &rhs.shape()
+    ));
+
+    Tensor::from_primitive(B::triangular_solve(
+        lhs.into_primitive(),
+        rhs.into_primitive(),
+        upper,
+        unit_diagonal,
+    ))
+}
+
+/// Computes the determinant of square matrices.
+///
+/// # Returns
+///
+/// The determinants with shape `[..., 1, 1]`, the matrix dimensions being kept with a size of
+/// one.
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix("Det", &tensor.shape()));
+
+    Tensor::from_primitive(B::det(tensor.into_primitive()))
+}
+
+/// Computes the Moore-Penrose pseudo-inverse of matrices.
+///
+/// For matrices of shape `[..., m, n]`, the pseudo-inverse has shape `[..., n, m]`.
+///
+/// # Notes
+///
+/// The pseudo-inverse is computed with the normal equations, `(A^T A)^-1 A^T` when `m >= n` and
+/// `A^T (A A^T)^-1` otherwise, so the matrices must have full rank.
+pub fn pinverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::matrix::<D>("Pinverse"));
+
+    let dims = tensor.dims();
+    let transposed = tensor.clone().transpose();
+
+    match dims[D - 2] >= dims[D - 1] {
+        true => solve(transposed.clone().matmul(tensor), transposed),
+        false => {
+            let gram = tensor.clone().matmul(transposed.clone());
+            // A^T (A A^T)^-1 = ((A A^T)^-1 A)^T, the gram matrix being symmetric.
+            solve(gram, tensor).transpose()
+        }
+    }
+}
+
+/// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+///
+/// Only the lower triangle of the matrices is used.
+///
+/// # Returns
+///
+/// The lower triangular matrices `L` such that `L @ L^T` is the input.
+///
+/// # Notes
+///
+/// The result is unspecified for matrices that are not positive-definite.
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::square_matrix("Cholesky", &tensor.shape()));
+
+    Tensor::from_primitive(B::cholesky(tensor.into_primitive()))
+}
+
+/// Computes the reduced QR decomposition of matrices.
+///
+/// # Returns
+///
+/// For matrices of shape `[..., m, n]` and `k = min(m, n)`, the matrices `Q` of shape
+/// `[..., m, k]` with orthonormal columns and the upper triangular matrices `R` of shape
+/// `[..., k, n]` such that `Q @ R` is the input.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("QR"));
+
+    let (q, r) = B::qr(tensor.into_primitive());
+
+    (Tensor::from_primitive(q), Tensor::from_primitive(r))
+}
+
+/// Computes the eigenvalues and eigenvectors of symmetric matrices.
+///
+/// # Returns
+///
+/// The eigenvalues in ascending order with shape `[..., 1, n]` and the matrices of shape
+/// `[..., n, n]` whose columns are the matching orthonormal eigenvectors. The eigenvalues are
+/// stored as rows, so `vectors * values` scales each eigenvector by its eigenvalue.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let tensor = Tensor::<B, 2>::from_floats([[2.0, 1.0], [1.0, 2.0]]);
+///     let (values, vectors) = linalg::eigh(tensor.clone());
+///     println!("{}", values);
+///     // [[1.0, 3.0]]
+///
+///     // A @ V = V * values
+///     let reconstructed = vectors.clone() * values;
+///     println!("{}", tensor.matmul(vectors) - reconstructed);
+/// }
+/// ```
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::square_matrix("Eigh", &tensor.shape()));
+
+    let (values, vectors) = B::eigh(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(values),
+        Tensor::from_primitive(vectors),
+    )
+}
+
+/// Computes the reduced singular value decomposition of matrices.
+///
+/// # Returns
+///
+/// For matrices of shape `[..., m, n]` and `k = min(m, n)`, the matrices `U` of shape
+/// `[..., m, k]`, the singular values in descending order with shape `[..., 1, k]` and the
+/// matrices `Vh` of shape `[..., k, n]`, such that `(U * S) @ Vh` is the input.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::matrix::<D>("SVD"));
+
+    let (u, s, vh) = B::svd(tensor.into_primitive());
+
+    (
+        Tensor::from_primitive(u),
+        Tensor::from_primitive(s),
+        Tensor::from_primitive(vh),
+    )
+}
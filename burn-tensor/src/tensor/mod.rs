@@ -19,6 +19,12 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The fast Fourier transform module.
+pub mod fft;
+
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
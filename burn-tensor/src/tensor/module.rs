@@ -1,7 +1,12 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{
+        AttentionDropout, ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions,
+        UnfoldOptions,
+    },
+    Bool, Int, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -48,6 +53,24 @@ where
     ))
 }
 
+/// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
+pub fn conv3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -84,6 +107,24 @@ where
     ))
 }
 
+/// Applies a [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d).
+pub fn conv_transpose3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv_transpose3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [4D to 3D unfold](crate::ops::ModuleOps::unfold4d).
 pub fn unfold4d<B>(x: Tensor<B, 4>, kernel_size: [usize; 2], options: UnfoldOptions) -> Tensor<B, 3>
 where
@@ -92,6 +133,26 @@ where
     Tensor::new(B::unfold4d(x.primitive, kernel_size, options))
 }
 
+/// Applies a [3D to 4D fold](crate::ops::ModuleOps::fold4d).
+pub fn fold4d<B>(
+    x: Tensor<B, 3>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::fold4d(
+        &x.shape(),
+        output_size,
+        kernel_size,
+        &options
+    ));
+
+    Tensor::new(B::fold4d(x.primitive, output_size, kernel_size, options))
+}
+
 /// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
 pub fn max_pool1d<B>(
     x: Tensor<B, 3>,
@@ -212,6 +273,27 @@ where
     Tensor::new(B::adaptive_avg_pool2d(x.primitive, output_size))
 }
 
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_max_pool2d(x.primitive, output_size))
+}
+
+/// Applies a [2D adaptive max pooling with indices](crate::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+pub fn adaptive_max_pool2d_with_indices<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+) -> (Tensor<B, 4>, Tensor<B, 4, Int>)
+where
+    B: Backend,
+{
+    let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+
+    (Tensor::new(output.output), Tensor::new(output.indices))
+}
+
 /// Applies a [1D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool1d).
 pub fn adaptive_avg_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
 where
@@ -219,3 +301,177 @@ where
 {
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
+
+/// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate), resizing the last two
+/// dimensions to the given output size.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::interpolate;
+/// use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+///     let options = InterpolateOptions::new(InterpolateMode::Bilinear, true);
+///     let output = interpolate(x, [3, 3], options);
+///     println!("{}", output);
+///     // [[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]
+/// }
+/// ```
+pub fn interpolate<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate(x.primitive, output_size, options))
+}
+
+/// Applies a 1D interpolation, resizing the last dimension to the given output size.
+///
+/// The [bilinear](crate::ops::InterpolateMode::Bilinear) and
+/// [bicubic](crate::ops::InterpolateMode::Bicubic) modes respectively apply a linear and a cubic
+/// interpolation.
+pub fn interpolate1d<B>(
+    x: Tensor<B, 3>,
+    output_size: usize,
+    options: InterpolateOptions,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [batch_size, channels, length] = x.dims();
+    let x = x.reshape([batch_size, channels, 1, length]);
+
+    interpolate(x, [1, output_size], options).reshape([batch_size, channels, output_size])
+}
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample_2d), interpolating the input
+/// at the normalized coordinates given by the grid.
+///
+/// # Panics
+///
+/// If the grid doesn't have the shape `[batch_size, height_out, width_out, 2]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::module::grid_sample_2d;
+/// use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+/// use burn_tensor::Tensor;
+///
+/// fn example<B: Backend>() {
+///     let x = Tensor::<B, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+///     // Samples the center and the top left corner.
+///     let grid = Tensor::<B, 4>::from_floats([[[[0.0, 0.0], [-1.0, -1.0]]]]);
+///     let options = GridSampleOptions::new(
+///         InterpolateMode::Bilinear,
+///         GridSamplePaddingMode::Border,
+///         true,
+///     );
+///     let output = grid_sample_2d(x, grid, options);
+///     println!("{}", output); // [[[[2.5, 1.0]]]]
+/// }
+/// ```
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample_2d(&x.shape(), &grid.shape()));
+
+    Tensor::new(B::grid_sample_2d(x.primitive, grid.primitive, options))
+}
+
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::attention).
+///
+/// The attention scores where the mask is `true` are replaced with `min_float` before the
+/// softmax, e.g. to ignore padding tokens or future tokens.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, n_heads, seq_length_1, d_k]`
+/// - key: `[batch_size, n_heads, seq_length_2, d_k]`
+/// - value: `[batch_size, n_heads, seq_length_2, d_v]`
+/// - mask: `[batch_size, seq_length_1, seq_length_2]`
+/// - output: `[batch_size, n_heads, seq_length_1, d_v]`
+pub fn attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 3, Bool>>,
+    min_float: f64,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    attention_with_dropout(query, key, value, mask, None, min_float)
+}
+
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::attention), the attention
+/// weights being dropped with the given [dropout](AttentionDropout).
+///
+/// The dropout mask is generated from its seed by the attention, so it isn't materialized by the
+/// backends computing the attention without storing the attention weights.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, n_heads, seq_length_1, d_k]`
+/// - key: `[batch_size, n_heads, seq_length_2, d_k]`
+/// - value: `[batch_size, n_heads, seq_length_2, d_v]`
+/// - mask: `[batch_size, seq_length_1, seq_length_2]`
+/// - output: `[batch_size, n_heads, seq_length_1, d_v]`
+pub fn attention_with_dropout<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 3, Bool>>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        mask.as_ref().map(|mask| mask.shape()).as_ref(),
+        dropout.as_ref(),
+    ));
+
+    Tensor::new(B::attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        mask.map(|mask| mask.primitive),
+        dropout,
+        min_float,
+    ))
+}
+
+/// Materializes the mask of the [attention dropout](AttentionDropout), the dropped weights being
+/// zero and the kept ones `1 / (1 - prob)`, e.g. to compute a reference of the attention.
+///
+/// # Shapes
+///
+/// - shape: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+pub fn attention_dropout_mask<B>(
+    dropout: AttentionDropout,
+    shape: [usize; 4],
+    device: &B::Device,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    crate::ops::attention::attention_dropout_mask::<B>(dropout, shape, device)
+}
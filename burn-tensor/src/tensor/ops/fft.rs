@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use libm::{cos, sin};
+
+use super::FloatTensor;
+use crate::{backend::Backend, Data, Shape, Tensor};
+
+/// Discrete Fourier transform operations.
+///
+/// Complex values are represented by two tensors of the same shape, holding the real and the
+/// imaginary parts. The default implementations multiply by the matrices of the discrete
+/// Fourier transform, so they are differentiable and run on every backend, but their cost is
+/// quadratic in the size of the transformed dimension. This trait let backend implementations
+/// override them with fast Fourier transforms.
+pub trait FftOps<B: Backend> {
+    /// Computes the discrete Fourier transform of complex values along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the values.
+    /// * `imag` - The imaginary part of the values.
+    /// * `dim` - The dimension along which to compute the transform.
+    /// * `inverse` - If the inverse transform should be computed, which is normalized by the
+    ///   size of the dimension.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform, with the same shape as the input.
+    fn fft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let real = Tensor::<B, D>::from_primitive(real);
+        let imag = Tensor::<B, D>::from_primitive(imag);
+        let size = real.dims()[dim];
+        let device = real.device();
+
+        let (sign, scale) = match inverse {
+            true => (1.0, 1.0 / size as f64),
+            false => (-1.0, 1.0),
+        };
+        let cos = dft_matrix::<B>(size, size, &device, |k, j| cos(angle(k, j, size)) * scale);
+        let sin = dft_matrix::<B>(size, size, &device, |k, j| {
+            sign * sin(angle(k, j, size)) * scale
+        });
+
+        // (a + ib)(cos + i sin) = (a cos - b sin) + i(a sin + b cos)
+        let output_real =
+            matmul_dim(real.clone(), dim, cos.clone()) - matmul_dim(imag.clone(), dim, sin.clone());
+        let output_imag = matmul_dim(real, dim, sin) + matmul_dim(imag, dim, cos);
+
+        (output_real.into_primitive(), output_imag.into_primitive())
+    }
+
+    /// Computes the discrete Fourier transform of real values along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The real values.
+    /// * `dim` - The dimension along which to compute the transform.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the non-negative frequencies of the transform, the
+    /// dimension `dim` having a size of `n / 2 + 1`.
+    fn rfft<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let size = tensor.dims()[dim];
+        let num_frequencies = size / 2 + 1;
+        let device = tensor.device();
+
+        let cos = dft_matrix::<B>(size, num_frequencies, &device, |j, k| {
+            cos(angle(k, j, size))
+        });
+        let sin = dft_matrix::<B>(size, num_frequencies, &device, |j, k| {
+            -sin(angle(k, j, size))
+        });
+
+        (
+            matmul_dim(tensor.clone(), dim, cos).into_primitive(),
+            matmul_dim(tensor, dim, sin).into_primitive(),
+        )
+    }
+
+    /// Computes the inverse of [rfft](FftOps::rfft) along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the non-negative frequencies.
+    /// * `imag` - The imaginary part of the non-negative frequencies.
+    /// * `dim` - The dimension along which to compute the transform.
+    /// * `size` - The size of the output along the dimension `dim`. Only the first
+    ///   `size / 2 + 1` frequencies are used, missing ones are considered to be zeros.
+    ///
+    /// # Returns
+    ///
+    /// The real values, the dimension `dim` having the given size.
+    fn irfft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        size: usize,
+    ) -> FloatTensor<B, D> {
+        let mut real = Tensor::<B, D>::from_primitive(real);
+        let mut imag = Tensor::<B, D>::from_primitive(imag);
+        let device = real.device();
+
+        let num_frequencies = usize::min(real.dims()[dim], size / 2 + 1);
+        let mut ranges = real.dims().map(|dim| 0..dim);
+        ranges[dim] = 0..num_frequencies;
+        real = real.slice(ranges.clone());
+        imag = imag.slice(ranges);
+
+        // The negative frequencies are the conjugates of the positive ones, so every frequency
+        // except the first and the middle one count twice.
+        let weight = |k: usize| match k == 0 || 2 * k == size {
+            true => 1.0 / size as f64,
+            false => 2.0 / size as f64,
+        };
+        let cos = dft_matrix::<B>(num_frequencies, size, &device, |k, j| {
+            weight(k) * cos(angle(k, j, size))
+        });
+        let sin = dft_matrix::<B>(num_frequencies, size, &device, |k, j| {
+            weight(k) * sin(angle(k, j, size))
+        });
+
+        (matmul_dim(real, dim, cos) - matmul_dim(imag, dim, sin)).into_primitive()
+    }
+}
+
+/// The angle `2 pi k j / n`, computed with the remainder of `k j` to keep the precision.
+fn angle(k: usize, j: usize, size: usize) -> f64 {
+    2.0 * PI * ((k * j) % size) as f64 / size as f64
+}
+
+/// Creates a matrix from the value of each position.
+fn dft_matrix<B: Backend>(
+    num_rows: usize,
+    num_columns: usize,
+    device: &B::Device,
+    value: impl Fn(usize, usize) -> f64,
+) -> Tensor<B, 2> {
+    let values = (0..num_rows * num_columns)
+        .map(|i| value(i / num_columns, i % num_columns) as f32)
+        .collect::<Vec<_>>();
+    let data = Data::new(values, Shape::new([num_rows, num_columns]));
+
+    Tensor::from_data_device(data.convert(), device)
+}
+
+/// Multiplies the dimension `dim` of the tensor by the given matrix, the size of the dimension
+/// becoming the number of columns of the matrix.
+fn matmul_dim<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    dim: usize,
+    matrix: Tensor<B, 2>,
+) -> Tensor<B, D> {
+    let tensor = tensor.swap_dims(dim, D - 1);
+    let mut dims = tensor.dims();
+    let size = dims[D - 1];
+    let num_rows = dims.iter().product::<usize>() / usize::max(size, 1);
+
+    let output = tensor.reshape([num_rows, size]).matmul(matrix);
+    dims[D - 1] = output.dims()[1];
+
+    output.reshape(dims).swap_dims(dim, D - 1)
+}
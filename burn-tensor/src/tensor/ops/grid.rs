@@ -0,0 +1,15 @@
+/// The indexing convention of the coordinate grids created by
+/// [meshgrid](crate::Tensor::meshgrid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridIndexing {
+    /// Matrix indexing, also called `ij`: the `i`-th output dimension follows the `i`-th input.
+    ///
+    /// Coordinates of sizes `[3]` and `[4]` give grids of shape `[3, 4]`.
+    Matrix,
+
+    /// Cartesian indexing, also called `xy`: the first two output dimensions are swapped, so the
+    /// first input varies along the columns.
+    ///
+    /// Coordinates of sizes `[3]` and `[4]` give grids of shape `[4, 3]`.
+    Cartesian,
+}
@@ -1,4 +1,4 @@
-use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
+use super::{pad_indices, BoolTensor, Device, FloatTensor, IntElem, IntTensor, PadMode};
 use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -682,6 +682,68 @@ pub trait IntTensorOps<B: Backend> {
     /// The mean of all elements in the tensor along the dimension.
     fn int_mean_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
+    /// Computes the cumulative sum of the elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative sum of.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative sum of the elements in the tensor along the dimension.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let size = B::int_shape(&tensor).dims[dim];
+        let mut ranges: [Range<usize>; D] = B::int_shape(&tensor).dims.map(|size| 0..size);
+        let mut outputs: Vec<IntTensor<B, D>> = Vec::with_capacity(size);
+
+        for index in 0..size {
+            ranges[dim] = index..index + 1;
+            let slice = B::int_slice(tensor.clone(), ranges.clone());
+
+            outputs.push(match outputs.last() {
+                Some(previous) => B::int_add(previous.clone(), slice),
+                None => slice,
+            });
+        }
+
+        match outputs.is_empty() {
+            true => tensor,
+            false => B::int_cat(outputs, dim),
+        }
+    }
+
+    /// Computes the cumulative product of the elements in the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the cumulative product of.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative product of the elements in the tensor along the dimension.
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let size = B::int_shape(&tensor).dims[dim];
+        let mut ranges: [Range<usize>; D] = B::int_shape(&tensor).dims.map(|size| 0..size);
+        let mut outputs: Vec<IntTensor<B, D>> = Vec::with_capacity(size);
+
+        for index in 0..size {
+            ranges[dim] = index..index + 1;
+            let slice = B::int_slice(tensor.clone(), ranges.clone());
+
+            outputs.push(match outputs.last() {
+                Some(previous) => B::int_mul(previous.clone(), slice),
+                None => slice,
+            });
+        }
+
+        match outputs.is_empty() {
+            true => tensor,
+            false => B::int_cat(outputs, dim),
+        }
+    }
+
     /// Gets the indices of the maximum elements along a dimension.
     ///
     /// # Arguments
@@ -735,7 +797,7 @@ pub trait IntTensorOps<B: Backend> {
     fn int_max_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
         let index = B::int_argmax(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the maximum elements and corresponding indices along a dimension.
@@ -753,7 +815,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (IntTensor<B, D>, IntTensor<B, D>) {
         let index = B::int_argmax(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
+        let values = B::int_gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -787,7 +849,7 @@ pub trait IntTensorOps<B: Backend> {
     fn int_min_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
         let index = B::int_argmin(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
 
     /// Gets the minimum elements and corresponding indices along a dimension.
@@ -805,7 +867,7 @@ pub trait IntTensorOps<B: Backend> {
         dim: usize,
     ) -> (IntTensor<B, D>, IntTensor<B, D>) {
         let indices = B::int_argmin(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, indices.clone());
+        let values = B::int_gather(dim, tensor, indices.clone());
 
         (values, indices)
     }
@@ -850,4 +912,60 @@ pub trait IntTensorOps<B: Backend> {
         dim1: usize,
         dim2: usize,
     ) -> IntTensor<B, D>;
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements to add before and after each dimension.
+    /// * `mode` - How the padded values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded int tensor.
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> IntTensor<B, D> {
+        let device = B::int_device(&tensor);
+        let mut tensor = tensor;
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let shape = B::int_shape(&tensor);
+
+            tensor = match mode {
+                PadMode::Constant(value) => {
+                    let mut parts = Vec::with_capacity(3);
+                    let mut shape_pad = shape.clone();
+
+                    if before > 0 {
+                        shape_pad.dims[dim] = before;
+                        parts.push(B::int_full(shape_pad.clone(), value.elem(), &device));
+                    }
+                    parts.push(tensor);
+                    if after > 0 {
+                        shape_pad.dims[dim] = after;
+                        parts.push(B::int_full(shape_pad, value.elem(), &device));
+                    }
+
+                    B::int_cat(parts, dim)
+                }
+                _ => {
+                    let indices = pad_indices(&mode, shape.dims[dim], before, after);
+                    let num_indices = indices.len();
+                    let indices = Data::new(indices, Shape::new([num_indices]));
+
+                    B::int_select(tensor, dim, B::int_from_data(indices.convert(), &device))
+                }
+            };
+        }
+
+        tensor
+    }
 }
@@ -0,0 +1,574 @@
+use alloc::vec::Vec;
+
+use super::FloatTensor;
+use crate::{backend::Backend, Data, Int, Shape, Tensor};
+
+/// Linear algebra operations on batched matrices.
+///
+/// The matrices are stored in the last two dimensions of the tensors, the other dimensions are
+/// batch dimensions. The default implementations use a Gauss-Jordan elimination with partial
+/// pivoting written with tensor operations, so they are differentiable and run on every backend.
+/// This trait let backend implementations override them with dedicated kernels.
+pub trait LinalgOps<B: Backend> {
+    /// Computes the inverse of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverse matrices, with the same shape as the input.
+    fn inverse<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let shape = B::shape(&tensor);
+        let device = B::device(&tensor);
+        let identity = identity::<B, D>(&shape, &device);
+
+        let (solution, _det) = gauss_jordan(Tensor::<B, D>::from_primitive(tensor), identity);
+
+        solution.into_primitive()
+    }
+
+    /// Solves the linear systems `lhs @ x = rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices of shape `[..., n, n]`.
+    /// * `rhs` - The right hand side of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `x` of shape `[..., n, k]`.
+    fn solve<const D: usize>(lhs: FloatTensor<B, D>, rhs: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let (solution, _det) = gauss_jordan(
+            Tensor::<B, D>::from_primitive(lhs),
+            Tensor::from_primitive(rhs),
+        );
+
+        solution.into_primitive()
+    }
+
+    /// Solves the linear systems `lhs @ x = rhs` where `lhs` is triangular.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices of shape `[..., n, n]`, only the triangle given by `upper`
+    ///   is used.
+    /// * `rhs` - The right hand side of shape `[..., n, k]`.
+    /// * `upper` - Whether `lhs` is upper triangular instead of lower triangular.
+    /// * `unit_diagonal` - Whether the diagonal of `lhs` is assumed to be ones and not read.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `x` of shape `[..., n, k]`.
+    fn triangular_solve<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+        upper: bool,
+        unit_diagonal: bool,
+    ) -> FloatTensor<B, D> {
+        let lhs = Tensor::<B, D>::from_primitive(lhs);
+        let rhs = Tensor::<B, D>::from_primitive(rhs);
+        let size = lhs.dims()[D - 1];
+
+        let order = match upper {
+            true => (0..size).rev().collect::<Vec<_>>(),
+            false => (0..size).collect(),
+        };
+        let mut solution = rhs.zeros_like();
+
+        for i in order {
+            // The rows that are not solved yet are still zeros, so the product only covers the
+            // known ones and ignores the other triangle.
+            let values = row(&rhs, i) - row(&lhs, i).matmul(solution.clone());
+            let values = match unit_diagonal {
+                true => values,
+                false => values / element(&lhs, i, i),
+            };
+
+            solution = assign_row(solution, i, values);
+        }
+
+        solution.into_primitive()
+    }
+
+    /// Computes the determinant of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The determinants of shape `[..., 1, 1]`.
+    fn det<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let mut shape = B::shape(&tensor);
+        let device = B::device(&tensor);
+        shape.dims[D - 1] = 1;
+
+        let (_solution, det) = gauss_jordan(
+            Tensor::<B, D>::from_primitive(tensor),
+            Tensor::zeros_device(shape, &device),
+        );
+
+        det.into_primitive()
+    }
+
+    /// Computes the Cholesky decomposition of symmetric positive-definite matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`, only their lower triangle is used.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular matrices `L` such that `L @ L^T` is the input.
+    fn cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let dims = tensor.dims();
+        let device = tensor.device();
+        let size = dims[D - 1];
+
+        let mut lower = Tensor::<B, D>::zeros_device(dims, &device);
+
+        for j in 0..size {
+            let mut ranges_row = dims.map(|dim| 0..dim);
+            ranges_row[D - 2] = j..j + 1;
+            let row = lower.clone().slice(ranges_row);
+
+            // The columns after `j` are still zeros, so the product only covers the known ones.
+            let values = column(&tensor, j) - lower.clone().matmul(row.transpose());
+            let diagonal = element(&values, j, 0).sqrt();
+            let values = values / diagonal * mask::<B, D>(size, 1, &device, |i, _| i >= j);
+
+            lower = assign_column(lower, j, values);
+        }
+
+        lower.into_primitive()
+    }
+
+    /// Computes the reduced QR decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `Q` of shape `[..., m, k]` with orthonormal columns and the upper triangular
+    /// matrices `R` of shape `[..., k, n]`, where `k = min(m, n)`, such that `Q @ R` is the input.
+    fn qr<const D: usize>(tensor: FloatTensor<B, D>) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let dims = tensor.dims();
+        let device = tensor.device();
+        let size = usize::min(dims[D - 2], dims[D - 1]);
+
+        let mut shape_q = dims;
+        shape_q[D - 1] = size;
+        let mut q = Tensor::<B, D>::zeros_device(shape_q, &device);
+
+        // Gram-Schmidt orthogonalization, applied twice to keep the columns orthogonal.
+        for j in 0..size {
+            let mut values = column(&tensor, j);
+
+            for _ in 0..2 {
+                values = values.clone() - q.clone().matmul(q.clone().transpose().matmul(values));
+            }
+
+            let norm = (values.clone() * values.clone()).sum_dim(D - 2).sqrt();
+            let norm = norm.clone().mask_fill(norm.equal_elem(0.0), 1.0);
+
+            q = assign_column(q, j, values / norm);
+        }
+
+        let r = q.clone().transpose().matmul(tensor)
+            * mask::<B, D>(size, dims[D - 1], &device, |i, j| i <= j);
+
+        (q.into_primitive(), r.into_primitive())
+    }
+
+    /// Computes the eigendecomposition of symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The symmetric matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order with shape `[..., 1, n]` and the matrices of shape
+    /// `[..., n, n]` whose columns are the matching orthonormal eigenvectors.
+    fn eigh<const D: usize>(tensor: FloatTensor<B, D>) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let shape = B::shape(&tensor);
+        let device = B::device(&tensor);
+        let size = shape.dims[D - 1];
+
+        let mut matrices = Tensor::<B, D>::from_primitive(tensor);
+        let mut vectors = identity::<B, D>(&shape, &device);
+
+        for _ in 0..JACOBI_SWEEPS {
+            for p in 0..size {
+                for q in p + 1..size {
+                    let (cosine, sine) = jacobi_rotation(
+                        element(&matrices, p, p),
+                        element(&matrices, q, q),
+                        element(&matrices, p, q),
+                    );
+
+                    // The rotated matrices are symmetric, so rotating the columns of the
+                    // transposed matrices rotates their rows.
+                    matrices = rotate_columns(matrices, p, q, cosine.clone(), sine.clone());
+                    matrices =
+                        rotate_columns(matrices.transpose(), p, q, cosine.clone(), sine.clone());
+                    vectors = rotate_columns(vectors, p, q, cosine, sine);
+                }
+            }
+        }
+
+        let values = (matrices * identity::<B, D>(&shape, &device)).sum_dim(D - 2);
+        let permutation = sort_permutation(values.clone(), false);
+
+        (
+            values.matmul(permutation.clone()).into_primitive(),
+            vectors.matmul(permutation).into_primitive(),
+        )
+    }
+
+    /// Computes the reduced singular value decomposition of matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The matrices `U` of shape `[..., m, k]`, the singular values in descending order with
+    /// shape `[..., 1, k]` and the matrices `Vh` of shape `[..., k, n]`, where `k = min(m, n)`,
+    /// such that `(U * S) @ Vh` is the input.
+    fn svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let dims = tensor.dims();
+        let device = tensor.device();
+
+        if dims[D - 2] < dims[D - 1] {
+            let (u, s, vh) = Self::svd(tensor.transpose().into_primitive());
+            let u = Tensor::<B, D>::from_primitive(u);
+            let vh = Tensor::<B, D>::from_primitive(vh);
+
+            return (
+                vh.transpose().into_primitive(),
+                s,
+                u.transpose().into_primitive(),
+            );
+        }
+
+        let size = dims[D - 1];
+        let mut shape_vectors = dims;
+        shape_vectors[D - 2] = size;
+
+        let mut columns = tensor;
+        let mut vectors = identity::<B, D>(&Shape::new(shape_vectors), &device);
+
+        // One-sided Jacobi, the rotations orthogonalize the columns of the input.
+        for _ in 0..JACOBI_SWEEPS {
+            for p in 0..size {
+                for q in p + 1..size {
+                    let column_p = column(&columns, p);
+                    let column_q = column(&columns, q);
+
+                    let (cosine, sine) = jacobi_rotation(
+                        (column_p.clone() * column_p.clone()).sum_dim(D - 2),
+                        (column_q.clone() * column_q.clone()).sum_dim(D - 2),
+                        (column_p * column_q).sum_dim(D - 2),
+                    );
+
+                    columns = rotate_columns(columns, p, q, cosine.clone(), sine.clone());
+                    vectors = rotate_columns(vectors, p, q, cosine, sine);
+                }
+            }
+        }
+
+        let values = (columns.clone() * columns.clone()).sum_dim(D - 2).sqrt();
+        let norms = values
+            .clone()
+            .mask_fill(values.clone().equal_elem(0.0), 1.0);
+        let permutation = sort_permutation(values.clone(), true);
+
+        (
+            (columns / norms)
+                .matmul(permutation.clone())
+                .into_primitive(),
+            values.matmul(permutation.clone()).into_primitive(),
+            vectors.matmul(permutation).transpose().into_primitive(),
+        )
+    }
+}
+
+/// Number of sweeps over every pair of columns done by the Jacobi methods, which is enough for
+/// them to converge to the floating point precision on small and medium matrices.
+const JACOBI_SWEEPS: usize = 10;
+
+/// Creates a batch of identity matrices with the given shape.
+fn identity<B: Backend, const D: usize>(shape: &Shape<D>, device: &B::Device) -> Tensor<B, D> {
+    let size = shape.dims[D - 1];
+    let mut identity = mask::<B, D>(size, size, device, |row, column| row == column);
+
+    for dim in 0..D - 2 {
+        identity = identity.repeat(dim, shape.dims[dim]);
+    }
+
+    identity
+}
+
+/// Creates a single matrix of ones where the predicate holds and zeros elsewhere.
+fn mask<B: Backend, const D: usize>(
+    num_rows: usize,
+    num_columns: usize,
+    device: &B::Device,
+    predicate: impl Fn(usize, usize) -> bool,
+) -> Tensor<B, D> {
+    matrix(
+        num_rows,
+        num_columns,
+        device,
+        |row, column| match predicate(row, column) {
+            true => 1.0,
+            false => 0.0,
+        },
+    )
+}
+
+/// Creates a single matrix from the value of each position, with the batch dimensions of size
+/// one so that it broadcasts over a batch.
+fn matrix<B: Backend, const D: usize>(
+    num_rows: usize,
+    num_columns: usize,
+    device: &B::Device,
+    value: impl Fn(usize, usize) -> f32,
+) -> Tensor<B, D> {
+    let values = (0..num_rows * num_columns)
+        .map(|i| value(i / num_columns, i % num_columns))
+        .collect::<Vec<f32>>();
+
+    let mut shape = [1; D];
+    shape[D - 2] = num_rows;
+    shape[D - 1] = num_columns;
+
+    let data = Data::new(values, Shape::new(shape));
+    Tensor::from_data_device(data.convert(), device)
+}
+
+/// Returns the row at the given index, keeping the dimension with a size of one.
+fn row<B: Backend, const D: usize>(tensor: &Tensor<B, D>, index: usize) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 2] = index..index + 1;
+
+    tensor.clone().slice(ranges)
+}
+
+/// Assigns the row at the given index.
+fn assign_row<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    index: usize,
+    value: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 2] = index..index + 1;
+
+    tensor.slice_assign(ranges, value)
+}
+
+/// Returns the column at the given index, keeping the dimension with a size of one.
+fn column<B: Backend, const D: usize>(tensor: &Tensor<B, D>, index: usize) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 1] = index..index + 1;
+
+    tensor.clone().slice(ranges)
+}
+
+/// Assigns the column at the given index.
+fn assign_column<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    index: usize,
+    value: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 1] = index..index + 1;
+
+    tensor.slice_assign(ranges, value)
+}
+
+/// Returns the element at the given position of each matrix, with shape `[..., 1, 1]`.
+fn element<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    row: usize,
+    column: usize,
+) -> Tensor<B, D> {
+    let mut ranges = tensor.dims().map(|dim| 0..dim);
+    ranges[D - 2] = row..row + 1;
+    ranges[D - 1] = column..column + 1;
+
+    tensor.clone().slice(ranges)
+}
+
+/// Computes the cosine and the sine of the Jacobi rotation that diagonalizes the symmetric
+/// matrices `[[alpha, gamma], [gamma, beta]]`, all of shape `[..., 1, 1]`.
+fn jacobi_rotation<B: Backend, const D: usize>(
+    alpha: Tensor<B, D>,
+    beta: Tensor<B, D>,
+    gamma: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let is_diagonal = gamma.clone().equal_elem(0.0);
+    let gamma = gamma.mask_fill(is_diagonal.clone(), 1.0);
+
+    let zeta = (beta - alpha) / gamma.mul_scalar(2.0);
+    let sign = zeta
+        .clone()
+        .greater_equal_elem(0.0)
+        .float()
+        .mul_scalar(2.0)
+        .sub_scalar(1.0);
+    let tangent = sign / (zeta.clone().abs() + (zeta.clone() * zeta).add_scalar(1.0).sqrt());
+    let tangent = tangent.mask_fill(is_diagonal, 0.0);
+
+    let cosine = (tangent.clone() * tangent.clone())
+        .add_scalar(1.0)
+        .sqrt()
+        .recip();
+    let sine = tangent * cosine.clone();
+
+    (cosine, sine)
+}
+
+/// Rotates the columns `p` and `q` of the matrices by the given angles.
+fn rotate_columns<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    p: usize,
+    q: usize,
+    cosine: Tensor<B, D>,
+    sine: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let column_p = column(&tensor, p);
+    let column_q = column(&tensor, q);
+
+    let rotated_p = column_p.clone() * cosine.clone() - column_q.clone() * sine.clone();
+    let rotated_q = column_p * sine + column_q * cosine;
+
+    assign_column(assign_column(tensor, p, rotated_p), q, rotated_q)
+}
+
+/// Computes the permutation matrices sorting the values of shape `[..., 1, n]`.
+///
+/// Multiplying the values, or matrices whose columns match the values, by the permutation
+/// sorts them. Equal values keep their order.
+fn sort_permutation<B: Backend, const D: usize>(
+    values: Tensor<B, D>,
+    descending: bool,
+) -> Tensor<B, D> {
+    let size = values.dims()[D - 1];
+    let device = values.device();
+
+    // The element [i, j] is the value j for `others` and the value i for `current`.
+    let others = values.repeat(D - 2, size);
+    let current = others.clone().transpose();
+
+    let before = match descending {
+        true => others.clone().greater(current.clone()),
+        false => others.clone().lower(current.clone()),
+    };
+    let ties = others.equal(current).float() * mask::<B, D>(size, size, &device, |i, j| j < i);
+    let ranks = (before.float() + ties).sum_dim(D - 1).repeat(D - 1, size);
+
+    let positions = matrix::<B, D>(size, size, &device, |_, column| column as f32);
+
+    (ranks - positions).abs().lower_elem(0.5).float()
+}
+
+/// Solves `lhs @ x = rhs` with a Gauss-Jordan elimination using partial pivoting.
+///
+/// Returns the solution and the determinant of `lhs`, with shape `[..., 1, 1]`. A zero pivot
+/// is replaced by one for the elimination, which keeps every value finite and gives a zero
+/// determinant, but the solution is then meaningless.
+fn gauss_jordan<B: Backend, const D: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let dims = lhs.dims();
+    let device = lhs.device();
+    let size = dims[D - 1];
+    let num_columns = rhs.dims()[D - 1];
+
+    let mut shape_scalar = dims;
+    shape_scalar[D - 2] = 1;
+    shape_scalar[D - 1] = 1;
+
+    // Row index of each element of a column, used to build the row permutations.
+    let mut shape_rows = [1; D];
+    shape_rows[D - 2] = size;
+    let mut rows = Tensor::<B, 1, Int>::arange_device(0..size, &device).reshape(shape_rows);
+    for (dim, times) in dims.iter().enumerate().take(D - 2) {
+        rows = rows.repeat(dim, *times);
+    }
+
+    let mut lhs = lhs;
+    let mut rhs = rhs;
+    let mut det = Tensor::<B, D>::ones_device(shape_scalar, &device);
+
+    for k in 0..size {
+        let mut ranges = dims.map(|dim| 0..dim);
+
+        // Find the row with the largest absolute value in the current column.
+        ranges[D - 2] = k..size;
+        ranges[D - 1] = k..k + 1;
+        let pivot = lhs
+            .clone()
+            .slice(ranges.clone())
+            .abs()
+            .argmax(D - 2)
+            .add_scalar(k as i64);
+
+        // Swap the current row with the pivot row.
+        let pivot_rows = pivot.clone().repeat(D - 2, size);
+        let offset = pivot_rows.clone().sub_scalar(k as i64);
+        let is_current = rows.clone().equal_elem(k as i64).int();
+        let is_pivot = rows.clone().equal(pivot_rows).int();
+        let permutation = rows.clone() + is_current * offset.clone() - is_pivot * offset;
+
+        lhs = lhs.gather(D - 2, permutation.clone().repeat(D - 1, size));
+        rhs = rhs.gather(D - 2, permutation.repeat(D - 1, num_columns));
+
+        let sign = pivot
+            .equal_elem(k as i64)
+            .int()
+            .float()
+            .mul_scalar(2.0)
+            .sub_scalar(1.0);
+
+        ranges[D - 2] = k..k + 1;
+        ranges[D - 1] = k..k + 1;
+        let pivot_value = lhs.clone().slice(ranges.clone());
+        det = det * sign * pivot_value.clone();
+        let pivot_value = pivot_value
+            .clone()
+            .mask_fill(pivot_value.equal_elem(0.0), 1.0);
+
+        // Normalize the pivot row and eliminate the current column from the other rows.
+        let mut ranges_lhs_row = ranges.clone();
+        ranges_lhs_row[D - 1] = 0..size;
+        let mut ranges_rhs_row = ranges;
+        ranges_rhs_row[D - 1] = 0..num_columns;
+
+        let lhs_row = lhs.clone().slice(ranges_lhs_row.clone()) / pivot_value.clone();
+        let rhs_row = rhs.clone().slice(ranges_rhs_row.clone()) / pivot_value;
+
+        let mut ranges_column = dims.map(|dim| 0..dim);
+        ranges_column[D - 1] = k..k + 1;
+        let factors = lhs.clone().slice(ranges_column);
+
+        lhs = (lhs - factors.clone().matmul(lhs_row.clone())).slice_assign(ranges_lhs_row, lhs_row);
+        rhs = (rhs - factors.matmul(rhs_row.clone())).slice_assign(ranges_rhs_row, rhs_row);
+    }
+
+    (rhs, det)
+}
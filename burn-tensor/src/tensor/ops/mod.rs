@@ -1,13 +1,21 @@
 mod activation;
 mod alias;
 mod bool_tensor;
+mod fft;
+mod grid;
 mod int_tensor;
+mod linalg;
 mod modules;
+mod pad;
 mod tensor;
 
 pub use activation::*;
 pub use alias::*;
 pub use bool_tensor::*;
+pub use fft::*;
+pub use grid::*;
 pub use int_tensor::*;
+pub use linalg::*;
 pub use modules::*;
+pub use pad::*;
 pub use tensor::*;
@@ -0,0 +1,258 @@
+use crate::backend::Backend;
+use crate::ops::{AttentionBackward, AttentionDropout, AttentionForward, BoolTensor, FloatTensor};
+use crate::{Bool, Data, Shape, Tensor};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The maximum number of attention weights materialized at once, over all the batch items and
+/// heads, by the attention computed by blocks of queries.
+const BLOCK_NUM_WEIGHTS: usize = 1 << 22;
+
+/// Computes the scaled dot-product attention by blocks of queries, so that only the attention
+/// weights of a block are materialized, which works on every backend.
+pub(crate) fn attention_forward_in_blocks<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 3>>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+) -> AttentionForward<B> {
+    let query = Tensor::<B, 4>::from_primitive(query);
+    let key = Tensor::<B, 4>::from_primitive(key);
+    let value = Tensor::<B, 4>::from_primitive(value);
+    let mask = mask.map(Tensor::<B, 3, Bool>::from_primitive);
+    let [batch_size, n_heads, seq_length_1, _] = query.dims();
+
+    let mut outputs = Vec::new();
+    let mut logsumexps = Vec::new();
+
+    for rows in blocks(query.dims(), key.dims()[2]) {
+        let scores = block_scores(&query, &key, mask.as_ref(), rows.clone(), min_float);
+        let max = scores.clone().max_dim(3);
+        let logsumexp = (scores.clone() - max.clone()).exp().sum_dim(3).log() + max;
+        let weights = match dropout {
+            Some(dropout) => {
+                (scores - logsumexp.clone()).exp()
+                    * dropout_mask_block(
+                        dropout,
+                        query.dims(),
+                        key.dims()[2],
+                        rows,
+                        &query.device(),
+                    )
+            }
+            None => (scores - logsumexp.clone()).exp(),
+        };
+
+        outputs.push(weights.matmul(value.clone()));
+        logsumexps.push(logsumexp);
+    }
+
+    let logsumexp = Tensor::cat(logsumexps, 2).reshape([batch_size, n_heads, seq_length_1]);
+
+    AttentionForward::new(
+        Tensor::cat(outputs, 2).into_primitive(),
+        logsumexp.into_primitive(),
+    )
+}
+
+/// Computes the gradients of the scaled dot-product attention by blocks of queries, recomputing
+/// the attention weights `P` of each block from the log-sum-exp of the forward pass.
+///
+/// With `dP = dO V^T * M`, `M` being the dropout mask, the gradient of the scores is
+/// `dS = P * (dP - sum(dO * O))`, the sum being over the values.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn attention_backward_in_blocks<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 3>>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+    forward: AttentionForward<B>,
+    output_grad: FloatTensor<B, 4>,
+) -> AttentionBackward<B> {
+    let query = Tensor::<B, 4>::from_primitive(query);
+    let key = Tensor::<B, 4>::from_primitive(key);
+    let value = Tensor::<B, 4>::from_primitive(value);
+    let mask = mask.map(Tensor::<B, 3, Bool>::from_primitive);
+    let output = Tensor::<B, 4>::from_primitive(forward.output);
+    let output_grad = Tensor::<B, 4>::from_primitive(output_grad);
+    let [batch_size, n_heads, seq_length_1, d_k] = query.dims();
+    let [_, _, seq_length_2, d_v] = value.dims();
+    let scale = 1.0 / libm::sqrt(d_k as f64);
+
+    let logsumexp = Tensor::<B, 3>::from_primitive(forward.logsumexp).reshape([
+        batch_size,
+        n_heads,
+        seq_length_1,
+        1,
+    ]);
+
+    let mut query_grads = Vec::new();
+    let mut key_grad = key.zeros_like();
+    let mut value_grad = value.zeros_like();
+
+    for rows in blocks(query.dims(), seq_length_2) {
+        let indices = [0..batch_size, 0..n_heads, rows.clone()];
+        let query_block = query.clone().slice(indices.clone());
+        let output_grad_block = output_grad.clone().slice(indices.clone());
+        let output_block = output
+            .clone()
+            .slice([0..batch_size, 0..n_heads, rows.clone(), 0..d_v]);
+
+        let scores = block_scores(&query, &key, mask.as_ref(), rows.clone(), min_float);
+        let weights = (scores - logsumexp.clone().slice(indices)).exp();
+        let weights_grad = output_grad_block.clone().matmul(value.clone().transpose());
+
+        let (dropped_weights, weights_grad) = match dropout {
+            Some(dropout) => {
+                let dropout_mask =
+                    dropout_mask_block(dropout, query.dims(), seq_length_2, rows, &query.device());
+                (
+                    weights.clone() * dropout_mask.clone(),
+                    weights_grad * dropout_mask,
+                )
+            }
+            None => (weights.clone(), weights_grad),
+        };
+
+        value_grad = value_grad
+            + dropped_weights
+                .transpose()
+                .matmul(output_grad_block.clone());
+
+        let output_dot = (output_grad_block * output_block).sum_dim(3);
+        let scores_grad = (weights * (weights_grad - output_dot)).mul_scalar(scale);
+
+        query_grads.push(scores_grad.clone().matmul(key.clone()));
+        key_grad = key_grad + scores_grad.transpose().matmul(query_block);
+    }
+
+    AttentionBackward::new(
+        Tensor::cat(query_grads, 2).into_primitive(),
+        key_grad.into_primitive(),
+        value_grad.into_primitive(),
+    )
+}
+
+/// Materializes the [attention dropout](AttentionDropout) mask, the dropped weights being zero
+/// and the kept ones `1 / (1 - prob)`.
+pub(crate) fn attention_dropout_mask<B: Backend>(
+    dropout: AttentionDropout,
+    shape: [usize; 4],
+    device: &B::Device,
+) -> Tensor<B, 4> {
+    let [_, _, seq_length_1, seq_length_2] = shape;
+
+    dropout_mask_block(dropout, shape, seq_length_2, 0..seq_length_1, device)
+}
+
+/// The ranges of queries processed together, so that each block has at most
+/// [BLOCK_NUM_WEIGHTS] attention weights, unless a single query has more.
+fn blocks(shape_query: [usize; 4], seq_length_2: usize) -> impl Iterator<Item = Range<usize>> {
+    let [batch_size, n_heads, seq_length_1, _] = shape_query;
+    let block_size = usize::max(
+        BLOCK_NUM_WEIGHTS / usize::max(batch_size * n_heads * seq_length_2, 1),
+        1,
+    );
+
+    (0..seq_length_1)
+        .step_by(block_size)
+        .map(move |start| start..usize::min(start + block_size, seq_length_1))
+}
+
+/// The attention scores `QK^T / sqrt(d_k)` of the given queries, the masked scores being
+/// `min_float`.
+fn block_scores<B: Backend>(
+    query: &Tensor<B, 4>,
+    key: &Tensor<B, 4>,
+    mask: Option<&Tensor<B, 3, Bool>>,
+    rows: Range<usize>,
+    min_float: f64,
+) -> Tensor<B, 4> {
+    let [batch_size, n_heads, _, d_k] = query.dims();
+
+    let scores = query
+        .clone()
+        .slice([0..batch_size, 0..n_heads, rows.clone()])
+        .matmul(key.clone().transpose())
+        .div_scalar(libm::sqrt(d_k as f64));
+
+    match mask {
+        Some(mask) => {
+            let [_, _, seq_length_2] = mask.dims();
+            let mask = mask
+                .clone()
+                .slice([0..batch_size, rows.clone(), 0..seq_length_2])
+                .reshape([batch_size, 1, rows.len(), seq_length_2]);
+
+            scores.mask_fill(mask, min_float)
+        }
+        None => scores,
+    }
+}
+
+/// The dropout mask of the attention weights of the given queries, generated on the host.
+fn dropout_mask_block<B: Backend>(
+    dropout: AttentionDropout,
+    shape_query: [usize; 4],
+    seq_length_2: usize,
+    rows: Range<usize>,
+    device: &B::Device,
+) -> Tensor<B, 4> {
+    let [batch_size, n_heads, seq_length_1, _] = shape_query;
+    let scale = dropout.scale() as f32;
+    let mut values = Vec::with_capacity(batch_size * n_heads * rows.len() * seq_length_2);
+
+    for batch_head in 0..batch_size * n_heads {
+        for row in rows.clone() {
+            let offset = (batch_head * seq_length_1 + row) * seq_length_2;
+
+            for column in 0..seq_length_2 {
+                let index = (offset + column) as u32;
+                values.push(match dropout.is_kept(index) {
+                    true => scale,
+                    false => 0.0,
+                });
+            }
+        }
+    }
+
+    let shape = Shape::new([batch_size, n_heads, rows.len(), seq_length_2]);
+
+    Tensor::from_data_device(Data::new(values, shape).convert(), device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_bound_the_number_of_weights() {
+        let rows = blocks([2, 4, 3000, 16], 2048).collect::<Vec<_>>();
+
+        assert_eq!(rows.len(), 12);
+        assert_eq!(rows[0], 0..256);
+        assert_eq!(rows[11], 2816..3000);
+    }
+
+    #[test]
+    fn test_blocks_of_a_single_query_when_a_query_has_too_many_weights() {
+        let rows = blocks([1, 1, 3, 16], BLOCK_NUM_WEIGHTS * 2).collect::<Vec<_>>();
+
+        assert_eq!(rows, vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    fn test_dropout_keeps_weights_with_probability() {
+        let dropout = AttentionDropout::new(0.2, 3);
+
+        let num_kept = (0..100_000).filter(|index| dropout.is_kept(*index)).count();
+
+        assert!((79_000..81_000).contains(&num_kept), "{num_kept}");
+        assert!((0..1000).all(|index| AttentionDropout::new(0.0, 3).is_kept(index)));
+        assert!((0..1000).all(|index| !AttentionDropout::new(1.0, 3).is_kept(index)));
+    }
+}
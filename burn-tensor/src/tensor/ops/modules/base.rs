@@ -1,7 +1,10 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{
+    attention, conv, grid_sample, interpolate, pool,
+    unfold::{fold4d_using_conv_transpose2d, unfold4d_using_conv2d},
+};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, Device, FloatTensor, IntTensor},
     Shape,
 };
 
@@ -18,6 +21,78 @@ pub struct Conv2dBackward<B: Backend> {
     pub bias_grad: Option<FloatTensor<B, 1>>,
 }
 
+/// Dropout of the [attention](ModuleOps::attention) weights, whose mask is generated from a seed
+/// by the attention instead of being materialized with the shape of the weights.
+///
+/// The weight of the query `i` and the key `j` in the head `h` of the batch item `b` is kept when
+/// the [hash](AttentionDropout::hash) of its index `((b * n_heads + h) * seq_length_1 + i) *
+/// seq_length_2 + j`, wrapped to 32 bits, is at least the [threshold](AttentionDropout::threshold)
+/// once shifted right by 8 bits. The kept weights are scaled by `1 / (1 - prob)`. Backends
+/// compute the same hash in their kernels, so the forward and backward passes of every backend
+/// drop the same weights for a given seed.
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct AttentionDropout {
+    /// The probability of dropping each attention weight.
+    pub prob: f64,
+    /// The seed of the dropout mask.
+    pub seed: u32,
+}
+
+impl AttentionDropout {
+    /// The minimum value of the 24 most significant bits of the hash of a kept weight.
+    pub fn threshold(&self) -> u32 {
+        libm::ceil(self.prob * (1u32 << 24) as f64) as u32
+    }
+
+    /// The scale of the kept weights.
+    pub fn scale(&self) -> f64 {
+        1.0 / (1.0 - self.prob)
+    }
+
+    /// Hash the index of an attention weight with the seed.
+    ///
+    /// The multipliers are below `2^31`, so backends without unsigned 32 bits integers can
+    /// compute the products on 64 bits integers before wrapping them.
+    pub fn hash(&self, index: u32) -> u32 {
+        let mut x = index ^ self.seed.wrapping_mul(0x2c1b3c6d);
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7feb352d);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x1b873593);
+        x ^= x >> 16;
+        x
+    }
+
+    /// Whether the attention weight of the given index is kept.
+    pub fn is_kept(&self, index: u32) -> bool {
+        self.hash(index) >> 8 >= self.threshold()
+    }
+}
+
+/// Results from the forward pass of the [attention](ModuleOps::attention_forward), which are
+/// needed by its backward pass.
+#[derive(new)]
+pub struct AttentionForward<B: Backend> {
+    /// The output, `[batch_size, n_heads, seq_length_1, d_v]`.
+    pub output: FloatTensor<B, 4>,
+
+    /// The log-sum-exp of the attention scores of each query, `[batch_size, n_heads, seq_length_1]`.
+    pub logsumexp: FloatTensor<B, 3>,
+}
+
+/// Gradient computed during the backward pass for each tensor used by [attention](ModuleOps::attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: FloatTensor<B, 4>,
+
+    /// Key gradient.
+    pub key_grad: FloatTensor<B, 4>,
+
+    /// Value gradient.
+    pub value_grad: FloatTensor<B, 4>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
 #[derive(new)]
 pub struct MaxPool1dBackward<B: Backend> {
@@ -114,6 +189,61 @@ pub struct UnfoldOptions {
     pub dilation: [usize; 2],
 }
 
+/// The interpolation method used by [interpolate](ModuleOps::interpolate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Takes the value of the nearest input element, the one whose index is the output index
+    /// scaled down and rounded down.
+    Nearest,
+
+    /// Linear interpolation between the two nearest input elements along each axis.
+    Bilinear,
+
+    /// Cubic convolution of the four nearest input elements along each axis, the edge values
+    /// being replicated.
+    Bicubic,
+}
+
+/// Interpolate operation options.
+#[derive(new, Debug, Clone)]
+pub struct InterpolateOptions {
+    /// The interpolation method.
+    pub mode: InterpolateMode,
+
+    /// If true, the centers of the corner elements of the input and output are aligned, so the
+    /// corner values are preserved. Otherwise the corners of the elements are aligned. Ignored
+    /// by the [nearest](InterpolateMode::Nearest) mode.
+    pub align_corners: bool,
+}
+
+/// The way the samples outside of the input are computed by
+/// [grid_sample_2d](ModuleOps::grid_sample_2d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// The input is padded with zeros.
+    Zeros,
+
+    /// The input is padded by repeating the edge values.
+    Border,
+
+    /// The input is padded with its reflection by the borders.
+    Reflection,
+}
+
+/// Grid sample operation options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// The interpolation method.
+    pub mode: InterpolateMode,
+
+    /// The way the samples outside of the input are computed.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the coordinates `-1` and `1` are the centers of the corner elements. Otherwise
+    /// they are the outer corners of the corner elements.
+    pub align_corners: bool,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -210,6 +340,23 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Three dimensional convolution.
+    ///
+    /// The default implementation sums a [conv2d](ModuleOps::conv2d) for each depth of the kernel.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv3d(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<B, 5> {
+        conv::conv3d_from_conv2d::<B>(x, weight, bias, options)
+    }
     /// One dimensional transposed convolution.
     ///
     /// # Shapes
@@ -259,6 +406,24 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Three dimensional transposed convolution.
+    ///
+    /// The default implementation accumulates a [conv transpose 2d](ModuleOps::conv_transpose2d)
+    /// for each depth of the kernel.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv_transpose3d(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<B, 5> {
+        conv::conv_transpose3d_from_conv_transpose2d::<B>(x, weight, bias, options)
+    }
 
     /// Four-dimensional unfolding.
     ///
@@ -274,6 +439,142 @@ pub trait ModuleOps<B: Backend> {
         unfold4d_using_conv2d::<B>(x, kernel_size, options)
     }
 
+    /// Four-dimensional folding, the inverse of [unfold4d](ModuleOps::unfold4d) where the
+    /// overlapping values are summed.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in * kernel_size_1 * kernel_size_2, number of blocks]`,
+    /// returns: `[batch_size, channels_in, output_size_1, output_size_2]`,
+    fn fold4d(
+        x: FloatTensor<B, 3>,
+        output_size: [usize; 2],
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<B, 4> {
+        fold4d_using_conv_transpose2d::<B>(x, output_size, kernel_size, options)
+    }
+
+    /// Two dimensional interpolation, resizing the spatial dimensions to the given output size.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels, height, width]`,
+    /// returns: `[batch_size, channels, output_size_1, output_size_2]`,
+    fn interpolate(
+        x: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 4> {
+        interpolate::interpolate_from_matmul::<B>(x, output_size, options)
+    }
+
+    /// Backward pass for the [interpolate](ModuleOps::interpolate) operation.
+    fn interpolate_backward(
+        x: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<B, 4> {
+        interpolate::interpolate_backward_from_matmul::<B>(x, grad, output_size, options)
+    }
+
+    /// Two dimensional grid sampling, interpolating the input at the locations given by the grid.
+    ///
+    /// The grid contains the `(x, y)` coordinates of each sample, normalized so that `-1` and `1`
+    /// are the left and right (top and bottom) borders of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels, height, width]`,
+    /// grid:   `[batch_size, height_out, width_out, 2]`,
+    /// returns: `[batch_size, channels, height_out, width_out]`,
+    fn grid_sample_2d(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::grid_sample_2d_from_gather::<B>(x, grid, options)
+    }
+
+    /// Scaled dot-product attention, `softmax(QK^T / sqrt(d_k))V`.
+    ///
+    /// The attention scores where the mask is `true` are replaced with `min_float` before the
+    /// softmax, and the [dropout](AttentionDropout), if any, is applied to the attention weights.
+    /// Backends can provide a fused implementation which doesn't materialize the attention
+    /// weights, reducing the memory usage from quadratic to linear in the sequence length.
+    ///
+    /// # Shapes
+    ///
+    /// query:        `[batch_size, n_heads, seq_length_1, d_k]`,
+    /// key:          `[batch_size, n_heads, seq_length_2, d_k]`,
+    /// value:        `[batch_size, n_heads, seq_length_2, d_v]`,
+    /// mask:         `[batch_size, seq_length_1, seq_length_2]`,
+    /// returns:      `[batch_size, n_heads, seq_length_1, d_v]`,
+    fn attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> FloatTensor<B, 4> {
+        B::attention_forward(query, key, value, mask, dropout, min_float).output
+    }
+
+    /// Forward pass of the [attention](ModuleOps::attention), also returning the log-sum-exp of
+    /// the attention scores of each query, from which the backward pass recomputes the attention
+    /// weights.
+    ///
+    /// The default implementation processes the queries by blocks, so only the attention weights
+    /// of a block are materialized.
+    fn attention_forward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> AttentionForward<B> {
+        attention::attention_forward_in_blocks::<B>(query, key, value, mask, dropout, min_float)
+    }
+
+    /// Backward pass for the [attention](ModuleOps::attention) operation.
+    ///
+    /// The attention weights are recomputed from the query, the key and the log-sum-exp of the
+    /// [forward pass](ModuleOps::attention_forward), so the forward pass doesn't need to keep
+    /// them. The default implementation processes the queries by blocks.
+    #[allow(clippy::too_many_arguments)]
+    fn attention_backward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+        forward: AttentionForward<B>,
+        output_grad: FloatTensor<B, 4>,
+    ) -> AttentionBackward<B> {
+        attention::attention_backward_in_blocks::<B>(
+            query,
+            key,
+            value,
+            mask,
+            dropout,
+            min_float,
+            forward,
+            output_grad,
+        )
+    }
+
+    /// Draw the seed of an [attention dropout](AttentionDropout) on the given device.
+    ///
+    /// Backends with their own random generator should draw from it, so that the dropout is
+    /// reproducible with [seed](Backend::seed). The default implementation uses entropy.
+    fn attention_dropout_seed(_device: &Device<B>) -> u32 {
+        burn_common::rand::gen_random()
+    }
+
     /// One dimensional avg pooling.
     ///
     /// # Shapes
@@ -353,6 +654,31 @@ pub trait ModuleOps<B: Backend> {
     ) -> FloatTensor<B, 3> {
         pool::adaptive_avg_pool1d_backward_from_2d::<B>(x, grad)
     }
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B, 4>, output_size: [usize; 2]) -> FloatTensor<B, 4> {
+        Self::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+    /// Two dimensional adaptive max pooling with indices.
+    ///
+    /// The indices are flattened over the height and width of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<B>;
+    /// Backward pass for the [adaptive max pooling 2d](ModuleOps::adaptive_max_pool2d_with_indices) operation.
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<B, 4>,
+        output_grad: FloatTensor<B, 4>,
+        indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<B>;
     /// One dimensional max pooling.
     ///
     /// # Shapes
@@ -1,6 +1,7 @@
 #![allow(clippy::single_range_in_vec_init)]
 use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
 use crate::{backend::Backend, ops::FloatTensor, Shape};
+use alloc::vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -360,6 +361,204 @@ pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     B::reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D convolution using a 2D convolution for each depth of the kernel.
+///
+/// For each kernel depth, the input depths it is applied to are gathered into the batch dimension,
+/// so that a single 2D convolution covers all the output depths.
+pub(crate) fn conv3d_from_conv2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_out, channels_per_group, kernel_depth, kernel_height, kernel_width] =
+        B::shape(&weight).dims;
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    let [stride, padding, dilation] = [options.stride[0], options.padding[0], options.dilation[0]];
+    let depth_out = calculate_conv_output_size(kernel_depth, stride, padding, dilation, depth_in);
+
+    let x = match padding {
+        0 => x,
+        _ => {
+            let zeros = B::zeros(
+                Shape::new([batch_size, channels_in, padding, height_in, width_in]),
+                &device,
+            );
+            B::cat(vec![zeros.clone(), x, zeros], 2)
+        }
+    };
+
+    let options_2d = ConvOptions::new(
+        [options.stride[1], options.stride[2]],
+        [options.padding[1], options.padding[2]],
+        [options.dilation[1], options.dilation[2]],
+        options.groups,
+    );
+
+    let mut output: Option<FloatTensor<B, 4>> = None;
+
+    for k in 0..kernel_depth {
+        let start = k * dilation;
+        let indices = B::arange_step(start..start + (depth_out - 1) * stride + 1, stride, &device);
+        let x_k = B::swap_dims(B::select(x.clone(), 2, indices), 1, 2);
+        let x_k = B::reshape(
+            x_k,
+            Shape::new([batch_size * depth_out, channels_in, height_in, width_in]),
+        );
+
+        let weight_k = B::slice(
+            weight.clone(),
+            [
+                0..channels_out,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_height,
+                0..kernel_width,
+            ],
+        );
+        let weight_k = B::reshape(
+            weight_k,
+            Shape::new([
+                channels_out,
+                channels_per_group,
+                kernel_height,
+                kernel_width,
+            ]),
+        );
+
+        let output_k = B::conv2d(x_k, weight_k, None, options_2d.clone());
+        output = Some(match output {
+            Some(output) => B::add(output, output_k),
+            None => output_k,
+        });
+    }
+
+    let output = output.expect("The kernel should have at least one depth");
+    let [_, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels_out, height_out, width_out]),
+    );
+    let output = B::swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
+/// Execute a 3D transposed convolution using a 2D transposed convolution for each depth of the
+/// kernel.
+///
+/// Each kernel depth spreads all the input depths over the output depths, which are accumulated
+/// before the depth padding is removed.
+pub(crate) fn conv_transpose3d_from_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_in, channels_per_group, kernel_depth, kernel_height, kernel_width] =
+        B::shape(&weight).dims;
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let channels_out = channels_per_group * options.groups;
+    let device = B::device(&x);
+
+    let [stride, padding, padding_out, dilation] = [
+        options.stride[0],
+        options.padding[0],
+        options.padding_out[0],
+        options.dilation[0],
+    ];
+    let depth_out = calculate_conv_transpose_output_size(
+        kernel_depth,
+        stride,
+        padding,
+        padding_out,
+        dilation,
+        depth_in,
+    );
+    // Output depths before removing the padding.
+    let depth_full = (depth_in - 1) * stride + dilation * (kernel_depth - 1) + 1 + padding_out;
+
+    let options_2d = ConvTransposeOptions::new(
+        [options.stride[1], options.stride[2]],
+        [options.padding[1], options.padding[2]],
+        [options.padding_out[1], options.padding_out[2]],
+        [options.dilation[1], options.dilation[2]],
+        options.groups,
+    );
+
+    let x = B::reshape(
+        B::swap_dims(x, 1, 2),
+        Shape::new([batch_size * depth_in, channels_in, height_in, width_in]),
+    );
+
+    let mut output: Option<FloatTensor<B, 5>> = None;
+
+    for k in 0..kernel_depth {
+        let weight_k = B::slice(
+            weight.clone(),
+            [
+                0..channels_in,
+                0..channels_per_group,
+                k..k + 1,
+                0..kernel_height,
+                0..kernel_width,
+            ],
+        );
+        let weight_k = B::reshape(
+            weight_k,
+            Shape::new([channels_in, channels_per_group, kernel_height, kernel_width]),
+        );
+
+        let output_k = B::conv_transpose2d(x.clone(), weight_k, None, options_2d.clone());
+        let [_, _, height_out, width_out] = B::shape(&output_k).dims;
+        let output_k = B::reshape(
+            output_k,
+            Shape::new([batch_size, depth_in, channels_out, height_out, width_out]),
+        );
+        let output_k = B::swap_dims(output_k, 1, 2);
+
+        let output_full = output.unwrap_or_else(|| {
+            B::zeros(
+                Shape::new([batch_size, channels_out, depth_full, height_out, width_out]),
+                &device,
+            )
+        });
+        let start = k * dilation;
+        let indices = B::arange_step(start..start + (depth_in - 1) * stride + 1, stride, &device);
+
+        output = Some(B::select_assign(output_full, 2, indices, output_k));
+    }
+
+    let output = output.expect("The kernel should have at least one depth");
+    let [_, _, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::slice(
+        output,
+        [
+            0..batch_size,
+            0..channels_out,
+            padding..padding + depth_out,
+            0..height_out,
+            0..width_out,
+        ],
+    );
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
 fn conv1d_weight_grad_groups<B: Backend>(
     x: FloatTensor<B, 3>,
     mut weight_grad: FloatTensor<B, 3>,
@@ -0,0 +1,185 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{Int, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+
+/// The `a` coefficient of the cubic convolution kernel, the same as PyTorch.
+const CUBIC_COEFFICIENT: f64 = -0.75;
+
+/// One interpolation tap along an axis: the clamped input indices and their weights, which are
+/// zero for the indices outside of the input.
+type Tap<B> = (Tensor<B, 3, Int>, Tensor<B, 3>);
+
+/// Computes the grid sampling with gathers, so that it works on every backend and is
+/// differentiable with respect to both the input and the grid.
+pub(crate) fn grid_sample_2d_from_gather<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(x);
+    let grid = Tensor::<B, 4>::from_primitive(grid);
+    let [batch_size, channels, height, width] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+    let num_samples = height_out * width_out;
+
+    let grid = grid.reshape([batch_size, num_samples, 2]).swap_dims(1, 2);
+    let grid_x = grid.clone().slice([0..batch_size, 0..1, 0..num_samples]);
+    let grid_y = grid.slice([0..batch_size, 1..2, 0..num_samples]);
+
+    let taps_x = axis_taps(grid_x, width, &options);
+    let taps_y = axis_taps(grid_y, height, &options);
+
+    let x = x.reshape([batch_size, channels, height * width]);
+    let mut output: Option<Tensor<B, 3>> = None;
+
+    for (index_y, weight_y) in taps_y.iter() {
+        for (index_x, weight_x) in taps_x.iter() {
+            let index = index_y.clone().mul_scalar(width as i64) + index_x.clone();
+            let values = x.clone().gather(2, index.repeat(1, channels));
+            let values = values * (weight_y.clone() * weight_x.clone());
+
+            output = Some(match output {
+                Some(output) => output + values,
+                None => values,
+            });
+        }
+    }
+
+    output
+        .unwrap()
+        .reshape([batch_size, channels, height_out, width_out])
+        .into_primitive()
+}
+
+/// Computes the taps along one axis from the normalized coordinates of the samples.
+fn axis_taps<B: Backend>(
+    coordinates: Tensor<B, 3>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Vec<Tap<B>> {
+    let coordinates = match options.align_corners {
+        true => coordinates
+            .add_scalar(1.0)
+            .mul_scalar((size - 1) as f64 / 2.0),
+        false => coordinates
+            .add_scalar(1.0)
+            .mul_scalar(size as f64)
+            .sub_scalar(1.0)
+            .div_scalar(2.0),
+    };
+    let tap = |index: Tensor<B, 3>, weight: Tensor<B, 3>| -> Tap<B> {
+        let outside = index
+            .clone()
+            .lower_elem(0.0)
+            .float()
+            .add(index.clone().greater_elem((size - 1) as f64).float());
+        let weight = weight.mask_fill(outside.greater_elem(0.0), 0.0);
+        let index = index.clamp(0.0, (size - 1) as f64).int();
+
+        (index, weight)
+    };
+
+    match options.mode {
+        InterpolateMode::Nearest => {
+            let coordinates = apply_padding(coordinates, size, options);
+            let index = round(coordinates);
+            let weight = index.ones_like();
+
+            vec![tap(index, weight)]
+        }
+        InterpolateMode::Bilinear => {
+            let coordinates = apply_padding(coordinates, size, options);
+            let index = floor(coordinates.clone());
+            let lambda = coordinates - index.clone();
+
+            vec![
+                tap(index.clone(), lambda.clone().neg().add_scalar(1.0)),
+                tap(index.add_scalar(1.0), lambda),
+            ]
+        }
+        InterpolateMode::Bicubic => {
+            let index = floor(coordinates.clone());
+            let t = coordinates - index.clone();
+            let a = CUBIC_COEFFICIENT;
+            let near = |x: Tensor<B, 3>| {
+                (x.clone().mul_scalar(a + 2.0).sub_scalar(a + 3.0) * x.clone() * x).add_scalar(1.0)
+            };
+            let far = |x: Tensor<B, 3>| {
+                ((x.clone().mul_scalar(a).sub_scalar(5.0 * a) * x.clone()).add_scalar(8.0 * a) * x)
+                    .sub_scalar(4.0 * a)
+            };
+            let weights = [
+                far(t.clone().add_scalar(1.0)),
+                near(t.clone()),
+                near(t.clone().neg().add_scalar(1.0)),
+                far(t.neg().add_scalar(2.0)),
+            ];
+
+            // The padding is applied to each tap instead of the sampled coordinate.
+            weights
+                .into_iter()
+                .enumerate()
+                .map(|(offset, weight)| {
+                    let index = index.clone().add_scalar(offset as f64 - 1.0);
+                    tap(apply_padding(index, size, options), weight)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Moves the coordinates outside of the input back inside, depending on the padding mode.
+fn apply_padding<B: Backend>(
+    coordinates: Tensor<B, 3>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Tensor<B, 3> {
+    let last = (size - 1) as f64;
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coordinates,
+        GridSamplePaddingMode::Border => coordinates.clamp(0.0, last),
+        GridSamplePaddingMode::Reflection => {
+            let (min, span) = match options.align_corners {
+                true => (0.0, last),
+                false => (-0.5, size as f64),
+            };
+
+            if span == 0.0 {
+                return coordinates.zeros_like();
+            }
+
+            let coordinates = coordinates.sub_scalar(min).abs();
+            let flips = floor(coordinates.clone().div_scalar(span));
+            let extra = coordinates - flips.clone().mul_scalar(span);
+            let odd = flips.clone() - floor(flips.div_scalar(2.0)).mul_scalar(2.0);
+            let reflected = extra.clone().neg().add_scalar(span);
+
+            extra
+                .mask_where(odd.greater_elem(0.5), reflected)
+                .add_scalar(min)
+                .clamp(0.0, last)
+        }
+    }
+}
+
+/// Rounds down the values, without propagating any gradient.
+fn floor<B: Backend>(tensor: Tensor<B, 3>) -> Tensor<B, 3> {
+    let truncated = tensor.clone().int().float();
+    let correction = truncated.clone().greater(tensor).float();
+
+    truncated - correction
+}
+
+/// Rounds the values to the nearest integer, the halfway values being rounded to the even one.
+fn round<B: Backend>(tensor: Tensor<B, 3>) -> Tensor<B, 3> {
+    let rounded = floor(tensor.clone().add_scalar(0.5));
+    let tie = (rounded.clone() - tensor).equal_elem(0.5).float();
+    let odd = rounded.clone() - floor(rounded.clone().div_scalar(2.0)).mul_scalar(2.0);
+
+    rounded - tie * odd
+}
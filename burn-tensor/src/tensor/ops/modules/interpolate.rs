@@ -0,0 +1,168 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{Data, ElementConversion, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{InterpolateMode, InterpolateOptions};
+
+/// The `a` coefficient of the cubic convolution kernel, the same as PyTorch and OpenCV.
+const CUBIC_COEFFICIENT: f64 = -0.75;
+
+/// Computes the interpolation weights along one axis.
+///
+/// # Returns
+///
+/// For each output position, the input positions it is interpolated from along with their
+/// weights. The weights of an output position sum to one.
+pub fn interpolate_weights(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> Vec<Vec<(usize, f64)>> {
+    let last = input_size as i64 - 1;
+    let clamp = |index: i64| index.clamp(0, last) as usize;
+
+    (0..output_size)
+        .map(|position| match options.mode {
+            InterpolateMode::Nearest => {
+                let source = position * input_size / output_size;
+                vec![(source.min(input_size - 1), 1.0)]
+            }
+            InterpolateMode::Bilinear => {
+                let source = source_position(position, input_size, output_size, options);
+                let source = source.max(0.0);
+                let index = libm::floor(source);
+                let lambda = source - index;
+                let index = index as i64;
+
+                vec![(clamp(index), 1.0 - lambda), (clamp(index + 1), lambda)]
+            }
+            InterpolateMode::Bicubic => {
+                let source = source_position(position, input_size, output_size, options);
+                let index = libm::floor(source);
+                let t = source - index;
+                let index = index as i64;
+
+                cubic_weights(t)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(offset, weight)| (clamp(index + offset as i64 - 1), weight))
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// Maps an output position to the (fractional) input position it samples.
+fn source_position(
+    position: usize,
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+) -> f64 {
+    if options.align_corners {
+        match output_size {
+            1 => 0.0,
+            _ => position as f64 * (input_size - 1) as f64 / (output_size - 1) as f64,
+        }
+    } else {
+        (position as f64 + 0.5) * input_size as f64 / output_size as f64 - 0.5
+    }
+}
+
+/// The weights of the four neighbours `-1, 0, 1, 2` of a point at distance `t` of the second one.
+fn cubic_weights(t: f64) -> [f64; 4] {
+    let a = CUBIC_COEFFICIENT;
+    let near = |x: f64| ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+    let far = |x: f64| ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+
+    [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)]
+}
+
+/// Creates the matrix of shape `[input_size, output_size]` that interpolates the rows of a
+/// matrix when multiplied on the right.
+fn weight_matrix<B: Backend>(
+    input_size: usize,
+    output_size: usize,
+    options: &InterpolateOptions,
+    device: &B::Device,
+) -> FloatTensor<B, 2> {
+    let mut weights = vec![0.0; input_size * output_size];
+
+    for (position, taps) in interpolate_weights(input_size, output_size, options)
+        .into_iter()
+        .enumerate()
+    {
+        for (source, weight) in taps {
+            weights[source * output_size + position] += weight;
+        }
+    }
+
+    let weights = weights.into_iter().map(|weight| weight.elem()).collect();
+    B::from_data(
+        Data::new(weights, Shape::new([input_size, output_size])),
+        device,
+    )
+}
+
+/// Multiplies the last two dimensions of `x` by the given matrices on the right, the
+/// interpolation being separable.
+fn interpolate_separable<B: Backend>(
+    x: FloatTensor<B, 4>,
+    weights_height: FloatTensor<B, 2>,
+    weights_width: FloatTensor<B, 2>,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::shape(&x).dims;
+    let [_, height_out] = B::shape(&weights_height).dims;
+    let [_, width_out] = B::shape(&weights_width).dims;
+    let batch = batch_size * channels;
+
+    let x = B::reshape(x, Shape::new([batch * height, width]));
+    let x = B::matmul(x, weights_width);
+    let x = B::reshape(x, Shape::new([batch, height, width_out]));
+    let x = B::swap_dims(x, 1, 2);
+    let x = B::reshape(x, Shape::new([batch * width_out, height]));
+    let x = B::matmul(x, weights_height);
+    let x = B::reshape(x, Shape::new([batch, width_out, height_out]));
+    let x = B::swap_dims(x, 1, 2);
+
+    B::reshape(x, Shape::new([batch_size, channels, height_out, width_out]))
+}
+
+/// Computes the interpolation as matrix multiplications with the interpolation weights of each
+/// axis.
+pub(crate) fn interpolate_from_matmul<B: Backend>(
+    x: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 4> {
+    let [_, _, height, width] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    let weights_height = weight_matrix::<B>(height, output_size[0], &options, &device);
+    let weights_width = weight_matrix::<B>(width, output_size[1], &options, &device);
+
+    interpolate_separable::<B>(x, weights_height, weights_width)
+}
+
+/// Computes the gradient of the interpolation, which multiplies by the transposed interpolation
+/// weights.
+pub(crate) fn interpolate_backward_from_matmul<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    options: InterpolateOptions,
+) -> FloatTensor<B, 4> {
+    let [_, _, height, width] = B::shape(&x).dims;
+    let device = B::device(&x);
+
+    let weights_height = weight_matrix::<B>(height, output_size[0], &options, &device);
+    let weights_width = weight_matrix::<B>(width, output_size[1], &options, &device);
+
+    interpolate_separable::<B>(
+        grad,
+        B::transpose(weights_height),
+        B::transpose(weights_width),
+    )
+}
@@ -1,9 +1,18 @@
+/// Module with attention operations.
+pub(crate) mod attention;
+
 /// Module with convolution operations.
 pub mod conv;
 
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
+/// Module with grid sampling operations.
+pub(crate) mod grid_sample;
+
+/// Module with interpolation operations.
+pub mod interpolate;
+
 /// Module with pooling operations.
 pub mod pool;
 
@@ -1,10 +1,12 @@
 use crate::backend::Backend;
+use crate::check;
+use crate::check::TensorCheck;
 use crate::ops::FloatTensor;
 use crate::{Data, ElementConversion, Shape};
 use alloc::vec;
 use alloc::vec::Vec;
 
-use super::{ConvOptions, UnfoldOptions};
+use super::{ConvOptions, ConvTransposeOptions, UnfoldOptions};
 
 /// Constructs a special weight tensor used for unfolding.
 ///
@@ -84,3 +86,57 @@ pub(crate) fn unfold4d_using_conv2d<B: Backend>(
         Shape::new([batch_size, channels_out, out_height * out_width]),
     )
 }
+
+/// Compute the fold4d operation using the conv_transpose2d operations.
+///
+/// # Notes
+///
+/// Folding is the adjoint of unfolding: using the same weight tensor with a transposed
+/// convolution scatters each block back to its position, summing the overlapping values.
+pub(crate) fn fold4d_using_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B, 3>,
+    output_size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: UnfoldOptions,
+) -> FloatTensor<B, 4> {
+    let shape = B::shape(&x);
+    check!(TensorCheck::fold4d(
+        &shape,
+        output_size,
+        kernel_size,
+        &options
+    ));
+
+    let [batch_size, channels_blocks, _num_blocks] = shape.dims;
+    let in_channels = channels_blocks / (kernel_size[0] * kernel_size[1]);
+    let weight = create_unfolding_weight::<B>(in_channels, kernel_size, &B::device(&x));
+
+    let num_blocks = |i: usize| {
+        (output_size[i] + 2 * options.padding[i] - options.dilation[i] * (kernel_size[i] - 1) - 1)
+            / options.stride[i]
+            + 1
+    };
+    let blocks = [num_blocks(0), num_blocks(1)];
+    let padding_out = |i: usize| {
+        output_size[i] + 2 * options.padding[i]
+            - ((blocks[i] - 1) * options.stride[i] + options.dilation[i] * (kernel_size[i] - 1) + 1)
+    };
+
+    let x = B::reshape(
+        x,
+        Shape::new([batch_size, channels_blocks, blocks[0], blocks[1]]),
+    );
+
+    B::conv_transpose2d(
+        x,
+        weight,
+        None,
+        ConvTransposeOptions {
+            stride: options.stride,
+            padding: options.padding,
+            padding_out: [padding_out(0), padding_out(1)],
+            dilation: options.dilation,
+            groups: 1,
+        },
+    )
+}
@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+/// The way the values of the padded region are computed by [pad](crate::Tensor::pad).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// Pads with the given constant value.
+    Constant(f64),
+
+    /// Pads with the reflection of the tensor, the edge value excluded.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+
+    /// Pads by repeating the edge value.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides gives `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+
+    /// Pads by wrapping the tensor around, as if it was periodic.
+    ///
+    /// `[1, 2, 3]` padded by 2 on both sides gives `[2, 3, 1, 2, 3, 1, 2]`.
+    Circular,
+}
+
+impl PadMode {
+    /// Returns the index of the source element used for the given position along a padded
+    /// dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position relative to the start of the unpadded dimension, negative
+    ///   in the padding before it and greater or equal to `size` in the padding after it.
+    /// * `size` - The size of the unpadded dimension.
+    ///
+    /// # Returns
+    ///
+    /// The source index, or `None` when the position is filled with a constant.
+    pub fn source_index(&self, position: isize, size: usize) -> Option<usize> {
+        let size = size as isize;
+
+        if (0..size).contains(&position) {
+            return Some(position as usize);
+        }
+
+        let index = match self {
+            Self::Constant(_) => return None,
+            Self::Reflect => {
+                let period = 2 * (size - 1);
+                let index = position.rem_euclid(period.max(1));
+                match index < size {
+                    true => index,
+                    false => period - index,
+                }
+            }
+            Self::Replicate => position.clamp(0, size - 1),
+            Self::Circular => position.rem_euclid(size),
+        };
+
+        Some(index as usize)
+    }
+}
+
+/// Returns the source indices of every position along a dimension padded with a non constant
+/// mode.
+pub(crate) fn pad_indices(mode: &PadMode, size: usize, before: usize, after: usize) -> Vec<i64> {
+    (-(before as isize)..(size + after) as isize)
+        .map(|position| {
+            mode.source_index(position, size)
+                .expect("Non constant padding modes always have a source index") as i64
+        })
+        .collect()
+}
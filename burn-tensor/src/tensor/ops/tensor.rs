@@ -1,4 +1,7 @@
-use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
+use super::{
+    pad_indices, BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem,
+    IntTensor, PadMode,
+};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -799,6 +802,70 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the mean of all elements in `tensor` along `dim`.
     fn mean_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D>;
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of all the
+    /// previous elements along `dim`, itself included.
+    fn cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        let size = B::shape(&tensor).dims[dim];
+        let mut ranges: [Range<usize>; D] = B::shape(&tensor).dims.map(|size| 0..size);
+        let mut outputs: Vec<FloatTensor<B, D>> = Vec::with_capacity(size);
+
+        for index in 0..size {
+            ranges[dim] = index..index + 1;
+            let slice = B::slice(tensor.clone(), ranges.clone());
+
+            outputs.push(match outputs.last() {
+                Some(previous) => B::add(previous.clone(), slice),
+                None => slice,
+            });
+        }
+
+        match outputs.is_empty() {
+            true => tensor,
+            false => B::cat(outputs, dim),
+        }
+    }
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to multiply.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the product of all the
+    /// previous elements along `dim`, itself included.
+    fn cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        let size = B::shape(&tensor).dims[dim];
+        let mut ranges: [Range<usize>; D] = B::shape(&tensor).dims.map(|size| 0..size);
+        let mut outputs: Vec<FloatTensor<B, D>> = Vec::with_capacity(size);
+
+        for index in 0..size {
+            ranges[dim] = index..index + 1;
+            let slice = B::slice(tensor.clone(), ranges.clone());
+
+            outputs.push(match outputs.last() {
+                Some(previous) => B::mul(previous.clone(), slice),
+                None => slice,
+            });
+        }
+
+        match outputs.is_empty() {
+            true => tensor,
+            false => B::cat(outputs, dim),
+        }
+    }
+
     /// Converts a tensor to full precision.
     ///
     /// # Arguments
@@ -1001,7 +1068,7 @@ pub trait TensorOps<B: Backend> {
     fn max_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
         let index = B::argmax(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
 
     /// Gets the maximum elements of a tensor along an axis and their indices.
@@ -1019,7 +1086,7 @@ pub trait TensorOps<B: Backend> {
         dim: usize,
     ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
         let index = B::argmax(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -1053,7 +1120,7 @@ pub trait TensorOps<B: Backend> {
     fn min_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
         let index = B::argmin(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
 
     /// Gets the minimum elements of a tensor along an axis and their indices.
@@ -1071,8 +1138,64 @@ pub trait TensorOps<B: Backend> {
         dim: usize,
     ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
         let index = B::argmin(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
+
+    /// Pads the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to pad.
+    /// * `pads` - The number of elements to add before and after each dimension.
+    /// * `mode` - How the padded values are computed.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn pad<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        pads: [(usize, usize); D],
+        mode: PadMode,
+    ) -> FloatTensor<B, D> {
+        let device = B::device(&tensor);
+        let mut tensor = tensor;
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let shape = B::shape(&tensor);
+
+            tensor = match mode {
+                PadMode::Constant(value) => {
+                    let mut parts = Vec::with_capacity(3);
+                    let mut shape_pad = shape.clone();
+
+                    if before > 0 {
+                        shape_pad.dims[dim] = before;
+                        parts.push(B::full(shape_pad.clone(), value.elem(), &device));
+                    }
+                    parts.push(tensor);
+                    if after > 0 {
+                        shape_pad.dims[dim] = after;
+                        parts.push(B::full(shape_pad, value.elem(), &device));
+                    }
+
+                    B::cat(parts, dim)
+                }
+                _ => {
+                    let indices = pad_indices(&mode, shape.dims[dim], before, after);
+                    let num_indices = indices.len();
+                    let indices = Data::new(indices, Shape::new([num_indices]));
+
+                    B::select(tensor, dim, B::int_from_data(indices.convert(), &device))
+                }
+            };
+        }
+
+        tensor
+    }
 }
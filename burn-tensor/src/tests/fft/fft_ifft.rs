@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{fft, Data, Tensor};
+
+    #[test]
+    fn should_compute_fft() {
+        let real = Tensor::<TestBackend, 1>::from_data([1.0, 2.0, 3.0, 4.0]);
+        let imag = Tensor::<TestBackend, 1>::zeros([4]);
+
+        let (real, imag) = fft::fft(real, imag, 0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([10.0, -2.0, -2.0, -2.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 0.0, -2.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_fft_along_first_dim_of_odd_size() {
+        let real = TestTensor::from_data([[1.0, 0.0], [2.0, 1.0], [3.0, 0.0]]);
+        let imag = TestTensor::from_data([[0.0, 0.0], [0.0, 0.0], [0.0, 1.0]]);
+
+        let (real, imag) = fft::fft(real, imag, 0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[6.0, 1.0], [-1.5, -1.366], [-1.5, 0.366]]), 3);
+        imag.into_data().assert_approx_eq(
+            &Data::from([[0.0, 1.0], [0.866, -1.366], [-0.866, 0.366]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_invert_fft_with_ifft() {
+        let real = TestTensor::from_data([[1.0, -2.0, 0.5, 3.0], [0.0, 1.0, 2.0, -1.0]]);
+        let imag = TestTensor::from_data([[0.0, 1.0, -1.0, 2.0], [4.0, 0.0, 0.0, 1.0]]);
+
+        let (output_real, output_imag) = fft::fft(real.clone(), imag.clone(), 1);
+        let (output_real, output_imag) = fft::ifft(output_real, output_imag, 1);
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 3);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_parts_have_different_shapes() {
+        let real = TestTensor::zeros([2, 4]);
+        let imag = TestTensor::zeros([2, 3]);
+
+        let _output = fft::fft(real, imag, 1);
+    }
+}
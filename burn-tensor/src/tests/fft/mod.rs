@@ -0,0 +1,3 @@
+mod fft_ifft;
+mod rfft;
+mod stft;
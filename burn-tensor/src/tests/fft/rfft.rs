@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(rfft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{fft, Data, Tensor};
+
+    #[test]
+    fn should_compute_rfft() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 0.0]]);
+
+        let (real, imag) = fft::rfft(tensor, 1);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0, -2.0], [6.0, -2.0, 2.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0, 0.0], [0.0, -2.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_rfft_of_odd_size() {
+        let tensor = Tensor::<TestBackend, 1>::from_data([1.0, 2.0, 3.0]);
+
+        let (real, imag) = fft::rfft(tensor, 0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([6.0, -1.5]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.866]), 3);
+    }
+
+    #[test]
+    fn should_invert_rfft_with_irfft() {
+        let even = TestTensor::from_data([[1.0, -2.0, 0.5, 3.0], [0.0, 1.0, 2.0, -1.0]]);
+        let odd = TestTensor::from_data([[1.0, -2.0, 0.5], [0.0, 1.0, 2.0]]);
+
+        let (real, imag) = fft::rfft(even.clone(), 1);
+        let output_even = fft::irfft(real, imag, 1, 4);
+        let (real, imag) = fft::rfft(odd.clone(), 1);
+        let output_odd = fft::irfft(real, imag, 1, 3);
+
+        output_even
+            .into_data()
+            .assert_approx_eq(&even.into_data(), 3);
+        output_odd.into_data().assert_approx_eq(&odd.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_irfft_with_missing_frequencies() {
+        let real = Tensor::<TestBackend, 1>::from_data([4.0, 2.0]);
+        let imag = Tensor::<TestBackend, 1>::from_data([0.0, 0.0]);
+
+        let output = fft::irfft(real, imag, 0, 4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, 1.0, 0.0, 1.0]), 3);
+    }
+}
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{fft, Data, Int, Tensor};
+
+    #[test]
+    fn should_compute_stft() {
+        let signal = Tensor::<TestBackend, 1, Int>::arange(0..8)
+            .float()
+            .reshape([1, 8]);
+
+        let (real, imag) = fft::stft(signal, 4, 2, None, false);
+
+        real.into_data().assert_approx_eq(
+            &Data::from([[[6.0, 14.0, 22.0], [-2.0, -2.0, -2.0], [-2.0, -2.0, -2.0]]]),
+            3,
+        );
+        imag.into_data().assert_approx_eq(
+            &Data::from([[[0.0, 0.0, 0.0], [2.0, 2.0, 2.0], [0.0, 0.0, 0.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_centered_stft_with_window() {
+        let signal = Tensor::<TestBackend, 2>::ones([2, 16]);
+        let window = fft::hann_window::<TestBackend>(8, &signal.device());
+
+        let (real, imag) = fft::stft(signal, 8, 4, Some(window), true);
+
+        assert_eq!(real.dims(), [2, 5, 5]);
+        assert_eq!(imag.dims(), [2, 5, 5]);
+        // The sum of the Hann window of a constant signal.
+        real.slice([0..1, 0..1, 0..1])
+            .into_data()
+            .assert_approx_eq(&Data::from([[[4.0]]]), 3);
+    }
+
+    #[test]
+    fn should_create_hann_window() {
+        let window = fft::hann_window::<TestBackend>(4, &Default::default());
+
+        window
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.5, 1.0, 0.5]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_window_size_differs() {
+        let signal = TestTensor::ones([1, 16]);
+        let window = fft::hann_window::<TestBackend>(6, &signal.device());
+
+        let _output = fft::stft(signal, 8, 4, Some(window), false);
+    }
+}
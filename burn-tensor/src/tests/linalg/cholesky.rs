@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_compute_cholesky_2d() {
+        let tensor = TestTensor::from_data([[4.0, 2.0], [2.0, 5.0]]);
+
+        let output = linalg::cholesky(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0], [1.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_cholesky_batched() {
+        let tensor = Tensor::<TestBackend, 3>::from_data([
+            [
+                [4.0, 12.0, -16.0],
+                [12.0, 37.0, -43.0],
+                [-16.0, -43.0, 98.0],
+            ],
+            [[1.0, 0.0, 0.0], [0.0, 9.0, 0.0], [0.0, 0.0, 16.0]],
+        ]);
+
+        let output = linalg::cholesky(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+                [[1.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 4.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_not_square() {
+        let tensor = TestTensor::from_data([[4.0, 2.0, 1.0], [2.0, 5.0, 1.0]]);
+
+        let _output = linalg::cholesky(tensor);
+    }
+}
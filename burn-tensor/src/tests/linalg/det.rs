@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(det)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_compute_det_2d() {
+        let tensor = TestTensor::from_data([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[10.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_batched_with_row_swaps() {
+        let tensor = Tensor::<TestBackend, 3>::from_data([
+            [[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [4.0, -3.0, 8.0]],
+            [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[-2.0]], [[-1.0]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_singular_matrix() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [2.0, 4.0]]);
+
+        let output = linalg::det(tensor);
+
+        output.into_data().assert_approx_eq(&Data::from([[0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_singular_matrix_with_zero_column() {
+        let tensor = TestTensor::from_data([[0.0, 0.0, 1.0], [0.0, 0.0, 2.0], [1.0, 1.0, 1.0]]);
+
+        let output = linalg::det(tensor);
+
+        output.into_data().assert_approx_eq(&Data::from([[0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_empty_matrix_as_one() {
+        let tensor = Tensor::<TestBackend, 3>::zeros([2, 0, 0]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0]], [[1.0]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_empty_batch() {
+        let tensor = Tensor::<TestBackend, 3>::zeros([0, 3, 3]);
+
+        let output = linalg::det(tensor);
+
+        assert_eq!(output.dims(), [0, 1, 1]);
+    }
+}
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_compute_eigh_2d() {
+        let tensor = TestTensor::from_data([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 3.0]]), 3);
+        (vectors.clone() * values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_eigh_batched() {
+        let tensor = Tensor::<TestBackend, 3>::from_data([
+            [[2.0, 0.0, 0.0], [0.0, 3.0, 4.0], [0.0, 4.0, 9.0]],
+            [[5.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 2.0]],
+        ]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0, 2.0, 11.0]], [[-1.0, 2.0, 5.0]]]), 3);
+        (vectors.clone() * values)
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
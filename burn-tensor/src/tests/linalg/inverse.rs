@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(inverse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_inverse_2d() {
+        let tensor = TestTensor::from_data([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.6, -0.7], [-0.2, 0.4]]), 3);
+    }
+
+    #[test]
+    fn should_inverse_batched_with_pivoting() {
+        let tensor = Tensor::<TestBackend, 3>::from_data([
+            [[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [4.0, -3.0, 8.0]],
+            [[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 0.5]],
+        ]);
+
+        let output = linalg::inverse(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[-4.5, 7.0, -1.5], [-2.0, 4.0, -1.0], [1.5, -2.0, 0.5]],
+                [[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 2.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_pinverse_tall_matrix() {
+        let tensor = TestTensor::from_data([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output = linalg::pinverse(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [2.0 / 3.0, -1.0 / 3.0, 1.0 / 3.0],
+                [-1.0 / 3.0, 2.0 / 3.0, 1.0 / 3.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_pinverse_wide_matrix() {
+        let tensor = TestTensor::from_data([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+
+        let output = linalg::pinverse(tensor);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [2.0 / 3.0, -1.0 / 3.0],
+                [-1.0 / 3.0, 2.0 / 3.0],
+                [1.0 / 3.0, 1.0 / 3.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matrix_is_not_square() {
+        let tensor = TestTensor::from_data([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+
+        let _output = linalg::inverse(tensor);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_pinverse_of_vector() {
+        let tensor = Tensor::<TestBackend, 1>::from_data([1.0, 2.0, 3.0]);
+
+        linalg::pinverse(tensor);
+    }
+}
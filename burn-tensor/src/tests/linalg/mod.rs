@@ -0,0 +1,8 @@
+mod cholesky;
+mod det;
+mod eigh;
+mod inverse;
+mod qr;
+mod solve;
+mod svd;
+mod triangular_solve;
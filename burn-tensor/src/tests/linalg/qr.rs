@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_compute_qr_of_tall_matrix() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        q.clone()
+            .matmul(r.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        q.clone()
+            .transpose()
+            .matmul(q)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        r.slice([1..2, 0..1])
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_qr_of_wide_matrix() {
+        let tensor = TestTensor::from_data([[2.0, 1.0, 3.0], [4.0, -1.0, 0.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2]);
+        assert_eq!(r.dims(), [2, 3]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
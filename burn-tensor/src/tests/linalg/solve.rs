@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_solve_linear_system() {
+        let lhs = TestTensor::from_data([[3.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::from_data([[9.0, 1.0], [8.0, 0.0]]);
+
+        let output = linalg::solve(lhs, rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.4], [3.0, -0.2]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_rhs_rows_differ() {
+        let lhs = TestTensor::from_data([[3.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::from_data([[9.0, 1.0, 3.0]]);
+
+        let _output = linalg::solve(lhs, rhs);
+    }
+}
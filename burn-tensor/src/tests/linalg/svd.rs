@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_compute_svd_of_square_matrix() {
+        let tensor = TestTensor::from_data([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.7082, 2.2361]]), 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_svd_of_wide_matrix() {
+        let tensor = TestTensor::from_data([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 3.0]]), 3);
+        vh.clone()
+            .matmul(vh.clone().transpose())
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(triangular_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_solve_lower_triangular_system() {
+        // The upper triangle isn't read.
+        let lhs = TestTensor::from_data([[2.0, 9.0], [1.0, 3.0]]);
+        let rhs = TestTensor::from_data([[4.0, 2.0], [11.0, 4.0]]);
+
+        let output = linalg::triangular_solve(lhs, rhs, false, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 1.0], [3.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_solve_upper_triangular_system() {
+        let lhs = TestTensor::from_data([[2.0, 1.0], [7.0, 4.0]]);
+        let rhs = TestTensor::from_data([[5.0], [8.0]]);
+
+        let output = linalg::triangular_solve(lhs, rhs, true, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.5], [2.0]]), 3);
+    }
+
+    #[test]
+    fn should_solve_unit_diagonal_batched_systems() {
+        let lhs = TestTensor::from_data([[[5.0, 0.0], [2.0, 5.0]], [[1.0, 0.0], [-1.0, 3.0]]]);
+        let rhs = TestTensor::from_data([[[1.0], [4.0]], [[2.0], [1.0]]]);
+
+        let output = linalg::triangular_solve(lhs, rhs, false, true);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1.0], [2.0]], [[2.0], [3.0]]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matrices_are_not_square() {
+        let lhs = TestTensor::from_data([[2.0, 0.0, 1.0], [1.0, 3.0, 1.0]]);
+        let rhs = TestTensor::from_data([[4.0], [11.0]]);
+
+        let _output = linalg::triangular_solve(lhs, rhs, false, false);
+    }
+}
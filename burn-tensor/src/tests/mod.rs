@@ -1,7 +1,10 @@
 mod activation;
 mod clone_invariance;
+mod fft;
+mod linalg;
 mod module;
 mod ops;
+mod seed;
 mod stats;
 
 #[allow(missing_docs)]
@@ -16,19 +19,41 @@ macro_rules! testgen_all {
         burn_tensor::testgen_silu!();
         burn_tensor::testgen_tanh_activation!();
 
+        // test fft
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_rfft!();
+        burn_tensor::testgen_stft!();
+
+        // test linalg
+        burn_tensor::testgen_cholesky!();
+        burn_tensor::testgen_det!();
+        burn_tensor::testgen_eigh!();
+        burn_tensor::testgen_inverse!();
+        burn_tensor::testgen_qr!();
+        burn_tensor::testgen_solve!();
+        burn_tensor::testgen_svd!();
+        burn_tensor::testgen_triangular_solve!();
+
         // test module
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
+        burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
+        burn_tensor::testgen_module_fold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
+        burn_tensor::testgen_module_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_attention!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -36,42 +61,67 @@ macro_rules! testgen_all {
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_bincount!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_checked!();
         burn_tensor::testgen_clamp!();
+        burn_tensor::testgen_complex!();
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_device!();
+        burn_tensor::testgen_diag!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_flip!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_histc!();
+        burn_tensor::testgen_index!();
         burn_tensor::testgen_init!();
         burn_tensor::testgen_iter_dim!();
+        burn_tensor::testgen_labeled!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
+        burn_tensor::testgen_logical!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
+        burn_tensor::testgen_meshgrid!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_nonzero!();
+        burn_tensor::testgen_norm!();
         burn_tensor::testgen_one_hot!();
+        burn_tensor::testgen_pad!();
+        burn_tensor::testgen_permute!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_product!();
         burn_tensor::testgen_random!();
+        burn_tensor::testgen_readback!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
+        burn_tensor::testgen_repeat_interleave!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_roll!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_split!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_triangular!();
+        burn_tensor::testgen_unfold!();
+        burn_tensor::testgen_unique!();
 
         // test stats
         burn_tensor::testgen_var!();
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool2d, adaptive_max_pool2d_with_indices};
+    use burn_tensor::{Data, Tensor};
+
+    fn input() -> TestTensor<4> {
+        TestTensor::from_floats([[[
+            [0.5, -1.0, 3.0, 2.0, 0.0],
+            [1.5, 4.0, -2.0, 0.5, 1.0],
+            [-0.5, 2.5, 1.0, 3.5, -1.5],
+            [2.0, 0.0, 5.0, -3.0, 0.5],
+        ]]])
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_simple() {
+        let output = adaptive_max_pool2d(input(), [2, 2]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4.0, 3.0], [5.0, 5.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_with_indices_overlapping_windows() {
+        let (output, indices) = adaptive_max_pool2d_with_indices(input(), [3, 3]);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[4.0, 4.0, 2.0], [4.0, 4.0, 3.5], [2.5, 5.0, 3.5]]]]),
+            3,
+        );
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[[[6, 6, 3], [6, 6, 13], [11, 17, 13]]]])
+        );
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_bigger_output() {
+        let (output, indices) = adaptive_max_pool2d_with_indices(input(), [5, 6]);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.5, 0.5, 3.0, 3.0, 2.0, 0.0],
+                [1.5, 4.0, 4.0, 3.0, 2.0, 1.0],
+                [1.5, 4.0, 4.0, 3.5, 3.5, 1.0],
+                [2.0, 2.5, 5.0, 5.0, 3.5, 0.5],
+                [2.0, 2.0, 5.0, 5.0, 0.5, 0.5],
+            ]]]),
+            3,
+        );
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[[
+                [0, 0, 2, 2, 3, 4],
+                [5, 6, 6, 2, 3, 9],
+                [5, 6, 6, 13, 13, 9],
+                [15, 11, 17, 17, 13, 19],
+                [15, 15, 17, 17, 19, 19],
+            ]]])
+        );
+    }
+}
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{attention, attention_dropout_mask, attention_with_dropout};
+    use burn_tensor::ops::AttentionDropout;
+    use burn_tensor::{activation, Bool, Data, Distribution, Tensor};
+
+    fn values() -> TestTensor<4> {
+        TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]])
+    }
+
+    #[test]
+    fn test_attention_uniform_weights() {
+        let query = TestTensor::zeros([1, 1, 2, 1]);
+        let key = TestTensor::from_floats([[[[1.0], [-1.0]]]]);
+
+        let output = attention(query, key, values(), None, -1.0e4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.0, 3.0], [2.0, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_attention_scaled_scores() {
+        // The scores are [2 ln(3), 0] / sqrt(4), so the weights are [0.75, 0.25].
+        let ln_3 = 1.0986123;
+        let query = TestTensor::from_floats([[[[ln_3, ln_3, ln_3, ln_3]]]]);
+        let key = TestTensor::from_floats([[[[0.5, 0.5, 0.5, 0.5], [0.0, 0.0, 0.0, 0.0]]]]);
+
+        let output = attention(query, key, values(), None, -1.0e4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.5, 2.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_attention_mask() {
+        let query = TestTensor::zeros([1, 1, 2, 1]);
+        let key = TestTensor::from_floats([[[[1.0], [-1.0]]]]);
+        let mask = Tensor::<TestBackend, 3, Bool>::from_bool(Data::from([[
+            [false, true],
+            [false, false],
+        ]]));
+
+        let output = attention(query, key, values(), Some(mask), -1.0e4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 2.0], [2.0, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_attention_dropout_applied_to_weights() {
+        let query = TestTensor::random([2, 3, 5, 4], Distribution::Default);
+        let key = TestTensor::random([2, 3, 6, 4], Distribution::Default);
+        let value = TestTensor::random([2, 3, 6, 3], Distribution::Default);
+        let dropout = AttentionDropout::new(0.3, 42);
+
+        let output = attention_with_dropout(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            Some(dropout),
+            -1.0e4,
+        );
+
+        let dropout_mask =
+            attention_dropout_mask::<TestBackend>(dropout, [2, 3, 5, 6], &query.device());
+        let scores = query.matmul(key.transpose()).div_scalar(2.0);
+        let output_ref = (activation::softmax(scores, 3) * dropout_mask).matmul(value);
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+}
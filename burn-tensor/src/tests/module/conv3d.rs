@@ -0,0 +1,246 @@
+#[burn_tensor_testgen::testgen(module_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv3d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_conv3d_simple() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [
+                    [13840.0, 20920.0, 14032.0],
+                    [21264.0, 32082.0, 21480.0],
+                    [14320.0, 21568.0, 14416.0],
+                ],
+                [
+                    [22488.0, 33810.0, 22560.0],
+                    [34002.0, 51039.0, 34002.0],
+                    [22560.0, 33810.0, 22488.0],
+                ],
+                [
+                    [14416.0, 21568.0, 14320.0],
+                    [21480.0, 32082.0, 21264.0],
+                    [14032.0, 20920.0, 13840.0],
+                ],
+            ],
+            [
+                [
+                    [31121.0, 47489.0, 32177.0],
+                    [49129.0, 74851.0, 50641.0],
+                    [34193.0, 52025.0, 35153.0],
+                ],
+                [
+                    [54241.0, 82411.0, 55609.0],
+                    [84547.0, 128314.0, 86491.0],
+                    [58201.0, 88243.0, 59425.0],
+                ],
+                [
+                    [39473.0, 59801.0, 40241.0],
+                    [61009.0, 92347.0, 62089.0],
+                    [41681.0, 63041.0, 42353.0],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 3,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[268.0, 296.0], [352.0, 380.0]],
+                [[520.0, 548.0], [604.0, 632.0]],
+            ],
+            [
+                [[685.0, 777.0], [961.0, 1053.0]],
+                [[1513.0, 1605.0], [1789.0, 1881.0]],
+            ],
+            [
+                [[5314.0, 5470.0], [5782.0, 5938.0]],
+                [[6718.0, 6874.0], [7186.0, 7342.0]],
+            ],
+            [
+                [[7459.0, 7679.0], [8119.0, 8339.0]],
+                [[9439.0, 9659.0], [10099.0, 10319.0]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 2,
+            kernel_size_2: 3,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 2,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 5,
+            height: 4,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [
+                [
+                    [[5154.0, 10302.0], [5424.0, 10824.0]],
+                    [[10848.0, 21432.0], [11280.0, 22260.0]],
+                    [[4830.0, 9402.0], [4992.0, 9708.0]],
+                ],
+                [
+                    [[11635.0, 23695.0], [12337.0, 25081.0]],
+                    [[27265.0, 55129.0], [28561.0, 57685.0]],
+                    [[14767.0, 29707.0], [15361.0, 30877.0]],
+                ],
+                [
+                    [[18116.0, 37088.0], [19250.0, 39338.0]],
+                    [[43682.0, 88826.0], [45842.0, 93110.0]],
+                    [[24704.0, 50012.0], [25730.0, 52046.0]],
+                ],
+            ],
+            [
+                [
+                    [[15954.0, 31182.0], [16224.0, 31704.0]],
+                    [[28128.0, 54552.0], [28560.0, 55380.0]],
+                    [[11310.0, 21642.0], [11472.0, 21948.0]],
+                ],
+                [
+                    [[39715.0, 79135.0], [40417.0, 80521.0]],
+                    [[79105.0, 157369.0], [80401.0, 159925.0]],
+                    [[38527.0, 76507.0], [39121.0, 77677.0]],
+                ],
+                [
+                    [[63476.0, 127088.0], [64610.0, 129338.0]],
+                    [[130082.0, 260186.0], [132242.0, 264470.0]],
+                    [[65744.0, 131372.0], [66770.0, 133406.0]],
+                ],
+            ],
+        ]));
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weight = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv3d(
+                x,
+                weight,
+                Some(bias),
+                ConvOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -89,6 +89,37 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv_transpose1d_groups_stride_dilation_padding_out() {
+        let test = ConvTranspose1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size: 3,
+            padding: 1,
+            padding_out: 1,
+            stride: 2,
+            dilation: 2,
+            groups: 2,
+            length: 4,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [
+                [0.0, 0.0, 0.0, 1.0, 0.0, 4.0, 0.0, 7.0, 0.0, 6.0],
+                [1.0, 4.0, 1.0, 11.0, 1.0, 23.0, 1.0, 23.0, 1.0, 16.0],
+                [2.0, 60.0, 2.0, 105.0, 2.0, 126.0, 2.0, 99.0, 2.0, 58.0],
+                [3.0, 88.0, 3.0, 151.0, 3.0, 181.0, 3.0, 139.0, 3.0, 80.0],
+            ],
+            [
+                [0.0, 8.0, 0.0, 25.0, 0.0, 28.0, 0.0, 31.0, 0.0, 22.0],
+                [1.0, 60.0, 1.0, 107.0, 1.0, 119.0, 1.0, 95.0, 1.0, 56.0],
+                [2.0, 164.0, 2.0, 273.0, 2.0, 294.0, 2.0, 219.0, 2.0, 122.0],
+                [3.0, 240.0, 3.0, 391.0, 3.0, 421.0, 3.0, 307.0, 3.0, 168.0],
+            ],
+        ]));
+    }
+
     struct ConvTranspose1dTestCase {
         batch_size: usize,
         channels_in: usize,
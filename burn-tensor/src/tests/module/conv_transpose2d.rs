@@ -272,6 +272,100 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_conv_transpose2d_groups_multiple_channels() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            stride_1: 1,
+            stride_2: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            groups: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[0.0, 0.0, 1.0], [0.0, 4.0, 6.0], [4.0, 12.0, 9.0]],
+            [[1.0, 5.0, 6.0], [9.0, 29.0, 23.0], [13.0, 33.0, 22.0]],
+            [
+                [34.0, 78.0, 47.0],
+                [90.0, 206.0, 120.0],
+                [62.0, 138.0, 79.0],
+            ],
+            [
+                [51.0, 115.0, 68.0],
+                [131.0, 295.0, 169.0],
+                [87.0, 191.0, 108.0],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose2d_groups_stride_dilation_padding_out() {
+        let test = ConvTranspose2dTestCase {
+            batch_size: 1,
+            channels_in: 4,
+            channels_out: 4,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_out_1: 1,
+            padding_out_2: 1,
+            stride_1: 2,
+            stride_2: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            groups: 2,
+            height: 3,
+            width: 3,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [126.0, 0.0, 277.0, 0.0, 311.0, 0.0, 171.0, 0.0],
+                [288.0, 0.0, 632.0, 0.0, 700.0, 0.0, 384.0, 0.0],
+                [174.0, 0.0, 379.0, 0.0, 413.0, 0.0, 225.0, 0.0],
+                [384.0, 0.0, 836.0, 0.0, 904.0, 0.0, 492.0, 0.0],
+                [222.0, 0.0, 481.0, 0.0, 515.0, 0.0, 279.0, 0.0],
+                [264.0, 0.0, 569.0, 0.0, 611.0, 0.0, 329.0, 0.0],
+            ],
+            [
+                [181.0, 1.0, 398.0, 1.0, 456.0, 1.0, 250.0, 1.0],
+                [433.0, 1.0, 945.0, 1.0, 1061.0, 1.0, 577.0, 1.0],
+                [265.0, 1.0, 572.0, 1.0, 630.0, 1.0, 340.0, 1.0],
+                [601.0, 1.0, 1293.0, 1.0, 1409.0, 1.0, 757.0, 1.0],
+                [349.0, 1.0, 746.0, 1.0, 804.0, 1.0, 430.0, 1.0],
+                [391.0, 1.0, 834.0, 1.0, 900.0, 1.0, 480.0, 1.0],
+            ],
+            [
+                [1496.0, 2.0, 3099.0, 2.0, 3229.0, 2.0, 1673.0, 2.0],
+                [3170.0, 2.0, 6562.0, 2.0, 6822.0, 2.0, 3530.0, 2.0],
+                [1688.0, 2.0, 3489.0, 2.0, 3619.0, 2.0, 1871.0, 2.0],
+                [3554.0, 2.0, 7342.0, 2.0, 7602.0, 2.0, 3926.0, 2.0],
+                [1880.0, 2.0, 3879.0, 2.0, 4009.0, 2.0, 2069.0, 2.0],
+                [1994.0, 2.0, 4111.0, 2.0, 4249.0, 2.0, 2191.0, 2.0],
+            ],
+            [
+                [1767.0, 3.0, 3652.0, 3.0, 3806.0, 3.0, 1968.0, 3.0],
+                [3747.0, 3.0, 7739.0, 3.0, 8047.0, 3.0, 4155.0, 3.0],
+                [1995.0, 3.0, 4114.0, 3.0, 4268.0, 3.0, 2202.0, 3.0],
+                [4203.0, 3.0, 8663.0, 3.0, 8971.0, 3.0, 4623.0, 3.0],
+                [2223.0, 3.0, 4576.0, 3.0, 4730.0, 3.0, 2436.0, 3.0],
+                [2337.0, 3.0, 4808.0, 3.0, 4970.0, 3.0, 2558.0, 3.0],
+            ],
+        ]]));
+    }
+
     struct ConvTranspose2dTestCase {
         batch_size: usize,
         channels_in: usize,
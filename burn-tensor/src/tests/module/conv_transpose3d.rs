@@ -0,0 +1,395 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::ConvTransposeOptions;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size_1: 3,
+            kernel_size_2: 3,
+            kernel_size_3: 3,
+            padding_1: 1,
+            padding_2: 1,
+            padding_3: 1,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[5576.0, 5696.0], [5936.0, 6056.0]],
+                [[6656.0, 6776.0], [7016.0, 7136.0]],
+            ],
+            [
+                [[8817.0, 8937.0], [9177.0, 9297.0]],
+                [[9897.0, 10017.0], [10257.0, 10377.0]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size_1: 2,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 0,
+            padding_2: 0,
+            padding_3: 0,
+            padding_out_1: 0,
+            padding_out_2: 0,
+            padding_out_3: 0,
+            stride_1: 1,
+            stride_2: 1,
+            stride_3: 1,
+            dilation_1: 1,
+            dilation_2: 1,
+            dilation_3: 1,
+            groups: 2,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[0.0, 0.0, 1.0], [0.0, 4.0, 6.0], [4.0, 12.0, 9.0]],
+                [[0.0, 8.0, 10.0], [16.0, 56.0, 44.0], [24.0, 64.0, 42.0]],
+                [[16.0, 40.0, 25.0], [48.0, 116.0, 70.0], [36.0, 84.0, 49.0]],
+            ],
+            [
+                [[1.0, 9.0, 10.0], [17.0, 53.0, 39.0], [21.0, 53.0, 34.0]],
+                [
+                    [33.0, 89.0, 59.0],
+                    [113.0, 281.0, 173.0],
+                    [89.0, 209.0, 123.0],
+                ],
+                [
+                    [49.0, 113.0, 66.0],
+                    [129.0, 293.0, 167.0],
+                    [85.0, 189.0, 106.0],
+                ],
+            ],
+            [
+                [
+                    [130.0, 282.0, 155.0],
+                    [306.0, 662.0, 360.0],
+                    [182.0, 390.0, 211.0],
+                ],
+                [
+                    [354.0, 762.0, 412.0],
+                    [818.0, 1754.0, 942.0],
+                    [474.0, 1010.0, 540.0],
+                ],
+                [
+                    [242.0, 514.0, 275.0],
+                    [546.0, 1158.0, 616.0],
+                    [310.0, 654.0, 347.0],
+                ],
+            ],
+            [
+                [
+                    [195.0, 419.0, 228.0],
+                    [451.0, 967.0, 521.0],
+                    [263.0, 559.0, 300.0],
+                ],
+                [
+                    [515.0, 1099.0, 589.0],
+                    [1171.0, 2491.0, 1327.0],
+                    [667.0, 1411.0, 749.0],
+                ],
+                [
+                    [339.0, 715.0, 380.0],
+                    [755.0, 1591.0, 841.0],
+                    [423.0, 887.0, 468.0],
+                ],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_complex() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            kernel_size_3: 2,
+            padding_1: 1,
+            padding_2: 0,
+            padding_3: 1,
+            padding_out_1: 1,
+            padding_out_2: 0,
+            padding_out_3: 1,
+            stride_1: 2,
+            stride_2: 1,
+            stride_3: 2,
+            dilation_1: 1,
+            dilation_2: 2,
+            dilation_3: 1,
+            groups: 1,
+            depth: 2,
+            height: 2,
+            width: 2,
+        };
+
+        test.assert_output(TestTensor::from_floats([
+            [
+                [
+                    [
+                        [328.0, 364.0, 374.0],
+                        [420.0, 452.0, 466.0],
+                        [344.0, 384.0, 394.0],
+                        [444.0, 480.0, 494.0],
+                    ],
+                    [
+                        [808.0, 872.0, 900.0],
+                        [992.0, 1048.0, 1084.0],
+                        [856.0, 928.0, 956.0],
+                        [1056.0, 1120.0, 1156.0],
+                    ],
+                    [
+                        [512.0, 540.0, 558.0],
+                        [604.0, 628.0, 650.0],
+                        [544.0, 576.0, 594.0],
+                        [644.0, 672.0, 694.0],
+                    ],
+                    [
+                        [576.0, 612.0, 630.0],
+                        [684.0, 716.0, 738.0],
+                        [608.0, 648.0, 666.0],
+                        [724.0, 760.0, 782.0],
+                    ],
+                ],
+                [
+                    [
+                        [425.0, 485.0, 495.0],
+                        [565.0, 621.0, 635.0],
+                        [441.0, 505.0, 515.0],
+                        [589.0, 649.0, 663.0],
+                    ],
+                    [
+                        [1097.0, 1209.0, 1237.0],
+                        [1377.0, 1481.0, 1517.0],
+                        [1145.0, 1265.0, 1293.0],
+                        [1441.0, 1553.0, 1589.0],
+                    ],
+                    [
+                        [705.0, 757.0, 775.0],
+                        [845.0, 893.0, 915.0],
+                        [737.0, 793.0, 811.0],
+                        [885.0, 937.0, 959.0],
+                    ],
+                    [
+                        [769.0, 829.0, 847.0],
+                        [925.0, 981.0, 1003.0],
+                        [801.0, 865.0, 883.0],
+                        [965.0, 1025.0, 1047.0],
+                    ],
+                ],
+                [
+                    [
+                        [522.0, 606.0, 616.0],
+                        [710.0, 790.0, 804.0],
+                        [538.0, 626.0, 636.0],
+                        [734.0, 818.0, 832.0],
+                    ],
+                    [
+                        [1386.0, 1546.0, 1574.0],
+                        [1762.0, 1914.0, 1950.0],
+                        [1434.0, 1602.0, 1630.0],
+                        [1826.0, 1986.0, 2022.0],
+                    ],
+                    [
+                        [898.0, 974.0, 992.0],
+                        [1086.0, 1158.0, 1180.0],
+                        [930.0, 1010.0, 1028.0],
+                        [1126.0, 1202.0, 1224.0],
+                    ],
+                    [
+                        [962.0, 1046.0, 1064.0],
+                        [1166.0, 1246.0, 1268.0],
+                        [994.0, 1082.0, 1100.0],
+                        [1206.0, 1290.0, 1312.0],
+                    ],
+                ],
+            ],
+            [
+                [
+                    [
+                        [1064.0, 1068.0, 1110.0],
+                        [1156.0, 1156.0, 1202.0],
+                        [1144.0, 1152.0, 1194.0],
+                        [1244.0, 1248.0, 1294.0],
+                    ],
+                    [
+                        [2280.0, 2280.0, 2372.0],
+                        [2464.0, 2456.0, 2556.0],
+                        [2456.0, 2464.0, 2556.0],
+                        [2656.0, 2656.0, 2756.0],
+                    ],
+                    [
+                        [1248.0, 1244.0, 1294.0],
+                        [1340.0, 1332.0, 1386.0],
+                        [1344.0, 1344.0, 1394.0],
+                        [1444.0, 1440.0, 1494.0],
+                    ],
+                    [
+                        [1440.0, 1444.0, 1494.0],
+                        [1548.0, 1548.0, 1602.0],
+                        [1536.0, 1544.0, 1594.0],
+                        [1652.0, 1656.0, 1710.0],
+                    ],
+                ],
+                [
+                    [
+                        [1545.0, 1573.0, 1615.0],
+                        [1685.0, 1709.0, 1755.0],
+                        [1625.0, 1657.0, 1699.0],
+                        [1773.0, 1801.0, 1847.0],
+                    ],
+                    [
+                        [3337.0, 3385.0, 3477.0],
+                        [3617.0, 3657.0, 3757.0],
+                        [3513.0, 3569.0, 3661.0],
+                        [3809.0, 3857.0, 3957.0],
+                    ],
+                    [
+                        [1825.0, 1845.0, 1895.0],
+                        [1965.0, 1981.0, 2035.0],
+                        [1921.0, 1945.0, 1995.0],
+                        [2069.0, 2089.0, 2143.0],
+                    ],
+                    [
+                        [2017.0, 2045.0, 2095.0],
+                        [2173.0, 2197.0, 2251.0],
+                        [2113.0, 2145.0, 2195.0],
+                        [2277.0, 2305.0, 2359.0],
+                    ],
+                ],
+                [
+                    [
+                        [2026.0, 2078.0, 2120.0],
+                        [2214.0, 2262.0, 2308.0],
+                        [2106.0, 2162.0, 2204.0],
+                        [2302.0, 2354.0, 2400.0],
+                    ],
+                    [
+                        [4394.0, 4490.0, 4582.0],
+                        [4770.0, 4858.0, 4958.0],
+                        [4570.0, 4674.0, 4766.0],
+                        [4962.0, 5058.0, 5158.0],
+                    ],
+                    [
+                        [2402.0, 2446.0, 2496.0],
+                        [2590.0, 2630.0, 2684.0],
+                        [2498.0, 2546.0, 2596.0],
+                        [2694.0, 2738.0, 2792.0],
+                    ],
+                    [
+                        [2594.0, 2646.0, 2696.0],
+                        [2798.0, 2846.0, 2900.0],
+                        [2690.0, 2746.0, 2796.0],
+                        [2902.0, 2954.0, 3008.0],
+                    ],
+                ],
+            ],
+        ]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size_1: usize,
+        kernel_size_2: usize,
+        kernel_size_3: usize,
+        padding_1: usize,
+        padding_2: usize,
+        padding_3: usize,
+        padding_out_1: usize,
+        padding_out_2: usize,
+        padding_out_3: usize,
+        stride_1: usize,
+        stride_2: usize,
+        stride_3: usize,
+        dilation_1: usize,
+        dilation_2: usize,
+        dilation_3: usize,
+        groups: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+                self.kernel_size_3,
+            ]);
+            let weights = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weights.num_elements())
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv_transpose3d(
+                x,
+                weights,
+                Some(bias),
+                ConvTransposeOptions::new(
+                    [self.stride_1, self.stride_2, self.stride_3],
+                    [self.padding_1, self.padding_2, self.padding_3],
+                    [self.padding_out_1, self.padding_out_2, self.padding_out_3],
+                    [self.dilation_1, self.dilation_2, self.dilation_3],
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+#[burn_tensor_testgen::testgen(module_fold4d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{fold4d, unfold4d};
+    use burn_tensor::ops::UnfoldOptions;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn test_fold4d_non_overlapping_is_inverse_of_unfold4d() {
+        let test = Fold4dTestCase {
+            batch_size: 2,
+            channels_in: 3,
+            kernel_size: [2, 2],
+            padding: [0, 0],
+            stride: [2, 2],
+            dilation: [1, 1],
+            height: 4,
+            width: 6,
+        };
+
+        let x = test.input();
+        let output = test.fold_unfold(x.clone());
+
+        output.into_data().assert_approx_eq(&x.into_data(), 3);
+    }
+
+    #[test]
+    fn test_fold4d_sums_overlapping_values() {
+        let test = Fold4dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            kernel_size: [2, 2],
+            padding: [0, 0],
+            stride: [1, 1],
+            dilation: [1, 1],
+            height: 3,
+            width: 3,
+        };
+
+        let output = test.fold_unfold(TestTensor::ones([1, 1, 3, 3]));
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1., 2., 1.], [2., 4., 2.], [1., 2., 1.]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_fold4d_with_padding() {
+        let test = Fold4dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            kernel_size: [2, 2],
+            padding: [1, 1],
+            stride: [1, 1],
+            dilation: [1, 1],
+            height: 2,
+            width: 2,
+        };
+
+        let output = test.fold_unfold(TestTensor::ones([1, 1, 2, 2]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4., 4.], [4., 4.]]]]), 3);
+    }
+
+    #[test]
+    fn test_fold4d_uncovered_values_are_zeros() {
+        let test = Fold4dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            kernel_size: [2, 2],
+            padding: [0, 0],
+            stride: [2, 2],
+            dilation: [1, 1],
+            height: 3,
+            width: 3,
+        };
+
+        let output = test.fold_unfold(TestTensor::ones([1, 1, 3, 3]));
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1., 1., 0.], [1., 1., 0.], [0., 0., 0.]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold4d_should_panic_when_channels_not_divisible_by_kernel() {
+        let x = TestTensor::<3>::zeros([1, 5, 4]);
+
+        fold4d(x, [3, 3], [2, 2], UnfoldOptions::new([1, 1], [0, 0], [1, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold4d_should_panic_when_blocks_dont_match_output_size() {
+        let x = TestTensor::<3>::zeros([1, 4, 5]);
+
+        fold4d(x, [3, 3], [2, 2], UnfoldOptions::new([1, 1], [0, 0], [1, 1]));
+    }
+
+    struct Fold4dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        kernel_size: [usize; 2],
+        padding: [usize; 2],
+        stride: [usize; 2],
+        dilation: [usize; 2],
+        height: usize,
+        width: usize,
+    }
+
+    impl Fold4dTestCase {
+        fn input(&self) -> TestTensor<4> {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
+            TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+        }
+
+        fn fold_unfold(&self, x: TestTensor<4>) -> TestTensor<4> {
+            let options = UnfoldOptions::new(self.stride, self.padding, self.dilation);
+            let unfolded = unfold4d(x, self.kernel_size, options.clone());
+            let output = fold4d(
+                unfolded,
+                [self.height, self.width],
+                self.kernel_size,
+                options,
+            );
+
+            assert_eq!(
+                output.dims(),
+                [self.batch_size, self.channels_in, self.height, self.width],
+                "Expected shape doesn't match the actual shape"
+            );
+
+            output
+        }
+    }
+}
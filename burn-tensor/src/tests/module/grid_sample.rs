@@ -0,0 +1,111 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleOptions, GridSamplePaddingMode, InterpolateMode};
+    use burn_tensor::{Data, Tensor};
+
+    fn square() -> TestTensor<4> {
+        TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]])
+    }
+
+    fn row() -> TestTensor<4> {
+        TestTensor::from_floats([[[[1.0, 2.0, 3.0]]]])
+    }
+
+    fn row_grid() -> TestTensor<4> {
+        TestTensor::from_floats([[[[-1.5, 0.0], [0.5, 0.0], [1.5, 0.0]]]])
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_align_corners() {
+        let grid =
+            TestTensor::from_floats([[[[0.0, 0.0], [-1.0, -1.0]], [[1.0, -1.0], [-1.0, 1.0]]]]);
+        let options = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            true,
+        );
+
+        let output = grid_sample_2d(square(), grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.5, 1.0], [2.0, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_and_border() {
+        let grid = || TestTensor::from_floats([[[[-1.0, -1.0], [1.0, 1.0]]]]);
+        let zeros = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+        let border = GridSampleOptions::new(
+            InterpolateMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            false,
+        );
+
+        let output_zeros = grid_sample_2d(square(), grid(), zeros);
+        let output_border = grid_sample_2d(square(), grid(), border);
+
+        output_zeros
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.25, 1.0]]]]), 3);
+        output_border
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.0, 4.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest() {
+        let grid = TestTensor::from_floats([[[[0.5, -0.5], [-0.5, 0.5]]]]);
+        let options =
+            GridSampleOptions::new(InterpolateMode::Nearest, GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample_2d(square(), grid, options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.0, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_padding_modes() {
+        let sample = |padding_mode| {
+            let options = GridSampleOptions::new(InterpolateMode::Bilinear, padding_mode, true);
+            grid_sample_2d(row(), row_grid(), options).into_data()
+        };
+
+        sample(GridSamplePaddingMode::Zeros)
+            .assert_approx_eq(&Data::from([[[[0.5, 2.5, 1.5]]]]), 3);
+        sample(GridSamplePaddingMode::Border)
+            .assert_approx_eq(&Data::from([[[[1.0, 2.5, 3.0]]]]), 3);
+        sample(GridSamplePaddingMode::Reflection)
+            .assert_approx_eq(&Data::from([[[[1.5, 2.5, 2.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bicubic() {
+        let grid = || TestTensor::from_floats([[[[0.5, 0.0]]]]);
+        let zeros =
+            GridSampleOptions::new(InterpolateMode::Bicubic, GridSamplePaddingMode::Zeros, true);
+        let border = GridSampleOptions::new(
+            InterpolateMode::Bicubic,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        let output_zeros = grid_sample_2d(row(), grid(), zeros);
+        let output_border = grid_sample_2d(row(), grid(), border);
+
+        output_zeros
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.875]]]]), 3);
+        output_border
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.59375]]]]), 3);
+    }
+}
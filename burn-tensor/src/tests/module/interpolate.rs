@@ -0,0 +1,103 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{interpolate, interpolate1d};
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::{Data, Tensor};
+
+    fn input() -> TestTensor<4> {
+        TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]])
+    }
+
+    #[test]
+    fn test_interpolate_nearest() {
+        let options = InterpolateOptions::new(InterpolateMode::Nearest, false);
+
+        let output = interpolate(input(), [3, 4], options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners() {
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, true);
+
+        let output = interpolate(input(), [3, 3], options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, false);
+
+        let output = interpolate(input(), [4, 4], options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.25, 1.75, 2.0],
+                [1.5, 1.75, 2.25, 2.5],
+                [2.5, 2.75, 3.25, 3.5],
+                [3.0, 3.25, 3.75, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bicubic() {
+        let options = InterpolateOptions::new(InterpolateMode::Bicubic, false);
+
+        let output = interpolate(input(), [4, 4], options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.6836, 1.0156, 1.5625, 1.8945],
+                [1.3477, 1.6797, 2.2266, 2.5586],
+                [2.4414, 2.7734, 3.3203, 3.6523],
+                [3.1055, 3.4375, 3.9844, 4.3164],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_downsample_bilinear() {
+        let x = TestTensor::from_floats([[[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]]]);
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, false);
+
+        let output = interpolate(x, [2, 2], options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[3.5, 5.5], [11.5, 13.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate1d_linear() {
+        let x = TestTensor::from_floats([[[0.0, 2.0, 4.0]], [[1.0, 1.0, 1.0]]]);
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear, true);
+
+        let output = interpolate1d(x, 5, options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[0.0, 1.0, 2.0, 3.0, 4.0]], [[1.0, 1.0, 1.0, 1.0, 1.0]]]),
+            3,
+        );
+    }
+}
@@ -1,12 +1,19 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
+mod fold4d;
 mod forward;
+mod grid_sample;
+mod interpolate;
 mod maxpool1d;
 mod maxpool2d;
 mod unfold4d;
@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(bincount)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_bincount() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 1, 0, 7, -1]);
+
+        let counts = tensor.bincount(4);
+
+        assert_eq!(counts.into_data(), Data::from([1, 2, 0, 1]));
+    }
+
+    #[test]
+    fn should_support_bincount_weighted() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 2, 5]);
+        let weights = Tensor::<TestBackend, 1>::from_floats([0.5, 1.0, 1.5, 2.0]);
+
+        let sums = tensor.bincount_weighted(weights, 3);
+
+        assert_eq!(sums.into_data(), Data::from([1.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_weights_shape_differs() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 2]);
+        let weights = Tensor::<TestBackend, 1>::from_floats([0.5, 1.0]);
+
+        let _sums = tensor.bincount_weighted(weights, 3);
+    }
+}
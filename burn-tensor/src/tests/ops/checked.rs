@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(checked)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_return_ok_when_valid() {
+        let lhs = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_data([[1.0], [1.0]]);
+
+        let output = lhs
+            .clone()
+            .try_matmul(rhs.clone())
+            .unwrap()
+            .try_add(rhs)
+            .unwrap()
+            .try_reshape([2])
+            .unwrap();
+
+        assert_eq!(output.into_data(), Data::from([4.0, 8.0]));
+        assert_eq!(
+            lhs.try_slice([1..2]).unwrap().into_data(),
+            Data::from([[3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_return_error_when_matmul_shapes_mismatch() {
+        let lhs = TestTensor::<2>::zeros([2, 3]);
+        let rhs = TestTensor::<2>::zeros([2, 3]);
+
+        let error = lhs.try_matmul(rhs).unwrap_err();
+
+        assert!(error.message().contains("Matmul"));
+    }
+
+    #[test]
+    fn should_return_error_when_reshape_changes_num_elements() {
+        let tensor = TestTensor::<2>::zeros([2, 3]);
+
+        let error = tensor.try_reshape([4, 2]).unwrap_err();
+
+        assert!(error.to_string().contains("Reshape"));
+    }
+
+    #[test]
+    fn should_return_error_when_invalid() {
+        let tensor = TestTensor::<2>::zeros([2, 3]);
+
+        assert!(tensor.clone().try_slice([0..3]).is_err());
+        assert!(tensor.clone().try_mul(TestTensor::zeros([2, 2])).is_err());
+        assert!(Tensor::try_cat(vec![tensor, TestTensor::zeros([2, 2])], 0).is_err());
+        assert!(TestTensor::<2>::try_cat(vec![], 0).is_err());
+    }
+}
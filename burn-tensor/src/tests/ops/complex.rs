@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, ComplexElem, Data, ElementConversion, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+
+    fn complex(real: [f32; 3], imag: [f32; 3]) -> Tensor<TestBackend, 1, Complex> {
+        Tensor::from_parts(Tensor::from_floats(real), Tensor::from_floats(imag))
+    }
+
+    #[test]
+    fn should_support_complex_data() {
+        let value = vec![
+            ComplexElem::new(1.0.elem(), 2.0.elem()),
+            ComplexElem::new((-3.0).elem(), 0.5.elem()),
+        ];
+        let tensor =
+            Tensor::<TestBackend, 1, Complex>::from_data(Data::new(value.clone(), [2].into()));
+
+        let (real, imag) = tensor.clone().into_parts();
+
+        assert_eq!(real.into_data(), Data::from([1.0, -3.0]));
+        assert_eq!(imag.into_data(), Data::from([2.0, 0.5]));
+        assert_eq!(tensor.reshape([2, 1]).into_data().value, value);
+    }
+
+    #[test]
+    fn should_support_complex_arithmetic() {
+        let lhs = complex([1.0, 2.0, 0.0], [2.0, -1.0, 1.0]);
+        let rhs = complex([3.0, 1.0, 0.0], [-1.0, 1.0, 2.0]);
+
+        let (real, imag) = lhs.clone().add_complex(rhs.clone()).into_parts();
+        assert_eq!(real.into_data(), Data::from([4.0, 3.0, 0.0]));
+        assert_eq!(imag.into_data(), Data::from([1.0, 0.0, 3.0]));
+
+        let (real, imag) = lhs.clone().sub_complex(rhs.clone()).into_parts();
+        assert_eq!(real.into_data(), Data::from([-2.0, 1.0, 0.0]));
+        assert_eq!(imag.into_data(), Data::from([3.0, -2.0, -1.0]));
+
+        let (real, imag) = lhs.clone().mul_complex(rhs.clone()).into_parts();
+        assert_eq!(real.into_data(), Data::from([5.0, 3.0, -2.0]));
+        assert_eq!(imag.into_data(), Data::from([5.0, 1.0, 0.0]));
+
+        let (real, imag) = lhs.div_complex(rhs).into_parts();
+        real.into_data()
+            .assert_approx_eq(&Data::from([0.1, 0.5, 0.5]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.7, -1.5, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_support_conj_norm_and_angle() {
+        let tensor = complex([3.0, -1.0, 0.0], [4.0, 1.0, -2.0]);
+
+        let (real, imag) = tensor.clone().conj().into_parts();
+        assert_eq!(real.into_data(), Data::from([3.0, -1.0, 0.0]));
+        assert_eq!(imag.into_data(), Data::from([-4.0, -1.0, 2.0]));
+
+        tensor
+            .clone()
+            .norm()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, SQRT_2, 2.0]), 3);
+        tensor
+            .angle()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9273, 3.0 * FRAC_PI_4, -FRAC_PI_2]), 3);
+    }
+
+    #[test]
+    fn should_support_from_polar() {
+        let abs = Tensor::<TestBackend, 1>::from_floats([2.0, 1.0, 0.5]);
+        let angle = Tensor::<TestBackend, 1>::from_floats([0.0, FRAC_PI_2, -PI]);
+
+        let tensor = Tensor::<TestBackend, 1, Complex>::from_polar(abs.clone(), angle.clone());
+
+        tensor
+            .clone()
+            .norm()
+            .into_data()
+            .assert_approx_eq(&abs.into_data(), 3);
+        tensor
+            .angle()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, FRAC_PI_2, PI]), 3);
+    }
+
+    #[test]
+    fn should_support_complex_fft_roundtrip() {
+        let signal = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 0.0, -1.0]);
+
+        let spectrum = signal.clone().rfft(0);
+        let (real, imag) = spectrum.clone().into_parts();
+        assert_eq!(real.into_data(), Data::from([2.0, 1.0, 0.0]));
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, -3.0, 0.0]), 3);
+
+        spectrum
+            .irfft(0, 4)
+            .into_data()
+            .assert_approx_eq(&signal.clone().into_data(), 3);
+
+        let complex = Tensor::<TestBackend, 1, Complex>::from_real(signal.clone());
+        let (real, imag) = complex.fft(0).ifft(0).into_parts();
+        real.into_data().assert_approx_eq(&signal.into_data(), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 0.0, 0.0]), 3);
+    }
+}
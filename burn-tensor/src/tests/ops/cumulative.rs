@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_cumsum_ops() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.clone().cumsum(0).into_data();
+        let data_expected = Data::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+
+        let data_actual = tensor.cumsum(1).into_data();
+        let data_expected = Data::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_cumsum_ops_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]]);
+
+        let data_actual = tensor.cumsum(1).into_data();
+
+        let data_expected = Data::from([[0, 1, 3], [3, 7, 12]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_cumprod_ops() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.clone().cumprod(0).into_data();
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [4.0, 10.0, 18.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+
+        let data_actual = tensor.cumprod(1).into_data();
+        let data_expected = Data::from([[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_cumprod_ops_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1, 2, 3], [4, 5, 6]]);
+
+        let data_actual = tensor.cumprod(0).into_data();
+
+        let data_expected = Data::from([[1, 2, 3], [4, 10, 18]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_dim_exceeds_rank() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _ = tensor.cumsum(2);
+    }
+}
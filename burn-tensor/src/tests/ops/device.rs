@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(device)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Int, Tensor};
+
+    #[test]
+    fn should_list_at_least_one_device() {
+        assert!(!TestBackend::devices().is_empty());
+    }
+
+    #[test]
+    fn should_move_tensors_to_every_device() {
+        let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        for device in TestBackend::devices() {
+            let tensor = TestTensor::from_data(data.clone()).to_device(&device);
+            let tensor_int = Tensor::<TestBackend, 1, Int>::arange_device(0..3, &device);
+
+            assert_eq!(tensor.device(), device);
+            assert_eq!(tensor.into_data(), data);
+
+            let tensor_int = tensor_int.to_device(&Default::default());
+            assert_eq!(tensor_int.into_data(), Data::from([0, 1, 2]));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#[burn_tensor_testgen::testgen(diag)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    fn batched() -> TestTensor<3> {
+        TestTensor::from_floats([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 9.0], [10.0, 11.0, 12.0]],
+        ])
+    }
+
+    #[test]
+    fn should_support_diagonal_ops() {
+        let output = batched().diagonal_dim::<2>(0, 1, 2);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 5.0], [7.0, 11.0]]));
+    }
+
+    #[test]
+    fn should_support_diagonal_offsets() {
+        let upper = batched().diagonal_dim::<2>(1, 1, 2);
+        let lower = batched().diagonal_dim::<2>(-1, 1, 2);
+
+        assert_eq!(upper.into_data(), Data::from([[2.0, 6.0], [8.0, 12.0]]));
+        assert_eq!(lower.into_data(), Data::from([[4.0], [10.0]]));
+    }
+
+    #[test]
+    fn should_support_diagonal_outer_dims() {
+        let output = batched().diagonal_dim::<2>(0, 0, 2);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 8.0], [4.0, 11.0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_diagonal_is_empty() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = tensor.diagonal_dim::<1>(3, 0, 1);
+    }
+
+    #[test]
+    fn should_support_diag_embed_ops() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.diag_embed::<3>(0, 1, 2);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[1.0, 0.0], [0.0, 2.0]], [[3.0, 0.0], [0.0, 4.0]]])
+        );
+    }
+
+    #[test]
+    fn should_support_diag_embed_offset_and_dims() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let upper = tensor.clone().diag_embed::<3>(1, 1, 2);
+        let outer = tensor.diag_embed::<3>(0, 0, 2);
+
+        assert_eq!(
+            upper.into_data(),
+            Data::from([
+                [[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [0.0, 0.0, 0.0]],
+                [[0.0, 3.0, 0.0], [0.0, 0.0, 4.0], [0.0, 0.0, 0.0]]
+            ])
+        );
+        assert_eq!(
+            outer.into_data(),
+            Data::from([[[1.0, 0.0], [3.0, 0.0]], [[0.0, 2.0], [0.0, 4.0]]])
+        );
+    }
+
+    #[test]
+    fn should_support_trace_ops() {
+        let output = batched().trace::<2>();
+
+        assert_eq!(output.into_data(), Data::from([[6.0], [18.0]]));
+    }
+
+    #[test]
+    fn should_support_diag_int() {
+        let vector = Tensor::<TestBackend, 1, Int>::from_ints([1, 2]);
+
+        let matrix = vector.diag(-1);
+        let diagonal = matrix.clone().diag(-1);
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[0, 0, 0], [1, 0, 0], [0, 2, 0]])
+        );
+        assert_eq!(diagonal.into_data(), Data::from([1, 2]));
+    }
+}
@@ -0,0 +1,153 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, einsum2, Data, Int, Tensor};
+
+    #[test]
+    fn should_support_matmul_equation() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output: TestTensor<2> = einsum("ij,jk->ik", vec![lhs.clone(), rhs.clone()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_transpose_equation() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output: TestTensor<2> = einsum("ij->ji", vec![tensor]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_sum_equations() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let rows: TestTensor<1> = einsum("ij->i", vec![tensor.clone()]);
+        let total: TestTensor<1> = einsum("ij->", vec![tensor]);
+
+        rows.into_data()
+            .assert_approx_eq(&Data::from([6.0, 15.0]), 3);
+        total.into_data().assert_approx_eq(&Data::from([21.0]), 3);
+    }
+
+    #[test]
+    fn should_support_implicit_output() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_floats([[5.0, 6.0], [7.0, 8.0]]);
+
+        // Implicit output is "ik", the shared label `j` is contracted.
+        let output: TestTensor<2> = einsum("ij,jk", vec![lhs.clone(), rhs.clone()]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&lhs.matmul(rhs).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_outer_product_and_dot() {
+        let lhs = TestTensor::from_floats([1.0, 2.0]);
+        let rhs = TestTensor::from_floats([3.0, 4.0]);
+
+        let outer: TestTensor<2> = einsum("i,j->ij", vec![lhs.clone(), rhs.clone()]);
+        let dot: TestTensor<1> = einsum("i,i->", vec![lhs, rhs]);
+
+        outer
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0], [6.0, 8.0]]), 3);
+        dot.into_data().assert_approx_eq(&Data::from([11.0]), 3);
+    }
+
+    #[test]
+    fn should_support_attention_equation() {
+        let query = Tensor::<TestBackend, 1, Int>::arange(0..24)
+            .float()
+            .reshape([1, 3, 2, 4]);
+        let key = Tensor::<TestBackend, 1, Int>::arange(0..16)
+            .float()
+            .reshape([1, 2, 2, 4]);
+
+        let output: TestTensor<4> = einsum("bqhd,bkhd->bhqk", vec![query.clone(), key.clone()]);
+
+        let expected = query
+            .swap_dims(1, 2)
+            .matmul(key.swap_dims(1, 2).transpose());
+
+        assert_eq!(output.dims(), [1, 2, 3, 2]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_sum_labels_present_in_a_single_operand() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_floats([[1.0, 1.0], [2.0, 2.0]]);
+
+        // `j` only exists in the lhs and isn't part of the output.
+        let output: TestTensor<2> = einsum("ij,kl->ik", vec![lhs, rhs]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.0, 12.0], [14.0, 28.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_operands_of_different_ranks() {
+        let matrix = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector = TestTensor::from_floats([1.0, 0.0, 2.0]);
+
+        let output: TestTensor<1> = einsum2("ij,j->i", matrix, vector);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([7.0, 16.0]), 3);
+    }
+
+    #[test]
+    fn should_support_batched_matmul_with_a_shared_matrix() {
+        let lhs = Tensor::<TestBackend, 1, Int>::arange(0..12)
+            .float()
+            .reshape([2, 2, 3]);
+        let rhs = TestTensor::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output: TestTensor<3> = einsum2("bij,jk->bik", lhs.clone(), rhs.clone());
+
+        let expected = lhs.matmul(rhs.unsqueeze::<3>().repeat(0, 2));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shared_label_sizes_mismatch() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let _: TestTensor<2> = einsum("ij,jk->ik", vec![lhs, rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_mismatch() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _: TestTensor<2> = einsum("ij->i", vec![tensor]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_more_than_two_operands() {
+        let tensor = TestTensor::from_floats([1.0, 2.0]);
+
+        let _: TestTensor<1> = einsum("i,i,i->", vec![tensor.clone(), tensor.clone(), tensor]);
+    }
+}
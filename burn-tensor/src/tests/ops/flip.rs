@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_flip_single_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.flip(&[1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_flip_multiple_dims_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..8).reshape([2, 2, 2]);
+
+        let output = tensor.flip(&[0, 2]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[5, 4], [7, 6]], [[1, 0], [3, 2]]])
+        );
+    }
+
+    #[test]
+    fn should_rot90() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.rot90(1, [0, 1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 6.0], [2.0, 5.0], [1.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_rot90_multiple_times() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.clone().rot90(2, [0, 1]);
+        assert_eq!(output.into_data(), Data::from([[4.0, 3.0], [2.0, 1.0]]));
+
+        let output = tensor.clone().rot90(-1, [0, 1]);
+        assert_eq!(output.into_data(), Data::from([[3.0, 1.0], [4.0, 2.0]]));
+
+        let output = tensor.clone().rot90(4, [0, 1]);
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_flip_dims_are_duplicated() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = tensor.flip(&[1, 1]);
+    }
+}
@@ -0,0 +1,22 @@
+#[burn_tensor_testgen::testgen(histc)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_histc() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.5, 1.5, 1.0], [4.0, 2.0, -0.1]]);
+
+        let histogram = tensor.histc(4, 0.0, 2.0);
+
+        assert_eq!(histogram.into_data(), Data::from([0.0, 1.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_bounds_are_invalid() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.5, 1.5]);
+
+        let _histogram = tensor.histc(4, 2.0, 2.0);
+    }
+}
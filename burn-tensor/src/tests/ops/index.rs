@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(index)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_index_2d() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let rows = TestTensorInt::from_data([1, 0, 1]);
+        let columns = TestTensorInt::from_data([2, 0, 0]);
+
+        let output = tensor.index([rows, columns]);
+
+        assert_eq!(output.into_data(), Data::from([5.0, 0.0, 3.0]));
+    }
+
+    #[test]
+    fn should_index_3d_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24).reshape([2, 3, 4]);
+        let dim0 = TestTensorInt::from_data([1, 0]);
+        let dim1 = TestTensorInt::from_data([2, 1]);
+        let dim2 = TestTensorInt::from_data([3, 0]);
+
+        let output = tensor.index([dim0, dim1, dim2]);
+
+        assert_eq!(output.into_data(), Data::from([23, 4]));
+    }
+
+    #[test]
+    fn should_index_assign_2d() {
+        let tensor = TestTensor::zeros([2, 3]);
+        let rows = TestTensorInt::from_data([1, 0, 1]);
+        let columns = TestTensorInt::from_data([2, 0, 2]);
+        let values = TestTensor::from_data([1.0, 2.0, 3.0]);
+
+        let output = tensor.index_assign([rows, columns], values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 0.0, 0.0], [0.0, 0.0, 4.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_indices_have_different_lengths() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let rows = TestTensorInt::from_data([1, 0]);
+        let columns = TestTensorInt::from_data([2, 0, 0]);
+
+        let _output = tensor.index([rows, columns]);
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(labeled)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, LabeledTensor, Tensor};
+
+    #[test]
+    fn should_propagate_names_through_matmul() {
+        let lhs = TestTensor::from_data([[[1.0, 2.0], [3.0, 4.0]]])
+            .with_names(["batch", "seq", "hidden"]);
+        let rhs = TestTensor::from_data([[[1.0], [1.0]]]).with_names(["batch", "hidden", "output"]);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(output.names(), ["batch", "seq", "output"]);
+        assert_eq!(output.size("seq"), 2);
+        assert_eq!(
+            output.into_tensor().into_data(),
+            Data::from([[[3.0], [7.0]]])
+        );
+    }
+
+    #[test]
+    fn should_cat_and_reshape_by_name() {
+        let tensor = TestTensor::<2>::ones([2, 6]).with_names(["batch", "hidden"]);
+
+        let output = LabeledTensor::cat(vec![tensor.clone(), tensor], "batch")
+            .reshape([4, 2, 3], ["batch", "heads", "head_dim"])
+            .swap_dims("batch", "heads");
+
+        assert_eq!(output.names(), ["heads", "batch", "head_dim"]);
+        assert_eq!(output.shape().dims, [2, 4, 3]);
+        assert_eq!(output.dim("head_dim"), 2);
+    }
+
+    #[test]
+    fn should_add_tensors_with_the_same_names() {
+        let lhs = TestTensor::from_data([[1.0, 2.0]]).with_names(["batch", "hidden"]);
+        let rhs = TestTensor::from_data([[3.0, 4.0], [5.0, 6.0]]).with_names(["batch", "hidden"]);
+
+        let output = lhs.add(rhs);
+
+        assert_eq!(
+            output.into_tensor().into_data(),
+            Data::from([[4.0, 6.0], [6.0, 8.0]])
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn should_panic_when_broadcasting_different_names() {
+        let lhs = TestTensor::<2>::ones([4, 1]).with_names(["batch", "hidden"]);
+        let rhs = TestTensor::<2>::ones([1, 4]).with_names(["hidden", "batch"]);
+
+        let _output = lhs.add(rhs);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn should_panic_when_matmul_contracts_different_names() {
+        let lhs = TestTensor::<2>::ones([3, 3]).with_names(["seq", "hidden"]);
+        let rhs = TestTensor::<2>::ones([3, 3]).with_names(["seq", "output"]);
+
+        let _output = lhs.matmul(rhs);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn should_panic_when_reshape_changes_size_of_named_dim() {
+        let tensor = TestTensor::<2>::ones([2, 6]).with_names(["batch", "hidden"]);
+
+        let _output = tensor.reshape([4, 3], ["batch", "hidden"]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn should_panic_when_names_are_duplicated() {
+        let _tensor = TestTensor::<2>::ones([2, 2]).with_names(["seq", "seq"]);
+    }
+}
@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(logical)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    fn mask() -> Tensor<TestBackend, 2, Bool> {
+        Tensor::from_bool(Data::from([
+            [true, false, true],
+            [false, false, false],
+            [true, true, true],
+        ]))
+    }
+
+    #[test]
+    fn should_support_any_dim() {
+        assert_eq!(
+            mask().any(1).into_data(),
+            Data::from([[true], [false], [true]])
+        );
+        assert_eq!(mask().any(0).into_data(), Data::from([[true, true, true]]));
+    }
+
+    #[test]
+    fn should_support_all_dim() {
+        assert_eq!(
+            mask().all(1).into_data(),
+            Data::from([[false], [false], [true]])
+        );
+        assert_eq!(
+            mask().all(0).into_data(),
+            Data::from([[false, false, false]])
+        );
+    }
+
+    #[test]
+    fn should_support_count_nonzero_dim() {
+        assert_eq!(
+            mask().count_nonzero(1).into_data(),
+            Data::from([[2], [0], [3]])
+        );
+        assert_eq!(mask().count_nonzero(0).into_data(), Data::from([[2, 1, 2]]));
+    }
+
+    #[test]
+    fn should_support_logical_ops() {
+        let lhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, true, false, false]));
+        let rhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false, true, false]));
+
+        assert_eq!(
+            lhs.clone().logical_and(rhs.clone()).into_data(),
+            Data::from([true, false, false, false])
+        );
+        assert_eq!(
+            lhs.clone().logical_or(rhs.clone()).into_data(),
+            Data::from([true, true, true, false])
+        );
+        assert_eq!(
+            lhs.clone().logical_xor(rhs).into_data(),
+            Data::from([false, true, true, false])
+        );
+        assert_eq!(
+            lhs.logical_not().into_data(),
+            Data::from([false, false, true, true])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_logical_shapes_differ() {
+        let lhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false]));
+        let rhs = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false, true]));
+
+        let _ = lhs.logical_and(rhs);
+    }
+}
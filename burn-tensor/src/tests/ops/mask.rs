@@ -52,4 +52,64 @@ mod tests {
         let data_expected = Data::from([[9, 7], [2, 9]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_masked_select_ops() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [true, true]]));
+
+        let data_actual = tensor.masked_select(mask).into_data();
+
+        let data_expected = Data::from([1.0, 2.0, 3.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_select_empty_mask() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, false], [false, false]]));
+
+        let output = tensor.masked_select(mask);
+
+        assert_eq!(output.dims(), [0]);
+    }
+
+    #[test]
+    fn should_support_masked_scatter_ops() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+        let source = Tensor::<TestBackend, 1>::from_data(Data::from([8.0, 9.0, 10.0]));
+
+        let data_actual = tensor.masked_scatter(mask, source).into_data();
+
+        let data_expected = Data::from([[8.0, 7.0], [2.0, 9.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_scatter_int() {
+        let tensor = TestTensorInt::from_data([[1, 7], [2, 3]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, true], [true, false]]));
+        let source = TestTensorInt::from_data([[4, 5], [6, 7]]);
+
+        let data_actual = tensor.masked_scatter(mask, source).into_data();
+
+        let data_expected = Data::from([[1, 4], [5, 3]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_masked_scatter_source_is_too_small() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [true, true]]));
+        let source = Tensor::<TestBackend, 1>::from_data(Data::from([8.0, 9.0]));
+
+        let _output = tensor.masked_scatter(mask, source);
+    }
 }
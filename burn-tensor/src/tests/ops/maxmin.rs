@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(maxmin)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn test_max_dim_2d() {
@@ -48,4 +48,23 @@ mod tests {
         assert_eq!(output_expected, output_actual.into_data());
         assert_eq!(index_expected, index_actual.into_data());
     }
+
+    #[test]
+    fn test_max_dim_first_dim() {
+        let tensor = TestTensor::from_floats([[0.0, 4.0, 2.0], [3.0, 1.0, 5.0]]);
+
+        let (output_actual, index_actual) = tensor.max_dim_with_indices(0);
+
+        assert_eq!(Data::from([[3., 4., 5.]]), output_actual.into_data());
+        assert_eq!(Data::from([[1, 0, 1]]), index_actual.into_data());
+    }
+
+    #[test]
+    fn test_min_dim_first_dim_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[0, 4, 2], [3, 1, 5]]);
+
+        let output_actual = tensor.min_dim(0);
+
+        assert_eq!(Data::from([[0, 1, 2]]), output_actual.into_data());
+    }
 }
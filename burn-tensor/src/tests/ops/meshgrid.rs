@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(meshgrid)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::GridIndexing;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_meshgrid_matrix_indexing() {
+        let x = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3]);
+        let y = Tensor::<TestBackend, 1, Int>::from_ints([4, 5]);
+
+        let [grid_x, grid_y] = Tensor::meshgrid([x, y], GridIndexing::Matrix);
+
+        assert_eq!(grid_x.into_data(), Data::from([[1, 1], [2, 2], [3, 3]]));
+        assert_eq!(grid_y.into_data(), Data::from([[4, 5], [4, 5], [4, 5]]));
+    }
+
+    #[test]
+    fn should_support_meshgrid_cartesian_indexing() {
+        let x = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0]);
+        let y = Tensor::<TestBackend, 1>::from_floats([4.0, 5.0]);
+
+        let [grid_x, grid_y] = Tensor::meshgrid([x, y], GridIndexing::Cartesian);
+
+        assert_eq!(
+            grid_x.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]])
+        );
+        assert_eq!(
+            grid_y.into_data(),
+            Data::from([[4.0, 4.0, 4.0], [5.0, 5.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_meshgrid_3d() {
+        let x = Tensor::<TestBackend, 1, Int>::from_ints([0, 1]);
+        let y = Tensor::<TestBackend, 1, Int>::from_ints([2]);
+        let z = Tensor::<TestBackend, 1, Int>::from_ints([3, 4, 5]);
+
+        let [_, _, grid_z] = Tensor::meshgrid([x, y, z], GridIndexing::Matrix);
+
+        assert_eq!(grid_z.into_data(), Data::from([[[3, 4, 5]], [[3, 4, 5]]]));
+    }
+}
@@ -4,38 +4,63 @@ mod aggregation;
 mod arange;
 mod arange_step;
 mod arg;
+mod bincount;
 mod cast;
 mod cat;
+mod checked;
 mod clamp;
+mod complex;
 mod cos;
 mod create_like;
+mod cumulative;
+mod device;
+mod diag;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod flatten;
+mod flip;
 mod full;
 mod gather_scatter;
+mod histc;
+mod index;
 mod init;
 mod iter_dim;
+mod labeled;
 mod log;
 mod log1p;
+mod logical;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
+mod meshgrid;
 mod mul;
 mod neg;
+mod nonzero;
+mod norm;
 mod one_hot;
+mod pad;
+mod permute;
 mod powf;
+mod product;
 mod random;
+mod readback;
 mod recip;
 mod repeat;
+mod repeat_interleave;
 mod reshape;
+mod roll;
 mod select;
 mod sin;
 mod slice;
+mod split;
 mod sqrt;
 mod squeeze;
 mod sub;
 mod tanh;
 mod transpose;
+mod triangular;
+mod unfold;
+mod unique;
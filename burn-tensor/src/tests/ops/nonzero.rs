@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(nonzero)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_find_nonzero_float() {
+        let tensor = TestTensor::from_data([[0.0, 1.5, 0.0], [-2.0, 0.0, 3.0]]);
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices.len(), 2);
+        assert_eq!(indices[0].to_data(), Data::from([0, 1, 1]));
+        assert_eq!(indices[1].to_data(), Data::from([1, 0, 2]));
+    }
+
+    #[test]
+    fn should_find_nonzero_int() {
+        let tensor = TestTensorInt::from_data([0, 4, 0, 7]);
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].to_data(), Data::from([1, 3]));
+    }
+
+    #[test]
+    fn should_find_nonzero_bool() {
+        let tensor = Tensor::<TestBackend, 3, Bool>::from_bool(Data::from([
+            [[true, false], [false, false]],
+            [[false, false], [true, true]],
+        ]));
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices[0].to_data(), Data::from([0, 1, 1]));
+        assert_eq!(indices[1].to_data(), Data::from([0, 1, 1]));
+        assert_eq!(indices[2].to_data(), Data::from([0, 0, 1]));
+    }
+
+    #[test]
+    fn should_support_argwhere() {
+        let tensor = TestTensor::from_data([[0.0, 1.5, 0.0], [-2.0, 0.0, 3.0]]);
+
+        let coordinates = tensor.argwhere();
+
+        assert_eq!(
+            coordinates.into_data(),
+            Data::from([[0, 1], [1, 0], [1, 2]])
+        );
+    }
+
+    #[test]
+    fn should_index_nonzero_elements() {
+        let tensor = TestTensor::from_data([[0.0, 1.5, 0.0], [-2.0, 0.0, 3.0]]);
+
+        let indices = tensor.clone().nonzero();
+        let output = tensor.index(indices.try_into().unwrap());
+
+        assert_eq!(output.into_data(), Data::from([1.5, -2.0, 3.0]));
+    }
+}
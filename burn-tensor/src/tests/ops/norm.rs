@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(norm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, MatrixNorm, Norm};
+    use core::f32::consts::SQRT_2;
+
+    fn vectors() -> TestTensor<2> {
+        TestTensor::from_floats([[3.0, -4.0], [1.0, 0.0]])
+    }
+
+    #[test]
+    fn should_support_vector_norms() {
+        let l1 = vectors().norm(Norm::L1, 1);
+        let l2 = vectors().norm(Norm::L2, 1);
+        let inf = vectors().norm(Norm::Inf, 1);
+        let neg_inf = vectors().norm(Norm::NegInf, 1);
+
+        l1.into_data()
+            .assert_approx_eq(&Data::from([[7.0], [1.0]]), 3);
+        l2.into_data()
+            .assert_approx_eq(&Data::from([[5.0], [1.0]]), 3);
+        inf.into_data()
+            .assert_approx_eq(&Data::from([[4.0], [1.0]]), 3);
+        neg_inf
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0], [0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_p_norm() {
+        let output = vectors().norm(Norm::Lp(3.0), 0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0366, 4.0]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_p_is_zero() {
+        let _output = vectors().norm(Norm::Lp(0.0), 0);
+    }
+
+    #[test]
+    fn should_support_normalize() {
+        let tensor = TestTensor::from_floats([[3.0, 4.0], [0.0, 0.0]]);
+
+        let output = tensor.normalize(Norm::L2, 1, 1e-12);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.6, 0.8], [0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_matrix_norms() {
+        let tensor =
+            || TestTensor::from_floats([[[1.0, -2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+
+        let frobenius = tensor().matrix_norm(MatrixNorm::Frobenius);
+        let l1 = tensor().matrix_norm(MatrixNorm::L1);
+        let inf = tensor().matrix_norm(MatrixNorm::Inf);
+
+        frobenius
+            .into_data()
+            .assert_approx_eq(&Data::from([[[5.4772]], [[SQRT_2]]]), 3);
+        l1.into_data()
+            .assert_approx_eq(&Data::from([[[6.0]], [[1.0]]]), 3);
+        inf.into_data()
+            .assert_approx_eq(&Data::from([[[7.0]], [[1.0]]]), 3);
+    }
+}
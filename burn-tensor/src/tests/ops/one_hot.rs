@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(one_hot)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Int};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn should_support_one_hot() {
@@ -29,4 +29,28 @@ mod tests {
     fn should_panic_when_number_of_classes_is_zero() {
         let tensor = TestTensor::<1>::one_hot(0, 0);
     }
+
+    #[test]
+    fn should_support_one_hot_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[2, 0], [1, 1]]);
+
+        let output: Tensor<TestBackend, 3, Int> = tensor.one_hot(3);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[0, 0, 1], [1, 0, 0]], [[0, 1, 0], [0, 1, 0]]])
+        );
+    }
+
+    #[test]
+    fn should_encode_out_of_range_indices_as_zeros() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([3, -1, 1]);
+
+        let output: Tensor<TestBackend, 2> = tensor.one_hot(3).float();
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+        );
+    }
 }
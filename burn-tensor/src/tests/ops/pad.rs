@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ops::PadMode, Data, Tensor};
+
+    #[test]
+    fn should_pad_constant() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.pad([(1, 0), (0, 2)], PadMode::Constant(-1.0));
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [-1.0, -1.0, -1.0, -1.0],
+                [1.0, 2.0, -1.0, -1.0],
+                [3.0, 4.0, -1.0, -1.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_reflect() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.pad([(1, 1), (2, 1)], PadMode::Reflect);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+                [6.0, 5.0, 4.0, 5.0, 6.0, 5.0],
+                [3.0, 2.0, 1.0, 2.0, 3.0, 2.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_replicate() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0]]);
+
+        let output = tensor.pad([(0, 1), (2, 2)], PadMode::Replicate);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0],
+                [1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_pad_circular() {
+        let tensor = TestTensor::from_data([1.0, 2.0, 3.0]);
+
+        let output = tensor.pad([(2, 3)], PadMode::Circular);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn should_pad_int() {
+        let tensor = TestTensorInt::from_data([[1, 2], [3, 4]]);
+
+        let output = tensor.pad([(0, 1), (1, 0)], PadMode::Constant(7.0));
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[7, 1, 2], [7, 3, 4], [7, 7, 7]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_padding_is_too_large() {
+        let tensor = TestTensor::from_data([1.0, 2.0, 3.0]);
+
+        let _output = tensor.pad([(3, 0)], PadMode::Reflect);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(permute)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_permute_ops() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let data_actual = tensor.permute([2, 0, 1]).into_data();
+
+        let data_expected = Data::from([
+            [[0.0, 3.0], [6.0, 9.0]],
+            [[1.0, 4.0], [7.0, 10.0]],
+            [[2.0, 5.0], [8.0, 11.0]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_permute_ops_int() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data([
+            [[0, 1, 2], [3, 4, 5]],
+            [[6, 7, 8], [9, 10, 11]],
+        ]);
+
+        let data_actual = tensor.permute([1, 2, 0]).into_data();
+
+        let data_expected = Data::from([[[0, 6], [1, 7], [2, 8]], [[3, 9], [4, 10], [5, 11]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_identity_permutation() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let data_actual = tensor.clone().permute([0, 1]).into_data();
+
+        assert_eq!(tensor.into_data(), data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_duplicated_axes() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _ = tensor.permute([0, 0]);
+    }
+}
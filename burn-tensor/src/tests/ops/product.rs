@@ -0,0 +1,100 @@
+#[burn_tensor_testgen::testgen(product)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_outer() {
+        let lhs = TestTensor::from_floats([1.0, 2.0]);
+        let rhs = TestTensor::from_floats([3.0, 4.0, 5.0]);
+
+        let output = lhs.outer(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_kron() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from_floats([[0.0, 1.0], [1.0, 0.0]]);
+
+        let output = lhs.kron(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [0.0, 1.0, 0.0, 2.0],
+                [1.0, 0.0, 2.0, 0.0],
+                [0.0, 3.0, 0.0, 4.0],
+                [3.0, 0.0, 4.0, 0.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_kron_different_shapes() {
+        let lhs = TestTensor::from_floats([[1.0], [2.0]]);
+        let rhs = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let output = lhs.kron(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cross() {
+        let lhs = TestTensor::from_floats([[1.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+        let rhs = TestTensor::from_floats([[0.0, 1.0, 0.0], [4.0, 5.0, 6.0]]);
+
+        let output = lhs.clone().cross(rhs.clone(), 1);
+        let output_transposed = lhs.transpose().cross(rhs.transpose(), 0);
+
+        let expected = Data::from([[0.0, 0.0, 1.0], [-3.0, 6.0, -3.0]]);
+        assert_eq!(output.into_data(), expected);
+        assert_eq!(output_transposed.transpose().into_data(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_cross_dim_is_not_3() {
+        let lhs = TestTensor::from_floats([[1.0, 0.0], [1.0, 2.0]]);
+        let rhs = TestTensor::from_floats([[0.0, 1.0], [4.0, 5.0]]);
+
+        let _output = lhs.cross(rhs, 1);
+    }
+
+    #[test]
+    fn should_support_tensordot() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let output: Tensor<TestBackend, 2> = lhs.clone().tensordot(rhs, &[1], &[0]);
+        let full: Tensor<TestBackend, 1> = lhs.clone().tensordot(lhs, &[0, 1], &[0, 1]);
+
+        assert_eq!(output.into_data(), Data::from([[22.0, 28.0], [49.0, 64.0]]));
+        assert_eq!(full.into_data(), Data::from([91.0]));
+    }
+
+    #[test]
+    fn should_support_tensordot_permuted_dims() {
+        let lhs = Tensor::<TestBackend, 3>::ones([2, 3, 4]);
+        let rhs = TestTensor::from_floats([
+            [1.0, 2.0, 3.0],
+            [1.0, 2.0, 3.0],
+            [1.0, 2.0, 3.0],
+            [1.0, 2.0, 3.0],
+        ]);
+
+        let output: Tensor<TestBackend, 1> = lhs.clone().tensordot(rhs.clone(), &[1, 2], &[1, 0]);
+        let batched: Tensor<TestBackend, 3> = lhs.tensordot(rhs, &[2], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([24.0, 24.0]));
+        assert_eq!(batched.dims(), [2, 3, 3]);
+    }
+}
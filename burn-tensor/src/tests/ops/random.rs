@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Data, Distribution, ElementConversion, Int, Tensor};
 
     #[test]
     fn rand_default() {
@@ -24,4 +24,100 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1.; 20].into());
     }
+
+    #[test]
+    fn rand_normal_per_element() {
+        let mean = TestTensor::from_floats([[0.0, 10.0]]);
+        let std = TestTensor::from_floats([[0.0], [0.0]]);
+
+        let tensor = Tensor::random_normal(mean, std);
+
+        assert_eq!(tensor.into_data(), Data::from([[0.0, 10.0], [0.0, 10.0]]));
+    }
+
+    #[test]
+    fn rand_normal_statistics() {
+        let mean = TestTensor::<1>::full([10000], 5.0);
+        let std = TestTensor::<1>::full([10000], 2.0);
+
+        let tensor = Tensor::random_normal(mean, std);
+
+        let sample_mean = tensor.clone().mean().into_scalar().elem::<f32>();
+        let sample_var = tensor.var(0).into_scalar().elem::<f32>();
+        assert!((sample_mean - 5.0).abs() < 0.2, "mean {sample_mean}");
+        assert!((sample_var - 4.0).abs() < 0.4, "variance {sample_var}");
+    }
+
+    #[test]
+    fn rand_uniform_per_element() {
+        let low = TestTensor::from_floats([[0.0, 4.0], [-2.0, 1.0]]);
+        let high = low.clone().add_scalar(1.0);
+
+        let tensor = Tensor::random_uniform(low.clone(), high);
+        let offset = tensor - low;
+
+        offset.into_data().assert_within_range(0.0..1.0);
+    }
+
+    #[test]
+    fn rand_bernoulli_per_element() {
+        let probabilities = TestTensor::from_floats([0.0, 1.0, 0.0, 1.0]);
+
+        let tensor = probabilities.bernoulli();
+
+        assert_eq!(tensor.into_data(), Data::from([0.0, 1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn multinomial_with_replacement() {
+        let weights = TestTensor::from_floats([[0.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+
+        let indices = weights.multinomial(3, true);
+
+        assert_eq!(indices.into_data(), Data::from([[1, 1, 1], [2, 2, 2]]));
+    }
+
+    #[test]
+    fn multinomial_frequencies() {
+        let weights = TestTensor::from_floats([[1.0, 3.0]]);
+
+        let indices = weights.multinomial(4000, true);
+
+        let ratio = indices.float().mean().into_scalar().elem::<f32>();
+        assert!((ratio - 0.75).abs() < 0.05, "ratio {ratio}");
+    }
+
+    #[test]
+    fn multinomial_without_replacement() {
+        let weights = TestTensor::from_floats([[0.0, 1.0, 0.0, 2.0], [3.0, 1.0, 0.0, 1.0]]);
+
+        let indices = weights
+            .multinomial(2, false)
+            .into_data()
+            .convert::<i64>()
+            .value;
+
+        let mut first = indices[..2].to_vec();
+        first.sort();
+        assert_eq!(first, vec![1_i64, 3]);
+        assert_ne!(indices[2], indices[3]);
+        assert!(!indices[2..].contains(&2_i64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn multinomial_should_panic_without_enough_categories() {
+        let weights = TestTensor::from_floats([[1.0, 2.0]]);
+
+        let _indices = weights.multinomial(3, false);
+    }
+
+    #[test]
+    fn randperm() {
+        let tensor = Tensor::<TestBackend, 1, Int>::randperm(11, &Default::default());
+
+        let mut values = tensor.into_data().convert::<i64>().value;
+        values.sort();
+        assert_eq!(values, (0..11).collect::<Vec<i64>>());
+    }
 }
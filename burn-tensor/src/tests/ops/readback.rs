@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(readback)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+    use core::future::Future;
+    use core::task::{Context, Poll, Waker};
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = core::pin::pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn should_read_data_async() {
+        let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+        let tensor = TestTensor::from_data(data.clone());
+
+        // The readback is started before the tensor is modified.
+        let future = tensor.to_data_async();
+        let tensor = tensor.add_scalar(1.0);
+
+        assert_eq!(block_on(future), data);
+        assert_eq!(
+            block_on(tensor.into_data_async()),
+            Data::from([[2.0, 3.0], [4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_read_int_and_bool_data_async() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..4);
+        let mask = tensor.clone().greater_elem(1);
+
+        assert_eq!(block_on(tensor.into_data_async()), Data::from([0, 1, 2, 3]));
+        assert_eq!(
+            block_on(mask.into_data_async()),
+            Data::from([false, false, true, true])
+        );
+    }
+
+    #[test]
+    fn should_read_scalar_async() {
+        let tensor = TestTensor::from_data([1.0, 2.0, 3.0]);
+
+        let sum = block_on(tensor.sum().into_scalar_async());
+
+        assert_eq!(sum, 6.0);
+    }
+}
@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(repeat_interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_repeat_interleave() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let rows = tensor.clone().repeat_interleave(2, 0);
+        let columns = tensor.repeat_interleave(3, 1);
+
+        assert_eq!(
+            rows.into_data(),
+            Data::from([[1.0, 2.0], [1.0, 2.0], [3.0, 4.0], [3.0, 4.0]])
+        );
+        assert_eq!(
+            columns.into_data(),
+            Data::from([
+                [1.0, 1.0, 1.0, 2.0, 2.0, 2.0],
+                [3.0, 3.0, 3.0, 4.0, 4.0, 4.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_repeat_interleave_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3]);
+
+        let repeated = tensor.repeat_interleave(2, 0);
+
+        assert_eq!(repeated.into_data(), Data::from([1, 1, 2, 2, 3, 3]));
+    }
+}
@@ -13,6 +13,15 @@ mod tests {
         assert_eq!(data_expected, data_actual);
     }
 
+    #[test]
+    fn should_support_reshape_transposed() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.transpose().reshape([6]).into_data();
+        let data_expected = Data::from([1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
     #[test]
     fn should_support_reshape_int() {
         let data = Data::from([0, 1, 2]);
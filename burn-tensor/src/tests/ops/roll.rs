@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_roll_single_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.roll(&[1], &[1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_roll_negative_and_multiple_dims() {
+        let tensor = TestTensorInt::from_data([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.roll(&[-1, 4], &[1, 0]);
+
+        assert_eq!(output.into_data(), Data::from([[2, 3, 1], [5, 6, 4]]));
+    }
+
+    #[test]
+    fn should_roll_bool() {
+        let tensor = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false, false]));
+
+        let output = tensor.roll(&[2], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([false, false, true]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_differ() {
+        let tensor = TestTensor::from_data([1.0, 2.0, 3.0]);
+
+        let _output = tensor.roll(&[1, 2], &[0]);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(split)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_narrow() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]);
+
+        let output = tensor.clone().narrow(1, 1, 2);
+        assert_eq!(output.into_data(), Data::from([[1.0, 2.0], [5.0, 6.0]]));
+
+        let output = tensor.narrow(0, 1, 1);
+        assert_eq!(output.into_data(), Data::from([[4.0, 5.0, 6.0, 7.0]]));
+    }
+
+    #[test]
+    fn should_support_chunk() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..5);
+
+        let chunks = tensor.clone().chunk(2, 0);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].to_data(), Data::from([0, 1, 2]));
+        assert_eq!(chunks[1].to_data(), Data::from([3, 4]));
+
+        // Five elements in four chunks gives three chunks of sizes 2, 2 and 1.
+        let chunks = tensor.chunk(4, 0);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].to_data(), Data::from([4]));
+    }
+
+    #[test]
+    fn should_support_split() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let parts = tensor.split(&[1, 2], 1);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].to_data(), Data::from([[0.0], [3.0]]));
+        assert_eq!(parts[1].to_data(), Data::from([[1.0, 2.0], [4.0, 5.0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_split_sizes_dont_match() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _ = tensor.split(&[1, 1], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_narrow_out_of_bound() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _ = tensor.narrow(1, 2, 2);
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(triangular)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    fn matrix() -> TestTensor<2> {
+        TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+    }
+
+    #[test]
+    fn should_support_tril_ops() {
+        let output = matrix().tril(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_tril_diagonals() {
+        let above = matrix().tril(1);
+        let below = matrix().tril(-1);
+
+        assert_eq!(
+            above.into_data(),
+            Data::from([[1.0, 2.0, 0.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+        );
+        assert_eq!(
+            below.into_data(),
+            Data::from([[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [7.0, 8.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_triu_ops() {
+        let output = matrix().triu(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [0.0, 5.0, 6.0], [0.0, 0.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_triu_diagonals() {
+        let above = matrix().triu(1);
+        let below = matrix().triu(-1);
+
+        assert_eq!(
+            above.into_data(),
+            Data::from([[0.0, 2.0, 3.0], [0.0, 0.0, 6.0], [0.0, 0.0, 0.0]])
+        );
+        assert_eq!(
+            below.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [0.0, 8.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_batched_rectangular_int() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_ints([
+            [[1, 2, 3], [4, 5, 6]],
+            [[7, 8, 9], [10, 11, 12]],
+        ]);
+
+        let output = tensor.tril(0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[1, 0, 0], [4, 5, 0]], [[7, 0, 0], [10, 11, 0]]])
+        );
+    }
+}
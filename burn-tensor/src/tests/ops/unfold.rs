@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(unfold)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_unfold_last_dim() {
+        let tensor =
+            TestTensor::from_floats([[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]);
+
+        let data_actual = tensor.unfold::<3>(1, 3, 2).into_data();
+
+        let data_expected = Data::from([
+            [[0.0, 1.0, 2.0], [2.0, 3.0, 4.0]],
+            [[5.0, 6.0, 7.0], [7.0, 8.0, 9.0]],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_unfold_first_dim() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+
+        let data_actual = tensor.unfold::<3>(0, 2, 1).into_data();
+
+        let data_expected = Data::from([[[0.0, 2.0], [1.0, 3.0]], [[2.0, 4.0], [3.0, 5.0]]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_unfold_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..6);
+
+        let data_actual = tensor.unfold::<2>(0, 2, 2).into_data();
+
+        let data_expected = Data::from([[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_window_larger_than_dim() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _ = tensor.unfold::<3>(1, 3, 1);
+    }
+}
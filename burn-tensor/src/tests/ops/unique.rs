@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_unique_ints() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[3, 1, 3], [-2, 1, 3]]);
+
+        let values = tensor.unique();
+
+        assert_eq!(values.into_data(), Data::from([-2, 1, 3]));
+    }
+
+    #[test]
+    fn should_support_unique_counts() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.5, -1.0, 0.5, 2.0, 0.5]);
+
+        let (values, counts) = tensor.unique_counts();
+
+        assert_eq!(values.into_data(), Data::from([-1.0, 0.5, 2.0]));
+        assert_eq!(counts.into_data(), Data::from([1, 3, 1]));
+    }
+
+    #[test]
+    fn should_support_unique_inverse() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[2, 0, 2], [1, 0, 2]]);
+
+        let (values, inverse) = tensor.clone().unique_inverse();
+
+        assert_eq!(values.clone().into_data(), Data::from([0, 1, 2]));
+        assert_eq!(
+            inverse.clone().into_data(),
+            Data::from([[2, 0, 2], [1, 0, 2]])
+        );
+        assert_eq!(
+            values.select(0, inverse.reshape([6])).into_data(),
+            tensor.reshape([6]).into_data()
+        );
+    }
+}
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(seed)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Distribution, Tensor};
+
+    type Device = <TestBackend as Backend>::Device;
+
+    fn stream(device: &Device) -> [Data<f32, 2>; 3] {
+        [
+            Tensor::<TestBackend, 2>::random_device([4, 8], Distribution::Default, device)
+                .into_data()
+                .convert(),
+            Tensor::<TestBackend, 2>::random_device([4, 8], Distribution::Normal(0.0, 1.0), device)
+                .into_data()
+                .convert(),
+            Tensor::<TestBackend, 2>::random_device([4, 8], Distribution::Bernoulli(0.5), device)
+                .into_data()
+                .convert(),
+        ]
+    }
+
+    #[test]
+    fn should_produce_identical_streams_when_seeded() {
+        let device = Device::default();
+
+        TestBackend::seed_all(42);
+        let expected = stream(&device);
+
+        TestBackend::seed_all(42);
+        assert_eq!(stream(&device), expected);
+
+        TestBackend::seed_device(42, &device);
+        assert_eq!(stream(&device), expected);
+
+        TestBackend::seed_all(43);
+        assert_ne!(stream(&device), expected);
+    }
+}
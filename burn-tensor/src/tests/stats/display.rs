@@ -273,6 +273,64 @@ mod tests {
   backend:  {:?},
   kind:  "Float",
   dtype:  "f32",
+}}"#,
+            tensor.device(),
+            TestBackend::name(),
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_display_tensor_summarize_edge_values() {
+        let tensor: burn_tensor::Tensor<TestBackend, 3, burn_tensor::Int> =
+            Tensor::arange(0..2400).reshape([2, 200, 6]);
+
+        let output = format!("{}", tensor);
+        let expected = format!(
+            r#"Tensor {{
+  data:
+[[[0, 1, 2, 3, 4, 5],
+  [6, 7, 8, 9, 10, 11],
+  [12, 13, 14, 15, 16, 17],
+  ...
+  [1182, 1183, 1184, 1185, 1186, 1187],
+  [1188, 1189, 1190, 1191, 1192, 1193],
+  [1194, 1195, 1196, 1197, 1198, 1199]],
+ [[1200, 1201, 1202, 1203, 1204, 1205],
+  [1206, 1207, 1208, 1209, 1210, 1211],
+  [1212, 1213, 1214, 1215, 1216, 1217],
+  ...
+  [2382, 2383, 2384, 2385, 2386, 2387],
+  [2388, 2389, 2390, 2391, 2392, 2393],
+  [2394, 2395, 2396, 2397, 2398, 2399]]],
+  shape:  [2, 200, 6],
+  device:  {:?},
+  backend:  {:?},
+  kind:  "Int",
+  dtype:  "{dtype}",
+}}"#,
+            tensor.device(),
+            TestBackend::name(),
+            dtype = core::any::type_name::<IntElem>(),
+        );
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_display_tensor_with_precision() {
+        let tensor: burn_tensor::Tensor<TestBackend, 1, burn_tensor::Float> =
+            Tensor::from_data([1.0, 0.123456, -2.5]);
+
+        let output = format!("{:.2}", tensor);
+        let expected = format!(
+            r#"Tensor {{
+  data:
+[1.00, 0.12, -2.50],
+  shape:  [3],
+  device:  {:?},
+  backend:  {:?},
+  kind:  "Float",
+  dtype:  "f32",
 }}"#,
             tensor.device(),
             TestBackend::name(),
@@ -0,0 +1,82 @@
+use burn_core as burn;
+use burn_core::record::Record;
+
+/// The state of the training loop saved with each checkpoint, so that an interrupted training
+/// can be resumed at the same position.
+#[derive(Record, Clone, Debug, PartialEq, new)]
+pub struct TrainingStateRecord {
+    /// The last epoch completed.
+    pub epoch: usize,
+
+    /// The number of training iterations completed since the beginning of the training.
+    pub iteration: usize,
+
+    /// The seed of the random number generators, if the training is seeded.
+    pub seed: Option<u64>,
+
+    /// The factor applied to the learning rate by the [callbacks](crate::learner::LearnerCallback).
+    pub lr_factor: f64,
+}
+
+/// Find the latest epoch for which all the given checkpoints are saved in the directory.
+///
+/// The checkpoints are expected to be saved by a [file checkpointer](super::FileCheckpointer),
+/// i.e. named `<name>-<epoch>.<extension>`. An epoch missing one of the checkpoints, for instance
+/// because the training was interrupted while saving it, is ignored.
+pub(crate) fn latest_checkpoint(directory: &str, names: &[&str]) -> Option<usize> {
+    let entries = std::fs::read_dir(directory).ok()?;
+    let files = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+
+    let epochs = |name: &str| {
+        let prefix = format!("{name}-");
+
+        files
+            .iter()
+            .filter_map(|file| file.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split('.').next())
+            .filter_map(|epoch| epoch.parse::<usize>().ok())
+            .collect::<Vec<_>>()
+    };
+
+    let (first, others) = names.split_first()?;
+    let others = others.iter().map(|name| epochs(name)).collect::<Vec<_>>();
+
+    epochs(first)
+        .into_iter()
+        .filter(|epoch| others.iter().all(|epochs| epochs.contains(epoch)))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_checkpoint_ignores_incomplete_epochs() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-checkpoint-state-test-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        for file in [
+            "model-1.mpk",
+            "optim-1.mpk",
+            "model-2.mpk",
+            "optim-2.mpk",
+            "model-3.mpk",
+            "model-10.mpk",
+            "model-notes.txt",
+        ] {
+            std::fs::write(directory.join(file), []).unwrap();
+        }
+
+        let latest = latest_checkpoint(directory.to_str().unwrap(), &["model", "optim"]);
+        let missing = latest_checkpoint(directory.to_str().unwrap(), &["model", "scheduler"]);
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(latest, Some(2));
+        assert_eq!(missing, None);
+    }
+}
@@ -1,10 +1,14 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    AsyncCheckpointer, Checkpointer, CheckpointingAction, CheckpointingStrategy,
+    TrainingStateRecord,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
-use crate::metric::store::EventStoreClient;
+use crate::learner::{EarlyStoppingStrategy, LearnerCallbacks, StochasticWeightAveraging};
+use crate::metric::store::{Aggregate, Direction, EventStoreClient, Split};
+use crate::profiler::Profiler;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{GradScaler, Optimizer};
 use burn_core::tensor::backend::Backend;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -18,13 +22,40 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) lr_scheduler: LC::LrScheduler,
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
+    pub(crate) seed: Option<u64>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) sum_accumulated_grads: bool,
+    pub(crate) grad_scaler: Option<GradScaler>,
+    pub(crate) valid_every: Option<usize>,
+    pub(crate) valid_batches: Option<usize>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) lr_scheduler_metric: Option<LrSchedulerMetric>,
+    pub(crate) swa: Option<StochasticWeightAveraging>,
+    pub(crate) best_model_metric: Option<BestModelMetric>,
+    pub(crate) callbacks: LearnerCallbacks<LC::Model>,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
+    pub(crate) profiler: Option<Profiler<LC::Backend>>,
+}
+
+/// The metric reported to the [learning rate scheduler](LrScheduler) at the end of each epoch.
+#[derive(new)]
+pub(crate) struct LrSchedulerMetric {
+    pub(crate) name: String,
+    pub(crate) aggregate: Aggregate,
+    pub(crate) split: Split,
+}
+
+/// The metric used to select the best model, restored at the end of the training.
+#[derive(new)]
+pub(crate) struct BestModelMetric {
+    pub(crate) name: String,
+    pub(crate) aggregate: Aggregate,
+    pub(crate) direction: Direction,
+    pub(crate) split: Split,
 }
 
 #[derive(new)]
@@ -32,18 +63,25 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    state: AsyncCheckpointer<TrainingStateRecord>,
+    swa: LC::CheckpointerModel,
     strategy: LC::CheckpointerStrategy,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Save the checkpoints of the epoch according to the strategy, along with the model with
+    /// the weights averaged by the [stochastic weight averaging](StochasticWeightAveraging), if
+    /// any.
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
-        epoch: usize,
+        state: TrainingStateRecord,
+        swa_model: Option<LC::Model>,
         store: &EventStoreClient,
     ) {
+        let epoch = state.epoch;
         let actions = self.strategy.checkpointing(epoch, store);
 
         for action in actions {
@@ -58,6 +96,12 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .delete(epoch)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    self.state
+                        .delete(epoch)
+                        .expect("Can delete training state checkpoint.");
+                    self.swa
+                        .delete(epoch)
+                        .expect("Can delete averaged model checkpoint.");
                 }
                 CheckpointingAction::Save => {
                     self.model
@@ -69,11 +113,45 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .save(epoch, scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    self.state
+                        .save(epoch, state.clone())
+                        .expect("Can save training state checkpoint.");
+                    if let Some(swa_model) = swa_model.clone() {
+                        self.save_swa_model(epoch, swa_model);
+                    }
                 }
             }
         }
     }
 
+    /// Save the model with the averaged weights of the
+    /// [stochastic weight averaging](StochasticWeightAveraging).
+    pub(crate) fn save_swa_model(&self, epoch: usize, model: LC::Model) {
+        self.swa
+            .save(epoch, model.into_record())
+            .expect("Can save averaged model checkpoint.");
+    }
+
+    /// Load the model with the averaged weights saved at the given epoch, if any.
+    pub(crate) fn load_swa_model(&self, model: LC::Model, epoch: usize) -> Option<LC::Model> {
+        match self.swa.restore(epoch) {
+            Ok(record) => Some(model.load_record(record)),
+            Err(err) => {
+                log::warn!("Can't load the averaged model of epoch {epoch}: {err:?}");
+                None
+            }
+        }
+    }
+
+    pub(crate) fn load_model(&self, model: LC::Model, epoch: usize) -> LC::Model {
+        let record = self
+            .model
+            .restore(epoch)
+            .expect("Can load model checkpoint.");
+
+        model.load_record(record)
+    }
+
     pub(crate) fn load_checkpoint(
         &self,
         model: LC::Model,
@@ -101,6 +179,17 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    /// Load the state of the training loop, missing from the checkpoints saved by older versions.
+    pub(crate) fn load_training_state(&self, epoch: usize) -> Option<TrainingStateRecord> {
+        match self.state.restore(epoch) {
+            Ok(state) => Some(state),
+            Err(err) => {
+                log::warn!("Can't load the training state of epoch {epoch}: {err:?}");
+                None
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
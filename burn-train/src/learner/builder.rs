@@ -1,23 +1,29 @@
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
 use super::log::install_file_logger;
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    latest_checkpoint, AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy,
+    FileCheckpointer, KeepLastNCheckpoints, MetricCheckpointingStrategy, TrainingStateRecord,
 };
 use crate::components::LearnerComponentsMarker;
-use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::learner::base::{BestModelMetric, LrSchedulerMetric, TrainingInterrupter};
+use crate::learner::{
+    EarlyStoppingStrategy, LearnerCallback, LearnerCallbacks, StochasticWeightAveraging,
+};
+use crate::logger::{
+    FileMetricLogger, MetricLogger, TensorBoardCallback, TensorBoardMetricLogger, TensorBoardWriter,
+};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
-use crate::renderer::{default_renderer, MetricsRenderer};
+use crate::profiler::Profiler;
+use crate::renderer::{default_renderer, MetricsRenderer, ProgressEvent};
 use crate::LearnerCheckpointer;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{GradScaler, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -39,13 +45,23 @@ where
         AsyncCheckpointer<M::Record>,
         AsyncCheckpointer<O::Record>,
         AsyncCheckpointer<S::Record>,
+        AsyncCheckpointer<TrainingStateRecord>,
+        AsyncCheckpointer<M::Record>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
+    resume: bool,
+    seed: Option<u64>,
+    deterministic: bool,
     directory: String,
     grad_accumulation: Option<usize>,
+    sum_accumulated_grads: bool,
+    grad_scaler: Option<GradScaler>,
+    valid_every: Option<usize>,
+    valid_batches: Option<usize>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
+    subscribers: Vec<Sender<ProgressEvent>>,
     metrics: Metrics<T, V>,
     event_store: LogEventStore,
     interrupter: TrainingInterrupter,
@@ -53,6 +69,11 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    lr_scheduler_metric: Option<LrSchedulerMetric>,
+    swa: Option<StochasticWeightAveraging>,
+    best_model_metric: Option<BestModelMetric>,
+    callbacks: Vec<Box<dyn LearnerCallback<M>>>,
+    profile: bool,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -73,13 +94,21 @@ where
         Self {
             num_epochs: 1,
             checkpoint: None,
+            resume: false,
+            seed: None,
+            deterministic: false,
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            sum_accumulated_grads: false,
+            grad_scaler: None,
+            valid_every: None,
+            valid_batches: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
             renderer: None,
+            subscribers: Vec::new(),
             interrupter: TrainingInterrupter::new(),
             log_to_file: true,
             num_loggers: 0,
@@ -94,6 +123,29 @@ where
                     .build(),
             ),
             early_stopping: None,
+            lr_scheduler_metric: None,
+            swa: None,
+            best_model_metric: None,
+            callbacks: Vec::new(),
+            profile: false,
+        }
+    }
+
+    /// Creates a new learner builder resuming the training saved in the directory by a previous,
+    /// possibly interrupted, run.
+    ///
+    /// The model, the optimizer, the learning rate scheduler, the epoch and iteration counters and
+    /// the [seed](Self::seed) are restored from the latest complete checkpoint, so the same
+    /// [file checkpointer](Self::with_file_checkpointer) must be registered. When no checkpoint is
+    /// found, the training starts from the beginning, so the same code can start and resume it.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory where the checkpoints of the previous run were saved.
+    pub fn resume_from(directory: &str) -> Self {
+        Self {
+            resume: true,
+            ..Self::new(directory)
         }
     }
 
@@ -114,6 +166,27 @@ where
         self
     }
 
+    /// Also write the numeric metrics, the learning rate, and the histograms of the gradients and
+    /// of the model parameters in TensorBoard event files, see [TensorBoardCallback].
+    ///
+    /// The event files are written in the `tensorboard/train` and `tensorboard/valid` directories
+    /// of the artifacts, shown as two runs by `tensorboard --logdir <directory>/tensorboard`.
+    pub fn with_tensorboard(mut self) -> Self {
+        let directory = format!("{}/tensorboard", self.directory);
+        let writer_train = TensorBoardWriter::new(&format!("{directory}/train"));
+        let writer_valid = TensorBoardWriter::new(&format!("{directory}/valid"));
+
+        self.event_store
+            .register_logger_train(TensorBoardMetricLogger::from_writer(writer_train.clone()));
+        self.event_store
+            .register_logger_valid(TensorBoardMetricLogger::from_writer(writer_valid));
+        self.callbacks
+            .push(Box::new(TensorBoardCallback::<B>::from_writer(
+                writer_train,
+            )));
+        self
+    }
+
     /// Update the checkpointing_strategy.
     pub fn with_checkpointing_strategy<CS>(&mut self, strategy: CS)
     where
@@ -124,6 +197,9 @@ where
 
     /// Replace the default CLI renderer with a custom one.
     ///
+    /// The default renderer is the terminal UI when the standard output is an interactive
+    /// terminal, and the [headless renderer](crate::renderer::HeadlessMetricsRenderer) otherwise.
+    ///
     /// # Arguments
     ///
     /// * `renderer` - The custom renderer.
@@ -135,6 +211,15 @@ where
         self
     }
 
+    /// Send the [progress events](ProgressEvent) of the training to the given channel.
+    ///
+    /// The events are sent in addition to the renderer, and the channel is disconnected when the
+    /// learner is dropped at the end of the training.
+    pub fn subscribe(mut self, sender: Sender<ProgressEvent>) -> Self {
+        self.subscribers.push(sender);
+        self
+    }
+
     /// Register a training metric.
     pub fn metric_train<Me: Metric + 'static>(mut self, metric: Me) -> Self
     where
@@ -153,18 +238,62 @@ where
         self
     }
 
-    /// Enable gradients accumulation.
+    /// Enable gradients accumulation over the given number of batches.
     ///
     /// # Notes
     ///
     /// When you enable gradients accumulation, the gradients object used by the optimizer will be
-    /// the sum of all gradients generated by each backward pass. It might be a good idea to
-    /// reduce the learning to compensate.
+    /// the mean of the gradients generated by each backward pass, so the effect is similar to
+    /// increasing the `batch size` by the `accumulation` amount without changing the learning rate.
     ///
-    /// The effect is similar to increasing the `batch size` and the `learning rate` by the `accumulation`
-    /// amount.
+    /// The learning rate scheduler is stepped once per optimizer step, while the metrics are still
+    /// updated after each batch.
+    pub fn grad_accumulation(mut self, accumulation: usize) -> Self {
+        self.grad_accumulation = Some(accumulation);
+        self.sum_accumulated_grads = false;
+        self
+    }
+
+    /// Enable gradients accumulation, the gradients object used by the optimizer being the sum
+    /// of all gradients generated by each backward pass.
+    #[deprecated(
+        note = "Use `grad_accumulation`, which averages the accumulated gradients instead of \
+                summing them, so the learning rate doesn't need to be reduced."
+    )]
     pub fn grads_accumulation(mut self, accumulation: usize) -> Self {
         self.grad_accumulation = Some(accumulation);
+        self.sum_accumulated_grads = true;
+        self
+    }
+
+    /// Train in mixed precision, the losses created with
+    /// [TrainOutput::from_loss](crate::TrainOutput::from_loss) being multiplied by the scale of
+    /// the given [gradient scaler](GradScaler) before the backward pass.
+    ///
+    /// # Notes
+    ///
+    /// The optimizer must unscale the gradients with the same scaler, e.g. by creating it with
+    /// `AdamConfig::new().init_mixed_precision(grad_scaler.clone())`. The state of the scaler is
+    /// saved with the optimizer in the checkpoints.
+    pub fn mixed_precision(mut self, grad_scaler: GradScaler) -> Self {
+        self.grad_scaler = Some(grad_scaler);
+        self
+    }
+
+    /// Also run a validation every `num_steps` training iterations, in addition to the one at the
+    /// end of each epoch, for the trainings where an epoch is too long to wait for.
+    ///
+    /// Each of these validations is aggregated on its own and isn't stored with the validation
+    /// metrics of the epoch, which only come from the validation at its end.
+    pub fn validate_every(mut self, num_steps: usize) -> Self {
+        self.valid_every = Some(num_steps);
+        self
+    }
+
+    /// Only evaluate the first `num_batches` batches of the validation dataloader in each
+    /// validation.
+    pub fn validation_batches(mut self, num_batches: usize) -> Self {
+        self.valid_batches = Some(num_batches);
         self
     }
 
@@ -208,6 +337,43 @@ where
         self
     }
 
+    /// Seed the random number generators of the backend at the beginning of each epoch.
+    ///
+    /// The seed of each epoch is derived from the given seed and the epoch number, so a
+    /// [resumed](Self::resume_from) training uses the same random numbers as an uninterrupted one.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Make the training reproducible, [seeding](Self::seed) the random number generators with
+    /// the given seed and enabling the
+    /// [deterministic mode](burn_core::tensor::backend::Backend::set_deterministic) of the
+    /// backend, which panics on the operations known to be nondeterministic.
+    ///
+    /// The parameters are initialized before the training, so the backend must also be seeded
+    /// with [seed_all](burn_core::tensor::backend::Backend::seed_all) before creating the model.
+    /// The dataloaders must be deterministic as well, i.e. shuffled with a fixed seed and loading
+    /// the batches with a single worker, since multiple workers don't return them in a fixed
+    /// order.
+    pub fn deterministic(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self.deterministic = true;
+        self
+    }
+
+    /// Profile the training iterations, measuring the time of each
+    /// [phase](crate::profiler::Phase) and the peak memory used on the devices.
+    ///
+    /// The mean time of each phase is shown by the renderer and logged at the end of the
+    /// training, while every measure is written as a chrome trace in the `profile` directory of
+    /// the artifacts. Waiting for each phase to complete slows down the training, and only the
+    /// training on a single device is profiled.
+    pub fn profile(mut self) -> Self {
+        self.profile = true;
+        self
+    }
+
     /// Provides a handle that can be used to interrupt training.
     pub fn interrupter(&self) -> TrainingInterrupter {
         self.interrupter.clone()
@@ -223,6 +389,65 @@ where
         self
     }
 
+    /// Only keep the checkpoint of the best epoch according to the given validation metric, and
+    /// return the model of that epoch at the end of the training instead of the last one.
+    ///
+    /// # Notes
+    ///
+    /// The metric should be registered, otherwise no data is collected. A checkpointer is also
+    /// required, e.g. with [with_file_checkpointer](Self::with_file_checkpointer), since the best
+    /// model is restored from its checkpoint. When [stochastic weight averaging](Self::swa) is
+    /// enabled, the averaged model is returned instead.
+    pub fn save_best_only<Me: Metric>(
+        mut self,
+        aggregate: Aggregate,
+        direction: Direction,
+    ) -> Self {
+        self.checkpointer_strategy = Box::new(MetricCheckpointingStrategy::new::<Me>(
+            aggregate,
+            direction,
+            Split::Valid,
+        ));
+        self.best_model_metric = Some(BestModelMetric::new(
+            Me::NAME.to_string(),
+            aggregate,
+            direction,
+            Split::Valid,
+        ));
+        self
+    }
+
+    /// Register a [callback](LearnerCallback) called at the different steps of the training.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: LearnerCallback<M> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Report a metric to the [learning rate scheduler](LrScheduler) at the end of each epoch,
+    /// e.g. the validation loss for a scheduler reducing the learning rate on plateau.
+    ///
+    /// # Notes
+    ///
+    /// The metric should be registered, otherwise no data is collected.
+    pub fn lr_scheduler_metric<Me: Metric>(mut self, aggregate: Aggregate, split: Split) -> Self {
+        self.lr_scheduler_metric = Some(LrSchedulerMetric::new(
+            Me::NAME.to_string(),
+            aggregate,
+            split,
+        ));
+        self
+    }
+
+    /// Enable [stochastic weight averaging](StochasticWeightAveraging), the trained model
+    /// returned by the learner then has the averaged weights.
+    pub fn swa(mut self, swa: StochasticWeightAveraging) -> Self {
+        self.swa = Some(swa);
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -232,7 +457,11 @@ where
     }
 
     /// Register a checkpointer that will save the [optimizer](Optimizer), the
-    /// [model](AutodiffModule) and the [scheduler](LrScheduler) to different files.
+    /// [model](AutodiffModule), the [scheduler](LrScheduler) and the
+    /// [state of the training loop](TrainingStateRecord) to different files.
+    ///
+    /// With [stochastic weight averaging](Self::swa), the averaged model is also saved to the
+    /// `swa` files.
     pub fn with_file_checkpointer<FR>(mut self, recorder: FR) -> Self
     where
         FR: FileRecorder + 'static,
@@ -251,15 +480,27 @@ where
             "optim",
         );
         let checkpointer_scheduler = FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
         );
+        let checkpointer_state = FileCheckpointer::new(
+            recorder.clone(),
+            format!("{}/checkpoint", self.directory).as_str(),
+            "training",
+        );
+        let checkpointer_swa = FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "swa",
+        );
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_state),
+            AsyncCheckpointer::new(checkpointer_swa),
         ));
 
         self
@@ -296,9 +537,26 @@ where
         if self.log_to_file {
             self.init_logger();
         }
-        let renderer = self.renderer.unwrap_or_else(|| {
-            Box::new(default_renderer(self.interrupter.clone(), self.checkpoint))
-        });
+        if self.resume && self.checkpoint.is_none() {
+            self.checkpoint = self.latest_checkpoint();
+        }
+        if self.deterministic {
+            if !B::set_deterministic(true) {
+                log::warn!(
+                    "The backend {} can't guarantee deterministic results.",
+                    B::name()
+                );
+            }
+            if self.devices.len() > 1 {
+                log::warn!(
+                    "The training on multiple devices isn't deterministic, the gradients being \
+                     accumulated in the order the devices complete their step."
+                );
+            }
+        }
+        let renderer = self
+            .renderer
+            .unwrap_or_else(|| default_renderer(self.interrupter.clone(), self.checkpoint));
         let directory = &self.directory;
 
         if self.num_loggers == 0 {
@@ -312,12 +570,44 @@ where
                 ));
         }
 
+        let profiler = match (self.profile, self.devices.len()) {
+            (false, _) => None,
+            (true, 1) => Some(Profiler::new(
+                self.devices.clone(),
+                format!("{directory}/profile/trace.json"),
+            )),
+            (true, _) => {
+                log::warn!("Only the training on a single device can be profiled.");
+                None
+            }
+        };
+
         let event_store = Arc::new(EventStoreClient::new(self.event_store));
-        let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
+        let event_processor = FullEventProcessor::new(
+            self.metrics,
+            renderer,
+            event_store.clone(),
+            self.subscribers,
+        );
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer = self
+            .checkpointers
+            .map(|(model, optim, scheduler, state, swa)| {
+                LearnerCheckpointer::new(
+                    model,
+                    optim,
+                    scheduler,
+                    state,
+                    swa,
+                    self.checkpointer_strategy,
+                )
+            });
+
+        let callbacks = LearnerCallbacks::new(
+            self.callbacks,
+            event_store.clone(),
+            self.interrupter.clone(),
+        );
 
         Learner {
             model,
@@ -328,11 +618,39 @@ where
             event_processor,
             event_store,
             checkpoint: self.checkpoint,
+            seed: self.seed,
             grad_accumulation: self.grad_accumulation,
+            sum_accumulated_grads: self.sum_accumulated_grads,
+            grad_scaler: self.grad_scaler,
+            valid_every: self.valid_every,
+            valid_batches: self.valid_batches,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            lr_scheduler_metric: self.lr_scheduler_metric,
+            swa: self.swa,
+            best_model_metric: self.best_model_metric,
+            callbacks,
+            profiler,
+        }
+    }
+
+    fn latest_checkpoint(&self) -> Option<usize> {
+        if self.checkpointers.is_none() {
+            log::warn!("Can't resume the training without a checkpointer.");
+            return None;
+        }
+
+        let directory = format!("{}/checkpoint", self.directory);
+        let checkpoint =
+            latest_checkpoint(&directory, &["model", "optim", "scheduler", "training"]);
+
+        match checkpoint {
+            Some(epoch) => log::info!("Resuming the training from the checkpoint of epoch {epoch}"),
+            None => log::info!("No checkpoint found in {directory}, starting a new training"),
         }
+
+        checkpoint
     }
 
     fn init_logger(&self) {
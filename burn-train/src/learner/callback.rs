@@ -0,0 +1,276 @@
+use crate::{metric::store::EventStoreClient, TrainingInterrupter};
+use burn_core::optim::GradientsParams;
+use burn_core::LearningRate;
+use std::sync::Arc;
+
+/// The state of the training passed to the [callbacks](LearnerCallback), which can modify it to
+/// adjust the training.
+pub struct LearnerState<'a, M> {
+    /// The model being trained, which can be modified, e.g. to change the probability of its
+    /// dropout layers.
+    pub model: &'a mut M,
+    /// The current epoch, starting at 1.
+    pub epoch: usize,
+    /// The total number of epochs.
+    pub epoch_total: usize,
+    /// The current iteration of the epoch, zero outside of the batch events.
+    pub iteration: usize,
+    /// The number of training iterations completed since the beginning of the training, including
+    /// the ones before resuming.
+    pub total_iterations: usize,
+    /// The learning rate of the batch, only set for the batch events.
+    pub lr: Option<LearningRate>,
+    /// The factor applied to the learning rate of the scheduler for the next iterations, `1.0` by
+    /// default. It is saved in the checkpoints, so a resumed training keeps the adjusted rate.
+    pub lr_factor: f64,
+    /// The event store, to query the metrics collected so far.
+    pub store: &'a EventStoreClient,
+    /// The handle to interrupt the training.
+    pub interrupter: &'a TrainingInterrupter,
+}
+
+/// A callback called by the learner at the different steps of the training.
+///
+/// All methods do nothing by default, so only the events of interest have to be implemented.
+/// The callbacks can adjust the training through the [state](LearnerState), by modifying the
+/// model or the factor applied to the learning rate.
+///
+/// # Example
+///
+/// ```ignore
+/// struct ReduceLrOnPlateau {
+///     best: f64,
+/// }
+///
+/// impl<B: AutodiffBackend> LearnerCallback<MyModel<B>> for ReduceLrOnPlateau {
+///     fn on_valid_end(&mut self, state: &mut LearnerState<MyModel<B>>) {
+///         let loss = state
+///             .store
+///             .find_metric("Loss", state.epoch, Aggregate::Mean, Split::Valid)
+///             .unwrap_or(f64::MAX);
+///
+///         if loss < self.best {
+///             self.best = loss;
+///         } else {
+///             state.lr_factor *= 0.1;
+///         }
+///     }
+/// }
+/// ```
+pub trait LearnerCallback<M> {
+    /// Called once before the first training epoch.
+    fn on_train_begin(&mut self, _state: &mut LearnerState<M>) {}
+
+    /// Called before each optimizer step with the gradients, accumulated over the batches of the
+    /// step, that will update the model.
+    fn on_optimizer_step(&mut self, _state: &mut LearnerState<M>, _grads: &GradientsParams) {}
+
+    /// Called after each training batch, once the model has been updated.
+    fn on_batch_end(&mut self, _state: &mut LearnerState<M>) {}
+
+    /// Called at the end of each training epoch, before the validation.
+    fn on_epoch_end(&mut self, _state: &mut LearnerState<M>) {}
+
+    /// Called at the end of each validation epoch.
+    fn on_valid_end(&mut self, _state: &mut LearnerState<M>) {}
+
+    /// Called once when the training ends, with the model that will be returned.
+    fn on_train_end(&mut self, _state: &mut LearnerState<M>) {}
+}
+
+/// The [callbacks](LearnerCallback) registered in the learner, called with its state.
+pub struct LearnerCallbacks<M> {
+    callbacks: Vec<Box<dyn LearnerCallback<M>>>,
+    store: Arc<EventStoreClient>,
+    interrupter: TrainingInterrupter,
+    /// The number of training iterations since the beginning of the training.
+    total_iterations: usize,
+    lr_factor: f64,
+}
+
+/// The training progress given to the [callbacks](LearnerCallbacks).
+#[derive(new, Clone, Copy)]
+pub(crate) struct CallbackProgress {
+    pub(crate) epoch: usize,
+    pub(crate) epoch_total: usize,
+    pub(crate) iteration: usize,
+    pub(crate) lr: Option<LearningRate>,
+}
+
+impl<M> LearnerCallbacks<M> {
+    pub(crate) fn new(
+        callbacks: Vec<Box<dyn LearnerCallback<M>>>,
+        store: Arc<EventStoreClient>,
+        interrupter: TrainingInterrupter,
+    ) -> Self {
+        Self {
+            callbacks,
+            store,
+            interrupter,
+            total_iterations: 0,
+            lr_factor: 1.0,
+        }
+    }
+
+    /// The number of training iterations since the beginning of the training.
+    pub(crate) fn total_iterations(&self) -> usize {
+        self.total_iterations
+    }
+
+    /// The factor set by the callbacks, applied to the learning rate of the scheduler.
+    pub(crate) fn lr_factor(&self) -> f64 {
+        self.lr_factor
+    }
+
+    /// Continue counting the training iterations from those completed before resuming, with the
+    /// learning rate factor of the checkpoint.
+    pub(crate) fn resume(&mut self, total_iterations: usize, lr_factor: f64) {
+        self.total_iterations = total_iterations;
+        self.lr_factor = lr_factor;
+    }
+
+    pub(crate) fn on_train_begin(&mut self, model: &mut M, progress: CallbackProgress) {
+        self.call(model, progress, |callback, state| {
+            callback.on_train_begin(state)
+        });
+    }
+
+    pub(crate) fn on_optimizer_step(
+        &mut self,
+        model: &mut M,
+        grads: &GradientsParams,
+        progress: CallbackProgress,
+    ) {
+        self.call(model, progress, |callback, state| {
+            callback.on_optimizer_step(state, grads)
+        });
+    }
+
+    pub(crate) fn on_batch_end(&mut self, model: &mut M, progress: CallbackProgress) {
+        self.total_iterations += 1;
+        self.call(model, progress, |callback, state| {
+            callback.on_batch_end(state)
+        });
+    }
+
+    pub(crate) fn on_epoch_end(&mut self, model: &mut M, progress: CallbackProgress) {
+        self.call(model, progress, |callback, state| {
+            callback.on_epoch_end(state)
+        });
+    }
+
+    pub(crate) fn on_valid_end(&mut self, model: &mut M, progress: CallbackProgress) {
+        self.call(model, progress, |callback, state| {
+            callback.on_valid_end(state)
+        });
+    }
+
+    pub(crate) fn on_train_end(&mut self, model: &mut M, progress: CallbackProgress) {
+        self.call(model, progress, |callback, state| {
+            callback.on_train_end(state)
+        });
+    }
+
+    fn call<F>(&mut self, model: &mut M, progress: CallbackProgress, func: F)
+    where
+        F: Fn(&mut dyn LearnerCallback<M>, &mut LearnerState<M>),
+    {
+        if self.callbacks.is_empty() {
+            return;
+        }
+
+        let mut state = LearnerState {
+            model,
+            epoch: progress.epoch,
+            epoch_total: progress.epoch_total,
+            iteration: progress.iteration,
+            total_iterations: self.total_iterations,
+            lr: progress.lr,
+            lr_factor: self.lr_factor,
+            store: &self.store,
+            interrupter: &self.interrupter,
+        };
+
+        for callback in self.callbacks.iter_mut() {
+            func(callback.as_mut(), &mut state);
+        }
+
+        self.lr_factor = state.lr_factor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::LogEventStore;
+
+    #[derive(Default)]
+    struct EventsRecorder {
+        events: Arc<std::sync::Mutex<Vec<(&'static str, usize, usize)>>>,
+    }
+
+    impl LearnerCallback<()> for EventsRecorder {
+        fn on_batch_end(&mut self, state: &mut LearnerState<()>) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(("batch", state.epoch, state.iteration));
+        }
+
+        fn on_epoch_end(&mut self, state: &mut LearnerState<()>) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(("epoch", state.epoch, state.iteration));
+            state.interrupter.stop();
+        }
+    }
+
+    #[test]
+    fn test_callbacks_receive_the_learner_state() {
+        let recorder = EventsRecorder::default();
+        let events = recorder.events.clone();
+        let interrupter = TrainingInterrupter::new();
+        let mut callbacks = LearnerCallbacks::new(
+            vec![Box::new(recorder)],
+            Arc::new(EventStoreClient::new(LogEventStore::default())),
+            interrupter.clone(),
+        );
+
+        callbacks.on_batch_end(&mut (), CallbackProgress::new(1, 2, 1, Some(0.1)));
+        callbacks.on_batch_end(&mut (), CallbackProgress::new(1, 2, 2, Some(0.1)));
+        callbacks.on_valid_end(&mut (), CallbackProgress::new(1, 2, 0, Some(0.1)));
+        callbacks.on_epoch_end(&mut (), CallbackProgress::new(1, 2, 0, Some(0.1)));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![("batch", 1, 1), ("batch", 1, 2), ("epoch", 1, 0)]
+        );
+        assert!(interrupter.should_stop());
+    }
+
+    struct LrHalver;
+
+    impl LearnerCallback<usize> for LrHalver {
+        fn on_valid_end(&mut self, state: &mut LearnerState<usize>) {
+            *state.model += 1;
+            state.lr_factor *= 0.5;
+        }
+    }
+
+    #[test]
+    fn test_callbacks_adjust_the_model_and_the_lr() {
+        let mut callbacks = LearnerCallbacks::new(
+            vec![Box::new(LrHalver)],
+            Arc::new(EventStoreClient::new(LogEventStore::default())),
+            TrainingInterrupter::new(),
+        );
+        let mut model = 0;
+
+        callbacks.on_valid_end(&mut model, CallbackProgress::new(1, 2, 0, None));
+        callbacks.on_valid_end(&mut model, CallbackProgress::new(2, 2, 0, None));
+
+        assert_eq!(model, 2);
+        assert_eq!(callbacks.lr_factor(), 0.25);
+    }
+}
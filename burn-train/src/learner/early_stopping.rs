@@ -28,6 +28,7 @@ pub struct MetricEarlyStoppingStrategy {
     split: Split,
     best_epoch: usize,
     best_value: f64,
+    min_delta: f64,
 }
 
 impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
@@ -42,8 +43,8 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
             };
 
         let is_best = match self.direction {
-            Direction::Lowest => current_value < self.best_value,
-            Direction::Highest => current_value > self.best_value,
+            Direction::Lowest => current_value < self.best_value - self.min_delta,
+            Direction::Highest => current_value > self.best_value + self.min_delta,
         };
 
         if is_best {
@@ -98,8 +99,15 @@ impl MetricEarlyStoppingStrategy {
             split,
             best_epoch: 1,
             best_value: init_value,
+            min_delta: 0.0,
         }
     }
+
+    /// Set the minimum change of the metric counted as an improvement, zero by default.
+    pub fn with_min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -110,8 +118,8 @@ mod tests {
         logger::InMemoryMetricLogger,
         metric::{
             processor::{
-                test_utils::{end_epoch, process_train},
-                Metrics, MinimalEventProcessor,
+                test_utils::{end_epoch, process_train, process_valid},
+                Event, EventProcessor, Metrics, MinimalEventProcessor,
             },
             store::LogEventStore,
             LossMetric,
@@ -175,13 +183,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn early_stop_when_improvements_are_below_min_delta() {
+        test_early_stopping_with_min_delta(
+            2,
+            0.1,
+            &[
+                (&[0.5, 0.3], false, "Should not stop first epoch"),
+                (
+                    &[0.4, 0.3],
+                    false,
+                    "Should not stop first time it improves less than min delta",
+                ),
+                (
+                    &[0.4, 0.22],
+                    true,
+                    "Should stop since two following epochs improved less than min delta",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn validations_during_the_epoch_not_aggregated_with_the_final_one() {
+        let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+            StoppingCondition::NoImprovementSince { n_epochs: 1 },
+        );
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+
+        store.register_logger_valid(InMemoryMetricLogger::default());
+        metrics.register_valid_metric_numeric(LossMetric::<TestBackend>::new());
+
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        // The validations during the epochs get worse while the final ones improve.
+        for (epoch, (during_epoch, final_value)) in
+            [(0.5, 1.0), (2.0, 0.75)].into_iter().enumerate()
+        {
+            let epoch = epoch + 1;
+            processor.process_valid(Event::StartValidation);
+            process_valid(&mut processor, during_epoch, epoch);
+            processor.process_valid(Event::EndValidation(epoch));
+            process_valid(&mut processor, final_value, epoch);
+            end_epoch(&mut processor, epoch);
+
+            // The value reported to the learning rate scheduler.
+            assert_eq!(
+                store.find_metric("Loss", epoch, Aggregate::Mean, Split::Valid),
+                Some(final_value)
+            );
+            assert!(
+                !early_stopping.should_stop(epoch, &store),
+                "Should not stop since the final validations improve"
+            );
+        }
+    }
+
     fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
+        test_early_stopping_with_min_delta(n_epochs, 0.0, data)
+    }
+
+    fn test_early_stopping_with_min_delta(
+        n_epochs: usize,
+        min_delta: f64,
+        data: &[(&[f64], bool, &str)],
+    ) {
         let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
             Aggregate::Mean,
             Direction::Lowest,
             Split::Train,
             StoppingCondition::NoImprovementSince { n_epochs },
-        );
+        )
+        .with_min_delta(min_delta);
         let mut store = LogEventStore::default();
         let mut metrics = Metrics::<f64, f64>::default();
 
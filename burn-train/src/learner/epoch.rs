@@ -1,11 +1,19 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::{DataLoader, Progress},
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradScaler, GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
 
+use crate::learner::train_val::with_grad_scaler;
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
+use crate::profiler::Profiler;
+use crate::{
+    components::LearnerComponents,
+    learner::{base::TrainingInterrupter, CallbackProgress, LearnerCallbacks},
+};
 use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
 
 /// A validation epoch.
@@ -14,6 +22,7 @@ pub struct ValidEpoch<VI> {
     dataloader: Arc<dyn DataLoader<VI>>,
     epoch: usize,
     epoch_total: usize,
+    num_batches: Option<usize>,
 }
 
 /// A training epoch.
@@ -23,8 +32,16 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    valid_every: Option<usize>,
+    #[new(default)]
+    sum_accumulated_grads: bool,
+    #[new(default)]
+    grad_scaler: Option<GradScaler>,
 }
 
+/// Runs a validation during a training epoch, with the model being trained.
+pub type ValidationHook<'a, M, P> = &'a mut dyn FnMut(&M, &mut P);
+
 impl<VI> ValidEpoch<VI> {
     /// Runs the validation epoch.
     ///
@@ -42,14 +59,51 @@ impl<VI> ValidEpoch<VI> {
         <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
     {
         log::info!("Executing validation step for epoch {}", self.epoch);
+        self.evaluate::<LC, VO>(model, processor, interrupter);
+        processor.process_valid(Event::EndEpoch(self.epoch));
+    }
+
+    /// Runs a validation during the epoch, with the model being trained.
+    ///
+    /// Its items are aggregated on their own, so the validation metrics of the epoch only come
+    /// from the validation [run](Self::run) at its end.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to validate.
+    /// * `processor` - The event processor to use.
+    pub fn run_during_epoch<LC: LearnerComponents, VO>(
+        &self,
+        model: &LC::Model,
+        processor: &mut LC::EventProcessor,
+        interrupter: &TrainingInterrupter,
+    ) where
+        LC::EventProcessor: EventProcessor<ItemValid = VO>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
+    {
+        log::info!("Executing validation step during epoch {}", self.epoch);
+        processor.process_valid(Event::StartValidation);
+        self.evaluate::<LC, VO>(model, processor, interrupter);
+        processor.process_valid(Event::EndValidation(self.epoch));
+    }
+
+    fn evaluate<LC: LearnerComponents, VO>(
+        &self,
+        model: &LC::Model,
+        processor: &mut LC::EventProcessor,
+        interrupter: &TrainingInterrupter,
+    ) where
+        LC::EventProcessor: EventProcessor<ItemValid = VO>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
+    {
         let model = model.valid();
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
 
         while let Some(item) = iterator.next() {
-            let progress = iterator.progress();
             iteration += 1;
+            let progress = self.progress(iterator.progress(), iteration);
 
             let item = model.step(item);
             let item = LearnerItem::new(
@@ -67,12 +121,58 @@ impl<VI> ValidEpoch<VI> {
                 log::info!("Training interrupted.");
                 break;
             }
+            if Some(iteration) == self.num_batches {
+                break;
+            }
+        }
+    }
+
+    /// The progress of the validation, estimating the number of items of the evaluated batches
+    /// when they are bounded.
+    fn progress(&self, progress: Progress, iteration: usize) -> Progress {
+        match self.num_batches {
+            Some(num_batches) => Progress {
+                items_total: usize::min(
+                    progress.items_total,
+                    progress.items_processed * num_batches / iteration,
+                ),
+                items_processed: progress.items_processed,
+            },
+            None => progress,
         }
-        processor.process_valid(Event::EndEpoch(self.epoch));
     }
 }
 
 impl<TI> TrainEpoch<TI> {
+    /// Sum the accumulated gradients instead of averaging them, as done by the deprecated
+    /// [grads_accumulation](crate::LearnerBuilder::grads_accumulation).
+    pub(crate) fn with_summed_grads(mut self, sum_accumulated_grads: bool) -> Self {
+        self.sum_accumulated_grads = sum_accumulated_grads;
+        self
+    }
+
+    /// Scale the losses of the training steps with the given gradient scaler, as configured by
+    /// [mixed_precision](crate::LearnerBuilder::mixed_precision).
+    pub(crate) fn with_grad_scaler(mut self, grad_scaler: Option<GradScaler>) -> Self {
+        self.grad_scaler = grad_scaler;
+        self
+    }
+
+    fn accumulated_grads<B, M>(
+        &self,
+        accumulator: &mut GradientsAccumulator<M>,
+        model: &M,
+    ) -> GradientsParams
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+    {
+        match self.sum_accumulated_grads {
+            true => accumulator.grads(),
+            false => accumulator.grads_mean(model),
+        }
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -81,17 +181,24 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to call after each batch.
+    /// * `profiler` - The profiler measuring each iteration, if the training is profiled.
+    /// * `validate` - The validation to run every `valid_every` iterations.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
-    pub fn run<LC: LearnerComponents, TO>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn run<LC: LearnerComponents, TO, S: LrScheduler>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
-        scheduler: &mut LC::LrScheduler,
+        scheduler: &mut S,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<LC::Model>,
         interrupter: &TrainingInterrupter,
+        mut profiler: Option<&mut Profiler<LC::Backend>>,
+        validate: ValidationHook<'_, LC::Model, LC::EventProcessor>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -102,28 +209,44 @@ impl<TI> TrainEpoch<TI> {
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
+        let accumulation = self.grad_accumulation.unwrap_or(1);
+        let mut lr = 0.0;
+
+        if let Some(profiler) = &mut profiler {
+            profiler.start_epoch(self.epoch);
+        }
 
         while let Some(item) = iterator.next() {
             iteration += 1;
-            let lr = scheduler.step();
+            if let Some(profiler) = &mut profiler {
+                profiler.data_loaded(iteration);
+            }
+            // The scheduler is stepped once per optimizer step.
+            if accumulator.num_accumulated() == 0 {
+                lr = scheduler.step() * callbacks.lr_factor();
+            }
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
-            let item = model.step(item);
+            let should_validate = self.should_validate(iteration, &progress);
+            let item = with_grad_scaler(self.grad_scaler.as_ref(), || model.step(item));
+            if let Some(profiler) = &mut profiler {
+                profiler.step_done();
+            }
 
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
-                    accumulation_current += 1;
+            accumulator.accumulate(&model, item.grads);
 
-                    if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
-                        model = model.optimize(&mut optim, lr, grads);
-                        accumulation_current = 0;
-                    }
-                }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+            if accumulation <= accumulator.num_accumulated() {
+                let grads = self.accumulated_grads(&mut accumulator, &model);
+                callbacks.on_optimizer_step(
+                    &mut model,
+                    &grads,
+                    CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
+                );
+                model = model.optimize(&mut optim, lr, grads);
+            }
+            if let Some(profiler) = &mut profiler {
+                profiler.optimizer_done();
             }
 
             let item = LearnerItem::new(
@@ -136,12 +259,38 @@ impl<TI> TrainEpoch<TI> {
             );
 
             processor.process_train(Event::ProcessedItem(item));
+            callbacks.on_batch_end(
+                &mut model,
+                CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
+            );
+            if let Some(profiler) = &mut profiler {
+                processor.process_train(Event::Profile(profiler.iteration_done()));
+            }
+
+            if should_validate {
+                validate(&model, processor);
+                if let Some(profiler) = &mut profiler {
+                    profiler.resume();
+                }
+            }
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
             }
         }
+
+        // The gradients of the last incomplete accumulation aren't lost.
+        if accumulator.num_accumulated() > 0 {
+            let grads = self.accumulated_grads(&mut accumulator, &model);
+            callbacks.on_optimizer_step(
+                &mut model,
+                &grads,
+                CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
+            );
+            model = model.optimize(&mut optim, lr, grads);
+        }
+
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
@@ -157,19 +306,24 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks to call after each batch.
     /// * `devices` - The devices to use.
+    /// * `validate` - The validation to run every `valid_every` iterations.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
-    pub fn run_multi_device<LC: LearnerComponents, TO>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_multi_device<LC: LearnerComponents, TO, S: LrScheduler>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
-        lr_scheduler: &mut LC::LrScheduler,
+        lr_scheduler: &mut S,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<LC::Model>,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        validate: ValidationHook<'_, LC::Model, LC::EventProcessor>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -186,10 +340,10 @@ impl<TI> TrainEpoch<TI> {
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
+        let mut lr = 0.0;
 
         let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
-        let step = MultiDevicesTrainStep::new(&devices);
+        let step = MultiDevicesTrainStep::new(&devices).with_grad_scaler(self.grad_scaler.clone());
 
         // The main device is always the first in the list.
         let device_main = devices.get(0).expect("A minimum of one device.").clone();
@@ -201,32 +355,49 @@ impl<TI> TrainEpoch<TI> {
                 break;
             }
 
+            // The items of the step are processed together, so the states of their metrics are
+            // merged across the devices.
+            let mut processed = Vec::with_capacity(items.len());
+
             for item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
+                if accumulator.num_accumulated() == 0 {
+                    lr = lr_scheduler.step() * callbacks.lr_factor();
+                }
                 let progress = iterator.progress();
+                let should_validate = self.should_validate(iteration, &progress);
 
                 let grads = item.grads.to_device(&device_main, &model);
 
                 accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
+                if accumulation <= accumulator.num_accumulated() {
+                    let grads = self.accumulated_grads(&mut accumulator, &model);
+                    callbacks.on_optimizer_step(
+                        &mut model,
+                        &grads,
+                        CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
+                    );
                     model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
                 }
 
-                let item = LearnerItem::new(
+                processed.push(LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
                     self.epoch_total,
                     iteration,
                     Some(lr),
+                ));
+                callbacks.on_batch_end(
+                    &mut model,
+                    CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
                 );
 
-                processor.process_train(Event::ProcessedItem(item));
+                if should_validate {
+                    processor.process_train(Event::ProcessedItems(core::mem::take(&mut processed)));
+                    validate(&model, processor);
+                }
 
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
@@ -235,13 +406,40 @@ impl<TI> TrainEpoch<TI> {
                 }
             }
 
+            if !processed.is_empty() {
+                processor.process_train(Event::ProcessedItems(processed));
+            }
+
             if interrupted {
                 break;
             }
         }
 
+        if accumulator.num_accumulated() > 0 {
+            let grads = self.accumulated_grads(&mut accumulator, &model);
+            callbacks.on_optimizer_step(
+                &mut model,
+                &grads,
+                CallbackProgress::new(self.epoch, self.epoch_total, iteration, Some(lr)),
+            );
+            model = model.optimize(&mut optim, lr, grads);
+        }
+
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// If a validation should run after the iteration, skipping the last one of the epoch which
+    /// is always followed by a validation.
+    fn should_validate(&self, iteration: usize, progress: &Progress) -> bool {
+        match self.valid_every {
+            Some(num_steps) if num_steps > 0 => {
+                iteration % num_steps == 0 && progress.items_processed < progress.items_total
+            }
+            _ => false,
+        }
+    }
+}
@@ -1,20 +1,24 @@
 mod base;
 mod builder;
+mod callback;
 mod classification;
 mod early_stopping;
 mod epoch;
 mod regression;
 mod step;
+mod swa;
 mod train_val;
 
 pub(crate) mod log;
 
 pub use base::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
 pub use early_stopping::*;
 pub use epoch::*;
 pub use regression::*;
 pub use step::*;
+pub use swa::*;
 pub use train::*;
 pub use train_val::*;
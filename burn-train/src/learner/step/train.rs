@@ -1,6 +1,8 @@
+use crate::learner::train_val::with_grad_scaler;
 use crate::{TrainOutput, TrainStep};
 use burn_core::{
-    data::dataloader::DataLoaderIterator, module::AutodiffModule, tensor::backend::AutodiffBackend,
+    data::dataloader::DataLoaderIterator, module::AutodiffModule, optim::GradScaler,
+    tensor::backend::AutodiffBackend,
 };
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread::spawn;
@@ -9,11 +11,13 @@ use std::thread::spawn;
 pub struct MultiDevicesTrainStep<B: AutodiffBackend, M, TI, TO> {
     workers: Vec<Worker<B, M, TI>>,
     receiver: Receiver<TrainOutput<TO>>,
+    grad_scaler: Option<GradScaler>,
 }
 
 struct Message<M, TI> {
     item: TI,
     model: M,
+    grad_scaler: Option<GradScaler>,
 }
 
 struct Worker<B: AutodiffBackend, M, TI> {
@@ -26,10 +30,11 @@ where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
-    fn register(&self, item: TI, model: &M) {
+    fn register(&self, item: TI, model: &M, grad_scaler: Option<GradScaler>) {
         let message = Message {
             item,
             model: model.clone(),
+            grad_scaler,
         };
         self.sender_input.send(message).unwrap();
     }
@@ -49,7 +54,8 @@ where
             match receiver_input.recv() {
                 Ok(item) => {
                     let step = item.model.fork(&device);
-                    let output = step.step(item.item);
+                    let output =
+                        with_grad_scaler(item.grad_scaler.as_ref(), || step.step(item.item));
 
                     sender_output.send(output).unwrap();
                 }
@@ -100,9 +106,16 @@ where
         Self {
             workers,
             receiver: receiver_output,
+            grad_scaler: None,
         }
     }
 
+    /// Scale the losses computed with [TrainOutput::from_loss] with the given gradient scaler.
+    pub fn with_grad_scaler(mut self, grad_scaler: Option<GradScaler>) -> Self {
+        self.grad_scaler = grad_scaler;
+        self
+    }
+
     /// Collect outputs from workers for one step.
     ///
     /// # Arguments
@@ -122,7 +135,7 @@ where
 
         for worker in self.workers.iter() {
             if let Some(item) = dataloader.next() {
-                worker.register(item, model);
+                worker.register(item, model, self.grad_scaler.clone());
                 num_send += 1;
             }
         }
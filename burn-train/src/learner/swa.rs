@@ -0,0 +1,346 @@
+use crate::TrainStep;
+use burn_core::{
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::{AutodiffModule, Module, ModuleMapper, ModulePath, ModuleVisitor, ParamId},
+    tensor::{
+        backend::{AutodiffBackend, Backend},
+        container::TensorContainer,
+        Tensor,
+    },
+    LearningRate,
+};
+
+/// Stochastic weight averaging as described in
+/// [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407).
+///
+/// From the start epoch, the learning rate is annealed to a constant SWA learning rate and the
+/// weights of the model at the end of each training epoch are averaged. The averaged weights
+/// replace the weights of the trained model at the end of the training, after which the batch
+/// norm statistics can be recomputed with a pass over the training data.
+///
+/// The model with the averaged weights is saved by the checkpointer with each checkpoint, so the
+/// averaging continues when the training is resumed, and once more at the end of the training.
+#[derive(Clone, Debug)]
+pub struct StochasticWeightAveraging {
+    start_epoch: usize,
+    swa_lr: LearningRate,
+    anneal_epochs: usize,
+    update_bn: bool,
+}
+
+impl StochasticWeightAveraging {
+    /// Create a new stochastic weight averaging starting at the given epoch with the given
+    /// learning rate.
+    pub fn new(start_epoch: usize, swa_lr: LearningRate) -> Self {
+        Self {
+            start_epoch,
+            swa_lr,
+            anneal_epochs: 0,
+            update_bn: true,
+        }
+    }
+
+    /// Set the number of epochs over which the learning rate of the scheduler is linearly
+    /// annealed to the SWA learning rate, zero by default.
+    pub fn with_anneal_epochs(mut self, anneal_epochs: usize) -> Self {
+        self.anneal_epochs = anneal_epochs;
+        self
+    }
+
+    /// Set whether the batch norm statistics are recomputed with a pass over the training data
+    /// once the averaged weights are loaded, enabled by default.
+    ///
+    /// The running statistics are reset, then updated with the momentum of each batch norm layer
+    /// by forward passes without gradients, so the pass should contain enough batches for the
+    /// initial statistics to be forgotten.
+    pub fn with_update_bn(mut self, update_bn: bool) -> Self {
+        self.update_bn = update_bn;
+        self
+    }
+
+    pub(crate) fn is_active(&self, epoch: usize) -> bool {
+        epoch >= self.start_epoch
+    }
+
+    /// The number of models averaged at the end of the given epoch.
+    pub(crate) fn num_averaged(&self, epoch: usize) -> usize {
+        (epoch + 1).saturating_sub(self.start_epoch)
+    }
+
+    pub(crate) fn update_bn(&self) -> bool {
+        self.update_bn
+    }
+
+    pub(crate) fn lr_scheduler<'a, S: LrScheduler>(
+        &self,
+        scheduler: &'a mut S,
+        epoch: usize,
+    ) -> SwaLrScheduler<'a, S> {
+        let alpha = match self.anneal_epochs {
+            0 => 1.0,
+            anneal_epochs => f64::min(
+                (epoch + 1 - self.start_epoch) as f64 / anneal_epochs as f64,
+                1.0,
+            ),
+        };
+
+        SwaLrScheduler {
+            scheduler,
+            swa_lr: self.swa_lr,
+            alpha,
+        }
+    }
+}
+
+/// Learning rate scheduler interpolating the learning rate of a scheduler with the SWA learning
+/// rate, only used during the epochs of a [stochastic weight averaging](StochasticWeightAveraging).
+pub(crate) struct SwaLrScheduler<'a, S> {
+    scheduler: &'a mut S,
+    swa_lr: LearningRate,
+    alpha: f64,
+}
+
+impl<'a, S: LrScheduler> LrScheduler for SwaLrScheduler<'a, S> {
+    type Record = ();
+
+    fn step(&mut self) -> LearningRate {
+        // The scheduler is still stepped, so it stays in sync with the number of iterations.
+        let lr = self.scheduler.step();
+
+        (1.0 - self.alpha) * lr + self.alpha * self.swa_lr
+    }
+
+    fn to_record(&self) -> Self::Record {}
+
+    fn load_record(self, _record: Self::Record) -> Self {
+        self
+    }
+}
+
+/// Equal weight average of the parameters of a model.
+pub(crate) struct SwaAverage<B: AutodiffBackend, M: AutodiffModule<B>> {
+    average: Option<M::InnerModule>,
+    num_averaged: usize,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> SwaAverage<B, M> {
+    pub(crate) fn new() -> Self {
+        Self {
+            average: None,
+            num_averaged: 0,
+        }
+    }
+
+    /// Add the parameters of the given model to the average.
+    pub(crate) fn update(&mut self, module: &M) {
+        let module = module.valid();
+        self.num_averaged += 1;
+
+        self.average = Some(match self.average.take() {
+            Some(average) => {
+                let mut collector = ParamsCollector::default();
+                module.visit(&mut collector);
+
+                let mut mapper = ParamsAverager::new(collector.params, self.num_averaged);
+                average.map(&mut mapper)
+            }
+            None => module,
+        });
+    }
+
+    /// Resume the average from a model with the averaged parameters of the given number of
+    /// models.
+    pub(crate) fn resume(&mut self, module: &M, num_averaged: usize) {
+        self.average = Some(module.valid());
+        self.num_averaged = num_averaged;
+    }
+
+    /// The given model with the averaged parameters, if any model was averaged.
+    pub(crate) fn averaged(&self, module: &M) -> Option<M> {
+        self.average.as_ref().map(|_| self.apply(module.clone()))
+    }
+
+    /// Replace the parameters of the given model by the averaged parameters, if any.
+    pub(crate) fn apply(&self, module: M) -> M {
+        let average = match &self.average {
+            Some(average) => average,
+            None => return module,
+        };
+
+        let mut collector = ParamsCollector::default();
+        average.visit(&mut collector);
+
+        let mut mapper = ParamsReplacer::new(collector.params);
+        module.map(&mut mapper)
+    }
+}
+
+/// Recomputes the batch norm statistics of the model with forward passes over the data.
+///
+/// The running statistics are reset first, so the statistics of the weights before averaging
+/// don't leak into the new ones.
+pub(crate) fn update_batch_norm<B, M, TI, TO>(model: M, dataloader: &dyn DataLoader<TI>) -> M
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + TrainStep<TI, TO>,
+{
+    let model = model.map(&mut RunningStatsReset::default());
+    // The running states are shared between the clones of a module, so the statistics computed
+    // with the model without gradients are also the ones of the returned model. Without any
+    // tracked parameter, no graph is built and the backward pass of the step is free.
+    let model_forward = model.clone().no_grad();
+
+    for item in dataloader.iter() {
+        let _ = model_forward.step(item);
+    }
+
+    model
+}
+
+/// Resets the running statistics of the batch norm layers, identified by their field names, to
+/// their initial values.
+#[derive(Default)]
+struct RunningStatsReset {
+    path: ModulePath,
+}
+
+impl<B: Backend> ModuleMapper<B> for RunningStatsReset {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name);
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.path.segments().last().map(String::as_str) {
+            Some("running_mean") => tensor.zeros_like(),
+            Some("running_var") => tensor.ones_like(),
+            _ => tensor,
+        }
+    }
+}
+
+#[derive(Default)]
+struct ParamsCollector {
+    params: TensorContainer<ParamId>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamsCollector {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone());
+    }
+}
+
+#[derive(new)]
+struct ParamsAverager {
+    params: TensorContainer<ParamId>,
+    num_averaged: usize,
+}
+
+impl<B: Backend> ModuleMapper<B> for ParamsAverager {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B, D>(id) {
+            Some(param) => {
+                let delta = param
+                    .sub(tensor.clone())
+                    .div_scalar(self.num_averaged as f64);
+                tensor.add(delta)
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[derive(new)]
+struct ParamsReplacer {
+    params: TensorContainer<ParamId>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for ParamsReplacer {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B::InnerBackend, D>(id) {
+            Some(param) => {
+                let param = Tensor::from_inner(param);
+                match tensor.is_require_grad() {
+                    true => param.require_grad(),
+                    false => param,
+                }
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::{
+        nn::{BatchNormConfig, LinearConfig},
+        tensor::Data,
+    };
+
+    #[test]
+    fn test_params_averaged_with_number_of_models() {
+        let linear = LinearConfig::new(1, 2)
+            .with_bias(false)
+            .init::<TestBackend>();
+        let average = linear.clone().map(&mut ParamsFiller(1.0));
+
+        let mut collector = ParamsCollector::default();
+        linear.map(&mut ParamsFiller(4.0)).visit(&mut collector);
+        let average = average.map(&mut ParamsAverager::new(collector.params, 3));
+
+        average
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn test_running_stats_reset_to_initial_values() {
+        let batch_norm = BatchNormConfig::new(2).init::<TestBackend, 1>();
+        let batch_norm = batch_norm.map(&mut ParamsFiller(4.0));
+
+        let batch_norm = batch_norm.map(&mut RunningStatsReset::default());
+        let record = batch_norm.into_record();
+
+        record
+            .running_mean
+            .to_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 3);
+        record
+            .running_var
+            .to_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+        record
+            .gamma
+            .to_data()
+            .assert_approx_eq(&Data::from([4.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn test_swa_lr_annealed_to_swa_lr() {
+        let swa = StochasticWeightAveraging::new(2, 0.1).with_anneal_epochs(2);
+        let mut scheduler = 1.0;
+
+        assert!((swa.lr_scheduler(&mut scheduler, 2).step() - 0.55).abs() < 1e-9);
+        assert_eq!(swa.lr_scheduler(&mut scheduler, 3).step(), 0.1);
+        assert_eq!(swa.lr_scheduler(&mut scheduler, 4).step(), 0.1);
+    }
+
+    struct ParamsFiller(f32);
+
+    impl<B: Backend> ModuleMapper<B> for ParamsFiller {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: &ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            tensor.zeros_like().add_scalar(self.0)
+        }
+    }
+}
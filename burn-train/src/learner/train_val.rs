@@ -1,12 +1,44 @@
+use crate::checkpoint::TrainingStateRecord;
 use crate::components::LearnerComponents;
+use crate::learner::swa::{update_batch_norm, SwaAverage};
+use crate::learner::{CallbackProgress, LearnerCallbacks};
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::profiler::{scope, Phase, Profiler};
+use crate::{Learner, TrainEpoch, TrainingInterrupter, ValidEpoch, ValidationHook};
 use burn_core::data::dataloader::DataLoader;
+use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::{AutodiffModule, Module};
-use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::optim::{GradScaler, GradientsParams, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+use std::cell::RefCell;
 use std::sync::Arc;
 
+thread_local! {
+    /// The gradient scaler of the learner executing the training step on the current thread.
+    static GRAD_SCALER: RefCell<Option<GradScaler>> = const { RefCell::new(None) };
+}
+
+/// Executes the training step with the gradient scaler used by [TrainOutput::from_loss].
+pub(crate) fn with_grad_scaler<O>(grad_scaler: Option<&GradScaler>, func: impl FnOnce() -> O) -> O {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            GRAD_SCALER.with(|scaler| scaler.borrow_mut().take());
+        }
+    }
+
+    let grad_scaler = match grad_scaler {
+        Some(grad_scaler) => grad_scaler.clone(),
+        None => return func(),
+    };
+    GRAD_SCALER.with(|scaler| *scaler.borrow_mut() = Some(grad_scaler));
+    let _guard = Guard;
+
+    func()
+}
+
 /// A training output.
 pub struct TrainOutput<TO> {
     /// The gradients.
@@ -36,6 +68,37 @@ impl<TO> TrainOutput<TO> {
         let grads = GradientsParams::from_grads(grads, module);
         Self { grads, item }
     }
+
+    /// Creates a new training output from the loss, executing the backward pass.
+    ///
+    /// When the learner trains in [mixed precision](crate::LearnerBuilder::mixed_precision), the
+    /// loss is multiplied by the scale of the gradient scaler before the backward pass.
+    /// When the training is [profiled](crate::LearnerBuilder::profile), the backward pass is
+    /// measured separately from the rest of the step.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module.
+    /// * `loss` - The loss.
+    /// * `item` - The item.
+    ///
+    /// # Returns
+    ///
+    /// A new training output.
+    pub fn from_loss<B: AutodiffBackend, M: AutodiffModule<B>, const D: usize>(
+        module: &M,
+        loss: Tensor<B, D>,
+        item: TO,
+    ) -> Self {
+        let loss = GRAD_SCALER.with(|scaler| match scaler.borrow().as_ref() {
+            Some(scaler) => scaler.scale_loss(loss),
+            None => loss,
+        });
+
+        let grads = scope(Phase::Backward, || loss.backward());
+
+        Self::new(module, grads, item)
+    }
 }
 
 /// Trait to be implemented for training models.
@@ -128,65 +191,141 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
+        let mut swa_average = SwaAverage::<LC::Backend, LC::Model>::new();
         let starting_epoch = match self.checkpoint {
             Some(checkpoint) => {
                 if let Some(checkpointer) = &mut self.checkpointer {
+                    if let Some(swa) = self.swa.as_ref().filter(|swa| swa.is_active(checkpoint)) {
+                        if let Some(model) =
+                            checkpointer.load_swa_model(self.model.clone(), checkpoint)
+                        {
+                            swa_average.resume(&model, swa.num_averaged(checkpoint));
+                        }
+                    }
+
                     (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
                         self.model,
                         self.optim,
                         self.lr_scheduler,
                         checkpoint,
                     );
+
+                    if let Some(state) = checkpointer.load_training_state(checkpoint) {
+                        self.callbacks.resume(state.iteration, state.lr_factor);
+                        if let Some(seed) = state.seed.filter(|seed| Some(*seed) != self.seed) {
+                            log::warn!("Resuming with the seed {seed} of the checkpoint");
+                            self.seed = Some(seed);
+                        }
+                    }
                 }
                 checkpoint + 1
             }
             None => 1,
         };
 
+        let mut last_epoch = starting_epoch.saturating_sub(1);
+        self.callbacks.on_train_begin(
+            &mut self.model,
+            CallbackProgress::new(starting_epoch, self.num_epochs, 0, None),
+        );
+
         for epoch in starting_epoch..self.num_epochs + 1 {
+            if let Some(seed) = self.seed {
+                // Derived from the epoch, so resuming doesn't replay the random numbers of the
+                // first epoch.
+                <LC::Backend as Backend>::seed_all(seed.wrapping_add(epoch as u64));
+            }
+
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
+                self.valid_every,
+            )
+            .with_summed_grads(self.sum_accumulated_grads)
+            .with_grad_scaler(self.grad_scaler.clone());
+            let epoch_valid = ValidEpoch::new(
+                dataloader_valid.clone(),
+                epoch,
+                self.num_epochs,
+                self.valid_batches,
             );
+            let mut validate = |model: &LC::Model, processor: &mut LC::EventProcessor| {
+                epoch_valid.run_during_epoch::<LC, OutputValid>(model, processor, &self.interrupter)
+            };
+            let swa = self.swa.as_ref().filter(|swa| swa.is_active(epoch));
 
-            if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
+            (self.model, self.optim) = match swa {
+                Some(swa) => run_train_epoch::<LC, _, _, _>(
+                    &epoch_train,
                     self.model,
                     self.optim,
-                    &mut self.lr_scheduler,
+                    &mut swa.lr_scheduler(&mut self.lr_scheduler, epoch),
                     &mut self.event_processor,
-                    self.devices.clone(),
+                    &mut self.callbacks,
+                    &self.devices,
                     &self.interrupter,
-                )
-            } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
+                    self.profiler.as_mut(),
+                    &mut validate,
+                ),
+                None => run_train_epoch::<LC, _, _, _>(
+                    &epoch_train,
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
+                    &mut self.callbacks,
+                    &self.devices,
                     &self.interrupter,
-                );
+                    self.profiler.as_mut(),
+                    &mut validate,
+                ),
+            };
+
+            if swa.is_some() {
+                swa_average.update(&self.model);
             }
+            last_epoch = epoch;
+
+            let progress = CallbackProgress::new(epoch, self.num_epochs, 0, None);
+            self.callbacks.on_epoch_end(&mut self.model, progress);
 
             if self.interrupter.should_stop() {
                 break;
             }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
             epoch_valid.run::<LC, OutputValid>(
                 &self.model,
                 &mut self.event_processor,
                 &self.interrupter,
             );
+            self.callbacks.on_valid_end(&mut self.model, progress);
+
+            if let Some(metric) = &self.lr_scheduler_metric {
+                match self.event_store.find_metric(
+                    &metric.name,
+                    epoch,
+                    metric.aggregate,
+                    metric.split,
+                ) {
+                    Some(value) => self.lr_scheduler.report_metric(value),
+                    None => log::warn!("Can't find metric for the learning rate scheduler."),
+                }
+            }
 
             if let Some(checkpointer) = &mut self.checkpointer {
                 checkpointer.checkpoint(
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
-                    epoch,
+                    TrainingStateRecord::new(
+                        epoch,
+                        self.callbacks.total_iterations(),
+                        self.seed,
+                        self.callbacks.lr_factor(),
+                    ),
+                    swa_average.averaged(&self.model),
                     &self.event_store,
                 );
             }
@@ -196,8 +335,128 @@ impl<LC: LearnerComponents> Learner<LC> {
                     break;
                 }
             }
+
+            if self.interrupter.should_stop() {
+                break;
+            }
+        }
+
+        if let (Some(metric), None) = (&self.best_model_metric, &self.swa) {
+            match (
+                &self.checkpointer,
+                self.event_store.find_epoch(
+                    &metric.name,
+                    metric.aggregate,
+                    metric.direction,
+                    metric.split,
+                ),
+            ) {
+                (Some(checkpointer), Some(epoch)) => {
+                    log::info!("Restoring the model of the best epoch {}", epoch);
+                    self.model = checkpointer.load_model(self.model, epoch);
+                }
+                (None, _) => log::warn!("Can't restore the best model without a checkpointer."),
+                (_, None) => log::warn!("Can't find metric to restore the best model."),
+            }
+        }
+
+        if let Some(swa) = &self.swa {
+            self.model = swa_average.apply(self.model);
+
+            if swa.update_bn() {
+                log::info!("Recomputing the batch norm statistics with the averaged weights");
+                self.model = update_batch_norm(self.model, dataloader_train.as_ref());
+            }
+
+            if let Some(checkpointer) = &self.checkpointer {
+                checkpointer.save_swa_model(last_epoch, self.model.clone());
+            }
         }
 
+        if let Some(profiler) = &mut self.profiler {
+            profiler.finish();
+        }
+
+        self.callbacks.on_train_end(
+            &mut self.model,
+            CallbackProgress::new(self.num_epochs, self.num_epochs, 0, None),
+        );
+
         self.model
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+fn run_train_epoch<LC, S, TI, TO>(
+    epoch: &TrainEpoch<TI>,
+    model: LC::Model,
+    optim: LC::Optimizer,
+    lr_scheduler: &mut S,
+    event_processor: &mut LC::EventProcessor,
+    callbacks: &mut LearnerCallbacks<LC::Model>,
+    devices: &[<LC::Backend as Backend>::Device],
+    interrupter: &TrainingInterrupter,
+    profiler: Option<&mut Profiler<LC::Backend>>,
+    validate: ValidationHook<'_, LC::Model, LC::EventProcessor>,
+) -> (LC::Model, LC::Optimizer)
+where
+    LC: LearnerComponents,
+    S: LrScheduler,
+    TI: Send + 'static,
+    TO: Send + 'static,
+    LC::Model: TrainStep<TI, TO>,
+    LC::EventProcessor: EventProcessor<ItemTrain = TO>,
+{
+    if devices.len() > 1 {
+        epoch.run_multi_device::<LC, TO, S>(
+            model,
+            optim,
+            lr_scheduler,
+            event_processor,
+            callbacks,
+            devices.to_vec(),
+            interrupter,
+            validate,
+        )
+    } else {
+        epoch.run::<LC, TO, S>(
+            model,
+            optim,
+            lr_scheduler,
+            event_processor,
+            callbacks,
+            interrupter,
+            profiler,
+            validate,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_autodiff::Autodiff;
+    use burn_core::nn::LinearConfig;
+    use burn_core::optim::GradScalerConfig;
+
+    type TestAutodiffBackend = Autodiff<TestBackend>;
+
+    #[test]
+    fn test_loss_scaled_by_the_grad_scaler_of_the_learner() {
+        let linear = LinearConfig::new(2, 2).init::<TestAutodiffBackend>();
+        let grad_scaler = GradScalerConfig::new().with_init_scale(4.0).init();
+        let loss = || linear.forward(Tensor::ones([1, 2])).sum();
+
+        let output = TrainOutput::from_loss(&linear, loss(), ());
+        let output_scaled = with_grad_scaler(Some(&grad_scaler), || {
+            TrainOutput::from_loss(&linear, loss(), ())
+        });
+
+        let norm = output.grads.norm(&linear).unwrap();
+        let norm_scaled = output_scaled.grads.norm(&linear).unwrap();
+        norm_scaled
+            .to_data()
+            .assert_approx_eq(&norm.mul_scalar(4.0).to_data(), 3);
+    }
+}
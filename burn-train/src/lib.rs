@@ -19,6 +19,13 @@ pub mod logger;
 /// The metric module.
 pub mod metric;
 
+/// The profiler module.
+pub mod profiler;
+
+/// The hyperparameter tuner module.
+#[cfg(feature = "tuner")]
+pub mod tuner;
+
 mod learner;
 
 pub use learner::*;
@@ -1,5 +1,5 @@
 use super::{AsyncLogger, FileLogger, InMemoryLogger, Logger};
-use crate::metric::MetricEntry;
+use crate::metric::{store::MetricsUpdate, MetricEntry};
 use std::collections::HashMap;
 
 /// Metric logger.
@@ -11,6 +11,14 @@ pub trait MetricLogger: Send {
     /// * `item` - The item.
     fn log(&mut self, item: &MetricEntry);
 
+    /// Logs all the entries of a training or validation step.
+    ///
+    /// By default, each entry is logged with [log](MetricLogger::log), so only loggers writing
+    /// the entries of a step together have to implement it.
+    fn log_step(&mut self, update: &MetricsUpdate) {
+        update.iter_entries().for_each(|entry| self.log(entry));
+    }
+
     /// Logs an epoch.
     ///
     /// # Arguments
@@ -3,9 +3,19 @@ mod base;
 mod file;
 mod in_memory;
 mod metric;
+#[cfg(feature = "structured")]
+mod structured;
+mod tensorboard;
+#[cfg(feature = "tracking")]
+mod tracking;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use in_memory::*;
 pub use metric::*;
+#[cfg(feature = "structured")]
+pub use structured::*;
+pub use tensorboard::*;
+#[cfg(feature = "tracking")]
+pub use tracking::*;
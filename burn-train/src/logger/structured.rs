@@ -0,0 +1,396 @@
+use super::MetricLogger;
+use crate::metric::{store::MetricsUpdate, MetricEntry};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The file format of the [structured metric logger](StructuredMetricLogger).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructuredLogFormat {
+    /// One CSV row per step with the `timestamp`, `epoch` and `iteration` columns followed by one
+    /// column per metric.
+    Csv,
+    /// One JSON object per line and step with the `timestamp`, `epoch`, `iteration` and
+    /// `metrics` fields.
+    Jsonl,
+}
+
+/// Metric logger writing one row per training or validation step with the epoch, the iteration,
+/// the value of each metric and the Unix timestamp in seconds.
+///
+/// The logger is resume-safe: when the file already exists, the rows are appended to it, after
+/// removing the last row if it was only partially written and the rows of the epochs that are
+/// logged again, e.g. when resuming the training from the checkpoint of an earlier epoch.
+pub struct StructuredMetricLogger {
+    path: PathBuf,
+    format: StructuredLogFormat,
+    // The metric names of the CSV columns.
+    columns: Option<Vec<String>>,
+    // The rows of the existing file, kept until the first step to remove the epochs logged again.
+    existing_rows: Vec<(usize, String)>,
+    values: HashMap<usize, HashMap<String, Vec<f64>>>,
+    writer: Option<BufWriter<File>>,
+}
+
+impl StructuredMetricLogger {
+    /// Create a new structured metric logger, appending to the file if it exists.
+    pub fn new<P: AsRef<Path>>(path: P, format: StructuredLogFormat) -> Self {
+        let mut logger = Self {
+            path: path.as_ref().to_path_buf(),
+            format,
+            columns: None,
+            existing_rows: Vec::new(),
+            values: HashMap::new(),
+            writer: None,
+        };
+
+        if let Ok(content) = std::fs::read_to_string(&logger.path) {
+            logger.load(&content);
+        }
+
+        logger
+    }
+
+    /// Create a new CSV metric logger, appending to the file if it exists.
+    pub fn csv<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(path, StructuredLogFormat::Csv)
+    }
+
+    /// Create a new JSON lines metric logger, appending to the file if it exists.
+    pub fn jsonl<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(path, StructuredLogFormat::Jsonl)
+    }
+
+    fn load(&mut self, content: &str) {
+        // A row without its line ending was interrupted while being written.
+        let content = match content.rfind('\n') {
+            Some(end) => &content[..end + 1],
+            None => "",
+        };
+        let mut lines = content.lines();
+
+        if self.format == StructuredLogFormat::Csv {
+            match lines.next() {
+                Some(header) => self.columns = Some(parse_csv(header).split_off(3)),
+                None => return,
+            }
+        }
+
+        for line in lines {
+            match self.parse_row(line) {
+                Some((epoch, values)) => {
+                    let epoch_values = self.values.entry(epoch).or_default();
+                    for (name, value) in values {
+                        epoch_values.entry(name).or_default().push(value);
+                    }
+                    self.existing_rows.push((epoch, line.to_string()));
+                }
+                None => log::warn!("Skipping the invalid metrics row: {line}"),
+            }
+        }
+    }
+
+    /// Parse the epoch and the numeric values of a row.
+    fn parse_row(&self, line: &str) -> Option<(usize, Vec<(String, f64)>)> {
+        match self.format {
+            StructuredLogFormat::Csv => {
+                let fields = parse_csv(line);
+                let epoch = fields.get(1)?.parse().ok()?;
+                let values = self
+                    .columns
+                    .iter()
+                    .flatten()
+                    .zip(fields.iter().skip(3))
+                    .filter_map(|(name, value)| Some((name.clone(), value.parse().ok()?)))
+                    .collect();
+
+                Some((epoch, values))
+            }
+            StructuredLogFormat::Jsonl => {
+                let row: Value = serde_json::from_str(line).ok()?;
+                let epoch = row["epoch"].as_u64()? as usize;
+                let values = row["metrics"]
+                    .as_object()?
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.clone(), value.as_f64()?)))
+                    .collect();
+
+                Some((epoch, values))
+            }
+        }
+    }
+
+    /// Remove the rows of the epochs logged again, then open the file to append the new rows.
+    ///
+    /// The file is always rewritten with the kept rows, which also truncates it to its last
+    /// complete line, so a new row is never appended to a partially written one.
+    fn open(&mut self, epoch: usize, entries: &[&MetricEntry]) -> std::io::Result<BufWriter<File>> {
+        self.existing_rows
+            .retain(|(row_epoch, _line)| *row_epoch < epoch);
+        self.values.retain(|row_epoch, _values| *row_epoch < epoch);
+
+        if self.format == StructuredLogFormat::Csv && self.columns.is_none() {
+            self.columns = Some(entries.iter().map(|entry| entry.name.clone()).collect());
+        }
+
+        if let Some(directory) = self.path.parent() {
+            std::fs::create_dir_all(directory).ok();
+        }
+
+        // Write the kept rows in a temporary file replacing the existing one, so that they aren't
+        // lost if interrupted.
+        let path_tmp = self.path.with_extension("tmp");
+        let mut file = BufWriter::new(File::create(&path_tmp)?);
+        if let Some(columns) = &self.columns {
+            let header = ["timestamp", "epoch", "iteration"]
+                .into_iter()
+                .map(String::from)
+                .chain(columns.iter().cloned())
+                .collect::<Vec<_>>();
+            writeln!(file, "{}", format_csv(&header))?;
+        }
+        for (_epoch, line) in self.existing_rows.iter() {
+            writeln!(file, "{line}")?;
+        }
+        file.flush()?;
+        std::fs::rename(path_tmp, &self.path)?;
+
+        self.existing_rows.clear();
+
+        let file = OpenOptions::new().append(true).open(&self.path)?;
+        Ok(BufWriter::new(file))
+    }
+
+    fn format_row(&self, update: &MetricsUpdate, entries: &[&MetricEntry]) -> String {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs_f64())
+            .unwrap_or_default();
+
+        match self.format {
+            StructuredLogFormat::Csv => {
+                let mut fields = vec![
+                    timestamp.to_string(),
+                    update.epoch.to_string(),
+                    update.iteration.to_string(),
+                ];
+                for name in self.columns.iter().flatten() {
+                    let value = entries
+                        .iter()
+                        .find(|entry| &entry.name == name)
+                        .map(|entry| entry.serialize.clone());
+                    fields.push(value.unwrap_or_default());
+                }
+
+                format_csv(&fields)
+            }
+            StructuredLogFormat::Jsonl => {
+                let metrics = entries
+                    .iter()
+                    .map(|entry| {
+                        let value = match entry.serialize.parse::<f64>() {
+                            Ok(value) if value.is_finite() => Value::from(value),
+                            _ => Value::from(entry.serialize.clone()),
+                        };
+                        (entry.name.clone(), value)
+                    })
+                    .collect::<Map<_, _>>();
+
+                serde_json::json!({
+                    "timestamp": timestamp,
+                    "epoch": update.epoch,
+                    "iteration": update.iteration,
+                    "metrics": metrics,
+                })
+                .to_string()
+            }
+        }
+    }
+}
+
+impl MetricLogger for StructuredMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        // The entries are written together by step, see `log_step`.
+        log::warn!(
+            "The structured metric logger only logs full steps, skipping {}",
+            item.name
+        );
+    }
+
+    fn log_step(&mut self, update: &MetricsUpdate) {
+        let entries = update.iter_entries().collect::<Vec<_>>();
+
+        if self.writer.is_none() {
+            match self.open(update.epoch, &entries) {
+                Ok(writer) => self.writer = Some(writer),
+                Err(err) => {
+                    log::error!("Can't open the metrics file {:?}: {err}", self.path);
+                    return;
+                }
+            }
+        }
+
+        if let Some(columns) = &self.columns {
+            for entry in entries.iter() {
+                if !columns.contains(&entry.name) {
+                    log::warn!("The metric {} isn't a column of the CSV file", entry.name);
+                }
+            }
+        }
+
+        let row = self.format_row(update, &entries);
+        let writer = self.writer.as_mut().unwrap();
+        // Flushing each row keeps the file complete if the training is interrupted.
+        if let Err(err) = writeln!(writer, "{row}").and_then(|_| writer.flush()) {
+            log::error!("Can't write the metrics row: {err}");
+        }
+
+        let values = self.values.entry(update.epoch).or_default();
+        for entry in entries {
+            if let Ok(value) = entry.serialize.parse::<f64>() {
+                values.entry(entry.name.clone()).or_default().push(value);
+            }
+        }
+    }
+
+    fn end_epoch(&mut self, _epoch: usize) {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush().ok();
+        }
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        Ok(self
+            .values
+            .get(&epoch)
+            .and_then(|values| values.get(name))
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+fn format_csv(fields: &[String]) -> String {
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    writer.write_record(fields).ok();
+
+    let line = writer
+        .into_inner()
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+        .unwrap_or_default();
+    line.trim_end_matches('\n').to_string()
+}
+
+fn parse_csv(line: &str) -> Vec<String> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(line.as_bytes())
+        .records()
+        .next()
+        .and_then(|record| record.ok())
+        .map(|record| record.iter().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(epoch: usize, iteration: usize, loss: f64) -> MetricsUpdate {
+        let entry = |name: &str, value: String| MetricEntry::new(name.into(), value.clone(), value);
+
+        MetricsUpdate::new(
+            vec![entry("Device", "GPU, 0".to_string())],
+            vec![(entry("Loss", loss.to_string()), loss)],
+            epoch,
+            iteration,
+        )
+    }
+
+    fn test_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "burn-structured-logger-{}-{name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let path = test_path("rows.csv");
+        let mut logger = StructuredMetricLogger::csv(&path);
+
+        logger.log_step(&update(1, 1, 0.5));
+        logger.log_step(&update(1, 2, 0.25));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let rows = content.lines().map(parse_csv).collect::<Vec<_>>();
+
+        assert_eq!(
+            rows[0],
+            ["timestamp", "epoch", "iteration", "Device", "Loss"]
+        );
+        assert_eq!(rows[2][1..], ["1", "2", "GPU, 0", "0.25"]);
+        assert_eq!(logger.read_numeric("Loss", 1), Ok(vec![0.5, 0.25]));
+    }
+
+    #[test]
+    fn test_resume_removes_partial_rows_and_epochs_logged_again() {
+        for format in [StructuredLogFormat::Csv, StructuredLogFormat::Jsonl] {
+            let path = test_path(&format!("resume-{format:?}"));
+            let mut logger = StructuredMetricLogger::new(&path, format);
+            logger.log_step(&update(1, 1, 0.5));
+            logger.log_step(&update(2, 1, 0.4));
+            drop(logger);
+
+            // Interrupted while writing a row.
+            let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+            write!(file, "{{\"timestamp\": 1").unwrap();
+            drop(file);
+
+            // Resumed from the checkpoint of the first epoch.
+            let mut logger = StructuredMetricLogger::new(&path, format);
+            assert_eq!(logger.read_numeric("Loss", 2), Ok(vec![0.4]));
+            logger.log_step(&update(2, 1, 0.3));
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            std::fs::remove_file(&path).ok();
+
+            let num_header_lines = match format {
+                StructuredLogFormat::Csv => 1,
+                StructuredLogFormat::Jsonl => 0,
+            };
+            assert_eq!(content.lines().count(), num_header_lines + 2);
+            assert_eq!(logger.read_numeric("Loss", 1), Ok(vec![0.5]));
+            assert_eq!(logger.read_numeric("Loss", 2), Ok(vec![0.3]));
+        }
+    }
+
+    #[test]
+    fn test_resume_truncates_the_partial_row_when_keeping_all_rows() {
+        let path = test_path("partial.jsonl");
+        let mut logger = StructuredMetricLogger::jsonl(&path);
+        logger.log_step(&update(1, 1, 0.5));
+        drop(logger);
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"timestamp\": 1").unwrap();
+        drop(file);
+
+        // Resumed at the next epoch, so no row is removed.
+        let mut logger = StructuredMetricLogger::jsonl(&path);
+        logger.log_step(&update(2, 1, 0.4));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(content.lines().count(), 2);
+        assert!(content
+            .lines()
+            .all(|line| serde_json::from_str::<Value>(line).is_ok()));
+    }
+}
@@ -0,0 +1,620 @@
+use super::{InMemoryMetricLogger, MetricLogger};
+use crate::learner::{LearnerCallback, LearnerState};
+use crate::metric::MetricEntry;
+use burn_core::module::{AutodiffModule, Module, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The number of buckets of the histograms.
+const NUM_BUCKETS: usize = 30;
+
+/// Writer of TensorBoard event files, readable with `tensorboard --logdir <directory>`.
+///
+/// The writer can be cloned to share the same event file, e.g. between the
+/// [metric logger](TensorBoardMetricLogger) and the [callback](TensorBoardCallback).
+#[derive(Clone)]
+pub struct TensorBoardWriter {
+    file: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl TensorBoardWriter {
+    /// Create a new event file in the given directory, which is created if needed.
+    ///
+    /// Each training split should be written in its own directory, e.g. `tensorboard/train` and
+    /// `tensorboard/valid`, so that TensorBoard displays them as different runs.
+    pub fn new(directory: &str) -> Self {
+        static NUM_FILES: AtomicUsize = AtomicUsize::new(0);
+
+        std::fs::create_dir_all(directory).ok();
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let file_path = format!(
+            "{directory}/events.out.tfevents.{timestamp}.burn.{}.{}",
+            std::process::id(),
+            NUM_FILES.fetch_add(1, Ordering::Relaxed)
+        );
+        let file = File::create(&file_path).unwrap_or_else(|err| {
+            panic!("Should be able to create the event file {file_path}: {err}")
+        });
+
+        let writer = Self {
+            file: Arc::new(Mutex::new(BufWriter::new(file))),
+        };
+
+        let mut event = ProtoEncoder::default();
+        event.double(1, wall_time());
+        event.string(3, "brain.Event:2");
+        writer.write_record(&event.bytes);
+        writer.flush();
+
+        writer
+    }
+
+    /// Write a scalar value.
+    pub fn add_scalar(&self, tag: &str, value: f64, step: usize) {
+        let mut value_proto = ProtoEncoder::default();
+        value_proto.string(1, tag);
+        value_proto.float(2, value as f32);
+
+        self.write_summary(&value_proto, step);
+    }
+
+    /// Write the histogram of the given values.
+    pub fn add_histogram(&self, tag: &str, values: &[f64], step: usize) {
+        if values.is_empty() {
+            return;
+        }
+
+        let histogram = Histogram::new(values);
+
+        let mut value_proto = ProtoEncoder::default();
+        value_proto.string(1, tag);
+        value_proto.message(5, &histogram.encode());
+
+        self.write_summary(&value_proto, step);
+    }
+
+    /// Write the histograms of the float parameters of a module, tagged with their path in the
+    /// module, e.g. `params/encoder.linear.weight`.
+    pub fn add_parameter_histograms<B: Backend, M: Module<B>>(&self, module: &M, step: usize) {
+        let mut visitor = ParamHistograms {
+            path: ModulePath::default(),
+            writer: self,
+            step,
+        };
+        module.visit(&mut visitor);
+        self.flush();
+    }
+
+    /// Write the histograms of the gradients of the float parameters of a module, tagged with
+    /// their path in the module, e.g. `grads/encoder.linear.weight`.
+    ///
+    /// The gradients are only available before the optimizer step, so this should be called
+    /// there, e.g. by the [callback](TensorBoardCallback) every few hundred iterations.
+    pub fn add_gradient_histograms<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        grads: &GradientsParams,
+        step: usize,
+    ) {
+        let mut visitor = GradHistograms {
+            path: ModulePath::default(),
+            writer: self,
+            grads,
+            step,
+        };
+        module.visit(&mut visitor);
+        self.flush();
+    }
+
+    /// Flush the events to the file.
+    pub fn flush(&self) {
+        self.file.lock().unwrap().flush().ok();
+    }
+
+    fn write_summary(&self, value: &ProtoEncoder, step: usize) {
+        let mut summary = ProtoEncoder::default();
+        summary.message(1, &value.bytes);
+
+        let mut event = ProtoEncoder::default();
+        event.double(1, wall_time());
+        event.varint(2, step as u64);
+        event.message(5, &summary.bytes);
+
+        self.write_record(&event.bytes);
+    }
+
+    /// Write a record in the TFRecord format: the length, its masked CRC, the data and its
+    /// masked CRC.
+    fn write_record(&self, data: &[u8]) {
+        let length = (data.len() as u64).to_le_bytes();
+
+        let mut file = self.file.lock().unwrap();
+        file.write_all(&length)
+            .and_then(|_| file.write_all(&masked_crc32c(&length).to_le_bytes()))
+            .and_then(|_| file.write_all(data))
+            .and_then(|_| file.write_all(&masked_crc32c(data).to_le_bytes()))
+            .unwrap_or_else(|err| log::error!("Failed to write a TensorBoard event: {err}"));
+    }
+}
+
+/// Numeric metric logger writing the values in TensorBoard event files.
+///
+/// Each value is written under the metric name with the number of values logged so far for this
+/// metric as the step. Non numeric metrics are ignored.
+pub struct TensorBoardMetricLogger {
+    writer: TensorBoardWriter,
+    steps: HashMap<String, usize>,
+    // The event files can't be read back, so the values are also kept to be aggregated.
+    values: InMemoryMetricLogger,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger writing in the given directory.
+    pub fn new(directory: &str) -> Self {
+        Self::from_writer(TensorBoardWriter::new(directory))
+    }
+
+    /// Create a new TensorBoard metric logger using the given writer.
+    pub fn from_writer(writer: TensorBoardWriter) -> Self {
+        Self {
+            writer,
+            steps: HashMap::new(),
+            values: InMemoryMetricLogger::new(),
+        }
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        self.values.log(item);
+
+        if let Ok(value) = item.serialize.parse::<f64>() {
+            let step = self.steps.entry(item.name.clone()).or_insert(0);
+            *step += 1;
+            self.writer.add_scalar(&item.name, value, *step);
+        }
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.values.end_epoch(epoch);
+        self.writer.flush();
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+}
+
+/// [Callback](LearnerCallback) writing in TensorBoard event files:
+///   - The learning rate of each training iteration, tagged `learning_rate`.
+///   - The histograms of the gradients every few hundred iterations.
+///   - The histograms of the parameters of the model at the end of each epoch.
+///
+/// The learning rate and the gradients are written with the number of training iterations as the
+/// step, and the parameters with the epoch.
+pub struct TensorBoardCallback<B> {
+    writer: TensorBoardWriter,
+    grads_every: usize,
+    grads_last: usize,
+    _b: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> TensorBoardCallback<B> {
+    /// Create a new callback writing in the given directory.
+    pub fn new(directory: &str) -> Self {
+        Self::from_writer(TensorBoardWriter::new(directory))
+    }
+
+    /// Create a new callback using the given writer.
+    pub fn from_writer(writer: TensorBoardWriter) -> Self {
+        Self {
+            writer,
+            grads_every: 100,
+            grads_last: 0,
+            _b: PhantomData,
+        }
+    }
+
+    /// Write the histograms of the gradients at most once every given number of iterations, 100
+    /// by default.
+    pub fn with_gradient_histograms_every(mut self, num_iterations: usize) -> Self {
+        self.grads_every = num_iterations;
+        self
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> LearnerCallback<M> for TensorBoardCallback<B> {
+    fn on_optimizer_step(&mut self, state: &mut LearnerState<M>, grads: &GradientsParams) {
+        if state.total_iterations < self.grads_last + self.grads_every {
+            return;
+        }
+
+        self.grads_last = state.total_iterations;
+        self.writer
+            .add_gradient_histograms::<B, M>(state.model, grads, state.total_iterations);
+    }
+
+    fn on_batch_end(&mut self, state: &mut LearnerState<M>) {
+        if let Some(lr) = state.lr {
+            self.writer
+                .add_scalar("learning_rate", lr, state.total_iterations);
+        }
+    }
+
+    fn on_epoch_end(&mut self, state: &mut LearnerState<M>) {
+        self.writer
+            .add_parameter_histograms::<B, M>(state.model, state.epoch);
+    }
+}
+
+/// The path of the visited parameter in the module.
+#[derive(Default)]
+struct ModulePath {
+    names: Vec<String>,
+}
+
+impl ModulePath {
+    fn tag(&self, prefix: &str) -> String {
+        format!("{prefix}/{}", self.names.join("."))
+    }
+}
+
+struct ParamHistograms<'a> {
+    path: ModulePath,
+    writer: &'a TensorBoardWriter,
+    step: usize,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamHistograms<'_> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.names.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.names.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        let values = tensor_values(tensor.clone());
+        self.writer
+            .add_histogram(&self.path.tag("params"), &values, self.step);
+    }
+}
+
+struct GradHistograms<'a> {
+    path: ModulePath,
+    writer: &'a TensorBoardWriter,
+    grads: &'a GradientsParams,
+    step: usize,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradHistograms<'_> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.names.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.names.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let values = tensor_values(grad);
+            self.writer
+                .add_histogram(&self.path.tag("grads"), &values, self.step);
+        }
+    }
+}
+
+/// A histogram in the TensorBoard format.
+struct Histogram {
+    min: f64,
+    max: f64,
+    num: f64,
+    sum: f64,
+    sum_squares: f64,
+    bucket_limits: Vec<f64>,
+    buckets: Vec<f64>,
+}
+
+impl Histogram {
+    fn new(values: &[f64]) -> Self {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let width = (max - min) / NUM_BUCKETS as f64;
+
+        let mut buckets = vec![0.0; NUM_BUCKETS];
+        for value in values {
+            let index = match width > 0.0 {
+                true => (((value - min) / width) as usize).min(NUM_BUCKETS - 1),
+                false => NUM_BUCKETS - 1,
+            };
+            buckets[index] += 1.0;
+        }
+
+        // The limits are the right edges of the buckets.
+        let bucket_limits = (1..=NUM_BUCKETS)
+            .map(|i| match i {
+                NUM_BUCKETS => max,
+                _ => min + width * i as f64,
+            })
+            .collect();
+
+        Self {
+            min,
+            max,
+            num: values.len() as f64,
+            sum: values.iter().sum(),
+            sum_squares: values.iter().map(|value| value * value).sum(),
+            bucket_limits,
+            buckets,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut histogram = ProtoEncoder::default();
+        histogram.double(1, self.min);
+        histogram.double(2, self.max);
+        histogram.double(3, self.num);
+        histogram.double(4, self.sum);
+        histogram.double(5, self.sum_squares);
+        histogram.packed_doubles(6, &self.bucket_limits);
+        histogram.packed_doubles(7, &self.buckets);
+        histogram.bytes
+    }
+}
+
+/// Minimal protocol buffers encoder for the TensorBoard event messages.
+#[derive(Default)]
+struct ProtoEncoder {
+    bytes: Vec<u8>,
+}
+
+impl ProtoEncoder {
+    const VARINT: u64 = 0;
+    const FIXED64: u64 = 1;
+    const LENGTH_DELIMITED: u64 = 2;
+    const FIXED32: u64 = 5;
+
+    fn key(&mut self, field: u64, wire_type: u64) {
+        self.raw_varint((field << 3) | wire_type);
+    }
+
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn varint(&mut self, field: u64, value: u64) {
+        self.key(field, Self::VARINT);
+        self.raw_varint(value);
+    }
+
+    fn double(&mut self, field: u64, value: f64) {
+        self.key(field, Self::FIXED64);
+        self.bytes.extend(value.to_le_bytes());
+    }
+
+    fn float(&mut self, field: u64, value: f32) {
+        self.key(field, Self::FIXED32);
+        self.bytes.extend(value.to_le_bytes());
+    }
+
+    fn message(&mut self, field: u64, bytes: &[u8]) {
+        self.key(field, Self::LENGTH_DELIMITED);
+        self.raw_varint(bytes.len() as u64);
+        self.bytes.extend(bytes);
+    }
+
+    fn string(&mut self, field: u64, value: &str) {
+        self.message(field, value.as_bytes());
+    }
+
+    fn packed_doubles(&mut self, field: u64, values: &[f64]) {
+        let bytes = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect::<Vec<_>>();
+        self.message(field, &bytes);
+    }
+}
+
+fn tensor_values<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Vec<f64> {
+    tensor.into_data().convert::<f64>().value
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+/// The CRC-32C (Castagnoli) checksum, masked as done by TensorFlow.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    crc.rotate_right(15).wrapping_add(0xa282ead8)
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0x82F63B78,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::{EventStoreClient, LogEventStore};
+    use crate::{TestBackend, TrainingInterrupter};
+    use burn_autodiff::Autodiff;
+    use burn_core::nn::LinearConfig;
+
+    #[test]
+    fn test_crc32c() {
+        assert_eq!(crc32c(b"123456789"), 0xE3069283);
+    }
+
+    #[test]
+    fn test_encode_scalar_event() {
+        let mut value = ProtoEncoder::default();
+        value.string(1, "a");
+        value.float(2, 0.5);
+        let mut summary = ProtoEncoder::default();
+        summary.message(1, &value.bytes);
+        let mut event = ProtoEncoder::default();
+        event.double(1, 1.0);
+        event.varint(2, 2);
+        event.message(5, &summary.bytes);
+
+        assert_eq!(
+            event.bytes,
+            vec![
+                0x09, 0, 0, 0, 0, 0, 0, 0xF0, 0x3F, // wall_time
+                0x10, 0x02, // step
+                0x2A, 0x0A, 0x0A, 0x08, // summary, value
+                0x0A, 0x01, 0x61, // tag
+                0x15, 0, 0, 0, 0x3F, // simple_value
+            ]
+        );
+    }
+
+    #[test]
+    fn test_histogram_buckets() {
+        let histogram = Histogram::new(&[0.0, 1.0, 2.0, 3.0]);
+
+        assert_eq!(histogram.num, 4.0);
+        assert_eq!(histogram.sum_squares, 14.0);
+        assert_eq!(histogram.buckets.iter().sum::<f64>(), 4.0);
+        assert_eq!(histogram.buckets[0], 1.0);
+        assert_eq!(histogram.buckets[NUM_BUCKETS - 1], 1.0);
+        assert_eq!(*histogram.bucket_limits.last().unwrap(), 3.0);
+    }
+
+    /// Read the records of the event file in the directory, checking their framing.
+    fn read_records(directory: &str) -> Vec<Vec<u8>> {
+        let file = std::fs::read_dir(directory)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let bytes = std::fs::read(file).unwrap();
+        std::fs::remove_dir_all(directory).ok();
+
+        let mut offset = 0;
+        let mut records = Vec::new();
+        while offset < bytes.len() {
+            let length = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+            let data = &bytes[offset + 12..offset + 12 + length as usize];
+            let crc = u32::from_le_bytes(
+                bytes[offset + 12 + length as usize..offset + 16 + length as usize]
+                    .try_into()
+                    .unwrap(),
+            );
+            assert_eq!(crc, masked_crc32c(data));
+            offset += 16 + length as usize;
+            records.push(data.to_vec());
+        }
+
+        records
+    }
+
+    fn temp_directory(name: &str) -> String {
+        let directory =
+            std::env::temp_dir().join(format!("burn-tensorboard-{name}-{}", std::process::id()));
+        directory.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_records_are_framed() {
+        let directory = temp_directory("records");
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+
+        logger.log(&MetricEntry::new(
+            "Loss".to_string(),
+            "epoch 0.50".to_string(),
+            "0.5".to_string(),
+        ));
+        logger.end_epoch(1);
+
+        // The file version event followed by the scalar event.
+        assert_eq!(read_records(&directory).len(), 2);
+        assert_eq!(logger.read_numeric("Loss", 1), Ok(vec![0.5]));
+    }
+
+    #[test]
+    fn test_callback_writes_the_gradients_and_the_learning_rate() {
+        type B = Autodiff<TestBackend>;
+
+        let directory = temp_directory("callback");
+        let mut callback =
+            TensorBoardCallback::<B>::new(&directory).with_gradient_histograms_every(2);
+        let mut model = LinearConfig::new(2, 1).init::<B>();
+        let grads = model.forward(Tensor::ones([1, 2])).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        let store = EventStoreClient::new(LogEventStore::default());
+        let interrupter = TrainingInterrupter::new();
+
+        for total_iterations in 0..4 {
+            let mut state = LearnerState {
+                model: &mut model,
+                epoch: 1,
+                epoch_total: 1,
+                iteration: total_iterations + 1,
+                total_iterations,
+                lr: Some(0.1),
+                lr_factor: 1.0,
+                store: &store,
+                interrupter: &interrupter,
+            };
+            callback.on_optimizer_step(&mut state, &grads);
+            state.total_iterations += 1;
+            callback.on_batch_end(&mut state);
+        }
+        callback.writer.flush();
+
+        let records = read_records(&directory);
+        let contains = |record: &Vec<u8>, tag: &str| {
+            record
+                .windows(tag.len())
+                .any(|window| window == tag.as_bytes())
+        };
+        let num_records = |tag| {
+            records
+                .iter()
+                .filter(|record| contains(record, tag))
+                .count()
+        };
+
+        // The gradients are only written at the second iteration, the learning rate at each one.
+        assert_eq!(num_records("grads/weight"), 1);
+        assert_eq!(num_records("grads/bias"), 1);
+        assert_eq!(num_records("learning_rate"), 4);
+    }
+}
@@ -0,0 +1,862 @@
+use super::{InMemoryMetricLogger, MetricLogger};
+use crate::metric::MetricEntry;
+use burn_core::config::Config;
+use nvml_wrapper::Nvml;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sysinfo::{CpuExt, CpuRefreshKind, RefreshKind, System, SystemExt};
+
+/// The maximum number of metric values sent in one request.
+const MAX_BATCH_SIZE: usize = 500;
+/// The maximum time a metric value is buffered before being sent.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+/// The default interval between two reports of the system stats.
+const SYSTEM_STATS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Error that can happen when reporting to an experiment tracking service.
+#[derive(Debug)]
+pub enum TrackingError {
+    /// The request failed, e.g. the service is unreachable or returned an error status.
+    Request(String),
+    /// The response of the service can't be understood.
+    InvalidResponse(String),
+    /// A local file can't be read.
+    Io(std::io::Error),
+}
+
+impl core::fmt::Display for TrackingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Request(err) => write!(f, "Tracking request failed: {err}"),
+            Self::InvalidResponse(err) => write!(f, "Invalid tracking response: {err}"),
+            Self::Io(err) => write!(f, "Tracking I/O error: {err}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for TrackingError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => Self::Request(format!(
+                "status {status}: {}",
+                response.into_string().unwrap_or_default()
+            )),
+            err => Self::Request(err.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for TrackingError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// A numeric value of a metric reported to an [experiment tracker](ExperimentTracker).
+#[derive(Clone, Debug)]
+pub struct TrackedMetric {
+    /// The metric key, prefixed with the split, e.g. `train/Loss`.
+    pub key: String,
+    /// The value.
+    pub value: f64,
+    /// The number of values logged for this key so far.
+    pub step: usize,
+    /// The Unix timestamp in milliseconds.
+    pub timestamp: u64,
+}
+
+/// A client of an experiment tracking service, e.g. [MLflow](MlflowTracker) or
+/// [Weights & Biases](WandbTracker).
+///
+/// The methods are called from a background thread by [ExperimentTracking], so a slow service
+/// never blocks the training.
+pub trait ExperimentTracker: Send {
+    /// Report the configuration of the experiment, flattened as `key: value` pairs, e.g.
+    /// `optimizer.weight_decay: 0.0001`.
+    fn log_config(&mut self, config: &[(String, Value)]) -> Result<(), TrackingError>;
+
+    /// Report metric values.
+    fn log_metrics(&mut self, metrics: &[TrackedMetric]) -> Result<(), TrackingError>;
+
+    /// Upload a file, e.g. a model checkpoint.
+    fn log_artifact(&mut self, path: &str) -> Result<(), TrackingError>;
+
+    /// Mark the experiment as finished.
+    fn finish(&mut self) -> Result<(), TrackingError>;
+}
+
+enum TrackingEvent {
+    Config(Vec<(String, Value)>),
+    Metric(TrackedMetric),
+    Artifact(String),
+    Flush,
+    Finish,
+}
+
+/// Handle reporting the experiment to an [experiment tracker](ExperimentTracker) from a
+/// background thread.
+///
+/// The metrics are reported by registering the [metric loggers](TrackingMetricLogger) of each
+/// split in the learner builder, which compose with the other metric loggers. The system stats,
+/// i.e. the CPU usage, the used memory and the usage of the CUDA GPUs, are reported periodically
+/// under the `system/` prefix.
+///
+/// # Example
+///
+/// ```ignore
+/// let tracking = ExperimentTracking::new(MlflowTracker::new("http://localhost:5000", "mnist", None)?);
+/// tracking.log_config(&config);
+///
+/// let learner = LearnerBuilder::new(ARTIFACT_DIR)
+///     .metric_loggers(tracking.metric_logger("train"), tracking.metric_logger("valid"))
+///     .build(model, optim, lr_scheduler);
+/// let model_trained = learner.fit(dataloader_train, dataloader_valid);
+///
+/// tracking.log_artifact(&format!("{ARTIFACT_DIR}/model.mpk"));
+/// tracking.finish();
+/// ```
+#[derive(Clone)]
+pub struct ExperimentTracking {
+    sender: Sender<TrackingEvent>,
+    handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl ExperimentTracking {
+    /// Start reporting to the given tracker, with the system stats every 10 seconds.
+    pub fn new<T: ExperimentTracker + 'static>(tracker: T) -> Self {
+        Self::with_system_stats_every(tracker, SYSTEM_STATS_INTERVAL)
+    }
+
+    /// Start reporting to the given tracker, with the system stats at the given interval.
+    pub fn with_system_stats_every<T: ExperimentTracker + 'static>(
+        tracker: T,
+        interval: Duration,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stats = SystemStats::new(interval);
+        let handle = std::thread::spawn(move || run_tracker(tracker, receiver, stats));
+
+        Self {
+            sender,
+            handle: Arc::new(Mutex::new(Some(handle))),
+        }
+    }
+
+    /// Report the configuration of the experiment.
+    pub fn log_config<C: Config>(&self, config: &C) {
+        let config = serde_json::to_value(config).unwrap_or(Value::Null);
+        let mut entries = Vec::new();
+        flatten_config("", config, &mut entries);
+
+        self.send(TrackingEvent::Config(entries));
+    }
+
+    /// Upload a file, e.g. a model checkpoint.
+    pub fn log_artifact(&self, path: &str) {
+        self.send(TrackingEvent::Artifact(path.to_string()));
+    }
+
+    /// Create the metric logger of a split, e.g. `train` or `valid`, used as the prefix of the
+    /// metric keys.
+    pub fn metric_logger(&self, split: &str) -> TrackingMetricLogger {
+        TrackingMetricLogger {
+            tracking: self.clone(),
+            split: split.to_string(),
+            steps: HashMap::new(),
+            values: InMemoryMetricLogger::new(),
+        }
+    }
+
+    /// Report the remaining values, mark the experiment as finished and wait for the background
+    /// thread to end.
+    pub fn finish(&self) {
+        self.send(TrackingEvent::Finish);
+
+        if let Some(handle) = self.handle.lock().unwrap().take() {
+            handle.join().ok();
+        }
+    }
+
+    fn send(&self, event: TrackingEvent) {
+        // The background thread only stops once finished, so the events sent after are dropped.
+        self.sender.send(event).ok();
+    }
+}
+
+/// [Metric logger](MetricLogger) reporting the numeric metrics of a split to an
+/// [experiment tracker](ExperimentTracker).
+///
+/// Created with [ExperimentTracking::metric_logger].
+pub struct TrackingMetricLogger {
+    tracking: ExperimentTracking,
+    split: String,
+    steps: HashMap<String, usize>,
+    // The values can't be read back from the tracker, so they are also kept to be aggregated.
+    values: InMemoryMetricLogger,
+}
+
+impl MetricLogger for TrackingMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        self.values.log(item);
+
+        if let Ok(value) = item.serialize.parse::<f64>() {
+            let step = self.steps.entry(item.name.clone()).or_insert(0);
+            *step += 1;
+
+            self.tracking.send(TrackingEvent::Metric(TrackedMetric {
+                key: format!("{}/{}", self.split, item.name),
+                value,
+                step: *step,
+                timestamp: timestamp_millis(),
+            }));
+        }
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.values.end_epoch(epoch);
+        self.tracking.send(TrackingEvent::Flush);
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+}
+
+/// Sample the system stats as [tracked metrics](TrackedMetric).
+struct SystemStats {
+    sys: System,
+    nvml: Option<Nvml>,
+    interval: Duration,
+    last_sample: Instant,
+    step: usize,
+}
+
+impl SystemStats {
+    fn new(interval: Duration) -> Self {
+        let mut sys = System::new();
+        // The CPU usage is computed between two refreshes.
+        sys.refresh_specifics(Self::refresh_kind());
+
+        Self {
+            sys,
+            nvml: Nvml::init().ok(),
+            interval,
+            last_sample: Instant::now(),
+            step: 0,
+        }
+    }
+
+    fn refresh_kind() -> RefreshKind {
+        RefreshKind::new()
+            .with_cpu(CpuRefreshKind::new().with_cpu_usage())
+            .with_memory()
+    }
+
+    /// The time until the next sample.
+    fn timeout(&self) -> Duration {
+        self.interval.saturating_sub(self.last_sample.elapsed())
+    }
+
+    /// Sample the stats if the interval has elapsed since the last sample.
+    fn sample(&mut self, metrics: &mut Vec<TrackedMetric>) {
+        if self.last_sample.elapsed() < self.interval {
+            return;
+        }
+
+        self.last_sample = Instant::now();
+        self.step += 1;
+        self.sys.refresh_specifics(Self::refresh_kind());
+
+        let cpus = self.sys.cpus();
+        let cpu_usage =
+            cpus.iter().map(|cpu| cpu.cpu_usage() as f64).sum::<f64>() / cpus.len().max(1) as f64;
+        let mut values = vec![
+            ("CPU Usage".to_string(), cpu_usage),
+            (
+                "CPU Memory".to_string(),
+                bytes_to_gb(self.sys.used_memory()),
+            ),
+        ];
+
+        if let Some(nvml) = &self.nvml {
+            for index in 0..nvml.device_count().unwrap_or(0) {
+                let Ok(device) = nvml.device_by_index(index) else {
+                    continue;
+                };
+                if let Ok(memory) = device.memory_info() {
+                    values.push((format!("GPU {index} Memory"), bytes_to_gb(memory.used)));
+                }
+                if let Ok(utilization) = device.utilization_rates() {
+                    values.push((format!("GPU {index} Usage"), utilization.gpu as f64));
+                }
+            }
+        }
+
+        let timestamp = timestamp_millis();
+        metrics.extend(values.into_iter().map(|(name, value)| TrackedMetric {
+            key: format!("system/{name}"),
+            value,
+            step: self.step,
+            timestamp,
+        }));
+    }
+}
+
+fn bytes_to_gb(bytes: u64) -> f64 {
+    bytes as f64 / 1e9
+}
+
+fn run_tracker<T: ExperimentTracker>(
+    mut tracker: T,
+    receiver: Receiver<TrackingEvent>,
+    mut stats: SystemStats,
+) {
+    let mut metrics = Vec::new();
+
+    let flush = |tracker: &mut T, metrics: &mut Vec<TrackedMetric>| {
+        for batch in metrics.chunks(MAX_BATCH_SIZE) {
+            report(tracker.log_metrics(batch));
+        }
+        metrics.clear();
+    };
+
+    loop {
+        stats.sample(&mut metrics);
+
+        match receiver.recv_timeout(FLUSH_INTERVAL.min(stats.timeout())) {
+            Ok(TrackingEvent::Metric(metric)) => {
+                metrics.push(metric);
+                if metrics.len() >= MAX_BATCH_SIZE {
+                    flush(&mut tracker, &mut metrics);
+                }
+            }
+            Ok(TrackingEvent::Config(config)) => report(tracker.log_config(&config)),
+            Ok(TrackingEvent::Artifact(path)) => {
+                flush(&mut tracker, &mut metrics);
+                report(tracker.log_artifact(&path));
+            }
+            Ok(TrackingEvent::Flush) | Err(RecvTimeoutError::Timeout) => {
+                flush(&mut tracker, &mut metrics)
+            }
+            Ok(TrackingEvent::Finish) | Err(RecvTimeoutError::Disconnected) => {
+                flush(&mut tracker, &mut metrics);
+                report(tracker.finish());
+                return;
+            }
+        }
+    }
+}
+
+fn report(result: Result<(), TrackingError>) {
+    if let Err(err) = result {
+        log::error!("{err}");
+    }
+}
+
+/// Flatten the nested objects of the configuration, joining the keys with dots.
+fn flatten_config(prefix: &str, value: Value, entries: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = match prefix.is_empty() {
+                    true => key,
+                    false => format!("{prefix}.{key}"),
+                };
+                flatten_config(&key, value, entries);
+            }
+        }
+        value => entries.push((prefix.to_string(), value)),
+    }
+}
+
+fn timestamp_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+fn file_name(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// [Experiment tracker](ExperimentTracker) reporting to an MLflow tracking server with its REST
+/// API.
+///
+/// The artifacts are uploaded through the artifacts proxy of the server, started with
+/// `mlflow server --serve-artifacts`.
+pub struct MlflowTracker {
+    agent: ureq::Agent,
+    tracking_uri: String,
+    experiment_id: String,
+    run_id: String,
+}
+
+impl MlflowTracker {
+    /// Create a new run in the given experiment, which is created if it doesn't exist.
+    pub fn new(
+        tracking_uri: &str,
+        experiment_name: &str,
+        run_name: Option<&str>,
+    ) -> Result<Self, TrackingError> {
+        let agent = ureq::Agent::new();
+        let tracking_uri = tracking_uri.trim_end_matches('/').to_string();
+        let api = format!("{tracking_uri}/api/2.0/mlflow");
+
+        let experiment_id = match agent
+            .get(&format!("{api}/experiments/get-by-name"))
+            .query("experiment_name", experiment_name)
+            .call()
+        {
+            Ok(response) => {
+                let response: Value = response.into_json()?;
+                json_string(&response["experiment"]["experiment_id"])?
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                let response: Value = agent
+                    .post(&format!("{api}/experiments/create"))
+                    .send_json(json!({ "name": experiment_name }))?
+                    .into_json()?;
+                json_string(&response["experiment_id"])?
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut run = json!({
+            "experiment_id": experiment_id,
+            "start_time": timestamp_millis(),
+        });
+        if let Some(run_name) = run_name {
+            run["run_name"] = json!(run_name);
+        }
+        let response: Value = agent
+            .post(&format!("{api}/runs/create"))
+            .send_json(run)?
+            .into_json()?;
+        let run_id = json_string(&response["run"]["info"]["run_id"])?;
+
+        Ok(Self {
+            agent,
+            tracking_uri,
+            experiment_id,
+            run_id,
+        })
+    }
+
+    fn post(&self, endpoint: &str, body: Value) -> Result<(), TrackingError> {
+        self.agent
+            .post(&format!("{}/api/2.0/mlflow/{endpoint}", self.tracking_uri))
+            .send_json(body)?;
+        Ok(())
+    }
+}
+
+impl ExperimentTracker for MlflowTracker {
+    fn log_config(&mut self, config: &[(String, Value)]) -> Result<(), TrackingError> {
+        // The parameters are limited to 100 per batch.
+        for batch in config.chunks(100) {
+            let params = batch
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(value) => value.clone(),
+                        value => value.to_string(),
+                    };
+                    json!({ "key": key, "value": value })
+                })
+                .collect::<Vec<_>>();
+
+            self.post(
+                "runs/log-batch",
+                json!({ "run_id": self.run_id, "params": params }),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn log_metrics(&mut self, metrics: &[TrackedMetric]) -> Result<(), TrackingError> {
+        let metrics = metrics
+            .iter()
+            .map(|metric| {
+                json!({
+                    "key": metric.key,
+                    "value": metric.value,
+                    "timestamp": metric.timestamp,
+                    "step": metric.step,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.post(
+            "runs/log-batch",
+            json!({ "run_id": self.run_id, "metrics": metrics }),
+        )
+    }
+
+    fn log_artifact(&mut self, path: &str) -> Result<(), TrackingError> {
+        let content = std::fs::read(path)?;
+
+        self.agent
+            .put(&format!(
+                "{}/api/2.0/mlflow-artifacts/artifacts/{}/{}/artifacts/{}",
+                self.tracking_uri,
+                self.experiment_id,
+                self.run_id,
+                file_name(path)
+            ))
+            .send_bytes(&content)?;
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), TrackingError> {
+        self.post(
+            "runs/update",
+            json!({
+                "run_id": self.run_id,
+                "status": "FINISHED",
+                "end_time": timestamp_millis(),
+            }),
+        )
+    }
+}
+
+/// [Experiment tracker](ExperimentTracker) reporting to Weights & Biases with its HTTP API.
+pub struct WandbTracker {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: String,
+    entity: String,
+    project: String,
+    run_id: String,
+    history_offset: usize,
+    summary: Map<String, Value>,
+    num_rows: usize,
+}
+
+const UPSERT_RUN: &str = "mutation UpsertBucket($id: String, $name: String, $project: String, \
+    $entity: String, $config: JSONString, $displayName: String) { \
+    upsertBucket(input: {id: $id, name: $name, modelName: $project, entityName: $entity, \
+    config: $config, displayName: $displayName}) { bucket { id name } } }";
+
+const CREATE_RUN_FILES: &str = "mutation CreateRunFiles($entity: String!, $project: String!, \
+    $run: String!, $files: [String!]!) { createRunFiles(input: {entityName: $entity, \
+    projectName: $project, runName: $run, files: $files}) { files { name uploadUrl } } }";
+
+impl WandbTracker {
+    /// Create a new run in the given project, authenticated with the API key.
+    pub fn new(
+        api_key: &str,
+        entity: &str,
+        project: &str,
+        run_name: Option<&str>,
+    ) -> Result<Self, TrackingError> {
+        Self::with_base_url("https://api.wandb.ai", api_key, entity, project, run_name)
+    }
+
+    /// Create a new run on a self-hosted W&B server.
+    pub fn with_base_url(
+        base_url: &str,
+        api_key: &str,
+        entity: &str,
+        project: &str,
+        run_name: Option<&str>,
+    ) -> Result<Self, TrackingError> {
+        let mut tracker = Self {
+            agent: ureq::Agent::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            authorization: format!("Basic {}", base64(format!("api:{api_key}").as_bytes())),
+            entity: entity.to_string(),
+            project: project.to_string(),
+            run_id: run_id(),
+            history_offset: 0,
+            summary: Map::new(),
+            num_rows: 0,
+        };
+
+        let mut variables = tracker.run_variables();
+        if let Some(run_name) = run_name {
+            variables["displayName"] = json!(run_name);
+        }
+        tracker.graphql(UPSERT_RUN, variables)?;
+
+        Ok(tracker)
+    }
+
+    fn run_variables(&self) -> Value {
+        json!({
+            "name": self.run_id,
+            "project": self.project,
+            "entity": self.entity,
+        })
+    }
+
+    fn graphql(&mut self, query: &str, variables: Value) -> Result<Value, TrackingError> {
+        let response: Value = self
+            .agent
+            .post(&format!("{}/graphql", self.base_url))
+            .set("Authorization", &self.authorization)
+            .send_json(json!({ "query": query, "variables": variables }))?
+            .into_json()?;
+
+        match response.get("errors") {
+            Some(errors) => Err(TrackingError::InvalidResponse(errors.to_string())),
+            None => Ok(response),
+        }
+    }
+
+    fn file_stream(&mut self, body: Value) -> Result<(), TrackingError> {
+        self.agent
+            .post(&format!(
+                "{}/files/{}/{}/{}/file_stream",
+                self.base_url, self.entity, self.project, self.run_id
+            ))
+            .set("Authorization", &self.authorization)
+            .send_json(body)?;
+        Ok(())
+    }
+}
+
+impl ExperimentTracker for WandbTracker {
+    fn log_config(&mut self, config: &[(String, Value)]) -> Result<(), TrackingError> {
+        let config = config
+            .iter()
+            .map(|(key, value)| (key.clone(), json!({ "value": value })))
+            .collect::<Map<_, _>>();
+
+        let mut variables = self.run_variables();
+        variables["config"] = json!(Value::Object(config).to_string());
+        self.graphql(UPSERT_RUN, variables)?;
+
+        Ok(())
+    }
+
+    fn log_metrics(&mut self, metrics: &[TrackedMetric]) -> Result<(), TrackingError> {
+        // Each value is a history row, with the step of its own key since the splits are logged
+        // at different rates.
+        let rows = metrics
+            .iter()
+            .enumerate()
+            .map(|(i, metric)| {
+                json!({
+                    "_step": self.num_rows + i,
+                    "_timestamp": metric.timestamp as f64 / 1000.0,
+                    metric.key.clone(): metric.value,
+                    format!("{}/step", metric.key): metric.step,
+                })
+                .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        for metric in metrics {
+            self.summary.insert(metric.key.clone(), json!(metric.value));
+        }
+
+        self.file_stream(json!({
+            "files": {
+                "wandb-history.jsonl": { "offset": self.history_offset, "content": rows },
+                "wandb-summary.json": {
+                    "offset": 0,
+                    "content": [Value::Object(self.summary.clone()).to_string()],
+                },
+            }
+        }))?;
+
+        self.num_rows += metrics.len();
+        self.history_offset += metrics.len();
+
+        Ok(())
+    }
+
+    fn log_artifact(&mut self, path: &str) -> Result<(), TrackingError> {
+        let content = std::fs::read(path)?;
+        let name = file_name(path);
+
+        let mut variables = self.run_variables();
+        variables["run"] = json!(self.run_id);
+        variables["files"] = json!([name]);
+        let response = self.graphql(CREATE_RUN_FILES, variables)?;
+
+        let upload_url = response["data"]["createRunFiles"]["files"][0]["uploadUrl"]
+            .as_str()
+            .ok_or_else(|| TrackingError::InvalidResponse(response.to_string()))?;
+        self.agent.put(upload_url).send_bytes(&content)?;
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), TrackingError> {
+        self.file_stream(json!({ "complete": true, "exitcode": 0 }))
+    }
+}
+
+fn json_string(value: &Value) -> Result<String, TrackingError> {
+    value
+        .as_str()
+        .map(|value| value.to_string())
+        .ok_or_else(|| TrackingError::InvalidResponse(value.to_string()))
+}
+
+/// A run identifier of 8 lowercase alphanumeric characters, unique enough for a project.
+fn run_id() -> String {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or_default();
+    let mut seed = nanos ^ ((std::process::id() as u64) << 32);
+
+    (0..8)
+        .map(|_| {
+            // Xorshift, only to spread the bits of the seed.
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            ALPHABET[(seed % ALPHABET.len() as u64) as usize] as char
+        })
+        .collect()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut output = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => output.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3F] as char),
+                false => output.push('='),
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct RecordingTracker {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ExperimentTracker for RecordingTracker {
+        fn log_config(&mut self, config: &[(String, Value)]) -> Result<(), TrackingError> {
+            for (key, value) in config {
+                self.events.lock().unwrap().push(format!("{key}={value}"));
+            }
+            Ok(())
+        }
+
+        fn log_metrics(&mut self, metrics: &[TrackedMetric]) -> Result<(), TrackingError> {
+            for metric in metrics {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}[{}]={}", metric.key, metric.step, metric.value));
+            }
+            Ok(())
+        }
+
+        fn log_artifact(&mut self, path: &str) -> Result<(), TrackingError> {
+            self.events.lock().unwrap().push(format!("artifact {path}"));
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<(), TrackingError> {
+            self.events.lock().unwrap().push("finish".to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_metrics_reported_with_the_split_prefix() {
+        let tracker = RecordingTracker::default();
+        let events = tracker.events.clone();
+        let tracking = ExperimentTracking::new(tracker);
+        let mut logger_train = tracking.metric_logger("train");
+        let mut logger_valid = tracking.metric_logger("valid");
+
+        let entry = |value: &str| MetricEntry::new("Loss".into(), value.into(), value.into());
+        logger_train.log(&entry("0.5"));
+        logger_train.log(&entry("0.25"));
+        logger_train.log(&MetricEntry::new("CUDA".into(), "".into(), "GPU 0".into()));
+        logger_valid.log(&entry("0.75"));
+        tracking.log_artifact("model.mpk");
+        tracking.finish();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "train/Loss[1]=0.5",
+                "train/Loss[2]=0.25",
+                "valid/Loss[1]=0.75",
+                "artifact model.mpk",
+                "finish"
+            ]
+        );
+        assert_eq!(logger_train.read_numeric("Loss", 1), Ok(vec![0.5, 0.25]));
+    }
+
+    #[test]
+    fn test_system_stats_reported_periodically() {
+        let tracker = RecordingTracker::default();
+        let events = tracker.events.clone();
+        let tracking =
+            ExperimentTracking::with_system_stats_every(tracker, Duration::from_millis(10));
+
+        std::thread::sleep(Duration::from_millis(50));
+        tracking.finish();
+
+        let events = events.lock().unwrap();
+        assert!(events
+            .iter()
+            .any(|event| event.starts_with("system/CPU Usage[1]=")));
+        assert!(events
+            .iter()
+            .any(|event| event.starts_with("system/CPU Memory[1]=")));
+    }
+
+    #[test]
+    fn test_flatten_config() {
+        let mut entries = Vec::new();
+        flatten_config(
+            "",
+            json!({ "num_epochs": 10, "optimizer": { "beta_1": 0.9 } }),
+            &mut entries,
+        );
+
+        assert_eq!(
+            entries,
+            vec![
+                ("num_epochs".to_string(), json!(10)),
+                ("optimizer.beta_1".to_string(), json!(0.9)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b"api:key"), "YXBpOmtleQ==");
+        assert_eq!(base64(b"abc"), "YWJj");
+        assert_eq!(base64(b"ab"), "YWI=");
+    }
+}
@@ -1,4 +1,4 @@
-use super::state::{FormatOptions, NumericMetricState};
+use super::state::{FormatOptions, MeanState, NumericMetricState};
 use super::{MetricEntry, MetricMetadata};
 use crate::metric::{Metric, Numeric};
 use burn_core::tensor::backend::Backend;
@@ -36,8 +36,9 @@ impl<B: Backend> Metric for AccuracyMetric<B> {
     const NAME: &'static str = "Accuracy";
 
     type Input = AccuracyInput<B>;
+    type State = MeanState;
 
-    fn update(&mut self, input: &AccuracyInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, input: &AccuracyInput<B>, _metadata: &MetricMetadata) -> MeanState {
         let [batch_size, _n_classes] = input.outputs.dims();
 
         let targets = input.targets.clone().to_device(&B::Device::default());
@@ -48,30 +49,35 @@ impl<B: Backend> Metric for AccuracyMetric<B> {
             .to_device(&B::Device::default())
             .reshape([batch_size]);
 
-        let accuracy = match self.pad_token {
+        let (num_matches, num_items) = match self.pad_token {
             Some(pad_token) => {
                 let mask = targets.clone().equal_elem(pad_token as i64);
                 let matches = outputs.equal(targets).int().mask_fill(mask.clone(), 0);
-                let num_pad = mask.int().sum().into_scalar().elem::<f64>();
+                let num_pad = mask.int().sum().into_scalar().elem::<i64>() as usize;
 
-                matches.sum().into_scalar().elem::<f64>() / (batch_size as f64 - num_pad)
+                (
+                    matches.sum().into_scalar().elem::<f64>(),
+                    batch_size - num_pad,
+                )
             }
             None => {
-                outputs
+                let num_matches = outputs
                     .equal(targets)
                     .int()
                     .sum()
                     .into_scalar()
-                    .elem::<f64>()
-                    / batch_size as f64
+                    .elem::<f64>();
+
+                (num_matches, batch_size)
             }
         };
 
-        self.state.update(
-            100.0 * accuracy,
-            batch_size,
-            FormatOptions::new(Self::NAME).unit("%").precision(2),
-        )
+        MeanState::new(100.0 * num_matches, num_items)
+    }
+
+    fn merge(&mut self, state: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state
+            .merge(state, FormatOptions::new(Self::NAME).unit("%").precision(2))
     }
 
     fn clear(&mut self) {
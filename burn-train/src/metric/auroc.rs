@@ -0,0 +1,216 @@
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{MergeableState, Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{activation::sigmoid, ElementConversion, Int, Tensor};
+
+/// The area under the ROC curve metric for binary classification.
+///
+/// The value is only defined over all the items of the epoch, so it is computed once at the end
+/// of each epoch, and all [aggregates](crate::metric::store::Aggregate) of an epoch give this
+/// value. It isn't defined, i.e. `NaN`, when only one class has been targeted.
+///
+/// The probabilities of the positive class are counted in a fixed number of bins, so the memory
+/// doesn't grow with the number of items, the items of the same bin being counted as ties.
+pub struct AurocMetric<B: Backend> {
+    state: AurocState,
+    _b: B,
+}
+
+/// The number of positive and negative items in each bin of probabilities, the
+/// [mergeable state](MergeableState) of the [AUROC metric](AurocMetric).
+#[derive(Clone, Debug, PartialEq)]
+pub struct AurocState {
+    positives: Vec<u64>,
+    negatives: Vec<u64>,
+}
+
+impl AurocState {
+    fn new(num_bins: usize) -> Self {
+        Self {
+            positives: vec![0; num_bins],
+            negatives: vec![0; num_bins],
+        }
+    }
+
+    fn num_items(&self) -> u64 {
+        self.positives.iter().chain(&self.negatives).sum()
+    }
+}
+
+impl MergeableState for AurocState {
+    fn merge(&mut self, other: Self) {
+        for (count, other) in self.positives.iter_mut().zip(other.positives) {
+            *count += other;
+        }
+        for (count, other) in self.negatives.iter_mut().zip(other.negatives) {
+            *count += other;
+        }
+    }
+}
+
+/// The [AUROC metric](AurocMetric) input type.
+///
+/// The outputs are either the probability of the positive class for each item, or the logits of
+/// the negative and positive classes. The targets are 1 for the positive class and 0 for the
+/// negative class.
+#[derive(new)]
+pub struct AurocInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> Default for AurocMetric<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> AurocMetric<B> {
+    /// Creates the metric, with 1000 bins of probabilities.
+    pub fn new() -> Self {
+        Self::init(1000)
+    }
+
+    /// Sets the number of bins of probabilities, more bins giving a more precise value.
+    pub fn with_num_bins(self, num_bins: usize) -> Self {
+        assert!(num_bins > 0, "The number of bins must be positive.");
+        Self::init(num_bins)
+    }
+
+    fn init(num_bins: usize) -> Self {
+        Self {
+            state: AurocState::new(num_bins),
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for AurocMetric<B> {
+    const NAME: &'static str = "AUROC";
+
+    type Input = AurocInput<B>;
+    type State = AurocState;
+
+    fn batch_state(&self, input: &AurocInput<B>, _metadata: &MetricMetadata) -> AurocState {
+        let [batch_size, num_outputs] = input.outputs.dims();
+
+        let probabilities = match num_outputs {
+            1 => input.outputs.clone().reshape([batch_size]).clamp(0.0, 1.0),
+            2 => {
+                // The softmax probability of the positive class.
+                let positive = input.outputs.clone().slice([0..batch_size, 1..2]);
+                let negative = input.outputs.clone().slice([0..batch_size, 0..1]);
+                sigmoid(positive.sub(negative)).reshape([batch_size])
+            }
+            _ => {
+                panic!("AUROC is only defined for binary classification, got {num_outputs} outputs")
+            }
+        };
+
+        let num_bins = self.state.positives.len();
+        let mut state = AurocState::new(num_bins);
+        let items = probabilities
+            .into_data()
+            .value
+            .into_iter()
+            .zip(input.targets.clone().into_data().value);
+
+        for (probability, target) in items {
+            let bin = ((probability.elem::<f64>() * num_bins as f64) as usize).min(num_bins - 1);
+
+            match target.elem::<i64>() != 0 {
+                true => state.positives[bin] += 1,
+                false => state.negatives[bin] += 1,
+            }
+        }
+
+        state
+    }
+
+    fn merge(&mut self, state: AurocState, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state.merge(state);
+        let num_items = self.state.num_items();
+
+        MetricEntry::new(
+            Self::NAME.to_string(),
+            format!("computed at the end of the epoch - {num_items} items"),
+            num_items.to_string(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state = AurocState::new(self.state.positives.len());
+    }
+}
+
+impl<B: Backend> Numeric for AurocMetric<B> {
+    fn value(&self) -> f64 {
+        auroc(&self.state.positives, &self.state.negatives)
+    }
+
+    fn is_epoch_level(&self) -> bool {
+        true
+    }
+}
+
+/// Compute the area under the ROC curve from the number of positive and negative items of each
+/// bin: the probability that a positive item is ranked higher than a negative one, with the items
+/// of the same bin counted as ties.
+fn auroc(positives: &[u64], negatives: &[u64]) -> f64 {
+    let num_positives = positives.iter().sum::<u64>() as f64;
+    let num_negatives = negatives.iter().sum::<u64>() as f64;
+
+    if num_positives == 0.0 || num_negatives == 0.0 {
+        return f64::NAN;
+    }
+
+    let mut num_negatives_below = 0.0;
+    let mut num_pairs_ranked = 0.0;
+
+    for (positive, negative) in positives.iter().zip(negatives) {
+        let (positive, negative) = (*positive as f64, *negative as f64);
+
+        num_pairs_ranked += positive * (num_negatives_below + negative / 2.0);
+        num_negatives_below += negative;
+    }
+
+    num_pairs_ranked / (num_positives * num_negatives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_auroc_with_ties() {
+        // Positive items in bins 3 and 1, negative items in bins 1 and 0.
+        let value = auroc(&[0, 1, 0, 1], &[1, 1, 0, 0]);
+
+        // 3 pairs ranked correctly and 1 tie out of 4 pairs.
+        assert_eq!(value, 0.875);
+    }
+
+    #[test]
+    fn test_auroc_accumulated_over_batches() {
+        let mut metric = AurocMetric::<TestBackend>::new();
+
+        metric.update(
+            &AurocInput::new(Tensor::from_data([[0.9], [0.7]]), Tensor::from_data([1, 1])),
+            &MetricMetadata::fake(),
+        );
+        assert!(metric.value().is_nan());
+
+        metric.update(
+            &AurocInput::new(
+                Tensor::from_data([[0.2, 0.1], [0.0, 0.95]]),
+                Tensor::from_data([0, 0]),
+            ),
+            &MetricMetadata::fake(),
+        );
+        // The probabilities of the second batch are sigmoid(-0.1) and sigmoid(0.95), so only
+        // 0.72 > 0.7 is misranked.
+        assert_eq!(metric.value(), 0.75);
+        assert!(metric.is_epoch_level());
+    }
+}
@@ -36,11 +36,44 @@ impl MetricMetadata {
 
 /// Metric trait.
 ///
+/// The metric computes the [state](Metric::State) of each batch, which is merged into the state
+/// accumulated by the metric. When training on multiple devices, the states of the batches of all
+/// devices are merged before being merged into the metric, so the value is the same as if the
+/// batches were processed on a single device.
+///
 /// # Notes
 ///
 /// Implementations should define their own input type only used by the metric.
 /// This is important since some conflict may happen when the model output is adapted for each
 /// metric's input type.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Default)]
+/// struct WordErrorRate {
+///     state: NumericMetricState,
+/// }
+///
+/// impl Metric for WordErrorRate {
+///     const NAME: &'static str = "Word Error Rate";
+///
+///     type Input = TranscriptionInput;
+///     type State = MeanState;
+///
+///     fn batch_state(&self, input: &TranscriptionInput, _metadata: &MetricMetadata) -> MeanState {
+///         MeanState::new(100.0 * input.edit_distance() as f64, input.num_target_words())
+///     }
+///
+///     fn merge(&mut self, state: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
+///         self.state.merge(state, FormatOptions::new(Self::NAME).unit("%").precision(2))
+///     }
+///
+///     fn clear(&mut self) {
+///         self.state.reset()
+///     }
+/// }
+/// ```
 pub trait Metric: Send + Sync {
     /// The name of the metric.
     ///
@@ -50,12 +83,42 @@ pub trait Metric: Send + Sync {
     /// The input type of the metric.
     type Input;
 
-    /// Update the metric state and returns the current metric entry.
-    fn update(&mut self, item: &Self::Input, metadata: &MetricMetadata) -> MetricEntry;
+    /// The state of the metric for a batch.
+    type State: MergeableState;
+
+    /// Compute the state of a batch.
+    fn batch_state(&self, item: &Self::Input, metadata: &MetricMetadata) -> Self::State;
+
+    /// Merge the state of a batch, possibly merged with the batches of other devices, into the
+    /// metric state and returns the current metric entry.
+    fn merge(&mut self, state: Self::State, metadata: &MetricMetadata) -> MetricEntry;
+
+    /// Update the metric state with a batch and returns the current metric entry.
+    fn update(&mut self, item: &Self::Input, metadata: &MetricMetadata) -> MetricEntry {
+        let state = self.batch_state(item, metadata);
+        self.merge(state, metadata)
+    }
+
     /// Clear the metric state.
     fn clear(&mut self);
 }
 
+/// The state of a [metric](Metric) for some items, which can be merged with the state of other
+/// items, e.g. the items of another batch or of another device.
+///
+/// The state should hold the sufficient statistics of the metric, e.g. the number of errors and
+/// the number of words for the word error rate, so that merging the states of the batches gives
+/// the same value as computing the metric on all the items at once.
+pub trait MergeableState: Send {
+    /// Merge the state of other items into this state.
+    fn merge(&mut self, other: Self);
+}
+
+/// The state of the metrics not computed from the items, e.g. the system metrics.
+impl MergeableState for () {
+    fn merge(&mut self, _other: Self) {}
+}
+
 /// Adaptor are used to transform types so that they can be used by metrics.
 ///
 /// This should be implemented by a model's output type for all [metric inputs](Metric::Input) that are
@@ -71,6 +134,14 @@ pub trait Adaptor<T> {
 pub trait Numeric {
     /// Returns the numeric value of the metric.
     fn value(&self) -> f64;
+
+    /// If the value is only defined over all the items of an epoch, e.g. the area under the ROC
+    /// curve. Such a value is computed once at the end of each epoch instead of after each item,
+    /// so it is logged once per epoch and all [aggregates](crate::metric::store::Aggregate) of the
+    /// epoch are equal.
+    fn is_epoch_level(&self) -> bool {
+        false
+    }
 }
 
 /// Data type that contains the current state of a metric at a given time.
@@ -93,3 +164,17 @@ pub fn format_float(float: f64, precision: usize) -> String {
         false => format!("{float:.precision$}"),
     }
 }
+
+/// Format the value of a metric computed over the epoch and over the current batch.
+pub(crate) fn format_epoch_batch(epoch: f64, batch: f64, unit: &str) -> String {
+    let unit = match unit.is_empty() {
+        true => String::new(),
+        false => format!(" {unit}"),
+    };
+
+    format!(
+        "epoch {}{unit} - batch {}{unit}",
+        format_float(epoch, 2),
+        format_float(batch, 2)
+    )
+}
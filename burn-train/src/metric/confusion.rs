@@ -0,0 +1,393 @@
+use super::{format_epoch_batch, MetricEntry, MetricMetadata};
+use crate::metric::{MergeableState, Metric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// How the per-class values of a classification metric are averaged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassAverage {
+    /// The unweighted mean of the values of each class.
+    Macro,
+    /// The value computed from the counts of all classes together.
+    ///
+    /// For single-label classification, the micro precision, recall and F1 score are all equal
+    /// to the accuracy.
+    Micro,
+    /// The mean of the values of each class, weighted by the number of targets of the class.
+    Weighted,
+}
+
+/// The input type of the classification metrics, e.g. the [confusion matrix](ConfusionMatrixMetric)
+/// or the [precision](crate::metric::PrecisionMetric).
+#[derive(new)]
+pub struct ClassificationInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+/// Counts of the predicted classes for each target class, the [mergeable state](MergeableState)
+/// of the classification metrics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfusionMatrix {
+    num_classes: usize,
+    // The count of the target `i` predicted as `j` is at `i * num_classes + j`.
+    counts: Vec<usize>,
+}
+
+impl ConfusionMatrix {
+    pub(crate) fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            counts: vec![0; num_classes * num_classes],
+        }
+    }
+
+    /// The confusion matrix of the predictions, the classes with the highest outputs.
+    pub(crate) fn from_input<B: Backend>(input: &ClassificationInput<B>) -> Self {
+        let [batch_size, num_classes] = input.outputs.dims();
+        let predictions = input.outputs.clone().argmax(1).reshape([batch_size]);
+
+        Self::from_indices(
+            num_classes,
+            input.targets.clone().into_data().value,
+            predictions.into_data().value,
+            None,
+        )
+    }
+
+    /// The confusion matrix of the target and predicted class indices, skipping the items whose
+    /// target is the ignored index.
+    pub(crate) fn from_indices<E: ElementConversion>(
+        num_classes: usize,
+        targets: Vec<E>,
+        predictions: Vec<E>,
+        ignore_index: Option<usize>,
+    ) -> Self {
+        let mut matrix = Self::new(num_classes);
+
+        for (target, prediction) in targets.into_iter().zip(predictions) {
+            let target = target.elem::<i64>() as usize;
+            let prediction = prediction.elem::<i64>() as usize;
+
+            if Some(target) == ignore_index {
+                continue;
+            }
+
+            matrix.counts[target * num_classes + prediction] += 1;
+        }
+
+        matrix
+    }
+
+    pub(crate) fn count(&self, target: usize, prediction: usize) -> usize {
+        self.counts[target * self.num_classes + prediction]
+    }
+
+    fn num_targets(&self, class: usize) -> usize {
+        (0..self.num_classes).map(|i| self.count(class, i)).sum()
+    }
+
+    fn num_predictions(&self, class: usize) -> usize {
+        (0..self.num_classes).map(|i| self.count(i, class)).sum()
+    }
+
+    fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub(crate) fn precision(&self, average: ClassAverage) -> f64 {
+        self.average(average, |class| {
+            ratio(self.count(class, class), self.num_predictions(class))
+        })
+    }
+
+    pub(crate) fn recall(&self, average: ClassAverage) -> f64 {
+        self.average(average, |class| {
+            ratio(self.count(class, class), self.num_targets(class))
+        })
+    }
+
+    pub(crate) fn f1_score(&self, average: ClassAverage) -> f64 {
+        self.average(average, |class| self.f1_score_class(class))
+    }
+
+    /// The F1 score of a class, also known as the Dice coefficient.
+    pub(crate) fn f1_score_class(&self, class: usize) -> f64 {
+        ratio(
+            2 * self.count(class, class),
+            self.num_targets(class) + self.num_predictions(class),
+        )
+    }
+
+    /// The intersection over union of the predictions and the targets.
+    pub(crate) fn iou(&self, average: ClassAverage) -> f64 {
+        self.average(average, |class| self.iou_class(class))
+    }
+
+    /// The intersection over union of the predictions and the targets of a class.
+    pub(crate) fn iou_class(&self, class: usize) -> f64 {
+        let true_positives = self.count(class, class);
+        ratio(
+            true_positives,
+            self.num_targets(class) + self.num_predictions(class) - true_positives,
+        )
+    }
+
+    /// The values of each class, `None` for the classes neither targeted nor predicted.
+    pub(crate) fn per_class<F: Fn(&Self, usize) -> f64>(&self, value: F) -> Vec<Option<f64>> {
+        (0..self.num_classes)
+            .map(
+                |class| match self.num_targets(class) + self.num_predictions(class) {
+                    0 => None,
+                    _ => Some(value(self, class)),
+                },
+            )
+            .collect()
+    }
+
+    /// Average the values of the classes that are either targeted or predicted.
+    fn average<F: Fn(usize) -> f64>(&self, average: ClassAverage, value: F) -> f64 {
+        let classes = (0..self.num_classes)
+            .filter(|class| self.num_targets(*class) + self.num_predictions(*class) > 0);
+
+        match average {
+            ClassAverage::Micro => ratio(
+                (0..self.num_classes)
+                    .map(|class| self.count(class, class))
+                    .sum(),
+                self.total(),
+            ),
+            ClassAverage::Macro => {
+                let values = classes.map(value).collect::<Vec<_>>();
+                match values.len() {
+                    0 => 0.0,
+                    num_classes => values.iter().sum::<f64>() / num_classes as f64,
+                }
+            }
+            ClassAverage::Weighted => match self.total() {
+                0 => 0.0,
+                total => {
+                    classes
+                        .map(|class| value(class) * self.num_targets(class) as f64)
+                        .sum::<f64>()
+                        / total as f64
+                }
+            },
+        }
+    }
+
+    fn format(&self) -> String {
+        let rows = (0..self.num_classes)
+            .map(|target| {
+                let counts = (0..self.num_classes)
+                    .map(|prediction| self.count(target, prediction).to_string())
+                    .collect::<Vec<_>>();
+                format!("[{}]", counts.join(", "))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", rows.join(", "))
+    }
+
+    fn format_table(&self) -> String {
+        let width = self
+            .counts
+            .iter()
+            .map(|count| count.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        (0..self.num_classes)
+            .map(|target| {
+                (0..self.num_classes)
+                    .map(|prediction| format!("{:>width$}", self.count(target, prediction)))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl MergeableState for ConfusionMatrix {
+    /// Add the counts of another confusion matrix, resizing this one when it has fewer classes.
+    fn merge(&mut self, other: Self) {
+        if other.num_classes > self.num_classes {
+            let matrix = core::mem::replace(self, Self::new(other.num_classes));
+            self.merge(matrix);
+        }
+
+        for target in 0..other.num_classes {
+            for prediction in 0..other.num_classes {
+                self.counts[target * self.num_classes + prediction] +=
+                    other.count(target, prediction);
+            }
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    match denominator {
+        0 => 0.0,
+        _ => numerator as f64 / denominator as f64,
+    }
+}
+
+/// The confusion matrix metric, counting the predicted classes for each target class over the
+/// epoch.
+///
+/// The rows are the target classes and the columns the predicted classes.
+#[derive(Default)]
+pub struct ConfusionMatrixMetric<B: Backend> {
+    matrix: Option<ConfusionMatrix>,
+    _b: B,
+}
+
+impl<B: Backend> ConfusionMatrixMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for ConfusionMatrixMetric<B> {
+    const NAME: &'static str = "Confusion Matrix";
+
+    type Input = ClassificationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        ConfusionMatrix::from_input(input)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        let matrix = self.matrix.get_or_insert_with(|| ConfusionMatrix::new(0));
+        matrix.merge(batch);
+
+        MetricEntry::new(
+            Self::NAME.to_string(),
+            format!("epoch\n{}", matrix.format_table()),
+            matrix.format(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.matrix = None;
+    }
+}
+
+/// State of the metrics computed from the confusion matrix of the epoch.
+pub(crate) struct ConfusionMatrixState {
+    pub(crate) matrix: Option<ConfusionMatrix>,
+    pub(crate) current: f64,
+}
+
+impl ConfusionMatrixState {
+    pub(crate) fn new() -> Self {
+        Self {
+            matrix: None,
+            current: f64::NAN,
+        }
+    }
+
+    /// Add the batch to the confusion matrix of the epoch, then compute the metric of the epoch
+    /// and of the batch.
+    pub(crate) fn merge<F: Fn(&ConfusionMatrix) -> f64>(
+        &mut self,
+        name: &str,
+        batch: ConfusionMatrix,
+        compute: F,
+    ) -> MetricEntry {
+        let value_batch = 100.0 * compute(&batch);
+
+        let matrix = self.matrix.get_or_insert_with(|| ConfusionMatrix::new(0));
+        matrix.merge(batch);
+        let value_epoch = 100.0 * compute(matrix);
+        self.current = value_epoch;
+
+        MetricEntry::new(
+            name.to_string(),
+            format_epoch_batch(value_epoch, value_batch, "%"),
+            value_epoch.to_string(),
+        )
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.matrix = None;
+        self.current = f64::NAN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_confusion_matrix_accumulated_over_batches() {
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new();
+
+        let _entry = metric.update(
+            &ClassificationInput::new(
+                Tensor::from_data([[0.9, 0.1], [0.2, 0.8]]),
+                Tensor::from_data([0, 0]),
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &ClassificationInput::new(Tensor::from_data([[0.3, 0.7]]), Tensor::from_data([1])),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(entry.serialize, "[[1, 1], [0, 1]]");
+        assert_eq!(entry.formatted, "epoch\n1 1\n0 1");
+    }
+
+    #[test]
+    fn test_averages() {
+        let mut matrix = ConfusionMatrix::new(3);
+        // Target 0: 2 correct, 1 predicted as 1.
+        // Target 1: 1 correct.
+        // Target 2: 1 predicted as 0.
+        matrix.counts = vec![2, 1, 0, 0, 1, 0, 1, 0, 0];
+
+        // Precision of each class: 2/3, 1/2, 0.
+        assert_approx(
+            matrix.precision(ClassAverage::Macro),
+            (2.0 / 3.0 + 0.5) / 3.0,
+        );
+        assert_approx(matrix.precision(ClassAverage::Micro), 3.0 / 5.0);
+        assert_approx(
+            matrix.precision(ClassAverage::Weighted),
+            (2.0 / 3.0 * 3.0 + 0.5) / 5.0,
+        );
+        // Recall of each class: 2/3, 1, 0.
+        assert_approx(matrix.recall(ClassAverage::Macro), (2.0 / 3.0 + 1.0) / 3.0);
+        assert_approx(matrix.recall(ClassAverage::Micro), 3.0 / 5.0);
+        // F1 score of each class: 2/3, 2/3, 0.
+        assert_approx(matrix.f1_score(ClassAverage::Macro), 4.0 / 9.0);
+    }
+
+    #[test]
+    fn test_merge_resizes_to_the_largest_number_of_classes() {
+        let mut matrix = ConfusionMatrix::new(1);
+        matrix.counts = vec![2];
+        let mut other = ConfusionMatrix::new(2);
+        other.counts = vec![1, 0, 0, 1];
+
+        matrix.merge(other);
+
+        assert_eq!(matrix.counts, vec![3, 0, 0, 1]);
+    }
+
+    fn assert_approx(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-9,
+            "{value} should be {expected}"
+        );
+    }
+}
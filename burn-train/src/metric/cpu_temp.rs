@@ -29,8 +29,11 @@ impl Metric for CpuTemperature {
     const NAME: &'static str = "CPU Temperature";
 
     type Input = ();
+    type State = ();
 
-    fn update(&mut self, _item: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, _item: &(), _metadata: &MetricMetadata) {}
+
+    fn merge(&mut self, _state: (), _metadata: &MetricMetadata) -> MetricEntry {
         match self.sys.cpu_temp() {
             Ok(temp) => self.temp_celsius = temp,
             Err(_) => self.temp_celsius = f32::NAN,
@@ -33,8 +33,11 @@ impl Metric for CUDAMetric {
     const NAME: &'static str = "CUDA Stats";
 
     type Input = ();
+    type State = ();
 
-    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, _item: &(), _metadata: &MetricMetadata) {}
+
+    fn merge(&mut self, _state: (), _metadata: &MetricMetadata) -> MetricEntry {
         let not_available = || {
             MetricEntry::new(
                 Self::NAME.to_string(),
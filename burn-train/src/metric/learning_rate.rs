@@ -28,8 +28,11 @@ impl Metric for LearningRateMetric {
     const NAME: &'static str = "Learning Rate";
 
     type Input = ();
+    type State = ();
 
-    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, _item: &(), _metadata: &MetricMetadata) {}
+
+    fn merge(&mut self, _state: (), metadata: &MetricMetadata) -> MetricEntry {
         let lr = metadata.lr.unwrap_or(0.0);
 
         self.state
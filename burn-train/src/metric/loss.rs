@@ -1,5 +1,5 @@
 use super::state::FormatOptions;
-use super::state::NumericMetricState;
+use super::state::{MeanState, NumericMetricState};
 use super::MetricEntry;
 use super::MetricMetadata;
 use crate::metric::{Metric, Numeric};
@@ -31,12 +31,17 @@ impl<B: Backend> Metric for LossMetric<B> {
     const NAME: &'static str = "Loss";
 
     type Input = LossInput<B>;
+    type State = MeanState;
 
-    fn update(&mut self, loss: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, loss: &Self::Input, _metadata: &MetricMetadata) -> MeanState {
         let loss = f64::from_elem(loss.tensor.clone().mean().into_data().value[0]);
 
+        MeanState::from_mean(loss, 1)
+    }
+
+    fn merge(&mut self, state: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
         self.state
-            .update(loss, 1, FormatOptions::new(Self::NAME).precision(2))
+            .merge(state, FormatOptions::new(Self::NAME).precision(2))
     }
 
     fn clear(&mut self) {
@@ -49,8 +49,11 @@ impl Metric for CpuMemory {
     const NAME: &'static str = "CPU Memory";
 
     type Input = ();
+    type State = ();
 
-    fn update(&mut self, _item: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
+    fn batch_state(&self, _item: &(), _metadata: &MetricMetadata) {}
+
+    fn merge(&mut self, _state: (), _metadata: &MetricMetadata) -> MetricEntry {
         if self.last_refresh.elapsed() >= self.refresh_frequency {
             self.refresh();
         }
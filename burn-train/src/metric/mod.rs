@@ -2,7 +2,9 @@
 pub mod state;
 
 mod acc;
+mod auroc;
 mod base;
+mod confusion;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
 #[cfg(feature = "metrics")]
@@ -13,9 +15,15 @@ mod learning_rate;
 mod loss;
 #[cfg(feature = "metrics")]
 mod memory_use;
+mod perplexity;
+mod precision_recall;
+mod segmentation;
+mod top_k_acc;
 
 pub use acc::*;
+pub use auroc::*;
 pub use base::*;
+pub use confusion::*;
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
 #[cfg(feature = "metrics")]
@@ -26,6 +34,10 @@ pub use learning_rate::*;
 pub use loss::*;
 #[cfg(feature = "metrics")]
 pub use memory_use::*;
+pub use perplexity::*;
+pub use precision_recall::*;
+pub use segmentation::*;
+pub use top_k_acc::*;
 
 pub(crate) mod processor;
 /// Module responsible to save and exposes data collected during training.
@@ -0,0 +1,105 @@
+use super::state::MeanState;
+use super::{format_float, MetricEntry, MetricMetadata};
+use crate::metric::{MergeableState, Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The perplexity metric, the exponential of the cross-entropy loss.
+///
+/// The loss is averaged over the epoch before taking the exponential, so the epoch value is the
+/// perplexity of all the items instead of the mean of the perplexity of each batch.
+#[derive(Default)]
+pub struct PerplexityMetric<B: Backend> {
+    loss: MeanState,
+    current: f64,
+    _b: B,
+}
+
+/// The [perplexity metric](PerplexityMetric) input type.
+#[derive(new)]
+pub struct PerplexityInput<B: Backend> {
+    /// The cross-entropy loss, in nats, averaged over the items of the batch.
+    loss: Tensor<B, 1>,
+}
+
+impl<B: Backend> PerplexityMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self {
+            current: f64::NAN,
+            ..Default::default()
+        }
+    }
+}
+
+impl<B: Backend> Metric for PerplexityMetric<B> {
+    const NAME: &'static str = "Perplexity";
+
+    type Input = PerplexityInput<B>;
+    type State = MeanState;
+
+    fn batch_state(&self, input: &PerplexityInput<B>, _metadata: &MetricMetadata) -> MeanState {
+        let loss = f64::from_elem(input.loss.clone().mean().into_data().value[0]);
+
+        MeanState::from_mean(loss, 1)
+    }
+
+    fn merge(&mut self, loss: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
+        self.current = loss.mean().exp();
+        self.loss.merge(loss);
+
+        let value_epoch = self.loss.mean().exp();
+
+        MetricEntry::new(
+            Self::NAME.to_string(),
+            format!(
+                "epoch {} - batch {}",
+                format_float(value_epoch, 2),
+                format_float(self.current, 2)
+            ),
+            self.current.to_string(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.loss = MeanState::default();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for PerplexityMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_perplexity_averages_the_loss_over_the_epoch() {
+        let mut metric = PerplexityMetric::<TestBackend>::new();
+
+        let _entry = metric.update(
+            &PerplexityInput::new(Tensor::from_data([0.0])),
+            &MetricMetadata::fake(),
+        );
+        assert_eq!(metric.value(), 1.0);
+
+        let entry = metric.update(
+            &PerplexityInput::new(Tensor::from_data([2.0])),
+            &MetricMetadata::fake(),
+        );
+        assert!((metric.value() - 2.0f64.exp()).abs() < 1e-5);
+        assert_eq!(
+            entry.formatted,
+            format!(
+                "epoch {} - batch {}",
+                format_float(1.0f64.exp(), 2),
+                format_float(2.0f64.exp(), 2)
+            )
+        );
+    }
+}
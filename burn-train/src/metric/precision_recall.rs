@@ -0,0 +1,212 @@
+use super::confusion::{ConfusionMatrix, ConfusionMatrixState};
+use super::{ClassAverage, ClassificationInput, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The precision metric, the percentage of the predictions of a class that are correct.
+///
+/// The value is computed over all the batches of the epoch, so the last value of each epoch
+/// should be used, e.g. with [Aggregate::Last](crate::metric::store::Aggregate::Last).
+pub struct PrecisionMetric<B: Backend> {
+    state: ConfusionMatrixState,
+    average: ClassAverage,
+    _b: B,
+}
+
+/// The recall metric, the percentage of the targets of a class that are correctly predicted.
+///
+/// The value is computed over all the batches of the epoch, so the last value of each epoch
+/// should be used, e.g. with [Aggregate::Last](crate::metric::store::Aggregate::Last).
+pub struct RecallMetric<B: Backend> {
+    state: ConfusionMatrixState,
+    average: ClassAverage,
+    _b: B,
+}
+
+/// The F1 score metric, the harmonic mean of the precision and the recall.
+///
+/// The value is computed over all the batches of the epoch, so the last value of each epoch
+/// should be used, e.g. with [Aggregate::Last](crate::metric::store::Aggregate::Last).
+pub struct F1ScoreMetric<B: Backend> {
+    state: ConfusionMatrixState,
+    average: ClassAverage,
+    _b: B,
+}
+
+impl<B: Backend> PrecisionMetric<B> {
+    /// Creates the metric, averaging the precision of each class as specified.
+    pub fn new(average: ClassAverage) -> Self {
+        Self {
+            state: ConfusionMatrixState::new(),
+            average,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> RecallMetric<B> {
+    /// Creates the metric, averaging the recall of each class as specified.
+    pub fn new(average: ClassAverage) -> Self {
+        Self {
+            state: ConfusionMatrixState::new(),
+            average,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> F1ScoreMetric<B> {
+    /// Creates the metric, averaging the F1 score of each class as specified.
+    pub fn new(average: ClassAverage) -> Self {
+        Self {
+            state: ConfusionMatrixState::new(),
+            average,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for PrecisionMetric<B> {
+    const NAME: &'static str = "Precision";
+
+    type Input = ClassificationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        ConfusionMatrix::from_input(input)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        let average = self.average;
+        self.state
+            .merge(Self::NAME, batch, |matrix| matrix.precision(average))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Metric for RecallMetric<B> {
+    const NAME: &'static str = "Recall";
+
+    type Input = ClassificationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        ConfusionMatrix::from_input(input)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        let average = self.average;
+        self.state
+            .merge(Self::NAME, batch, |matrix| matrix.recall(average))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Metric for F1ScoreMetric<B> {
+    const NAME: &'static str = "F1 Score";
+
+    type Input = ClassificationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        ConfusionMatrix::from_input(input)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        let average = self.average;
+        self.state
+            .merge(Self::NAME, batch, |matrix| matrix.f1_score(average))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for PrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.current
+    }
+}
+
+impl<B: Backend> Numeric for RecallMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.current
+    }
+}
+
+impl<B: Backend> Numeric for F1ScoreMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_metrics_computed_over_the_epoch() {
+        let mut precision = PrecisionMetric::<TestBackend>::new(ClassAverage::Macro);
+        let mut recall = RecallMetric::<TestBackend>::new(ClassAverage::Macro);
+        let mut f1_score = F1ScoreMetric::<TestBackend>::new(ClassAverage::Macro);
+        let batches = [
+            ClassificationInput::new(
+                Tensor::from_data([[0.9, 0.1], [0.8, 0.2]]),
+                Tensor::from_data([0, 1]),
+            ),
+            ClassificationInput::new(
+                Tensor::from_data([[0.1, 0.9], [0.3, 0.7]]),
+                Tensor::from_data([1, 1]),
+            ),
+        ];
+
+        for batch in batches.iter() {
+            precision.update(batch, &MetricMetadata::fake());
+            recall.update(batch, &MetricMetadata::fake());
+            f1_score.update(batch, &MetricMetadata::fake());
+        }
+
+        // Class 0: 1 correct out of 2 predictions and 1 target.
+        // Class 1: 2 correct out of 2 predictions and 3 targets.
+        assert!((precision.value() - 75.0).abs() < 1e-9);
+        assert!((recall.value() - 100.0 * (1.0 + 2.0 / 3.0) / 2.0).abs() < 1e-9);
+        assert!((f1_score.value() - 100.0 * (2.0 / 3.0 + 0.8) / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_metrics_cleared_between_epochs() {
+        let mut precision = PrecisionMetric::<TestBackend>::new(ClassAverage::Micro);
+        let input = ClassificationInput::new(
+            Tensor::from_data([[0.9, 0.1], [0.8, 0.2]]),
+            Tensor::from_data([0, 1]),
+        );
+
+        precision.update(&input, &MetricMetadata::fake());
+        precision.clear();
+        assert!(precision.value().is_nan());
+
+        precision.update(&input, &MetricMetadata::fake());
+        assert_eq!(precision.value(), 50.0);
+    }
+}
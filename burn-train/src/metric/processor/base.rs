@@ -1,3 +1,4 @@
+use crate::profiler::ProfileSummary;
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
 
@@ -5,8 +6,18 @@ use burn_core::LearningRate;
 pub enum Event<T> {
     /// Signal that an item have been processed.
     ProcessedItem(LearnerItem<T>),
+    /// Signal that items have been processed on multiple devices during one step, their metric
+    /// states being merged as if they were processed in a single batch.
+    ProcessedItems(Vec<LearnerItem<T>>),
     /// Signal the end of an epoch.
     EndEpoch(usize),
+    /// Signal the start of a validation run during an epoch, whose items are aggregated on their
+    /// own instead of being added to the metrics of the epoch.
+    StartValidation,
+    /// Signal the end of a validation run during the given epoch.
+    EndValidation(usize),
+    /// Signal the updated [profile](ProfileSummary) of the training iterations.
+    Profile(ProfileSummary),
 }
 
 /// Process events happening during training and validation.
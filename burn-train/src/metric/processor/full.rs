@@ -1,15 +1,19 @@
-use super::{Event, EventProcessor, Metrics};
-use crate::metric::store::EventStoreClient;
-use crate::renderer::{MetricState, MetricsRenderer};
+use super::{Event, EventProcessor, LearnerItem, Metrics};
+use crate::metric::store::{EventStoreClient, MetricsUpdate, Split};
+use crate::renderer::{MetricState, MetricsRenderer, ProgressEvent, TrainingProgress};
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
 /// An [event processor](EventProcessor) that handles:
 ///   - Computing and storing metrics in an [event store](crate::metric::store::EventStore).
 ///   - Render metrics using a [metrics renderer](MetricsRenderer).
+///   - Send the [progress events](ProgressEvent) to the subscribers.
 pub struct FullEventProcessor<T, V> {
     metrics: Metrics<T, V>,
     renderer: Box<dyn MetricsRenderer>,
     store: Arc<EventStoreClient>,
+    subscribers: Vec<Sender<ProgressEvent>>,
+    validating: bool,
 }
 
 impl<T, V> FullEventProcessor<T, V> {
@@ -17,13 +21,81 @@ impl<T, V> FullEventProcessor<T, V> {
         metrics: Metrics<T, V>,
         renderer: Box<dyn MetricsRenderer>,
         store: Arc<EventStoreClient>,
+        subscribers: Vec<Sender<ProgressEvent>>,
     ) -> Self {
         Self {
             metrics,
             renderer,
             store,
+            subscribers,
+            validating: false,
         }
     }
+
+    /// Store the metrics update, then render it and send it to the subscribers.
+    ///
+    /// The updates of the validations run during an epoch aren't stored, so the metrics of the
+    /// epoch only come from its final validation.
+    fn process_update(&mut self, update: MetricsUpdate, split: Split) {
+        let event = crate::metric::store::Event::MetricsUpdate(update.clone());
+        match split {
+            Split::Train => self.store.add_event_train(event),
+            Split::Valid if self.validating => {}
+            Split::Valid => self.store.add_event_valid(event),
+        }
+
+        let states = update.entries.into_iter().map(MetricState::Generic).chain(
+            update
+                .entries_numeric
+                .into_iter()
+                .map(|(entry, value)| MetricState::Numeric(entry, value)),
+        );
+        for state in states {
+            self.publish(|| ProgressEvent::MetricUpdate {
+                split,
+                state: state.clone(),
+            });
+            match split {
+                Split::Train => self.renderer.update_train(state),
+                Split::Valid => self.renderer.update_valid(state),
+            }
+        }
+    }
+
+    /// Update the metrics with the training items of one step, then render the progress.
+    fn process_items_train(&mut self, items: &[LearnerItem<T>]) {
+        let update = self.metrics.update_train(items);
+        self.process_update(update, Split::Train);
+
+        let progress: TrainingProgress = items.last().expect("Processed items").into();
+        self.publish(|| ProgressEvent::Progress {
+            split: Split::Train,
+            progress: progress.clone(),
+        });
+        self.renderer.render_train(progress);
+    }
+
+    /// Update the metrics with the validation items of one step, then render the progress.
+    fn process_items_valid(&mut self, items: &[LearnerItem<V>]) {
+        let update = self.metrics.update_valid(items);
+        self.process_update(update, Split::Valid);
+
+        let progress: TrainingProgress = items.last().expect("Processed items").into();
+        self.publish(|| ProgressEvent::Progress {
+            split: Split::Valid,
+            progress: progress.clone(),
+        });
+        self.renderer.render_valid(progress);
+    }
+
+    /// Send the event to the subscribers, forgetting the ones that are disconnected.
+    fn publish<F>(&mut self, event: F)
+    where
+        F: Fn() -> ProgressEvent,
+    {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(event()).is_ok());
+    }
 }
 
 impl<T, V> EventProcessor for FullEventProcessor<T, V> {
@@ -32,69 +104,215 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
 
     fn process_train(&mut self, event: Event<Self::ItemTrain>) {
         match event {
-            Event::ProcessedItem(item) => {
-                let progress = (&item).into();
-                let metadata = (&item).into();
-
-                let update = self.metrics.update_train(&item, &metadata);
-
-                self.store
-                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update.clone()));
-
-                update
-                    .entries
-                    .into_iter()
-                    .for_each(|entry| self.renderer.update_train(MetricState::Generic(entry)));
-
-                update
-                    .entries_numeric
-                    .into_iter()
-                    .for_each(|(entry, value)| {
-                        self.renderer
-                            .update_train(MetricState::Numeric(entry, value))
-                    });
-
-                self.renderer.render_train(progress);
-            }
+            Event::ProcessedItem(item) => self.process_items_train(std::slice::from_ref(&item)),
+            Event::ProcessedItems(items) => self.process_items_train(&items),
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_train();
+                if let Some(update) = self.metrics.end_epoch_train(epoch) {
+                    self.process_update(update, Split::Train);
+                }
                 self.store
                     .add_event_train(crate::metric::store::Event::EndEpoch(epoch));
+                self.publish(|| ProgressEvent::EndEpoch {
+                    split: Split::Train,
+                    epoch,
+                });
+            }
+            Event::StartValidation | Event::EndValidation(_) => {}
+            Event::Profile(summary) => {
+                self.publish(|| ProgressEvent::Profile(summary.clone()));
+                self.renderer.update_profile(summary);
             }
         }
     }
 
     fn process_valid(&mut self, event: Event<Self::ItemValid>) {
         match event {
-            Event::ProcessedItem(item) => {
-                let progress = (&item).into();
-                let metadata = (&item).into();
-
-                let update = self.metrics.update_valid(&item, &metadata);
-
-                self.store
-                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update.clone()));
-
-                update
-                    .entries
-                    .into_iter()
-                    .for_each(|entry| self.renderer.update_valid(MetricState::Generic(entry)));
-
-                update
-                    .entries_numeric
-                    .into_iter()
-                    .for_each(|(entry, value)| {
-                        self.renderer
-                            .update_valid(MetricState::Numeric(entry, value))
-                    });
-
-                self.renderer.render_valid(progress);
-            }
+            Event::ProcessedItem(item) => self.process_items_valid(std::slice::from_ref(&item)),
+            Event::ProcessedItems(items) => self.process_items_valid(&items),
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_valid();
+                if let Some(update) = self.metrics.end_epoch_valid(epoch) {
+                    self.process_update(update, Split::Valid);
+                }
                 self.store
                     .add_event_valid(crate::metric::store::Event::EndEpoch(epoch));
+                self.publish(|| ProgressEvent::EndEpoch {
+                    split: Split::Valid,
+                    epoch,
+                });
+            }
+            Event::StartValidation => self.validating = true,
+            Event::EndValidation(epoch) => {
+                if let Some(update) = self.metrics.end_epoch_valid(epoch) {
+                    self.process_update(update, Split::Valid);
+                }
+                self.validating = false;
+            }
+            Event::Profile(summary) => {
+                self.publish(|| ProgressEvent::Profile(summary.clone()));
+                self.renderer.update_profile(summary);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::processor::LearnerItem;
+    use crate::metric::store::LogEventStore;
+    use crate::metric::{
+        AccuracyInput, AccuracyMetric, Adaptor, AurocInput, AurocMetric, LossMetric,
+    };
+    use crate::renderer::HeadlessMetricsRenderer;
+    use crate::TestBackend;
+    use burn_core::data::dataloader::Progress;
+    use burn_core::tensor::{Data, Tensor};
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_subscribers_receive_the_progress_events() {
+        let mut metrics = Metrics::<f64, f64>::default();
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(LogEventStore::default()));
+        let renderer = Box::new(HeadlessMetricsRenderer::new().with_writer(std::io::sink()));
+        let (sender, receiver) = mpsc::channel();
+        let mut processor = FullEventProcessor::new(metrics, renderer, store, vec![sender]);
+
+        processor.process_train(Event::ProcessedItem(LearnerItem::new(
+            0.5,
+            Progress {
+                items_processed: 1,
+                items_total: 1,
+            },
+            1,
+            1,
+            1,
+            None,
+        )));
+        processor.process_train(Event::EndEpoch(1));
+        drop(processor);
+
+        let events = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            &events[0],
+            ProgressEvent::MetricUpdate {
+                split: Split::Train,
+                state: MetricState::Numeric(entry, value),
+            } if entry.name == "Loss" && *value == 0.5
+        ));
+        assert!(matches!(
+            &events[1],
+            ProgressEvent::Progress { progress, .. } if progress.iteration == 1
+        ));
+        assert!(matches!(
+            events[2],
+            ProgressEvent::EndEpoch {
+                split: Split::Train,
+                epoch: 1
+            }
+        ));
+    }
+
+    struct AurocItem(f32, i64);
+
+    impl Adaptor<AurocInput<TestBackend>> for AurocItem {
+        fn adapt(&self) -> AurocInput<TestBackend> {
+            AurocInput::new(
+                Tensor::from_data([[self.0]]),
+                Tensor::from_data(Data::from([self.1])),
+            )
+        }
+    }
+
+    #[test]
+    fn test_epoch_level_metrics_published_at_the_end_of_the_epoch() {
+        let mut metrics = Metrics::<AurocItem, AurocItem>::default();
+        metrics.register_train_metric_numeric(AurocMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(LogEventStore::default()));
+        let renderer = Box::new(HeadlessMetricsRenderer::new().with_writer(std::io::sink()));
+        let (sender, receiver) = mpsc::channel();
+        let mut processor = FullEventProcessor::new(metrics, renderer, store, vec![sender]);
+
+        for (iteration, item) in [AurocItem(0.9, 1), AurocItem(0.2, 0)]
+            .into_iter()
+            .enumerate()
+        {
+            processor.process_train(Event::ProcessedItem(LearnerItem::new(
+                item,
+                Progress {
+                    items_processed: iteration + 1,
+                    items_total: 2,
+                },
+                1,
+                1,
+                iteration + 1,
+                None,
+            )));
+        }
+        processor.process_train(Event::EndEpoch(1));
+        drop(processor);
+
+        let updates = receiver
+            .iter()
+            .filter_map(|event| match event {
+                ProgressEvent::MetricUpdate {
+                    state: MetricState::Numeric(entry, value),
+                    ..
+                } => Some((entry.name, value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(updates, vec![("AUROC".to_string(), 1.0)]);
+    }
+
+    struct AccuracyItem(Vec<f32>, Vec<i64>);
+
+    impl Adaptor<AccuracyInput<TestBackend>> for AccuracyItem {
+        fn adapt(&self) -> AccuracyInput<TestBackend> {
+            AccuracyInput::new(
+                Tensor::<TestBackend, 1>::from_floats(self.0.as_slice()).reshape([self.1.len(), 2]),
+                Tensor::from_data(Data::from(self.1.as_slice())),
+            )
+        }
+    }
+
+    #[test]
+    fn test_items_of_multiple_devices_merged_as_a_single_batch() {
+        let mut metrics = Metrics::<AccuracyItem, AccuracyItem>::default();
+        metrics.register_train_metric_numeric(AccuracyMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(LogEventStore::default()));
+        let renderer = Box::new(HeadlessMetricsRenderer::new().with_writer(std::io::sink()));
+        let (sender, receiver) = mpsc::channel();
+        let mut processor = FullEventProcessor::new(metrics, renderer, store, vec![sender]);
+        let item = |item, iteration| {
+            let progress = Progress {
+                items_processed: iteration,
+                items_total: 2,
+            };
+            LearnerItem::new(item, progress, 1, 1, iteration, None)
+        };
+
+        // One correct prediction out of one on the first device, one out of three on the other.
+        processor.process_train(Event::ProcessedItems(vec![
+            item(AccuracyItem(vec![0.0, 1.0], vec![1]), 1),
+            item(
+                AccuracyItem(vec![0.0, 1.0, 1.0, 0.0, 1.0, 0.0], vec![1, 1, 1]),
+                2,
+            ),
+        ]));
+        drop(processor);
+
+        let updates = receiver
+            .iter()
+            .filter_map(|event| match event {
+                ProgressEvent::MetricUpdate {
+                    state: MetricState::Numeric(_, value),
+                    ..
+                } => Some(value),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(updates, vec![50.0]);
+    }
+}
@@ -1,6 +1,9 @@
 use super::LearnerItem;
 use crate::{
-    metric::{store::MetricsUpdate, Adaptor, Metric, MetricEntry, MetricMetadata, Numeric},
+    metric::{
+        format_float, store::MetricsUpdate, Adaptor, MergeableState, Metric, MetricEntry,
+        MetricMetadata, Numeric,
+    },
     renderer::TrainingProgress,
 };
 
@@ -9,6 +12,8 @@ pub(crate) struct Metrics<T, V> {
     valid: Vec<Box<dyn MetricUpdater<V>>>,
     train_numeric: Vec<Box<dyn NumericMetricUpdater<T>>>,
     valid_numeric: Vec<Box<dyn NumericMetricUpdater<V>>>,
+    iteration_train: usize,
+    iteration_valid: usize,
 }
 
 impl<T, V> Default for Metrics<T, V> {
@@ -18,6 +23,8 @@ impl<T, V> Default for Metrics<T, V> {
             valid: Vec::default(),
             train_numeric: Vec::default(),
             valid_numeric: Vec::default(),
+            iteration_train: 0,
+            iteration_valid: 0,
         }
     }
 }
@@ -63,68 +70,94 @@ impl<T, V> Metrics<T, V> {
         self.valid_numeric.push(Box::new(metric))
     }
 
-    /// Update the training information from the training item.
-    pub(crate) fn update_train(
-        &mut self,
-        item: &LearnerItem<T>,
-        metadata: &MetricMetadata,
-    ) -> MetricsUpdate {
+    /// Update the training information from the training items of one step, processed on one
+    /// or multiple devices.
+    pub(crate) fn update_train(&mut self, items: &[LearnerItem<T>]) -> MetricsUpdate {
+        let metadata = &items
+            .last()
+            .expect("At least one item should be processed")
+            .into();
         let mut entries = Vec::with_capacity(self.train.len());
         let mut entries_numeric = Vec::with_capacity(self.train_numeric.len());
 
         for metric in self.train.iter_mut() {
-            let state = metric.update(item, metadata);
+            let state = metric.update(items, metadata);
             entries.push(state);
         }
 
         for metric in self.train_numeric.iter_mut() {
-            let (state, value) = metric.update(item, metadata);
-            entries_numeric.push((state, value));
+            if let Some(entry) = metric.update(items, metadata) {
+                entries_numeric.push(entry);
+            }
         }
 
-        MetricsUpdate::new(entries, entries_numeric)
+        self.iteration_train = metadata.iteration;
+        MetricsUpdate::new(entries, entries_numeric, metadata.epoch, metadata.iteration)
     }
 
-    /// Update the training information from the validation item.
-    pub(crate) fn update_valid(
-        &mut self,
-        item: &LearnerItem<V>,
-        metadata: &MetricMetadata,
-    ) -> MetricsUpdate {
+    /// Update the training information from the validation items of one step, processed on one
+    /// or multiple devices.
+    pub(crate) fn update_valid(&mut self, items: &[LearnerItem<V>]) -> MetricsUpdate {
+        let metadata = &items
+            .last()
+            .expect("At least one item should be processed")
+            .into();
         let mut entries = Vec::with_capacity(self.valid.len());
         let mut entries_numeric = Vec::with_capacity(self.valid_numeric.len());
 
         for metric in self.valid.iter_mut() {
-            let state = metric.update(item, metadata);
+            let state = metric.update(items, metadata);
             entries.push(state);
         }
 
         for metric in self.valid_numeric.iter_mut() {
-            let (state, value) = metric.update(item, metadata);
-            entries_numeric.push((state, value));
+            if let Some(entry) = metric.update(items, metadata) {
+                entries_numeric.push(entry);
+            }
         }
 
-        MetricsUpdate::new(entries, entries_numeric)
+        self.iteration_valid = metadata.iteration;
+        MetricsUpdate::new(entries, entries_numeric, metadata.epoch, metadata.iteration)
     }
 
-    /// Signal the end of a training epoch.
-    pub(crate) fn end_epoch_train(&mut self) {
+    /// Signal the end of a training epoch, returning the values of the
+    /// [epoch level](Numeric::is_epoch_level) metrics.
+    pub(crate) fn end_epoch_train(&mut self, epoch: usize) -> Option<MetricsUpdate> {
+        let entries_numeric = self
+            .train_numeric
+            .iter_mut()
+            .filter_map(|metric| metric.end_epoch())
+            .collect::<Vec<_>>();
+
         for metric in self.train.iter_mut() {
             metric.clear();
         }
         for metric in self.train_numeric.iter_mut() {
             metric.clear();
         }
+
+        (!entries_numeric.is_empty())
+            .then(|| MetricsUpdate::new(Vec::new(), entries_numeric, epoch, self.iteration_train))
     }
 
-    /// Signal the end of a validation epoch.
-    pub(crate) fn end_epoch_valid(&mut self) {
+    /// Signal the end of a validation epoch, returning the values of the
+    /// [epoch level](Numeric::is_epoch_level) metrics.
+    pub(crate) fn end_epoch_valid(&mut self, epoch: usize) -> Option<MetricsUpdate> {
+        let entries_numeric = self
+            .valid_numeric
+            .iter_mut()
+            .filter_map(|metric| metric.end_epoch())
+            .collect::<Vec<_>>();
+
         for metric in self.valid.iter_mut() {
             metric.clear();
         }
         for metric in self.valid_numeric.iter_mut() {
             metric.clear();
         }
+
+        (!entries_numeric.is_empty())
+            .then(|| MetricsUpdate::new(Vec::new(), entries_numeric, epoch, self.iteration_valid))
     }
 }
 
@@ -152,12 +185,21 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
 }
 
 trait NumericMetricUpdater<T>: Send + Sync {
-    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> (MetricEntry, f64);
+    /// Update the metric with the merged states of the items, returning its value unless it is
+    /// only computed at the end of the epoch.
+    fn update(
+        &mut self,
+        items: &[LearnerItem<T>],
+        metadata: &MetricMetadata,
+    ) -> Option<(MetricEntry, f64)>;
+    /// The value of the metric computed at the end of the epoch, if it is epoch level.
+    fn end_epoch(&mut self) -> Option<(MetricEntry, f64)>;
     fn clear(&mut self);
 }
 
 trait MetricUpdater<T>: Send + Sync {
-    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> MetricEntry;
+    /// Update the metric with the merged states of the items.
+    fn update(&mut self, items: &[LearnerItem<T>], metadata: &MetricMetadata) -> MetricEntry;
     fn clear(&mut self);
 }
 
@@ -166,17 +208,56 @@ struct MetricWrapper<M> {
     metric: M,
 }
 
+impl<M: Metric> MetricWrapper<M> {
+    /// Merge the states of the items processed on the different devices, so the metric is
+    /// updated as if they were processed in a single batch.
+    fn batch_state<T: Adaptor<M::Input>>(&self, items: &[LearnerItem<T>]) -> M::State {
+        let mut states = items
+            .iter()
+            .map(|item| self.metric.batch_state(&item.item.adapt(), &item.into()));
+        let mut state = states
+            .next()
+            .expect("At least one item should be processed");
+        states.for_each(|other| state.merge(other));
+
+        state
+    }
+}
+
 impl<T, M> NumericMetricUpdater<T> for MetricWrapper<M>
 where
     T: 'static,
     M: Metric + Numeric + 'static,
     T: Adaptor<M::Input>,
 {
-    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> (MetricEntry, f64) {
-        let update = self.metric.update(&item.item.adapt(), metadata);
-        let numeric = self.metric.value();
+    fn update(
+        &mut self,
+        items: &[LearnerItem<T>],
+        metadata: &MetricMetadata,
+    ) -> Option<(MetricEntry, f64)> {
+        let state = self.batch_state(items);
+        let update = self.metric.merge(state, metadata);
+
+        if self.metric.is_epoch_level() {
+            return None;
+        }
+
+        Some((update, self.metric.value()))
+    }
+
+    fn end_epoch(&mut self) -> Option<(MetricEntry, f64)> {
+        if !self.metric.is_epoch_level() {
+            return None;
+        }
+
+        let value = self.metric.value();
+        let entry = MetricEntry::new(
+            M::NAME.to_string(),
+            format_float(value, 2),
+            value.to_string(),
+        );
 
-        (update, numeric)
+        Some((entry, value))
     }
 
     fn clear(&mut self) {
@@ -190,8 +271,9 @@ where
     M: Metric + 'static,
     T: Adaptor<M::Input>,
 {
-    fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> MetricEntry {
-        self.metric.update(&item.item.adapt(), metadata)
+    fn update(&mut self, items: &[LearnerItem<T>], metadata: &MetricMetadata) -> MetricEntry {
+        let state = self.batch_state(items);
+        self.metric.merge(state, metadata)
     }
 
     fn clear(&mut self) {
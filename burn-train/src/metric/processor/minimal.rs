@@ -8,6 +8,8 @@ use std::sync::Arc;
 pub(crate) struct MinimalEventProcessor<T, V> {
     metrics: Metrics<T, V>,
     store: Arc<EventStoreClient>,
+    #[new(default)]
+    validating: bool,
 }
 
 impl<T, V> EventProcessor for MinimalEventProcessor<T, V> {
@@ -17,36 +19,62 @@ impl<T, V> EventProcessor for MinimalEventProcessor<T, V> {
     fn process_train(&mut self, event: Event<Self::ItemTrain>) {
         match event {
             Event::ProcessedItem(item) => {
-                let metadata = (&item).into();
+                let update = self.metrics.update_train(std::slice::from_ref(&item));
 
-                let update = self.metrics.update_train(&item, &metadata);
+                self.store
+                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
+            }
+            Event::ProcessedItems(items) => {
+                let update = self.metrics.update_train(&items);
 
                 self.store
                     .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
             }
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_train();
+                if let Some(update) = self.metrics.end_epoch_train(epoch) {
+                    self.store
+                        .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
+                }
                 self.store
                     .add_event_train(crate::metric::store::Event::EndEpoch(epoch));
             }
+            Event::StartValidation | Event::EndValidation(_) => {}
+            Event::Profile(_) => {}
         }
     }
 
     fn process_valid(&mut self, event: Event<Self::ItemValid>) {
         match event {
             Event::ProcessedItem(item) => {
-                let metadata = (&item).into();
+                let update = self.metrics.update_valid(std::slice::from_ref(&item));
 
-                let update = self.metrics.update_valid(&item, &metadata);
+                if !self.validating {
+                    self.store
+                        .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+                }
+            }
+            Event::ProcessedItems(items) => {
+                let update = self.metrics.update_valid(&items);
 
-                self.store
-                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+                if !self.validating {
+                    self.store
+                        .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+                }
             }
             Event::EndEpoch(epoch) => {
-                self.metrics.end_epoch_valid();
+                if let Some(update) = self.metrics.end_epoch_valid(epoch) {
+                    self.store
+                        .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+                }
                 self.store
                     .add_event_valid(crate::metric::store::Event::EndEpoch(epoch));
             }
+            Event::StartValidation => self.validating = true,
+            Event::EndValidation(epoch) => {
+                self.metrics.end_epoch_valid(epoch);
+                self.validating = false;
+            }
+            Event::Profile(_) => {}
         }
     }
 }
@@ -46,6 +46,28 @@ pub(crate) mod test_utils {
         )));
     }
 
+    pub(crate) fn process_valid(
+        processor: &mut MinimalEventProcessor<f64, f64>,
+        value: f64,
+        epoch: usize,
+    ) {
+        let dummy_progress = burn_core::data::dataloader::Progress {
+            items_processed: 1,
+            items_total: 10,
+        };
+        let num_epochs = 3;
+        let dummy_iteration = 1;
+
+        processor.process_valid(Event::ProcessedItem(LearnerItem::new(
+            value,
+            dummy_progress,
+            epoch,
+            num_epochs,
+            dummy_iteration,
+            None,
+        )));
+    }
+
     pub(crate) fn end_epoch(processor: &mut MinimalEventProcessor<f64, f64>, epoch: usize) {
         processor.process_train(Event::EndEpoch(epoch));
         processor.process_valid(Event::EndEpoch(epoch));
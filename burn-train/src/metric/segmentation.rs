@@ -0,0 +1,262 @@
+use super::confusion::{ConfusionMatrix, ConfusionMatrixState};
+use super::{format_float, ClassAverage, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// The input type of the segmentation metrics, the [mean IoU](MeanIouMetric) and the
+/// [Dice coefficient](DiceMetric).
+pub struct SegmentationInput<B: Backend> {
+    num_classes: usize,
+    predictions: Tensor<B, 3, Int>,
+    targets: Tensor<B, 3, Int>,
+}
+
+impl<B: Backend> SegmentationInput<B> {
+    /// Create the input from the logits of shape `[batch_size, num_classes, height, width]` and
+    /// the class-index masks of shape `[batch_size, height, width]`.
+    pub fn from_logits(logits: Tensor<B, 4>, targets: Tensor<B, 3, Int>) -> Self {
+        let [batch_size, num_classes, height, width] = logits.dims();
+        let predictions = logits.argmax(1).reshape([batch_size, height, width]);
+
+        Self {
+            num_classes,
+            predictions,
+            targets,
+        }
+    }
+
+    /// Create the input from the predicted and the target class-index masks of shape
+    /// `[batch_size, height, width]`.
+    pub fn from_masks(predictions: Tensor<B, 3, Int>, targets: Tensor<B, 3, Int>) -> Self {
+        let max_class =
+            |mask: &Tensor<B, 3, Int>| mask.clone().max().into_scalar().elem::<i64>() as usize;
+        let num_classes = usize::max(max_class(&predictions), max_class(&targets)) + 1;
+
+        Self {
+            num_classes,
+            predictions,
+            targets,
+        }
+    }
+
+    fn confusion_matrix(&self, ignore_index: Option<usize>) -> ConfusionMatrix {
+        ConfusionMatrix::from_indices(
+            self.num_classes,
+            self.targets.clone().into_data().value,
+            self.predictions.clone().into_data().value,
+            ignore_index,
+        )
+    }
+}
+
+/// The mean intersection over union (mIoU) metric, the mean over the classes of the number of
+/// pixels both predicted and targeted divided by the number of pixels either predicted or
+/// targeted.
+///
+/// The pixels are counted over all the batches of the epoch, so the last value of each epoch
+/// should be used, e.g. with [Aggregate::Last](crate::metric::store::Aggregate::Last). The IoU of
+/// each class is displayed along with the mean.
+#[derive(Default)]
+pub struct MeanIouMetric<B: Backend> {
+    state: SegmentationState,
+    _b: B,
+}
+
+/// The Dice coefficient metric, the mean over the classes of twice the number of pixels both
+/// predicted and targeted divided by the sum of the numbers of predicted and targeted pixels.
+///
+/// The pixels are counted over all the batches of the epoch, so the last value of each epoch
+/// should be used, e.g. with [Aggregate::Last](crate::metric::store::Aggregate::Last). The Dice
+/// coefficient of each class is displayed along with the mean.
+#[derive(Default)]
+pub struct DiceMetric<B: Backend> {
+    state: SegmentationState,
+    _b: B,
+}
+
+struct SegmentationState {
+    matrix: ConfusionMatrixState,
+    ignore_index: Option<usize>,
+}
+
+impl Default for SegmentationState {
+    fn default() -> Self {
+        Self {
+            matrix: ConfusionMatrixState::new(),
+            ignore_index: None,
+        }
+    }
+}
+
+impl SegmentationState {
+    fn merge(
+        &mut self,
+        name: &str,
+        batch: ConfusionMatrix,
+        compute: fn(&ConfusionMatrix, ClassAverage) -> f64,
+        compute_class: fn(&ConfusionMatrix, usize) -> f64,
+    ) -> MetricEntry {
+        let mut entry = self
+            .matrix
+            .merge(name, batch, |matrix| compute(matrix, ClassAverage::Macro));
+
+        if let Some(matrix) = &self.matrix.matrix {
+            let classes = matrix
+                .per_class(compute_class)
+                .into_iter()
+                .map(|value| match value {
+                    Some(value) => format_float(100.0 * value, 2),
+                    None => "-".to_string(),
+                })
+                .collect::<Vec<_>>();
+
+            entry.formatted = format!("{}\nclasses {}", entry.formatted, classes.join(" "));
+        }
+
+        entry
+    }
+}
+
+impl<B: Backend> MeanIouMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the target index of the pixels to ignore, e.g. the unlabeled pixels.
+    pub fn with_ignore_index(mut self, index: usize) -> Self {
+        self.state.ignore_index = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> DiceMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the target index of the pixels to ignore, e.g. the unlabeled pixels.
+    pub fn with_ignore_index(mut self, index: usize) -> Self {
+        self.state.ignore_index = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for MeanIouMetric<B> {
+    const NAME: &'static str = "Mean IoU";
+
+    type Input = SegmentationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &SegmentationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        input.confusion_matrix(self.state.ignore_index)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state.merge(
+            Self::NAME,
+            batch,
+            ConfusionMatrix::iou,
+            ConfusionMatrix::iou_class,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.matrix.reset()
+    }
+}
+
+impl<B: Backend> Metric for DiceMetric<B> {
+    const NAME: &'static str = "Dice";
+
+    type Input = SegmentationInput<B>;
+    type State = ConfusionMatrix;
+
+    fn batch_state(
+        &self,
+        input: &SegmentationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> ConfusionMatrix {
+        input.confusion_matrix(self.state.ignore_index)
+    }
+
+    fn merge(&mut self, batch: ConfusionMatrix, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state.merge(
+            Self::NAME,
+            batch,
+            ConfusionMatrix::f1_score,
+            ConfusionMatrix::f1_score_class,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.matrix.reset()
+    }
+}
+
+impl<B: Backend> Numeric for MeanIouMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.matrix.current
+    }
+}
+
+impl<B: Backend> Numeric for DiceMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.matrix.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn input() -> SegmentationInput<TestBackend> {
+        SegmentationInput::from_masks(
+            Tensor::from_data([[[0, 0], [1, 1]]]),
+            Tensor::from_data([[[0, 1], [1, 1]]]),
+        )
+    }
+
+    #[test]
+    fn test_mean_iou() {
+        let mut metric = MeanIouMetric::<TestBackend>::new();
+
+        let entry = metric.update(&input(), &MetricMetadata::fake());
+
+        // Class 0: 1 pixel in the intersection and 2 in the union.
+        // Class 1: 2 pixels in the intersection and 3 in the union.
+        assert!((metric.value() - 100.0 * (0.5 + 2.0 / 3.0) / 2.0).abs() < 1e-9);
+        assert!(entry.formatted.ends_with("\nclasses 50.00 66.67"));
+    }
+
+    #[test]
+    fn test_dice() {
+        let mut metric = DiceMetric::<TestBackend>::new();
+
+        let _entry = metric.update(&input(), &MetricMetadata::fake());
+
+        // Class 0: 2 * 1 / (2 + 1), class 1: 2 * 2 / (2 + 3).
+        assert!((metric.value() - 100.0 * (2.0 / 3.0 + 0.8) / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mean_iou_from_logits_with_ignore_index() {
+        let mut metric = MeanIouMetric::<TestBackend>::new().with_ignore_index(2);
+        let input = SegmentationInput::from_logits(
+            // Predictions [[0, 1], [1, 0]].
+            Tensor::from_data([[[[0.9, 0.1], [0.2, 0.8]], [[0.1, 0.9], [0.8, 0.2]]]]),
+            Tensor::from_data([[[0, 1], [1, 2]]]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(metric.value(), 100.0);
+    }
+}
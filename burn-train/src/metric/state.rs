@@ -1,4 +1,33 @@
-use crate::metric::{format_float, MetricEntry, Numeric};
+use crate::metric::{format_float, MergeableState, MetricEntry, Numeric};
+
+/// The [mergeable state](MergeableState) of a metric averaged over the items: the sum of the
+/// values of the items and their number.
+#[derive(new, Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeanState {
+    /// The sum of the values of the items.
+    pub sum: f64,
+    /// The number of items.
+    pub count: usize,
+}
+
+impl MeanState {
+    /// The state of items whose values have the given mean.
+    pub fn from_mean(mean: f64, count: usize) -> Self {
+        Self::new(mean * count as f64, count)
+    }
+
+    /// The mean of the values of the items.
+    pub fn mean(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+}
+
+impl MergeableState for MeanState {
+    fn merge(&mut self, other: Self) {
+        self.sum += other.sum;
+        self.count += other.count;
+    }
+}
 
 /// Useful utility to implement numeric metrics.
 ///
@@ -61,11 +90,16 @@ impl NumericMetricState {
 
     /// Update the state.
     pub fn update(&mut self, value: f64, batch_size: usize, format: FormatOptions) -> MetricEntry {
-        self.sum += value * batch_size as f64;
-        self.count += batch_size;
-        self.current = value;
+        self.merge(MeanState::from_mean(value, batch_size), format)
+    }
 
-        let value_current = value;
+    /// Merge the state of a batch, the batch value being the mean of its items.
+    pub fn merge(&mut self, state: MeanState, format: FormatOptions) -> MetricEntry {
+        self.sum += state.sum;
+        self.count += state.count;
+        self.current = state.mean();
+
+        let value_current = self.current;
         let value_running = self.sum / self.count as f64;
         let serialized = value_current.to_string();
 
@@ -99,3 +133,63 @@ impl Default for NumericMetricState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{Metric, MetricMetadata};
+
+    #[derive(Default)]
+    struct ErrorRate {
+        state: NumericMetricState,
+    }
+
+    impl Metric for ErrorRate {
+        const NAME: &'static str = "Error Rate";
+
+        type Input = Vec<bool>;
+        type State = MeanState;
+
+        fn batch_state(&self, input: &Vec<bool>, _metadata: &MetricMetadata) -> MeanState {
+            let errors = input.iter().filter(|error| **error).count();
+            MeanState::new(100.0 * errors as f64, input.len())
+        }
+
+        fn merge(&mut self, state: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
+            self.state
+                .merge(state, FormatOptions::new(Self::NAME).unit("%").precision(2))
+        }
+
+        fn clear(&mut self) {
+            self.state.reset()
+        }
+    }
+
+    #[test]
+    fn test_state_merged_over_batches_of_different_sizes() {
+        let mut metric = ErrorRate::default();
+
+        let _entry = metric.update(&vec![true], &MetricMetadata::fake());
+        let entry = metric.update(&vec![true, false, false, false], &MetricMetadata::fake());
+
+        // 2 errors out of 5 items, while the mean of the batch values would be 62.5 %.
+        assert_eq!(entry.formatted, "epoch 40.00 % - batch 25.00 %");
+    }
+
+    #[test]
+    fn test_states_merged_across_devices() {
+        let mut metric = ErrorRate::default();
+        let metadata = MetricMetadata::fake();
+
+        let mut state = metric.batch_state(&vec![true, false], &metadata);
+        state.merge(metric.batch_state(&vec![true, true], &metadata));
+        let entry = metric.merge(state, &metadata);
+
+        assert_eq!(state, MeanState::new(300.0, 4));
+        assert_eq!(metric.state.value(), 75.0);
+        assert_eq!(entry.formatted, "epoch 75.00 % - batch 75.00 %");
+
+        metric.clear();
+        assert!(metric.state.value().is_nan());
+    }
+}
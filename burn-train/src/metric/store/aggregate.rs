@@ -48,10 +48,9 @@ impl NumericMetricsAggregate {
             return None;
         }
 
-        let num_points = points.len();
-        let sum = points.into_iter().sum::<f64>();
         let value = match aggregate {
-            Aggregate::Mean => sum / num_points as f64,
+            Aggregate::Mean => points.iter().sum::<f64>() / points.len() as f64,
+            Aggregate::Last => *points.last().unwrap(),
         };
 
         self.value_for_each_epoch.insert(key, value);
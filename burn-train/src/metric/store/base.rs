@@ -15,6 +15,19 @@ pub struct MetricsUpdate {
     pub entries: Vec<MetricEntry>,
     /// Metrics information related to numeric metrics.
     pub entries_numeric: Vec<(MetricEntry, f64)>,
+    /// The epoch of the update.
+    pub epoch: usize,
+    /// The iteration of the update in the epoch.
+    pub iteration: usize,
+}
+
+impl MetricsUpdate {
+    /// Iterate over all the entries, the numeric entries last.
+    pub fn iter_entries(&self) -> impl Iterator<Item = &MetricEntry> {
+        self.entries
+            .iter()
+            .chain(self.entries_numeric.iter().map(|(entry, _value)| entry))
+    }
 }
 
 /// Defines how training and validation events are collected and searched.
@@ -48,9 +61,13 @@ pub trait EventStore: Send {
 pub enum Aggregate {
     /// Compute the average.
     Mean,
+    /// The last value of the epoch.
+    ///
+    /// Useful for metrics already computed over all the items of the epoch.
+    Last,
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 /// The split to use.
 pub enum Split {
     /// The training split.
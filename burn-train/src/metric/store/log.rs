@@ -13,28 +13,14 @@ impl EventStore for LogEventStore {
     fn add_event(&mut self, event: Event, split: Split) {
         match event {
             Event::MetricsUpdate(update) => match split {
-                Split::Train => {
-                    update
-                        .entries
-                        .iter()
-                        .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
-                        .for_each(|entry| {
-                            self.loggers_train
-                                .iter_mut()
-                                .for_each(|logger| logger.log(entry));
-                        });
-                }
-                Split::Valid => {
-                    update
-                        .entries
-                        .iter()
-                        .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
-                        .for_each(|entry| {
-                            self.loggers_valid
-                                .iter_mut()
-                                .for_each(|logger| logger.log(entry));
-                        });
-                }
+                Split::Train => self
+                    .loggers_train
+                    .iter_mut()
+                    .for_each(|logger| logger.log_step(&update)),
+                Split::Valid => self
+                    .loggers_valid
+                    .iter_mut()
+                    .for_each(|logger| logger.log_step(&update)),
             },
             Event::EndEpoch(epoch) => match split {
                 Split::Train => self
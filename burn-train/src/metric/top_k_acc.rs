@@ -0,0 +1,143 @@
+use super::state::{FormatOptions, MeanState, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// The top-k accuracy metric.
+///
+/// A prediction is correct when the target is among the `k` classes with the highest outputs.
+#[derive(Default)]
+pub struct TopKAccuracyMetric<B: Backend> {
+    k: usize,
+    state: NumericMetricState,
+    pad_token: Option<usize>,
+    _b: B,
+}
+
+/// The [top-k accuracy metric](TopKAccuracyMetric) input type.
+#[derive(new)]
+pub struct TopKAccuracyInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> TopKAccuracyMetric<B> {
+    /// Creates the metric with the number of highest outputs considered.
+    pub fn new(k: usize) -> Self {
+        assert!(
+            k > 0,
+            "The top-k accuracy requires k to be greater than zero"
+        );
+
+        Self {
+            k,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the pad token.
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for TopKAccuracyMetric<B> {
+    const NAME: &'static str = "Top-K Accuracy";
+
+    type Input = TopKAccuracyInput<B>;
+    type State = MeanState;
+
+    fn batch_state(&self, input: &TopKAccuracyInput<B>, _metadata: &MetricMetadata) -> MeanState {
+        let [batch_size, num_classes] = input.outputs.dims();
+
+        let targets = input.targets.clone().to_device(&B::Device::default());
+        let outputs = input.outputs.clone().to_device(&B::Device::default());
+
+        // The target is in the top k when fewer than k classes have a strictly higher output.
+        let target_outputs = outputs
+            .clone()
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .repeat(1, num_classes);
+        let num_higher = outputs
+            .greater(target_outputs)
+            .int()
+            .sum_dim(1)
+            .reshape([batch_size]);
+        let matches = num_higher.lower_elem(self.k as i64).int();
+
+        let (num_matches, num_items) = match self.pad_token {
+            Some(pad_token) => {
+                let mask = targets.equal_elem(pad_token as i64);
+                let matches = matches.mask_fill(mask.clone(), 0);
+                let num_pad = mask.int().sum().into_scalar().elem::<i64>() as usize;
+
+                (
+                    matches.sum().into_scalar().elem::<f64>(),
+                    batch_size - num_pad,
+                )
+            }
+            None => (matches.sum().into_scalar().elem::<f64>(), batch_size),
+        };
+
+        MeanState::new(100.0 * num_matches, num_items)
+    }
+
+    fn merge(&mut self, state: MeanState, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state
+            .merge(state, FormatOptions::new(Self::NAME).unit("%").precision(2))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for TopKAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_top_k_accuracy_without_padding() {
+        let mut metric = TopKAccuracyMetric::<TestBackend>::new(2);
+        let input = TopKAccuracyInput::new(
+            Tensor::from_data([
+                [0.0, 0.2, 0.8], // 2, 1
+                [1.0, 2.0, 0.5], // 1, 0
+                [0.4, 0.1, 0.2], // 0, 2
+                [0.6, 0.7, 0.2], // 1, 0
+            ]),
+            Tensor::from_data([2, 2, 1, 0]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+
+    #[test]
+    fn test_top_k_accuracy_with_padding() {
+        let mut metric = TopKAccuracyMetric::<TestBackend>::new(2).with_pad_token(3);
+        let input = TopKAccuracyInput::new(
+            Tensor::from_data([
+                [0.0, 0.2, 0.8, 0.0], // 2, 1
+                [1.0, 2.0, 0.5, 0.0], // 1, 0
+                [0.4, 0.1, 0.2, 0.0], // 0, 2
+                [0.6, 0.7, 0.2, 0.0], // 1, 0
+                [0.0, 0.1, 0.2, 5.0], // Predicted padding should not count
+                [0.0, 0.1, 0.2, 0.0], // Error on padding should not count
+            ]),
+            Tensor::from_data([2, 2, 1, 0, 3, 3]),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+}
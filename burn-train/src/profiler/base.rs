@@ -0,0 +1,387 @@
+use super::{ChromeTrace, MemorySampler};
+use burn_core::tensor::backend::Backend;
+use std::cell::{Cell, RefCell};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A phase of a training iteration measured by the profiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// Waiting for the next batch of the dataloader.
+    DataLoading,
+    /// The forward pass, i.e. the [train step](crate::TrainStep) without the backward pass.
+    Forward,
+    /// The backward pass, measured by [TrainOutput::from_loss](crate::TrainOutput::from_loss) or
+    /// with [scope].
+    Backward,
+    /// The gradient accumulation and the optimizer step.
+    Optimizer,
+    /// The metrics, the loggers, the renderer and the callbacks.
+    Metrics,
+}
+
+impl Phase {
+    /// All the phases, in the order they happen during an iteration.
+    pub const ALL: [Phase; 5] = [
+        Phase::DataLoading,
+        Phase::Forward,
+        Phase::Backward,
+        Phase::Optimizer,
+        Phase::Metrics,
+    ];
+
+    /// The name of the phase.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::DataLoading => "Data loading",
+            Phase::Forward => "Forward",
+            Phase::Backward => "Backward",
+            Phase::Optimizer => "Optimizer",
+            Phase::Metrics => "Metrics",
+        }
+    }
+}
+
+thread_local! {
+    // The scopes measured during the current step, only collected on the thread of a profiled
+    // training.
+    static SCOPES: RefCell<Option<Vec<(Phase, Instant, Instant)>>> = const { RefCell::new(None) };
+    // The number of scopes being measured, the scopes nested in them not being recorded twice.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+
+/// Measure a part of the [train step](crate::TrainStep) when the training is profiled.
+///
+/// The learner only sees the train step as a whole and counts it as the forward pass, except the
+/// backward pass of [TrainOutput::from_loss](crate::TrainOutput::from_loss) and the parts
+/// measured with this function, e.g. a backward pass executed by the train step itself:
+///
+/// ```ignore
+/// let grads = burn_train::profiler::scope(Phase::Backward, || loss.backward());
+/// ```
+///
+/// The scopes nested in another one are only measured as part of the outer scope.
+///
+/// With an asynchronous backend, the measured time is the time spent to launch the operations,
+/// the learner waiting for their completion only at the end of the step.
+pub fn scope<R, F>(phase: Phase, func: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let start = Instant::now();
+    let depth = DEPTH.with(|depth| depth.replace(depth.get() + 1));
+    let output = func();
+    DEPTH.with(|current| current.set(depth));
+
+    if depth == 0 {
+        SCOPES.with(|scopes| {
+            if let Some(scopes) = scopes.borrow_mut().as_mut() {
+                scopes.push((phase, start, Instant::now()));
+            }
+        });
+    }
+
+    output
+}
+
+/// The mean time of each phase of the training iterations and the peak memory of the devices.
+#[derive(Clone, Debug, Default)]
+pub struct ProfileSummary {
+    /// The number of profiled iterations.
+    pub iterations: usize,
+    /// The mean duration of each phase per iteration.
+    pub phases: Vec<(Phase, Duration)>,
+    /// The peak memory used on each device in bytes.
+    pub peak_memory: Vec<(String, u64)>,
+}
+
+impl core::fmt::Display for ProfileSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let total = self
+            .phases
+            .iter()
+            .map(|(_, duration)| *duration)
+            .sum::<Duration>();
+        write!(f, "Profile of {} iterations", self.iterations)?;
+
+        for (phase, duration) in self.phases.iter() {
+            let percent = match total.is_zero() {
+                true => 0.0,
+                false => 100.0 * duration.as_secs_f64() / total.as_secs_f64(),
+            };
+            write!(
+                f,
+                "\n  {:<13}: {:>9.3} ms ({:>4.1}%)",
+                phase.name(),
+                duration.as_secs_f64() * 1e3,
+                percent
+            )?;
+        }
+
+        for (device, bytes) in self.peak_memory.iter() {
+            write!(
+                f,
+                "\n  {:<13}: {:>9.3} Gb peak",
+                device,
+                *bytes as f64 * 1e-9
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Measures the phases of the training iterations, writing them to a chrome trace.
+///
+/// Each phase goes from the end of the previous one to its mark, waiting for the operations
+/// launched on the devices to complete, so the time of asynchronous backends is attributed to the
+/// right phase. It is created by the [learner builder](crate::LearnerBuilder::profile).
+pub struct Profiler<B: Backend> {
+    devices: Vec<B::Device>,
+    start: Instant,
+    last: Instant,
+    epoch: usize,
+    iteration: usize,
+    iterations: usize,
+    totals: [Duration; 5],
+    memory: MemorySampler,
+    trace: Option<ChromeTrace>,
+}
+
+impl<B: Backend> Profiler<B> {
+    pub(crate) fn new<P: AsRef<Path>>(devices: Vec<B::Device>, trace: P) -> Self {
+        let trace = match ChromeTrace::new(trace.as_ref()) {
+            Ok(trace) => Some(trace),
+            Err(err) => {
+                log::warn!("Can't create the profiler trace: {err}");
+                None
+            }
+        };
+
+        Self {
+            devices,
+            start: Instant::now(),
+            last: Instant::now(),
+            epoch: 0,
+            iteration: 0,
+            iterations: 0,
+            totals: Default::default(),
+            memory: MemorySampler::new(),
+            trace,
+        }
+    }
+
+    /// Start measuring the iterations of a training epoch, on the current thread.
+    pub(crate) fn start_epoch(&mut self, epoch: usize) {
+        SCOPES.with(|scopes| *scopes.borrow_mut() = Some(Vec::new()));
+        self.epoch = epoch;
+        self.last = Instant::now();
+    }
+
+    /// Exclude the time since the end of the last iteration, e.g. of a validation, from the
+    /// measures.
+    pub(crate) fn resume(&mut self) {
+        self.last = Instant::now();
+    }
+
+    /// Mark the end of the data loading of the iteration.
+    pub(crate) fn data_loaded(&mut self, iteration: usize) {
+        self.iteration = iteration;
+        let start = self.last;
+        self.last = Instant::now();
+        self.record(Phase::DataLoading, start, self.last);
+    }
+
+    /// Mark the end of the train step, split into the forward pass and the measured
+    /// [scopes](scope).
+    pub(crate) fn step_done(&mut self) {
+        self.sync();
+        let (start, end) = (self.last, Instant::now());
+        self.last = end;
+
+        let scopes = SCOPES
+            .with(|scopes| scopes.borrow_mut().as_mut().map(core::mem::take))
+            .unwrap_or_default();
+        let mut forward = end - start;
+
+        for (phase, start, end) in scopes {
+            let duration = end - start;
+            forward = forward.saturating_sub(duration);
+            self.totals[phase as usize] += duration;
+            self.write_span(phase.name(), start, duration);
+        }
+
+        self.totals[Phase::Forward as usize] += forward;
+        self.write_span("Step", start, end - start);
+    }
+
+    /// Mark the end of the optimizer step.
+    pub(crate) fn optimizer_done(&mut self) {
+        self.sync();
+        let start = self.last;
+        self.last = Instant::now();
+        self.record(Phase::Optimizer, start, self.last);
+    }
+
+    /// Mark the end of the iteration, after the metrics, and return the updated summary.
+    pub(crate) fn iteration_done(&mut self) -> ProfileSummary {
+        let start = self.last;
+        self.last = Instant::now();
+        self.record(Phase::Metrics, start, self.last);
+        self.iterations += 1;
+
+        if let Some(memory) = self.memory.sample::<B>(&self.devices) {
+            if let Some(trace) = &mut self.trace {
+                let time = self.last - self.start;
+                if let Err(err) = trace.counter("Memory", time, &memory) {
+                    log::warn!("Can't write the profiler trace: {err}");
+                    self.trace = None;
+                }
+            }
+        }
+
+        self.summary()
+    }
+
+    /// The summary of all the iterations measured so far.
+    pub(crate) fn summary(&self) -> ProfileSummary {
+        let phases = Phase::ALL
+            .iter()
+            .map(|phase| {
+                let total = self.totals[*phase as usize];
+                let mean = match self.iterations {
+                    0 => Duration::ZERO,
+                    iterations => total.div_f64(iterations as f64),
+                };
+                (*phase, mean)
+            })
+            .collect();
+
+        ProfileSummary {
+            iterations: self.iterations,
+            phases,
+            peak_memory: self.memory.peaks().to_vec(),
+        }
+    }
+
+    /// Close the trace and log the summary.
+    pub(crate) fn finish(&mut self) -> ProfileSummary {
+        SCOPES.with(|scopes| *scopes.borrow_mut() = None);
+
+        if let Some(trace) = self.trace.take() {
+            if let Err(err) = trace.finish() {
+                log::warn!("Can't write the profiler trace: {err}");
+            }
+        }
+
+        let summary = self.summary();
+        log::info!("{summary}");
+        summary
+    }
+
+    fn sync(&self) {
+        self.devices.iter().for_each(B::sync);
+    }
+
+    fn record(&mut self, phase: Phase, start: Instant, end: Instant) {
+        self.totals[phase as usize] += end - start;
+        self.write_span(phase.name(), start, end - start);
+    }
+
+    fn write_span(&mut self, name: &str, start: Instant, duration: Duration) {
+        if let Some(trace) = &mut self.trace {
+            let start = start - self.start;
+            if let Err(err) = trace.span(name, start, duration, self.epoch, self.iteration) {
+                log::warn!("Can't write the profiler trace: {err}");
+                self.trace = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestBackend, TrainOutput};
+    use burn_core::nn::LinearConfig;
+    use burn_core::tensor::Tensor;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_profiler_splits_the_step_with_scopes() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("trace.json");
+        let mut profiler = Profiler::<TestBackend>::new(vec![Default::default()], &path);
+
+        profiler.start_epoch(1);
+        for iteration in 1..=2 {
+            sleep(Duration::from_millis(2));
+            profiler.data_loaded(iteration);
+            sleep(Duration::from_millis(2));
+            scope(Phase::Backward, || sleep(Duration::from_millis(4)));
+            profiler.step_done();
+            profiler.optimizer_done();
+            profiler.iteration_done();
+        }
+        let summary = profiler.finish();
+
+        assert_eq!(summary.iterations, 2);
+        let phase = |phase: Phase| summary.phases[phase as usize].1;
+        assert!(phase(Phase::DataLoading) >= Duration::from_millis(2));
+        assert!(phase(Phase::Forward) >= Duration::from_millis(2));
+        assert!(phase(Phase::Backward) >= Duration::from_millis(4));
+
+        let trace: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let names = trace
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|event| event["ph"] == "X" && event["args"]["iteration"] == 2)
+            .map(|event| event["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["Data loading", "Backward", "Step", "Optimizer", "Metrics"]
+        );
+    }
+
+    #[test]
+    fn test_profiler_measures_the_backward_pass_of_the_train_output() {
+        type B = burn_autodiff::Autodiff<TestBackend>;
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("trace.json");
+        let mut profiler = Profiler::<B>::new(vec![Default::default()], &path);
+        let linear = LinearConfig::new(2, 2).init::<B>();
+        let loss = || linear.forward(Tensor::ones([1, 2])).sum();
+
+        profiler.start_epoch(1);
+        profiler.data_loaded(1);
+        let _output = scope(Phase::Metrics, || {
+            TrainOutput::from_loss(&linear, loss(), ())
+        });
+        let _output = TrainOutput::from_loss(&linear, loss(), ());
+        profiler.step_done();
+        profiler.finish();
+
+        let trace: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let names = trace
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|event| event["ph"] == "X")
+            .map(|event| event["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Data loading", "Metrics", "Backward", "Step"]);
+    }
+
+    #[test]
+    fn test_scope_outside_profiling_does_nothing() {
+        let value = scope(Phase::Backward, || 42);
+
+        assert_eq!(value, 42);
+        SCOPES.with(|scopes| assert!(scopes.borrow().is_none()));
+    }
+}
@@ -0,0 +1,58 @@
+use burn_core::tensor::backend::Backend;
+use std::time::{Duration, Instant};
+
+const REFRESH_FREQUENCY: Duration = Duration::from_millis(200);
+
+/// Samples the memory used on each device with [memory_stats](Backend::memory_stats) and keeps the
+/// peak values.
+///
+/// The devices of a backend not tracking its memory are ignored.
+pub(crate) struct MemorySampler {
+    last_refresh: Option<Instant>,
+    peaks: Vec<(String, u64)>,
+}
+
+impl MemorySampler {
+    pub(crate) fn new() -> Self {
+        Self {
+            last_refresh: None,
+            peaks: Vec::new(),
+        }
+    }
+
+    /// Sample the memory in use on each device, unless it was sampled recently.
+    pub(crate) fn sample<B: Backend>(
+        &mut self,
+        devices: &[B::Device],
+    ) -> Option<Vec<(String, u64)>> {
+        if let Some(last_refresh) = self.last_refresh {
+            if last_refresh.elapsed() < REFRESH_FREQUENCY {
+                return None;
+            }
+        }
+        self.last_refresh = Some(Instant::now());
+
+        let mut current = Vec::with_capacity(devices.len());
+        for device in devices {
+            let stats = match B::memory_stats(device) {
+                Some(stats) => stats,
+                None => continue,
+            };
+            let name = format!("{device:?}");
+            let peak = stats.peak_bytes_in_use as u64;
+
+            match self.peaks.iter_mut().find(|(device, _)| *device == name) {
+                Some((_, bytes)) => *bytes = u64::max(*bytes, peak),
+                None => self.peaks.push((name.clone(), peak)),
+            }
+            current.push((name, stats.bytes_in_use as u64));
+        }
+
+        Some(current)
+    }
+
+    /// The peak memory used on each device in bytes.
+    pub(crate) fn peaks(&self) -> &[(String, u64)] {
+        &self.peaks
+    }
+}
@@ -0,0 +1,7 @@
+mod base;
+mod memory;
+mod trace;
+
+pub use base::*;
+pub(crate) use memory::*;
+pub(crate) use trace::*;
@@ -0,0 +1,105 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Writes the events of the profiler in the chrome trace format, viewable in `chrome://tracing`
+/// or [Perfetto](https://ui.perfetto.dev).
+///
+/// The events are written as they happen, so that the trace of a long training isn't kept in
+/// memory. The closing bracket of the JSON array is optional in that format, the trace can thus
+/// be opened even when the training is interrupted.
+pub(crate) struct ChromeTrace {
+    writer: BufWriter<File>,
+    empty: bool,
+}
+
+impl ChromeTrace {
+    pub(crate) fn new<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(b"[\n")?;
+
+        Ok(Self {
+            writer,
+            empty: true,
+        })
+    }
+
+    /// Write a complete event, from its start since the beginning of the profiling.
+    pub(crate) fn span(
+        &mut self,
+        name: &str,
+        start: Duration,
+        duration: Duration,
+        epoch: usize,
+        iteration: usize,
+    ) -> std::io::Result<()> {
+        self.write(&format!(
+            "{{\"name\":{},\"cat\":\"train\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":0,\
+             \"tid\":0,\"args\":{{\"epoch\":{epoch},\"iteration\":{iteration}}}}}",
+            json_string(name),
+            micros(start),
+            micros(duration),
+        ))
+    }
+
+    /// Write a counter event, shown as a graph of the values over time.
+    pub(crate) fn counter(
+        &mut self,
+        name: &str,
+        time: Duration,
+        values: &[(String, u64)],
+    ) -> std::io::Result<()> {
+        let args = values
+            .iter()
+            .map(|(name, value)| format!("{}:{value}", json_string(name)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.write(&format!(
+            "{{\"name\":{},\"ph\":\"C\",\"ts\":{},\"pid\":0,\"args\":{{{args}}}}}",
+            json_string(name),
+            micros(time),
+        ))
+    }
+
+    /// Close the JSON array and flush the file.
+    pub(crate) fn finish(mut self) -> std::io::Result<()> {
+        self.writer.write_all(b"\n]\n")?;
+        self.writer.flush()
+    }
+
+    fn write(&mut self, event: &str) -> std::io::Result<()> {
+        if !self.empty {
+            self.writer.write_all(b",\n")?;
+        }
+        self.empty = false;
+
+        self.writer.write_all(event.as_bytes())
+    }
+}
+
+/// The JSON string literal of the value, with its special characters escaped.
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            char if char.is_control() => output.push_str(&format!("\\u{:04x}", char as u32)),
+            char => output.push(char),
+        }
+    }
+    output.push('"');
+
+    output
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e6
+}
@@ -1,6 +1,7 @@
 use burn_core::data::dataloader::Progress;
 
 use crate::metric::MetricEntry;
+use crate::profiler::ProfileSummary;
 
 /// Trait for rendering metrics.
 pub trait MetricsRenderer: Send + Sync {
@@ -31,10 +32,18 @@ pub trait MetricsRenderer: Send + Sync {
     ///
     /// * `item` - The validation progress.
     fn render_valid(&mut self, item: TrainingProgress);
+
+    /// Updates the [profile](ProfileSummary) of the training iterations, only called when the
+    /// training is profiled.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile summary.
+    fn update_profile(&mut self, _profile: ProfileSummary) {}
 }
 
 /// The state of a metric.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum MetricState {
     /// A generic metric.
     Generic(MetricEntry),
@@ -44,7 +53,7 @@ pub enum MetricState {
 }
 
 /// Training progress.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TrainingProgress {
     /// The progress.
     pub progress: Progress,
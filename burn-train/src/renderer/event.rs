@@ -0,0 +1,33 @@
+use crate::metric::store::Split;
+use crate::profiler::ProfileSummary;
+use crate::renderer::{MetricState, TrainingProgress};
+
+/// An event of the training sent to the [subscribers](crate::LearnerBuilder::subscribe) of the
+/// learner, to follow the training programmatically, e.g. from a notebook or a GUI application.
+#[derive(Clone, Debug)]
+pub enum ProgressEvent {
+    /// A metric has been updated after an item was processed.
+    MetricUpdate {
+        /// The split of the item.
+        split: Split,
+        /// The updated state of the metric.
+        state: MetricState,
+    },
+    /// An item has been processed, after its metrics have been updated.
+    Progress {
+        /// The split of the item.
+        split: Split,
+        /// The progress of the training.
+        progress: TrainingProgress,
+    },
+    /// An epoch has ended.
+    EndEpoch {
+        /// The split of the epoch.
+        split: Split,
+        /// The epoch, starting at 1.
+        epoch: usize,
+    },
+    /// The profile of the training iterations has been updated, only sent when the training is
+    /// [profiled](crate::LearnerBuilder::profile).
+    Profile(ProfileSummary),
+}
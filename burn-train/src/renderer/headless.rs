@@ -0,0 +1,177 @@
+use crate::metric::store::Split;
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// A renderer printing compact progress lines, for the environments without an interactive
+/// terminal such as CI jobs and notebooks.
+///
+/// A line is printed at most once per interval and at the end of each epoch, with the latest
+/// value of the numeric metrics:
+///
+/// ```text
+/// [Train] Epoch 1/10 - Iteration 120 - Items 7680/60000 (12.8%) - Loss 0.5123
+/// ```
+pub struct HeadlessMetricsRenderer {
+    writer: Box<dyn Write + Send + Sync>,
+    interval: Duration,
+    last_print: Option<Instant>,
+    metrics_train: Vec<(String, f64)>,
+    metrics_valid: Vec<(String, f64)>,
+}
+
+impl HeadlessMetricsRenderer {
+    /// Create a new renderer printing to the standard output every 10 seconds.
+    pub fn new() -> Self {
+        Self {
+            writer: Box::new(std::io::stdout()),
+            interval: Duration::from_secs(10),
+            last_print: None,
+            metrics_train: Vec::new(),
+            metrics_valid: Vec::new(),
+        }
+    }
+
+    /// Set the minimum time between two progress lines in the same epoch.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Print the progress lines to the given writer instead of the standard output.
+    pub fn with_writer<W>(mut self, writer: W) -> Self
+    where
+        W: Write + Send + Sync + 'static,
+    {
+        self.writer = Box::new(writer);
+        self
+    }
+
+    fn print(&mut self, split: Split, item: TrainingProgress) {
+        let progress = &item.progress;
+        let epoch_done = progress.items_processed >= progress.items_total;
+        let recently_printed = self
+            .last_print
+            .map(|last_print| last_print.elapsed() < self.interval)
+            .unwrap_or(false);
+
+        if recently_printed && !epoch_done {
+            return;
+        }
+        self.last_print = Some(Instant::now());
+
+        let (name, metrics) = match split {
+            Split::Train => ("Train", &self.metrics_train),
+            Split::Valid => ("Valid", &self.metrics_valid),
+        };
+        let mut line = format!(
+            "[{name}] Epoch {}/{} - Iteration {} - Items {}/{}",
+            item.epoch,
+            item.epoch_total,
+            item.iteration,
+            progress.items_processed,
+            progress.items_total
+        );
+        if progress.items_total > 0 {
+            let percent = 100.0 * progress.items_processed as f64 / progress.items_total as f64;
+            line += &format!(" ({percent:.1}%)");
+        }
+
+        for (metric, value) in metrics.iter() {
+            line += &format!(" - {metric} {value:.4}");
+        }
+
+        writeln!(self.writer, "{line}").ok();
+        self.writer.flush().ok();
+    }
+}
+
+impl Default for HeadlessMetricsRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn update_metric(metrics: &mut Vec<(String, f64)>, state: MetricState) {
+    if let MetricState::Numeric(entry, value) = state {
+        match metrics.iter_mut().find(|(name, _)| *name == entry.name) {
+            Some((_, current)) => *current = value,
+            None => metrics.push((entry.name, value)),
+        }
+    }
+}
+
+impl MetricsRenderer for HeadlessMetricsRenderer {
+    fn update_train(&mut self, state: MetricState) {
+        update_metric(&mut self.metrics_train, state);
+    }
+
+    fn update_valid(&mut self, state: MetricState) {
+        update_metric(&mut self.metrics_valid, state);
+    }
+
+    fn render_train(&mut self, item: TrainingProgress) {
+        self.print(Split::Train, item);
+    }
+
+    fn render_valid(&mut self, item: TrainingProgress) {
+        self.print(Split::Valid, item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::MetricEntry;
+    use burn_core::data::dataloader::Progress;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn progress(items_processed: usize, iteration: usize) -> TrainingProgress {
+        TrainingProgress {
+            progress: Progress {
+                items_processed,
+                items_total: 20,
+            },
+            epoch: 1,
+            epoch_total: 2,
+            iteration,
+        }
+    }
+
+    #[test]
+    fn test_print_first_and_last_items_of_the_epoch() {
+        let writer = SharedWriter::default();
+        let mut renderer = HeadlessMetricsRenderer::new()
+            .with_interval(Duration::from_secs(3600))
+            .with_writer(writer.clone());
+        let loss = |value: f64| {
+            let entry = MetricEntry::new("Loss".to_string(), String::new(), String::new());
+            MetricState::Numeric(entry, value)
+        };
+
+        for iteration in 1..=4 {
+            renderer.update_train(loss(1.0 / iteration as f64));
+            renderer.render_train(progress(iteration * 5, iteration));
+        }
+
+        let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output,
+            "[Train] Epoch 1/2 - Iteration 1 - Items 5/20 (25.0%) - Loss 1.0000\n\
+             [Train] Epoch 1/2 - Iteration 4 - Items 20/20 (100.0%) - Loss 0.2500\n"
+        );
+    }
+}
@@ -1,10 +1,10 @@
 mod base;
-pub use base::*;
+mod event;
+mod headless;
 
-#[cfg(not(feature = "tui"))]
-mod cli;
-#[cfg(not(feature = "tui"))]
-pub use cli::CliMetricsRenderer as SelectedMetricsRenderer;
+pub use base::*;
+pub use event::*;
+pub use headless::*;
 
 #[cfg(feature = "tui")]
 mod tui;
@@ -12,15 +12,21 @@ use crate::TrainingInterrupter;
 #[cfg(feature = "tui")]
 pub use tui::TuiMetricsRenderer as SelectedMetricsRenderer;
 
-/// The TUI renderer, or a simple stub if the tui feature is not enabled.
+/// The TUI renderer when the standard output is an interactive terminal, or the
+/// [headless renderer](HeadlessMetricsRenderer) otherwise and when the tui feature is not enabled.
 #[allow(unused_variables)]
 pub(crate) fn default_renderer(
     interuptor: TrainingInterrupter,
     checkpoint: Option<usize>,
-) -> SelectedMetricsRenderer {
+) -> Box<dyn MetricsRenderer> {
     #[cfg(feature = "tui")]
-    return SelectedMetricsRenderer::new(interuptor, checkpoint);
+    {
+        use std::io::IsTerminal;
+
+        if std::io::stdout().is_terminal() {
+            return Box::new(SelectedMetricsRenderer::new(interuptor, checkpoint));
+        }
+    }
 
-    #[cfg(not(feature = "tui"))]
-    return SelectedMetricsRenderer::new();
+    Box::new(HeadlessMetricsRenderer::new())
 }
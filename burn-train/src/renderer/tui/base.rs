@@ -1,5 +1,6 @@
 use super::{
-    ControlsView, NumericMetricView, ProgressBarView, StatusView, TerminalFrame, TextMetricView,
+    ControlsView, NumericMetricView, ProfileView, ProgressBarView, StatusView, TerminalFrame,
+    TextMetricView,
 };
 use ratatui::prelude::{Constraint, Direction, Layout, Rect};
 
@@ -10,6 +11,7 @@ pub(crate) struct MetricsView<'a> {
     progress: ProgressBarView,
     controls: ControlsView,
     status: StatusView,
+    profile: ProfileView,
 }
 
 impl<'a> MetricsView<'a> {
@@ -30,16 +32,29 @@ impl<'a> MetricsView<'a> {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Max(5), Constraint::Min(6), Constraint::Max(6)].as_ref())
+            .constraints(
+                [
+                    Constraint::Max(5),
+                    Constraint::Min(6),
+                    Constraint::Max(6),
+                    Constraint::Max(self.profile.height()),
+                ]
+                .as_ref(),
+            )
             .split(size_other);
         let size_controls = chunks[0];
         let size_metric_text = chunks[1];
         let size_status = chunks[2];
+        let size_profile = chunks[3];
 
         self.metric_numeric.render(frame, size_metric_numeric);
         self.metric_text.render(frame, size_metric_text);
         self.controls.render(frame, size_controls);
         self.progress.render(frame, size_progress);
         self.status.render(frame, size_status);
+
+        if self.profile.height() > 0 {
+            self.profile.render(frame, size_profile);
+        }
     }
 }
@@ -57,17 +57,22 @@ impl TextMetricView {
         let mut lines = Vec::with_capacity(names.len() * 4);
 
         let start_line = |title: &str| vec![Span::from(format!(" {title} ")).bold().yellow()];
-        let train_line = |formatted: &str| {
-            vec![
-                Span::from("   Train ").bold(),
-                Span::from(formatted.to_string()).italic(),
-            ]
-        };
-        let valid_line = |formatted: &str| {
-            vec![
-                Span::from("   Valid ").bold(),
-                Span::from(formatted.to_string()).italic(),
-            ]
+        // Multi-line values, e.g. a confusion matrix, are aligned after the split title.
+        let split_lines = |split: &'static str, formatted: &str| {
+            formatted
+                .lines()
+                .enumerate()
+                .map(|(i, line)| match i {
+                    0 => vec![
+                        Span::from(split).bold(),
+                        Span::from(line.to_string()).italic(),
+                    ],
+                    _ => vec![
+                        Span::from(" ".repeat(split.len())),
+                        Span::from(line.to_string()).italic(),
+                    ],
+                })
+                .collect::<Vec<_>>()
         };
 
         for name in names {
@@ -76,11 +81,11 @@ impl TextMetricView {
             let entry = data.get(name).unwrap();
 
             if let Some(entry) = &entry.train {
-                lines.push(train_line(&entry.formatted));
+                lines.extend(split_lines("   Train ", &entry.formatted));
             }
 
             if let Some(entry) = &entry.valid {
-                lines.push(valid_line(&entry.formatted));
+                lines.extend(split_lines("   Valid ", &entry.formatted));
             }
 
             lines.push(vec![Span::from("")]);
@@ -5,6 +5,7 @@ mod metric_numeric;
 mod metric_text;
 mod plot_utils;
 mod popup;
+mod profile;
 mod progress;
 mod recent_history;
 mod renderer;
@@ -17,6 +18,7 @@ pub(crate) use metric_numeric::*;
 pub(crate) use metric_text::*;
 pub(crate) use plot_utils::*;
 pub(crate) use popup::*;
+pub(crate) use profile::*;
 pub(crate) use progress::*;
 pub(crate) use recent_history::*;
 pub use renderer::*;
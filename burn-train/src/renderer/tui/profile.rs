@@ -0,0 +1,80 @@
+use super::TerminalFrame;
+use crate::profiler::ProfileSummary;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Show the time of each phase of the training iterations when the training is profiled.
+#[derive(Default)]
+pub(crate) struct ProfileState {
+    summary: Option<ProfileSummary>,
+}
+
+impl ProfileState {
+    /// Update the profile summary.
+    pub(crate) fn update(&mut self, summary: ProfileSummary) {
+        self.summary = Some(summary);
+    }
+
+    /// Create a view.
+    pub(crate) fn view(&self) -> ProfileView {
+        ProfileView::new(self.summary.as_ref())
+    }
+}
+
+pub(crate) struct ProfileView {
+    lines: Vec<Vec<Span<'static>>>,
+}
+
+impl ProfileView {
+    fn new(summary: Option<&ProfileSummary>) -> Self {
+        let summary = match summary {
+            Some(summary) => summary,
+            None => return Self { lines: Vec::new() },
+        };
+        let title = |title: &str| Span::from(format!(" {title:<13}: ")).bold().yellow();
+        let value = |value: String| Span::from(value).italic();
+
+        let phases = summary.phases.iter().map(|(phase, duration)| {
+            vec![
+                title(phase.name()),
+                value(format!("{:.3} ms", duration.as_secs_f64() * 1e3)),
+            ]
+        });
+        let memory = summary.peak_memory.iter().map(|(device, bytes)| {
+            vec![
+                title(&format!("{device} peak")),
+                value(format!("{:.2} Gb", *bytes as f64 * 1e-9)),
+            ]
+        });
+
+        Self {
+            lines: phases.chain(memory).collect(),
+        }
+    }
+
+    /// The height of the view, zero when the training isn't profiled.
+    pub(crate) fn height(&self) -> u16 {
+        match self.lines.len() {
+            0 => 0,
+            num_lines => num_lines as u16 + 2,
+        }
+    }
+
+    pub(crate) fn render(self, frame: &mut TerminalFrame<'_>, size: Rect) {
+        let paragraph = Paragraph::new(self.lines.into_iter().map(Line::from).collect::<Vec<_>>())
+            .alignment(Alignment::Left)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Profile (mean per iteration)"),
+            )
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(Color::Gray));
+
+        frame.render_widget(paragraph, size);
+    }
+}
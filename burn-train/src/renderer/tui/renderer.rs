@@ -1,3 +1,4 @@
+use crate::profiler::ProfileSummary;
 use crate::renderer::{tui::NumericMetricsState, MetricsRenderer};
 use crate::renderer::{MetricState, TrainingProgress};
 use crate::TrainingInterrupter;
@@ -14,8 +15,8 @@ use std::{
 };
 
 use super::{
-    Callback, CallbackFn, ControlsView, MetricsView, PopupState, ProgressBarState, StatusState,
-    TextMetricsState,
+    Callback, CallbackFn, ControlsView, MetricsView, PopupState, ProfileState, ProgressBarState,
+    StatusState, TextMetricsState,
 };
 
 /// The current terminal backend.
@@ -33,6 +34,7 @@ pub struct TuiMetricsRenderer {
     metrics_numeric: NumericMetricsState,
     metrics_text: TextMetricsState,
     status: StatusState,
+    profile: ProfileState,
     interuptor: TrainingInterrupter,
     popup: PopupState,
 }
@@ -75,6 +77,10 @@ impl MetricsRenderer for TuiMetricsRenderer {
         self.status.update_valid(item);
         self.render().unwrap();
     }
+
+    fn update_profile(&mut self, profile: ProfileSummary) {
+        self.profile.update(profile);
+    }
 }
 
 impl TuiMetricsRenderer {
@@ -92,6 +98,7 @@ impl TuiMetricsRenderer {
             metrics_numeric: NumericMetricsState::default(),
             metrics_text: TextMetricsState::default(),
             status: StatusState::default(),
+            profile: ProfileState::default(),
             interuptor,
             popup: PopupState::Empty,
         }
@@ -124,6 +131,7 @@ impl TuiMetricsRenderer {
                         self.progress.view(),
                         ControlsView,
                         self.status.view(),
+                        self.profile.view(),
                     );
 
                     view.render(frame, size);
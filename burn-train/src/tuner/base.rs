@@ -0,0 +1,245 @@
+use super::{
+    apply_params, PruningStrategy, SearchSpace, Trial, TrialReports, TrialResult, TrialStatus,
+    TuningReport,
+};
+use crate::metric::store::Direction;
+use burn_core::config::Config;
+use rand::{rngs::StdRng, SeedableRng};
+use std::sync::Arc;
+
+/// Error that can happen when preparing a hyperparameter search.
+#[derive(Debug)]
+pub enum TunerError {
+    /// The config has no field with the given path.
+    UnknownField(String),
+    /// The config can't be created with the values of the search space.
+    InvalidConfig(String),
+}
+
+impl core::fmt::Display for TunerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownField(field) => write!(f, "Unknown config field: {field}"),
+            Self::InvalidConfig(err) => write!(f, "Invalid config: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TunerError {}
+
+/// How the trials are chosen in the [search space](SearchSpace).
+#[derive(Clone, Copy, Debug)]
+pub enum SearchStrategy {
+    /// Every combination of values.
+    Grid {
+        /// The number of evenly spaced values tried for the float ranges.
+        points: usize,
+    },
+    /// Values sampled randomly.
+    Random {
+        /// The number of trials.
+        trials: usize,
+        /// The seed of the sampling.
+        seed: u64,
+    },
+}
+
+/// Search the hyperparameters of a [config](Config) giving the best value of a metric.
+///
+/// Each trial trains with a config where the fields of the [search space](SearchSpace) are
+/// replaced, and returns the value to optimize, usually a validation metric of the last epoch.
+///
+/// # Example
+///
+/// ```ignore
+/// let space = SearchSpace::new()
+///     .log_uniform("optimizer.lr", 1e-4, 1e-2)
+///     .choice("batch_size", vec![32, 64]);
+///
+/// let report = Tuner::new(config, space, Direction::Lowest)
+///     .strategy(SearchStrategy::Random { trials: 20, seed: 42 })
+///     .pruner(MedianPruner::new())
+///     .run(|config, trial| {
+///         let learner = LearnerBuilder::new(&format!("/tmp/tuning/{}", trial.id()))
+///             .metric_valid_numeric(LossMetric::new())
+///             .callback(trial.callback::<LossMetric<B>>(Aggregate::Mean, Split::Valid))
+///             .num_epochs(config.num_epochs)
+///             .build(model, optim, config.optimizer.lr);
+///         let model = learner.fit(dataloader_train, dataloader_valid);
+///
+///         evaluate(model)
+///     })?;
+///
+/// println!("{report}");
+/// ```
+pub struct Tuner<C> {
+    base: C,
+    space: SearchSpace,
+    direction: Direction,
+    strategy: SearchStrategy,
+    pruner: Option<Arc<dyn PruningStrategy>>,
+}
+
+impl<C: Config> Tuner<C> {
+    /// Create a tuner searching around the base config, with a grid of three values for the
+    /// float ranges by default.
+    pub fn new(base: C, space: SearchSpace, direction: Direction) -> Self {
+        Self {
+            base,
+            space,
+            direction,
+            strategy: SearchStrategy::Grid { points: 3 },
+            pruner: None,
+        }
+    }
+
+    /// Set how the trials are chosen in the search space.
+    pub fn strategy(mut self, strategy: SearchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Stop the bad trials early with the given [strategy](PruningStrategy).
+    ///
+    /// The trials must report their intermediate values, e.g. with [Trial::callback].
+    pub fn pruner<P>(mut self, pruner: P) -> Self
+    where
+        P: PruningStrategy + 'static,
+    {
+        self.pruner = Some(Arc::new(pruner));
+        self
+    }
+
+    /// Run the trials one after the other and return the [report](TuningReport) of the search.
+    ///
+    /// The configs of all the trials are created before the first one starts, so that an invalid
+    /// search space is found immediately.
+    pub fn run<F>(self, mut train: F) -> Result<TuningReport<C>, TunerError>
+    where
+        F: FnMut(&C, &Trial) -> f64,
+    {
+        let params = match self.strategy {
+            SearchStrategy::Grid { points } => self.space.grid(points),
+            SearchStrategy::Random { trials, seed } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                (0..trials).map(|_| self.space.sample(&mut rng)).collect()
+            }
+        };
+        let configs = params
+            .iter()
+            .map(|params| apply_params(&self.base, params))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_trials = configs.len();
+        let mut completed: Vec<TrialReports> = Vec::new();
+        let mut results = Vec::with_capacity(num_trials);
+
+        for (id, (params, config)) in params.into_iter().zip(configs).enumerate() {
+            log::info!("Starting trial {}/{}: {:?}", id + 1, num_trials, params);
+            let trial = Trial::new(
+                id,
+                self.pruner.clone(),
+                Arc::new(completed.clone()),
+                self.direction,
+            );
+
+            let value = train(&config, &trial);
+            let reports = trial.reports();
+            let status = match trial.is_pruned() {
+                true => TrialStatus::Pruned,
+                false => {
+                    completed.push(reports.clone());
+                    TrialStatus::Completed
+                }
+            };
+            log::info!("Trial {} {:?} with value {}", id, status, value);
+
+            results.push(TrialResult {
+                id,
+                params,
+                config,
+                value,
+                status,
+                reports,
+            });
+        }
+
+        Ok(TuningReport::new(results, self.direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tuner::MedianPruner;
+    use burn_core as burn;
+
+    #[derive(Config)]
+    struct TestConfig {
+        #[config(default = 0.0)]
+        x: f64,
+        #[config(default = 1)]
+        scale: usize,
+    }
+
+    fn train(config: &TestConfig, trial: &Trial) -> f64 {
+        let mut value = 0.0;
+
+        for epoch in 1..=5 {
+            value = (config.x - 0.3).powi(2) * config.scale as f64 + 1.0 / epoch as f64;
+            if trial.report(epoch, value) {
+                break;
+            }
+        }
+
+        value
+    }
+
+    #[test]
+    fn test_grid_search_finds_the_best_config() {
+        let space = SearchSpace::new()
+            .uniform("x", 0.0, 1.0)
+            .choice("scale", vec![1, 2]);
+
+        let report = Tuner::new(TestConfig::new(), space, Direction::Lowest)
+            .strategy(SearchStrategy::Grid { points: 11 })
+            .run(train)
+            .unwrap();
+
+        assert_eq!(report.trials.len(), 22);
+        let best = report.best_config().unwrap();
+        assert!((best.x - 0.3).abs() < 1e-9);
+        assert!(report
+            .to_string()
+            .contains("22 trials, 22 completed, 0 pruned"));
+    }
+
+    #[test]
+    fn test_random_search_prunes_bad_trials() {
+        let space = SearchSpace::new().uniform("x", 0.0, 1.0);
+
+        let report = Tuner::new(TestConfig::new(), space, Direction::Lowest)
+            .strategy(SearchStrategy::Random {
+                trials: 20,
+                seed: 42,
+            })
+            .pruner(MedianPruner::new())
+            .run(train)
+            .unwrap();
+
+        let pruned = report
+            .trials
+            .iter()
+            .filter(|trial| trial.status == TrialStatus::Pruned)
+            .collect::<Vec<_>>();
+        assert!(!pruned.is_empty());
+        assert!(pruned.iter().all(|trial| trial.reports.len() == 2));
+
+        let best = report.best().unwrap();
+        assert_eq!(best.status, TrialStatus::Completed);
+        assert!(report
+            .trials
+            .iter()
+            .all(|trial| trial.status == TrialStatus::Pruned || trial.value >= best.value));
+    }
+}
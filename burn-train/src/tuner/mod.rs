@@ -0,0 +1,11 @@
+mod base;
+mod pruner;
+mod report;
+mod space;
+mod trial;
+
+pub use base::*;
+pub use pruner::*;
+pub use report::*;
+pub use space::*;
+pub use trial::*;
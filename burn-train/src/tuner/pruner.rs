@@ -0,0 +1,127 @@
+use super::TrialReports;
+use crate::metric::store::Direction;
+
+/// A strategy that checks if a [trial](super::Trial) should be stopped early, based on the values
+/// reported by the previous completed trials.
+pub trait PruningStrategy {
+    /// Returns if the trial reporting the value at the given epoch should be pruned.
+    fn should_prune(
+        &self,
+        epoch: usize,
+        value: f64,
+        direction: Direction,
+        previous: &[TrialReports],
+    ) -> bool;
+}
+
+/// A [pruning strategy](PruningStrategy) stopping the trials doing worse than the median of the
+/// previous trials at the same epoch.
+///
+/// Trials reporting a NaN value are always pruned, since their training has diverged.
+pub struct MedianPruner {
+    warmup_epochs: usize,
+    min_trials: usize,
+}
+
+impl Default for MedianPruner {
+    fn default() -> Self {
+        Self {
+            warmup_epochs: 1,
+            min_trials: 3,
+        }
+    }
+}
+
+impl MedianPruner {
+    /// Create a new median pruner, with one warmup epoch and three trials required by default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of epochs during which trials are never pruned, as the first values are
+    /// too noisy to compare.
+    pub fn with_warmup_epochs(mut self, warmup_epochs: usize) -> Self {
+        self.warmup_epochs = warmup_epochs;
+        self
+    }
+
+    /// Set the minimum number of previous trials with a value at the epoch before pruning.
+    pub fn with_min_trials(mut self, min_trials: usize) -> Self {
+        self.min_trials = min_trials;
+        self
+    }
+}
+
+impl PruningStrategy for MedianPruner {
+    fn should_prune(
+        &self,
+        epoch: usize,
+        value: f64,
+        direction: Direction,
+        previous: &[TrialReports],
+    ) -> bool {
+        if value.is_nan() {
+            return true;
+        }
+
+        if epoch <= self.warmup_epochs {
+            return false;
+        }
+
+        let mut values = previous
+            .iter()
+            .filter_map(|reports| {
+                reports
+                    .iter()
+                    .find(|(reported_epoch, _)| *reported_epoch == epoch)
+                    .map(|(_, value)| *value)
+            })
+            .filter(|value| !value.is_nan())
+            .collect::<Vec<_>>();
+
+        if values.is_empty() || values.len() < self.min_trials {
+            return false;
+        }
+
+        values.sort_by(|a, b| a.total_cmp(b));
+        let middle = values.len() / 2;
+        let median = match values.len() % 2 {
+            0 => (values[middle - 1] + values[middle]) / 2.0,
+            _ => values[middle],
+        };
+
+        match direction {
+            Direction::Lowest => value > median,
+            Direction::Highest => value < median,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_worse_than_median() {
+        let previous = vec![
+            vec![(1, 1.0), (2, 0.5)],
+            vec![(1, 1.2), (2, 0.7)],
+            vec![(1, 0.9), (2, 0.6)],
+        ];
+        let pruner = MedianPruner::new();
+
+        assert!(!pruner.should_prune(1, 5.0, Direction::Lowest, &previous));
+        assert!(pruner.should_prune(2, 0.65, Direction::Lowest, &previous));
+        assert!(!pruner.should_prune(2, 0.55, Direction::Lowest, &previous));
+        assert!(!pruner.should_prune(2, 0.65, Direction::Highest, &previous));
+        assert!(pruner.should_prune(2, f64::NAN, Direction::Lowest, &previous));
+    }
+
+    #[test]
+    fn test_no_pruning_without_enough_trials() {
+        let previous = vec![vec![(2, 0.5)], vec![(1, 0.5)]];
+        let pruner = MedianPruner::new().with_min_trials(2);
+
+        assert!(!pruner.should_prune(2, 1.0, Direction::Lowest, &previous));
+    }
+}
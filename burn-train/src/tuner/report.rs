@@ -0,0 +1,120 @@
+use super::{TrialParams, TrialReports};
+use crate::metric::store::Direction;
+use burn_core::config::Config;
+use serde::Serialize;
+use serde_json::json;
+use std::path::Path;
+
+/// How a [trial](super::Trial) ended.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrialStatus {
+    /// The training went to the end.
+    Completed,
+    /// The training was stopped early by the [pruning strategy](super::PruningStrategy).
+    Pruned,
+}
+
+/// The result of a [trial](super::Trial).
+#[derive(Serialize)]
+pub struct TrialResult<C> {
+    /// The number of the trial, starting at 0.
+    pub id: usize,
+    /// The values of the tuned hyperparameters.
+    pub params: TrialParams,
+    /// The config used for the training.
+    pub config: C,
+    /// The value returned by the training.
+    pub value: f64,
+    /// How the trial ended.
+    pub status: TrialStatus,
+    /// The intermediate values reported during the training.
+    pub reports: TrialReports,
+}
+
+/// The summary of a hyperparameter search, with the result of every trial.
+pub struct TuningReport<C> {
+    /// The trials in the order they were run.
+    pub trials: Vec<TrialResult<C>>,
+    direction: Direction,
+}
+
+impl<C: Config> TuningReport<C> {
+    pub(crate) fn new(trials: Vec<TrialResult<C>>, direction: Direction) -> Self {
+        Self { trials, direction }
+    }
+
+    /// The completed trial with the best value, if any.
+    pub fn best(&self) -> Option<&TrialResult<C>> {
+        self.trials
+            .iter()
+            .filter(|trial| trial.status == TrialStatus::Completed && !trial.value.is_nan())
+            .min_by(|a, b| match self.direction {
+                Direction::Lowest => a.value.total_cmp(&b.value),
+                Direction::Highest => b.value.total_cmp(&a.value),
+            })
+    }
+
+    /// The config of the best trial, if any.
+    pub fn best_config(&self) -> Option<&C> {
+        self.best().map(|trial| &trial.config)
+    }
+
+    /// Save the report to a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, file: P) -> std::io::Result<()> {
+        let report = json!({
+            "best": self.best().map(|trial| trial.id),
+            "trials": self.trials,
+        });
+        let content = serde_json::to_string_pretty(&report)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        std::fs::write(file, content)
+    }
+}
+
+impl<C: Config> core::fmt::Display for TuningReport<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let num_pruned = self
+            .trials
+            .iter()
+            .filter(|trial| trial.status == TrialStatus::Pruned)
+            .count();
+        writeln!(
+            f,
+            "Hyperparameter search: {} trials, {} completed, {} pruned",
+            self.trials.len(),
+            self.trials.len() - num_pruned,
+            num_pruned
+        )?;
+
+        for trial in self.trials.iter() {
+            writeln!(
+                f,
+                "  Trial {:>3} {:<9} {:<12.6} {}",
+                trial.id,
+                format!("{:?}", trial.status),
+                trial.value,
+                format_params(&trial.params)
+            )?;
+        }
+
+        match self.best() {
+            Some(best) => write!(
+                f,
+                "Best trial {} with value {}: {}",
+                best.id,
+                best.value,
+                format_params(&best.params)
+            ),
+            None => write!(f, "No trial completed."),
+        }
+    }
+}
+
+fn format_params(params: &TrialParams) -> String {
+    params
+        .iter()
+        .map(|(field, value)| format!("{field}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
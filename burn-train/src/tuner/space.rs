@@ -0,0 +1,247 @@
+use super::TunerError;
+use burn_core::config::Config;
+use rand::{rngs::StdRng, Rng};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The values a hyperparameter can take during the search.
+#[derive(Clone, Debug)]
+pub enum ParamSpace {
+    /// One of the given values.
+    Choice(Vec<Value>),
+    /// An integer between the bounds, both included.
+    Int {
+        /// The lowest value.
+        low: i64,
+        /// The highest value.
+        high: i64,
+    },
+    /// A float between the bounds.
+    Uniform {
+        /// The lowest value.
+        low: f64,
+        /// The highest value.
+        high: f64,
+    },
+    /// A float between the bounds on a log scale, useful for learning rates and weight decays.
+    LogUniform {
+        /// The lowest value, greater than zero.
+        low: f64,
+        /// The highest value.
+        high: f64,
+    },
+}
+
+impl ParamSpace {
+    fn grid(&self, points: usize) -> Vec<Value> {
+        let steps = |low: f64, high: f64| {
+            (0..points).map(move |i| match points {
+                1 => low,
+                _ => low + (high - low) * i as f64 / (points - 1) as f64,
+            })
+        };
+
+        match self {
+            Self::Choice(values) => values.clone(),
+            Self::Int { low, high } => (*low..=*high).map(Value::from).collect(),
+            Self::Uniform { low, high } => steps(*low, *high).map(Value::from).collect(),
+            Self::LogUniform { low, high } => steps(low.ln(), high.ln())
+                .map(|value| Value::from(value.exp()))
+                .collect(),
+        }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> Value {
+        let uniform = |rng: &mut StdRng, low: f64, high: f64| match low < high {
+            true => rng.gen_range(low..high),
+            false => low,
+        };
+
+        match self {
+            Self::Choice(values) => values[rng.gen_range(0..values.len())].clone(),
+            Self::Int { low, high } => Value::from(rng.gen_range(*low..=*high)),
+            Self::Uniform { low, high } => Value::from(uniform(rng, *low, *high)),
+            Self::LogUniform { low, high } => Value::from(uniform(rng, low.ln(), high.ln()).exp()),
+        }
+    }
+}
+
+/// The hyperparameters to search, each one being a field of the [config](Config) to tune.
+///
+/// Nested fields are selected with their path separated by dots, e.g. `optimizer.weight_decay`.
+#[derive(Clone, Debug, Default)]
+pub struct SearchSpace {
+    params: Vec<(String, ParamSpace)>,
+}
+
+/// The values of the hyperparameters of a trial, by field path.
+pub type TrialParams = Vec<(String, Value)>;
+
+impl SearchSpace {
+    /// Create an empty search space.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search the field in the given values.
+    pub fn choice<T: Serialize>(self, field: &str, values: Vec<T>) -> Self {
+        assert!(!values.is_empty(), "No value to search for {field}.");
+        let values = values
+            .iter()
+            .map(|value| serde_json::to_value(value).expect("Can serialize the value."))
+            .collect();
+
+        self.param(field, ParamSpace::Choice(values))
+    }
+
+    /// Search the field in the integers between the bounds, both included.
+    pub fn int(self, field: &str, low: i64, high: i64) -> Self {
+        assert!(low <= high, "Invalid bounds for {field}: {low} > {high}.");
+        self.param(field, ParamSpace::Int { low, high })
+    }
+
+    /// Search the field in the floats between the bounds.
+    pub fn uniform(self, field: &str, low: f64, high: f64) -> Self {
+        assert!(low <= high, "Invalid bounds for {field}: {low} > {high}.");
+        self.param(field, ParamSpace::Uniform { low, high })
+    }
+
+    /// Search the field in the floats between the bounds on a log scale.
+    pub fn log_uniform(self, field: &str, low: f64, high: f64) -> Self {
+        assert!(
+            0.0 < low && low <= high,
+            "Invalid bounds for {field}: {low}, {high}."
+        );
+        self.param(field, ParamSpace::LogUniform { low, high })
+    }
+
+    /// Search the field in the given [space](ParamSpace).
+    pub fn param(mut self, field: &str, space: ParamSpace) -> Self {
+        self.params.push((field.to_string(), space));
+        self
+    }
+
+    /// All the combinations of the values, the float ranges being split in the given number of
+    /// evenly spaced points.
+    pub(crate) fn grid(&self, points: usize) -> Vec<TrialParams> {
+        self.params
+            .iter()
+            .fold(vec![Vec::new()], |combinations, (field, space)| {
+                let values = space.grid(points);
+
+                combinations
+                    .into_iter()
+                    .flat_map(|combination| {
+                        values.iter().map(move |value| {
+                            let mut combination = combination.clone();
+                            combination.push((field.clone(), value.clone()));
+                            combination
+                        })
+                    })
+                    .collect()
+            })
+    }
+
+    /// Values sampled randomly for each field.
+    pub(crate) fn sample(&self, rng: &mut StdRng) -> TrialParams {
+        self.params
+            .iter()
+            .map(|(field, space)| (field.clone(), space.sample(rng)))
+            .collect()
+    }
+}
+
+/// Set the values of the hyperparameters in the serialized config and deserialize it back, so that
+/// any [config](Config) can be tuned.
+pub(crate) fn apply_params<C: Config>(base: &C, params: &TrialParams) -> Result<C, TunerError> {
+    let mut config =
+        serde_json::to_value(base).map_err(|err| TunerError::InvalidConfig(err.to_string()))?;
+
+    for (field, value) in params {
+        let mut target = &mut config;
+        for key in field.split('.') {
+            target = target
+                .get_mut(key)
+                .ok_or_else(|| TunerError::UnknownField(field.clone()))?;
+        }
+        *target = value.clone();
+    }
+
+    serde_json::from_value(config).map_err(|err| TunerError::InvalidConfig(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core as burn;
+    use rand::SeedableRng;
+
+    #[derive(Config)]
+    struct TestOptimizerConfig {
+        #[config(default = 1e-3)]
+        lr: f64,
+    }
+
+    #[derive(Config)]
+    struct TestConfig {
+        optimizer: TestOptimizerConfig,
+        #[config(default = 32)]
+        batch_size: usize,
+    }
+
+    #[test]
+    fn test_grid_contains_all_combinations() {
+        let space = SearchSpace::new()
+            .choice("activation", vec!["relu", "gelu"])
+            .int("layers", 1, 3)
+            .log_uniform("lr", 1e-4, 1e-2);
+
+        let grid = space.grid(3);
+
+        assert_eq!(grid.len(), 2 * 3 * 3);
+        assert_eq!(grid[0][0], ("activation".to_string(), Value::from("relu")));
+        assert_eq!(grid[17][1], ("layers".to_string(), Value::from(3)));
+        let lr = grid[1][2].1.as_f64().unwrap();
+        assert!((lr - 1e-3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sample_within_bounds() {
+        let space = SearchSpace::new()
+            .int("layers", 1, 3)
+            .uniform("dropout", 0.1, 0.5);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..100 {
+            let params = space.sample(&mut rng);
+            let layers = params[0].1.as_i64().unwrap();
+            let dropout = params[1].1.as_f64().unwrap();
+            assert!((1..=3).contains(&layers));
+            assert!((0.1..0.5).contains(&dropout));
+        }
+    }
+
+    #[test]
+    fn test_apply_params_to_nested_fields() {
+        let base = TestConfig::new(TestOptimizerConfig::new());
+        let params = vec![
+            ("optimizer.lr".to_string(), Value::from(0.1)),
+            ("batch_size".to_string(), Value::from(64)),
+        ];
+
+        let config = apply_params(&base, &params).unwrap();
+
+        assert_eq!(config.optimizer.lr, 0.1);
+        assert_eq!(config.batch_size, 64);
+    }
+
+    #[test]
+    fn test_apply_params_fails_with_unknown_field() {
+        let base = TestConfig::new(TestOptimizerConfig::new());
+        let params = vec![("optimizer.momentum".to_string(), Value::from(0.9))];
+
+        let result = apply_params(&base, &params);
+
+        assert!(matches!(result, Err(TunerError::UnknownField(_))));
+    }
+}
@@ -0,0 +1,132 @@
+use super::PruningStrategy;
+use crate::metric::{
+    store::{Aggregate, Direction, Split},
+    Metric,
+};
+use crate::{LearnerCallback, LearnerState};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+/// The intermediate values reported by a trial, with their epoch.
+pub type TrialReports = Vec<(usize, f64)>;
+
+#[derive(Default)]
+struct TrialState {
+    reports: Mutex<TrialReports>,
+    pruned: AtomicBool,
+}
+
+/// A handle given to the training of a trial, to report its intermediate values so that it can be
+/// pruned when it does worse than the previous trials.
+#[derive(Clone)]
+pub struct Trial {
+    id: usize,
+    state: Arc<TrialState>,
+    pruner: Option<Arc<dyn PruningStrategy>>,
+    previous: Arc<Vec<TrialReports>>,
+    direction: Direction,
+}
+
+impl Trial {
+    pub(crate) fn new(
+        id: usize,
+        pruner: Option<Arc<dyn PruningStrategy>>,
+        previous: Arc<Vec<TrialReports>>,
+        direction: Direction,
+    ) -> Self {
+        Self {
+            id,
+            state: Arc::default(),
+            pruner,
+            previous,
+            direction,
+        }
+    }
+
+    /// The number of the trial, starting at 0.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Report the value of the tuned metric at the given epoch, returns if the trial should stop.
+    pub fn report(&self, epoch: usize, value: f64) -> bool {
+        self.state.reports.lock().unwrap().push((epoch, value));
+
+        if self.is_pruned() {
+            return true;
+        }
+
+        let should_prune = match &self.pruner {
+            Some(pruner) => pruner.should_prune(epoch, value, self.direction, &self.previous),
+            None => false,
+        };
+
+        if should_prune {
+            log::info!(
+                "Pruning the trial {} at epoch {}, value: {}",
+                self.id,
+                epoch,
+                value
+            );
+            self.state.pruned.store(true, Ordering::Relaxed);
+        }
+
+        should_prune
+    }
+
+    /// True if the trial has been pruned.
+    pub fn is_pruned(&self) -> bool {
+        self.state.pruned.load(Ordering::Relaxed)
+    }
+
+    /// Create a [callback](LearnerCallback) reporting the metric at the end of each validation
+    /// epoch and interrupting the training when the trial is pruned.
+    ///
+    /// # Notes
+    ///
+    /// The metric should be registered in the learner, otherwise nothing is reported.
+    pub fn callback<Me: Metric>(&self, aggregate: Aggregate, split: Split) -> TrialCallback {
+        TrialCallback {
+            trial: self.clone(),
+            metric_name: Me::NAME.to_string(),
+            aggregate,
+            split,
+        }
+    }
+
+    pub(crate) fn reports(&self) -> TrialReports {
+        self.state.reports.lock().unwrap().clone()
+    }
+}
+
+/// A [callback](LearnerCallback) reporting a metric to a [trial](Trial), created with
+/// [Trial::callback].
+pub struct TrialCallback {
+    trial: Trial,
+    metric_name: String,
+    aggregate: Aggregate,
+    split: Split,
+}
+
+impl<M> LearnerCallback<M> for TrialCallback {
+    fn on_valid_end(&mut self, state: &mut LearnerState<M>) {
+        let value = match state.store.find_metric(
+            &self.metric_name,
+            state.epoch,
+            self.aggregate,
+            self.split,
+        ) {
+            Some(value) => value,
+            None => {
+                log::warn!("Can't find metric for pruning the trial.");
+                return;
+            }
+        };
+
+        if self.trial.report(state.epoch, value) {
+            state.interrupter.stop();
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use burn_core as burn;
+use burn_core::data::dataloader::batcher::Batcher;
+use burn_core::data::dataloader::{DataLoader, DataLoaderBuilder};
+use burn_core::data::dataset::InMemDataset;
+use burn_core::lr_scheduler::step::StepLrSchedulerConfig;
+use burn_core::module::Module;
+use burn_core::nn::{Linear, LinearConfig};
+use burn_core::optim::AdamConfig;
+use burn_core::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Distribution, Tensor};
+use burn_train::checkpoint::TrainingStateRecord;
+use burn_train::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use burn_train::{
+    LearnerBuilder, LearnerCallback, LearnerState, RegressionOutput, TrainOutput, TrainStep,
+    ValidStep,
+};
+use std::path::Path;
+use std::sync::Arc;
+
+type InnerBackend = burn_ndarray::NdArray<f32>;
+type TestBackend = burn_autodiff::Autodiff<InnerBackend>;
+type TestRecorder = BinFileRecorder<FullPrecisionSettings>;
+
+const NUM_EPOCHS: usize = 4;
+const NUM_BATCHES: usize = 3;
+
+#[derive(Module, Debug)]
+struct Model<B: Backend> {
+    linear: Linear<B>,
+}
+
+impl<B: Backend> Model<B> {
+    fn forward(&self, inputs: Tensor<B, 2>, targets: Tensor<B, 2>) -> RegressionOutput<B> {
+        let output = self.linear.forward(inputs);
+        let loss = output.clone().sub(targets.clone()).powf(2.0).mean();
+
+        RegressionOutput::new(loss, output, targets)
+    }
+}
+
+impl<B: AutodiffBackend> TrainStep<(Tensor<B, 2>, Tensor<B, 2>), RegressionOutput<B>> for Model<B> {
+    fn step(
+        &self,
+        (inputs, targets): (Tensor<B, 2>, Tensor<B, 2>),
+    ) -> TrainOutput<RegressionOutput<B>> {
+        // The noise makes the training depend on the random number generator.
+        let noise = targets.random_like(Distribution::Normal(0.0, 0.1));
+        let item = self.forward(inputs, targets.add(noise));
+
+        TrainOutput::new(self, item.loss.backward(), item)
+    }
+}
+
+impl<B: Backend> ValidStep<(Tensor<B, 2>, Tensor<B, 2>), RegressionOutput<B>> for Model<B> {
+    fn step(&self, (inputs, targets): (Tensor<B, 2>, Tensor<B, 2>)) -> RegressionOutput<B> {
+        self.forward(inputs, targets)
+    }
+}
+
+struct PointBatcher;
+
+impl<B: Backend> Batcher<[f32; 3], (Tensor<B, 2>, Tensor<B, 2>)> for PointBatcher {
+    fn batch(&self, items: Vec<[f32; 3]>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+        let inputs = items
+            .iter()
+            .map(|[x1, x2, _]| Tensor::from_floats([[*x1, *x2]]))
+            .collect();
+        let targets = items
+            .iter()
+            .map(|[_, _, y]| Tensor::from_floats([[*y]]))
+            .collect();
+
+        (Tensor::cat(inputs, 0), Tensor::cat(targets, 0))
+    }
+}
+
+fn dataloader<B: Backend>() -> Arc<dyn DataLoader<(Tensor<B, 2>, Tensor<B, 2>)>> {
+    let items = (0..NUM_BATCHES * 2)
+        .map(|i| {
+            let (x1, x2) = (i as f32 / 4.0, 1.0 - i as f32 / 8.0);
+            [x1, x2, 2.0 * x1 - x2 + 0.5]
+        })
+        .collect();
+
+    DataLoaderBuilder::new(PointBatcher)
+        .batch_size(2)
+        .build(InMemDataset::new(items))
+}
+
+struct NoRenderer;
+
+impl MetricsRenderer for NoRenderer {
+    fn update_train(&mut self, _state: MetricState) {}
+    fn update_valid(&mut self, _state: MetricState) {}
+    fn render_train(&mut self, _item: TrainingProgress) {}
+    fn render_valid(&mut self, _item: TrainingProgress) {}
+}
+
+/// Interrupts the training in the middle of an epoch, like a preempted job.
+struct InterruptAt {
+    epoch: usize,
+    iteration: usize,
+}
+
+impl<M> LearnerCallback<M> for InterruptAt {
+    fn on_batch_end(&mut self, state: &mut LearnerState<M>) {
+        if state.epoch == self.epoch && state.iteration == self.iteration {
+            state.interrupter.stop();
+        }
+    }
+}
+
+fn train(
+    directory: &Path,
+    resume: bool,
+    seed: Option<u64>,
+    interrupt: Option<InterruptAt>,
+    model: Model<TestBackend>,
+) -> Model<TestBackend> {
+    let directory = directory.to_str().unwrap();
+    let mut builder = match resume {
+        true => LearnerBuilder::resume_from(directory),
+        false => LearnerBuilder::new(directory),
+    };
+    if let Some(seed) = seed {
+        builder = builder.seed(seed);
+    }
+    if let Some(interrupt) = interrupt {
+        builder = builder.callback(interrupt);
+    }
+
+    let learner = builder
+        .with_file_checkpointer(TestRecorder::new())
+        .renderer(NoRenderer)
+        .log_to_file(false)
+        .num_epochs(NUM_EPOCHS)
+        .build(
+            model,
+            AdamConfig::new().init(),
+            StepLrSchedulerConfig::new(0.05, 4).init(),
+        );
+
+    learner.fit(dataloader::<TestBackend>(), dataloader::<InnerBackend>())
+}
+
+fn training_state(directory: &Path, epoch: usize) -> TrainingStateRecord {
+    TestRecorder::new()
+        .load(directory.join(format!("checkpoint/training-{epoch}")))
+        .unwrap()
+}
+
+#[test]
+fn test_interrupted_training_resumed_like_uninterrupted_training() {
+    let model = Model {
+        linear: LinearConfig::new(2, 1).init::<TestBackend>(),
+    };
+    let directory = tempfile::tempdir().unwrap();
+    let uninterrupted = directory.path().join("uninterrupted");
+    let interrupted = directory.path().join("interrupted");
+
+    let expected = train(&uninterrupted, false, Some(42), None, model.clone());
+
+    // Without checkpoint, the training starts from the beginning. It is interrupted during the
+    // third epoch, after the checkpoint of the second one.
+    let interrupt = InterruptAt {
+        epoch: 3,
+        iteration: 2,
+    };
+    let _model = train(&interrupted, true, Some(42), Some(interrupt), model.clone());
+    assert_eq!(
+        training_state(&interrupted, 2),
+        TrainingStateRecord::new(2, 2 * NUM_BATCHES, Some(42), 1.0)
+    );
+    assert!(!interrupted.join("checkpoint/training-3.bin").exists());
+
+    // The seed is restored from the checkpoint, the optimizer and the scheduler continue from
+    // their saved state.
+    let resumed = train(&interrupted, true, None, None, model);
+
+    assert_eq!(
+        training_state(&interrupted, NUM_EPOCHS),
+        training_state(&uninterrupted, NUM_EPOCHS),
+    );
+    resumed
+        .linear
+        .weight
+        .to_data()
+        .assert_approx_eq(&expected.linear.weight.to_data(), 5);
+}
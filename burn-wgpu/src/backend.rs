@@ -4,11 +4,26 @@ use crate::{
     tensor::WgpuTensor,
     AutoGraphicsApi, GraphicsApi, WgpuDevice,
 };
-use burn_tensor::backend::Backend;
-use rand::{rngs::StdRng, SeedableRng};
-use std::{marker::PhantomData, sync::Mutex};
+use burn_common::rand::DeviceGenerators;
+use burn_tensor::backend::{Backend, MemoryStats};
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+pub(crate) static SEED: Mutex<DeviceGenerators<WgpuDevice>> = Mutex::new(DeviceGenerators::new());
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+// When set, the autotuned operations always use the same kernel, since the kernels don't
+// accumulate the values in the same order.
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// If the [deterministic mode](Backend::set_deterministic) is enabled.
+pub(crate) fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
 
 /// Tensor backend that uses the [wgpu] crate for executing GPU compute shaders.
 ///
@@ -19,6 +34,9 @@ pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
 ///   - [DirectX 12](crate::Dx12) on Windows.
 ///   - [Metal](crate::Metal) on Apple hardware.
 ///   - [WebGPU](crate::WebGpu) on supported browsers and `wasm` runtimes.
+///
+/// The float element can be `f32` or `f16`, the latter only on the devices where
+/// [supports_f16](crate::compute::supports_f16) is true.
 #[derive(Debug, Default, Clone)]
 pub struct Wgpu<G = AutoGraphicsApi, F = f32, I = i32>
 where
@@ -48,17 +66,39 @@ impl<G: GraphicsApi + 'static, F: FloatElement, I: IntElement> Backend for Wgpu<
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        SEED.lock().unwrap().seed_all(seed);
+    }
+
+    fn seed_device(seed: u64, device: &Self::Device) {
+        SEED.lock().unwrap().seed_device(device, seed);
     }
 
     fn ad_enabled() -> bool {
         false
     }
 
+    fn set_deterministic(deterministic: bool) -> bool {
+        DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+        true
+    }
+
+    fn devices() -> Vec<Self::Device> {
+        crate::compute::devices::<G>()
+    }
+
     fn sync(device: &Self::Device) {
         let client = compute_client::<G>(device);
         client.sync();
     }
+
+    fn memory_stats(device: &Self::Device) -> Option<MemoryStats> {
+        let usage = compute_client::<G>(device).memory_usage();
+
+        Some(MemoryStats {
+            bytes_in_use: usage.bytes_in_use,
+            peak_bytes_in_use: usage.peak_bytes_in_use,
+            bytes_reserved: usage.bytes_reserved,
+            peak_bytes_reserved: usage.peak_bytes_reserved,
+        })
+    }
 }
@@ -4,14 +4,14 @@ use alloc::sync::Arc;
 use burn_compute::{
     channel::MutexComputeChannel,
     client::ComputeClient,
-    memory_management::{DeallocStrategy, SimpleMemoryManagement, SliceStrategy},
+    memory_management::{DeallocStrategy, PoolMemoryManagement},
     tune::Tuner,
     Compute,
 };
 use spin::Mutex;
 use wgpu::DeviceDescriptor;
 
-type MemoryManagement = SimpleMemoryManagement<WgpuStorage>;
+type MemoryManagement = PoolMemoryManagement<WgpuStorage>;
 /// Wgpu [compute server](WgpuServer)
 pub type Server = WgpuServer<MemoryManagement>;
 type Channel = MutexComputeChannel<Server>;
@@ -23,6 +23,10 @@ pub type WgpuHandle = burn_compute::server::Handle<Server>;
 
 /// Compute handle for the wgpu backend.
 static COMPUTE: Compute<WgpuDevice, WgpuServer<MemoryManagement>, Channel> = Compute::new();
+/// The workgroup memory of the devices with a compute client, in bytes.
+static WORKGROUP_STORAGE: Mutex<Vec<(WgpuDevice, usize)>> = Mutex::new(Vec::new());
+/// The workgroup memory guaranteed by WebGPU, in bytes.
+const WORKGROUP_STORAGE_DEFAULT: usize = 16384;
 
 /// Get the [compute client](ComputeClient) for the given [device](WgpuDevice).
 pub fn compute_client<G: GraphicsApi>(device: &WgpuDevice) -> ComputeClient<Server, Channel> {
@@ -58,17 +62,40 @@ async fn create_client<G: GraphicsApi>(device: &WgpuDevice) -> ComputeClient<Ser
         Err(_) => 64, // 64 tasks by default
     };
 
+    let workgroup_storage = device_wgpu.limits().max_compute_workgroup_storage_size as usize;
+    let mut workgroup_storages = WORKGROUP_STORAGE.lock();
+    workgroup_storages.retain(|(key, _)| key != device);
+    workgroup_storages.push((device.clone(), workgroup_storage));
+    core::mem::drop(workgroup_storages);
+
     let device = Arc::new(device_wgpu);
     let storage = WgpuStorage::new(device.clone());
-    let memory_management = SimpleMemoryManagement::new(
-        storage,
-        DeallocStrategy::new_period_tick(max_tasks * 2),
-        SliceStrategy::Ratio(0.8),
-    );
+    // The buffers are recycled across the operations and the training steps, only the ones not
+    // reused for a few thousand reservations are deallocated.
+    let memory_management =
+        PoolMemoryManagement::new(storage, DeallocStrategy::new_period_tick(max_tasks * 64));
     let server = WgpuServer::new(memory_management, device, queue, max_tasks);
     let channel = Channel::new(server);
 
-    ComputeClient::new(channel, Arc::new(Mutex::new(Tuner::new())))
+    ComputeClient::new(channel, Arc::new(Mutex::new(Tuner::new(&device_id(&info)))))
+}
+
+/// The workgroup memory available on the given [device](WgpuDevice), in bytes.
+pub(crate) fn max_workgroup_storage_size(device: &WgpuDevice) -> usize {
+    WORKGROUP_STORAGE
+        .lock()
+        .iter()
+        .find(|(key, _)| key == device)
+        .map(|(_, size)| *size)
+        .unwrap_or(WORKGROUP_STORAGE_DEFAULT)
+}
+
+/// The id of the device model, used to store the autotune results of the device.
+fn device_id(info: &wgpu::AdapterInfo) -> String {
+    format!(
+        "{:?}-{}-{}-{}",
+        info.backend, info.vendor, info.device, info.name
+    )
 }
 
 /// Select the wgpu device and queue based on the provided [device](WgpuDevice).
@@ -82,12 +109,14 @@ pub async fn select_device<G: GraphicsApi>(
     let adapter = select_adapter::<G>(device);
 
     let limits = adapter.limits();
+    // Enabled when available, so the f16 kernels can be used on the device.
+    let features = adapter.features() & wgpu::Features::SHADER_F16;
 
     let (device, queue) = adapter
         .request_device(
             &DeviceDescriptor {
                 label: None,
-                features: wgpu::Features::empty(),
+                features,
                 limits,
             },
             None,
@@ -105,6 +134,85 @@ pub async fn select_device<G: GraphicsApi>(
     (device, queue, adapter.get_info())
 }
 
+/// Whether the [device](WgpuDevice) can execute f16 kernels, so `Wgpu<G, f16, I>` can be used to
+/// halve the memory bandwidth, e.g. for inference. Otherwise, f32 should be used instead.
+#[cfg(not(target_family = "wasm"))]
+pub fn supports_f16<G: GraphicsApi>(device: &WgpuDevice) -> bool {
+    pollster::block_on(supports_f16_async::<G>(device))
+}
+
+/// Whether the [device](WgpuDevice) can execute f16 kernels, necessary for wasm.
+pub async fn supports_f16_async<G: GraphicsApi>(device: &WgpuDevice) -> bool {
+    let (device, _queue, _info) = select_device::<G>(device).await;
+    if !device.features().contains(wgpu::Features::SHADER_F16) {
+        return false;
+    }
+
+    // The shader compiler may not support the extension even if the device does, so a shader
+    // using it is compiled to make sure.
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: None,
+        source: wgpu::ShaderSource::Wgsl(
+            "enable f16;
+
+@group(0)
+@binding(0)
+var<storage, read_write> output: array<f16>;
+
+@compute
+@workgroup_size(1, 1, 1)
+fn main() {
+    output[0] = output[0] * 2.0h;
+}"
+            .into(),
+        ),
+    });
+
+    device.pop_error_scope().await.is_none()
+}
+
+/// List the devices available with the given [graphics API](GraphicsApi).
+///
+/// Devices are indexed per type in the same order used to select the adapter of a
+/// [device](WgpuDevice). Adapters of an unknown type are listed as discrete GPUs, since they are
+/// selected when the index exceeds the number of discrete GPUs.
+#[cfg(not(target_family = "wasm"))]
+pub fn devices<G: GraphicsApi>() -> Vec<WgpuDevice> {
+    use wgpu::DeviceType;
+
+    let instance = wgpu::Instance::default();
+    let (mut discrete, mut integrated, mut virtual_, mut cpu, mut other) = (0, 0, 0, 0, 0);
+
+    instance
+        .enumerate_adapters(G::backend().into())
+        .for_each(|adapter| match adapter.get_info().device_type {
+            DeviceType::DiscreteGpu => discrete += 1,
+            DeviceType::IntegratedGpu => integrated += 1,
+            DeviceType::VirtualGpu => virtual_ += 1,
+            DeviceType::Cpu => cpu += 1,
+            DeviceType::Other => other += 1,
+        });
+
+    let mut devices = Vec::new();
+    devices.extend((0..usize::max(discrete, other)).map(WgpuDevice::DiscreteGpu));
+    devices.extend((0..integrated).map(WgpuDevice::IntegratedGpu));
+    devices.extend((0..virtual_).map(WgpuDevice::VirtualGpu));
+    if cpu > 0 {
+        devices.push(WgpuDevice::Cpu);
+    }
+
+    devices
+}
+
+/// List the devices available with the given [graphics API](GraphicsApi).
+///
+/// Adapters can't be enumerated on `wasm`, only the best available device is returned.
+#[cfg(target_family = "wasm")]
+pub fn devices<G: GraphicsApi>() -> Vec<WgpuDevice> {
+    vec![WgpuDevice::BestAvailable]
+}
+
 #[cfg(target_family = "wasm")]
 async fn select_adapter<G: GraphicsApi>(_device: &WgpuDevice) -> wgpu::Adapter {
     let instance = wgpu::Instance::default();
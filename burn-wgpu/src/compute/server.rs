@@ -2,10 +2,12 @@ use super::{WgpuAutotuneKey, WgpuStorage, WorkGroup};
 use crate::kernel::SourceTemplate;
 use alloc::{borrow::Cow, sync::Arc};
 use burn_compute::{
-    memory_management::MemoryManagement,
+    memory_management::{MemoryManagement, MemoryUsage},
     server::{self, ComputeServer},
 };
 use burn_tensor::Reader;
+#[cfg(not(target_family = "wasm"))]
+use burn_tensor::SyncReader;
 use hashbrown::HashMap;
 use wgpu::{
     util::{BufferInitDescriptor, DeviceExt},
@@ -166,9 +168,22 @@ where
     }
 
     fn compile_source(&self, source: &str) -> Arc<ComputePipeline> {
+        // The f16 type is a WGSL extension, which must be enabled by the shaders using it.
+        let source = match source.contains("f16") {
+            true => {
+                assert!(
+                    self.device.features().contains(wgpu::Features::SHADER_F16),
+                    "The device doesn't support f16 shaders, use f32 on devices where \
+                     `supports_f16` is false."
+                );
+                Cow::Owned(format!("enable f16;\n{source}"))
+            }
+            false => Cow::Borrowed(source),
+        };
+
         let module = self.device.create_shader_module(ShaderModuleDescriptor {
             label: None,
-            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
+            source: wgpu::ShaderSource::Wgsl(source),
         });
 
         Arc::new(
@@ -206,27 +221,28 @@ where
 
         self.submit();
 
-        BufferReader::new(buffer_dest)
+        BufferReader::new(buffer_dest, self.device.clone())
     }
 }
 
+/// Reads a buffer once the copy submitted by the server is done, only waiting for the device
+/// when the data is read.
 #[derive(new)]
 struct BufferReader {
     buffer: wgpu::Buffer,
+    device: Arc<wgpu::Device>,
 }
 
-impl BufferReader {
-    #[cfg(target_family = "wasm")]
-    async fn read(self, device: alloc::sync::Arc<wgpu::Device>) -> Vec<u8> {
-        self.read_async(&device).await
-    }
-
-    #[cfg(not(target_family = "wasm"))]
-    fn read(self, device: &wgpu::Device) -> Vec<u8> {
-        pollster::block_on(self.read_async(device))
+#[cfg(not(target_family = "wasm"))]
+impl SyncReader<Vec<u8>> for BufferReader {
+    fn read(self: Box<Self>) -> Vec<u8> {
+        pollster::block_on(self.read_async())
     }
+}
 
-    async fn read_async(&self, device: &wgpu::Device) -> Vec<u8> {
+impl BufferReader {
+    async fn read_async(&self) -> Vec<u8> {
+        let device = &self.device;
         let buffer_slice = self.buffer.slice(..);
         let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
         buffer_slice.map_async(wgpu::MapMode::Read, move |v| {
@@ -264,12 +280,14 @@ where
     fn read(&mut self, handle: &server::Handle<Self>) -> Reader<Vec<u8>> {
         #[cfg(target_family = "wasm")]
         {
-            let future = self.buffer_reader(handle).read(self.device.clone());
-            return Reader::Future(Box::pin(future));
+            let reader = self.buffer_reader(handle);
+            return Reader::Future(Box::pin(async move { reader.read_async().await }));
         }
 
+        // The copy is submitted right away, but the device is only waited for when the reader
+        // is read, outside of the server lock.
         #[cfg(not(target_family = "wasm"))]
-        Reader::Concrete(self.buffer_reader(handle).read(&self.device))
+        Reader::Sync(Box::new(self.buffer_reader(handle)))
     }
 
     /// When we create a new handle from existing data, we use custom allocations so that we don't
@@ -345,4 +363,8 @@ where
 
         self.device.poll(wgpu::Maintain::Wait);
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
 }
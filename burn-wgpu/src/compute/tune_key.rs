@@ -2,7 +2,9 @@ use std::fmt::Display;
 
 use burn_compute::tune::AutotuneKey;
 
-use crate::kernel::{matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey};
+use crate::kernel::{
+    conv::Conv2dAutotuneKey, matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey,
+};
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone)]
 /// Key for all autotune-enabled operations
@@ -13,14 +15,17 @@ pub enum WgpuAutotuneKey {
     SumDim(ReduceAutotuneKey),
     /// Key for mean_dim operations
     MeanDim(ReduceAutotuneKey),
+    /// Key for conv2d operations
+    Conv2d(Conv2dAutotuneKey),
 }
 
 impl Display for WgpuAutotuneKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             WgpuAutotuneKey::Matmul(matmul_key) => std::fmt::Display::fmt(&matmul_key, f),
-            WgpuAutotuneKey::SumDim(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
-            WgpuAutotuneKey::MeanDim(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
+            WgpuAutotuneKey::SumDim(reduce_key) => write!(f, "SumDim {reduce_key}"),
+            WgpuAutotuneKey::MeanDim(reduce_key) => write!(f, "MeanDim {reduce_key}"),
+            WgpuAutotuneKey::Conv2d(conv2d_key) => std::fmt::Display::fmt(&conv2d_key, f),
         }
     }
 }
@@ -6,6 +6,9 @@ pub trait WgpuElement:
 where
     Self: Sized,
 {
+    /// The element type in which the reductions accumulate the values.
+    type Accumulation: WgpuElement;
+
     fn type_name() -> &'static str;
     fn as_bytes(slice: &[Self]) -> &[u8];
     fn from_bytes(bytes: &[u8]) -> &[Self];
@@ -20,6 +23,8 @@ pub trait FloatElement: WgpuElement + Element {}
 pub trait IntElement: WgpuElement + Element {}
 
 impl WgpuElement for u32 {
+    type Accumulation = u32;
+
     fn type_name() -> &'static str {
         "u32"
     }
@@ -36,6 +41,8 @@ impl WgpuElement for u32 {
 }
 
 impl WgpuElement for i32 {
+    type Accumulation = i32;
+
     fn type_name() -> &'static str {
         "i32"
     }
@@ -52,6 +59,8 @@ impl WgpuElement for i32 {
 }
 
 impl WgpuElement for f32 {
+    type Accumulation = f32;
+
     fn type_name() -> &'static str {
         "f32"
     }
@@ -68,5 +77,89 @@ impl WgpuElement for f32 {
     }
 }
 
+impl WgpuElement for half::f16 {
+    type Accumulation = f32;
+
+    fn type_name() -> &'static str {
+        "f16"
+    }
+    fn as_bytes(slice: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(slice)
+    }
+    fn from_bytes(bytes: &[u8]) -> &[Self] {
+        bytemuck::cast_slice(bytes)
+    }
+
+    #[cfg(any(feature = "fusion", test))]
+    fn elem_type() -> crate::fusion::codegen::Elem {
+        crate::fusion::codegen::Elem::F16
+    }
+}
+
 impl FloatElement for f32 {}
+impl FloatElement for half::f16 {}
 impl IntElement for i32 {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compute::supports_f16, AutoGraphicsApi, Wgpu, WgpuDevice};
+    use burn_tensor::{Data, Tensor};
+    use half::f16;
+
+    type TestBackendF16 = Wgpu<AutoGraphicsApi, f16, i32>;
+
+    #[test]
+    fn f16_tensors_should_match_f32_when_supported() {
+        if !supports_f16::<AutoGraphicsApi>(&WgpuDevice::default()) {
+            return;
+        }
+
+        let data = Data::<f32, 2>::from([[0.5, -1.0, 2.0], [3.0, 0.25, -4.0]]);
+        let lhs = Tensor::<crate::tests::TestBackend, 2>::from_data(data.clone());
+        let lhs_f16 = Tensor::<TestBackendF16, 2>::from_data(data.clone().convert());
+
+        let output = lhs.clone().matmul(lhs.transpose()).exp().sum_dim(1);
+        let output_f16 = lhs_f16.clone().matmul(lhs_f16.transpose()).exp().sum_dim(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_f16.into_data().convert(), 1);
+    }
+
+    #[test]
+    fn f16_sum_dim_should_accumulate_in_f32() {
+        if !supports_f16::<AutoGraphicsApi>(&WgpuDevice::default()) {
+            return;
+        }
+
+        // Above 2048, adding one to an f16 sum is rounded away.
+        let tensor = Tensor::<TestBackendF16, 2>::ones([2, 4096]);
+
+        let output = tensor.sum_dim(1);
+
+        output
+            .into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&Data::from([[4096.0], [4096.0]]), 3);
+    }
+
+    #[test]
+    fn f16_mean_should_not_overflow_when_the_sum_does() {
+        if !supports_f16::<AutoGraphicsApi>(&WgpuDevice::default()) {
+            return;
+        }
+
+        // The sum is 81920, larger than the largest f16 value.
+        let tensor = Tensor::<TestBackendF16, 2>::full([8, 1024], 10.0);
+
+        let sum = tensor.clone().sum_dim(1);
+        let mean = tensor.mean();
+
+        sum.into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&Data::from([[10240.0]; 8]), 3);
+        mean.into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&Data::from([10.0]), 3);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     compute::{WgpuComputeClient, WgpuHandle},
     element::WgpuElement,
-    fusion::FloatElementWiseFusionOps,
+    fusion::{codegen::Elem, FloatElementWiseFusionOps},
     tensor::WgpuTensor,
     FloatElement, GraphicsApi, IntElement, Wgpu, WgpuDevice,
 };
@@ -34,6 +34,11 @@ where
     type FusionClient = MutexFusionClient<Self, GreedyGraphExecution>;
 
     fn operations(device: &WgpuDevice) -> Vec<Box<dyn burn_fusion::FusionOps<Self>>> {
+        // The scalars of the fused kernels are f32, so only f32 tensors are fused.
+        if F::elem_type() != Elem::F32 {
+            return Vec::new();
+        }
+
         vec![Box::new(FloatElementWiseFusionOps::new(device.clone()))]
     }
 
@@ -65,6 +65,26 @@ pub enum Operator {
         input: Variable,
         out: Variable,
     },
+    Sqrt {
+        input: Variable,
+        out: Variable,
+    },
+    Max {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    Min {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    Clamp {
+        input: Variable,
+        min_value: Variable,
+        max_value: Variable,
+        out: Variable,
+    },
     Equal {
         lhs: Variable,
         rhs: Variable,
@@ -140,6 +160,23 @@ impl Display for Operator {
             Operator::Recip { input, out } => {
                 f.write_fmt(format_args!("let {out} = 1.0 / {input};"))
             }
+            Operator::Sqrt { input, out } => {
+                f.write_fmt(format_args!("let {out} = sqrt({input});"))
+            }
+            Operator::Max { lhs, rhs, out } => {
+                f.write_fmt(format_args!("let {out} = max({lhs}, {rhs});"))
+            }
+            Operator::Min { lhs, rhs, out } => {
+                f.write_fmt(format_args!("let {out} = min({lhs}, {rhs});"))
+            }
+            Operator::Clamp {
+                input,
+                min_value,
+                max_value,
+                out,
+            } => f.write_fmt(format_args!(
+                "let {out} = clamp({input}, {min_value}, {max_value});"
+            )),
             Operator::Equal { lhs, rhs, out } => {
                 f.write_fmt(format_args!("let {out} = {lhs} == {rhs};"))
             }
@@ -22,6 +22,7 @@ pub enum Visibility {
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
 pub enum Elem {
     F32,
+    F16,
     #[allow(dead_code)]
     I32,
     U32,
@@ -186,6 +187,7 @@ impl Display for Elem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Elem::F32 => f.write_str("f32"),
+            Elem::F16 => f.write_str("f16"),
             Elem::I32 => f.write_str("i32"),
             Elem::U32 => f.write_str("u32"),
             Elem::Bool => f.write_str("bool"),
@@ -226,6 +226,31 @@ where
                     mark(input, &mut local_tensor_ids_input);
                     mark(out, &mut local_tensor_ids_output);
                 }
+                Operator::Sqrt { input, out } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
+                Operator::Max { lhs, rhs, out } => {
+                    mark(lhs, &mut local_tensor_ids_input);
+                    mark(rhs, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
+                Operator::Min { lhs, rhs, out } => {
+                    mark(lhs, &mut local_tensor_ids_input);
+                    mark(rhs, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
+                Operator::Clamp {
+                    input,
+                    min_value,
+                    max_value,
+                    out,
+                } => {
+                    mark(input, &mut local_tensor_ids_input);
+                    mark(min_value, &mut local_tensor_ids_input);
+                    mark(max_value, &mut local_tensor_ids_input);
+                    mark(out, &mut local_tensor_ids_output);
+                }
                 Operator::Lower { lhs, rhs, out } => {
                     mark(lhs, &mut local_tensor_ids_input);
                     mark(rhs, &mut local_tensor_ids_input);
@@ -397,6 +422,11 @@ where
                     Operator::Recip { input, out }
                 })
             }
+            FloatOpsDescription::Sqrt(desc) => {
+                self.register_unary_ops(desc, (E::elem_type(), E::elem_type()), |input, out| {
+                    Operator::Sqrt { input, out }
+                })
+            }
             _ => false,
         }
     }
@@ -493,6 +523,35 @@ where
                 (E::elem_type(), E::elem_type(), Elem::Bool),
                 |lhs, rhs, out| Operator::Equal { lhs, rhs, out },
             ),
+            NumericOpsDescription::ClampMin(desc) => self.register_scalar_ops(
+                desc,
+                (E::elem_type(), E::elem_type(), E::elem_type()),
+                |lhs, rhs, out| Operator::Max { lhs, rhs, out },
+            ),
+            NumericOpsDescription::ClampMax(desc) => self.register_scalar_ops(
+                desc,
+                (E::elem_type(), E::elem_type(), E::elem_type()),
+                |lhs, rhs, out| Operator::Min { lhs, rhs, out },
+            ),
+            NumericOpsDescription::Clamp(desc) => {
+                if !self.output_is_compatible(&desc.out) {
+                    return false;
+                }
+
+                let input = self.input_to_var(&desc.tensor, E::elem_type());
+                let min_value = self.scalar_to_var(&desc.min, E::elem_type());
+                let max_value = self.scalar_to_var(&desc.max, E::elem_type());
+                let out = self.output_to_var(&desc.out, E::elem_type());
+
+                self.operators.push(Operator::Clamp {
+                    input,
+                    min_value,
+                    max_value,
+                    out,
+                });
+
+                true
+            }
             NumericOpsDescription::MaskWhere(desc) => {
                 if !self.output_is_compatible(&desc.out) {
                     return false;
@@ -614,6 +673,9 @@ where
                 self.scalars_u32.push(value.elem());
                 Variable::Scalar(self.scalars_u32.len() as u16 - 1, Elem::U32)
             }
+            Elem::F16 => {
+                panic!("F16 scalars not supported")
+            }
             Elem::Bool => {
                 panic!("Bool scalars not supported")
             }
@@ -676,4 +738,25 @@ mod tests {
 
         result_fused.assert_approx_eq(&result_ref, 3);
     }
+
+    #[test]
+    fn test_fusion_clamp_sqrt_chain_same_behavior() {
+        type Backend = Wgpu;
+        type FusedBackend = Fusion<Wgpu>;
+
+        fn chain<B: burn_tensor::backend::Backend>(tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+            let tensor = tensor.clamp_min(0.2).sqrt();
+            let tensor = (tensor.clone() * 2.0).clamp(0.5, 1.5) + tensor;
+
+            tensor.clamp_max(2.0)
+        }
+
+        let data =
+            Tensor::<Backend, 2>::random([32, 32], burn_tensor::Distribution::Default).into_data();
+
+        let result_ref = chain(Tensor::<Backend, 2>::from_data(data.clone())).into_data();
+        let result_fused = chain(Tensor::<FusedBackend, 2>::from_data(data)).into_data();
+
+        result_fused.assert_approx_eq(&result_ref, 3);
+    }
 }
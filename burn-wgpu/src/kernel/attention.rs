@@ -0,0 +1,442 @@
+use crate::{
+    compute::{max_workgroup_storage_size, DynamicKernel, WgpuHandle, WorkGroup},
+    element::WgpuElement,
+    kernel::{self, DynamicKernelSource, SourceTemplate, StaticKernelSource},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+use burn_tensor::{ops::AttentionDropout, Element, Shape};
+use std::marker::PhantomData;
+
+kernel_wgsl!(
+    FlashAttentionForwardRaw,
+    "../template/attention/forward.wgsl"
+);
+kernel_wgsl!(
+    FlashAttentionBackwardStatisticsRaw,
+    "../template/attention/backward_statistics.wgsl"
+);
+kernel_wgsl!(
+    FlashAttentionBackwardQueryRaw,
+    "../template/attention/backward_query.wgsl"
+);
+kernel_wgsl!(
+    FlashAttentionBackwardKeyValueRaw,
+    "../template/attention/backward_key_value.wgsl"
+);
+
+/// The maximum number of queries or keys processed by a workgroup.
+const BLOCK_SIZE_MAX: usize = 64;
+/// The maximum workgroup memory used for the blocks of keys and values, in bytes.
+const WORKGROUP_MEMORY: usize = 16384;
+
+#[derive(new)]
+struct FlashAttention<K: StaticKernelSource, E: WgpuElement> {
+    block_size: usize,
+    d_k: usize,
+    d_v: usize,
+    _kernel: PhantomData<K>,
+    _elem: PhantomData<E>,
+}
+
+impl<K: StaticKernelSource, E: WgpuElement> DynamicKernelSource for FlashAttention<K, E> {
+    fn source(&self) -> SourceTemplate {
+        K::source()
+            .register("block_size", self.block_size.to_string())
+            .register("d_k", self.d_k.to_string())
+            .register("d_v", self.d_v.to_string())
+            .register("block_size_x_d_k", (self.block_size * self.d_k).to_string())
+            .register("block_size_x_d_v", (self.block_size * self.d_v).to_string())
+            .register("block_size_x_2", (self.block_size * 2).to_string())
+            .register("elem", E::type_name())
+            .add_template(include_str!("../template/attention/dropout.wgsl"))
+    }
+
+    fn id(&self) -> String {
+        let id = core::any::TypeId::of::<K>();
+
+        std::format!(
+            "{:?}-{}-{}-{}-{}",
+            id,
+            self.block_size,
+            self.d_k,
+            self.d_v,
+            E::type_name()
+        )
+    }
+}
+
+/// Scaled dot-product attention computed in a single kernel, without storing the attention
+/// weights.
+///
+/// The keys and values are processed by blocks shared by the queries of a workgroup, and the
+/// softmax is computed online, so the memory usage is linear in the sequence length. The dropout,
+/// if any, is generated in the kernel from its seed and only scales the contribution of each
+/// value to the output.
+///
+/// Returns the output and the log-sum-exp of the attention scores of each query, from which the
+/// [backward pass](flash_attention_backward) recomputes the attention weights.
+pub(crate) fn flash_attention<E: WgpuElement + Element>(
+    query: WgpuTensor<E, 4>,
+    key: WgpuTensor<E, 4>,
+    value: WgpuTensor<E, 4>,
+    mask: Option<WgpuTensor<u32, 3>>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+) -> (WgpuTensor<E, 4>, WgpuTensor<E, 3>) {
+    let query = kernel::into_contiguous(query);
+    let key = kernel::into_contiguous(key);
+    let value = kernel::into_contiguous(value);
+
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape.dims;
+    let [_, _, _, d_v] = value.shape.dims;
+
+    let output = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_1, d_v]),
+    );
+    let logsumexp = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_1]),
+    );
+
+    let block_size = workgroup_block_size::<E>(&query, d_k + d_v);
+    let mask_handle = mask_handle(&query, mask);
+    let info_handle = info_handle(&query, &key, mask_handle.1, dropout, min_float);
+    let kernel = FlashAttention::<FlashAttentionForwardRaw, E>::new(block_size, d_k, d_v);
+
+    query.client.execute(
+        Box::new(DynamicKernel::new(
+            kernel,
+            workgroup(seq_length_1, block_size, batch_size * n_heads),
+        )),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &mask_handle.0,
+            &output.handle,
+            &logsumexp.handle,
+            &info_handle,
+        ],
+    );
+
+    (output, logsumexp)
+}
+
+/// Backward pass of the [flash attention](flash_attention), returning the gradients of the
+/// query, the key and the value.
+///
+/// The attention weights are recomputed from the log-sum-exp of the forward pass by blocks
+/// shared by the invocations of a workgroup, like in the forward pass, so the memory usage stays
+/// linear in the sequence length. A first kernel computes the statistics of each query, then the
+/// gradient of the queries is computed with one invocation per query, and the gradients of the
+/// keys and values with one invocation per key.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn flash_attention_backward<E: WgpuElement + Element>(
+    query: WgpuTensor<E, 4>,
+    key: WgpuTensor<E, 4>,
+    value: WgpuTensor<E, 4>,
+    mask: Option<WgpuTensor<u32, 3>>,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+    output: WgpuTensor<E, 4>,
+    logsumexp: WgpuTensor<E, 3>,
+    output_grad: WgpuTensor<E, 4>,
+) -> (WgpuTensor<E, 4>, WgpuTensor<E, 4>, WgpuTensor<E, 4>) {
+    let query = kernel::into_contiguous(query);
+    let key = kernel::into_contiguous(key);
+    let value = kernel::into_contiguous(value);
+    let output = kernel::into_contiguous(output);
+    let logsumexp = kernel::into_contiguous(logsumexp);
+    let output_grad = kernel::into_contiguous(output_grad);
+
+    let [batch_size, n_heads, seq_length_1, d_k] = query.shape.dims;
+    let [_, _, seq_length_2, d_v] = value.shape.dims;
+    let num_batch_heads = batch_size * n_heads;
+
+    let mask_handle = mask_handle(&query, mask);
+    let info_handle = info_handle(&query, &key, mask_handle.1, dropout, min_float);
+
+    let statistics = empty_device::<E, 4>(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_1, 2]),
+    );
+    let kernel =
+        FlashAttention::<FlashAttentionBackwardStatisticsRaw, E>::new(BLOCK_SIZE_MAX, d_k, d_v);
+    query.client.execute(
+        Box::new(DynamicKernel::new(
+            kernel,
+            workgroup(seq_length_1, BLOCK_SIZE_MAX, num_batch_heads),
+        )),
+        &[
+            &output.handle,
+            &output_grad.handle,
+            &logsumexp.handle,
+            &statistics.handle,
+            &info_handle,
+        ],
+    );
+
+    let query_grad = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        query.shape.clone(),
+    );
+    let block_size = workgroup_block_size::<E>(&query, d_k + d_v);
+    let kernel = FlashAttention::<FlashAttentionBackwardQueryRaw, E>::new(block_size, d_k, d_v);
+    query.client.execute(
+        Box::new(DynamicKernel::new(
+            kernel,
+            workgroup(seq_length_1, block_size, num_batch_heads),
+        )),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &mask_handle.0,
+            &output_grad.handle,
+            &statistics.handle,
+            &query_grad.handle,
+            &info_handle,
+        ],
+    );
+
+    let key_value_grad = empty_device(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new([batch_size, n_heads, seq_length_2, d_k + d_v]),
+    );
+    let block_size = workgroup_block_size::<E>(&query, d_k + d_v + 2);
+    let kernel = FlashAttention::<FlashAttentionBackwardKeyValueRaw, E>::new(block_size, d_k, d_v);
+    query.client.execute(
+        Box::new(DynamicKernel::new(
+            kernel,
+            workgroup(seq_length_2, block_size, num_batch_heads),
+        )),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &mask_handle.0,
+            &output_grad.handle,
+            &statistics.handle,
+            &key_value_grad.handle,
+            &info_handle,
+        ],
+    );
+
+    let key_grad = kernel::slice(
+        key_value_grad.clone(),
+        [0..batch_size, 0..n_heads, 0..seq_length_2, 0..d_k],
+    );
+    let value_grad = kernel::slice(
+        key_value_grad,
+        [0..batch_size, 0..n_heads, 0..seq_length_2, d_k..d_k + d_v],
+    );
+
+    (query_grad, key_grad, value_grad)
+}
+
+/// The number of queries or keys processed by a workgroup, each one needing `row_elems`
+/// elements of workgroup memory.
+fn workgroup_block_size<E: WgpuElement>(query: &WgpuTensor<E, 4>, row_elems: usize) -> usize {
+    let workgroup_memory = usize::min(WORKGROUP_MEMORY, max_workgroup_storage_size(&query.device));
+    let row_memory = core::mem::size_of::<E>() * row_elems;
+    assert!(
+        row_memory <= workgroup_memory,
+        "The attention head dimensions need {row_memory} bytes of workgroup memory per query or \
+         key, but the device only provides {workgroup_memory} bytes."
+    );
+
+    usize::clamp(workgroup_memory / row_memory, 1, BLOCK_SIZE_MAX)
+}
+
+fn workgroup(num_rows: usize, block_size: usize, num_batch_heads: usize) -> WorkGroup {
+    WorkGroup::new(
+        f32::ceil(num_rows as f32 / block_size as f32) as u32,
+        num_batch_heads as u32,
+        1,
+    )
+}
+
+/// The handle of the mask and whether there is a mask.
+fn mask_handle<E: WgpuElement>(
+    query: &WgpuTensor<E, 4>,
+    mask: Option<WgpuTensor<u32, 3>>,
+) -> (WgpuHandle, u32) {
+    match mask {
+        Some(mask) => (kernel::into_contiguous(mask).handle, 1),
+        None => (query.client.create(bytemuck::cast_slice(&[0u32])), 0),
+    }
+}
+
+/// The info shared by the attention kernels. The scalars are stored as the bits of 32 bits
+/// floats, since the kernels can't bind another buffer.
+fn info_handle<E: WgpuElement>(
+    query: &WgpuTensor<E, 4>,
+    key: &WgpuTensor<E, 4>,
+    has_mask: u32,
+    dropout: Option<AttentionDropout>,
+    min_float: f64,
+) -> WgpuHandle {
+    let [_, n_heads, seq_length_1, d_k] = query.shape.dims;
+    let [_, _, seq_length_2, _] = key.shape.dims;
+    let scale = 1.0 / f64::sqrt(d_k as f64);
+
+    let (has_dropout, dropout_seed, dropout_threshold, dropout_scale) = match dropout {
+        Some(dropout) => (1, dropout.seed, dropout.threshold(), dropout.scale()),
+        None => (0, 0, 0, 1.0),
+    };
+
+    let info = [
+        seq_length_1 as u32,
+        seq_length_2 as u32,
+        n_heads as u32,
+        has_mask,
+        has_dropout,
+        dropout_seed,
+        dropout_threshold,
+        (scale as f32).to_bits(),
+        (min_float as f32).to_bits(),
+        (dropout_scale as f32).to_bits(),
+    ];
+
+    query.client.create(bytemuck::cast_slice(&info))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{module, ops::AttentionDropout, Bool, Distribution, Tensor};
+
+    type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+    type ReferenceAutodiffBackend = burn_autodiff::Autodiff<ReferenceBackend>;
+
+    #[test]
+    fn flash_attention_should_match_reference_backend() {
+        let query = Tensor::<TestBackend, 4>::random([2, 3, 100, 16], Distribution::Default);
+        let key = Tensor::<TestBackend, 4>::random([2, 3, 80, 16], Distribution::Default);
+        let value = Tensor::<TestBackend, 4>::random([2, 3, 80, 24], Distribution::Default);
+        let query_ref = Tensor::<ReferenceBackend, 4>::from_data(query.to_data());
+        let key_ref = Tensor::<ReferenceBackend, 4>::from_data(key.to_data());
+        let value_ref = Tensor::<ReferenceBackend, 4>::from_data(value.to_data());
+
+        let output = module::attention(query, key, value, None, -1.0e4);
+        let output_ref = module::attention(query_ref, key_ref, value_ref, None, -1.0e4);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn flash_attention_should_support_dropout() {
+        let query = Tensor::<TestBackend, 4>::random([2, 3, 40, 8], Distribution::Default);
+        let key = Tensor::<TestBackend, 4>::random([2, 3, 90, 8], Distribution::Default);
+        let value = Tensor::<TestBackend, 4>::random([2, 3, 90, 8], Distribution::Default);
+        let dropout = AttentionDropout::new(0.1, 123);
+        let query_ref = Tensor::<ReferenceBackend, 4>::from_data(query.to_data());
+        let key_ref = Tensor::<ReferenceBackend, 4>::from_data(key.to_data());
+        let value_ref = Tensor::<ReferenceBackend, 4>::from_data(value.to_data());
+
+        let output = module::attention_with_dropout(query, key, value, None, Some(dropout), -1.0e4);
+        let output_ref = module::attention_with_dropout(
+            query_ref,
+            key_ref,
+            value_ref,
+            None,
+            Some(dropout),
+            -1.0e4,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn flash_attention_should_support_mask_and_swapped_dims() {
+        let query = Tensor::<TestBackend, 4>::random([2, 70, 4, 8], Distribution::Default);
+        let key = Tensor::<TestBackend, 4>::random([2, 70, 4, 8], Distribution::Default);
+        let value = Tensor::<TestBackend, 4>::random([2, 70, 4, 8], Distribution::Default);
+        let mask =
+            Tensor::<TestBackend, 3>::random([2, 70, 70], Distribution::Default).greater_elem(0.5);
+        let query_ref = Tensor::<ReferenceBackend, 4>::from_data(query.to_data());
+        let key_ref = Tensor::<ReferenceBackend, 4>::from_data(key.to_data());
+        let value_ref = Tensor::<ReferenceBackend, 4>::from_data(value.to_data());
+        let mask_ref = Tensor::<ReferenceBackend, 3, Bool>::from_data(mask.to_data());
+
+        let output = module::attention(
+            query.swap_dims(1, 2),
+            key.swap_dims(1, 2),
+            value.swap_dims(1, 2),
+            Some(mask),
+            -1.0e4,
+        );
+        let output_ref = module::attention(
+            query_ref.swap_dims(1, 2),
+            key_ref.swap_dims(1, 2),
+            value_ref.swap_dims(1, 2),
+            Some(mask_ref),
+            -1.0e4,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn flash_attention_backward_should_match_reference_backend() {
+        let query = Tensor::<TestBackend, 4>::random([2, 3, 100, 16], Distribution::Default);
+        let key = Tensor::<TestBackend, 4>::random([2, 3, 80, 16], Distribution::Default);
+        let value = Tensor::<TestBackend, 4>::random([2, 3, 80, 24], Distribution::Default);
+        let mask =
+            Tensor::<TestBackend, 3>::random([2, 100, 80], Distribution::Default).greater_elem(0.8);
+        let dropout = AttentionDropout::new(0.2, 7);
+
+        let query_ref =
+            Tensor::<ReferenceAutodiffBackend, 4>::from_data(query.to_data()).require_grad();
+        let key_ref =
+            Tensor::<ReferenceAutodiffBackend, 4>::from_data(key.to_data()).require_grad();
+        let value_ref =
+            Tensor::<ReferenceAutodiffBackend, 4>::from_data(value.to_data()).require_grad();
+        let mask_ref = Tensor::<ReferenceAutodiffBackend, 3, Bool>::from_data(mask.to_data());
+        let query = Tensor::<TestAutodiffBackend, 4>::from_data(query.to_data()).require_grad();
+        let key = Tensor::<TestAutodiffBackend, 4>::from_data(key.to_data()).require_grad();
+        let value = Tensor::<TestAutodiffBackend, 4>::from_data(value.to_data()).require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 3, Bool>::from_data(mask.to_data());
+
+        let output = module::attention_with_dropout(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            Some(mask),
+            Some(dropout),
+            -1.0e4,
+        );
+        let output_ref = module::attention_with_dropout(
+            query_ref.clone(),
+            key_ref.clone(),
+            value_ref.clone(),
+            Some(mask_ref),
+            Some(dropout),
+            -1.0e4,
+        );
+        let grads = output.sin().sum().backward();
+        let grads_ref = output_ref.sin().sum().backward();
+
+        for (tensor, tensor_ref) in [(query, query_ref), (key, key_ref), (value, value_ref)] {
+            tensor
+                .grad(&grads)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&tensor_ref.grad(&grads_ref).unwrap().into_data(), 3);
+        }
+    }
+}
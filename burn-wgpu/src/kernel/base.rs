@@ -138,6 +138,7 @@ impl<
                 (WORKGROUP_X_SIZE * WORKGROUP_Y_SIZE * WORKGROUP_Z_SIZE).to_string(),
             )
             .register("elem", E::type_name())
+            .register("acc", E::Accumulation::type_name())
             .register("int", I::type_name())
     }
 }
@@ -166,6 +167,7 @@ impl<K: StaticKernelSource, E: WgpuElement, I: WgpuElement> DynamicKernelSource
                 (self.workgroup_x_size * self.workgroup_y_size * self.workgroup_z_size).to_string(),
             )
             .register("elem", E::type_name())
+            .register("acc", E::Accumulation::type_name())
             .register("int", I::type_name())
     }
 
@@ -19,8 +19,16 @@ pub(crate) fn conv2d<E: WgpuElement + Element>(
     bias: Option<WgpuTensor<E, 1>>,
     options: ConvOptions<2>,
 ) -> WgpuTensor<E, 4> {
-    let input = kernel::into_contiguous(input);
-    let weight = kernel::into_contiguous(weight);
+    let output = init_conv2d_output(&input, &weight, &options);
+    conv2d_workgroup::<E, WORKGROUP_DEFAULT>(input, weight, bias, options, output)
+}
+
+/// Creates the output tensor of a 2D convolution.
+pub(crate) fn init_conv2d_output<E: WgpuElement>(
+    input: &WgpuTensor<E, 4>,
+    weight: &WgpuTensor<E, 4>,
+    options: &ConvOptions<2>,
+) -> WgpuTensor<E, 4> {
     let [batch_size, _, in_height, in_width] = input.shape.dims;
     let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
 
@@ -41,11 +49,20 @@ pub(crate) fn conv2d<E: WgpuElement + Element>(
 
     let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
 
-    let output = empty_device(
-        input.client.clone(),
-        input.device.clone(),
-        shape_out.clone(),
-    );
+    empty_device(input.client.clone(), input.device.clone(), shape_out)
+}
+
+/// Executes the 2D convolution in the output tensor, with workgroups of
+/// `WORKGROUP * WORKGROUP` invocations.
+pub(crate) fn conv2d_workgroup<E: WgpuElement + Element, const WORKGROUP: usize>(
+    input: WgpuTensor<E, 4>,
+    weight: WgpuTensor<E, 4>,
+    bias: Option<WgpuTensor<E, 1>>,
+    options: ConvOptions<2>,
+    output: WgpuTensor<E, 4>,
+) -> WgpuTensor<E, 4> {
+    let input = kernel::into_contiguous(input);
+    let weight = kernel::into_contiguous(weight);
 
     let mut info = build_info(&[&input, &output, &weight]);
     info.push(options.stride[0] as u32);
@@ -62,12 +79,9 @@ pub(crate) fn conv2d<E: WgpuElement + Element>(
 
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
-    let kernel = StaticKernel::<
-        KernelSettings<Conv2d, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
-    >::new(elemwise_workgroup(
-        output.shape.num_elements(),
-        WORKGROUP_DEFAULT,
-    ));
+    let kernel = StaticKernel::<KernelSettings<Conv2d, E, i32, WORKGROUP, WORKGROUP, 1>>::new(
+        elemwise_workgroup(output.shape.num_elements(), WORKGROUP),
+    );
 
     input.client.execute(
         Box::new(kernel),
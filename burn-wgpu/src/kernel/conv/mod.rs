@@ -1,5 +1,7 @@
 mod conv2d;
 mod conv_transpose2d;
+mod tune;
 
 pub use conv2d::*;
 pub use conv_transpose2d::*;
+pub use tune::*;
@@ -0,0 +1,193 @@
+use burn_compute::tune::{AutotuneOperation, AutotuneOperationSet};
+use burn_tensor::{ops::ConvOptions, Element, ElementConversion};
+
+use crate::{
+    compute::WgpuAutotuneKey,
+    element::WgpuElement,
+    kernel::{
+        conv::{conv2d_workgroup, init_conv2d_output},
+        prng::random_like_uniform,
+        WORKGROUP_DEFAULT,
+    },
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+use super::key::Conv2dAutotuneKey;
+
+/// Set of conv2d implementations available for autotune
+/// The implementations differ by the size of their workgroups, whose best value depends on the
+/// GPU vendor
+pub struct Conv2dAutotuneOperationSet<E: WgpuElement> {
+    key: WgpuAutotuneKey,
+    input: WgpuTensor<E, 4>,
+    weight: WgpuTensor<E, 4>,
+    bias: Option<WgpuTensor<E, 1>>,
+    options: ConvOptions<2>,
+    output: WgpuTensor<E, 4>,
+}
+impl<E: WgpuElement> Conv2dAutotuneOperationSet<E> {
+    fn new(
+        input: WgpuTensor<E, 4>,
+        weight: WgpuTensor<E, 4>,
+        bias: Option<WgpuTensor<E, 1>>,
+        options: ConvOptions<2>,
+        output: WgpuTensor<E, 4>,
+    ) -> Self {
+        Self {
+            key: WgpuAutotuneKey::Conv2d(Conv2dAutotuneKey::new(
+                &input.shape,
+                &weight.shape,
+                &options,
+            )),
+            input,
+            weight,
+            bias,
+            options,
+            output,
+        }
+    }
+}
+
+impl<E: WgpuElement + Element> AutotuneOperationSet<WgpuAutotuneKey>
+    for Conv2dAutotuneOperationSet<E>
+{
+    fn key(&self) -> WgpuAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+        let input = random_like_uniform(&self.input, random_bounds.0, random_bounds.1);
+        let weight = random_like_uniform(&self.weight, random_bounds.0, random_bounds.1);
+        let bias = self
+            .bias
+            .as_ref()
+            .map(|bias| random_like_uniform(bias, random_bounds.0, random_bounds.1));
+
+        let output = empty_device(
+            self.output.client.clone(),
+            self.output.device.clone(),
+            self.output.shape.clone(),
+        );
+
+        vec![
+            Box::new(Conv2dDefault::<E>::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                self.options.clone(),
+                output.clone(),
+            )),
+            Box::new(Conv2dW16x16::<E>::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                self.options.clone(),
+                output.clone(),
+            )),
+            Box::new(Conv2dW8x8::<E>::new(
+                input,
+                weight,
+                bias,
+                self.options.clone(),
+                output,
+            )),
+        ]
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        match fastest_index {
+            0 => Box::new(Conv2dDefault::<E>::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.options,
+                self.output,
+            )),
+            1 => Box::new(Conv2dW16x16::<E>::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.options,
+                self.output,
+            )),
+            2 => Box::new(Conv2dW8x8::<E>::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.options,
+                self.output,
+            )),
+            _ => panic!("Fastest index is out of bound"),
+        }
+    }
+}
+
+/// Executes autotune on conv2d operations
+pub fn conv2d_autotune<E: WgpuElement + Element>(
+    input: WgpuTensor<E, 4>,
+    weight: WgpuTensor<E, 4>,
+    bias: Option<WgpuTensor<E, 1>>,
+    options: ConvOptions<2>,
+) -> WgpuTensor<E, 4> {
+    let client = input.client.clone();
+
+    let output = init_conv2d_output(&input, &weight, &options);
+
+    let operation_set = Box::new(Conv2dAutotuneOperationSet::<E>::new(
+        input,
+        weight,
+        bias,
+        options,
+        output.clone(),
+    ));
+
+    client.execute_autotune(operation_set);
+
+    output
+}
+
+macro_rules! conv2d_tune_ops {
+    ($name:ident, $workgroup:expr) => {
+        #[derive(new)]
+        pub(crate) struct $name<E: WgpuElement> {
+            input: WgpuTensor<E, 4>,
+            weight: WgpuTensor<E, 4>,
+            bias: Option<WgpuTensor<E, 1>>,
+            options: ConvOptions<2>,
+            output: WgpuTensor<E, 4>,
+        }
+
+        impl<E: WgpuElement + Element> AutotuneOperation for $name<E> {
+            fn execute(self: Box<Self>) {
+                conv2d_workgroup::<E, { $workgroup }>(
+                    self.input,
+                    self.weight,
+                    self.bias,
+                    self.options,
+                    self.output,
+                );
+            }
+
+            fn clone(&self) -> Box<dyn AutotuneOperation> {
+                Box::new(Self {
+                    input: self.input.clone(),
+                    weight: self.weight.clone(),
+                    bias: self.bias.clone(),
+                    options: self.options.clone(),
+                    output: self.output.clone(),
+                })
+            }
+        }
+    };
+}
+
+// The default workgroup size, 32x32 on native platforms.
+conv2d_tune_ops!(Conv2dDefault, WORKGROUP_DEFAULT);
+
+// Potentially better on GPUs with fewer invocations per compute unit.
+conv2d_tune_ops!(Conv2dW16x16, 16);
+
+// Potentially better for small outputs.
+conv2d_tune_ops!(Conv2dW8x8, 8);
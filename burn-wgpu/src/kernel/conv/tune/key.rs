@@ -0,0 +1,82 @@
+use burn_tensor::{ops::ConvOptions, Shape};
+use core::fmt::Debug;
+use std::{cmp::min, fmt::Display, hash::Hash};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+/// Autotune key representative of conv2d versions
+pub struct Conv2dAutotuneKey {
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    groups: usize,
+    anchored_batch: usize,
+    anchored_in_channels: usize,
+    anchored_out_channels: usize,
+    anchored_height: usize,
+    anchored_width: usize,
+}
+
+impl Display for Conv2dAutotuneKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            format!(
+                "Conv2d - kernel:{:?} stride:{:?} groups:{:?} batch:{:?} in:{:?} out:{:?} h:{:?} w:{:?}",
+                self.kernel_size,
+                self.stride,
+                self.groups,
+                self.anchored_batch,
+                self.anchored_in_channels,
+                self.anchored_out_channels,
+                self.anchored_height,
+                self.anchored_width
+            )
+            .as_str(),
+        )
+    }
+}
+
+impl Conv2dAutotuneKey {
+    /// Create a conv2d autotune key from the input and weight shapes and the options
+    pub fn new(input_shape: &Shape<4>, weight_shape: &Shape<4>, options: &ConvOptions<2>) -> Self {
+        let [batch_size, in_channels, height, width] = input_shape.dims;
+        let [out_channels, _, kernel_0, kernel_1] = weight_shape.dims;
+
+        Self {
+            kernel_size: [kernel_0, kernel_1],
+            stride: options.stride,
+            groups: options.groups,
+            anchored_batch: anchor(batch_size, Some(256)),
+            anchored_in_channels: anchor(in_channels, None),
+            anchored_out_channels: anchor(out_channels, None),
+            anchored_height: anchor(height, None),
+            anchored_width: anchor(width, None),
+        }
+    }
+}
+
+fn anchor(x: usize, max: Option<usize>) -> usize {
+    let exp = f32::ceil(f32::log2(x as f32)) as u32;
+    let power_of_2 = 2_u32.pow(exp) as usize;
+    if let Some(max) = max {
+        min(power_of_2, max)
+    } else {
+        power_of_2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conv2d_autotune_key_anchors_the_sizes() {
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+        let key = Conv2dAutotuneKey::new(&[6, 3, 200, 224].into(), &[12, 3, 3, 3].into(), &options);
+
+        assert_eq!(key.kernel_size, [3, 3]);
+        assert_eq!(key.anchored_batch, 8);
+        assert_eq!(key.anchored_in_channels, 4);
+        assert_eq!(key.anchored_out_channels, 16);
+        assert_eq!(key.anchored_height, 256);
+        assert_eq!(key.anchored_width, 256);
+    }
+}
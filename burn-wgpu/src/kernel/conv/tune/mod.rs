@@ -0,0 +1,5 @@
+mod base;
+mod key;
+
+pub use base::*;
+pub use key::*;
@@ -0,0 +1,130 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings, SourceTemplate, StaticKernelSource},
+    kernel_wgsl,
+    tensor::WgpuTensor,
+};
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(CumulativeRaw, "../template/cumulative.wgsl");
+
+pub(crate) struct CumSum;
+pub(crate) struct CumProd;
+
+impl StaticKernelSource for CumSum {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source()
+            .register("op", "+")
+            .register("initial", "0")
+    }
+}
+
+impl StaticKernelSource for CumProd {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source()
+            .register("op", "*")
+            .register("initial", "1")
+    }
+}
+
+/// Execute the cumulative sum kernel.
+pub fn cumsum<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<CumSum, E, D>(input, dim)
+}
+
+/// Execute the cumulative product kernel.
+pub fn cumprod<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    cumulative::<CumProd, E, D>(input, dim)
+}
+
+fn cumulative<K: StaticKernelSource, E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+    dim: usize,
+) -> WgpuTensor<E, D> {
+    let num_elems = input.shape.num_elements();
+    let buffer = input.client.empty(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+        buffer,
+    );
+
+    if num_elems == 0 {
+        return output;
+    }
+
+    // One invocation per line of elements along the dimension.
+    let num_lines = num_elems / input.shape.dims[dim];
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_lines, WORKGROUP_DEFAULT),
+        );
+
+    let mut info = build_info(&[&input, &output]);
+    info.push(dim as u32);
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_support_multiple_invocations() {
+        for dim in 0..3 {
+            test_same_as_reference([6, 256, 3], dim, false);
+        }
+    }
+
+    #[test]
+    fn cumprod_should_support_multiple_invocations() {
+        for dim in 0..3 {
+            test_same_as_reference([6, 256, 3], dim, true);
+        }
+    }
+
+    #[test]
+    fn cumsum_should_support_non_contiguous_inputs() {
+        let tensor = Tensor::<TestBackend, 2>::random([32, 17], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let output = tensor.transpose().cumsum(1);
+        let output_ref = tensor_ref.transpose().cumsum(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    fn test_same_as_reference(shape: [usize; 3], dim: usize, product: bool) {
+        // Values close to one keep the products in a reasonable range.
+        let tensor = Tensor::<TestBackend, 3>::random(shape, Distribution::Uniform(0.9, 1.1));
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let (output, output_ref) = match product {
+            true => (tensor.cumprod(dim), tensor_ref.cumprod(dim)),
+            false => (tensor.cumsum(dim), tensor_ref.cumsum(dim)),
+        };
+
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 2);
+    }
+}
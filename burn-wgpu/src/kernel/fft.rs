@@ -0,0 +1,137 @@
+use crate::{
+    compute::{Kernel, StaticKernel},
+    element::WgpuElement,
+    kernel::{elemwise_workgroup, into_contiguous, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(FftRadix2, "../template/fft/radix2.wgsl");
+kernel_wgsl!(FftDft, "../template/fft/dft.wgsl");
+
+/// Computes the discrete Fourier transform of complex values along a dimension, without
+/// normalizing the inverse transform.
+///
+/// Sizes that are powers of two use one pass of the Stockham algorithm per power, the other sizes
+/// use the definition of the transform.
+pub(crate) fn fft<E: WgpuElement, const D: usize>(
+    real: WgpuTensor<E, D>,
+    imag: WgpuTensor<E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let mut real = into_contiguous(real);
+    let mut imag = into_contiguous(imag);
+
+    let size = real.shape.dims[dim];
+    let num_elems = real.shape.num_elements();
+
+    if num_elems == 0 || size == 1 {
+        return (real, imag);
+    }
+
+    let stride = real.shape.dims[dim + 1..].iter().product::<usize>();
+    let num_lines = num_elems / size;
+    let info = [size, stride, num_lines, inverse as usize].map(|value| value as u32);
+
+    if !size.is_power_of_two() {
+        let kernel = StaticKernel::<
+            KernelSettings<FftDft, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+        >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+        return execute(Box::new(kernel), real, imag, &info);
+    }
+
+    let mut num_sub = 1;
+    while num_sub < size {
+        let kernel = StaticKernel::<
+            KernelSettings<FftRadix2, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+        >::new(elemwise_workgroup(num_elems / 2, WORKGROUP_DEFAULT));
+
+        let mut info = info.to_vec();
+        info.push(num_sub as u32);
+        (real, imag) = execute(Box::new(kernel), real, imag, &info);
+
+        num_sub *= 2;
+    }
+
+    (real, imag)
+}
+
+fn execute<E: WgpuElement, const D: usize>(
+    kernel: Box<dyn Kernel>,
+    real: WgpuTensor<E, D>,
+    imag: WgpuTensor<E, D>,
+    info: &[u32],
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let output_real = empty_device(real.client.clone(), real.device.clone(), real.shape.clone());
+    let output_imag = empty_device(real.client.clone(), real.device.clone(), real.shape.clone());
+    let info_handle = real.client.create(bytemuck::cast_slice(info));
+
+    real.client.execute(
+        kernel,
+        &[
+            &real.handle,
+            &imag.handle,
+            &output_real.handle,
+            &output_imag.handle,
+            &info_handle,
+        ],
+    );
+
+    (output_real, output_imag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn fft_should_work_with_power_of_two_sizes() {
+        for dim in 0..3 {
+            test_same_as_reference([4, 64, 8], dim);
+        }
+    }
+
+    #[test]
+    fn fft_should_work_with_other_sizes() {
+        for dim in 0..3 {
+            test_same_as_reference([3, 17, 6], dim);
+        }
+    }
+
+    #[test]
+    fn rfft_should_be_inverted_by_irfft() {
+        let tensor = Tensor::<TestBackend, 2>::random([8, 30], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data());
+
+        let (real, imag) = burn_tensor::fft::rfft(tensor, 1);
+        let (real_ref, imag_ref) = burn_tensor::fft::rfft(tensor_ref.clone(), 1);
+        let output = burn_tensor::fft::irfft(real.clone(), imag.clone(), 1, 30);
+
+        real.into_data().assert_approx_eq(&real_ref.into_data(), 3);
+        imag.into_data().assert_approx_eq(&imag_ref.into_data(), 3);
+        output
+            .into_data()
+            .assert_approx_eq(&tensor_ref.into_data(), 3);
+    }
+
+    fn test_same_as_reference(shape: [usize; 3], dim: usize) {
+        let real = Tensor::<TestBackend, 3>::random(shape, Distribution::Default);
+        let imag = Tensor::<TestBackend, 3>::random(shape, Distribution::Default);
+        let real_ref = Tensor::<ReferenceBackend, 3>::from_data(real.to_data());
+        let imag_ref = Tensor::<ReferenceBackend, 3>::from_data(imag.to_data());
+
+        let (output_real, output_imag) = burn_tensor::fft::fft(real, imag, dim);
+        let (output_real_ref, output_imag_ref) = burn_tensor::fft::fft(real_ref, imag_ref, dim);
+
+        output_real
+            .into_data()
+            .assert_approx_eq(&output_real_ref.into_data(), 2);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&output_imag_ref.into_data(), 2);
+    }
+}
@@ -0,0 +1,375 @@
+use burn_tensor::Shape;
+
+use crate::{
+    compute::{StaticKernel, WgpuHandle},
+    element::WgpuElement,
+    kernel::{
+        elemwise_workgroup, into_contiguous, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT,
+    },
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+
+kernel_wgsl!(GaussJordan, "../template/linalg/gauss_jordan.wgsl");
+kernel_wgsl!(TriangularSolve, "../template/linalg/triangular_solve.wgsl");
+kernel_wgsl!(Cholesky, "../template/linalg/cholesky.wgsl");
+kernel_wgsl!(Qr, "../template/linalg/qr.wgsl");
+kernel_wgsl!(Eigh, "../template/linalg/eigh.wgsl");
+kernel_wgsl!(Svd, "../template/linalg/svd.wgsl");
+
+/// Number of sweeps over every pair of columns done by the Jacobi methods, the same as the
+/// default implementations.
+const JACOBI_SWEEPS: usize = 10;
+
+/// Solves `lhs @ x = rhs` with a Gauss-Jordan elimination using partial pivoting, one invocation
+/// per matrix. Without right hand side, the identity is used to compute the inverse.
+///
+/// Returns the solution and the determinant of `lhs`, with shape `[..., 1, 1]`.
+pub(crate) fn gauss_jordan<E: WgpuElement, const D: usize>(
+    lhs: WgpuTensor<E, D>,
+    rhs: Option<WgpuTensor<E, D>>,
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let size = lhs.shape.dims[D - 1];
+    let num_matrices = num_matrices(&lhs.shape);
+    let lhs = into_mutable(lhs);
+
+    let (rhs, identity) = match rhs {
+        Some(rhs) => (into_mutable(rhs), false),
+        None => (
+            empty_device(lhs.client.clone(), lhs.device.clone(), lhs.shape.clone()),
+            true,
+        ),
+    };
+    let num_columns = rhs.shape.dims[D - 1];
+    let det = empty_device(
+        lhs.client.clone(),
+        lhs.device.clone(),
+        scalar_shape(&lhs.shape),
+    );
+
+    let info = [size, num_columns, num_matrices, identity as usize];
+    execute::<GaussJordan, E, D>(
+        &lhs,
+        num_matrices,
+        &[&lhs.handle, &rhs.handle, &det.handle],
+        &info,
+    );
+
+    (rhs, det)
+}
+
+/// Solves `lhs @ x = rhs` where `lhs` is triangular by substitution, one invocation per column of
+/// the right hand side.
+pub(crate) fn triangular_solve<E: WgpuElement, const D: usize>(
+    lhs: WgpuTensor<E, D>,
+    rhs: WgpuTensor<E, D>,
+    upper: bool,
+    unit_diagonal: bool,
+) -> WgpuTensor<E, D> {
+    let size = lhs.shape.dims[D - 1];
+    let num_columns = rhs.shape.dims[D - 1];
+    let num_matrices = num_matrices(&lhs.shape);
+    let lhs = into_contiguous(lhs);
+    let rhs = into_mutable(rhs);
+
+    let info = [
+        size,
+        num_columns,
+        num_matrices,
+        upper as usize,
+        unit_diagonal as usize,
+    ];
+    execute::<TriangularSolve, E, D>(
+        &lhs,
+        num_matrices * num_columns,
+        &[&lhs.handle, &rhs.handle],
+        &info,
+    );
+
+    rhs
+}
+
+/// Computes the Cholesky decomposition, one invocation per matrix.
+pub(crate) fn cholesky<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+) -> WgpuTensor<E, D> {
+    let size = tensor.shape.dims[D - 1];
+    let num_matrices = num_matrices(&tensor.shape);
+    let tensor = into_contiguous(tensor);
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    execute::<Cholesky, E, D>(
+        &tensor,
+        num_matrices,
+        &[&tensor.handle, &output.handle],
+        &[size, num_matrices],
+    );
+
+    output
+}
+
+/// Computes the reduced QR decomposition with a Gram-Schmidt orthogonalization, one invocation
+/// per matrix.
+pub(crate) fn qr<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let num_rows = tensor.shape.dims[D - 2];
+    let num_columns = tensor.shape.dims[D - 1];
+    let size = usize::min(num_rows, num_columns);
+    let num_matrices = num_matrices(&tensor.shape);
+    let tensor = into_contiguous(tensor);
+
+    let mut shape_q = tensor.shape.clone();
+    shape_q.dims[D - 1] = size;
+    let mut shape_r = tensor.shape.clone();
+    shape_r.dims[D - 2] = size;
+
+    let q = empty_device(tensor.client.clone(), tensor.device.clone(), shape_q);
+    let r = empty_device(tensor.client.clone(), tensor.device.clone(), shape_r);
+
+    execute::<Qr, E, D>(
+        &tensor,
+        num_matrices,
+        &[&tensor.handle, &q.handle, &r.handle],
+        &[num_rows, num_columns, num_matrices],
+    );
+
+    (q, r)
+}
+
+/// Computes the eigendecomposition of symmetric matrices with the cyclic Jacobi method, one
+/// invocation per matrix.
+///
+/// Returns the eigenvalues in ascending order with shape `[..., 1, n]` and the matching
+/// eigenvectors as columns.
+pub(crate) fn eigh<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let size = tensor.shape.dims[D - 1];
+    let num_matrices = num_matrices(&tensor.shape);
+    let matrices = into_mutable(tensor);
+
+    let mut shape_values = matrices.shape.clone();
+    shape_values.dims[D - 2] = 1;
+
+    let values = empty_device(
+        matrices.client.clone(),
+        matrices.device.clone(),
+        shape_values,
+    );
+    let vectors = empty_device(
+        matrices.client.clone(),
+        matrices.device.clone(),
+        matrices.shape.clone(),
+    );
+
+    execute::<Eigh, E, D>(
+        &matrices,
+        num_matrices,
+        &[&matrices.handle, &values.handle, &vectors.handle],
+        &[size, num_matrices, JACOBI_SWEEPS],
+    );
+
+    (values, vectors)
+}
+
+/// Computes the reduced singular value decomposition of matrices having at least as many rows as
+/// columns with the one-sided Jacobi method, one invocation per matrix.
+///
+/// Returns `U`, the singular values in descending order with shape `[..., 1, n]` and `Vh`.
+pub(crate) fn svd<E: WgpuElement, const D: usize>(
+    tensor: WgpuTensor<E, D>,
+) -> (WgpuTensor<E, D>, WgpuTensor<E, D>, WgpuTensor<E, D>) {
+    let num_rows = tensor.shape.dims[D - 2];
+    let size = tensor.shape.dims[D - 1];
+    let num_matrices = num_matrices(&tensor.shape);
+    let columns = into_mutable(tensor);
+
+    let mut shape_values = columns.shape.clone();
+    shape_values.dims[D - 2] = 1;
+    let mut shape_vectors = columns.shape.clone();
+    shape_vectors.dims[D - 2] = size;
+
+    let values = empty_device(columns.client.clone(), columns.device.clone(), shape_values);
+    let vectors = empty_device(
+        columns.client.clone(),
+        columns.device.clone(),
+        shape_vectors,
+    );
+
+    execute::<Svd, E, D>(
+        &columns,
+        num_matrices,
+        &[&columns.handle, &values.handle, &vectors.handle],
+        &[num_rows, size, num_matrices, JACOBI_SWEEPS],
+    );
+
+    (columns, values, vectors)
+}
+
+/// Executes the kernel, which reads its settings from the info buffer.
+fn execute<K: StaticKernelSource, E: WgpuElement, const D: usize>(
+    tensor: &WgpuTensor<E, D>,
+    num_invocations: usize,
+    handles: &[&WgpuHandle],
+    info: &[usize],
+) {
+    if num_invocations == 0 || tensor.shape.num_elements() == 0 {
+        return;
+    }
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_invocations, WORKGROUP_DEFAULT),
+        );
+    let info = info.iter().map(|value| *value as u32).collect::<Vec<_>>();
+    let info_handle = tensor.client.create(bytemuck::cast_slice(&info));
+
+    let mut handles = handles.to_vec();
+    handles.push(&info_handle);
+
+    tensor.client.execute(Box::new(kernel), &handles);
+}
+
+/// Makes the tensor contiguous and safe to modify in place.
+fn into_mutable<E: WgpuElement, const D: usize>(tensor: WgpuTensor<E, D>) -> WgpuTensor<E, D> {
+    let tensor = into_contiguous(tensor);
+
+    match tensor.can_mut() {
+        true => tensor,
+        false => tensor.copy(),
+    }
+}
+
+/// The number of matrices in the batch dimensions.
+fn num_matrices<const D: usize>(shape: &Shape<D>) -> usize {
+    shape.dims[..D - 2].iter().product()
+}
+
+/// The shape `[..., 1, 1]` holding one value per matrix.
+fn scalar_shape<const D: usize>(shape: &Shape<D>) -> Shape<D> {
+    let mut shape = shape.clone();
+    shape.dims[D - 2] = 1;
+    shape.dims[D - 1] = 1;
+
+    shape
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{backend::Backend, linalg, Distribution, Tensor};
+
+    #[test]
+    fn inverse_solve_and_det_should_match_reference() {
+        let (lhs, lhs_ref) = positive_definite([3, 5, 5]);
+        let rhs = Tensor::<TestBackend, 3>::random([3, 5, 2], Distribution::Default);
+        let rhs_ref = Tensor::<ReferenceBackend, 3>::from_data(rhs.to_data());
+
+        assert_approx_eq(
+            linalg::inverse(lhs.clone()),
+            linalg::inverse(lhs_ref.clone()),
+        );
+        assert_approx_eq(
+            linalg::solve(lhs.clone(), rhs),
+            linalg::solve(lhs_ref.clone(), rhs_ref),
+        );
+        assert_approx_eq(linalg::det(lhs), linalg::det(lhs_ref));
+    }
+
+    #[test]
+    fn triangular_solve_should_match_reference() {
+        let (lhs, lhs_ref) = positive_definite([2, 6, 6]);
+        let rhs = Tensor::<TestBackend, 3>::random([2, 6, 3], Distribution::Default);
+        let rhs_ref = Tensor::<ReferenceBackend, 3>::from_data(rhs.to_data());
+
+        for upper in [false, true] {
+            assert_approx_eq(
+                linalg::triangular_solve(lhs.clone(), rhs.clone(), upper, false),
+                linalg::triangular_solve(lhs_ref.clone(), rhs_ref.clone(), upper, false),
+            );
+        }
+    }
+
+    #[test]
+    fn cholesky_should_match_reference() {
+        let (tensor, tensor_ref) = positive_definite([3, 6, 6]);
+
+        assert_approx_eq(linalg::cholesky(tensor), linalg::cholesky(tensor_ref));
+    }
+
+    #[test]
+    fn qr_should_match_reference() {
+        let tensor = Tensor::<TestBackend, 3>::random([2, 7, 4], Distribution::Default);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        let (q, r) = linalg::qr(tensor);
+        let (q_ref, r_ref) = linalg::qr(tensor_ref);
+
+        assert_approx_eq(q, q_ref);
+        assert_approx_eq(r, r_ref);
+    }
+
+    #[test]
+    fn eigh_should_match_reference() {
+        let (tensor, tensor_ref) = positive_definite([2, 5, 5]);
+
+        let (values, vectors) = linalg::eigh(tensor.clone());
+        let (values_ref, _) = linalg::eigh(tensor_ref);
+
+        // The eigenvectors are only defined up to their sign, so the reconstruction is compared.
+        let reconstruction = (vectors.clone() * values.clone()).matmul(vectors.transpose());
+
+        assert_approx_eq(values, values_ref);
+        reconstruction
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 2);
+    }
+
+    #[test]
+    fn svd_should_match_reference() {
+        for shape in [[2, 6, 4], [2, 3, 5]] {
+            let tensor = Tensor::<TestBackend, 3>::random(shape, Distribution::Default);
+            let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+            let (u, s, vh) = linalg::svd(tensor.clone());
+            let (_, s_ref, _) = linalg::svd(tensor_ref);
+
+            // The singular vectors are only defined up to their sign, so the reconstruction is
+            // compared.
+            assert_approx_eq(s.clone(), s_ref);
+            (u * s)
+                .matmul(vh)
+                .into_data()
+                .assert_approx_eq(&tensor.into_data(), 2);
+        }
+    }
+
+    /// Random symmetric positive-definite matrices, which are well conditioned.
+    fn positive_definite(
+        shape: [usize; 3],
+    ) -> (Tensor<TestBackend, 3>, Tensor<ReferenceBackend, 3>) {
+        let tensor = Tensor::<TestBackend, 3>::random(shape, Distribution::Default);
+        let identity = Tensor::<TestBackend, 2>::diagonal(shape[2]).unsqueeze::<3>();
+        let tensor =
+            tensor.clone().matmul(tensor.transpose()) + identity.mul_scalar(shape[2] as f32);
+        let tensor_ref = Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data());
+
+        (tensor, tensor_ref)
+    }
+
+    fn assert_approx_eq<B1: Backend, B2: Backend>(
+        tensor: Tensor<B1, 3>,
+        tensor_ref: Tensor<B2, 3>,
+    ) {
+        tensor
+            .into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&tensor_ref.into_data().convert(), 2);
+    }
+}
@@ -1,10 +1,14 @@
+mod attention;
 mod base;
 mod binary_elemwise;
 mod cast;
 mod cat;
 mod clamp;
 mod comparison;
+mod cumulative;
+mod fft;
 mod index;
+mod linalg;
 mod mask;
 mod source;
 mod unary;
@@ -28,8 +32,12 @@ pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
 
+pub(crate) use attention::*;
 pub(crate) use cat::*;
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use cumulative::*;
+pub(crate) use fft::*;
 pub(crate) use index::*;
+pub(crate) use linalg::*;
 pub(crate) use mask::*;
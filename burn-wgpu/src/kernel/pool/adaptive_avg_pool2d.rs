@@ -1,7 +1,9 @@
 use crate::{
-    compute::{StaticKernel, WgpuHandle},
+    compute::StaticKernel,
     element::WgpuElement,
-    kernel::{elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel::{
+        elemwise_workgroup, pool::build_adaptive_pool2d_info, KernelSettings, WORKGROUP_DEFAULT,
+    },
     kernel_wgsl,
     ops::numeric::empty_device,
     tensor::WgpuTensor,
@@ -33,7 +35,7 @@ pub(crate) fn adaptive_avg_pool2d<E: WgpuElement>(
         WORKGROUP_DEFAULT,
     ));
 
-    let info_handle = build_info(&x, &output);
+    let info_handle = build_adaptive_pool2d_info(&x, &output);
     x.client
         .execute(Box::new(kernel), &[&x.handle, &output.handle, &info_handle]);
 
@@ -61,7 +63,7 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: WgpuElement>(
         WORKGROUP_DEFAULT,
     ));
 
-    let info_handle = build_info(&x, &out_grad);
+    let info_handle = build_adaptive_pool2d_info(&x, &out_grad);
 
     x.client.execute(
         Box::new(kernel),
@@ -70,26 +72,3 @@ pub(crate) fn adaptive_avg_pool2d_backward<E: WgpuElement>(
 
     output
 }
-
-fn build_info<E: WgpuElement>(x: &WgpuTensor<E, 4>, output: &WgpuTensor<E, 4>) -> WgpuHandle {
-    let mut info: [u32; 16] = [0; 16];
-    info[0] = x.strides[0] as u32;
-    info[1] = x.strides[1] as u32;
-    info[2] = x.strides[2] as u32;
-    info[3] = x.strides[3] as u32;
-    info[4] = x.shape.dims[0] as u32;
-    info[5] = x.shape.dims[1] as u32;
-    info[6] = x.shape.dims[2] as u32;
-    info[7] = x.shape.dims[3] as u32;
-
-    info[8] = output.strides[0] as u32;
-    info[9] = output.strides[1] as u32;
-    info[10] = output.strides[2] as u32;
-    info[11] = output.strides[3] as u32;
-    info[12] = output.shape.dims[0] as u32;
-    info[13] = output.shape.dims[1] as u32;
-    info[14] = output.shape.dims[2] as u32;
-    info[15] = output.shape.dims[3] as u32;
-
-    output.client.create(bytemuck::cast_slice(&info))
-}
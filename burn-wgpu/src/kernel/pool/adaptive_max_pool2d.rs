@@ -0,0 +1,82 @@
+use crate::{
+    compute::StaticKernel,
+    element::WgpuElement,
+    kernel::{
+        self, elemwise_workgroup, pool::build_adaptive_pool2d_info, KernelSettings,
+        WORKGROUP_DEFAULT,
+    },
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::WgpuTensor,
+};
+use burn_tensor::Shape;
+
+kernel_wgsl!(
+    AdaptiveMaxPool2dWithIndices,
+    "../../template/pool/adaptive_max_pool2d_with_indices.wgsl"
+);
+kernel_wgsl!(
+    AdaptiveMaxPool2dWithIndicesBackward,
+    "../../template/pool/adaptive_max_pool2d_with_indices_backward.wgsl"
+);
+
+pub(crate) fn adaptive_max_pool2d_with_indices<E: WgpuElement, I: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    output_size: [usize; 2],
+) -> (WgpuTensor<E, 4>, WgpuTensor<I, 4>) {
+    let [batch_size, channels, _, _] = x.shape.dims;
+
+    let output_shape = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = empty_device(x.client.clone(), x.device.clone(), output_shape.clone());
+    let indices = empty_device(x.client.clone(), x.device.clone(), output_shape);
+
+    let kernel = StaticKernel::<
+        KernelSettings<AdaptiveMaxPool2dWithIndices, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(
+        output.shape.num_elements(),
+        WORKGROUP_DEFAULT,
+    ));
+
+    let info_handle = build_adaptive_pool2d_info(&x, &output);
+    x.client.execute(
+        Box::new(kernel),
+        &[&x.handle, &output.handle, &indices.handle, &info_handle],
+    );
+
+    (output, indices)
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices_backward<E: WgpuElement, I: WgpuElement>(
+    x: WgpuTensor<E, 4>,
+    grad: WgpuTensor<E, 4>,
+    indices: WgpuTensor<I, 4>,
+) -> WgpuTensor<E, 4> {
+    let grad = kernel::into_contiguous(grad);
+    let indices = kernel::into_contiguous(indices);
+
+    let num_elems = x.shape.num_elements();
+    let buffer = x.client.empty(num_elems * core::mem::size_of::<E>());
+    let output = WgpuTensor::new(x.client.clone(), x.device.clone(), x.shape.clone(), buffer);
+
+    let kernel = StaticKernel::<
+        KernelSettings<
+            AdaptiveMaxPool2dWithIndicesBackward,
+            E,
+            I,
+            WORKGROUP_DEFAULT,
+            WORKGROUP_DEFAULT,
+            1,
+        >,
+    >::new(elemwise_workgroup(
+        output.shape.num_elements(),
+        WORKGROUP_DEFAULT,
+    ));
+
+    let info_handle = build_adaptive_pool2d_info(&output, &grad);
+    x.client.execute(
+        Box::new(kernel),
+        &[&indices.handle, &grad.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
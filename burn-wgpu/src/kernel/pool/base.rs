@@ -71,3 +71,30 @@ pub fn build_pool2d_info<E: WgpuElement>(
 
     info_buffer
 }
+
+/// Build basic info to launch adaptive pool 2d kernels.
+pub fn build_adaptive_pool2d_info<E: WgpuElement>(
+    x: &WgpuTensor<E, 4>,
+    output: &WgpuTensor<E, 4>,
+) -> WgpuHandle {
+    let mut info: [u32; 16] = [0; 16];
+    info[0] = x.strides[0] as u32;
+    info[1] = x.strides[1] as u32;
+    info[2] = x.strides[2] as u32;
+    info[3] = x.strides[3] as u32;
+    info[4] = x.shape.dims[0] as u32;
+    info[5] = x.shape.dims[1] as u32;
+    info[6] = x.shape.dims[2] as u32;
+    info[7] = x.shape.dims[3] as u32;
+
+    info[8] = output.strides[0] as u32;
+    info[9] = output.strides[1] as u32;
+    info[10] = output.strides[2] as u32;
+    info[11] = output.strides[3] as u32;
+    info[12] = output.shape.dims[0] as u32;
+    info[13] = output.shape.dims[1] as u32;
+    info[14] = output.shape.dims[2] as u32;
+    info[15] = output.shape.dims[3] as u32;
+
+    output.client.create(bytemuck::cast_slice(&info))
+}
@@ -1,9 +1,11 @@
 mod adaptive_avg_pool2d;
+mod adaptive_max_pool2d;
 mod avg_pool2d;
 mod base;
 mod max_pool2d;
 
 pub(crate) use adaptive_avg_pool2d::*;
+pub(crate) use adaptive_max_pool2d::*;
 pub use avg_pool2d::*;
 pub(super) use base::*;
 pub use max_pool2d::*;
@@ -1,32 +1,28 @@
 use crate::{
     compute::{WgpuComputeClient, WgpuHandle},
     element::WgpuElement,
-    kernel_wgsl, SEED,
+    kernel_wgsl, WgpuDevice, SEED,
 };
-use burn_common::rand::get_seeded_rng;
 use rand::Rng;
 
 kernel_wgsl!(Prng, "../../template/prng/prng.wgsl");
 
-pub(crate) fn get_seeds() -> Vec<u32> {
+pub(crate) fn get_seeds(device: &WgpuDevice) -> Vec<u32> {
     let mut seed = SEED.lock().unwrap();
-    let mut rng = match seed.as_ref() {
-        Some(rng_seeded) => rng_seeded.clone(),
-        None => get_seeded_rng(),
-    };
+    let rng = seed.get(device);
     let mut seeds: Vec<u32> = Vec::with_capacity(4);
     for _ in 0..4 {
         seeds.push(rng.gen());
     }
-    *seed = Some(rng);
     seeds
 }
 
 pub(crate) fn make_info_buffer(
     client: WgpuComputeClient,
+    device: &WgpuDevice,
     n_values_per_thread: usize,
 ) -> WgpuHandle {
-    let mut info = get_seeds();
+    let mut info = get_seeds(device);
     info.insert(0, n_values_per_thread as u32);
     client.create(bytemuck::cast_slice(&info))
 }
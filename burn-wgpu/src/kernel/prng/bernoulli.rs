@@ -37,7 +37,7 @@ pub fn random_bernoulli<G: GraphicsApi, E: WgpuElement, const D: usize>(
 
     let client = compute_client::<G>(device);
     let output = empty_device(client.clone(), device.clone(), shape.clone());
-    let info_handle = make_info_buffer(client.clone(), N_VALUES_PER_THREAD);
+    let info_handle = make_info_buffer(client.clone(), device, N_VALUES_PER_THREAD);
     let args_handle = make_args_buffer(client.clone(), &[prob]);
     let workgroup = prng_workgroup(shape.num_elements(), WORKGROUP_DEFAULT, N_VALUES_PER_THREAD);
     let kernel = StaticKernel::<
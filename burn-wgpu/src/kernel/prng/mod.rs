@@ -3,6 +3,8 @@ mod bernoulli;
 mod normal;
 mod uniform;
 
+pub(crate) use base::get_seeds;
+
 pub use bernoulli::*;
 pub use normal::*;
 pub use uniform::*;
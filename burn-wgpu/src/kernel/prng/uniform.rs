@@ -62,7 +62,7 @@ fn uniform_kernel<E: WgpuElement, const D: usize>(
     const N_VALUES_PER_THREAD: usize = 128;
 
     let output = empty_device(client.clone(), device.clone(), shape.clone());
-    let info_handle = make_info_buffer(client.clone(), N_VALUES_PER_THREAD);
+    let info_handle = make_info_buffer(client.clone(), device, N_VALUES_PER_THREAD);
     let args_handle = make_args_buffer(client.clone(), &[low, high]);
     let workgroup = prng_workgroup(shape.num_elements(), WORKGROUP_DEFAULT, N_VALUES_PER_THREAD);
     let kernel = StaticKernel::<
@@ -1,14 +1,15 @@
 use crate::{
-    compute::StaticKernel,
+    compute::{Kernel, StaticKernel},
     element::WgpuElement,
     kernel::{
-        build_info, elemwise_workgroup, KernelSettings, SourceTemplate, StaticKernelSource,
+        build_info, cast, elemwise_workgroup, KernelSettings, SourceTemplate, StaticKernelSource,
         WORKGROUP_DEFAULT,
     },
     kernel_wgsl,
+    ops::numeric::div_scalar,
     tensor::WgpuTensor,
 };
-use burn_tensor::Shape;
+use burn_tensor::{ElementConversion, Shape};
 
 kernel_wgsl!(
     RecursiveSumRaw,
@@ -24,7 +25,13 @@ pub(crate) struct MeanDim;
 
 impl StaticKernelSource for SumDim {
     fn source() -> SourceTemplate {
-        ReductionDimRaw::source().register("assign", "output[id] = sum;")
+        ReductionDimRaw::source()
+            .add_template(
+                "fn sum_dim(sum: {{ acc }}) -> {{ elem }} { 
+    return {{ elem }}(sum);
+}",
+            )
+            .register("assign", "output[id] = sum_dim(sum);")
     }
 }
 
@@ -32,8 +39,8 @@ impl StaticKernelSource for MeanDim {
     fn source() -> SourceTemplate {
         ReductionDimRaw::source()
             .add_template(
-                "fn mean_dim(sum: {{ elem }}, dim: u32) -> {{ elem }} { 
-    return sum / {{ elem }}(dim);
+                "fn mean_dim(sum: {{ acc }}, dim: u32) -> {{ elem }} { 
+    return {{ elem }}(sum / {{ acc }}(dim));
 }",
             )
             .register("assign", "output[id] = mean_dim(sum, shape_dim);")
@@ -57,23 +64,56 @@ impl StaticKernelSource for ArgsMin {
 }
 
 /// Sum all elements in the input buffer.
+///
+/// The values are accumulated in the [accumulation type](WgpuElement::Accumulation) of the
+/// element, e.g. f32 for f16 tensors.
 pub fn sum<E: WgpuElement, const D: usize>(input: WgpuTensor<E, D>) -> WgpuTensor<E, 1> {
+    cast(sum_accumulation(input))
+}
+
+/// Compute the mean of all elements in the input buffer.
+///
+/// The values are accumulated and divided in the
+/// [accumulation type](WgpuElement::Accumulation) of the element, so the sum of f16 tensors can
+/// exceed the f16 range.
+pub fn mean<E: WgpuElement, const D: usize>(input: WgpuTensor<E, D>) -> WgpuTensor<E, 1> {
+    let num_elems = input.shape.num_elements();
+    let sum = sum_accumulation(input);
+
+    cast(div_scalar(sum, (num_elems as i64).elem()))
+}
+
+fn sum_accumulation<E: WgpuElement, const D: usize>(
+    input: WgpuTensor<E, D>,
+) -> WgpuTensor<E::Accumulation, 1> {
     let mut input_handle = input.handle;
     let mut workgroup = elemwise_workgroup(input.shape.num_elements(), WORKGROUP_DEFAULT);
+    let mut first_pass = true;
 
     loop {
         let num_invocations = workgroup.num_invocations();
         let handle = input
             .client
-            .empty(core::mem::size_of::<E>() * num_invocations);
-
-        let kernel = StaticKernel::<
-            KernelSettings<RecursiveSumRaw, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
-        >::new(workgroup);
-
-        input
-            .client
-            .execute(Box::new(kernel), &[&input_handle, &handle]);
+            .empty(core::mem::size_of::<E::Accumulation>() * num_invocations);
+
+        // The first pass reads the elements, the next ones the sums already accumulated.
+        let kernel: Box<dyn Kernel> = match first_pass {
+            true => Box::new(StaticKernel::<
+                KernelSettings<RecursiveSumRaw, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+            >::new(workgroup)),
+            false => Box::new(StaticKernel::<
+                KernelSettings<
+                    RecursiveSumRaw,
+                    E::Accumulation,
+                    i32,
+                    WORKGROUP_DEFAULT,
+                    WORKGROUP_DEFAULT,
+                    1,
+                >,
+            >::new(workgroup)),
+        };
+
+        input.client.execute(kernel, &[&input_handle, &handle]);
 
         if num_invocations <= 1 {
             return WgpuTensor::new(input.client, input.device, Shape::new([1]), handle);
@@ -81,6 +121,7 @@ pub fn sum<E: WgpuElement, const D: usize>(input: WgpuTensor<E, D>) -> WgpuTenso
 
         input_handle = handle;
         workgroup = elemwise_workgroup(num_invocations, WORKGROUP_DEFAULT);
+        first_pass = false;
     }
 }
 
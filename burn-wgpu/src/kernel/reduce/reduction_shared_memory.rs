@@ -23,7 +23,12 @@ impl StaticKernelSource for SumDimSharedMemory {
             )
             .register("initial", 0.0.to_string())
             .register("update", "shared_memory[local_id] += value; ")
-            .register("assign", "output[output_position] = final_value; ")
+            .add_template(
+                "fn sum_dim(sum: {{ acc }}) -> {{ elem }} { 
+                    return {{ elem }}(sum);
+                }",
+            )
+            .register("assign", "output[output_position] = sum_dim(final_value); ")
     }
 }
 
@@ -37,8 +42,8 @@ impl StaticKernelSource for MeanDimSharedMemory {
             .register("initial", 0.0.to_string())
             .register("update", "shared_memory[local_id] += value; ")
             .add_template(
-                "fn mean_dim(sum: {{ elem }}, dim: u32) -> {{ elem }} { 
-                    return sum / {{ elem }}(dim);
+                "fn mean_dim(sum: {{ acc }}, dim: u32) -> {{ elem }} { 
+                    return {{ elem }}(sum / {{ acc }}(dim));
                 }",
             )
             .register(
@@ -0,0 +1,101 @@
+use burn_tensor::ops::{FftOps, FloatTensor, IntTensorOps, TensorOps};
+use burn_tensor::{Data, ElementConversion, Shape};
+
+use crate::{
+    element::{FloatElement, IntElement},
+    kernel, GraphicsApi, Wgpu,
+};
+
+impl<G, F, I> FftOps<Wgpu<G, F, I>> for Wgpu<G, F, I>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let size = real.shape.dims[dim];
+        let (real, imag) = kernel::fft(real, imag, dim, inverse);
+
+        match inverse && size > 1 {
+            true => {
+                let scale = (1.0 / size as f64).elem();
+                (Self::mul_scalar(real, scale), Self::mul_scalar(imag, scale))
+            }
+            false => (real, imag),
+        }
+    }
+
+    fn rfft<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let imag = Self::zeros(tensor.shape.clone(), &tensor.device);
+        let mut ranges = tensor.shape.dims.map(|size| 0..size);
+        ranges[dim] = 0..tensor.shape.dims[dim] / 2 + 1;
+
+        let (real, imag) = kernel::fft(tensor, imag, dim, false);
+
+        (Self::slice(real, ranges.clone()), Self::slice(imag, ranges))
+    }
+
+    fn irfft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        size: usize,
+    ) -> FloatTensor<Self, D> {
+        let num_frequencies = usize::min(real.shape.dims[dim], size / 2 + 1);
+        let mut shape = real.shape.clone();
+        shape.dims[dim] = size;
+
+        let mut ranges = real.shape.dims.map(|size| 0..size);
+        ranges[dim] = 0..num_frequencies;
+        let real = Self::slice(real, ranges.clone());
+        let imag = Self::slice(imag, ranges.clone());
+
+        let output_real = Self::zeros(shape.clone(), &real.device);
+        let output_imag = Self::zeros(shape, &real.device);
+        let mut output_real = Self::slice_assign(output_real, ranges.clone(), real.clone());
+        let mut output_imag = Self::slice_assign(output_imag, ranges.clone(), imag.clone());
+
+        // Rebuild the negative frequencies, which are the conjugates of the positive ones in
+        // reverse order, the missing frequencies being zeros.
+        let num_negatives = usize::min(
+            num_frequencies.saturating_sub(1),
+            size.saturating_sub(num_frequencies),
+        );
+        if num_negatives > 0 {
+            let indices = (1..=num_negatives)
+                .rev()
+                .map(|k| (k as i64).elem())
+                .collect::<Vec<I>>();
+            let indices = Self::int_from_data(
+                Data::new(indices, Shape::new([num_negatives])),
+                &real.device,
+            );
+
+            ranges[dim] = size - num_negatives..size;
+            output_real = Self::slice_assign(
+                output_real,
+                ranges.clone(),
+                Self::select(real, dim, indices.clone()),
+            );
+            output_imag = Self::slice_assign(
+                output_imag,
+                ranges,
+                Self::neg(Self::select(imag, dim, indices)),
+            );
+        }
+
+        // Only the real part is kept, so the imaginary parts of the first and middle frequencies
+        // are ignored.
+        let (output, _) = Self::fft(output_real, output_imag, dim, true);
+
+        output
+    }
+}
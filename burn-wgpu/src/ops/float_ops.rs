@@ -1,12 +1,9 @@
 use super::numeric;
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::matmul::init_matmul_output;
 #[cfg(feature = "autotune")]
 use crate::kernel::matmul::matmul_autotune;
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::matmul::vec4::matmul_tiling_2d_vec4;
 use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
-#[cfg(not(feature = "autotune"))]
 use crate::kernel::reduce::init_reduce_output;
 use crate::kernel::{
     self, reduce, unary_default, unary_inplace_default, unary_scalar_default,
@@ -154,15 +151,12 @@ where
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
         #[cfg(feature = "autotune")]
-        {
-            matmul_autotune(lhs, rhs)
+        if !crate::is_deterministic() {
+            return matmul_autotune(lhs, rhs);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let out = init_matmul_output(&lhs, &rhs);
-            matmul_tiling_2d_vec4(lhs, rhs, out)
-        }
+        let out = init_matmul_output(&lhs, &rhs);
+        matmul_tiling_2d_vec4(lhs, rhs, out)
     }
 
     fn swap_dims<const D: usize>(
@@ -319,30 +313,36 @@ where
         reduce::sum(tensor)
     }
 
+    fn mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        reduce::mean(tensor)
+    }
+
     fn sum_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         #[cfg(feature = "autotune")]
-        {
-            reduce::sum_dim_autotune(tensor, dim)
+        if !crate::is_deterministic() {
+            return reduce::sum_dim_autotune(tensor, dim);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let output = init_reduce_output(&tensor, dim);
-            reduce::sum_dim(tensor, output, dim)
-        }
+        let output = init_reduce_output(&tensor, dim);
+        reduce::sum_dim(tensor, output, dim)
     }
 
     fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
         #[cfg(feature = "autotune")]
-        {
-            reduce::mean_dim_autotune(tensor, dim)
+        if !crate::is_deterministic() {
+            return reduce::mean_dim_autotune(tensor, dim);
         }
 
-        #[cfg(not(feature = "autotune"))]
-        {
-            let output = init_reduce_output(&tensor, dim);
-            reduce::mean_dim(tensor, output, dim)
-        }
+        let output = init_reduce_output(&tensor, dim);
+        reduce::mean_dim(tensor, output, dim)
+    }
+
+    fn cumsum<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
     }
 
     fn to_full_precision<const D: usize>(
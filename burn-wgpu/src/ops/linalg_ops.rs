@@ -0,0 +1,90 @@
+use burn_tensor::ops::{FloatTensor, LinalgOps, TensorOps};
+
+use crate::{
+    element::{FloatElement, IntElement},
+    kernel, GraphicsApi, Wgpu,
+};
+
+impl<G, F, I> LinalgOps<Wgpu<G, F, I>> for Wgpu<G, F, I>
+where
+    G: GraphicsApi + 'static,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let (solution, _det) = kernel::gauss_jordan(tensor, None);
+
+        solution
+    }
+
+    fn solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let (solution, _det) = kernel::gauss_jordan(lhs, Some(rhs));
+
+        solution
+    }
+
+    fn triangular_solve<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        upper: bool,
+        unit_diagonal: bool,
+    ) -> FloatTensor<Self, D> {
+        kernel::triangular_solve(lhs, rhs, upper, unit_diagonal)
+    }
+
+    fn det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // The determinant of an empty matrix is one.
+        if tensor.shape.dims[D - 1] == 0 {
+            let mut shape = tensor.shape.clone();
+            shape.dims[D - 2] = 1;
+            shape.dims[D - 1] = 1;
+
+            return Self::ones(shape, &tensor.device);
+        }
+
+        let mut shape = tensor.shape.clone();
+        shape.dims[D - 1] = 1;
+        let rhs = Self::zeros(shape, &tensor.device);
+
+        let (_solution, det) = kernel::gauss_jordan(tensor, Some(rhs));
+
+        det
+    }
+
+    fn cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::cholesky(tensor)
+    }
+
+    fn qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::qr(tensor)
+    }
+
+    fn eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        kernel::eigh(tensor)
+    }
+
+    fn svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        // The kernel needs at least as many rows as columns, so wide matrices are decomposed
+        // through their transpose.
+        if tensor.shape.dims[D - 2] < tensor.shape.dims[D - 1] {
+            let (u, s, vh) = kernel::svd(Self::transpose(tensor));
+
+            return (Self::transpose(vh), s, Self::transpose(u));
+        }
+
+        kernel::svd(tensor)
+    }
+}
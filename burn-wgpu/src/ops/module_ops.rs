@@ -1,13 +1,14 @@
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    AttentionBackward, AttentionDropout, AttentionForward, ConvOptions, ConvTransposeOptions,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 use crate::{
     element::{FloatElement, IntElement},
-    kernel, GraphicsApi, Wgpu,
+    kernel, GraphicsApi, Wgpu, WgpuDevice,
 };
 
-use burn_tensor::ops::{FloatTensor, IntTensor};
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor};
 
 impl<G, F, I> ModuleOps<Self> for Wgpu<G, F, I>
 where
@@ -21,6 +22,11 @@ where
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvOptions<2>,
     ) -> FloatTensor<Self, 4> {
+        #[cfg(feature = "autotune")]
+        if !crate::is_deterministic() {
+            return kernel::conv::conv2d_autotune(x, weight, bias, options);
+        }
+
         kernel::conv::conv2d(x, weight, bias, options)
     }
 
@@ -110,4 +116,68 @@ where
     ) -> FloatTensor<Self, 4> {
         kernel::pool::adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Wgpu<G, F, I>> {
+        let (output, indices) = kernel::pool::adaptive_max_pool2d_with_indices(x, output_size);
+
+        MaxPool2dWithIndices::new(output, indices)
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Wgpu<G, F, I>> {
+        MaxPool2dBackward::new(kernel::pool::adaptive_max_pool2d_with_indices_backward(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    fn attention_forward(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        mask: Option<BoolTensor<Self, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+    ) -> AttentionForward<Self> {
+        let (output, logsumexp) =
+            kernel::flash_attention(query, key, value, mask, dropout, min_float);
+
+        AttentionForward::new(output, logsumexp)
+    }
+
+    fn attention_backward(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        mask: Option<BoolTensor<Self, 3>>,
+        dropout: Option<AttentionDropout>,
+        min_float: f64,
+        forward: AttentionForward<Self>,
+        output_grad: FloatTensor<Self, 4>,
+    ) -> AttentionBackward<Self> {
+        let (query_grad, key_grad, value_grad) = kernel::flash_attention_backward(
+            query,
+            key,
+            value,
+            mask,
+            dropout,
+            min_float,
+            forward.output,
+            forward.logsumexp,
+            output_grad,
+        );
+
+        AttentionBackward::new(query_grad, key_grad, value_grad)
+    }
+
+    fn attention_dropout_seed(device: &WgpuDevice) -> u32 {
+        kernel::prng::get_seeds(device)[0]
+    }
 }
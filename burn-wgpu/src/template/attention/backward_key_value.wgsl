@@ -0,0 +1,160 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(4)
+var<storage, read> output_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(5)
+var<storage, read> statistics: array<{{ elem }}>;
+
+@group(0)
+@binding(6)
+var<storage, read_write> key_value_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(7)
+var<storage, read> info: array<u32, 10>;
+
+const BLOCK_SIZE = {{ block_size }}u;
+const D_K = {{ d_k }}u;
+const D_V = {{ d_v }}u;
+
+var<workgroup> query_block: array<{{ elem }}, {{ block_size_x_d_k }}>;
+var<workgroup> output_grad_block: array<{{ elem }}, {{ block_size_x_d_v }}>;
+var<workgroup> statistics_block: array<{{ elem }}, {{ block_size_x_2 }}>;
+
+// Each invocation computes the gradients of one key and its value, iterating over blocks of
+// queries, output gradients and statistics loaded in workgroup memory. The attention weights are
+// recomputed from the log-sum-exp of the scores, so they are never stored. The gradients of a
+// key and its value are stored next to each other.
+@compute
+@workgroup_size({{ block_size }}, 1, 1)
+fn main(
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(local_invocation_index) local_idx: u32,
+) {
+    let seq_length_1 = info[0];
+    let seq_length_2 = info[1];
+    let n_heads = info[2];
+    let has_mask = info[3];
+    let has_dropout = info[4];
+    let dropout_seed = info[5];
+    let dropout_threshold = info[6];
+    let scale = {{ elem }}(bitcast<f32>(info[7]));
+    let min_float = {{ elem }}(bitcast<f32>(info[8]));
+    let dropout_scale = {{ elem }}(bitcast<f32>(info[9]));
+
+    let batch_head = workgroup_id.y;
+    let batch = batch_head / n_heads;
+    let column = workgroup_id.x * BLOCK_SIZE + local_idx;
+    let is_valid = column < seq_length_2;
+
+    let index = batch_head * seq_length_2 + column;
+    let query_offset = batch_head * seq_length_1 * D_K;
+    let output_grad_offset = batch_head * seq_length_1 * D_V;
+    let statistics_offset = batch_head * seq_length_1 * 2u;
+    let mask_offset = batch * seq_length_1 * seq_length_2 + column;
+    let dropout_offset = batch_head * seq_length_1 * seq_length_2 + column;
+
+    var key_row: array<{{ elem }}, {{ d_k }}>;
+    var value_row: array<{{ elem }}, {{ d_v }}>;
+    var key_accumulator: array<{{ elem }}, {{ d_k }}>;
+    var value_accumulator: array<{{ elem }}, {{ d_v }}>;
+
+    if is_valid {
+        for (var i = 0u; i < D_K; i++) {
+            key_row[i] = key[index * D_K + i] * scale;
+        }
+        for (var i = 0u; i < D_V; i++) {
+            value_row[i] = value[index * D_V + i];
+        }
+    }
+
+    let num_blocks = (seq_length_1 + BLOCK_SIZE - 1u) / BLOCK_SIZE;
+
+    for (var block = 0u; block < num_blocks; block++) {
+        let block_start = block * BLOCK_SIZE;
+        let query_row = block_start + local_idx;
+
+        if query_row < seq_length_1 {
+            for (var i = 0u; i < D_K; i++) {
+                query_block[local_idx * D_K + i] = query[query_offset + query_row * D_K + i];
+            }
+            for (var i = 0u; i < D_V; i++) {
+                output_grad_block[local_idx * D_V + i] = output_grad[output_grad_offset + query_row * D_V + i];
+            }
+            statistics_block[local_idx * 2u] = statistics[statistics_offset + query_row * 2u];
+            statistics_block[local_idx * 2u + 1u] = statistics[statistics_offset + query_row * 2u + 1u];
+        }
+
+        workgroupBarrier();
+
+        if is_valid {
+            let block_length = min(BLOCK_SIZE, seq_length_1 - block_start);
+
+            for (var j = 0u; j < block_length; j++) {
+                let row = block_start + j;
+
+                var score: {{ elem }} = 0.0;
+                for (var i = 0u; i < D_K; i++) {
+                    score += query_block[j * D_K + i] * key_row[i];
+                }
+
+                if has_mask == 1u && mask[mask_offset + row * seq_length_2] != 0u {
+                    score = min_float;
+                }
+
+                let weight = exp(score - statistics_block[j * 2u]);
+
+                var weight_grad: {{ elem }} = 0.0;
+                for (var i = 0u; i < D_V; i++) {
+                    weight_grad += output_grad_block[j * D_V + i] * value_row[i];
+                }
+
+                var dropped_weight = weight;
+                if has_dropout == 1u {
+                    let is_kept = dropout_is_kept(dropout_offset + row * seq_length_2, dropout_seed, dropout_threshold);
+                    dropped_weight = select(0.0, weight * dropout_scale, is_kept);
+                    weight_grad = select(0.0, weight_grad * dropout_scale, is_kept);
+                }
+
+                for (var i = 0u; i < D_V; i++) {
+                    value_accumulator[i] += dropped_weight * output_grad_block[j * D_V + i];
+                }
+
+                let score_grad = weight * (weight_grad - statistics_block[j * 2u + 1u]);
+                for (var i = 0u; i < D_K; i++) {
+                    key_accumulator[i] += score_grad * query_block[j * D_K + i];
+                }
+            }
+        }
+
+        workgroupBarrier();
+    }
+
+    if is_valid {
+        let offset = index * (D_K + D_V);
+
+        for (var i = 0u; i < D_K; i++) {
+            key_value_grad[offset + i] = key_accumulator[i] * scale;
+        }
+        for (var i = 0u; i < D_V; i++) {
+            key_value_grad[offset + D_K + i] = value_accumulator[i];
+        }
+    }
+}
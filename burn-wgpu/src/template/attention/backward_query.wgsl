@@ -0,0 +1,145 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> mask: array<u32>;
+
+@group(0)
+@binding(4)
+var<storage, read> output_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(5)
+var<storage, read> statistics: array<{{ elem }}>;
+
+@group(0)
+@binding(6)
+var<storage, read_write> query_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(7)
+var<storage, read> info: array<u32, 10>;
+
+const BLOCK_SIZE = {{ block_size }}u;
+const D_K = {{ d_k }}u;
+const D_V = {{ d_v }}u;
+
+var<workgroup> key_block: array<{{ elem }}, {{ block_size_x_d_k }}>;
+var<workgroup> value_block: array<{{ elem }}, {{ block_size_x_d_v }}>;
+
+// Each invocation computes the gradient of one query, iterating over blocks of keys and values
+// loaded in workgroup memory like the forward pass. The attention weights are recomputed from
+// the log-sum-exp of the scores, so they are never stored.
+@compute
+@workgroup_size({{ block_size }}, 1, 1)
+fn main(
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(local_invocation_index) local_idx: u32,
+) {
+    let seq_length_1 = info[0];
+    let seq_length_2 = info[1];
+    let n_heads = info[2];
+    let has_mask = info[3];
+    let has_dropout = info[4];
+    let dropout_seed = info[5];
+    let dropout_threshold = info[6];
+    let scale = {{ elem }}(bitcast<f32>(info[7]));
+    let min_float = {{ elem }}(bitcast<f32>(info[8]));
+    let dropout_scale = {{ elem }}(bitcast<f32>(info[9]));
+
+    let batch_head = workgroup_id.y;
+    let batch = batch_head / n_heads;
+    let row = workgroup_id.x * BLOCK_SIZE + local_idx;
+    let is_valid = row < seq_length_1;
+
+    let index = batch_head * seq_length_1 + row;
+    let key_offset = batch_head * seq_length_2 * D_K;
+    let value_offset = batch_head * seq_length_2 * D_V;
+    let mask_offset = (batch * seq_length_1 + row) * seq_length_2;
+    let dropout_offset = index * seq_length_2;
+
+    var query_row: array<{{ elem }}, {{ d_k }}>;
+    var output_grad_row: array<{{ elem }}, {{ d_v }}>;
+    var accumulator: array<{{ elem }}, {{ d_k }}>;
+    var logsumexp: {{ elem }} = 0.0;
+    var output_dot: {{ elem }} = 0.0;
+
+    if is_valid {
+        for (var i = 0u; i < D_K; i++) {
+            query_row[i] = query[index * D_K + i] * scale;
+        }
+        for (var i = 0u; i < D_V; i++) {
+            output_grad_row[i] = output_grad[index * D_V + i];
+        }
+        logsumexp = statistics[index * 2u];
+        output_dot = statistics[index * 2u + 1u];
+    }
+
+    let num_blocks = (seq_length_2 + BLOCK_SIZE - 1u) / BLOCK_SIZE;
+
+    for (var block = 0u; block < num_blocks; block++) {
+        let block_start = block * BLOCK_SIZE;
+        let key_row = block_start + local_idx;
+
+        if key_row < seq_length_2 {
+            for (var i = 0u; i < D_K; i++) {
+                key_block[local_idx * D_K + i] = key[key_offset + key_row * D_K + i];
+            }
+            for (var i = 0u; i < D_V; i++) {
+                value_block[local_idx * D_V + i] = value[value_offset + key_row * D_V + i];
+            }
+        }
+
+        workgroupBarrier();
+
+        if is_valid {
+            let block_length = min(BLOCK_SIZE, seq_length_2 - block_start);
+
+            for (var j = 0u; j < block_length; j++) {
+                var score: {{ elem }} = 0.0;
+                for (var i = 0u; i < D_K; i++) {
+                    score += query_row[i] * key_block[j * D_K + i];
+                }
+
+                if has_mask == 1u && mask[mask_offset + block_start + j] != 0u {
+                    score = min_float;
+                }
+
+                let weight = exp(score - logsumexp);
+
+                var weight_grad: {{ elem }} = 0.0;
+                for (var i = 0u; i < D_V; i++) {
+                    weight_grad += output_grad_row[i] * value_block[j * D_V + i];
+                }
+
+                if has_dropout == 1u {
+                    let is_kept = dropout_is_kept(dropout_offset + block_start + j, dropout_seed, dropout_threshold);
+                    weight_grad = select(0.0, weight_grad * dropout_scale, is_kept);
+                }
+
+                let score_grad = weight * (weight_grad - output_dot);
+                for (var i = 0u; i < D_K; i++) {
+                    accumulator[i] += score_grad * key_block[j * D_K + i];
+                }
+            }
+        }
+
+        workgroupBarrier();
+    }
+
+    if is_valid {
+        for (var i = 0u; i < D_K; i++) {
+            query_grad[index * D_K + i] = accumulator[i] * scale;
+        }
+    }
+}
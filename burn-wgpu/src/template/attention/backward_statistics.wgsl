@@ -0,0 +1,51 @@
+@group(0)
+@binding(0)
+var<storage, read> output: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> output_grad: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> logsumexp: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> statistics: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32, 10>;
+
+const BLOCK_SIZE = {{ block_size }}u;
+const D_V = {{ d_v }}u;
+
+// Each invocation stores the statistics of one query used by the backward kernels: the
+// log-sum-exp of its scores and the dot product of its output and output gradient, which is the
+// sum of the attention weights multiplied by their gradient.
+@compute
+@workgroup_size({{ block_size }}, 1, 1)
+fn main(
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(local_invocation_index) local_idx: u32,
+) {
+    let seq_length_1 = info[0];
+
+    let batch_head = workgroup_id.y;
+    let row = workgroup_id.x * BLOCK_SIZE + local_idx;
+
+    if row >= seq_length_1 {
+        return;
+    }
+
+    let index = batch_head * seq_length_1 + row;
+    var output_dot: {{ elem }} = 0.0;
+
+    for (var i = 0u; i < D_V; i++) {
+        output_dot += output[index * D_V + i] * output_grad[index * D_V + i];
+    }
+
+    statistics[index * 2u] = logsumexp[index];
+    statistics[index * 2u + 1u] = output_dot;
+}
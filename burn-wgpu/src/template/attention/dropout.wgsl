@@ -0,0 +1,12 @@
+
+// Whether the attention weight of the given index is kept by the dropout, using the same hash as
+// `AttentionDropout::is_kept`.
+fn dropout_is_kept(index: u32, seed: u32, threshold: u32) -> bool {
+    var x = index ^ (seed * 0x2c1b3c6du);
+    x ^= x >> 16u;
+    x *= 0x7feb352du;
+    x ^= x >> 15u;
+    x *= 0x1b873593u;
+    x ^= x >> 16u;
+    return (x >> 8u) >= threshold;
+}
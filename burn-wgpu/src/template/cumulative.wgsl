@@ -0,0 +1,56 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation accumulates one line of elements along the dimension.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let dim: u32 = info[0];
+    let dim_cumulative = info[4u * dim + 1u];
+
+    var remaining = id;
+    var index_input = 0u;
+    var index_output = 0u;
+
+    for (var j: u32 = 0u; j < dim; j++) {
+        let i = dim - j;
+
+        if i - 1u != dim_cumulative {
+            let shape = info[i + 2u * dim];
+            let num_block = remaining % shape;
+            remaining = remaining / shape;
+
+            index_input += num_block * info[i];
+            index_output += num_block * info[i + dim];
+        }
+    }
+
+    if remaining > 0u {
+        return;
+    }
+
+    let stride_input = info[dim_cumulative + 1u];
+    let stride_output = info[dim_cumulative + 1u + dim];
+    let shape_dim = info[dim_cumulative + 1u + 2u * dim];
+    var accumulated = {{ elem }}({{ initial }});
+
+    for (var i = 0u; i < shape_dim; i++) {
+        accumulated = accumulated {{ op }} input[index_input + i * stride_input];
+        output[index_output + i * stride_output] = accumulated;
+    }
+}
@@ -0,0 +1,70 @@
+@group(0)
+@binding(0)
+var<storage, read> input_real: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> input_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output_real: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+const PI = 3.141592653589793;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation computes one frequency of a line with the definition of the transform,
+    // used when the size isn't a power of two.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let stride = info[1];
+    let num_lines = info[2];
+    let inverse = info[3];
+
+    let line = id / size;
+    let k = id % size;
+
+    if line >= num_lines {
+        return;
+    }
+
+    let offset = (line / stride) * size * stride + line % stride;
+    var sign = -2.0 * PI / {{ elem }}(size);
+    if inverse == 1u {
+        sign = -sign;
+    }
+
+    var real = {{ elem }}(0);
+    var imag = {{ elem }}(0);
+    var phase = 0u;
+
+    for (var j = 0u; j < size; j++) {
+        // The phase is `k * j` modulo the size, which keeps the angle small to preserve the
+        // precision.
+        let angle = sign * {{ elem }}(phase);
+        phase = (phase + k) % size;
+        let cosine = cos(angle);
+        let sine = sin(angle);
+        let index = offset + j * stride;
+
+        real += input_real[index] * cosine - input_imag[index] * sine;
+        imag += input_real[index] * sine + input_imag[index] * cosine;
+    }
+
+    output_real[offset + k * stride] = real;
+    output_imag[offset + k * stride] = imag;
+}
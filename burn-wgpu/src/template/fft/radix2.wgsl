@@ -0,0 +1,72 @@
+@group(0)
+@binding(0)
+var<storage, read> input_real: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> input_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output_real: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+const PI = 3.141592653589793;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation computes one butterfly of a stage of the Stockham algorithm, which merges
+    // the transforms of size `num_sub` into transforms of twice that size.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let stride = info[1];
+    let num_lines = info[2];
+    let inverse = info[3];
+    let num_sub = info[4];
+
+    let half = size / 2u;
+    let line = id / half;
+    let j = id % half;
+
+    if line >= num_lines {
+        return;
+    }
+
+    let offset = (line / stride) * size * stride + line % stride;
+    let index_0 = offset + j * stride;
+    let index_1 = offset + (j + half) * stride;
+
+    let k = j % num_sub;
+    var angle = -PI * {{ elem }}(k) / {{ elem }}(num_sub);
+    if inverse == 1u {
+        angle = -angle;
+    }
+    let cosine = cos(angle);
+    let sine = sin(angle);
+
+    let real_0 = input_real[index_0];
+    let imag_0 = input_imag[index_0];
+    let real_1 = input_real[index_1] * cosine - input_imag[index_1] * sine;
+    let imag_1 = input_real[index_1] * sine + input_imag[index_1] * cosine;
+
+    let position = (j / num_sub) * num_sub * 2u + k;
+    let output_0 = offset + position * stride;
+    let output_1 = offset + (position + num_sub) * stride;
+
+    output_real[output_0] = real_0 + real_1;
+    output_imag[output_0] = imag_0 + imag_1;
+    output_real[output_1] = real_0 - real_1;
+    output_imag[output_1] = imag_0 - imag_1;
+}
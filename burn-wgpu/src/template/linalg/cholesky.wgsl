@@ -0,0 +1,52 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation decomposes one matrix, column by column, using only its lower triangle.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let num_matrices = info[1];
+
+    if id >= num_matrices {
+        return;
+    }
+
+    let offset = id * size * size;
+
+    for (var j = 0u; j < size; j++) {
+        var diagonal = input[offset + j * size + j];
+        for (var k = 0u; k < j; k++) {
+            diagonal -= output[offset + j * size + k] * output[offset + j * size + k];
+        }
+        diagonal = sqrt(diagonal);
+        output[offset + j * size + j] = diagonal;
+
+        for (var i = 0u; i < j; i++) {
+            output[offset + i * size + j] = {{ elem }}(0);
+        }
+
+        for (var i = j + 1u; i < size; i++) {
+            var value = input[offset + i * size + j];
+            for (var k = 0u; k < j; k++) {
+                value -= output[offset + i * size + k] * output[offset + j * size + k];
+            }
+            output[offset + i * size + j] = value / diagonal;
+        }
+    }
+}
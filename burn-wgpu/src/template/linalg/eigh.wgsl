@@ -0,0 +1,114 @@
+@group(0)
+@binding(0)
+var<storage, read_write> matrices: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> vectors: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation diagonalizes one symmetric matrix in place with the cyclic Jacobi method.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let num_matrices = info[1];
+    let num_sweeps = info[2];
+
+    if id >= num_matrices {
+        return;
+    }
+
+    let offset = id * size * size;
+    let offset_values = id * size;
+
+    for (var i = 0u; i < size; i++) {
+        for (var j = 0u; j < size; j++) {
+            vectors[offset + i * size + j] = select({{ elem }}(0), {{ elem }}(1), i == j);
+        }
+    }
+
+    for (var sweep = 0u; sweep < num_sweeps; sweep++) {
+        for (var p = 0u; p < size; p++) {
+            for (var q = p + 1u; q < size; q++) {
+                let gamma = matrices[offset + p * size + q];
+                if gamma == {{ elem }}(0) {
+                    continue;
+                }
+
+                let rotation = jacobi_rotation(
+                    matrices[offset + p * size + p],
+                    matrices[offset + q * size + q],
+                    gamma,
+                );
+                let cosine = rotation.x;
+                let sine = rotation.y;
+
+                // The matrices stay symmetric, so both their columns and rows are rotated.
+                for (var i = 0u; i < size; i++) {
+                    let value_p = matrices[offset + i * size + p];
+                    let value_q = matrices[offset + i * size + q];
+                    matrices[offset + i * size + p] = value_p * cosine - value_q * sine;
+                    matrices[offset + i * size + q] = value_p * sine + value_q * cosine;
+                }
+                for (var i = 0u; i < size; i++) {
+                    let value_p = matrices[offset + p * size + i];
+                    let value_q = matrices[offset + q * size + i];
+                    matrices[offset + p * size + i] = value_p * cosine - value_q * sine;
+                    matrices[offset + q * size + i] = value_p * sine + value_q * cosine;
+                }
+                for (var i = 0u; i < size; i++) {
+                    let value_p = vectors[offset + i * size + p];
+                    let value_q = vectors[offset + i * size + q];
+                    vectors[offset + i * size + p] = value_p * cosine - value_q * sine;
+                    vectors[offset + i * size + q] = value_p * sine + value_q * cosine;
+                }
+            }
+        }
+    }
+
+    for (var i = 0u; i < size; i++) {
+        values[offset_values + i] = matrices[offset + i * size + i];
+    }
+
+    // Insertion sort in ascending order, which keeps the order of equal values.
+    for (var i = 1u; i < size; i++) {
+        var j = i;
+        while j > 0u && values[offset_values + j - 1u] > values[offset_values + j] {
+            let value = values[offset_values + j];
+            values[offset_values + j] = values[offset_values + j - 1u];
+            values[offset_values + j - 1u] = value;
+
+            for (var k = 0u; k < size; k++) {
+                let vector = vectors[offset + k * size + j];
+                vectors[offset + k * size + j] = vectors[offset + k * size + j - 1u];
+                vectors[offset + k * size + j - 1u] = vector;
+            }
+
+            j--;
+        }
+    }
+}
+
+// The cosine and the sine of the rotation that diagonalizes [[alpha, gamma], [gamma, beta]].
+fn jacobi_rotation(alpha: {{ elem }}, beta: {{ elem }}, gamma: {{ elem }}) -> vec2<{{ elem }}> {
+    let zeta = (beta - alpha) / ({{ elem }}(2) * gamma);
+    let sign = select({{ elem }}(-1), {{ elem }}(1), zeta >= {{ elem }}(0));
+    let tangent = sign / (abs(zeta) + sqrt(zeta * zeta + {{ elem }}(1)));
+    let cosine = {{ elem }}(1) / sqrt(tangent * tangent + {{ elem }}(1));
+
+    return vec2(cosine, tangent * cosine);
+}
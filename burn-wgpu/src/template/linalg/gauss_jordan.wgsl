@@ -0,0 +1,107 @@
+@group(0)
+@binding(0)
+var<storage, read_write> lhs: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> rhs: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> det: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation solves the systems of one matrix with a Gauss-Jordan elimination using
+    // partial pivoting, in place.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let num_columns = info[1];
+    let num_matrices = info[2];
+    let identity = info[3];
+
+    if id >= num_matrices {
+        return;
+    }
+
+    let offset_lhs = id * size * size;
+    let offset_rhs = id * size * num_columns;
+
+    if identity == 1u {
+        for (var i = 0u; i < size; i++) {
+            for (var j = 0u; j < num_columns; j++) {
+                rhs[offset_rhs + i * num_columns + j] = select({{ elem }}(0), {{ elem }}(1), i == j);
+            }
+        }
+    }
+
+    var determinant = {{ elem }}(1);
+
+    for (var k = 0u; k < size; k++) {
+        // Find the row with the largest absolute value in the current column.
+        var pivot = k;
+        for (var i = k + 1u; i < size; i++) {
+            if abs(lhs[offset_lhs + i * size + k]) > abs(lhs[offset_lhs + pivot * size + k]) {
+                pivot = i;
+            }
+        }
+
+        if pivot != k {
+            determinant = -determinant;
+
+            for (var j = 0u; j < size; j++) {
+                let value = lhs[offset_lhs + k * size + j];
+                lhs[offset_lhs + k * size + j] = lhs[offset_lhs + pivot * size + j];
+                lhs[offset_lhs + pivot * size + j] = value;
+            }
+            for (var j = 0u; j < num_columns; j++) {
+                let value = rhs[offset_rhs + k * num_columns + j];
+                rhs[offset_rhs + k * num_columns + j] = rhs[offset_rhs + pivot * num_columns + j];
+                rhs[offset_rhs + pivot * num_columns + j] = value;
+            }
+        }
+
+        // A zero pivot is replaced by one, which gives a zero determinant and keeps every value
+        // finite.
+        var pivot_value = lhs[offset_lhs + k * size + k];
+        determinant *= pivot_value;
+        if pivot_value == {{ elem }}(0) {
+            pivot_value = {{ elem }}(1);
+        }
+
+        // Normalize the pivot row and eliminate the current column from the other rows.
+        for (var j = 0u; j < size; j++) {
+            lhs[offset_lhs + k * size + j] /= pivot_value;
+        }
+        for (var j = 0u; j < num_columns; j++) {
+            rhs[offset_rhs + k * num_columns + j] /= pivot_value;
+        }
+
+        for (var i = 0u; i < size; i++) {
+            if i == k {
+                continue;
+            }
+
+            let factor = lhs[offset_lhs + i * size + k];
+
+            for (var j = 0u; j < size; j++) {
+                lhs[offset_lhs + i * size + j] -= factor * lhs[offset_lhs + k * size + j];
+            }
+            for (var j = 0u; j < num_columns; j++) {
+                rhs[offset_rhs + i * num_columns + j] -= factor * rhs[offset_rhs + k * num_columns + j];
+            }
+        }
+    }
+
+    det[id] = determinant;
+}
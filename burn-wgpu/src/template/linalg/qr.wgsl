@@ -0,0 +1,83 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> q: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> r: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation decomposes one matrix with a Gram-Schmidt orthogonalization.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_rows = info[0];
+    let num_columns = info[1];
+    let num_matrices = info[2];
+    let size = min(num_rows, num_columns);
+
+    if id >= num_matrices {
+        return;
+    }
+
+    let offset_input = id * num_rows * num_columns;
+    let offset_q = id * num_rows * size;
+    let offset_r = id * size * num_columns;
+
+    for (var j = 0u; j < size; j++) {
+        for (var i = 0u; i < num_rows; i++) {
+            q[offset_q + i * size + j] = input[offset_input + i * num_columns + j];
+        }
+
+        // Applied twice to keep the columns orthogonal.
+        for (var iteration = 0u; iteration < 2u; iteration++) {
+            for (var p = 0u; p < j; p++) {
+                var dot = {{ elem }}(0);
+                for (var i = 0u; i < num_rows; i++) {
+                    dot += q[offset_q + i * size + p] * q[offset_q + i * size + j];
+                }
+                for (var i = 0u; i < num_rows; i++) {
+                    q[offset_q + i * size + j] -= dot * q[offset_q + i * size + p];
+                }
+            }
+        }
+
+        var norm = {{ elem }}(0);
+        for (var i = 0u; i < num_rows; i++) {
+            norm += q[offset_q + i * size + j] * q[offset_q + i * size + j];
+        }
+        norm = sqrt(norm);
+        if norm == {{ elem }}(0) {
+            norm = {{ elem }}(1);
+        }
+
+        for (var i = 0u; i < num_rows; i++) {
+            q[offset_q + i * size + j] /= norm;
+        }
+    }
+
+    for (var i = 0u; i < size; i++) {
+        for (var j = 0u; j < num_columns; j++) {
+            var value = {{ elem }}(0);
+            if i <= j {
+                for (var k = 0u; k < num_rows; k++) {
+                    value += q[offset_q + k * size + i] * input[offset_input + k * num_columns + j];
+                }
+            }
+            r[offset_r + i * num_columns + j] = value;
+        }
+    }
+}
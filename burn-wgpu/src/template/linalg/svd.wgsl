@@ -0,0 +1,143 @@
+@group(0)
+@binding(0)
+var<storage, read_write> columns: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> vectors: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation decomposes one matrix in place with the one-sided Jacobi method, the
+    // rotations orthogonalizing its columns. The matrix must have at least as many rows as
+    // columns.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_rows = info[0];
+    let size = info[1];
+    let num_matrices = info[2];
+    let num_sweeps = info[3];
+
+    if id >= num_matrices {
+        return;
+    }
+
+    let offset = id * num_rows * size;
+    let offset_values = id * size;
+    let offset_vectors = id * size * size;
+
+    for (var i = 0u; i < size; i++) {
+        for (var j = 0u; j < size; j++) {
+            vectors[offset_vectors + i * size + j] = select({{ elem }}(0), {{ elem }}(1), i == j);
+        }
+    }
+
+    for (var sweep = 0u; sweep < num_sweeps; sweep++) {
+        for (var p = 0u; p < size; p++) {
+            for (var q = p + 1u; q < size; q++) {
+                var alpha = {{ elem }}(0);
+                var beta = {{ elem }}(0);
+                var gamma = {{ elem }}(0);
+                for (var i = 0u; i < num_rows; i++) {
+                    let value_p = columns[offset + i * size + p];
+                    let value_q = columns[offset + i * size + q];
+                    alpha += value_p * value_p;
+                    beta += value_q * value_q;
+                    gamma += value_p * value_q;
+                }
+                if gamma == {{ elem }}(0) {
+                    continue;
+                }
+
+                let rotation = jacobi_rotation(alpha, beta, gamma);
+                let cosine = rotation.x;
+                let sine = rotation.y;
+
+                for (var i = 0u; i < num_rows; i++) {
+                    let value_p = columns[offset + i * size + p];
+                    let value_q = columns[offset + i * size + q];
+                    columns[offset + i * size + p] = value_p * cosine - value_q * sine;
+                    columns[offset + i * size + q] = value_p * sine + value_q * cosine;
+                }
+                for (var i = 0u; i < size; i++) {
+                    let value_p = vectors[offset_vectors + i * size + p];
+                    let value_q = vectors[offset_vectors + i * size + q];
+                    vectors[offset_vectors + i * size + p] = value_p * cosine - value_q * sine;
+                    vectors[offset_vectors + i * size + q] = value_p * sine + value_q * cosine;
+                }
+            }
+        }
+    }
+
+    // The singular values are the norms of the columns, which are normalized.
+    for (var j = 0u; j < size; j++) {
+        var norm = {{ elem }}(0);
+        for (var i = 0u; i < num_rows; i++) {
+            norm += columns[offset + i * size + j] * columns[offset + i * size + j];
+        }
+        norm = sqrt(norm);
+        values[offset_values + j] = norm;
+
+        if norm == {{ elem }}(0) {
+            norm = {{ elem }}(1);
+        }
+        for (var i = 0u; i < num_rows; i++) {
+            columns[offset + i * size + j] /= norm;
+        }
+    }
+
+    // Insertion sort in descending order, which keeps the order of equal values.
+    for (var i = 1u; i < size; i++) {
+        var j = i;
+        while j > 0u && values[offset_values + j - 1u] < values[offset_values + j] {
+            let value = values[offset_values + j];
+            values[offset_values + j] = values[offset_values + j - 1u];
+            values[offset_values + j - 1u] = value;
+
+            for (var k = 0u; k < num_rows; k++) {
+                let column = columns[offset + k * size + j];
+                columns[offset + k * size + j] = columns[offset + k * size + j - 1u];
+                columns[offset + k * size + j - 1u] = column;
+            }
+            for (var k = 0u; k < size; k++) {
+                let vector = vectors[offset_vectors + k * size + j];
+                vectors[offset_vectors + k * size + j] = vectors[offset_vectors + k * size + j - 1u];
+                vectors[offset_vectors + k * size + j - 1u] = vector;
+            }
+
+            j--;
+        }
+    }
+
+    // The right singular vectors are the columns of the rotations, so they're transposed.
+    for (var i = 0u; i < size; i++) {
+        for (var j = i + 1u; j < size; j++) {
+            let vector = vectors[offset_vectors + i * size + j];
+            vectors[offset_vectors + i * size + j] = vectors[offset_vectors + j * size + i];
+            vectors[offset_vectors + j * size + i] = vector;
+        }
+    }
+}
+
+// The cosine and the sine of the rotation that diagonalizes [[alpha, gamma], [gamma, beta]].
+fn jacobi_rotation(alpha: {{ elem }}, beta: {{ elem }}, gamma: {{ elem }}) -> vec2<{{ elem }}> {
+    let zeta = (beta - alpha) / ({{ elem }}(2) * gamma);
+    let sign = select({{ elem }}(-1), {{ elem }}(1), zeta >= {{ elem }}(0));
+    let tangent = sign / (abs(zeta) + sqrt(zeta * zeta + {{ elem }}(1)));
+    let cosine = {{ elem }}(1) / sqrt(tangent * tangent + {{ elem }}(1));
+
+    return vec2(cosine, tangent * cosine);
+}
@@ -0,0 +1,59 @@
+@group(0)
+@binding(0)
+var<storage, read> lhs: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> rhs: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    // Each invocation solves one column of the right hand side by substitution, in place.
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let size = info[0];
+    let num_columns = info[1];
+    let num_matrices = info[2];
+    let upper = info[3] == 1u;
+    let unit_diagonal = info[4] == 1u;
+
+    let matrix = id / num_columns;
+    let column = id % num_columns;
+
+    if matrix >= num_matrices {
+        return;
+    }
+
+    let offset_lhs = matrix * size * size;
+    let offset_rhs = matrix * size * num_columns + column;
+
+    for (var step = 0u; step < size; step++) {
+        var i = step;
+        if upper {
+            i = size - 1u - step;
+        }
+
+        // Only the rows already solved are used, which ignores the other triangle.
+        var value = rhs[offset_rhs + i * num_columns];
+        for (var j = 0u; j < size; j++) {
+            if (upper && j > i) || (!upper && j < i) {
+                value -= lhs[offset_lhs + i * size + j] * rhs[offset_rhs + j * num_columns];
+            }
+        }
+
+        if !unit_diagonal {
+            value /= lhs[offset_lhs + i * size + i];
+        }
+
+        rhs[offset_rhs + i * num_columns] = value;
+    }
+}
@@ -7,9 +7,9 @@ var<storage, read> input: array<{{ elem }}>;
 
 @group(0)
 @binding(1)
-var<storage, read_write> output: array<{{ elem }}>;
+var<storage, read_write> output: array<{{ acc }}>;
 
-var<workgroup> data: array<{{ elem }}, WORKGROUP_SIZE>;
+var<workgroup> data: array<{{ acc }}, WORKGROUP_SIZE>;
 
 @compute
 @workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
@@ -22,12 +22,12 @@ fn main(
     let id_global = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
     let id_local = local_id.y * WORKGROUP_SIZE_X + local_id.x;
 
-    data[id_local] = input[id_global];
+    data[id_local] = {{ acc }}(input[id_global]);
 
     workgroupBarrier();
 
     if id_local == 0u {
-        var sum = {{ elem }}(0);
+        var sum = {{ acc }}(0);
         for (var i: u32 = 0u; i < WORKGROUP_SIZE; i++) {
             sum += data[i];
         }
@@ -42,11 +42,11 @@ fn main(
         }
     }
 
-    var sum = {{ elem }}(0);
+    var sum = {{ acc }}(0);
 
     for (var i = 0u; i < shape_dim; i++) {
         let index_input = i * stride_dim;
-        sum += input[index_input + index_offset];
+        sum += {{ acc }}(input[index_input + index_offset]);
     }
 
     {{ assign }}
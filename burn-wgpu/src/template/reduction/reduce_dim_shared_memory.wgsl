@@ -10,7 +10,7 @@ var<storage, read_write> output: array<{{ elem }}>;
 @binding(2)
 var<storage, read> info: array<u32>;
 
-var<workgroup> shared_memory: array<{{ elem }}, {{ shared_size }}>;
+var<workgroup> shared_memory: array<{{ acc }}, {{ shared_size }}>;
 
 const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
 
@@ -53,13 +53,13 @@ fn main(
     }
 
     // Ensure shared memory starts at 0
-    shared_memory[local_id] = {{ elem }}(0);
+    shared_memory[local_id] = {{ acc }}(0);
 
     for (var i = 0u; i < n_input_values_per_thread; i++) {
         let nth = local_id + i * n_threads;
         if nth < shape_input_dim_reduce {
             let current_position = index_offset + nth * stride_input_dim_reduce;
-            let value = input[current_position];
+            let value = {{ acc }}(input[current_position]);
             
             {{ update }}
         }
@@ -14,3 +14,9 @@ pub use burn_core::*;
 pub mod train {
     pub use burn_train::*;
 }
+
+/// Distributed training module
+#[cfg(feature = "distributed")]
+pub mod distributed {
+    pub use burn_distributed::*;
+}
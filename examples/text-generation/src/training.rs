@@ -13,7 +13,7 @@ use burn::{
     record::{CompactRecorder, DefaultRecorder, Recorder},
     tensor::backend::AutodiffBackend,
     train::{
-        metric::{AccuracyMetric, CUDAMetric, LearningRateMetric, LossMetric},
+        metric::{AccuracyMetric, CUDAMetric, LearningRateMetric, LossMetric, PerplexityMetric},
         LearnerBuilder,
     },
 };
@@ -62,7 +62,7 @@ pub fn train<B: AutodiffBackend, D: Dataset<TextGenerationItem> + 'static>(
 
     let accum = 6; // Effective batch size = 6 * 6 = 32.
     let optim = config.optimizer.init();
-    let lr_scheduler = NoamLrSchedulerConfig::new(0.01 / accum as f64)
+    let lr_scheduler = NoamLrSchedulerConfig::new(0.01)
         .with_warmup_steps(6000)
         .with_model_size(config.transformer.d_model)
         .init();
@@ -74,10 +74,12 @@ pub fn train<B: AutodiffBackend, D: Dataset<TextGenerationItem> + 'static>(
         .metric_valid_numeric(AccuracyMetric::new().with_pad_token(tokenizer.pad_token()))
         .metric_train(LossMetric::new())
         .metric_valid(LossMetric::new())
+        .metric_train_numeric(PerplexityMetric::new())
+        .metric_valid_numeric(PerplexityMetric::new())
         .metric_train_numeric(LearningRateMetric::new())
         .with_file_checkpointer(CompactRecorder::new())
         .devices(vec![device])
-        .grads_accumulation(accum)
+        .grad_accumulation(accum)
         .num_epochs(config.num_epochs)
         .build(model, optim, lr_scheduler);
 